@@ -93,7 +93,7 @@ fn parse_empty_json_object_does_not_crash() {
 fn parse_binary_garbage_does_not_crash() {
     let dir = TempDir::new().unwrap();
     let log_path = dir.path().join("session.jsonl");
-    std::fs::write(&log_path, &[0x00, 0x01, 0x02, 0xFF, 0xFE, 0x0A]).unwrap();
+    std::fs::write(&log_path, [0x00, 0x01, 0x02, 0xFF, 0xFE, 0x0A]).unwrap();
     assert_eq!(parse_session_log(&log_path), AgentState::Working);
 }
 
@@ -0,0 +1,166 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! File watching for runbook `watch` blocks
+//!
+//! Changes under the project root that match a watch's glob patterns are
+//! collected until no new match arrives for the debounce period, then
+//! reported together as one sorted list of project-relative paths.
+
+use glob::{MatchOptions, Pattern};
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use thiserror::Error;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+
+/// `*` stops at `/`; `**` crosses directories.
+pub(crate) const MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+/// Errors from starting a file watch
+#[derive(Debug, Error)]
+pub enum FileWatchError {
+    #[error("invalid glob '{0}': {1}")]
+    Pattern(String, String),
+    #[error("failed to watch {}: {}", .0.display(), .1)]
+    Watch(PathBuf, notify::Error),
+}
+
+/// A running file watch. Dropping it stops the watch.
+pub struct FileWatch {
+    _watcher: RecommendedWatcher,
+    task: JoinHandle<()>,
+}
+
+impl Drop for FileWatch {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Watch `root` for changes to files matching `patterns` (globs relative to
+/// `root`), sending each settled burst of changes to `on_change`.
+///
+/// Only the directories the patterns can match under are watched, so a
+/// `docs/**/*.md` watch isn't woken by builds writing to `target/`.
+pub fn watch_files(
+    root: &Path,
+    patterns: &[String],
+    debounce: Duration,
+    on_change: mpsc::Sender<Vec<String>>,
+) -> Result<FileWatch, FileWatchError> {
+    let compiled = patterns
+        .iter()
+        .map(|p| Pattern::new(p).map_err(|e| FileWatchError::Pattern(p.clone(), e.msg.into())))
+        .collect::<Result<Vec<_>, _>>()?;
+    // Events carry resolved paths; match them against the resolved root
+    let root = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
+
+    let (raw_tx, raw_rx) = mpsc::unbounded_channel();
+    let mut watcher = notify::recommended_watcher(move |res: Result<notify::Event, _>| {
+        if let Ok(event) = res {
+            if !matches!(event.kind, EventKind::Access(_)) {
+                for path in event.paths {
+                    let _ = raw_tx.send(path);
+                }
+            }
+        }
+    })
+    .map_err(|e| FileWatchError::Watch(root.clone(), e))?;
+
+    for dir in watch_dirs(&root, patterns) {
+        watcher
+            .watch(&dir, RecursiveMode::Recursive)
+            .map_err(|e| FileWatchError::Watch(dir.clone(), e))?;
+    }
+
+    let task = tokio::spawn(debounce_changes(
+        root, compiled, debounce, raw_rx, on_change,
+    ));
+    Ok(FileWatch {
+        _watcher: watcher,
+        task,
+    })
+}
+
+/// Collect matching changes until none arrives for `debounce`, then report
+/// them; repeat until either channel closes.
+async fn debounce_changes(
+    root: PathBuf,
+    patterns: Vec<Pattern>,
+    debounce: Duration,
+    mut raw_rx: mpsc::UnboundedReceiver<PathBuf>,
+    on_change: mpsc::Sender<Vec<String>>,
+) {
+    loop {
+        let mut files = BTreeSet::new();
+        // Wait for the first match of a burst
+        while files.is_empty() {
+            let Some(path) = raw_rx.recv().await else {
+                return;
+            };
+            files.extend(matching_path(&root, &patterns, &path));
+        }
+        // Keep collecting until the burst goes quiet
+        let mut deadline = Instant::now() + debounce;
+        while let Ok(Some(path)) = tokio::time::timeout_at(deadline, raw_rx.recv()).await {
+            if let Some(rel) = matching_path(&root, &patterns, &path) {
+                files.insert(rel);
+                deadline = Instant::now() + debounce;
+            }
+        }
+        if on_change.send(files.into_iter().collect()).await.is_err() {
+            return;
+        }
+    }
+}
+
+/// `path` relative to `root`, if it matches any pattern.
+pub(crate) fn matching_path(root: &Path, patterns: &[Pattern], path: &Path) -> Option<String> {
+    let rel = path.strip_prefix(root).ok()?.to_str()?;
+    patterns
+        .iter()
+        .any(|p| p.matches_with(rel, MATCH_OPTIONS))
+        .then(|| rel.to_string())
+}
+
+/// Directories to watch recursively: the literal leading directories of each
+/// pattern, falling back to `root` when one doesn't exist yet. Directories
+/// inside another watched directory are dropped.
+pub(crate) fn watch_dirs(root: &Path, patterns: &[String]) -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = patterns
+        .iter()
+        .map(|pattern| {
+            let parts: Vec<&str> = pattern.split('/').collect();
+            let literal = parts[..parts.len() - 1]
+                .iter()
+                .take_while(|part| !part.contains(['*', '?', '[']))
+                .fold(root.to_path_buf(), |dir, part| dir.join(part));
+            if literal.is_dir() {
+                literal
+            } else {
+                root.to_path_buf()
+            }
+        })
+        .collect();
+    dirs.sort();
+    dirs.dedup();
+    let mut kept: Vec<PathBuf> = Vec::new();
+    for dir in dirs {
+        if !kept.iter().any(|k| dir.starts_with(k)) {
+            kept.push(dir);
+        }
+    }
+    kept
+}
+
+#[cfg(test)]
+#[path = "file_watch_tests.rs"]
+mod tests;
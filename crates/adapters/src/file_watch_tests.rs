@@ -0,0 +1,108 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+fn patterns(globs: &[&str]) -> Vec<Pattern> {
+    globs.iter().map(|g| Pattern::new(g).unwrap()).collect()
+}
+
+#[yare::parameterized(
+    nested_markdown = { "docs/specs/**/*.md", "docs/specs/api/auth.md", true },
+    top_level_markdown = { "docs/specs/**/*.md", "docs/specs/index.md", true },
+    other_extension = { "docs/specs/**/*.md", "docs/specs/diagram.png", false },
+    star_stays_in_dir = { "docs/*.md", "docs/specs/index.md", false },
+    outside_pattern = { "docs/*.md", "src/main.rs", false },
+    literal_file = { "README.md", "README.md", true },
+)]
+fn matches_relative_paths(glob: &str, rel: &str, expected: bool) {
+    let root = Path::new("/proj");
+    let matched = matching_path(root, &patterns(&[glob]), &root.join(rel));
+    assert_eq!(matched.is_some(), expected);
+    if expected {
+        assert_eq!(matched.as_deref(), Some(rel));
+    }
+}
+
+#[test]
+fn paths_outside_root_never_match() {
+    let matched = matching_path(
+        Path::new("/proj"),
+        &patterns(&["**/*.md"]),
+        Path::new("/other/notes.md"),
+    );
+    assert_eq!(matched, None);
+}
+
+#[test]
+fn watch_dirs_use_literal_prefixes() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    std::fs::create_dir_all(root.join("docs/specs")).unwrap();
+    std::fs::create_dir_all(root.join("src")).unwrap();
+
+    let dirs = watch_dirs(
+        root,
+        &[
+            "docs/specs/**/*.md".to_string(),
+            "docs/**/*.txt".to_string(),
+            "src/*.rs".to_string(),
+        ],
+    );
+    assert_eq!(dirs, vec![root.join("docs"), root.join("src")]);
+}
+
+#[test]
+fn watch_dirs_fall_back_to_root() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    std::fs::create_dir_all(root.join("docs")).unwrap();
+
+    // A top-level file and a directory that doesn't exist yet both need root
+    assert_eq!(
+        watch_dirs(root, &["README.md".to_string(), "docs/*.md".to_string()]),
+        vec![root.to_path_buf()]
+    );
+    assert_eq!(
+        watch_dirs(root, &["missing/*.md".to_string()]),
+        vec![root.to_path_buf()]
+    );
+}
+
+#[test]
+fn invalid_pattern_is_rejected() {
+    let dir = tempfile::tempdir().unwrap();
+    let (tx, _rx) = mpsc::channel(1);
+    let result = watch_files(
+        dir.path(),
+        &["docs/[*.md".to_string()],
+        Duration::from_millis(50),
+        tx,
+    );
+    assert!(matches!(result, Err(FileWatchError::Pattern(..))));
+}
+
+#[tokio::test]
+async fn burst_of_changes_is_reported_once() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    std::fs::create_dir_all(root.join("docs")).unwrap();
+    let (tx, mut rx) = mpsc::channel(4);
+    let _watch = watch_files(
+        root,
+        &["docs/*.md".to_string()],
+        Duration::from_millis(300),
+        tx,
+    )
+    .unwrap();
+
+    std::fs::write(root.join("docs/b.md"), "b").unwrap();
+    std::fs::write(root.join("docs/a.md"), "a").unwrap();
+    std::fs::write(root.join("docs/notes.txt"), "ignored").unwrap();
+
+    let files = tokio::time::timeout(Duration::from_secs(10), rx.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(files, vec!["docs/a.md", "docs/b.md"]);
+}
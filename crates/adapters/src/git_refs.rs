@@ -0,0 +1,148 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Ref watching for runbook `git_trigger` blocks
+//!
+//! The repository's ref storage (loose refs and `packed-refs`) is watched
+//! for changes. After each settled burst the refs matching the trigger's
+//! pattern are listed again and reported if any of them moved.
+
+use crate::file_watch::{watch_files, FileWatch, FileWatchError, MATCH_OPTIONS};
+use crate::subprocess::{run_with_timeout, SHELL_EVAL_TIMEOUT};
+use glob::Pattern;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use thiserror::Error;
+use tokio::process::Command;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+/// Git rewrites several ref files for one update (lock, rename, reflog);
+/// wait this long for them to settle before listing refs.
+const REF_SETTLE: Duration = Duration::from_millis(200);
+
+/// Errors from starting a ref watch
+#[derive(Debug, Error)]
+pub enum GitRefWatchError {
+    #[error("invalid ref pattern '{0}': {1}")]
+    Pattern(String, String),
+    #[error("{}: {}", .0.display(), .1)]
+    Repo(PathBuf, String),
+    #[error(transparent)]
+    Watch(#[from] FileWatchError),
+}
+
+/// A running ref watch. Dropping it stops the watch.
+pub struct GitRefWatch {
+    _files: FileWatch,
+    task: JoinHandle<()>,
+}
+
+impl Drop for GitRefWatch {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Watch the refs of the repository at `repo` that match `ref_pattern`,
+/// sending their positions (ref name to commit) to `on_scan` once right away
+/// and again whenever any of them is created, moved, or deleted.
+pub async fn watch_git_refs(
+    repo: &Path,
+    ref_pattern: &str,
+    on_scan: mpsc::Sender<BTreeMap<String, String>>,
+) -> Result<GitRefWatch, GitRefWatchError> {
+    let pattern = Pattern::new(ref_pattern)
+        .map_err(|e| GitRefWatchError::Pattern(ref_pattern.to_string(), e.msg.into()))?;
+    let common_dir = common_git_dir(repo)
+        .await
+        .map_err(|e| GitRefWatchError::Repo(repo.to_path_buf(), e))?;
+
+    // Watch before the first listing so no update slips in between
+    let (changed_tx, mut changed_rx) = mpsc::channel(1);
+    let files = watch_files(
+        &common_dir,
+        &["refs/**".to_string(), "packed-refs".to_string()],
+        REF_SETTLE,
+        changed_tx,
+    )?;
+
+    let repo = repo.to_path_buf();
+    let task = tokio::spawn(async move {
+        let mut last = None;
+        loop {
+            match list_refs(&repo, &pattern).await {
+                Ok(refs) if last.as_ref() != Some(&refs) => {
+                    if on_scan.send(refs.clone()).await.is_err() {
+                        return;
+                    }
+                    last = Some(refs);
+                }
+                Ok(_) => {}
+                Err(e) => tracing::warn!(repo = %repo.display(), error = %e, "failed to list refs"),
+            }
+            if changed_rx.recv().await.is_none() {
+                return;
+            }
+        }
+    });
+    Ok(GitRefWatch {
+        _files: files,
+        task,
+    })
+}
+
+/// The directory holding the repository's refs, shared by all its worktrees.
+async fn common_git_dir(repo: &Path) -> Result<PathBuf, String> {
+    let output = git(repo, &["rev-parse", "--git-common-dir"], "git rev-parse").await?;
+    let dir = PathBuf::from(output.trim());
+    Ok(if dir.is_absolute() {
+        dir
+    } else {
+        repo.join(dir)
+    })
+}
+
+async fn list_refs(repo: &Path, pattern: &Pattern) -> Result<BTreeMap<String, String>, String> {
+    let output = git(
+        repo,
+        &["for-each-ref", "--format=%(objectname) %(refname)", "refs/"],
+        "git for-each-ref",
+    )
+    .await?;
+    Ok(parse_refs(&output, pattern))
+}
+
+/// Refs matching `pattern` from `for-each-ref` output of
+/// "<objectname> <refname>" lines.
+pub(crate) fn parse_refs(output: &str, pattern: &Pattern) -> BTreeMap<String, String> {
+    output
+        .lines()
+        .filter_map(|line| line.split_once(' '))
+        .filter(|(_, name)| pattern.matches_with(name, MATCH_OPTIONS))
+        .map(|(sha, name)| (name.to_string(), sha.to_string()))
+        .collect()
+}
+
+async fn git(repo: &Path, args: &[&str], description: &str) -> Result<String, String> {
+    let mut cmd = Command::new("git");
+    cmd.arg("-C")
+        .arg(repo)
+        .args(args)
+        .env_remove("GIT_DIR")
+        .env_remove("GIT_WORK_TREE");
+    let output = run_with_timeout(cmd, SHELL_EVAL_TIMEOUT, description).await?;
+    if !output.status.success() {
+        return Err(format!(
+            "{} failed: {}",
+            description,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+#[path = "git_refs_tests.rs"]
+mod tests;
@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+const FOR_EACH_REF: &str = "\
+1111111111111111111111111111111111111111 refs/heads/main
+2222222222222222222222222222222222222222 refs/heads/feature/login
+3333333333333333333333333333333333333333 refs/heads/feature/api/v2
+4444444444444444444444444444444444444444 refs/tags/v1.0
+";
+
+#[yare::parameterized(
+    exact = { "refs/heads/main", &["refs/heads/main"] },
+    star_stays_in_dir = { "refs/heads/feature/*", &["refs/heads/feature/login"] },
+    double_star = { "refs/heads/feature/**", &["refs/heads/feature/api/v2", "refs/heads/feature/login"] },
+    no_match = { "refs/heads/release/*", &[] },
+)]
+fn parse_refs_filters_by_pattern(glob: &str, expected: &[&str]) {
+    let refs = parse_refs(FOR_EACH_REF, &Pattern::new(glob).unwrap());
+    assert_eq!(
+        refs.keys().map(String::as_str).collect::<Vec<_>>(),
+        expected
+    );
+}
+
+#[test]
+fn parse_refs_maps_names_to_commits() {
+    let refs = parse_refs(FOR_EACH_REF, &Pattern::new("refs/tags/*").unwrap());
+    assert_eq!(refs["refs/tags/v1.0"], "4".repeat(40));
+}
+
+async fn next_scan(rx: &mut mpsc::Receiver<BTreeMap<String, String>>) -> BTreeMap<String, String> {
+    tokio::time::timeout(Duration::from_secs(10), rx.recv())
+        .await
+        .unwrap()
+        .unwrap()
+}
+
+fn run_git(repo: &Path, args: &[&str]) {
+    let status = std::process::Command::new("git")
+        .arg("-C")
+        .arg(repo)
+        .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+        .args(args)
+        .env_remove("GIT_DIR")
+        .env_remove("GIT_WORK_TREE")
+        .output()
+        .unwrap()
+        .status;
+    assert!(status.success(), "git {:?} failed", args);
+}
+
+#[tokio::test]
+async fn not_a_repository_is_rejected() {
+    let dir = tempfile::tempdir().unwrap();
+    let (tx, _rx) = mpsc::channel(1);
+    let result = watch_git_refs(dir.path(), "refs/heads/*", tx).await;
+    assert!(matches!(result, Err(GitRefWatchError::Repo(..))));
+}
+
+#[tokio::test]
+async fn reports_initial_refs_and_moves() {
+    let dir = tempfile::tempdir().unwrap();
+    let repo = dir.path();
+    run_git(repo, &["init", "-q", "-b", "main"]);
+    run_git(repo, &["commit", "-q", "--allow-empty", "-m", "first"]);
+
+    let (tx, mut rx) = mpsc::channel(4);
+    let _watch = watch_git_refs(repo, "refs/heads/*", tx).await.unwrap();
+    let initial = next_scan(&mut rx).await;
+    assert_eq!(initial.keys().collect::<Vec<_>>(), vec!["refs/heads/main"]);
+
+    run_git(repo, &["commit", "-q", "--allow-empty", "-m", "second"]);
+    let moved = next_scan(&mut rx).await;
+    assert_eq!(moved.len(), 1);
+    assert_ne!(moved["refs/heads/main"], initial["refs/heads/main"]);
+}
@@ -12,6 +12,8 @@
 
 pub mod agent;
 mod env;
+pub mod file_watch;
+pub mod git_refs;
 pub mod notify;
 pub mod session;
 pub mod subprocess;
@@ -21,6 +23,8 @@ pub use agent::{
     extract_process_name, AgentAdapter, AgentAdapterError, AgentHandle, AgentReconnectConfig,
     AgentSpawnConfig, ClaudeAgentAdapter,
 };
+pub use file_watch::{watch_files, FileWatch, FileWatchError};
+pub use git_refs::{watch_git_refs, GitRefWatch, GitRefWatchError};
 pub use notify::{DesktopNotifyAdapter, NoOpNotifyAdapter, NotifyAdapter};
 pub use session::{NoOpSessionAdapter, SessionAdapter, TmuxAdapter};
 pub use traced::{TracedAgent, TracedSession};
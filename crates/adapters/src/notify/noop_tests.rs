@@ -12,6 +12,6 @@ async fn noop_notify_returns_ok() {
 
 #[test]
 fn noop_notify_default() {
-    let adapter = NoOpNotifyAdapter::default();
+    let adapter = NoOpNotifyAdapter;
     assert!(std::mem::size_of_val(&adapter) == 0);
 }
@@ -51,6 +51,6 @@ async fn noop_session_is_process_running_returns_false() {
 
 #[test]
 fn noop_session_default() {
-    let adapter = NoOpSessionAdapter::default();
+    let adapter = NoOpSessionAdapter;
     assert!(std::mem::size_of_val(&adapter) == 0);
 }
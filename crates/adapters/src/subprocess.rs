@@ -3,7 +3,7 @@
 
 //! Subprocess execution helpers
 
-use std::process::Output;
+use std::process::{Output, Stdio};
 use std::time::Duration;
 use tokio::process::Command;
 
@@ -23,7 +23,8 @@ pub const GATE_TIMEOUT: Duration = Duration::from_secs(30);
 pub const QUEUE_COMMAND_TIMEOUT: Duration = Duration::from_secs(60);
 
 /// Default timeout for job shell step commands.
-/// Set to 10 minutes as a safety net for long-running user scripts.
+/// Set to 10 minutes as a safety net for long-running user scripts;
+/// steps override it with `timeout`.
 pub const SHELL_COMMAND_TIMEOUT: Duration = Duration::from_secs(600);
 
 /// Run a subprocess command with a timeout.
@@ -48,6 +49,53 @@ pub async fn run_with_timeout(
     }
 }
 
+/// Run a command in its own process group with a timeout.
+///
+/// Unlike [`run_with_timeout`], expiry kills the whole process group, so
+/// anything the command started (test runners, dev servers) goes with it.
+/// Returns `Ok(None)` when the timeout elapsed.
+pub async fn run_in_process_group(
+    mut cmd: Command,
+    timeout: Duration,
+    description: &str,
+) -> Result<Option<Output>, String> {
+    cmd.process_group(0)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+    let child = cmd
+        .spawn()
+        .map_err(|e| format!("{} failed: {}", description, e))?;
+    // With process_group(0) the group ID is the child's PID
+    let pgid = child.id();
+
+    match tokio::time::timeout(timeout, child.wait_with_output()).await {
+        Ok(Ok(output)) => Ok(Some(output)),
+        Ok(Err(io_err)) => Err(format!("{} failed: {}", description, io_err)),
+        Err(_elapsed) => {
+            if let Some(pgid) = pgid {
+                kill_process_group(pgid).await;
+            }
+            Ok(None)
+        }
+    }
+}
+
+/// Send SIGKILL to every process in a process group.
+async fn kill_process_group(pgid: u32) {
+    let status = Command::new("kill")
+        .args(["-KILL", "--", &format!("-{}", pgid)])
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .await;
+    if !matches!(status, Ok(s) if s.success()) {
+        tracing::warn!(pgid, "failed to kill process group");
+    }
+}
+
 #[cfg(test)]
 #[path = "subprocess_tests.rs"]
 mod tests;
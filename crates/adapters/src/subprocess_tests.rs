@@ -43,3 +43,31 @@ async fn run_with_timeout_timeout_elapsed() {
     assert!(err.contains("timed out"), "got: {}", err);
     assert!(err.contains("test sleep"), "got: {}", err);
 }
+
+#[tokio::test]
+async fn run_in_process_group_success() {
+    let mut cmd = Command::new("bash");
+    cmd.args(["-c", "echo hello"]);
+    let output = run_in_process_group(cmd, Duration::from_secs(5), "echo")
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "hello");
+}
+
+#[tokio::test]
+async fn run_in_process_group_kills_the_group_on_timeout() {
+    let dir = tempfile::tempdir().unwrap();
+    let marker = dir.path().join("survived");
+    let mut cmd = Command::new("bash");
+    // The background child would outlive a kill of the shell alone
+    cmd.arg("-c")
+        .arg(format!("(sleep 1 && touch {}) & wait", marker.display()));
+
+    let result = run_in_process_group(cmd, Duration::from_millis(200), "test sleep").await;
+    assert!(matches!(result, Ok(None)), "got: {:?}", result);
+
+    tokio::time::sleep(Duration::from_millis(1500)).await;
+    assert!(!marker.exists(), "background child outlived the timeout");
+}
@@ -21,7 +21,9 @@ use tokio::net::UnixStream;
 
 #[path = "client_queries.rs"]
 mod queries;
-pub use queries::{QueuePushResult, QueueRetryResult, RunCommandResult, StartResult};
+pub use queries::{
+    PushOptions, QueuePushResult, QueueRetryResult, RunCommandParams, RunCommandResult, StartResult,
+};
 
 /// Client semantics for CLI command dispatch.
 ///
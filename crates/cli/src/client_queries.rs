@@ -15,6 +15,6 @@ mod worker;
 #[path = "client_queries_queue.rs"]
 mod queue;
 
-pub use job::RunCommandResult;
-pub use queue::{QueuePushResult, QueueRetryResult};
+pub use job::{RunCommandParams, RunCommandResult};
+pub use queue::{PushOptions, QueuePushResult, QueueRetryResult};
 pub use worker::StartResult;
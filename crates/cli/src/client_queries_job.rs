@@ -22,6 +22,19 @@ pub enum RunCommandResult {
     },
 }
 
+/// Parameters for running a command from the project runbook
+pub struct RunCommandParams<'a> {
+    pub project_root: &'a Path,
+    pub invoke_dir: &'a Path,
+    pub namespace: &'a str,
+    pub command: &'a str,
+    pub args: &'a [String],
+    pub named_args: &'a HashMap<String, String>,
+    /// Jobs to wait for before starting (`JOB[:done|:any]`)
+    pub after: &'a [String],
+    pub priority: i32,
+}
+
 impl DaemonClient {
     /// Query for jobs
     pub async fn list_jobs(&self) -> Result<Vec<oj_daemon::JobSummary>, ClientError> {
@@ -145,13 +158,18 @@ impl DaemonClient {
     /// Run a command from the project runbook
     pub async fn run_command(
         &self,
-        project_root: &Path,
-        invoke_dir: &Path,
-        namespace: &str,
-        command: &str,
-        args: &[String],
-        named_args: &HashMap<String, String>,
+        params: RunCommandParams<'_>,
     ) -> Result<RunCommandResult, ClientError> {
+        let RunCommandParams {
+            project_root,
+            invoke_dir,
+            namespace,
+            command,
+            args,
+            named_args,
+            after,
+            priority,
+        } = params;
         let request = Request::RunCommand {
             project_root: project_root.to_path_buf(),
             invoke_dir: invoke_dir.to_path_buf(),
@@ -159,6 +177,8 @@ impl DaemonClient {
             command: command.to_string(),
             args: args.to_vec(),
             named_args: named_args.clone(),
+            after: after.to_vec(),
+            priority,
         };
         match self.send(&request).await? {
             Response::CommandStarted { job_id, job_name } => {
@@ -3,6 +3,7 @@
 
 //! Queue and decision methods for DaemonClient.
 
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 use oj_daemon::{Query, Request, Response};
@@ -19,20 +20,33 @@ impl DaemonClient {
         namespace: &str,
         queue_name: &str,
         data: serde_json::Value,
+        options: PushOptions,
     ) -> Result<QueuePushResult, ClientError> {
+        let PushOptions {
+            priority,
+            visible_after_epoch_ms,
+            dedup_key,
+            ttl,
+        } = options;
         let request = Request::QueuePush {
             project_root: project_root.to_path_buf(),
             namespace: namespace.to_string(),
             queue_name: queue_name.to_string(),
             data,
+            priority,
+            visible_after_epoch_ms,
+            dedup_key,
+            ttl,
         };
         match self.send(&request).await? {
             Response::QueuePushed {
                 queue_name,
                 item_id,
+                deduplicated,
             } => Ok(QueuePushResult::Pushed {
                 queue_name,
                 item_id,
+                deduplicated,
             }),
             Response::Ok => Ok(QueuePushResult::Refreshed),
             other => Self::reject(other),
@@ -103,6 +117,32 @@ impl DaemonClient {
         }
     }
 
+    /// Change a queue item's dispatch priority
+    pub async fn queue_edit(
+        &self,
+        project_root: &Path,
+        namespace: &str,
+        queue_name: &str,
+        item_id: &str,
+        priority: i32,
+    ) -> Result<(String, String), ClientError> {
+        let request = Request::QueueEdit {
+            project_root: project_root.to_path_buf(),
+            namespace: namespace.to_string(),
+            queue_name: queue_name.to_string(),
+            item_id: item_id.to_string(),
+            priority,
+        };
+        match self.send(&request).await? {
+            Response::QueueEdited {
+                queue_name,
+                item_id,
+                ..
+            } => Ok((queue_name, item_id)),
+            other => Self::reject(other),
+        }
+    }
+
     /// Force-fail an active queue item
     pub async fn queue_fail(
         &self,
@@ -285,11 +325,13 @@ impl DaemonClient {
         id: &str,
         chosen: Option<usize>,
         message: Option<String>,
+        values: HashMap<String, String>,
     ) -> Result<String, ClientError> {
         let request = Request::DecisionResolve {
             id: id.to_string(),
             chosen,
             message,
+            values,
         };
         match self.send(&request).await? {
             Response::DecisionResolved { id } => Ok(id),
@@ -298,9 +340,27 @@ impl DaemonClient {
     }
 }
 
+/// Options from `oj queue push` flags.
+#[derive(Debug, Default)]
+pub struct PushOptions {
+    /// Overrides the queue's `priority` template
+    pub priority: Option<i32>,
+    /// Hide the item from workers until this time (`--delay` / `--at`)
+    pub visible_after_epoch_ms: Option<u64>,
+    /// Overrides the queue's `dedup_key` template
+    pub dedup_key: Option<String>,
+    /// Overrides the queue's `ttl`
+    pub ttl: Option<String>,
+}
+
 /// Result from queue push operation
 pub enum QueuePushResult {
-    Pushed { queue_name: String, item_id: String },
+    Pushed {
+        queue_name: String,
+        item_id: String,
+        /// An existing item matched; `item_id` is that item
+        deduplicated: bool,
+    },
     Refreshed,
 }
 
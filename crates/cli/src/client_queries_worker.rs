@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: BUSL-1.1
 // Copyright (c) 2026 Alfred Jean LLC
 
-//! Worker and cron methods for DaemonClient.
+//! Worker, cron, watch, and git trigger methods for DaemonClient.
 
 use std::path::{Path, PathBuf};
 
@@ -70,24 +70,34 @@ impl DaemonClient {
         }
     }
 
-    /// Resize a worker's concurrency
+    /// Resize a worker's concurrency and/or dispatch rate
     pub async fn worker_resize(
         &self,
         name: &str,
         namespace: &str,
-        concurrency: u32,
-    ) -> Result<(String, u32, u32), ClientError> {
+        concurrency: Option<u32>,
+        rate: Option<&str>,
+    ) -> Result<ResizeResult, ClientError> {
         let request = Request::WorkerResize {
             worker_name: name.to_string(),
             namespace: namespace.to_string(),
             concurrency,
+            rate: rate.map(str::to_string),
         };
         match self.send(&request).await? {
             Response::WorkerResized {
                 worker_name,
                 old_concurrency,
                 new_concurrency,
-            } => Ok((worker_name, old_concurrency, new_concurrency)),
+                old_rate,
+                new_rate,
+            } => Ok(ResizeResult {
+                worker_name,
+                old_concurrency,
+                new_concurrency,
+                old_rate,
+                new_rate,
+            }),
             other => Self::reject(other),
         }
     }
@@ -265,9 +275,126 @@ impl DaemonClient {
             other => Self::reject(other),
         }
     }
+
+    // -- Watch commands --
+
+    /// Start a file watch
+    pub async fn watch_start(
+        &self,
+        project_root: &Path,
+        namespace: &str,
+        watch_name: &str,
+        all: bool,
+    ) -> Result<StartResult, ClientError> {
+        let request = Request::WatchStart {
+            project_root: project_root.to_path_buf(),
+            namespace: namespace.to_string(),
+            watch_name: watch_name.to_string(),
+            all,
+        };
+        match self.send(&request).await? {
+            Response::WatchStarted { watch_name } => Ok(StartResult::Single { name: watch_name }),
+            Response::WatchesStarted { started, skipped } => {
+                Ok(StartResult::Multiple { started, skipped })
+            }
+            other => Self::reject(other),
+        }
+    }
+
+    /// Stop a file watch
+    pub async fn watch_stop(
+        &self,
+        name: &str,
+        namespace: &str,
+        project_root: Option<&Path>,
+    ) -> Result<(), ClientError> {
+        let request = Request::WatchStop {
+            watch_name: name.to_string(),
+            namespace: namespace.to_string(),
+            project_root: project_root.map(|p| p.to_path_buf()),
+        };
+        self.send_simple(&request).await
+    }
+
+    /// List all file watches
+    pub async fn list_watches(
+        &self,
+    ) -> Result<Vec<oj_daemon::protocol::WatchSummary>, ClientError> {
+        let request = Request::Query {
+            query: Query::ListWatches,
+        };
+        match self.send(&request).await? {
+            Response::Watches { watches } => Ok(watches),
+            other => Self::reject(other),
+        }
+    }
+
+    // -- Git trigger commands --
+
+    /// Start a git trigger
+    pub async fn git_trigger_start(
+        &self,
+        project_root: &Path,
+        namespace: &str,
+        trigger_name: &str,
+        all: bool,
+    ) -> Result<StartResult, ClientError> {
+        let request = Request::GitTriggerStart {
+            project_root: project_root.to_path_buf(),
+            namespace: namespace.to_string(),
+            trigger_name: trigger_name.to_string(),
+            all,
+        };
+        match self.send(&request).await? {
+            Response::GitTriggerStarted { trigger_name } => {
+                Ok(StartResult::Single { name: trigger_name })
+            }
+            Response::GitTriggersStarted { started, skipped } => {
+                Ok(StartResult::Multiple { started, skipped })
+            }
+            other => Self::reject(other),
+        }
+    }
+
+    /// Stop a git trigger
+    pub async fn git_trigger_stop(
+        &self,
+        name: &str,
+        namespace: &str,
+        project_root: Option<&Path>,
+    ) -> Result<(), ClientError> {
+        let request = Request::GitTriggerStop {
+            trigger_name: name.to_string(),
+            namespace: namespace.to_string(),
+            project_root: project_root.map(|p| p.to_path_buf()),
+        };
+        self.send_simple(&request).await
+    }
+
+    /// List all git triggers
+    pub async fn list_git_triggers(
+        &self,
+    ) -> Result<Vec<oj_daemon::protocol::GitTriggerSummary>, ClientError> {
+        let request = Request::Query {
+            query: Query::ListGitTriggers,
+        };
+        match self.send(&request).await? {
+            Response::GitTriggers { git_triggers } => Ok(git_triggers),
+            other => Self::reject(other),
+        }
+    }
+}
+
+/// Result from resizing a worker
+pub struct ResizeResult {
+    pub worker_name: String,
+    pub old_concurrency: u32,
+    pub new_concurrency: u32,
+    pub old_rate: Option<String>,
+    pub new_rate: Option<String>,
 }
 
-/// Result from a start operation (worker or cron)
+/// Result from a start operation (worker, cron, watch, or git trigger)
 pub enum StartResult {
     Single {
         name: String,
@@ -307,6 +307,9 @@ pub async fn handle(
                         println!("    Files read: {}", a.files_read);
                         println!("    Files written: {}", a.files_written);
                         println!("    Commands run: {}", a.commands_run);
+                        if let Some(ref spend) = a.spend {
+                            println!("    Spend: {}", crate::output::format_spend(spend));
+                        }
 
                         println!();
                         if let Some(ref session) = a.session_id {
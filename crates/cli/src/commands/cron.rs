@@ -167,7 +167,7 @@ pub async fn handle(
                             cols.push(Column::left("PROJECT"));
                         }
                         cols.extend([
-                            Column::left("INTERVAL"),
+                            Column::left("SCHEDULE"),
                             Column::left("JOB"),
                             Column::left("TIME"),
                             Column::status("STATUS"),
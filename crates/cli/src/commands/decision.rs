@@ -3,6 +3,7 @@
 
 //! Decision command handlers
 
+use std::collections::HashMap;
 use std::io::{BufRead, IsTerminal, Write};
 
 use anyhow::Result;
@@ -13,6 +14,7 @@ use oj_daemon::protocol::DecisionDetail;
 
 use crate::client::{ClientKind, DaemonClient};
 use crate::color;
+use crate::commands::job::parse_key_value;
 use crate::output::{format_time_ago, OutputFormat};
 use crate::table::{project_cell, should_show_project, Column, Table};
 
@@ -42,6 +44,9 @@ pub enum DecisionCommand {
         /// Freeform message or answer
         #[arg(short = 'm', long)]
         message: Option<String>,
+        /// Value for a field the decision asks for (repeatable: --field reason=...)
+        #[arg(short = 'f', long = "field", value_parser = parse_key_value)]
+        field: Vec<(String, String)>,
     },
 }
 
@@ -173,7 +178,24 @@ pub async fn handle(
                             Some(msg_line.trim().to_string())
                         };
 
-                        match client.decision_resolve(&detail.id, Some(n), message).await {
+                        // The last option of an approval cancels; it needs no fields
+                        let mut values = HashMap::new();
+                        if n < option_count {
+                            for field in &detail.fields {
+                                eprint!("{}: ", field);
+                                std::io::stderr().flush().ok();
+                                let value = match lines.next() {
+                                    Some(Ok(l)) => l.trim().to_string(),
+                                    _ => String::new(),
+                                };
+                                values.insert(field.clone(), value);
+                            }
+                        }
+
+                        match client
+                            .decision_resolve(&detail.id, Some(n), message, values)
+                            .await
+                        {
                             Ok(_) => {
                                 let label = detail
                                     .options
@@ -212,8 +234,12 @@ pub async fn handle(
             id,
             choice,
             message,
+            field,
         } => {
-            let resolved_id = client.decision_resolve(&id, choice, message).await?;
+            let values = field.into_iter().collect();
+            let resolved_id = client
+                .decision_resolve(&id, choice, message, values)
+                .await?;
             println!("Resolved decision {}", resolved_id.short(8));
         }
     }
@@ -283,6 +309,11 @@ pub(crate) fn format_decision_detail(
         if let Some(ref m) = d.message {
             let _ = writeln!(out, "{} {}", color::context("Message:"), m);
         }
+        let mut values: Vec<_> = d.values.iter().collect();
+        values.sort();
+        for (field, value) in values {
+            let _ = writeln!(out, "{} {}", color::context(&format!("{}:", field)), value);
+        }
     }
 
     let _ = writeln!(out);
@@ -308,12 +339,22 @@ pub(crate) fn format_decision_detail(
         }
     }
 
+    if !d.fields.is_empty() && d.resolved_at_ms.is_none() {
+        let _ = writeln!(out);
+        let _ = writeln!(out, "{} {}", color::header("Fields:"), d.fields.join(", "));
+    }
+
     if show_resolve_hint && d.resolved_at_ms.is_none() {
+        let fields: String = d
+            .fields
+            .iter()
+            .map(|f| format!(" --field {}=<value>", f))
+            .collect();
         let _ = writeln!(out);
         let _ = writeln!(
             out,
-            "Use: oj decision resolve {} <number> [-m message]",
-            short_id
+            "Use: oj decision resolve {} <number> [-m message]{}",
+            short_id, fields
         );
     }
 }
@@ -42,6 +42,7 @@ fn parse_resolve_with_choice() {
         id,
         choice,
         message,
+        ..
     } = cli.command
     {
         assert_eq!(id, "abc123");
@@ -59,6 +60,7 @@ fn parse_resolve_with_message() {
         id,
         choice,
         message,
+        ..
     } = cli.command
     {
         assert_eq!(id, "abc123");
@@ -76,6 +78,7 @@ fn parse_resolve_with_choice_and_message() {
         id,
         choice,
         message,
+        ..
     } = cli.command
     {
         assert_eq!(id, "abc123");
@@ -86,6 +89,32 @@ fn parse_resolve_with_choice_and_message() {
     }
 }
 
+#[test]
+fn parse_resolve_with_fields() {
+    let cli = TestCli::parse_from([
+        "test",
+        "resolve",
+        "abc123",
+        "1",
+        "--field",
+        "reason=staging is green",
+        "-f",
+        "ticket=REL-7",
+    ]);
+    if let DecisionCommand::Resolve { choice, field, .. } = cli.command {
+        assert_eq!(choice, Some(1));
+        assert_eq!(
+            field,
+            vec![
+                ("reason".to_string(), "staging is green".to_string()),
+                ("ticket".to_string(), "REL-7".to_string()),
+            ]
+        );
+    } else {
+        panic!("expected Resolve");
+    }
+}
+
 fn make_decision(id: &str, namespace: &str, job: &str) -> DecisionSummary {
     DecisionSummary {
         id: id.to_string(),
@@ -130,6 +159,8 @@ fn make_detail(resolved: bool) -> DecisionDetail {
         resolved_at_ms: if resolved { Some(1000) } else { None },
         superseded_by: None,
         namespace: "myproject".to_string(),
+        fields: Vec::new(),
+        values: HashMap::new(),
     }
 }
 
@@ -265,3 +296,31 @@ fn review_input_invalid() {
     assert_eq!(parse_review_input("-1", 3), ReviewAction::Invalid);
     assert_eq!(parse_review_input("pick", 3), ReviewAction::Invalid);
 }
+
+#[test]
+fn format_decision_detail_lists_fields_in_hint() {
+    let mut d = make_detail(false);
+    d.fields = vec!["reason".to_string()];
+    let mut buf = Vec::new();
+    super::format_decision_detail(&mut buf, &d, true);
+    let out = output_string(&buf);
+
+    assert!(out.contains("Fields: reason"));
+    assert!(
+        out.contains("oj decision resolve abcdef12 <number> [-m message] --field reason=<value>")
+    );
+}
+
+#[test]
+fn format_decision_detail_shows_field_values() {
+    let mut d = make_detail(true);
+    d.fields = vec!["reason".to_string()];
+    d.values
+        .insert("reason".to_string(), "staging is green".to_string());
+    let mut buf = Vec::new();
+    super::format_decision_detail(&mut buf, &d, true);
+    let out = output_string(&buf);
+
+    assert!(out.contains("reason: staging is green"));
+    assert!(!out.contains("Fields:"));
+}
@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Git trigger command handlers
+
+use anyhow::Result;
+use clap::{Args, Subcommand};
+
+use crate::client::{ClientKind, DaemonClient};
+use crate::output::{print_start_results, OutputFormat};
+use crate::table::{project_cell, should_show_project, Column, Table};
+
+#[derive(Args)]
+pub struct GitTriggerArgs {
+    #[command(subcommand)]
+    pub command: GitTriggerCommand,
+}
+
+#[derive(Subcommand)]
+pub enum GitTriggerCommand {
+    /// List all git triggers and their status
+    List {},
+    /// Start a git trigger (runs its job when a matching ref moves)
+    Start {
+        /// Git trigger name from runbook (required unless --all)
+        name: Option<String>,
+        /// Start all git triggers defined in runbooks
+        #[arg(long)]
+        all: bool,
+    },
+    /// Stop a git trigger
+    Stop {
+        /// Git trigger name from runbook
+        name: String,
+    },
+}
+
+impl GitTriggerCommand {
+    pub fn client_kind(&self) -> ClientKind {
+        match self {
+            Self::List {} => ClientKind::Query,
+            _ => ClientKind::Action,
+        }
+    }
+}
+
+pub async fn handle(
+    command: GitTriggerCommand,
+    client: &DaemonClient,
+    project_root: &std::path::Path,
+    namespace: &str,
+    project_filter: Option<&str>,
+    format: OutputFormat,
+) -> Result<()> {
+    match command {
+        GitTriggerCommand::Start { name, all } => {
+            if !all && name.is_none() {
+                anyhow::bail!("git trigger name required (or use --all)");
+            }
+            let trigger_name = name.unwrap_or_default();
+            let result = client
+                .git_trigger_start(project_root, namespace, &trigger_name, all)
+                .await?;
+            print_start_results(&result, "Git trigger", "git triggers", namespace);
+        }
+        GitTriggerCommand::Stop { name } => {
+            client
+                .git_trigger_stop(&name, namespace, Some(project_root))
+                .await?;
+            println!("Git trigger '{}' stopped ({})", name, namespace);
+        }
+        GitTriggerCommand::List {} => {
+            let mut triggers = client.list_git_triggers().await?;
+
+            // Filter by explicit --project flag (OJ_NAMESPACE is NOT used for filtering)
+            if let Some(proj) = project_filter {
+                triggers.retain(|t| t.namespace == proj);
+            }
+            triggers.sort_by(|a, b| a.name.cmp(&b.name));
+            match format {
+                OutputFormat::Json => {
+                    println!("{}", serde_json::to_string_pretty(&triggers)?);
+                }
+                OutputFormat::Text => {
+                    if triggers.is_empty() {
+                        println!("No git triggers found");
+                    } else {
+                        let show_project =
+                            should_show_project(triggers.iter().map(|t| t.namespace.as_str()));
+
+                        let mut cols = vec![Column::left("KIND")];
+                        if show_project {
+                            cols.push(Column::left("PROJECT"));
+                        }
+                        cols.extend([
+                            Column::left("REF"),
+                            Column::right("REFS"),
+                            Column::left("JOB"),
+                            Column::left("FIRED"),
+                            Column::status("STATUS"),
+                        ]);
+                        let mut table = Table::new(cols);
+
+                        for t in &triggers {
+                            let mut cells = vec![t.name.clone()];
+                            if show_project {
+                                cells.push(project_cell(&t.namespace));
+                            }
+                            cells.extend([
+                                t.ref_pattern.clone(),
+                                t.refs.to_string(),
+                                t.job.clone(),
+                                t.last_fired.clone(),
+                                t.status.clone(),
+                            ]);
+                            table.row(cells);
+                        }
+                        table.render(&mut std::io::stdout());
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
@@ -10,13 +10,13 @@ use std::time::Duration;
 use anyhow::Result;
 use clap::{Args, Subcommand};
 
-use oj_core::{ShortId, StepOutcomeKind};
+use oj_core::{ShortId, StepOutcomeKind, SHELL_TIMEOUT_REASON};
 
 use crate::client::{ClientKind, DaemonClient};
 use crate::color;
 use crate::output::{
-    display_log, format_time_ago, print_peek_frame, print_prune_results, should_use_color,
-    OutputFormat,
+    display_log, format_spend, format_time_ago, print_peek_frame, print_prune_results,
+    should_use_color, OutputFormat,
 };
 use crate::table::{project_cell, should_show_project, Column, Table};
 
@@ -201,6 +201,9 @@ pub(crate) fn format_job_list(out: &mut impl Write, jobs: &[oj_daemon::JobSummar
     // Show RETRIES column only when any job has retries
     let show_retries = jobs.iter().any(|p| p.retry_count > 0);
 
+    // Show AFTER column only when any job is held on other jobs
+    let show_after = jobs.iter().any(|p| !p.blocked_on.is_empty());
+
     // Build columns
     let mut cols = vec![Column::muted("ID")];
     if show_project {
@@ -215,6 +218,9 @@ pub(crate) fn format_job_list(out: &mut impl Write, jobs: &[oj_daemon::JobSummar
     if show_retries {
         cols.push(Column::left("RETRIES"));
     }
+    if show_after {
+        cols.push(Column::left("AFTER"));
+    }
     cols.push(Column::status("STATUS"));
 
     let mut table = Table::new(cols);
@@ -231,6 +237,10 @@ pub(crate) fn format_job_list(out: &mut impl Write, jobs: &[oj_daemon::JobSummar
         if show_retries {
             cells.push(p.retry_count.to_string());
         }
+        if show_after {
+            let after: Vec<&str> = p.blocked_on.iter().map(|id| id.short(8)).collect();
+            cells.push(after.join(","));
+        }
         cells.push(p.step_status.to_string());
         table.row(cells);
     }
@@ -238,6 +248,58 @@ pub(crate) fn format_job_list(out: &mut impl Write, jobs: &[oj_daemon::JobSummar
     table.render(out);
 }
 
+/// Render a job's step history as a tree: parallel branches and the steps
+/// of sub-jobs are listed under the step that started them.
+pub(crate) fn format_steps(out: &mut impl Write, steps: &[oj_daemon::StepRecordDetail]) {
+    write_steps(out, steps, 0);
+}
+
+fn write_steps(out: &mut impl Write, steps: &[oj_daemon::StepRecordDetail], depth: usize) {
+    let indent = "  ".repeat(depth + 2);
+    let width = 12usize.saturating_sub(2 * depth).max(1);
+    for step in steps {
+        let duration = super::job_wait::format_duration(step.started_at_ms, step.finished_at_ms);
+        let line = format!(
+            "{}{:<width$} {:<8} {}",
+            indent,
+            step.name,
+            duration,
+            color::status(&step_status_label(step)),
+        );
+        let _ = match &step.job_id {
+            Some(job_id) => writeln!(out, "{} {}", line, color::muted(job_id.short(8))),
+            None => writeln!(out, "{}", line),
+        };
+        write_steps(out, &step.branches, depth + 1);
+        write_steps(out, &step.steps, depth + 1);
+    }
+}
+
+fn step_status_label(step: &oj_daemon::StepRecordDetail) -> String {
+    match step.outcome {
+        StepOutcomeKind::Completed => "completed".to_string(),
+        StepOutcomeKind::Running => match &step.timeout {
+            Some(timeout) => format!("running (timeout {})", timeout),
+            None => "running".to_string(),
+        },
+        StepOutcomeKind::Failed => match &step.detail {
+            Some(d) => match d.strip_prefix(SHELL_TIMEOUT_REASON) {
+                Some(after) => format!("timed out (after{})", after),
+                None => format!("failed ({})", truncate(d, 40)),
+            },
+            None => "failed".to_string(),
+        },
+        StepOutcomeKind::Waiting => match &step.detail {
+            Some(d) => format!("waiting ({})", truncate(d, 40)),
+            None => "waiting".to_string(),
+        },
+        StepOutcomeKind::Blocked => match &step.detail {
+            Some(d) => format!("blocked ({})", truncate(d, 40)),
+            None => "blocked".to_string(),
+        },
+    }
+}
+
 pub async fn handle(
     command: JobCommand,
     client: &DaemonClient,
@@ -273,7 +335,7 @@ pub async fn handle(
             }
 
             // Sort by most recently updated first
-            jobs.sort_by(|a, b| b.updated_at_ms.cmp(&a.updated_at_ms));
+            jobs.sort_by_key(|b| std::cmp::Reverse(b.updated_at_ms));
 
             // Limit
             let total = jobs.len();
@@ -313,6 +375,17 @@ pub async fn handle(
                             println!("  {} {}", color::context("Project:"), p.namespace);
                         }
                         println!("  {} {}", color::context("Kind:"), p.kind);
+                        if let Some(parent) = &p.parent_job_id {
+                            println!("  {} {}", color::context("Parent:"), parent);
+                        }
+                        if let Some(position) = p.queue_position {
+                            println!("  {} #{}", color::context("Queued:"), position);
+                        }
+                        if !p.blocked_on.is_empty() {
+                            let after: Vec<&str> =
+                                p.blocked_on.iter().map(|id| id.short(8)).collect();
+                            println!("  {} {}", color::context("After:"), after.join(", "));
+                        }
                         println!(
                             "  {} {}",
                             color::context("Status:"),
@@ -324,6 +397,9 @@ pub async fn handle(
                         if let Some(ws) = &p.workspace_path {
                             println!("  {} {}", color::context("Workspace:"), ws.display());
                         }
+                        if let Some(spend) = &p.spend {
+                            println!("  {} {}", color::context("Spend:"), format_spend(spend));
+                        }
                         if let Some(error) = &p.error {
                             println!();
                             println!("  {} {}", color::context("Error:"), error);
@@ -332,34 +408,7 @@ pub async fn handle(
                         if !p.steps.is_empty() {
                             println!();
                             println!("  {}", color::header("Steps:"));
-                            for step in &p.steps {
-                                let duration = super::job_wait::format_duration(
-                                    step.started_at_ms,
-                                    step.finished_at_ms,
-                                );
-                                let status = match step.outcome {
-                                    StepOutcomeKind::Completed => "completed".to_string(),
-                                    StepOutcomeKind::Running => "running".to_string(),
-                                    StepOutcomeKind::Failed => match &step.detail {
-                                        Some(d) => {
-                                            format!("failed ({})", truncate(d, 40))
-                                        }
-                                        None => "failed".to_string(),
-                                    },
-                                    StepOutcomeKind::Waiting => match &step.detail {
-                                        Some(d) => {
-                                            format!("waiting ({})", truncate(d, 40))
-                                        }
-                                        None => "waiting".to_string(),
-                                    },
-                                };
-                                println!(
-                                    "    {:<12} {:<8} {}",
-                                    step.name,
-                                    duration,
-                                    color::status(&status)
-                                );
-                            }
+                            format_steps(&mut std::io::stdout(), &p.steps);
                         }
 
                         if !p.agents.is_empty() {
@@ -3,8 +3,8 @@
 
 use super::super::job_wait::{print_step_progress, StepTracker};
 use super::{
-    format_job_list, format_var_value, group_vars_by_scope, is_var_truncated, parse_duration,
-    var_scope_order,
+    format_job_list, format_steps, format_var_value, group_vars_by_scope, is_var_truncated,
+    parse_duration, var_scope_order,
 };
 use oj_core::{StepOutcomeKind, StepStatusKind};
 use oj_daemon::{JobDetail, JobSummary, StepRecordDetail};
@@ -43,7 +43,7 @@ fn parse_duration_zero_fails() {
 
 #[test]
 fn sort_order_most_recently_updated_first() {
-    let mut jobs = vec![
+    let mut jobs = [
         JobSummary {
             id: "done-1".into(),
             name: "done-job".into(),
@@ -54,6 +54,7 @@ fn sort_order_most_recently_updated_first() {
             updated_at_ms: 5000,
             namespace: String::new(),
             retry_count: 0,
+            blocked_on: vec![],
         },
         JobSummary {
             id: "failed-1".into(),
@@ -65,6 +66,7 @@ fn sort_order_most_recently_updated_first() {
             updated_at_ms: 2000,
             namespace: String::new(),
             retry_count: 0,
+            blocked_on: vec![],
         },
         JobSummary {
             id: "active-1".into(),
@@ -76,10 +78,11 @@ fn sort_order_most_recently_updated_first() {
             updated_at_ms: 3000,
             namespace: String::new(),
             retry_count: 0,
+            blocked_on: vec![],
         },
     ];
 
-    jobs.sort_by(|a, b| b.updated_at_ms.cmp(&a.updated_at_ms));
+    jobs.sort_by_key(|b| std::cmp::Reverse(b.updated_at_ms));
 
     assert_eq!(jobs[0].id, "done-1"); // updated_at 5000
     assert_eq!(jobs[1].id, "active-1"); // updated_at 3000
@@ -88,7 +91,7 @@ fn sort_order_most_recently_updated_first() {
 
 #[test]
 fn sort_order_most_recent_updated_first_within_same_status() {
-    let mut jobs = vec![
+    let mut jobs = [
         JobSummary {
             id: "old".into(),
             name: "old-job".into(),
@@ -99,6 +102,7 @@ fn sort_order_most_recent_updated_first_within_same_status() {
             updated_at_ms: 1000,
             namespace: String::new(),
             retry_count: 0,
+            blocked_on: vec![],
         },
         JobSummary {
             id: "new".into(),
@@ -110,10 +114,11 @@ fn sort_order_most_recent_updated_first_within_same_status() {
             updated_at_ms: 5000,
             namespace: String::new(),
             retry_count: 0,
+            blocked_on: vec![],
         },
     ];
 
-    jobs.sort_by(|a, b| b.updated_at_ms.cmp(&a.updated_at_ms));
+    jobs.sort_by_key(|b| std::cmp::Reverse(b.updated_at_ms));
 
     assert_eq!(jobs[0].id, "new");
     assert_eq!(jobs[1].id, "old");
@@ -133,6 +138,10 @@ fn make_detail(name: &str, steps: Vec<StepRecordDetail>) -> JobDetail {
         steps,
         agents: vec![],
         namespace: String::new(),
+        parent_job_id: None,
+        blocked_on: vec![],
+        queue_position: None,
+        spend: None,
     }
 }
 
@@ -150,6 +159,10 @@ fn make_step(
         detail: None,
         agent_id: None,
         agent_name: None,
+        timeout: None,
+        job_id: None,
+        branches: Vec::new(),
+        steps: Vec::new(),
     }
 }
 
@@ -424,6 +437,7 @@ fn make_summary_ns(
         updated_at_ms: 0,
         namespace: namespace.into(),
         retry_count: 0,
+        blocked_on: vec![],
     }
 }
 
@@ -444,6 +458,7 @@ fn make_summary(
         updated_at_ms: 0,
         namespace: String::new(),
         retry_count: 0,
+        blocked_on: vec![],
     }
 }
 
@@ -617,6 +632,33 @@ fn list_retries_column_shown_when_nonzero() {
     assert!(lines[2].contains("0"));
 }
 
+#[test]
+fn list_after_column_shows_pending_dependencies() {
+    let mut p1 = make_summary(
+        "abcdef123456",
+        "deploy-a",
+        "deploy",
+        "ship",
+        StepStatusKind::Blocked,
+    );
+    p1.blocked_on = vec!["999999999999aaaa".to_string()];
+    let p2 = make_summary(
+        "999999999999aaaa",
+        "build-b",
+        "build",
+        "test",
+        StepStatusKind::Running,
+    );
+    let mut buf = Vec::new();
+    format_job_list(&mut buf, &[p1, p2]);
+    let out = output_string(&buf);
+    let lines: Vec<&str> = out.lines().collect();
+
+    assert!(lines[0].contains("AFTER"));
+    assert!(lines[1].contains("99999999"));
+    assert!(!lines[1].contains("999999999999aaaa"));
+}
+
 // ── Project filter tests ─────────────────────────────────────────────────
 
 #[test]
@@ -840,3 +882,67 @@ fn group_vars_by_scope_empty() {
     let sorted = group_vars_by_scope(&vars);
     assert!(sorted.is_empty());
 }
+
+#[test]
+fn steps_render_parallel_branches_under_their_step() {
+    let mut checks = make_step("checks", StepOutcomeKind::Failed, 1000, Some(5000));
+    checks.detail = Some("branch 'test' failed: shell exit code: 1".into());
+    let mut lint = make_step("lint", StepOutcomeKind::Completed, 1000, Some(3000));
+    lint.job_id = Some("abcdef123456".into());
+    let mut test = make_step("test", StepOutcomeKind::Failed, 1000, Some(5000));
+    test.job_id = Some("999999999999".into());
+    test.detail = Some("shell exit code: 1".into());
+    checks.branches = vec![lint, test];
+
+    let mut buf = Vec::new();
+    format_steps(
+        &mut buf,
+        &[
+            checks,
+            make_step("cleanup", StepOutcomeKind::Completed, 5000, Some(6000)),
+        ],
+    );
+    assert_eq!(
+        output_string(&buf),
+        "    checks       4s       failed (branch 'test' failed: shell exit code: 1)\n\
+         \x20     lint       2s       completed abcdef12\n\
+         \x20     test       4s       failed (shell exit code: 1) 99999999\n\
+         \x20   cleanup      1s       completed\n"
+    );
+}
+
+#[test]
+fn steps_render_sub_job_steps_under_their_step() {
+    let mut merge = make_step("merge", StepOutcomeKind::Running, 1000, None);
+    merge.job_id = Some("fedcba987654".into());
+    merge.steps = vec![
+        make_step("rebase", StepOutcomeKind::Completed, 1000, Some(2000)),
+        make_step("push", StepOutcomeKind::Running, 2000, None),
+    ];
+
+    let mut buf = Vec::new();
+    format_steps(&mut buf, &[merge]);
+    let output = output_string(&buf);
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert!(lines[0].starts_with("    merge "));
+    assert!(lines[0].ends_with("running fedcba98"));
+    assert!(lines[1].starts_with("      rebase     1s "));
+    assert!(lines[2].starts_with("      push "));
+}
+
+#[test]
+fn steps_render_timeouts() {
+    let mut test = make_step("test", StepOutcomeKind::Failed, 1000, Some(31000));
+    test.timeout = Some("30s".into());
+    test.detail = Some(format!("{} 30s", oj_core::SHELL_TIMEOUT_REASON));
+    let mut package = make_step("package", StepOutcomeKind::Running, 31000, None);
+    package.timeout = Some("5m".into());
+
+    let mut buf = Vec::new();
+    format_steps(&mut buf, &[test, package]);
+    let output = output_string(&buf);
+    let lines: Vec<&str> = output.lines().collect();
+    assert!(lines[0].ends_with("timed out (after 30s)"), "{output}");
+    assert!(lines[1].ends_with("running (timeout 5m)"), "{output}");
+}
@@ -9,6 +9,7 @@ pub mod daemon;
 pub mod decision;
 pub mod emit;
 pub mod env;
+pub mod git_trigger;
 pub mod job;
 mod job_wait;
 pub mod project;
@@ -18,5 +19,6 @@ pub mod run;
 pub mod runbook;
 pub mod session;
 pub mod status;
+pub mod watch;
 pub mod worker;
 pub mod workspace;
@@ -9,7 +9,7 @@ use std::path::Path;
 
 use oj_core::ShortId;
 
-use crate::client::{ClientKind, DaemonClient, QueuePushResult, QueueRetryResult};
+use crate::client::{ClientKind, DaemonClient, PushOptions, QueuePushResult, QueueRetryResult};
 use crate::color;
 use crate::output::{display_log, format_time_ago, print_prune_results, OutputFormat};
 use crate::table::{project_cell, should_show_project, Column, Table};
@@ -31,6 +31,23 @@ pub enum QueueCommand {
         /// Item variables (can be repeated: --var key=value)
         #[arg(long = "var", value_parser = super::job::parse_key_value)]
         var: Vec<(String, String)>,
+        /// Dispatch priority (higher is taken first; overrides the queue's `priority`)
+        #[arg(long, allow_negative_numbers = true)]
+        priority: Option<i32>,
+        /// Keep the item hidden from workers for this long (e.g. "30m", "2h")
+        #[arg(long, conflicts_with = "at")]
+        delay: Option<String>,
+        /// Keep the item hidden from workers until this time
+        /// (RFC 3339, or "YYYY-MM-DD HH:MM" in local time)
+        #[arg(long)]
+        at: Option<String>,
+        /// Deduplication key (overrides the queue's `dedup_key`)
+        #[arg(long)]
+        dedup_key: Option<String>,
+        /// Expire the item if no worker takes it within this long
+        /// (e.g. "7d"; overrides the queue's `ttl`)
+        #[arg(long)]
+        ttl: Option<String>,
     },
     /// List all known queues
     List {},
@@ -38,6 +55,9 @@ pub enum QueueCommand {
     Show {
         /// Queue name
         queue: String,
+        /// Show only expired items (hidden by default)
+        #[arg(long)]
+        expired: bool,
     },
     /// Remove an item from a persisted queue
     Drop {
@@ -46,6 +66,16 @@ pub enum QueueCommand {
         /// Item ID (or prefix)
         item_id: String,
     },
+    /// Change a pending item's dispatch priority
+    Edit {
+        /// Queue name
+        queue: String,
+        /// Item ID (or prefix)
+        item_id: String,
+        /// New priority (higher is taken first)
+        #[arg(long, allow_negative_numbers = true)]
+        priority: i32,
+    },
     /// View queue activity log
     Logs {
         /// Queue name
@@ -112,6 +142,12 @@ impl QueueCommand {
     }
 }
 
+/// Sort items the way workers take them: highest priority first, oldest
+/// first within a priority.
+fn sort_dispatch_order(items: &mut [oj_daemon::QueueItemSummary]) {
+    items.sort_by_key(|item| (std::cmp::Reverse(item.priority), item.pushed_at_epoch_ms));
+}
+
 /// Format a queue item's data map as a sorted `key=value` string.
 fn format_item_data(data: &std::collections::HashMap<String, String>) -> String {
     let mut pairs: Vec<_> = data.iter().collect();
@@ -123,6 +159,61 @@ fn format_item_data(data: &std::collections::HashMap<String, String>) -> String
         .join(" ")
 }
 
+/// Status cell for `oj queue show`; delayed items say when they're due.
+fn format_item_status(item: &oj_daemon::QueueItemSummary) -> String {
+    let now_ms = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64;
+    let secs_until = |at: u64| oj_core::format_elapsed(at.saturating_sub(now_ms).div_ceil(1000));
+    match (item.visible_after_epoch_ms, item.lease_expires_at_epoch_ms) {
+        (Some(at), _) if item.status == "scheduled" => format!("scheduled (in {})", secs_until(at)),
+        (_, Some(at)) if item.status == "active" => format!("active (lease {})", secs_until(at)),
+        _ => item.status.clone(),
+    }
+}
+
+/// Parse an `--at` timestamp into epoch ms: RFC 3339, or a local wall-clock
+/// time without an offset.
+fn parse_at(s: &str) -> Result<u64> {
+    use chrono::{DateTime, Local, NaiveDateTime, TimeZone};
+
+    if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
+        return Ok(dt.timestamp_millis().max(0) as u64);
+    }
+    let naive = [
+        "%Y-%m-%d %H:%M:%S",
+        "%Y-%m-%d %H:%M",
+        "%Y-%m-%dT%H:%M:%S",
+        "%Y-%m-%dT%H:%M",
+    ]
+    .iter()
+    .find_map(|fmt| NaiveDateTime::parse_from_str(s, fmt).ok())
+    .ok_or_else(|| {
+        anyhow::anyhow!(
+            "invalid time '{}': expected RFC 3339 or \"YYYY-MM-DD HH:MM\"",
+            s
+        )
+    })?;
+    let local = Local
+        .from_local_datetime(&naive)
+        .earliest()
+        .ok_or_else(|| anyhow::anyhow!("time '{}' doesn't exist in the local timezone", s))?;
+    Ok(local.timestamp_millis().max(0) as u64)
+}
+
+/// Resolve `--delay` / `--at` into the epoch ms an item becomes visible.
+fn visible_after(delay: Option<&str>, at: Option<&str>, now_ms: u64) -> Result<Option<u64>> {
+    match (delay, at) {
+        (Some(delay), _) => {
+            let delay = super::job::parse_duration(delay)?;
+            Ok(Some(now_ms + delay.as_millis() as u64))
+        }
+        (None, Some(at)) => parse_at(at).map(Some),
+        (None, None) => Ok(None),
+    }
+}
+
 /// Build a JSON object from optional JSON string and --var key=value pairs.
 fn build_data_map(data: Option<String>, var: Vec<(String, String)>) -> Result<serde_json::Value> {
     // Start with JSON data if provided
@@ -158,24 +249,64 @@ pub async fn handle(
     format: OutputFormat,
 ) -> Result<()> {
     match command {
-        QueueCommand::Push { queue, data, var } => {
+        QueueCommand::Push {
+            queue,
+            data,
+            var,
+            priority,
+            delay,
+            at,
+            dedup_key,
+            ttl,
+        } => {
             // Build data map; allow empty data for external queues (triggers poll)
             let json_data = if data.is_none() && var.is_empty() {
                 serde_json::Value::Object(serde_json::Map::new())
             } else {
                 build_data_map(data, var)?
             };
+            let now_ms = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis() as u64;
+            let visible_after_epoch_ms = visible_after(delay.as_deref(), at.as_deref(), now_ms)?;
 
             match client
-                .queue_push(project_root, namespace, &queue, json_data)
+                .queue_push(
+                    project_root,
+                    namespace,
+                    &queue,
+                    json_data,
+                    PushOptions {
+                        priority,
+                        visible_after_epoch_ms,
+                        dedup_key,
+                        ttl,
+                    },
+                )
                 .await?
             {
                 QueuePushResult::Pushed {
                     queue_name,
                     item_id,
-                } => {
-                    println!("Pushed item '{}' to queue '{}'", item_id, queue_name);
-                }
+                    deduplicated: true,
+                } => println!(
+                    "Item '{}' already in queue '{}' (deduplicated)",
+                    item_id, queue_name
+                ),
+                QueuePushResult::Pushed {
+                    queue_name,
+                    item_id,
+                    deduplicated: false,
+                } => match visible_after_epoch_ms.filter(|at| *at > now_ms) {
+                    Some(at) => println!(
+                        "Pushed item '{}' to queue '{}' (visible in {})",
+                        item_id,
+                        queue_name,
+                        oj_core::format_elapsed((at - now_ms).div_ceil(1000))
+                    ),
+                    None => println!("Pushed item '{}' to queue '{}'", item_id, queue_name),
+                },
                 QueuePushResult::Refreshed => {
                     println!("Refreshed external queue '{}'", queue);
                 }
@@ -191,6 +322,21 @@ pub async fn handle(
                 queue_name
             );
         }
+        QueueCommand::Edit {
+            queue,
+            item_id,
+            priority,
+        } => {
+            let (queue_name, item_id) = client
+                .queue_edit(project_root, namespace, &queue, &item_id, priority)
+                .await?;
+            println!(
+                "Set priority of item {} in queue {} to {}",
+                item_id.short(8),
+                queue_name,
+                priority
+            );
+        }
         QueueCommand::Retry {
             queue,
             item_ids,
@@ -374,13 +520,18 @@ pub async fn handle(
                 },
             )?;
         }
-        QueueCommand::Show { queue } => {
+        QueueCommand::Show { queue, expired } => {
             let mut items = client
                 .list_queue_items(&queue, namespace, Some(project_root))
                 .await?;
-            items.sort_by(|a, b| b.pushed_at_epoch_ms.cmp(&a.pushed_at_epoch_ms));
+            items.retain(|item| (item.status == "expired") == expired);
+            sort_dispatch_order(&mut items);
             if items.is_empty() {
-                println!("No items in queue '{}'", queue);
+                if expired {
+                    println!("No expired items in queue '{}'", queue);
+                } else {
+                    println!("No items in queue '{}'", queue);
+                }
                 return Ok(());
             }
             match format {
@@ -391,6 +542,7 @@ pub async fn handle(
                     let mut table = Table::new(vec![
                         Column::muted("ID"),
                         Column::status("STATUS"),
+                        Column::right("PRI"),
                         Column::right("AGE"),
                         Column::left("WORKER"),
                         Column::left("DATA"),
@@ -401,7 +553,8 @@ pub async fn handle(
                         let age = format_time_ago(item.pushed_at_epoch_ms);
                         table.row(vec![
                             item.id.short(8).to_string(),
-                            item.status.clone(),
+                            format_item_status(item),
+                            item.priority.to_string(),
                             age,
                             worker,
                             data_str,
@@ -2,7 +2,7 @@
 // Copyright (c) 2026 Alfred Jean LLC
 
 use super::super::job::parse_key_value;
-use super::{build_data_map, format_item_data};
+use super::{build_data_map, format_item_data, parse_at, sort_dispatch_order, visible_after};
 use serde_json::json;
 use std::collections::HashMap;
 
@@ -125,3 +125,58 @@ fn format_item_data_empty() {
     let data = HashMap::new();
     assert_eq!(format_item_data(&data), "");
 }
+
+#[test]
+fn visible_after_from_delay() {
+    let at = visible_after(Some("30m"), None, 1_000).unwrap();
+    assert_eq!(at, Some(1_000 + 30 * 60 * 1000));
+}
+
+#[test]
+fn visible_after_from_rfc3339_time() {
+    let at = visible_after(None, Some("2026-03-04T12:00:00+01:00"), 0).unwrap();
+    assert_eq!(at, Some(1_772_622_000_000));
+}
+
+#[test]
+fn visible_after_defaults_to_none() {
+    assert_eq!(visible_after(None, None, 1_000).unwrap(), None);
+}
+
+#[test]
+fn parse_at_accepts_local_wall_clock() {
+    assert!(parse_at("2026-03-04 12:00").is_ok());
+    assert!(parse_at("2026-03-04T12:00:30").is_ok());
+}
+
+#[test]
+fn parse_at_rejects_garbage() {
+    let err = parse_at("tomorrow").unwrap_err().to_string();
+    assert!(err.contains("invalid time 'tomorrow'"), "{err}");
+}
+
+#[test]
+fn sort_dispatch_order_is_priority_then_oldest() {
+    let item = |id: &str, priority: i32, pushed_at_epoch_ms: u64| oj_daemon::QueueItemSummary {
+        id: id.to_string(),
+        status: "pending".to_string(),
+        data: HashMap::new(),
+        worker_name: None,
+        pushed_at_epoch_ms,
+        failure_count: 0,
+        priority,
+        visible_after_epoch_ms: None,
+        lease_expires_at_epoch_ms: None,
+    };
+    let mut items = vec![
+        item("low", 0, 1_000),
+        item("high-new", 5, 3_000),
+        item("high-old", 5, 2_000),
+        item("low-old", 0, 500),
+    ];
+
+    sort_dispatch_order(&mut items);
+
+    let ids: Vec<&str> = items.iter().map(|item| item.id.as_str()).collect();
+    assert_eq!(ids, ["high-old", "high-new", "low-old", "low"]);
+}
@@ -16,6 +16,7 @@ fn job(id: &str, name: &str) -> JobSummary {
         updated_at_ms: 0,
         namespace: String::new(),
         retry_count: 0,
+        blocked_on: vec![],
     }
 }
 
@@ -13,7 +13,7 @@ use clap::Args;
 use oj_core::ShortId;
 use oj_runbook::RunDirective;
 
-use crate::client::DaemonClient;
+use crate::client::{DaemonClient, RunCommandParams};
 use crate::color;
 
 #[derive(Args)]
@@ -36,6 +36,15 @@ pub struct RunArgs {
     /// Do not attach to the agent's tmux session
     #[arg(long = "no-attach", conflicts_with = "attach")]
     pub no_attach: bool,
+
+    /// Hold the job until another job finishes (repeatable).
+    /// `:done` (default) needs it to succeed; `:any` accepts any outcome.
+    #[arg(long = "after", value_name = "JOB[:done|:any]")]
+    pub after: Vec<String>,
+
+    /// Admission priority when running-job limits queue the job (higher first)
+    #[arg(long = "priority", allow_negative_numbers = true)]
+    pub priority: Option<i32>,
 }
 
 /// Quick validation that a command exists in the runbook.
@@ -128,9 +137,13 @@ pub async fn handle(
         return print_available_commands(project_root);
     };
 
-    // Extract --attach/--no-attach from trailing args (since trailing_var_arg
-    // is greedy, these flags may end up in args.args rather than clap fields)
-    let mut raw_args = args.args.clone();
+    // Extract --attach/--no-attach/--after/--priority from trailing args
+    // (since trailing_var_arg is greedy, these flags may end up in args.args
+    // rather than clap fields)
+    let (mut raw_args, trailing) = extract_job_flags(&args.args)?;
+    let mut after = args.after.clone();
+    after.extend(trailing.after);
+    let priority = trailing.priority.or(args.priority).unwrap_or(0);
     let mut cli_attach = None;
     raw_args.retain(|a| {
         if a == "--attach" {
@@ -179,14 +192,16 @@ pub async fn handle(
     // Job or Agent: dispatch to daemon
     let client = DaemonClient::for_action()?;
     let result = client
-        .run_command(
+        .run_command(RunCommandParams {
             project_root,
             invoke_dir,
             namespace,
             command,
-            &positional,
-            &named,
-        )
+            args: &positional,
+            named_args: &named,
+            after: &after,
+            priority,
+        })
         .await?;
 
     match result {
@@ -210,6 +225,43 @@ pub async fn handle(
     }
 }
 
+/// Job flags found among the trailing args.
+#[derive(Debug, Default, PartialEq)]
+struct TrailingJobFlags {
+    after: Vec<String>,
+    priority: Option<i32>,
+}
+
+/// Split `--after <job>` and `--priority <n>` (or their `--flag=value`
+/// forms) out of the trailing args.
+fn extract_job_flags(args: &[String]) -> Result<(Vec<String>, TrailingJobFlags)> {
+    let mut rest = Vec::new();
+    let mut flags = TrailingJobFlags::default();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let (name, inline) = match arg.split_once('=') {
+            Some((name, value)) => (name, Some(value.to_string())),
+            None => (arg.as_str(), None),
+        };
+        if name != "--after" && name != "--priority" {
+            rest.push(arg.clone());
+            continue;
+        }
+        let Some(value) = inline.or_else(|| iter.next().cloned()) else {
+            bail!("{} requires a value", name);
+        };
+        if name == "--after" {
+            flags.after.push(value);
+        } else {
+            let priority = value.parse().map_err(|_| {
+                anyhow::anyhow!("invalid --priority '{}': expected an integer", value)
+            })?;
+            flags.priority = Some(priority);
+        }
+    }
+    Ok((rest, flags))
+}
+
 fn execute_shell_inline(
     cmd: &str,
     cmd_def: &oj_runbook::CommandDef,
@@ -273,6 +325,8 @@ async fn dispatch_job(
         Some(crate::client::run_wait_timeout()),
     );
     let mut started = false;
+    let mut blocked = false;
+    let mut queue_position = None;
 
     loop {
         match poller.tick().await {
@@ -280,6 +334,14 @@ async fn dispatch_job(
             _ => break,
         }
         if let Ok(Some(p)) = client.get_job(job_id).await {
+            if p.step_status == oj_core::StepStatusKind::Blocked && !p.blocked_on.is_empty() {
+                blocked = true;
+                break;
+            }
+            if p.queue_position.is_some() {
+                queue_position = p.queue_position;
+                break;
+            }
             if p.step_status != oj_core::StepStatusKind::Pending {
                 started = true;
                 break;
@@ -287,7 +349,25 @@ async fn dispatch_job(
         }
     }
 
-    if started {
+    if let Some(position) = queue_position {
+        println!(
+            "{} {} {}",
+            color::yellow("Queued"),
+            job_name,
+            color::muted(&format!(
+                "(job: {short_id}, position {position}, waiting for a free slot)"
+            ))
+        );
+    } else if blocked {
+        println!(
+            "{} {} {}",
+            color::yellow("Blocked"),
+            job_name,
+            color::muted(&format!(
+                "(job: {short_id}, waiting for other jobs to finish)"
+            ))
+        );
+    } else if started {
         println!(
             "{} {} {}",
             color::green("Started"),
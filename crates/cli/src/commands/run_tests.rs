@@ -6,7 +6,7 @@ use std::path::PathBuf;
 
 use oj_runbook::{ArgSpec, CommandDef, RunDirective};
 
-use super::{execute_shell_inline, format_available_commands};
+use super::{execute_shell_inline, extract_job_flags, format_available_commands};
 use crate::color::HelpPrinter;
 
 fn make_shell_command(name: &str, run: &str) -> CommandDef {
@@ -259,6 +259,7 @@ fn directive_is_shell_for_shell_commands() {
 fn directive_is_job_for_job_commands() {
     let directive = RunDirective::Job {
         job: "build".to_string(),
+        vars: HashMap::new(),
     };
     assert!(!directive.is_shell());
     assert!(directive.is_job());
@@ -435,3 +436,33 @@ fn attach_override_precedence_runbook_over_default() {
     let should_attach = cli_override.or(runbook_attach).unwrap_or(false);
     assert!(!should_attach);
 }
+
+fn strings(args: &[&str]) -> Vec<String> {
+    args.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn extract_job_flags_pulls_both_forms_from_trailing_args() {
+    let args = strings(&[
+        "feat",
+        "--after",
+        "abc123",
+        "--after=def456:any",
+        "--priority",
+        "-2",
+        "extra",
+    ]);
+    let (rest, flags) = extract_job_flags(&args).unwrap();
+    assert_eq!(rest, vec!["feat", "extra"]);
+    assert_eq!(flags.after, vec!["abc123", "def456:any"]);
+    assert_eq!(flags.priority, Some(-2));
+
+    let (_, flags) = extract_job_flags(&strings(&["--priority=5"])).unwrap();
+    assert_eq!(flags.priority, Some(5));
+}
+
+#[test]
+fn extract_job_flags_rejects_missing_or_bad_values() {
+    assert!(extract_job_flags(&strings(&["--after"])).is_err());
+    assert!(extract_job_flags(&strings(&["--priority", "high"])).is_err());
+}
@@ -233,6 +233,7 @@ fn format_text(
     let uptime = format_duration(uptime_secs);
     let total_active: usize = namespaces.iter().map(|ns| ns.active_jobs.len()).sum();
     let total_escalated: usize = namespaces.iter().map(|ns| ns.escalated_jobs.len()).sum();
+    let total_queued: usize = namespaces.iter().map(|ns| ns.queued_jobs.len()).sum();
 
     let _ = write!(
         out,
@@ -252,6 +253,9 @@ fn format_text(
             if total_active == 1 { "" } else { "s" }
         );
     }
+    if total_queued > 0 {
+        let _ = write!(out, " | {} queued", total_queued);
+    }
     if total_escalated > 0 {
         let _ = write!(out, " | {} {}", total_escalated, color::status("escalated"));
     }
@@ -313,6 +317,7 @@ fn format_text(
             .iter()
             .any(|q| q.pending > 0 || q.active > 0 || q.dead > 0);
         let has_content = !ns.active_jobs.is_empty()
+            || !ns.queued_jobs.is_empty()
             || !ns.escalated_jobs.is_empty()
             || !ns.orphaned_jobs.is_empty()
             || !ns.workers.is_empty()
@@ -334,14 +339,14 @@ fn format_text(
 
         // Sort jobs by most recent activity (descending) and workers alphabetically
         let mut active_jobs: Vec<&oj_daemon::JobStatusEntry> = ns.active_jobs.iter().collect();
-        active_jobs.sort_by(|a, b| b.last_activity_ms.cmp(&a.last_activity_ms));
+        active_jobs.sort_by_key(|b| std::cmp::Reverse(b.last_activity_ms));
 
         let mut escalated_jobs: Vec<&oj_daemon::JobStatusEntry> =
             ns.escalated_jobs.iter().collect();
-        escalated_jobs.sort_by(|a, b| b.last_activity_ms.cmp(&a.last_activity_ms));
+        escalated_jobs.sort_by_key(|b| std::cmp::Reverse(b.last_activity_ms));
 
         let mut orphaned_jobs: Vec<&oj_daemon::JobStatusEntry> = ns.orphaned_jobs.iter().collect();
-        orphaned_jobs.sort_by(|a, b| b.last_activity_ms.cmp(&a.last_activity_ms));
+        orphaned_jobs.sort_by_key(|b| std::cmp::Reverse(b.last_activity_ms));
 
         let mut workers: Vec<&oj_daemon::WorkerSummary> = ns.workers.iter().collect();
         workers.sort_by(|a, b| a.name.cmp(&b.name));
@@ -369,6 +374,46 @@ fn format_text(
             out.push('\n');
         }
 
+        // Queued jobs, in admission order
+        if !ns.queued_jobs.is_empty() {
+            let _ = writeln!(
+                out,
+                "  {}",
+                color::header(&format!("Queued ({}):", ns.queued_jobs.len()))
+            );
+            let rows: Vec<JobRow> = ns
+                .queued_jobs
+                .iter()
+                .map(|p| {
+                    let position = p
+                        .queue_position
+                        .map(|n| format!("#{}  ", n))
+                        .unwrap_or_default();
+                    let priority = if p.priority != 0 {
+                        format!("priority {}  ", p.priority)
+                    } else {
+                        String::new()
+                    };
+                    JobRow {
+                        prefix: "    ".to_string(),
+                        id: p.id.short(8).to_string(),
+                        name: friendly_name_label(&p.name, &p.kind, &p.id),
+                        kind_step: format!("{}/{}", p.kind, p.step),
+                        status: "queued".to_string(),
+                        suffix: format!(
+                            "{}{}{}",
+                            position,
+                            priority,
+                            format_duration_ms(p.elapsed_ms)
+                        ),
+                        reason: None,
+                    }
+                })
+                .collect();
+            write_aligned_job_rows(&mut out, &rows);
+            out.push('\n');
+        }
+
         // Escalated jobs
         if !escalated_jobs.is_empty() {
             let _ = writeln!(
@@ -224,6 +224,9 @@ fn non_tty_frame_with_full_status_has_no_ansi_escapes() {
         status: "running".to_string(),
         active: 1,
         concurrency: 4,
+        rate: None,
+        next_dispatch_at_ms: None,
+        sources: vec![],
         updated_at_ms: 0,
     });
     ns.queues.push(oj_daemon::QueueStatus {
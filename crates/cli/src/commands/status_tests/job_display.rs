@@ -226,3 +226,30 @@ fn escalated_job_truncates_long_reason() {
         "output should contain truncation indicator '...':\n{output}"
     );
 }
+
+// ── admission queue ─────────────────────────────────────────────────
+
+#[test]
+#[serial]
+fn queued_jobs_show_position_and_priority() {
+    setup_no_color();
+
+    let mut first = job_entry("aaaa1111-0000", "deploy", "ship");
+    first.step_status = StepStatusKind::Blocked;
+    first.queue_position = Some(1);
+    first.priority = 5;
+    let mut second = job_entry("bbbb2222-0000", "build", "compile");
+    second.step_status = StepStatusKind::Blocked;
+    second.queue_position = Some(3);
+    let mut ns = empty_ns("myproject");
+    ns.queued_jobs = vec![first, second];
+
+    let output = format_text(30, &[ns], None, None);
+
+    assert!(output.contains("| 2 queued"), "header:\n{output}");
+    assert!(output.contains("Queued (2):"), "section:\n{output}");
+    let first_line = output.lines().find(|l| l.contains("aaaa1111")).unwrap();
+    assert!(first_line.contains("#1  priority 5"), "{first_line}");
+    let second_line = output.lines().find(|l| l.contains("bbbb2222")).unwrap();
+    assert!(second_line.contains("queued  #3  1m"), "{second_line}");
+}
@@ -134,6 +134,9 @@ fn worker_columns_are_aligned_across_rows() {
         status: "running".to_string(),
         active: 1,
         concurrency: 4,
+        rate: None,
+        next_dispatch_at_ms: None,
+        sources: vec![],
         updated_at_ms: 0,
     });
     ns.workers.push(oj_daemon::WorkerSummary {
@@ -143,6 +146,9 @@ fn worker_columns_are_aligned_across_rows() {
         status: "stopped".to_string(),
         active: 0,
         concurrency: 2,
+        rate: None,
+        next_dispatch_at_ms: None,
+        sources: vec![],
         updated_at_ms: 0,
     });
 
@@ -173,6 +179,9 @@ fn worker_shows_full_at_max_concurrency() {
         status: "running".to_string(),
         active: 3,
         concurrency: 3,
+        rate: None,
+        next_dispatch_at_ms: None,
+        sources: vec![],
         updated_at_ms: 0,
     });
 
@@ -325,6 +334,9 @@ fn workers_sorted_alphabetically() {
             status: if active > 0 { "running" } else { "idle" }.to_string(),
             active,
             concurrency: 2,
+            rate: None,
+            next_dispatch_at_ms: None,
+            sources: vec![],
             updated_at_ms: 0,
         });
     }
@@ -36,6 +36,8 @@ pub(super) fn job_entry(id: &str, kind: &str, step: &str) -> oj_daemon::JobStatu
         last_activity_ms: 0,
         waiting_reason: None,
         escalate_source: None,
+        queue_position: None,
+        priority: 0,
     }
 }
 
@@ -45,6 +47,7 @@ pub(super) fn empty_ns(name: &str) -> NamespaceStatus {
         namespace: name.to_string(),
         active_jobs: vec![],
         escalated_jobs: vec![],
+        queued_jobs: vec![],
         orphaned_jobs: vec![],
         workers: vec![],
         queues: vec![],
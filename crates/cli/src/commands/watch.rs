@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Watch command handlers
+
+use anyhow::Result;
+use clap::{Args, Subcommand};
+
+use crate::client::{ClientKind, DaemonClient};
+use crate::output::{print_start_results, OutputFormat};
+use crate::table::{project_cell, should_show_project, Column, Table};
+
+#[derive(Args)]
+pub struct WatchArgs {
+    #[command(subcommand)]
+    pub command: WatchCommand,
+}
+
+#[derive(Subcommand)]
+pub enum WatchCommand {
+    /// List all file watches and their status
+    List {},
+    /// Start a watch (runs its job when matching files change)
+    Start {
+        /// Watch name from runbook (required unless --all)
+        name: Option<String>,
+        /// Start all watches defined in runbooks
+        #[arg(long)]
+        all: bool,
+    },
+    /// Stop a watch
+    Stop {
+        /// Watch name from runbook
+        name: String,
+    },
+}
+
+impl WatchCommand {
+    pub fn client_kind(&self) -> ClientKind {
+        match self {
+            Self::List {} => ClientKind::Query,
+            _ => ClientKind::Action,
+        }
+    }
+}
+
+pub async fn handle(
+    command: WatchCommand,
+    client: &DaemonClient,
+    project_root: &std::path::Path,
+    namespace: &str,
+    project_filter: Option<&str>,
+    format: OutputFormat,
+) -> Result<()> {
+    match command {
+        WatchCommand::Start { name, all } => {
+            if !all && name.is_none() {
+                anyhow::bail!("watch name required (or use --all)");
+            }
+            let watch_name = name.unwrap_or_default();
+            let result = client
+                .watch_start(project_root, namespace, &watch_name, all)
+                .await?;
+            print_start_results(&result, "Watch", "watches", namespace);
+        }
+        WatchCommand::Stop { name } => {
+            client
+                .watch_stop(&name, namespace, Some(project_root))
+                .await?;
+            println!("Watch '{}' stopped ({})", name, namespace);
+        }
+        WatchCommand::List {} => {
+            let mut watches = client.list_watches().await?;
+
+            // Filter by explicit --project flag (OJ_NAMESPACE is NOT used for filtering)
+            if let Some(proj) = project_filter {
+                watches.retain(|w| w.namespace == proj);
+            }
+            watches.sort_by(|a, b| a.name.cmp(&b.name));
+            match format {
+                OutputFormat::Json => {
+                    println!("{}", serde_json::to_string_pretty(&watches)?);
+                }
+                OutputFormat::Text => {
+                    if watches.is_empty() {
+                        println!("No watches found");
+                    } else {
+                        let show_project =
+                            should_show_project(watches.iter().map(|w| w.namespace.as_str()));
+
+                        let mut cols = vec![Column::left("KIND")];
+                        if show_project {
+                            cols.push(Column::left("PROJECT"));
+                        }
+                        cols.extend([
+                            Column::left("PATHS"),
+                            Column::left("DEBOUNCE"),
+                            Column::left("JOB"),
+                            Column::left("FIRED"),
+                            Column::status("STATUS"),
+                        ]);
+                        let mut table = Table::new(cols);
+
+                        for w in &watches {
+                            let mut cells = vec![w.name.clone()];
+                            if show_project {
+                                cells.push(project_cell(&w.namespace));
+                            }
+                            cells.extend([
+                                w.paths.join(" "),
+                                w.debounce.clone(),
+                                w.job.clone(),
+                                w.last_fired.clone(),
+                                w.status.clone(),
+                            ]);
+                            table.row(cells);
+                        }
+                        table.render(&mut std::io::stdout());
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
@@ -8,7 +8,9 @@ use clap::{Args, Subcommand};
 
 use crate::client::{ClientKind, DaemonClient};
 use crate::color;
-use crate::output::{display_log, print_prune_results, print_start_results, OutputFormat};
+use crate::output::{
+    display_log, format_time_until, print_prune_results, print_start_results, OutputFormat,
+};
 use crate::table::{project_cell, should_show_project, Column, Table};
 
 #[derive(Args)]
@@ -37,12 +39,15 @@ pub enum WorkerCommand {
         /// Worker name from runbook
         name: String,
     },
-    /// Resize a worker's concurrency limit at runtime
+    /// Resize a worker's concurrency limit or dispatch rate at runtime
     Resize {
         /// Worker name from runbook
         name: String,
         /// New concurrency limit (must be > 0)
-        concurrency: u32,
+        concurrency: Option<u32>,
+        /// New dispatch rate limit (e.g. "10/h"), or "none" to remove it
+        #[arg(long)]
+        rate: Option<String>,
     },
     /// View worker activity log
     Logs {
@@ -113,19 +118,39 @@ pub async fn handle(
                 .await?;
             println!("Worker '{}' restarted", color::header(&worker_name));
         }
-        WorkerCommand::Resize { name, concurrency } => {
-            if concurrency == 0 {
+        WorkerCommand::Resize {
+            name,
+            concurrency,
+            rate,
+        } => {
+            if concurrency == Some(0) {
                 anyhow::bail!("concurrency must be at least 1");
             }
-            let (worker_name, old, new) =
-                client.worker_resize(&name, namespace, concurrency).await?;
-            println!(
-                "Worker '{}' resized: {} → {} ({})",
-                color::header(&worker_name),
-                old,
-                new,
-                color::muted(namespace)
-            );
+            if concurrency.is_none() && rate.is_none() {
+                anyhow::bail!("nothing to resize: give a concurrency, --rate, or both");
+            }
+            let result = client
+                .worker_resize(&name, namespace, concurrency, rate.as_deref())
+                .await?;
+            if concurrency.is_some() {
+                println!(
+                    "Worker '{}' resized: {} → {} ({})",
+                    color::header(&result.worker_name),
+                    result.old_concurrency,
+                    result.new_concurrency,
+                    color::muted(namespace)
+                );
+            }
+            if rate.is_some() {
+                let show = |rate: Option<String>| rate.unwrap_or_else(|| "none".to_string());
+                println!(
+                    "Worker '{}' rate: {} → {} ({})",
+                    color::header(&result.worker_name),
+                    show(result.old_rate),
+                    show(result.new_rate),
+                    color::muted(namespace)
+                );
+            }
         }
         WorkerCommand::Logs {
             name,
@@ -144,7 +169,7 @@ pub async fn handle(
             if let Some(proj) = project_filter {
                 workers.retain(|w| w.namespace == proj);
             }
-            workers.sort_by(|a, b| b.updated_at_ms.cmp(&a.updated_at_ms));
+            workers.sort_by_key(|b| std::cmp::Reverse(b.updated_at_ms));
             match format {
                 OutputFormat::Json => {
                     println!("{}", serde_json::to_string_pretty(&workers)?);
@@ -155,6 +180,8 @@ pub async fn handle(
                     } else {
                         let show_project =
                             should_show_project(workers.iter().map(|w| w.namespace.as_str()));
+                        let show_rate = workers.iter().any(|w| w.rate.is_some());
+                        let show_sources = workers.iter().any(|w| !w.sources.is_empty());
 
                         let mut cols = vec![Column::left("KIND")];
                         if show_project {
@@ -166,6 +193,12 @@ pub async fn handle(
                             Column::left("ACTIVE"),
                             Column::left("CONCURRENCY"),
                         ]);
+                        if show_rate {
+                            cols.extend([Column::left("RATE"), Column::left("NEXT")]);
+                        }
+                        if show_sources {
+                            cols.push(Column::left("SOURCES"));
+                        }
                         let mut table = Table::new(cols);
 
                         for w in &workers {
@@ -179,6 +212,17 @@ pub async fn handle(
                                 w.active.to_string(),
                                 w.concurrency.to_string(),
                             ]);
+                            if show_rate {
+                                cells.push(w.rate.clone().unwrap_or_else(|| "-".to_string()));
+                                cells.push(match (&w.rate, w.next_dispatch_at_ms) {
+                                    (Some(_), Some(at_ms)) => format_time_until(at_ms),
+                                    (Some(_), None) if w.status == "running" => "now".to_string(),
+                                    _ => "-".to_string(),
+                                });
+                            }
+                            if show_sources {
+                                cells.push(format_sources(&w.sources));
+                            }
                             table.row(cells);
                         }
                         table.render(&mut std::io::stdout());
@@ -210,3 +254,15 @@ pub async fn handle(
     }
     Ok(())
 }
+
+/// Per-queue counts of a weighted worker, e.g. `bugs 2 active/5 pending`.
+fn format_sources(sources: &[oj_daemon::WorkerSourceSummary]) -> String {
+    if sources.is_empty() {
+        return "-".to_string();
+    }
+    sources
+        .iter()
+        .map(|s| format!("{} {} active/{} pending", s.queue, s.active, s.pending))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
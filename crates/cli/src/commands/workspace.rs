@@ -86,7 +86,7 @@ pub async fn handle(
             }
 
             // Sort by recency (most recent first)
-            workspaces.sort_by(|a, b| b.created_at_ms.cmp(&a.created_at_ms));
+            workspaces.sort_by_key(|b| std::cmp::Reverse(b.created_at_ms));
 
             // Limit
             let total = workspaces.len();
@@ -57,6 +57,8 @@ Resources:
   queue       Queue management
   worker      Worker management
   cron        Cron management
+  watch       File watch management
+  git-trigger  Git ref trigger management
   decision    Decision management
   project     Project management
   runbook     Runbook management
@@ -54,6 +54,8 @@ fn all_commands_assigned_to_section() {
             Commands::Queue(_) => "Resources",
             Commands::Worker(_) => "Resources",
             Commands::Cron(_) => "Resources",
+            Commands::Watch(_) => "Resources",
+            Commands::GitTrigger(_) => "Resources",
             Commands::Decision(_) => "Resources",
             Commands::Project(_) => "Resources",
             Commands::Runbook(_) => "Resources",
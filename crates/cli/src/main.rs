@@ -3,6 +3,11 @@
 
 //! oj - Odd Jobs CLI
 
+// Allow panic!/unwrap/expect in test code
+#![cfg_attr(test, allow(clippy::panic))]
+#![cfg_attr(test, allow(clippy::unwrap_used))]
+#![cfg_attr(test, allow(clippy::expect_used))]
+
 mod client;
 mod client_lifecycle;
 mod color;
@@ -20,8 +25,8 @@ use output::OutputFormat;
 use anyhow::Result;
 use clap::{CommandFactory, FromArgMatches, Parser, Subcommand};
 use commands::{
-    agent, cron, daemon, decision, emit, env as env_cmd, job, project, queue, resolve, run,
-    runbook, session, status, worker, workspace,
+    agent, cron, daemon, decision, emit, env as env_cmd, git_trigger, job, project, queue, resolve,
+    run, runbook, session, status, watch, worker, workspace,
 };
 use std::path::{Path, PathBuf};
 
@@ -79,6 +84,10 @@ enum Commands {
     Worker(worker::WorkerArgs),
     /// Cron management
     Cron(cron::CronArgs),
+    /// File watch management
+    Watch(watch::WatchArgs),
+    /// Git ref trigger management
+    GitTrigger(git_trigger::GitTriggerArgs),
     /// Decision management
     Decision(decision::DecisionArgs),
     /// Emit events to the daemon (for agents)
@@ -341,6 +350,30 @@ async fn run() -> Result<()> {
             )
             .await?
         }
+        Commands::Watch(args) => {
+            let client = DaemonClient::for_kind(args.command.client_kind())?;
+            watch::handle(
+                args.command,
+                &client,
+                &project_root,
+                &namespace,
+                project_filter,
+                format,
+            )
+            .await?
+        }
+        Commands::GitTrigger(args) => {
+            let client = DaemonClient::for_kind(args.command.client_kind())?;
+            git_trigger::handle(
+                args.command,
+                &client,
+                &project_root,
+                &namespace,
+                project_filter,
+                format,
+            )
+            .await?
+        }
         Commands::Decision(args) => {
             let client = DaemonClient::for_kind(args.command.client_kind())?;
             decision::handle(args.command, &client, &namespace, project_filter, format).await?
@@ -384,7 +417,10 @@ async fn run() -> Result<()> {
             if matches.is_empty() {
                 // No entity match — try as a queue name
                 queue::handle(
-                    queue::QueueCommand::Show { queue: id },
+                    queue::QueueCommand::Show {
+                        queue: id,
+                        expired: false,
+                    },
                     &client,
                     &project_root,
                     &namespace,
@@ -46,6 +46,31 @@ pub fn format_time_ago(epoch_ms: u64) -> String {
     oj_core::format_elapsed(elapsed_secs)
 }
 
+/// Format a future timestamp as a countdown (e.g., "in 5m"), or "now" once
+/// it has passed
+pub fn format_time_until(epoch_ms: u64) -> String {
+    let now_ms = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64;
+    if epoch_ms <= now_ms {
+        return "now".to_string();
+    }
+    format!(
+        "in {}",
+        oj_core::format_elapsed((epoch_ms - now_ms).div_ceil(1000))
+    )
+}
+
+/// Format agent spend as "1234567 tokens (~$3.21)", leaving out the cost when
+/// the model has no known price.
+pub fn format_spend(spend: &oj_daemon::SpendDetail) -> String {
+    match spend.usd {
+        Some(usd) => format!("{} tokens (~${:.2})", spend.tokens, usd),
+        None => format!("{} tokens", spend.tokens),
+    }
+}
+
 /// Print prune results in text or JSON format.
 ///
 /// Handles the dry-run header, per-entry formatting, and summary line that is
@@ -3,7 +3,7 @@
 
 use serde::Serialize;
 
-use super::{print_prune_results, OutputFormat};
+use super::{format_spend, format_time_until, print_prune_results, OutputFormat};
 
 #[derive(Debug, Clone, Serialize)]
 struct FakeEntry {
@@ -71,3 +71,24 @@ fn print_prune_results_text_real_run() {
     );
     assert!(result.is_ok());
 }
+
+#[test]
+fn format_spend_includes_cost_when_known() {
+    let mut spend = oj_daemon::SpendDetail {
+        tokens: 1_234_567,
+        usd: Some(3.214),
+    };
+    assert_eq!(format_spend(&spend), "1234567 tokens (~$3.21)");
+    spend.usd = None;
+    assert_eq!(format_spend(&spend), "1234567 tokens");
+}
+
+#[test]
+fn format_time_until_counts_down_to_now() {
+    let now_ms = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64;
+    assert_eq!(format_time_until(now_ms - 1_000), "now");
+    assert_eq!(format_time_until(now_ms + 5 * 60_000 + 500), "in 5m");
+}
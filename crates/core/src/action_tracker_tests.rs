@@ -76,11 +76,13 @@ fn reset_clears_all() {
 fn clear_agent_signal() {
     use crate::event::AgentSignalKind;
 
-    let mut tracker = ActionTracker::default();
-    tracker.agent_signal = Some(AgentSignal {
-        kind: AgentSignalKind::Complete,
-        message: None,
-    });
+    let mut tracker = ActionTracker {
+        agent_signal: Some(AgentSignal {
+            kind: AgentSignalKind::Complete,
+            message: None,
+        }),
+        ..Default::default()
+    };
     assert!(tracker.agent_signal.is_some());
 
     tracker.clear_agent_signal();
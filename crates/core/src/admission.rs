@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Job admission limits.
+//!
+//! The number of running jobs can be capped daemon-wide (`config.toml` in the
+//! daemon's state directory) and per project (`.oj/config.toml`):
+//!
+//! ```toml
+//! [limits]
+//! max_jobs = 4
+//! ```
+//!
+//! Jobs over a limit wait in an admission queue, ordered by priority and then
+//! creation time.
+
+use crate::job::Job;
+use std::cmp::Reverse;
+use std::path::Path;
+
+/// Read `[limits].max_jobs` from a TOML config file, if set.
+pub fn read_max_jobs(config_path: &Path) -> Option<u32> {
+    let content = std::fs::read_to_string(config_path).ok()?;
+    let table: toml::Table = content.parse().ok()?;
+    let max_jobs = table
+        .get("limits")?
+        .as_table()?
+        .get("max_jobs")?
+        .as_integer()?;
+    u32::try_from(max_jobs).ok()
+}
+
+/// The project limit on running jobs, from `.oj/config.toml`.
+pub fn project_max_jobs(project_root: &Path) -> Option<u32> {
+    read_max_jobs(&project_root.join(".oj/config.toml"))
+}
+
+/// Queued jobs in the order they will be admitted: highest priority first,
+/// then oldest first.
+pub fn admission_order<'a>(jobs: impl IntoIterator<Item = &'a Job>) -> Vec<&'a Job> {
+    let mut queued: Vec<&Job> = jobs
+        .into_iter()
+        .filter(|job| job.queued && !job.is_terminal())
+        .collect();
+    queued.sort_by_key(|job| {
+        let created_at_ms = job.step_history.first().map_or(0, |r| r.started_at_ms);
+        (Reverse(job.priority), created_at_ms, job.id.clone())
+    });
+    queued
+}
+
+#[cfg(test)]
+#[path = "admission_tests.rs"]
+mod tests;
@@ -0,0 +1,65 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use crate::job::{JobBuilder, StepOutcome, StepRecord};
+
+fn queued_job(id: &str, priority: i32, created_at_ms: u64) -> Job {
+    JobBuilder::default()
+        .id(id)
+        .priority(priority)
+        .queued(true)
+        .step_history(vec![StepRecord {
+            name: "execute".to_string(),
+            started_at_ms: created_at_ms,
+            finished_at_ms: None,
+            outcome: StepOutcome::Running,
+            agent_id: None,
+            agent_name: None,
+            job_id: None,
+            branches: Vec::new(),
+        }])
+        .build()
+}
+
+#[yare::parameterized(
+    set      = { "[limits]\nmax_jobs = 3\n", Some(3) },
+    missing  = { "[project]\nname = \"x\"\n", None },
+    negative = { "[limits]\nmax_jobs = -1\n", None },
+    invalid  = { "not valid toml {{{", None },
+)]
+fn reads_max_jobs(content: &str, expected: Option<u32>) {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("config.toml");
+    std::fs::write(&path, content).unwrap();
+    assert_eq!(read_max_jobs(&path), expected);
+}
+
+#[test]
+fn project_max_jobs_reads_project_config() {
+    let dir = tempfile::tempdir().unwrap();
+    assert_eq!(project_max_jobs(dir.path()), None);
+
+    std::fs::create_dir_all(dir.path().join(".oj")).unwrap();
+    std::fs::write(
+        dir.path().join(".oj/config.toml"),
+        "[limits]\nmax_jobs = 2\n",
+    )
+    .unwrap();
+    assert_eq!(project_max_jobs(dir.path()), Some(2));
+}
+
+#[test]
+fn admission_order_is_priority_then_age() {
+    let jobs = [
+        queued_job("old-low", 0, 1_000),
+        queued_job("new-high", 5, 3_000),
+        queued_job("new-low", 0, 2_000),
+        JobBuilder::default().id("running").priority(9).build(),
+    ];
+    let order: Vec<&str> = admission_order(&jobs)
+        .iter()
+        .map(|job| job.id.as_str())
+        .collect();
+    assert_eq!(order, vec!["new-high", "old-low", "new-low"]);
+}
@@ -9,6 +9,7 @@
 //! are needed.
 
 use crate::owner::OwnerId;
+use crate::usage::TokenUsage;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::path::PathBuf;
@@ -40,6 +41,22 @@ pub struct AgentRecord {
     pub created_at_ms: u64,
     /// Epoch milliseconds of last update
     pub updated_at_ms: u64,
+    /// Cumulative token usage from the agent's session log
+    #[serde(default)]
+    pub usage: TokenUsage,
+    /// Model reported by the session log, for cost estimates
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// The agent's `budget` was crossed and `on_budget` has fired
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub budget_exceeded: bool,
+}
+
+impl AgentRecord {
+    /// Estimated cost of this agent's usage, if its model has a known price.
+    pub fn cost_usd(&self) -> Option<f64> {
+        self.usage.cost_usd(self.model.as_deref()?)
+    }
 }
 
 /// Status of an agent in the unified agent record.
@@ -17,6 +17,9 @@ fn agent_record_serde_roundtrip_job_owner() {
         status: AgentRecordStatus::Running,
         created_at_ms: 1_000_000,
         updated_at_ms: 2_000_000,
+        usage: Default::default(),
+        model: None,
+        budget_exceeded: false,
     };
 
     let json = serde_json::to_string(&record).unwrap();
@@ -44,6 +47,9 @@ fn agent_record_serde_roundtrip_agent_run_owner() {
         status: AgentRecordStatus::Starting,
         created_at_ms: 500_000,
         updated_at_ms: 500_000,
+        usage: Default::default(),
+        model: None,
+        budget_exceeded: false,
     };
 
     let json = serde_json::to_string(&record).unwrap();
@@ -71,3 +77,36 @@ fn agent_record_status_variants() {
         assert_eq!(restored, status);
     }
 }
+
+#[test]
+fn agent_record_without_usage_deserializes_with_zero_usage() {
+    let json = r#"{
+        "agent_id": "agent-1",
+        "agent_name": "worker",
+        "owner": {"type": "job", "id": "job-1"},
+        "namespace": "",
+        "workspace_path": "/tmp/ws",
+        "status": "running",
+        "created_at_ms": 1,
+        "updated_at_ms": 1
+    }"#;
+    let record: AgentRecord = serde_json::from_str(json).unwrap();
+
+    assert_eq!(record.usage, TokenUsage::default());
+    assert!(!record.budget_exceeded);
+    assert_eq!(record.cost_usd(), None);
+}
+
+#[test]
+fn agent_record_cost_uses_recorded_model() {
+    let mut record: AgentRecord = serde_json::from_str(
+        r#"{"agent_id":"a","agent_name":"w","owner":{"type":"job","id":"j"},"namespace":"",
+            "workspace_path":"/tmp","status":"running","created_at_ms":1,"updated_at_ms":1}"#,
+    )
+    .unwrap();
+    record.usage.output_tokens = 1_000_000;
+    assert_eq!(record.cost_usd(), None);
+
+    record.model = Some("claude-sonnet-4-5-20250929".to_string());
+    assert_eq!(record.cost_usd(), Some(15.0));
+}
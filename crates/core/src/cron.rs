@@ -0,0 +1,289 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Cron schedule expressions (`schedule = "0 9 * * 1-5"`).
+//!
+//! Standard five-field syntax: minute, hour, day of month, month, day of
+//! week. Fields take `*`, numbers, ranges (`1-5`), lists (`1,15`), and steps
+//! (`*/15`, `0-30/10`); months and weekdays also take three-letter names.
+//! `@hourly`, `@daily`, `@weekly`, `@monthly`, and `@yearly` are shorthands.
+//! As in cron, when both day fields are restricted a day matching either one
+//! fires.
+//!
+//! Schedules are evaluated in a named IANA timezone, or the daemon's local
+//! time when none is given. A time skipped by a DST change fires at the
+//! instant it would have had under the old offset; a repeated time fires once.
+
+use chrono::{DateTime, Datelike, Duration, LocalResult, NaiveDate, NaiveDateTime};
+use chrono::{Local, Offset, TimeZone, Timelike, Utc};
+use chrono_tz::Tz;
+
+/// How far ahead to look for a matching time before giving up. Eight years
+/// covers every leap day, so only impossible dates (e.g. Feb 30) run out.
+const SEARCH_YEARS: i64 = 8;
+
+/// Where a schedule's wall-clock times are read.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Zone {
+    Local,
+    Named(Tz),
+}
+
+/// A parsed cron expression and the timezone it runs in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CronSchedule {
+    minutes: u64,
+    hours: u64,
+    days: u64,
+    months: u64,
+    weekdays: u64,
+    /// Day of month and day of week are both restricted (matched with OR)
+    either_day: bool,
+    zone: Zone,
+}
+
+/// One field of a cron expression: its name, range, and value aliases.
+struct Field {
+    name: &'static str,
+    min: u32,
+    max: u32,
+    names: &'static [&'static str],
+}
+
+const MINUTE: Field = Field {
+    name: "minute",
+    min: 0,
+    max: 59,
+    names: &[],
+};
+const HOUR: Field = Field {
+    name: "hour",
+    min: 0,
+    max: 23,
+    names: &[],
+};
+const DAY: Field = Field {
+    name: "day of month",
+    min: 1,
+    max: 31,
+    names: &[],
+};
+const MONTH: Field = Field {
+    name: "month",
+    min: 1,
+    max: 12,
+    names: &[
+        "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+    ],
+};
+// 7 is accepted as a second Sunday and folded into 0
+const WEEKDAY: Field = Field {
+    name: "day of week",
+    min: 0,
+    max: 7,
+    names: &["sun", "mon", "tue", "wed", "thu", "fri", "sat"],
+};
+
+impl Field {
+    fn value(&self, s: &str) -> Result<u32, String> {
+        let lower = s.to_ascii_lowercase();
+        if let Some(i) = self.names.iter().position(|n| *n == lower) {
+            return Ok(self.min + i as u32);
+        }
+        let n: u32 = s
+            .parse()
+            .map_err(|_| format!("invalid {} value '{}'", self.name, s))?;
+        if n < self.min || n > self.max {
+            return Err(format!(
+                "{} value {} is out of range {}-{}",
+                self.name, n, self.min, self.max
+            ));
+        }
+        Ok(n)
+    }
+
+    /// Parse a comma-separated field into a bit set of matching values.
+    fn parse(&self, s: &str) -> Result<u64, String> {
+        let mut bits = 0u64;
+        for part in s.split(',') {
+            let (range, step) = match part.split_once('/') {
+                Some((range, step)) => {
+                    let step: u32 = step
+                        .parse()
+                        .ok()
+                        .filter(|n| *n > 0)
+                        .ok_or_else(|| format!("invalid {} step '{}'", self.name, step))?;
+                    (range, step)
+                }
+                None => (part, 1),
+            };
+            let (lo, hi) = if range == "*" {
+                (self.min, self.max)
+            } else if let Some((lo, hi)) = range.split_once('-') {
+                let (lo, hi) = (self.value(lo)?, self.value(hi)?);
+                if lo > hi {
+                    return Err(format!("invalid {} range '{}'", self.name, range));
+                }
+                (lo, hi)
+            } else {
+                let lo = self.value(range)?;
+                // "5/15" means every 15 starting at 5
+                (lo, if step > 1 { self.max } else { lo })
+            };
+            for v in (lo..=hi).step_by(step as usize) {
+                bits |= 1 << v;
+            }
+        }
+        Ok(bits)
+    }
+}
+
+fn has(bits: u64, value: u32) -> bool {
+    bits & (1 << value) != 0
+}
+
+impl CronSchedule {
+    /// Parse an expression, optionally in an IANA timezone like
+    /// "America/New_York". Errors are user-facing.
+    pub fn parse(expr: &str, timezone: Option<&str>) -> Result<Self, String> {
+        let zone = match timezone {
+            Some(name) => Zone::Named(
+                name.parse::<Tz>()
+                    .map_err(|_| format!("unknown timezone '{}'", name))?,
+            ),
+            None => Zone::Local,
+        };
+
+        let expanded = match expr.trim() {
+            "@hourly" => "0 * * * *",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@weekly" => "0 0 * * 0",
+            "@monthly" => "0 0 1 * *",
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            other => other,
+        };
+        let fields: Vec<&str> = expanded.split_whitespace().collect();
+        let [minute, hour, day, month, weekday] = fields[..] else {
+            return Err(format!(
+                "invalid schedule '{}': expected 5 fields (minute hour day month weekday), got {}",
+                expr,
+                fields.len()
+            ));
+        };
+        let context = |e: String| format!("invalid schedule '{}': {}", expr, e);
+
+        let mut weekdays = WEEKDAY.parse(weekday).map_err(context)?;
+        if has(weekdays, 7) {
+            weekdays = (weekdays & !(1 << 7)) | 1;
+        }
+        let schedule = CronSchedule {
+            minutes: MINUTE.parse(minute).map_err(context)?,
+            hours: HOUR.parse(hour).map_err(context)?,
+            days: DAY.parse(day).map_err(context)?,
+            months: MONTH.parse(month).map_err(context)?,
+            weekdays,
+            either_day: !day.starts_with('*') && !weekday.starts_with('*'),
+            zone,
+        };
+
+        // Catch dates that never occur, like "0 0 30 2 *"
+        let from = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).single();
+        if from.and_then(|t| schedule.next_in(t)).is_none() {
+            return Err(format!("invalid schedule '{}': never fires", expr));
+        }
+        Ok(schedule)
+    }
+
+    /// Epoch ms of the first fire time strictly after `epoch_ms`.
+    pub fn next_after(&self, epoch_ms: u64) -> Option<u64> {
+        let after = DateTime::<Utc>::from_timestamp_millis(i64::try_from(epoch_ms).ok()?)?;
+        let next = self.next_in(after)?;
+        u64::try_from(next.timestamp_millis()).ok()
+    }
+
+    fn next_in(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self.zone {
+            Zone::Local => self.next_in_zone(&Local, after),
+            Zone::Named(tz) => self.next_in_zone(&tz, after),
+        }
+    }
+
+    fn day_matches(&self, date: NaiveDate) -> bool {
+        let day = has(self.days, date.day());
+        let weekday = has(self.weekdays, date.weekday().num_days_from_sunday());
+        if self.either_day {
+            day || weekday
+        } else {
+            day && weekday
+        }
+    }
+
+    fn next_in_zone<Z: TimeZone>(&self, zone: &Z, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        // Walk wall-clock time from the next whole minute, skipping ahead a
+        // month, day, or hour at a time when that field doesn't match
+        let local = after.with_timezone(zone).naive_local();
+        let mut t = local.with_second(0)?.with_nanosecond(0)? + Duration::minutes(1);
+        let limit = t + Duration::days(366 * SEARCH_YEARS);
+
+        while t < limit {
+            if !has(self.months, t.month()) {
+                let (year, month) = if t.month() == 12 {
+                    (t.year() + 1, 1)
+                } else {
+                    (t.year(), t.month() + 1)
+                };
+                t = NaiveDate::from_ymd_opt(year, month, 1)?.and_hms_opt(0, 0, 0)?;
+                continue;
+            }
+            if !self.day_matches(t.date()) {
+                t = t.date().succ_opt()?.and_hms_opt(0, 0, 0)?;
+                continue;
+            }
+            if !has(self.hours, t.hour()) {
+                t = t.with_minute(0)? + Duration::hours(1);
+                continue;
+            }
+            if !has(self.minutes, t.minute()) {
+                t += Duration::minutes(1);
+                continue;
+            }
+            if let Some(fire) = resolve_local(zone, t, after) {
+                return Some(fire);
+            }
+            t += Duration::minutes(1);
+        }
+        None
+    }
+}
+
+/// The instant for a matching wall-clock time, if it is after `after`.
+fn resolve_local<Z: TimeZone>(
+    zone: &Z,
+    t: NaiveDateTime,
+    after: DateTime<Utc>,
+) -> Option<DateTime<Utc>> {
+    match zone.from_local_datetime(&t) {
+        LocalResult::Single(dt) => Some(dt.with_timezone(&Utc)),
+        // Clocks went back: fire on the first pass only, unless that has
+        // already gone by
+        LocalResult::Ambiguous(first, second) => [first, second]
+            .into_iter()
+            .map(|dt| dt.with_timezone(&Utc))
+            .find(|dt| *dt > after),
+        // Clocks went forward over this time: use the offset from before
+        LocalResult::None => {
+            let before = zone
+                .from_local_datetime(&(t - Duration::hours(3)))
+                .earliest()?;
+            let offset = before.offset().fix();
+            offset
+                .from_local_datetime(&t)
+                .single()
+                .map(|dt| dt.with_timezone(&Utc))
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "cron_tests.rs"]
+mod tests;
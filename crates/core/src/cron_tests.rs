@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+/// Epoch ms for a UTC wall-clock time.
+fn utc(y: i32, mo: u32, d: u32, h: u32, mi: u32) -> u64 {
+    Utc.with_ymd_and_hms(y, mo, d, h, mi, 0)
+        .unwrap()
+        .timestamp_millis() as u64
+}
+
+fn next(expr: &str, tz: &str, after: u64) -> u64 {
+    CronSchedule::parse(expr, Some(tz))
+        .unwrap()
+        .next_after(after)
+        .unwrap()
+}
+
+#[yare::parameterized(
+    every_minute     = { "* * * * *",    utc(2026, 3, 4, 10, 0),  utc(2026, 3, 4, 10, 1) },
+    quarter_hours    = { "*/15 * * * *", utc(2026, 3, 4, 10, 7),  utc(2026, 3, 4, 10, 15) },
+    later_today      = { "30 14 * * *",  utc(2026, 3, 4, 10, 0),  utc(2026, 3, 4, 14, 30) },
+    tomorrow         = { "30 9 * * *",   utc(2026, 3, 4, 10, 0),  utc(2026, 3, 5, 9, 30) },
+    strictly_after   = { "0 9 * * *",    utc(2026, 3, 4, 9, 0),   utc(2026, 3, 5, 9, 0) },
+    weekday_skips_weekend = { "0 9 * * 1-5", utc(2026, 3, 6, 10, 0), utc(2026, 3, 9, 9, 0) },
+    named_weekdays   = { "0 9 * * mon,FRI", utc(2026, 3, 4, 10, 0), utc(2026, 3, 6, 9, 0) },
+    sunday_as_seven  = { "0 0 * * 7",    utc(2026, 3, 4, 10, 0),  utc(2026, 3, 8, 0, 0) },
+    next_month       = { "0 0 1 * *",    utc(2026, 3, 4, 10, 0),  utc(2026, 4, 1, 0, 0) },
+    named_month      = { "0 0 1 jul *",  utc(2026, 3, 4, 10, 0),  utc(2026, 7, 1, 0, 0) },
+    next_year        = { "@yearly",      utc(2026, 3, 4, 10, 0),  utc(2027, 1, 1, 0, 0) },
+    leap_day         = { "0 0 29 2 *",   utc(2026, 3, 4, 10, 0),  utc(2028, 2, 29, 0, 0) },
+    stepped_range    = { "0-30/10 8 * * *", utc(2026, 3, 4, 8, 11), utc(2026, 3, 4, 8, 20) },
+    offset_step      = { "5/20 * * * *", utc(2026, 3, 4, 8, 26),  utc(2026, 3, 4, 8, 45) },
+)]
+fn next_fire_in_utc(expr: &str, after: u64, expected: u64) {
+    assert_eq!(next(expr, "UTC", after), expected);
+}
+
+#[test]
+fn restricted_day_fields_match_either() {
+    // The 13th, or any Friday
+    let s = "0 0 13 * fri";
+    assert_eq!(next(s, "UTC", utc(2026, 3, 4, 0, 0)), utc(2026, 3, 6, 0, 0));
+    assert_eq!(
+        next(s, "UTC", utc(2026, 3, 6, 0, 0)),
+        utc(2026, 3, 13, 0, 0)
+    );
+}
+
+#[test]
+fn named_timezone_shifts_wall_clock() {
+    // 09:00 in New York is 14:00 UTC in winter, 13:00 UTC in summer
+    assert_eq!(
+        next("0 9 * * *", "America/New_York", utc(2026, 1, 15, 0, 0)),
+        utc(2026, 1, 15, 14, 0)
+    );
+    assert_eq!(
+        next("0 9 * * *", "America/New_York", utc(2026, 7, 15, 0, 0)),
+        utc(2026, 7, 15, 13, 0)
+    );
+}
+
+#[test]
+fn time_skipped_by_dst_fires_with_the_old_offset() {
+    // 2026-03-08 02:30 doesn't exist in New York; 02:30 EST is 07:30 UTC
+    assert_eq!(
+        next("30 2 * * *", "America/New_York", utc(2026, 3, 8, 0, 0)),
+        utc(2026, 3, 8, 7, 30)
+    );
+}
+
+#[test]
+fn time_repeated_by_dst_fires_once() {
+    // 2026-11-01 01:30 happens twice in New York (05:30 and 06:30 UTC)
+    let s = "30 1 * * *";
+    let first = next(s, "America/New_York", utc(2026, 11, 1, 0, 0));
+    assert_eq!(first, utc(2026, 11, 1, 5, 30));
+    assert_eq!(next(s, "America/New_York", first), utc(2026, 11, 2, 6, 30));
+}
+
+#[yare::parameterized(
+    too_few_fields   = { "0 9 * *",       "expected 5 fields" },
+    out_of_range     = { "60 * * * *",    "minute value 60 is out of range 0-59" },
+    bad_value        = { "0 nine * * *",  "invalid hour value 'nine'" },
+    bad_step         = { "*/0 * * * *",   "invalid minute step '0'" },
+    backwards_range  = { "0 9 * * 5-1",   "invalid day of week range '5-1'" },
+    bad_month_name   = { "0 0 1 foo *",   "invalid month value 'foo'" },
+    never_fires      = { "0 0 30 2 *",    "never fires" },
+)]
+fn invalid_expressions(expr: &str, fragment: &str) {
+    let err = CronSchedule::parse(expr, Some("UTC")).unwrap_err();
+    assert!(err.contains(fragment), "{err}");
+}
+
+#[test]
+fn unknown_timezone_is_rejected() {
+    let err = CronSchedule::parse("0 9 * * *", Some("Mars/Olympus")).unwrap_err();
+    assert_eq!(err, "unknown timezone 'Mars/Olympus'");
+}
+
+#[test]
+fn local_time_is_the_default() {
+    let schedule = CronSchedule::parse("@hourly", None).unwrap();
+    let next = schedule.next_after(utc(2026, 3, 4, 10, 20)).unwrap();
+    assert!(next > utc(2026, 3, 4, 10, 20) && next <= utc(2026, 3, 4, 11, 20));
+}
@@ -5,6 +5,7 @@
 
 use crate::owner::OwnerId;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 crate::define_id! {
     /// Unique identifier for a decision.
@@ -61,6 +62,15 @@ pub struct Decision {
     pub superseded_by: Option<DecisionId>,
     #[serde(default)]
     pub namespace: String,
+    /// Approval step that created this decision (None for escalations)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub step: Option<String>,
+    /// Freeform fields requested from the resolver
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<String>,
+    /// Field values given on resolve
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub values: HashMap<String, String>,
 }
 
 impl DecisionOption {
@@ -59,6 +59,9 @@ fn decision_serde_roundtrip() {
         resolved_at_ms: None,
         superseded_by: None,
         namespace: "myproject".to_string(),
+        step: None,
+        fields: Vec::new(),
+        values: HashMap::new(),
     };
     let json = serde_json::to_string(&decision).unwrap();
     let parsed: Decision = serde_json::from_str(&json).unwrap();
@@ -85,6 +88,9 @@ fn decision_is_resolved() {
         resolved_at_ms: None,
         superseded_by: None,
         namespace: String::new(),
+        step: None,
+        fields: Vec::new(),
+        values: HashMap::new(),
     };
     assert!(!decision.is_resolved());
 
@@ -0,0 +1,111 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Job dependencies (`oj run --after`, `depends_on` in a job definition).
+//!
+//! A dependency is written `<job-id>[:done|:any]`. With `:done` (the default)
+//! the dependency job must complete successfully; with `:any` any terminal
+//! state will do.
+
+use crate::job::Job;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Terminal state a dependency job must reach.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DependencyCondition {
+    /// Completed successfully
+    #[default]
+    Done,
+    /// Done, failed, or cancelled
+    Any,
+}
+
+/// A job that must finish before another job may start.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JobDependency {
+    pub job_id: String,
+    #[serde(default)]
+    pub condition: DependencyCondition,
+}
+
+/// Where a dependency stands right now.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DependencyState {
+    /// The dependency job reached the required state
+    Met,
+    /// The dependency job is still running
+    Pending,
+    /// The dependency can never be met (the reason is user-facing)
+    Broken(String),
+}
+
+impl JobDependency {
+    pub fn new(job_id: impl Into<String>, condition: DependencyCondition) -> Self {
+        Self {
+            job_id: job_id.into(),
+            condition,
+        }
+    }
+
+    /// Check the dependency against its job (`None` if the job doesn't exist).
+    pub fn check(&self, job: Option<&Job>) -> DependencyState {
+        self.check_step(job.map(|job| job.step.as_str()))
+    }
+
+    /// Check the dependency against the step its job is on (`None` if the
+    /// job doesn't exist). Also used with the recorded final step of a
+    /// deleted job.
+    pub fn check_step(&self, step: Option<&str>) -> DependencyState {
+        let Some(step) = step else {
+            return DependencyState::Broken(format!("dependency job {} not found", self.job_id));
+        };
+        match step {
+            "done" => DependencyState::Met,
+            "failed" | "cancelled" if self.condition == DependencyCondition::Any => {
+                DependencyState::Met
+            }
+            "failed" | "cancelled" => {
+                DependencyState::Broken(format!("dependency job {} {}", self.job_id, step))
+            }
+            _ => DependencyState::Pending,
+        }
+    }
+}
+
+impl FromStr for JobDependency {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (job_id, condition) = match s.rsplit_once(':') {
+            Some((id, "done")) => (id, DependencyCondition::Done),
+            Some((id, "any")) => (id, DependencyCondition::Any),
+            Some((_, other)) => {
+                return Err(format!(
+                    "invalid dependency '{}': unknown condition '{}' (expected done or any)",
+                    s, other
+                ))
+            }
+            None => (s, DependencyCondition::Done),
+        };
+        if job_id.is_empty() {
+            return Err(format!("invalid dependency '{}': missing job id", s));
+        }
+        Ok(Self::new(job_id, condition))
+    }
+}
+
+impl fmt::Display for JobDependency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.condition {
+            DependencyCondition::Done => write!(f, "{}", self.job_id),
+            DependencyCondition::Any => write!(f, "{}:any", self.job_id),
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "dependency_tests.rs"]
+mod tests;
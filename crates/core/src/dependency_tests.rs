@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use crate::job::JobBuilder;
+
+#[yare::parameterized(
+    bare = { "abc123", "abc123", DependencyCondition::Done },
+    done = { "abc123:done", "abc123", DependencyCondition::Done },
+    any  = { "abc123:any", "abc123", DependencyCondition::Any },
+)]
+fn parse_dependency(spec: &str, job_id: &str, condition: DependencyCondition) {
+    let dep: JobDependency = spec.parse().unwrap();
+    assert_eq!(dep, JobDependency::new(job_id, condition));
+}
+
+#[test]
+fn parse_rejects_unknown_condition_and_empty_id() {
+    let err = "abc:failed".parse::<JobDependency>().unwrap_err();
+    assert!(err.contains("unknown condition 'failed'"), "{err}");
+    let err = ":any".parse::<JobDependency>().unwrap_err();
+    assert!(err.contains("missing job id"), "{err}");
+}
+
+#[test]
+fn display_roundtrips_through_parse() {
+    for spec in ["abc123", "abc123:any"] {
+        let dep: JobDependency = spec.parse().unwrap();
+        assert_eq!(dep.to_string(), spec);
+    }
+}
+
+#[yare::parameterized(
+    running_done   = { "build", DependencyCondition::Done, DependencyState::Pending },
+    running_any    = { "build", DependencyCondition::Any, DependencyState::Pending },
+    done_done      = { "done", DependencyCondition::Done, DependencyState::Met },
+    failed_any     = { "failed", DependencyCondition::Any, DependencyState::Met },
+    cancelled_any  = { "cancelled", DependencyCondition::Any, DependencyState::Met },
+    failed_done    = { "failed", DependencyCondition::Done,
+                       DependencyState::Broken("dependency job dep-1 failed".to_string()) },
+    cancelled_done = { "cancelled", DependencyCondition::Done,
+                       DependencyState::Broken("dependency job dep-1 cancelled".to_string()) },
+)]
+fn check_dependency(step: &str, condition: DependencyCondition, expected: DependencyState) {
+    let job = JobBuilder::default().id("dep-1").step(step).build();
+    let dep = JobDependency::new("dep-1", condition);
+    assert_eq!(dep.check(Some(&job)), expected);
+}
+
+#[test]
+fn missing_job_is_broken() {
+    let dep = JobDependency::new("gone", DependencyCondition::Any);
+    assert_eq!(
+        dep.check(None),
+        DependencyState::Broken("dependency job gone not found".to_string())
+    );
+}
@@ -106,6 +106,9 @@ pub enum Effect {
         cwd: PathBuf,
         /// Environment variables
         env: HashMap<String, String>,
+        /// Kill the command's process group after this long (executor default if unset)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        timeout: Option<Duration>,
     },
 
     // === Worker effects ===
@@ -114,6 +117,9 @@ pub enum Effect {
         worker_name: String,
         list_command: String,
         cwd: PathBuf,
+        /// Queue `timeout` (executor default if unset)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        timeout: Option<Duration>,
     },
 
     /// Run the queue's take command to claim an item
@@ -125,6 +131,43 @@ pub enum Effect {
         item_id: String,
         /// Full item data (passed through to the completion event for job creation)
         item: serde_json::Value,
+        /// Queue `timeout` (executor default if unset)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        timeout: Option<Duration>,
+    },
+
+    // === Watch effects ===
+    /// Watch files under `root` and emit `WatchChanged` once changes settle
+    WatchFiles {
+        watch_name: String,
+        namespace: String,
+        root: PathBuf,
+        /// Glob patterns relative to `root`
+        paths: Vec<String>,
+        #[serde(with = "duration_serde")]
+        debounce: Duration,
+    },
+
+    /// Stop watching files for a watch
+    UnwatchFiles {
+        watch_name: String,
+        namespace: String,
+    },
+
+    // === Git trigger effects ===
+    /// Watch the refs of `repo` matching `ref_pattern` and emit
+    /// `GitRefsScanned` with their positions, now and whenever they change
+    WatchGitRefs {
+        trigger_name: String,
+        namespace: String,
+        repo: PathBuf,
+        ref_pattern: String,
+    },
+
+    /// Stop watching refs for a git trigger
+    UnwatchGitRefs {
+        trigger_name: String,
+        namespace: String,
     },
 
     // === Notification effects ===
@@ -154,6 +197,10 @@ impl Effect {
             Effect::Shell { .. } => "shell",
             Effect::PollQueue { .. } => "poll_queue",
             Effect::TakeQueueItem { .. } => "take_queue_item",
+            Effect::WatchFiles { .. } => "watch_files",
+            Effect::UnwatchFiles { .. } => "unwatch_files",
+            Effect::WatchGitRefs { .. } => "watch_git_refs",
+            Effect::UnwatchGitRefs { .. } => "unwatch_git_refs",
             Effect::Notify { .. } => "notify",
         }
     }
@@ -240,6 +287,41 @@ impl Effect {
                 ("cwd", cwd.display().to_string()),
                 ("item_id", item_id.clone()),
             ],
+            Effect::WatchFiles {
+                watch_name,
+                namespace,
+                root,
+                ..
+            } => vec![
+                ("watch_name", watch_name.clone()),
+                ("namespace", namespace.clone()),
+                ("root", root.display().to_string()),
+            ],
+            Effect::UnwatchFiles {
+                watch_name,
+                namespace,
+            } => vec![
+                ("watch_name", watch_name.clone()),
+                ("namespace", namespace.clone()),
+            ],
+            Effect::WatchGitRefs {
+                trigger_name,
+                namespace,
+                repo,
+                ref_pattern,
+            } => vec![
+                ("trigger_name", trigger_name.clone()),
+                ("namespace", namespace.clone()),
+                ("repo", repo.display().to_string()),
+                ("ref_pattern", ref_pattern.clone()),
+            ],
+            Effect::UnwatchGitRefs {
+                trigger_name,
+                namespace,
+            } => vec![
+                ("trigger_name", trigger_name.clone()),
+                ("namespace", namespace.clone()),
+            ],
             Effect::Notify { title, .. } => vec![("title", title.clone())],
         }
     }
@@ -64,11 +64,13 @@ fn effect_serialization_roundtrip() {
             env: [("KEY".to_string(), "value".to_string())]
                 .into_iter()
                 .collect(),
+            timeout: Some(Duration::from_secs(900)),
         },
         Effect::PollQueue {
             worker_name: "fixer".to_string(),
             list_command: "echo '[]'".to_string(),
             cwd: PathBuf::from("/work"),
+            timeout: None,
         },
         Effect::TakeQueueItem {
             worker_name: "fixer".to_string(),
@@ -76,6 +78,28 @@ fn effect_serialization_roundtrip() {
             cwd: PathBuf::from("/work"),
             item_id: "item-1".to_string(),
             item: serde_json::json!({"id": "item-1", "title": "test"}),
+            timeout: None,
+        },
+        Effect::WatchFiles {
+            watch_name: "specs".to_string(),
+            namespace: "docs".to_string(),
+            root: PathBuf::from("/work"),
+            paths: vec!["docs/specs/**/*.md".to_string()],
+            debounce: Duration::from_secs(30),
+        },
+        Effect::UnwatchFiles {
+            watch_name: "specs".to_string(),
+            namespace: "docs".to_string(),
+        },
+        Effect::WatchGitRefs {
+            trigger_name: "ci".to_string(),
+            namespace: "docs".to_string(),
+            repo: PathBuf::from("/work"),
+            ref_pattern: "refs/heads/feature/*".to_string(),
+        },
+        Effect::UnwatchGitRefs {
+            trigger_name: "ci".to_string(),
+            namespace: "docs".to_string(),
         },
         Effect::Notify {
             title: "Build complete".to_string(),
@@ -177,6 +201,7 @@ fn traced_effect_names() {
                 command: "cmd".to_string(),
                 cwd: PathBuf::from("/"),
                 env: HashMap::new(),
+                timeout: None,
             },
             "shell",
         ),
@@ -185,6 +210,7 @@ fn traced_effect_names() {
                 worker_name: "w".to_string(),
                 list_command: "cmd".to_string(),
                 cwd: PathBuf::from("/"),
+                timeout: None,
             },
             "poll_queue",
         ),
@@ -195,9 +221,43 @@ fn traced_effect_names() {
                 cwd: PathBuf::from("/"),
                 item_id: "i".to_string(),
                 item: serde_json::json!({}),
+                timeout: None,
             },
             "take_queue_item",
         ),
+        (
+            Effect::WatchFiles {
+                watch_name: "w".to_string(),
+                namespace: String::new(),
+                root: PathBuf::from("/"),
+                paths: vec![],
+                debounce: Duration::from_secs(1),
+            },
+            "watch_files",
+        ),
+        (
+            Effect::UnwatchFiles {
+                watch_name: "w".to_string(),
+                namespace: String::new(),
+            },
+            "unwatch_files",
+        ),
+        (
+            Effect::WatchGitRefs {
+                trigger_name: "t".to_string(),
+                namespace: String::new(),
+                repo: PathBuf::from("/"),
+                ref_pattern: "refs/heads/main".to_string(),
+            },
+            "watch_git_refs",
+        ),
+        (
+            Effect::UnwatchGitRefs {
+                trigger_name: "t".to_string(),
+                namespace: String::new(),
+            },
+            "unwatch_git_refs",
+        ),
         (
             Effect::Notify {
                 title: "t".to_string(),
@@ -329,6 +389,7 @@ fn traced_effect_fields() {
         command: "make".to_string(),
         cwd: PathBuf::from("/src"),
         env: HashMap::new(),
+        timeout: None,
     };
     let fields = effect.fields();
     assert_eq!(
@@ -345,6 +406,7 @@ fn traced_effect_fields() {
         worker_name: "fixer".to_string(),
         list_command: "echo '[]'".to_string(),
         cwd: PathBuf::from("/work"),
+        timeout: None,
     };
     let fields = effect.fields();
     assert_eq!(
@@ -362,6 +424,7 @@ fn traced_effect_fields() {
         cwd: PathBuf::from("/work"),
         item_id: "item-1".to_string(),
         item: serde_json::json!({"id": "item-1"}),
+        timeout: None,
     };
     let fields = effect.fields();
     assert_eq!(
@@ -373,6 +436,42 @@ fn traced_effect_fields() {
         ]
     );
 
+    // Test WatchFiles fields
+    let effect = Effect::WatchFiles {
+        watch_name: "specs".to_string(),
+        namespace: "docs".to_string(),
+        root: PathBuf::from("/work"),
+        paths: vec!["docs/**/*.md".to_string()],
+        debounce: Duration::from_secs(30),
+    };
+    let fields = effect.fields();
+    assert_eq!(
+        fields,
+        vec![
+            ("watch_name", "specs".to_string()),
+            ("namespace", "docs".to_string()),
+            ("root", "/work".to_string()),
+        ]
+    );
+
+    // Test WatchGitRefs fields
+    let effect = Effect::WatchGitRefs {
+        trigger_name: "ci".to_string(),
+        namespace: "docs".to_string(),
+        repo: PathBuf::from("/work"),
+        ref_pattern: "refs/heads/*".to_string(),
+    };
+    let fields = effect.fields();
+    assert_eq!(
+        fields,
+        vec![
+            ("trigger_name", "ci".to_string()),
+            ("namespace", "docs".to_string()),
+            ("repo", "/work".to_string()),
+            ("ref_pattern", "refs/heads/*".to_string()),
+        ]
+    );
+
     // Test Notify fields
     let effect = Effect::Notify {
         title: "Build".to_string(),
@@ -6,14 +6,16 @@
 use crate::agent::{AgentError, AgentId, AgentState};
 use crate::agent_run::{AgentRunId, AgentRunStatus};
 use crate::decision::{DecisionOption, DecisionSource};
+use crate::dependency::JobDependency;
 use crate::id::ShortId;
 use crate::job::JobId;
 use crate::owner::OwnerId;
 use crate::session::SessionId;
 use crate::timer::TimerId;
+use crate::usage::{BudgetScope, TokenUsage};
 use crate::workspace::WorkspaceId;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::PathBuf;
 
 /// Kind of signal an agent can emit
@@ -71,6 +73,10 @@ fn is_empty_map<K, V>(map: &HashMap<K, V>) -> bool {
     map.is_empty()
 }
 
+fn is_zero(n: &i32) -> bool {
+    *n == 0
+}
+
 /// Events that trigger state transitions in the system.
 ///
 /// Serializes with `{"type": "event:name", ...fields}` format.
@@ -152,6 +158,24 @@ pub enum Event {
         assistant_context: Option<String>,
     },
 
+    /// Cumulative token usage read from the agent's session log
+    #[serde(rename = "agent:usage")]
+    AgentUsage {
+        agent_id: AgentId,
+        usage: TokenUsage,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        model: Option<String>,
+    },
+
+    /// An agent or job budget was crossed and the agent's `on_budget` fired
+    #[serde(rename = "agent:budget_exceeded")]
+    AgentBudgetExceeded {
+        agent_id: AgentId,
+        owner: OwnerId,
+        scope: BudgetScope,
+        reason: String,
+    },
+
     // -- command --
     #[serde(rename = "command:run")]
     CommandRun {
@@ -166,6 +190,12 @@ pub enum Event {
         namespace: String,
         command: String,
         args: HashMap<String, String>,
+        /// Jobs that must finish before the job starts (`oj run --after`)
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        after: Vec<JobDependency>,
+        /// Admission priority (`oj run --priority`)
+        #[serde(default, skip_serializing_if = "is_zero")]
+        priority: i32,
     },
 
     // -- job --
@@ -184,8 +214,30 @@ pub enum Event {
         namespace: String,
         #[serde(default, skip_serializing_if = "Option::is_none")]
         cron_name: Option<String>,
+        /// Jobs that must finish before the first step starts
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        after: Vec<JobDependency>,
+        /// Admission priority (higher is admitted first)
+        #[serde(default, skip_serializing_if = "is_zero")]
+        priority: i32,
+        /// Project the job was started from (its `.oj/config.toml` sets the
+        /// project limit on running jobs)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        project_root: Option<PathBuf>,
     },
 
+    /// Job is held before its first step until the listed jobs finish
+    #[serde(rename = "job:blocked")]
+    JobBlocked { id: JobId, waiting_on: Vec<String> },
+
+    /// Job is held in the admission queue because a running-job limit is reached
+    #[serde(rename = "job:queued")]
+    JobQueued { id: JobId },
+
+    /// Queued job was given a running-job slot
+    #[serde(rename = "job:admitted")]
+    JobAdmitted { id: JobId },
+
     #[serde(rename = "job:advanced")]
     JobAdvanced { id: JobId, step: String },
 
@@ -248,6 +300,9 @@ pub enum Event {
         stdout: Option<String>,
         #[serde(default, skip_serializing_if = "Option::is_none")]
         stderr: Option<String>,
+        /// The command was killed for running past its timeout
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        timed_out: bool,
     },
 
     // -- step --
@@ -289,6 +344,34 @@ pub enum Event {
         error: String,
     },
 
+    /// A failed step will run again once its retry delay elapses
+    #[serde(rename = "step:retrying")]
+    StepRetrying {
+        job_id: JobId,
+        step: String,
+        /// Retry number for this step, starting at 1
+        attempt: u32,
+        error: String,
+    },
+
+    /// A parallel step started one of its branches in a child job
+    #[serde(rename = "step:branched")]
+    StepBranched {
+        job_id: JobId,
+        step: String,
+        /// Name of the sibling step run by the branch
+        branch: String,
+        branch_job_id: JobId,
+    },
+
+    /// A `run = { job }` step started a child job and waits for it to finish
+    #[serde(rename = "step:delegated")]
+    StepDelegated {
+        job_id: JobId,
+        step: String,
+        child_job_id: JobId,
+    },
+
     // -- system --
     #[serde(rename = "system:shutdown")]
     Shutdown,
@@ -297,6 +380,14 @@ pub enum Event {
     #[serde(rename = "timer:start")]
     TimerStart { id: TimerId },
 
+    /// A timer that must survive a daemon restart (re-armed on startup)
+    #[serde(rename = "timer:scheduled")]
+    TimerScheduled { id: TimerId, fires_at_ms: u64 },
+
+    /// A durable timer was cancelled before it fired
+    #[serde(rename = "timer:cancelled")]
+    TimerCancelled { id: TimerId },
+
     // -- workspace --
     #[serde(rename = "workspace:created")]
     WorkspaceCreated {
@@ -328,11 +419,25 @@ pub enum Event {
         cron_name: String,
         project_root: PathBuf,
         runbook_hash: String,
+        /// Empty for schedule-based crons
+        #[serde(default)]
         interval: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        schedule: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        timezone: Option<String>,
         /// What this cron runs: "job:name" or "agent:name"
         run_target: String,
         #[serde(default)]
         namespace: String,
+        /// Epoch ms when the cron started; a resumed cron keeps its original
+        /// start
+        #[serde(default)]
+        started_at_ms: u64,
+        /// Set when the daemon resumes a running cron after a restart: the
+        /// last fire time, or the start time if it never fired
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        resumed_from_ms: Option<u64>,
     },
 
     #[serde(rename = "cron:stopped")]
@@ -375,6 +480,9 @@ pub enum Event {
         agent_run_id: Option<String>,
         #[serde(default)]
         namespace: String,
+        /// Epoch ms when the run was started
+        #[serde(default)]
+        fired_at_ms: u64,
     },
 
     #[serde(rename = "cron:deleted")]
@@ -384,6 +492,103 @@ pub enum Event {
         namespace: String,
     },
 
+    // -- watch --
+    #[serde(rename = "watch:started")]
+    WatchStarted {
+        watch_name: String,
+        project_root: PathBuf,
+        runbook_hash: String,
+        /// Glob patterns relative to the project root
+        paths: Vec<String>,
+        /// Quiet period before a burst of changes runs the job (e.g. "30s")
+        debounce: String,
+        /// What this watch runs: "job:name"
+        run_target: String,
+        #[serde(default)]
+        namespace: String,
+    },
+
+    #[serde(rename = "watch:stopped")]
+    WatchStopped {
+        watch_name: String,
+        #[serde(default)]
+        namespace: String,
+    },
+
+    /// Watched files changed and settled for the debounce period
+    #[serde(rename = "watch:changed")]
+    WatchChanged {
+        watch_name: String,
+        #[serde(default)]
+        namespace: String,
+        /// Changed paths relative to the project root, sorted
+        files: Vec<String>,
+    },
+
+    #[serde(rename = "watch:fired")]
+    WatchFired {
+        watch_name: String,
+        job_id: JobId,
+        #[serde(default)]
+        namespace: String,
+    },
+
+    // -- git trigger --
+    #[serde(rename = "git_trigger:started")]
+    GitTriggerStarted {
+        trigger_name: String,
+        project_root: PathBuf,
+        runbook_hash: String,
+        /// Full ref name or glob (e.g. "refs/heads/feature/*")
+        ref_pattern: String,
+        /// What this trigger runs: "job:name"
+        run_target: String,
+        #[serde(default)]
+        namespace: String,
+    },
+
+    #[serde(rename = "git_trigger:stopped")]
+    GitTriggerStopped {
+        trigger_name: String,
+        #[serde(default)]
+        namespace: String,
+    },
+
+    /// Current positions of the refs a git trigger matches, reported on
+    /// start and whenever the repository's refs change
+    #[serde(rename = "git_trigger:scanned")]
+    GitRefsScanned {
+        trigger_name: String,
+        #[serde(default)]
+        namespace: String,
+        /// Ref name to commit
+        refs: BTreeMap<String, String>,
+    },
+
+    /// First positions recorded for a new git trigger; existing commits
+    /// don't run its job
+    #[serde(rename = "git_trigger:baselined")]
+    GitRefsBaselined {
+        trigger_name: String,
+        #[serde(default)]
+        namespace: String,
+        refs: BTreeMap<String, String>,
+    },
+
+    /// A matching ref moved (`new` is empty when the ref was deleted)
+    #[serde(rename = "git_trigger:ref_moved")]
+    GitRefMoved {
+        trigger_name: String,
+        #[serde(default)]
+        namespace: String,
+        ref_name: String,
+        old: String,
+        new: String,
+        /// Job started for the move, if any
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        job_id: Option<JobId>,
+    },
+
     // -- worker --
     #[serde(rename = "worker:started")]
     WorkerStarted {
@@ -392,8 +597,15 @@ pub enum Event {
         runbook_hash: String,
         #[serde(default)]
         queue_name: String,
+        /// Weighted source queues (`source.queues`); empty when the worker
+        /// consumes only `queue_name`
+        #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+        queues: BTreeMap<String, u32>,
         #[serde(default)]
         concurrency: u32,
+        /// Dispatch rate limit (e.g. "10/h"), if any
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        rate: Option<String>,
         #[serde(default)]
         namespace: String,
     },
@@ -425,7 +637,15 @@ pub enum Event {
     WorkerItemDispatched {
         worker_name: String,
         item_id: String,
+        #[serde(default)]
         job_id: JobId,
+        /// Set instead of `job_id` when the worker's handler is an agent
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        agent_run_id: Option<String>,
+        /// Rate-limited workers only: when the token bucket is full again
+        /// after this dispatch took a token
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        bucket_full_at_ms: Option<u64>,
         #[serde(default)]
         namespace: String,
     },
@@ -441,6 +661,9 @@ pub enum Event {
     WorkerResized {
         worker_name: String,
         concurrency: u32,
+        /// Dispatch rate limit after the resize (None = unlimited)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        rate: Option<String>,
         #[serde(default)]
         namespace: String,
     },
@@ -461,6 +684,21 @@ pub enum Event {
         pushed_at_epoch_ms: u64,
         #[serde(default)]
         namespace: String,
+        /// Dispatch priority (higher is taken first)
+        #[serde(default, skip_serializing_if = "is_zero")]
+        priority: i32,
+        /// Hidden from workers until this time (`oj queue push --delay/--at`)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        visible_after_epoch_ms: Option<u64>,
+        /// Key that later pushes of the same work are deduplicated against
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        dedup_key: Option<String>,
+        /// A still-pending item expires at this time (queue `ttl` / `--ttl`)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        expires_at_epoch_ms: Option<u64>,
+        /// Drop the item on expiry instead of marking it expired
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        drop_on_expire: bool,
     },
 
     #[serde(rename = "queue:taken")]
@@ -478,6 +716,9 @@ pub enum Event {
         item_id: String,
         #[serde(default)]
         namespace: String,
+        /// Starts the queue's `dedup_window` for the item's key
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        completed_at_epoch_ms: Option<u64>,
     },
 
     #[serde(rename = "queue:failed")]
@@ -503,6 +744,10 @@ pub enum Event {
         item_id: String,
         #[serde(default)]
         namespace: String,
+        /// Scheduled by the queue's retry policy rather than `oj queue retry`;
+        /// keeps the failure count so backoff and `attempts` carry over
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        automatic: bool,
     },
 
     #[serde(rename = "queue:item_dead")]
@@ -513,6 +758,80 @@ pub enum Event {
         namespace: String,
     },
 
+    /// Item's dispatch priority changed (`oj queue edit --priority`)
+    #[serde(rename = "queue:item_prioritized")]
+    QueueItemPrioritized {
+        queue_name: String,
+        item_id: String,
+        priority: i32,
+        #[serde(default)]
+        namespace: String,
+    },
+
+    /// A pending item outlived its TTL
+    #[serde(rename = "queue:item_expired")]
+    QueueItemExpired {
+        queue_name: String,
+        item_id: String,
+        #[serde(default)]
+        namespace: String,
+    },
+
+    /// An active item's lease was granted or renewed
+    #[serde(rename = "queue:leased")]
+    QueueItemLeased {
+        queue_name: String,
+        item_id: String,
+        expires_at_epoch_ms: u64,
+        #[serde(default)]
+        namespace: String,
+    },
+
+    /// An active item's lease ran out with no live handler; the item fails
+    /// as with `queue:failed`, then is retried or marked dead by the queue's
+    /// `retry` policy
+    #[serde(rename = "queue:lease_expired")]
+    QueueLeaseExpired {
+        queue_name: String,
+        item_id: String,
+        #[serde(default)]
+        namespace: String,
+    },
+
+    /// A duplicate push was folded into a pending item (`dedup_policy = "merge"`)
+    #[serde(rename = "queue:item_merged")]
+    QueueItemMerged {
+        queue_name: String,
+        item_id: String,
+        /// The item's data after the merge
+        data: HashMap<String, String>,
+        priority: i32,
+        #[serde(default)]
+        namespace: String,
+    },
+
+    // -- lock --
+    /// Step is blocked until it can acquire the listed locks/semaphores
+    #[serde(rename = "lock:waiting")]
+    LockWaiting {
+        job_id: JobId,
+        step: String,
+        /// Resource references as written in the runbook (e.g. "lock.main-branch")
+        resources: Vec<String>,
+        #[serde(default)]
+        namespace: String,
+    },
+
+    /// Step acquired all of its locks/semaphores and may start
+    #[serde(rename = "lock:acquired")]
+    LockAcquired {
+        job_id: JobId,
+        step: String,
+        resources: Vec<String>,
+        #[serde(default)]
+        namespace: String,
+    },
+
     // -- decision --
     #[serde(rename = "decision:created")]
     DecisionCreated {
@@ -529,6 +848,12 @@ pub enum Event {
         created_at_ms: u64,
         #[serde(default)]
         namespace: String,
+        /// Approval step this decision gates; set only for step-created decisions
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        step: Option<String>,
+        /// Freeform fields the resolver fills in (approval steps)
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        fields: Vec<String>,
     },
 
     #[serde(rename = "decision:resolved")]
@@ -543,6 +868,9 @@ pub enum Event {
         resolved_at_ms: u64,
         #[serde(default)]
         namespace: String,
+        /// Values for the decision's fields
+        #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+        values: HashMap<String, String>,
     },
 
     // -- agent_run --
@@ -651,8 +979,13 @@ impl Event {
             Event::AgentIdle { .. } => "agent:idle",
             Event::AgentStop { .. } => "agent:stop",
             Event::AgentPrompt { .. } => "agent:prompt",
+            Event::AgentUsage { .. } => "agent:usage",
+            Event::AgentBudgetExceeded { .. } => "agent:budget_exceeded",
             Event::CommandRun { .. } => "command:run",
             Event::JobCreated { .. } => "job:created",
+            Event::JobBlocked { .. } => "job:blocked",
+            Event::JobQueued { .. } => "job:queued",
+            Event::JobAdmitted { .. } => "job:admitted",
             Event::JobAdvanced { .. } => "job:advanced",
             Event::JobUpdated { .. } => "job:updated",
             Event::JobResume { .. } => "job:resume",
@@ -668,8 +1001,13 @@ impl Event {
             Event::StepWaiting { .. } => "step:waiting",
             Event::StepCompleted { .. } => "step:completed",
             Event::StepFailed { .. } => "step:failed",
+            Event::StepRetrying { .. } => "step:retrying",
+            Event::StepBranched { .. } => "step:branched",
+            Event::StepDelegated { .. } => "step:delegated",
             Event::Shutdown => "system:shutdown",
             Event::TimerStart { .. } => "timer:start",
+            Event::TimerScheduled { .. } => "timer:scheduled",
+            Event::TimerCancelled { .. } => "timer:cancelled",
             Event::WorkspaceCreated { .. } => "workspace:created",
             Event::WorkspaceReady { .. } => "workspace:ready",
             Event::WorkspaceFailed { .. } => "workspace:failed",
@@ -680,6 +1018,15 @@ impl Event {
             Event::CronOnce { .. } => "cron:once",
             Event::CronFired { .. } => "cron:fired",
             Event::CronDeleted { .. } => "cron:deleted",
+            Event::WatchStarted { .. } => "watch:started",
+            Event::WatchStopped { .. } => "watch:stopped",
+            Event::WatchChanged { .. } => "watch:changed",
+            Event::WatchFired { .. } => "watch:fired",
+            Event::GitTriggerStarted { .. } => "git_trigger:started",
+            Event::GitTriggerStopped { .. } => "git_trigger:stopped",
+            Event::GitRefsScanned { .. } => "git_trigger:scanned",
+            Event::GitRefsBaselined { .. } => "git_trigger:baselined",
+            Event::GitRefMoved { .. } => "git_trigger:ref_moved",
             Event::WorkerStarted { .. } => "worker:started",
             Event::WorkerWake { .. } => "worker:wake",
             Event::WorkerPollComplete { .. } => "worker:poll_complete",
@@ -695,6 +1042,13 @@ impl Event {
             Event::QueueDropped { .. } => "queue:dropped",
             Event::QueueItemRetry { .. } => "queue:item_retry",
             Event::QueueItemDead { .. } => "queue:item_dead",
+            Event::QueueItemPrioritized { .. } => "queue:item_prioritized",
+            Event::QueueItemMerged { .. } => "queue:item_merged",
+            Event::QueueItemExpired { .. } => "queue:item_expired",
+            Event::QueueItemLeased { .. } => "queue:leased",
+            Event::QueueLeaseExpired { .. } => "queue:lease_expired",
+            Event::LockWaiting { .. } => "lock:waiting",
+            Event::LockAcquired { .. } => "lock:acquired",
             Event::DecisionCreated { .. } => "decision:created",
             Event::DecisionResolved { .. } => "decision:resolved",
             Event::AgentRunCreated { .. } => "agent_run:created",
@@ -725,6 +1079,12 @@ impl Event {
                 prompt_type,
                 ..
             } => format!("{t} agent={agent_id} prompt_type={prompt_type:?}"),
+            Event::AgentUsage {
+                agent_id, usage, ..
+            } => format!("{t} agent={agent_id} tokens={}", usage.budget_tokens()),
+            Event::AgentBudgetExceeded {
+                agent_id, scope, ..
+            } => format!("{t} agent={agent_id} scope={scope}"),
             Event::CommandRun {
                 job_id,
                 command,
@@ -750,6 +1110,11 @@ impl Event {
                     format!("{t} id={id} ns={namespace} kind={kind} name={name}")
                 }
             }
+            Event::JobBlocked { id, waiting_on } => {
+                format!("{t} id={id} on={}", waiting_on.join(","))
+            }
+            Event::JobQueued { id } => format!("{t} id={id}"),
+            Event::JobAdmitted { id } => format!("{t} id={id}"),
             Event::JobAdvanced { id, step } => format!("{t} id={id} step={step}"),
             Event::JobUpdated { id, .. } => format!("{t} id={id}"),
             Event::JobResume { id, .. } => format!("{t} id={id}"),
@@ -786,16 +1151,41 @@ impl Event {
                 job_id,
                 step,
                 exit_code,
+                timed_out,
                 ..
-            } => format!("{t} job={job_id} step={step} exit={exit_code}"),
+            } => {
+                if *timed_out {
+                    format!("{t} job={job_id} step={step} timed_out")
+                } else {
+                    format!("{t} job={job_id} step={step} exit={exit_code}")
+                }
+            }
             Event::StepStarted { job_id, step, .. } => format!("{t} job={job_id} step={step}"),
             Event::StepWaiting { job_id, step, .. } => format!("{t} job={job_id} step={step}"),
             Event::StepCompleted { job_id, step } => {
                 format!("{t} job={job_id} step={step}")
             }
             Event::StepFailed { job_id, step, .. } => format!("{t} job={job_id} step={step}"),
+            Event::StepRetrying {
+                job_id,
+                step,
+                attempt,
+                ..
+            } => format!("{t} job={job_id} step={step} attempt={attempt}"),
+            Event::StepBranched {
+                job_id,
+                step,
+                branch,
+                branch_job_id,
+            } => format!("{t} job={job_id} step={step} branch={branch} branch_job={branch_job_id}"),
+            Event::StepDelegated {
+                job_id,
+                step,
+                child_job_id,
+            } => format!("{t} job={job_id} step={step} child_job={child_job_id}"),
             Event::Shutdown | Event::Custom => t.to_string(),
-            Event::TimerStart { id } => format!("{t} id={id}"),
+            Event::TimerStart { id } | Event::TimerCancelled { id } => format!("{t} id={id}"),
+            Event::TimerScheduled { id, fires_at_ms } => format!("{t} id={id} at={fires_at_ms}"),
             Event::WorkspaceCreated { id, .. } => format!("{t} id={id}"),
             Event::WorkspaceReady { id }
             | Event::WorkspaceFailed { id, .. }
@@ -837,6 +1227,46 @@ impl Event {
                     format!("{t} cron={cron_name} ns={namespace}")
                 }
             }
+            Event::WatchStarted { watch_name, .. } | Event::WatchStopped { watch_name, .. } => {
+                format!("{t} watch={watch_name}")
+            }
+            Event::WatchChanged {
+                watch_name, files, ..
+            } => format!("{t} watch={watch_name} files={}", files.len()),
+            Event::WatchFired {
+                watch_name, job_id, ..
+            } => format!("{t} watch={watch_name} job={job_id}"),
+            Event::GitTriggerStarted { trigger_name, .. }
+            | Event::GitTriggerStopped { trigger_name, .. } => {
+                format!("{t} git_trigger={trigger_name}")
+            }
+            Event::GitRefsScanned {
+                trigger_name, refs, ..
+            }
+            | Event::GitRefsBaselined {
+                trigger_name, refs, ..
+            } => format!("{t} git_trigger={trigger_name} refs={}", refs.len()),
+            Event::GitRefMoved {
+                trigger_name,
+                ref_name,
+                new,
+                job_id,
+                ..
+            } => {
+                let new = if new.is_empty() {
+                    "deleted"
+                } else {
+                    &new[..new.len().min(8)]
+                };
+                match job_id {
+                    Some(job_id) => {
+                        format!(
+                            "{t} git_trigger={trigger_name} ref={ref_name} new={new} job={job_id}"
+                        )
+                    }
+                    None => format!("{t} git_trigger={trigger_name} ref={ref_name} new={new}"),
+                }
+            }
             Event::WorkerStarted { worker_name, .. } => {
                 format!("{t} worker={worker_name}")
             }
@@ -854,19 +1284,31 @@ impl Event {
                 worker_name,
                 item_id,
                 job_id,
+                agent_run_id,
                 ..
-            } => format!("{t} worker={worker_name} item={item_id} job={job_id}"),
+            } => {
+                if let Some(ar_id) = agent_run_id {
+                    format!("{t} worker={worker_name} item={item_id} agent_run={ar_id}")
+                } else {
+                    format!("{t} worker={worker_name} item={item_id} job={job_id}")
+                }
+            }
             Event::WorkerStopped { worker_name, .. } => format!("{t} worker={worker_name}"),
             Event::WorkerResized {
                 worker_name,
                 concurrency,
+                rate,
                 namespace,
             } => {
-                if namespace.is_empty() {
+                let mut summary = if namespace.is_empty() {
                     format!("{t} worker={worker_name} concurrency={concurrency}")
                 } else {
                     format!("{t} worker={worker_name} ns={namespace} concurrency={concurrency}")
+                };
+                if let Some(rate) = rate {
+                    summary.push_str(&format!(" rate={rate}"));
                 }
+                summary
             }
             Event::WorkerDeleted {
                 worker_name,
@@ -881,8 +1323,27 @@ impl Event {
             Event::QueuePushed {
                 queue_name,
                 item_id,
+                priority,
+                visible_after_epoch_ms,
+                dedup_key,
+                expires_at_epoch_ms,
                 ..
-            } => format!("{t} queue={queue_name} item={item_id}"),
+            } => {
+                let mut s = format!("{t} queue={queue_name} item={item_id}");
+                if *priority != 0 {
+                    s.push_str(&format!(" priority={priority}"));
+                }
+                if let Some(at) = visible_after_epoch_ms {
+                    s.push_str(&format!(" visible_after={at}"));
+                }
+                if let Some(key) = dedup_key {
+                    s.push_str(&format!(" dedup_key={key}"));
+                }
+                if let Some(at) = expires_at_epoch_ms {
+                    s.push_str(&format!(" expires_at={at}"));
+                }
+                s
+            }
             Event::QueueTaken {
                 queue_name,
                 item_id,
@@ -913,6 +1374,48 @@ impl Event {
                 item_id,
                 ..
             } => format!("{t} queue={queue_name} item={item_id}"),
+            Event::QueueItemPrioritized {
+                queue_name,
+                item_id,
+                priority,
+                ..
+            } => format!("{t} queue={queue_name} item={item_id} priority={priority}"),
+            Event::QueueItemMerged {
+                queue_name,
+                item_id,
+                ..
+            }
+            | Event::QueueItemExpired {
+                queue_name,
+                item_id,
+                ..
+            }
+            | Event::QueueLeaseExpired {
+                queue_name,
+                item_id,
+                ..
+            } => format!("{t} queue={queue_name} item={item_id}"),
+            Event::QueueItemLeased {
+                queue_name,
+                item_id,
+                expires_at_epoch_ms,
+                ..
+            } => format!("{t} queue={queue_name} item={item_id} until={expires_at_epoch_ms}"),
+            Event::LockWaiting {
+                job_id,
+                step,
+                resources,
+                ..
+            }
+            | Event::LockAcquired {
+                job_id,
+                step,
+                resources,
+                ..
+            } => format!(
+                "{t} job={job_id} step={step} resources={}",
+                resources.join(",")
+            ),
             Event::DecisionCreated {
                 id,
                 job_id,
@@ -974,15 +1477,35 @@ impl Event {
             | Event::StepStarted { job_id, .. }
             | Event::StepWaiting { job_id, .. }
             | Event::StepCompleted { job_id, .. }
-            | Event::StepFailed { job_id, .. } => Some(job_id),
+            | Event::StepFailed { job_id, .. }
+            | Event::StepRetrying { job_id, .. }
+            | Event::StepBranched { job_id, .. }
+            | Event::StepDelegated { job_id, .. }
+            | Event::LockWaiting { job_id, .. }
+            | Event::LockAcquired { job_id, .. }
+            | Event::WatchFired { job_id, .. } => Some(job_id),
+            Event::GitRefMoved { job_id, .. } => job_id.as_ref(),
             Event::JobCreated { id, .. }
+            | Event::JobBlocked { id, .. }
+            | Event::JobQueued { id }
+            | Event::JobAdmitted { id }
             | Event::JobAdvanced { id, .. }
             | Event::JobUpdated { id, .. }
             | Event::JobResume { id, .. }
             | Event::JobCancelling { id, .. }
             | Event::JobCancel { id, .. }
             | Event::JobDeleted { id, .. } => Some(id),
-            Event::WorkerItemDispatched { job_id, .. } => Some(job_id),
+            Event::WorkerItemDispatched {
+                job_id,
+                agent_run_id,
+                ..
+            } => {
+                if agent_run_id.is_some() {
+                    None
+                } else {
+                    Some(job_id)
+                }
+            }
             Event::CronOnce {
                 job_id, agent_name, ..
             } => {
@@ -1011,10 +1534,12 @@ impl Event {
                     Some(job_id)
                 }
             }
-            Event::SessionCreated { owner, .. } => match owner {
-                OwnerId::Job(id) => Some(id),
-                OwnerId::AgentRun(_) => None,
-            },
+            Event::SessionCreated { owner, .. } | Event::AgentBudgetExceeded { owner, .. } => {
+                match owner {
+                    OwnerId::Job(id) => Some(id),
+                    OwnerId::AgentRun(_) => None,
+                }
+            }
             _ => None,
         }
     }
@@ -118,6 +118,8 @@ fn log_summary_command_run_no_namespace() {
         command: "build".to_string(),
         args: HashMap::new(),
         namespace: String::new(),
+        after: vec![],
+        priority: 0,
     };
     assert_eq!(event.log_summary(), "command:run id=j1 cmd=build");
 }
@@ -132,6 +134,8 @@ fn log_summary_command_run_with_namespace() {
         command: "deploy".to_string(),
         args: HashMap::new(),
         namespace: "myns".to_string(),
+        after: vec![],
+        priority: 0,
     };
     assert_eq!(event.log_summary(), "command:run id=j1 ns=myns cmd=deploy");
 }
@@ -149,6 +153,9 @@ fn log_summary_job_created_no_namespace() {
         created_at_epoch_ms: 0,
         namespace: String::new(),
         cron_name: None,
+        after: vec![],
+        priority: 0,
+        project_root: None,
     };
     assert_eq!(
         event.log_summary(),
@@ -169,6 +176,9 @@ fn log_summary_job_created_with_namespace() {
         created_at_epoch_ms: 0,
         namespace: "prod".to_string(),
         cron_name: None,
+        after: vec![],
+        priority: 0,
+        project_root: None,
     };
     assert_eq!(
         event.log_summary(),
@@ -309,10 +319,27 @@ fn log_summary_shell_exited() {
         exit_code: 42,
         stdout: None,
         stderr: None,
+        timed_out: false,
     };
     assert_eq!(event.log_summary(), "shell:exited job=j1 step=init exit=42");
 }
 
+#[test]
+fn log_summary_shell_timed_out() {
+    let event = Event::ShellExited {
+        job_id: JobId::new("j1"),
+        step: "test".to_string(),
+        exit_code: -1,
+        stdout: None,
+        stderr: None,
+        timed_out: true,
+    };
+    assert_eq!(
+        event.log_summary(),
+        "shell:exited job=j1 step=test timed_out"
+    );
+}
+
 #[test]
 fn log_summary_step_events() {
     assert_eq!(
@@ -352,6 +379,16 @@ fn log_summary_step_events() {
         .log_summary(),
         "step:failed job=j1 step=test"
     );
+    assert_eq!(
+        Event::StepRetrying {
+            job_id: JobId::new("j1"),
+            step: "fetch".to_string(),
+            attempt: 2,
+            error: "shell exit code: 1".to_string(),
+        }
+        .log_summary(),
+        "step:retrying job=j1 step=fetch attempt=2"
+    );
 }
 
 #[test]
@@ -359,3 +396,49 @@ fn log_summary_shutdown_and_custom() {
     assert_eq!(Event::Shutdown.log_summary(), "system:shutdown");
     assert_eq!(Event::Custom.log_summary(), "custom");
 }
+
+#[test]
+fn log_summary_job_blocked() {
+    let event = Event::JobBlocked {
+        id: JobId::new("j1"),
+        waiting_on: vec!["a1".to_string(), "b2".to_string()],
+    };
+    assert_eq!(event.log_summary(), "job:blocked id=j1 on=a1,b2");
+}
+
+#[test]
+fn log_summary_job_queued_and_admitted() {
+    let queued = Event::JobQueued {
+        id: JobId::new("j1"),
+    };
+    let admitted = Event::JobAdmitted {
+        id: JobId::new("j1"),
+    };
+    assert_eq!(queued.log_summary(), "job:queued id=j1");
+    assert_eq!(admitted.log_summary(), "job:admitted id=j1");
+}
+
+#[test]
+fn log_summary_agent_usage_and_budget_exceeded() {
+    let usage = Event::AgentUsage {
+        agent_id: AgentId::new("a1"),
+        usage: TokenUsage {
+            input_tokens: 100,
+            output_tokens: 50,
+            cache_creation_input_tokens: 25,
+            cache_read_input_tokens: 9000,
+        },
+        model: None,
+    };
+    let exceeded = Event::AgentBudgetExceeded {
+        agent_id: AgentId::new("a1"),
+        owner: OwnerId::Job(JobId::new("j1")),
+        scope: BudgetScope::Agent,
+        reason: "over".to_string(),
+    };
+    assert_eq!(usage.log_summary(), "agent:usage agent=a1 tokens=175");
+    assert_eq!(
+        exceeded.log_summary(),
+        "agent:budget_exceeded agent=a1 scope=agent"
+    );
+}
@@ -14,6 +14,23 @@ fn log_summary_timer_start() {
     assert_eq!(event.log_summary(), "timer:start id=t1");
 }
 
+#[test]
+fn log_summary_timer_scheduled() {
+    let event = Event::TimerScheduled {
+        id: TimerId::new("t1"),
+        fires_at_ms: 5000,
+    };
+    assert_eq!(event.log_summary(), "timer:scheduled id=t1 at=5000");
+}
+
+#[test]
+fn log_summary_timer_cancelled() {
+    let event = Event::TimerCancelled {
+        id: TimerId::new("t1"),
+    };
+    assert_eq!(event.log_summary(), "timer:cancelled id=t1");
+}
+
 #[test]
 fn log_summary_workspace_events() {
     assert_eq!(
@@ -66,8 +83,12 @@ fn log_summary_cron_started_stopped() {
             project_root: PathBuf::from("/proj"),
             runbook_hash: "abc".to_string(),
             interval: "1h".to_string(),
+            schedule: None,
+            timezone: None,
             run_target: "job:build".to_string(),
             namespace: String::new(),
+            started_at_ms: 1_000,
+            resumed_from_ms: None,
         }
         .log_summary(),
         "cron:started cron=nightly"
@@ -123,6 +144,7 @@ fn log_summary_cron_fired_job() {
         job_id: JobId::new("j1"),
         agent_run_id: None,
         namespace: String::new(),
+        fired_at_ms: 2_000,
     };
     assert_eq!(event.log_summary(), "cron:fired cron=nightly job=j1");
 }
@@ -134,6 +156,7 @@ fn log_summary_cron_fired_agent_run() {
         job_id: JobId::default(),
         agent_run_id: Some("ar1".to_string()),
         namespace: String::new(),
+        fired_at_ms: 2_000,
     };
     assert_eq!(event.log_summary(), "cron:fired cron=nightly agent_run=ar1");
 }
@@ -156,6 +179,75 @@ fn log_summary_cron_deleted_with_namespace() {
     assert_eq!(event.log_summary(), "cron:deleted cron=nightly ns=prod");
 }
 
+#[test]
+fn log_summary_watch_events() {
+    assert_eq!(
+        Event::WatchStopped {
+            watch_name: "specs".to_string(),
+            namespace: String::new(),
+        }
+        .log_summary(),
+        "watch:stopped watch=specs"
+    );
+    assert_eq!(
+        Event::WatchChanged {
+            watch_name: "specs".to_string(),
+            namespace: String::new(),
+            files: vec!["a.md".to_string(), "b.md".to_string()],
+        }
+        .log_summary(),
+        "watch:changed watch=specs files=2"
+    );
+    assert_eq!(
+        Event::WatchFired {
+            watch_name: "specs".to_string(),
+            job_id: JobId::new("j1"),
+            namespace: String::new(),
+        }
+        .log_summary(),
+        "watch:fired watch=specs job=j1"
+    );
+}
+
+#[test]
+fn log_summary_git_trigger_events() {
+    let refs: std::collections::BTreeMap<String, String> =
+        [("refs/heads/main".to_string(), "a".repeat(40))].into();
+    assert_eq!(
+        Event::GitRefsScanned {
+            trigger_name: "ci".to_string(),
+            namespace: String::new(),
+            refs,
+        }
+        .log_summary(),
+        "git_trigger:scanned git_trigger=ci refs=1"
+    );
+    assert_eq!(
+        Event::GitRefMoved {
+            trigger_name: "ci".to_string(),
+            namespace: String::new(),
+            ref_name: "refs/heads/main".to_string(),
+            old: "a".repeat(40),
+            new: "0123456789abcdef".to_string(),
+            job_id: Some(JobId::new("j1")),
+        }
+        .log_summary(),
+        "git_trigger:ref_moved git_trigger=ci ref=refs/heads/main new=01234567 job=j1"
+    );
+    assert_eq!(
+        Event::GitRefMoved {
+            trigger_name: "ci".to_string(),
+            namespace: String::new(),
+            ref_name: "refs/heads/old".to_string(),
+            old: "a".repeat(40),
+            new: String::new(),
+            job_id: None,
+        }
+        .log_summary(),
+        "git_trigger:ref_moved git_trigger=ci ref=refs/heads/old new=deleted"
+    );
+}
+
 #[test]
 fn log_summary_worker_events() {
     assert_eq!(
@@ -164,7 +256,9 @@ fn log_summary_worker_events() {
             project_root: PathBuf::from("/proj"),
             runbook_hash: "abc".to_string(),
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency: 2,
+            rate: None,
             namespace: String::new(),
         }
         .log_summary(),
@@ -224,12 +318,28 @@ fn log_summary_worker_item_dispatched() {
         worker_name: "fixer".to_string(),
         item_id: "item-1".to_string(),
         job_id: JobId::new("j1"),
+        agent_run_id: None,
+        bucket_full_at_ms: None,
         namespace: String::new(),
     };
     assert_eq!(
         event.log_summary(),
         "worker:item_dispatched worker=fixer item=item-1 job=j1"
     );
+
+    let event = Event::WorkerItemDispatched {
+        worker_name: "triage".to_string(),
+        item_id: "item-1".to_string(),
+        job_id: JobId::new(""),
+        agent_run_id: Some("ar-1".to_string()),
+        bucket_full_at_ms: None,
+        namespace: String::new(),
+    };
+    assert_eq!(
+        event.log_summary(),
+        "worker:item_dispatched worker=triage item=item-1 agent_run=ar-1"
+    );
+    assert_eq!(event.job_id(), None);
 }
 
 #[test]
@@ -237,6 +347,7 @@ fn log_summary_worker_resized_no_namespace() {
     let event = Event::WorkerResized {
         worker_name: "fixer".to_string(),
         concurrency: 4,
+        rate: None,
         namespace: String::new(),
     };
     assert_eq!(
@@ -250,6 +361,7 @@ fn log_summary_worker_resized_with_namespace() {
     let event = Event::WorkerResized {
         worker_name: "fixer".to_string(),
         concurrency: 4,
+        rate: None,
         namespace: "prod".to_string(),
     };
     assert_eq!(
@@ -258,6 +370,20 @@ fn log_summary_worker_resized_with_namespace() {
     );
 }
 
+#[test]
+fn log_summary_worker_resized_with_rate() {
+    let event = Event::WorkerResized {
+        worker_name: "fixer".to_string(),
+        concurrency: 2,
+        rate: Some("10/h".to_string()),
+        namespace: String::new(),
+    };
+    assert_eq!(
+        event.log_summary(),
+        "worker:resized worker=fixer concurrency=2 rate=10/h"
+    );
+}
+
 #[test]
 fn log_summary_worker_deleted_no_namespace() {
     let event = Event::WorkerDeleted {
@@ -285,10 +411,47 @@ fn log_summary_queue_events() {
             data: HashMap::new(),
             pushed_at_epoch_ms: 0,
             namespace: String::new(),
+            priority: 0,
+            visible_after_epoch_ms: None,
+            dedup_key: None,
+            expires_at_epoch_ms: None,
+            drop_on_expire: false,
         }
         .log_summary(),
         "queue:pushed queue=bugs item=i1"
     );
+    assert_eq!(
+        Event::QueuePushed {
+            queue_name: "bugs".to_string(),
+            item_id: "i1".to_string(),
+            data: HashMap::new(),
+            pushed_at_epoch_ms: 0,
+            namespace: String::new(),
+            priority: 2,
+            visible_after_epoch_ms: Some(5000),
+            dedup_key: None,
+            expires_at_epoch_ms: None,
+            drop_on_expire: false,
+        }
+        .log_summary(),
+        "queue:pushed queue=bugs item=i1 priority=2 visible_after=5000"
+    );
+    assert_eq!(
+        Event::QueuePushed {
+            queue_name: "bugs".to_string(),
+            item_id: "i1".to_string(),
+            data: HashMap::new(),
+            pushed_at_epoch_ms: 0,
+            namespace: String::new(),
+            priority: 0,
+            visible_after_epoch_ms: None,
+            dedup_key: None,
+            expires_at_epoch_ms: Some(9000),
+            drop_on_expire: false,
+        }
+        .log_summary(),
+        "queue:pushed queue=bugs item=i1 expires_at=9000"
+    );
     assert_eq!(
         Event::QueueTaken {
             queue_name: "bugs".to_string(),
@@ -304,6 +467,7 @@ fn log_summary_queue_events() {
             queue_name: "bugs".to_string(),
             item_id: "i1".to_string(),
             namespace: String::new(),
+            completed_at_epoch_ms: None,
         }
         .log_summary(),
         "queue:completed queue=bugs item=i1"
@@ -332,6 +496,7 @@ fn log_summary_queue_events() {
             queue_name: "bugs".to_string(),
             item_id: "i1".to_string(),
             namespace: String::new(),
+            automatic: false,
         }
         .log_summary(),
         "queue:item_retry queue=bugs item=i1"
@@ -345,6 +510,55 @@ fn log_summary_queue_events() {
         .log_summary(),
         "queue:item_dead queue=bugs item=i1"
     );
+    assert_eq!(
+        Event::QueueItemPrioritized {
+            queue_name: "bugs".to_string(),
+            item_id: "i1".to_string(),
+            priority: 3,
+            namespace: String::new(),
+        }
+        .log_summary(),
+        "queue:item_prioritized queue=bugs item=i1 priority=3"
+    );
+    assert_eq!(
+        Event::QueueItemMerged {
+            queue_name: "bugs".to_string(),
+            item_id: "i1".to_string(),
+            data: HashMap::new(),
+            priority: 0,
+            namespace: String::new(),
+        }
+        .log_summary(),
+        "queue:item_merged queue=bugs item=i1"
+    );
+    assert_eq!(
+        Event::QueueItemExpired {
+            queue_name: "bugs".to_string(),
+            item_id: "i1".to_string(),
+            namespace: String::new(),
+        }
+        .log_summary(),
+        "queue:item_expired queue=bugs item=i1"
+    );
+    assert_eq!(
+        Event::QueueItemLeased {
+            queue_name: "bugs".to_string(),
+            item_id: "i1".to_string(),
+            expires_at_epoch_ms: 7_200_000,
+            namespace: String::new(),
+        }
+        .log_summary(),
+        "queue:leased queue=bugs item=i1 until=7200000"
+    );
+    assert_eq!(
+        Event::QueueLeaseExpired {
+            queue_name: "bugs".to_string(),
+            item_id: "i1".to_string(),
+            namespace: String::new(),
+        }
+        .log_summary(),
+        "queue:lease_expired queue=bugs item=i1"
+    );
 }
 
 #[test]
@@ -359,6 +573,8 @@ fn log_summary_decision_created_job_owner() {
         options: vec![],
         created_at_ms: 0,
         namespace: String::new(),
+        step: None,
+        fields: Vec::new(),
     };
     assert_eq!(
         event.log_summary(),
@@ -380,6 +596,8 @@ fn log_summary_decision_created_agent_run_owner() {
         options: vec![],
         created_at_ms: 0,
         namespace: String::new(),
+        step: None,
+        fields: Vec::new(),
     };
     assert_eq!(
         event.log_summary(),
@@ -395,6 +613,7 @@ fn log_summary_decision_resolved_with_chosen() {
         message: None,
         resolved_at_ms: 0,
         namespace: String::new(),
+        values: HashMap::new(),
     };
     assert_eq!(event.log_summary(), "decision:resolved id=d1 chosen=2");
 }
@@ -407,6 +626,7 @@ fn log_summary_decision_resolved_no_chosen() {
         message: Some("custom".to_string()),
         resolved_at_ms: 0,
         namespace: String::new(),
+        values: HashMap::new(),
     };
     assert_eq!(event.log_summary(), "decision:resolved id=d1");
 }
@@ -528,3 +748,44 @@ fn log_summary_agent_run_resume_bare() {
     };
     assert_eq!(event.log_summary(), "agent_run:resume id=ar1");
 }
+
+#[test]
+fn log_summary_lock_waiting() {
+    let event = Event::LockWaiting {
+        job_id: JobId::new("j1"),
+        step: "merge".to_string(),
+        resources: vec!["lock.main".to_string(), "semaphore.gpu".to_string()],
+        namespace: String::new(),
+    };
+    assert_eq!(
+        event.log_summary(),
+        "lock:waiting job=j1 step=merge resources=lock.main,semaphore.gpu"
+    );
+}
+
+#[test]
+fn log_summary_step_branched() {
+    let event = Event::StepBranched {
+        job_id: JobId::new("j1"),
+        step: "checks".to_string(),
+        branch: "lint".to_string(),
+        branch_job_id: JobId::new("j2"),
+    };
+    assert_eq!(
+        event.log_summary(),
+        "step:branched job=j1 step=checks branch=lint branch_job=j2"
+    );
+}
+
+#[test]
+fn log_summary_step_delegated() {
+    let event = Event::StepDelegated {
+        job_id: JobId::new("j1"),
+        step: "merge".to_string(),
+        child_job_id: JobId::new("j2"),
+    };
+    assert_eq!(
+        event.log_summary(),
+        "step:delegated job=j1 step=merge child_job=j2"
+    );
+}
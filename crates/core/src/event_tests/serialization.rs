@@ -20,6 +20,8 @@ fn event_serialization_roundtrip() {
                 .into_iter()
                 .collect(),
             namespace: String::new(),
+            after: vec![],
+            priority: 0,
         },
         Event::AgentWaiting {
             agent_id: AgentId::new("agent-1"),
@@ -36,6 +38,28 @@ fn event_serialization_roundtrip() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
+        },
+        Event::ShellExited {
+            job_id: JobId::new("pipe-1"),
+            step: "test".to_string(),
+            exit_code: -1,
+            stdout: None,
+            stderr: None,
+            timed_out: true,
+        },
+        Event::StepRetrying {
+            job_id: JobId::new("pipe-1"),
+            step: "test".to_string(),
+            attempt: 1,
+            error: "shell exit code: 1".to_string(),
+        },
+        Event::TimerScheduled {
+            id: TimerId::new("step-retry:pipe-1"),
+            fires_at_ms: 1_000,
+        },
+        Event::TimerCancelled {
+            id: TimerId::new("step-retry:pipe-1"),
         },
     ];
 
@@ -143,6 +167,9 @@ fn event_job_created_roundtrip() {
         created_at_epoch_ms: 1_000_000,
         namespace: String::new(),
         cron_name: None,
+        after: vec![],
+        priority: 0,
+        project_root: None,
     };
     let json: serde_json::Value = serde_json::to_value(&event).unwrap();
     assert_eq!(json["type"], "job:created");
@@ -236,6 +263,125 @@ fn event_job_resume_roundtrip() {
     assert_roundtrip(&event);
 }
 
+#[test]
+fn event_job_blocked_roundtrip() {
+    let event = Event::JobBlocked {
+        id: JobId::new("pipe-2"),
+        waiting_on: vec!["pipe-1".to_string()],
+    };
+    let json: serde_json::Value = serde_json::to_value(&event).unwrap();
+    assert_eq!(json["type"], "job:blocked");
+    assert_eq!(json["waiting_on"][0], "pipe-1");
+
+    assert_roundtrip(&event);
+}
+
+#[test]
+fn event_job_queued_and_admitted_roundtrip() {
+    let queued = Event::JobQueued {
+        id: JobId::new("pipe-3"),
+    };
+    let json: serde_json::Value = serde_json::to_value(&queued).unwrap();
+    assert_eq!(json["type"], "job:queued");
+    assert_roundtrip(&queued);
+
+    let admitted = Event::JobAdmitted {
+        id: JobId::new("pipe-3"),
+    };
+    let json: serde_json::Value = serde_json::to_value(&admitted).unwrap();
+    assert_eq!(json["type"], "job:admitted");
+    assert_roundtrip(&admitted);
+}
+
+#[test]
+fn event_agent_usage_and_budget_exceeded_roundtrip() {
+    let usage = Event::AgentUsage {
+        agent_id: AgentId::new("a1"),
+        usage: TokenUsage {
+            input_tokens: 10,
+            output_tokens: 20,
+            cache_creation_input_tokens: 30,
+            cache_read_input_tokens: 40,
+        },
+        model: Some("claude-sonnet-4-5-20250929".to_string()),
+    };
+    let json: serde_json::Value = serde_json::to_value(&usage).unwrap();
+    assert_eq!(json["type"], "agent:usage");
+    assert_eq!(json["usage"]["output_tokens"], 20);
+    assert_roundtrip(&usage);
+
+    let exceeded = Event::AgentBudgetExceeded {
+        agent_id: AgentId::new("a1"),
+        owner: OwnerId::Job(JobId::new("pipe-1")),
+        scope: BudgetScope::Job,
+        reason: "2100000 tokens exceeds the budget of 2000000".to_string(),
+    };
+    let json: serde_json::Value = serde_json::to_value(&exceeded).unwrap();
+    assert_eq!(json["type"], "agent:budget_exceeded");
+    assert_eq!(json["scope"], "job");
+    assert_roundtrip(&exceeded);
+    assert_eq!(exceeded.job_id(), Some(&JobId::new("pipe-1")));
+}
+
+#[test]
+fn event_cron_started_schedule_roundtrip() {
+    let started = Event::CronStarted {
+        cron_name: "nightly".to_string(),
+        project_root: PathBuf::from("/proj"),
+        runbook_hash: "abc".to_string(),
+        interval: String::new(),
+        schedule: Some("0 9 * * 1-5".to_string()),
+        timezone: Some("America/New_York".to_string()),
+        run_target: "job:triage".to_string(),
+        namespace: String::new(),
+        started_at_ms: 1_000,
+        resumed_from_ms: None,
+    };
+    let json: serde_json::Value = serde_json::to_value(&started).unwrap();
+    assert_eq!(json["schedule"], "0 9 * * 1-5");
+    assert_roundtrip(&started);
+
+    // Interval crons written before schedules existed still load
+    let old = r#"{"type":"cron:started","cron_name":"janitor","project_root":"/proj","runbook_hash":"abc","interval":"30m","run_target":"job:cleanup"}"#;
+    match serde_json::from_str::<Event>(old).unwrap() {
+        Event::CronStarted {
+            interval, schedule, ..
+        } => {
+            assert_eq!(interval, "30m");
+            assert_eq!(schedule, None);
+        }
+        other => panic!("unexpected event: {:?}", other),
+    }
+}
+
+#[test]
+fn event_cron_started_resumed_from_roundtrip() {
+    let resumed = Event::CronStarted {
+        cron_name: "janitor".to_string(),
+        project_root: PathBuf::from("/proj"),
+        runbook_hash: "abc".to_string(),
+        interval: "30m".to_string(),
+        schedule: None,
+        timezone: None,
+        run_target: "job:cleanup".to_string(),
+        namespace: String::new(),
+        started_at_ms: 1_000,
+        resumed_from_ms: Some(1_000),
+    };
+    let json: serde_json::Value = serde_json::to_value(&resumed).unwrap();
+    assert_eq!(json["resumed_from_ms"], 1_000);
+    assert_roundtrip(&resumed);
+
+    // Events from `oj cron start` leave it out
+    let started = r#"{"type":"cron:started","cron_name":"janitor","project_root":"/proj","runbook_hash":"abc","interval":"30m","run_target":"job:cleanup"}"#;
+    match serde_json::from_str::<Event>(started).unwrap() {
+        Event::CronStarted {
+            resumed_from_ms, ..
+        } => assert_eq!(resumed_from_ms, None),
+        other => panic!("unexpected event: {:?}", other),
+    }
+}
+
 #[test]
 fn event_job_resume_no_message_roundtrip() {
     let event = Event::JobResume {
@@ -305,6 +451,8 @@ fn event_job_id_returns_id_for_job_events() {
                 command: "build".to_string(),
                 args: HashMap::new(),
                 namespace: String::new(),
+                after: vec![],
+                priority: 0,
             },
             JobId::new("p1"),
         ),
@@ -320,9 +468,31 @@ fn event_job_id_returns_id_for_job_events() {
                 created_at_epoch_ms: 1_000_000,
                 namespace: String::new(),
                 cron_name: None,
+                after: vec![],
+                priority: 0,
+                project_root: None,
             },
             JobId::new("p6"),
         ),
+        (
+            Event::JobBlocked {
+                id: JobId::new("p7"),
+                waiting_on: vec!["p6".to_string()],
+            },
+            JobId::new("p7"),
+        ),
+        (
+            Event::JobQueued {
+                id: JobId::new("p8"),
+            },
+            JobId::new("p8"),
+        ),
+        (
+            Event::JobAdmitted {
+                id: JobId::new("p8"),
+            },
+            JobId::new("p8"),
+        ),
     ];
 
     for (event, expected_id) in cases {
@@ -20,6 +20,11 @@ fn event_queue_pushed_roundtrip() {
             .collect(),
         pushed_at_epoch_ms: 1_000_000,
         namespace: String::new(),
+        priority: 0,
+        visible_after_epoch_ms: None,
+        dedup_key: None,
+        expires_at_epoch_ms: None,
+        drop_on_expire: false,
     };
     let json: serde_json::Value = serde_json::to_value(&event).unwrap();
     assert_eq!(json["type"], "queue:pushed");
@@ -50,6 +55,7 @@ fn event_queue_completed_roundtrip() {
         queue_name: "bugs".to_string(),
         item_id: "item-1".to_string(),
         namespace: String::new(),
+        completed_at_epoch_ms: None,
     };
     let json: serde_json::Value = serde_json::to_value(&event).unwrap();
     assert_eq!(json["type"], "queue:completed");
@@ -81,6 +87,11 @@ fn event_queue_name_returns_correct_strings() {
             data: HashMap::new(),
             pushed_at_epoch_ms: 0,
             namespace: String::new(),
+            priority: 0,
+            visible_after_epoch_ms: None,
+            dedup_key: None,
+            expires_at_epoch_ms: None,
+            drop_on_expire: false,
         }
         .name(),
         "queue:pushed"
@@ -100,6 +111,7 @@ fn event_queue_name_returns_correct_strings() {
             queue_name: "q".to_string(),
             item_id: "i".to_string(),
             namespace: String::new(),
+            completed_at_epoch_ms: None,
         }
         .name(),
         "queue:completed"
@@ -119,6 +131,7 @@ fn event_queue_name_returns_correct_strings() {
             queue_name: "q".to_string(),
             item_id: "i".to_string(),
             namespace: String::new(),
+            automatic: false,
         }
         .name(),
         "queue:item_retry"
@@ -132,6 +145,16 @@ fn event_queue_name_returns_correct_strings() {
         .name(),
         "queue:item_dead"
     );
+    assert_eq!(
+        Event::QueueItemPrioritized {
+            queue_name: "q".to_string(),
+            item_id: "i".to_string(),
+            priority: 1,
+            namespace: String::new(),
+        }
+        .name(),
+        "queue:item_prioritized"
+    );
 }
 
 #[test]
@@ -140,14 +163,22 @@ fn event_queue_item_retry_roundtrip() {
         queue_name: "bugs".to_string(),
         item_id: "item-1".to_string(),
         namespace: "myns".to_string(),
+        automatic: false,
     };
     let json: serde_json::Value = serde_json::to_value(&event).expect("serialize");
     assert_eq!(json["type"], "queue:item_retry");
     assert_eq!(json["queue_name"], "bugs");
     assert_eq!(json["item_id"], "item-1");
     assert_eq!(json["namespace"], "myns");
+    assert!(json.get("automatic").is_none());
 
     assert_roundtrip(&event);
+    assert_roundtrip(&Event::QueueItemRetry {
+        queue_name: "bugs".to_string(),
+        item_id: "item-1".to_string(),
+        namespace: "myns".to_string(),
+        automatic: true,
+    });
 }
 
 #[test]
@@ -164,6 +195,255 @@ fn event_queue_item_dead_roundtrip() {
     assert_roundtrip(&event);
 }
 
+#[test]
+fn event_queue_priority_roundtrip() {
+    let pushed = Event::QueuePushed {
+        queue_name: "bugs".to_string(),
+        item_id: "item-1".to_string(),
+        data: HashMap::new(),
+        pushed_at_epoch_ms: 1000,
+        namespace: String::new(),
+        priority: 0,
+        visible_after_epoch_ms: None,
+        dedup_key: None,
+        expires_at_epoch_ms: None,
+        drop_on_expire: false,
+    };
+    let json: serde_json::Value = serde_json::to_value(&pushed).expect("serialize");
+    assert!(json.get("priority").is_none());
+    assert_roundtrip(&pushed);
+
+    let event = Event::QueueItemPrioritized {
+        queue_name: "bugs".to_string(),
+        item_id: "item-1".to_string(),
+        priority: -2,
+        namespace: String::new(),
+    };
+    let json: serde_json::Value = serde_json::to_value(&event).expect("serialize");
+    assert_eq!(json["type"], "queue:item_prioritized");
+    assert_eq!(json["priority"], -2);
+    assert_roundtrip(&event);
+}
+
+#[test]
+fn event_queue_pushed_visible_after_roundtrip() {
+    let pushed = Event::QueuePushed {
+        queue_name: "merges".to_string(),
+        item_id: "item-1".to_string(),
+        data: HashMap::new(),
+        pushed_at_epoch_ms: 1000,
+        namespace: String::new(),
+        priority: 0,
+        visible_after_epoch_ms: Some(61_000),
+        dedup_key: None,
+        expires_at_epoch_ms: None,
+        drop_on_expire: false,
+    };
+    let json: serde_json::Value = serde_json::to_value(&pushed).expect("serialize");
+    assert_eq!(json["visible_after_epoch_ms"], 61_000);
+    assert_roundtrip(&pushed);
+
+    // Older WAL entries have no visibility time
+    let json = r#"{"type":"queue:pushed","queue_name":"merges","item_id":"i","data":{},"pushed_at_epoch_ms":1}"#;
+    let event: Event = serde_json::from_str(json).expect("deserialize");
+    assert!(matches!(
+        event,
+        Event::QueuePushed {
+            visible_after_epoch_ms: None,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn event_queue_dedup_roundtrip() {
+    let pushed = Event::QueuePushed {
+        queue_name: "issues".to_string(),
+        item_id: "item-1".to_string(),
+        data: HashMap::new(),
+        pushed_at_epoch_ms: 1000,
+        namespace: String::new(),
+        priority: 0,
+        visible_after_epoch_ms: None,
+        dedup_key: Some("42".to_string()),
+        expires_at_epoch_ms: None,
+        drop_on_expire: false,
+    };
+    let json: serde_json::Value = serde_json::to_value(&pushed).expect("serialize");
+    assert_eq!(json["dedup_key"], "42");
+    assert_roundtrip(&pushed);
+
+    let merged = Event::QueueItemMerged {
+        queue_name: "issues".to_string(),
+        item_id: "item-1".to_string(),
+        data: [("title".to_string(), "crash".to_string())]
+            .into_iter()
+            .collect(),
+        priority: 2,
+        namespace: String::new(),
+    };
+    let json: serde_json::Value = serde_json::to_value(&merged).expect("serialize");
+    assert_eq!(json["type"], "queue:item_merged");
+    assert_roundtrip(&merged);
+
+    let completed = Event::QueueCompleted {
+        queue_name: "issues".to_string(),
+        item_id: "item-1".to_string(),
+        namespace: String::new(),
+        completed_at_epoch_ms: Some(5000),
+    };
+    assert_roundtrip(&completed);
+}
+
+#[test]
+fn event_queue_expiry_roundtrip() {
+    let pushed = Event::QueuePushed {
+        queue_name: "inbox".to_string(),
+        item_id: "item-1".to_string(),
+        data: HashMap::new(),
+        pushed_at_epoch_ms: 1000,
+        namespace: String::new(),
+        priority: 0,
+        visible_after_epoch_ms: None,
+        dedup_key: None,
+        expires_at_epoch_ms: Some(86_401_000),
+        drop_on_expire: true,
+    };
+    let json: serde_json::Value = serde_json::to_value(&pushed).expect("serialize");
+    assert_eq!(json["expires_at_epoch_ms"], 86_401_000);
+    assert_eq!(json["drop_on_expire"], true);
+    assert_roundtrip(&pushed);
+
+    let expired = Event::QueueItemExpired {
+        queue_name: "inbox".to_string(),
+        item_id: "item-1".to_string(),
+        namespace: "proj".to_string(),
+    };
+    let json: serde_json::Value = serde_json::to_value(&expired).expect("serialize");
+    assert_eq!(json["type"], "queue:item_expired");
+    assert_eq!(expired.name(), "queue:item_expired");
+    assert_roundtrip(&expired);
+}
+
+#[test]
+fn event_queue_lease_roundtrip() {
+    let leased = Event::QueueItemLeased {
+        queue_name: "inbox".to_string(),
+        item_id: "item-1".to_string(),
+        expires_at_epoch_ms: 7_200_000,
+        namespace: "proj".to_string(),
+    };
+    let json: serde_json::Value = serde_json::to_value(&leased).expect("serialize");
+    assert_eq!(json["type"], "queue:leased");
+    assert_eq!(json["expires_at_epoch_ms"], 7_200_000);
+    assert_roundtrip(&leased);
+
+    let expired = Event::QueueLeaseExpired {
+        queue_name: "inbox".to_string(),
+        item_id: "item-1".to_string(),
+        namespace: "proj".to_string(),
+    };
+    assert_eq!(expired.name(), "queue:lease_expired");
+    assert_roundtrip(&expired);
+}
+
+#[test]
+fn event_watch_roundtrip() {
+    let started = Event::WatchStarted {
+        watch_name: "specs".to_string(),
+        project_root: PathBuf::from("/proj"),
+        runbook_hash: "abc".to_string(),
+        paths: vec!["docs/specs/**/*.md".to_string()],
+        debounce: "30s".to_string(),
+        run_target: "job:spec-review".to_string(),
+        namespace: "proj".to_string(),
+    };
+    let json: serde_json::Value = serde_json::to_value(&started).expect("serialize");
+    assert_eq!(json["type"], "watch:started");
+    assert_eq!(json["paths"][0], "docs/specs/**/*.md");
+    assert_roundtrip(&started);
+
+    let changed = Event::WatchChanged {
+        watch_name: "specs".to_string(),
+        namespace: "proj".to_string(),
+        files: vec!["docs/specs/a.md".to_string(), "docs/specs/b.md".to_string()],
+    };
+    assert_eq!(changed.name(), "watch:changed");
+    assert_roundtrip(&changed);
+
+    let fired = Event::WatchFired {
+        watch_name: "specs".to_string(),
+        job_id: JobId::new("j1"),
+        namespace: "proj".to_string(),
+    };
+    assert_eq!(fired.job_id(), Some(&JobId::new("j1")));
+    assert_roundtrip(&fired);
+
+    assert_roundtrip(&Event::WatchStopped {
+        watch_name: "specs".to_string(),
+        namespace: "proj".to_string(),
+    });
+}
+
+#[test]
+fn event_git_trigger_roundtrip() {
+    let started = Event::GitTriggerStarted {
+        trigger_name: "ci".to_string(),
+        project_root: PathBuf::from("/proj"),
+        runbook_hash: "abc".to_string(),
+        ref_pattern: "refs/heads/feature/*".to_string(),
+        run_target: "job:ci".to_string(),
+        namespace: "proj".to_string(),
+    };
+    let json: serde_json::Value = serde_json::to_value(&started).expect("serialize");
+    assert_eq!(json["type"], "git_trigger:started");
+    assert_eq!(json["ref_pattern"], "refs/heads/feature/*");
+    assert_roundtrip(&started);
+
+    let refs: std::collections::BTreeMap<String, String> =
+        [("refs/heads/feature/a".to_string(), "b".repeat(40))].into();
+    assert_roundtrip(&Event::GitRefsScanned {
+        trigger_name: "ci".to_string(),
+        namespace: "proj".to_string(),
+        refs: refs.clone(),
+    });
+    assert_roundtrip(&Event::GitRefsBaselined {
+        trigger_name: "ci".to_string(),
+        namespace: "proj".to_string(),
+        refs,
+    });
+
+    let moved = Event::GitRefMoved {
+        trigger_name: "ci".to_string(),
+        namespace: "proj".to_string(),
+        ref_name: "refs/heads/feature/a".to_string(),
+        old: "b".repeat(40),
+        new: "c".repeat(40),
+        job_id: Some(JobId::new("j1")),
+    };
+    assert_eq!(moved.job_id(), Some(&JobId::new("j1")));
+    assert_roundtrip(&moved);
+
+    // Moves without a job omit the field and read back as `None`
+    let deleted = Event::GitRefMoved {
+        trigger_name: "ci".to_string(),
+        namespace: "proj".to_string(),
+        ref_name: "refs/heads/feature/a".to_string(),
+        old: "c".repeat(40),
+        new: String::new(),
+        job_id: None,
+    };
+    let json: serde_json::Value = serde_json::to_value(&deleted).expect("serialize");
+    assert!(json.get("job_id").is_none());
+    assert_eq!(deleted.job_id(), None);
+    assert_roundtrip(&deleted);
+
+    assert_roundtrip(&Event::GitTriggerStopped {
+        trigger_name: "ci".to_string(),
+        namespace: "proj".to_string(),
+    });
+}
+
 // =============================================================================
 // WorkerTakeComplete Event Tests
 // =============================================================================
@@ -321,6 +601,8 @@ fn event_decision_created_roundtrip() {
         ],
         created_at_ms: 2_000_000,
         namespace: "myns".to_string(),
+        step: None,
+        fields: Vec::new(),
     };
     let json: serde_json::Value = serde_json::to_value(&event).unwrap();
     assert_eq!(json["type"], "decision:created");
@@ -341,6 +623,7 @@ fn event_decision_resolved_roundtrip() {
         message: Some("Approved".to_string()),
         resolved_at_ms: 3_000_000,
         namespace: "myns".to_string(),
+        values: HashMap::new(),
     };
     let json: serde_json::Value = serde_json::to_value(&event).unwrap();
     assert_eq!(json["type"], "decision:resolved");
@@ -360,10 +643,46 @@ fn event_decision_resolved_freeform_only_roundtrip() {
         message: Some("Custom response".to_string()),
         resolved_at_ms: 4_000_000,
         namespace: String::new(),
+        values: HashMap::new(),
     };
     assert_roundtrip(&event);
 }
 
+#[test]
+fn event_approval_decision_roundtrip() {
+    let created = Event::DecisionCreated {
+        id: "dec-ap1".to_string(),
+        job_id: JobId::new("pipe-1"),
+        agent_id: None,
+        owner: OwnerId::Job(JobId::new("pipe-1")),
+        source: DecisionSource::Approval,
+        context: "Ship it?".to_string(),
+        options: vec![DecisionOption::new("ship"), DecisionOption::new("Cancel")],
+        created_at_ms: 1_000,
+        namespace: String::new(),
+        step: Some("signoff".to_string()),
+        fields: vec!["reason".to_string()],
+    };
+    let json: serde_json::Value = serde_json::to_value(&created).unwrap();
+    assert_eq!(json["step"], "signoff");
+    assert_eq!(json["fields"][0], "reason");
+    assert_roundtrip(&created);
+
+    let resolved = Event::DecisionResolved {
+        id: "dec-ap1".to_string(),
+        chosen: Some(1),
+        message: None,
+        resolved_at_ms: 2_000,
+        namespace: String::new(),
+        values: [("reason".to_string(), "green".to_string())]
+            .into_iter()
+            .collect(),
+    };
+    let json: serde_json::Value = serde_json::to_value(&resolved).unwrap();
+    assert_eq!(json["values"]["reason"], "green");
+    assert_roundtrip(&resolved);
+}
+
 #[test]
 fn event_decision_name() {
     assert_eq!(
@@ -377,6 +696,8 @@ fn event_decision_name() {
             options: vec![],
             created_at_ms: 0,
             namespace: String::new(),
+            step: None,
+            fields: Vec::new(),
         }
         .name(),
         "decision:created"
@@ -388,6 +709,7 @@ fn event_decision_name() {
             message: None,
             resolved_at_ms: 0,
             namespace: String::new(),
+            values: HashMap::new(),
         }
         .name(),
         "decision:resolved"
@@ -444,3 +766,69 @@ fn event_agent_run_resume_name() {
         "agent_run:resume"
     );
 }
+
+// =============================================================================
+// Lock Event Tests
+// =============================================================================
+
+#[test]
+fn event_lock_waiting_roundtrip() {
+    let event = Event::LockWaiting {
+        job_id: JobId::new("job-1"),
+        step: "merge".to_string(),
+        resources: vec!["lock.main-branch".to_string()],
+        namespace: "myns".to_string(),
+    };
+    let json: serde_json::Value = serde_json::to_value(&event).unwrap();
+    assert_eq!(json["type"], "lock:waiting");
+    assert_eq!(json["resources"][0], "lock.main-branch");
+    assert_eq!(event.job_id(), Some(&JobId::new("job-1")));
+
+    assert_roundtrip(&event);
+}
+
+#[test]
+fn event_lock_acquired_roundtrip() {
+    let event = Event::LockAcquired {
+        job_id: JobId::new("job-1"),
+        step: "test".to_string(),
+        resources: vec!["semaphore.gpu".to_string(), "lock.db".to_string()],
+        namespace: String::new(),
+    };
+    let json: serde_json::Value = serde_json::to_value(&event).unwrap();
+    assert_eq!(json["type"], "lock:acquired");
+    assert_eq!(event.name(), "lock:acquired");
+
+    assert_roundtrip(&event);
+}
+
+#[test]
+fn event_step_branched_roundtrip() {
+    let event = Event::StepBranched {
+        job_id: JobId::new("job-1"),
+        step: "checks".to_string(),
+        branch: "lint".to_string(),
+        branch_job_id: JobId::new("job-2"),
+    };
+    let json: serde_json::Value = serde_json::to_value(&event).unwrap();
+    assert_eq!(json["type"], "step:branched");
+    assert_eq!(json["branch_job_id"], "job-2");
+    assert_eq!(event.job_id(), Some(&JobId::new("job-1")));
+
+    assert_roundtrip(&event);
+}
+
+#[test]
+fn event_step_delegated_roundtrip() {
+    let event = Event::StepDelegated {
+        job_id: JobId::new("job-1"),
+        step: "merge".to_string(),
+        child_job_id: JobId::new("job-2"),
+    };
+    let json: serde_json::Value = serde_json::to_value(&event).unwrap();
+    assert_eq!(json["type"], "step:delegated");
+    assert_eq!(json["child_job_id"], "job-2");
+    assert_eq!(event.job_id(), Some(&JobId::new("job-1")));
+
+    assert_roundtrip(&event);
+}
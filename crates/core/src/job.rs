@@ -5,6 +5,7 @@
 
 use crate::action_tracker::ActionTracker;
 use crate::clock::Clock;
+use crate::dependency::JobDependency;
 use crate::workspace::WorkspaceId;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -32,6 +33,9 @@ pub enum StepStatus {
     Running,
     /// Waiting for external input (optional decision_id)
     Waiting(Option<String>),
+    /// Blocked before starting, waiting on a shared resource (e.g. "lock.main-branch")
+    /// or on other jobs to finish (e.g. "after abcd1234")
+    Blocked(String),
     /// Step completed
     Completed,
     /// Step failed
@@ -43,6 +47,11 @@ impl StepStatus {
     pub fn is_waiting(&self) -> bool {
         matches!(self, StepStatus::Waiting(_))
     }
+
+    /// Check if this step is blocked waiting to acquire a resource.
+    pub fn is_blocked(&self) -> bool {
+        matches!(self, StepStatus::Blocked(_))
+    }
 }
 
 impl fmt::Display for StepStatus {
@@ -51,6 +60,7 @@ impl fmt::Display for StepStatus {
             StepStatus::Pending => write!(f, "pending"),
             StepStatus::Running => write!(f, "running"),
             StepStatus::Waiting(_) => write!(f, "waiting"),
+            StepStatus::Blocked(_) => write!(f, "blocked"),
             StepStatus::Completed => write!(f, "completed"),
             StepStatus::Failed => write!(f, "failed"),
         }
@@ -64,6 +74,7 @@ pub enum StepOutcome {
     Completed,
     Failed(String),
     Waiting(String),
+    Blocked(String),
 }
 
 /// Tag-only variant of [`StepStatus`] for protocol DTOs (strips associated data).
@@ -73,6 +84,7 @@ pub enum StepStatusKind {
     Pending,
     Running,
     Waiting,
+    Blocked,
     Completed,
     Failed,
     /// Orphaned job detected from breadcrumb (not a core step status).
@@ -85,6 +97,7 @@ impl From<&StepStatus> for StepStatusKind {
             StepStatus::Pending => StepStatusKind::Pending,
             StepStatus::Running => StepStatusKind::Running,
             StepStatus::Waiting(_) => StepStatusKind::Waiting,
+            StepStatus::Blocked(_) => StepStatusKind::Blocked,
             StepStatus::Completed => StepStatusKind::Completed,
             StepStatus::Failed => StepStatusKind::Failed,
         }
@@ -97,6 +110,7 @@ impl fmt::Display for StepStatusKind {
             StepStatusKind::Pending => write!(f, "pending"),
             StepStatusKind::Running => write!(f, "running"),
             StepStatusKind::Waiting => write!(f, "waiting"),
+            StepStatusKind::Blocked => write!(f, "blocked"),
             StepStatusKind::Completed => write!(f, "completed"),
             StepStatusKind::Failed => write!(f, "failed"),
             StepStatusKind::Orphaned => write!(f, "orphaned"),
@@ -112,6 +126,7 @@ pub enum StepOutcomeKind {
     Completed,
     Failed,
     Waiting,
+    Blocked,
 }
 
 impl From<&StepOutcome> for StepOutcomeKind {
@@ -121,6 +136,7 @@ impl From<&StepOutcome> for StepOutcomeKind {
             StepOutcome::Completed => StepOutcomeKind::Completed,
             StepOutcome::Failed(_) => StepOutcomeKind::Failed,
             StepOutcome::Waiting(_) => StepOutcomeKind::Waiting,
+            StepOutcome::Blocked(_) => StepOutcomeKind::Blocked,
         }
     }
 }
@@ -132,6 +148,7 @@ impl fmt::Display for StepOutcomeKind {
             StepOutcomeKind::Completed => write!(f, "completed"),
             StepOutcomeKind::Failed => write!(f, "failed"),
             StepOutcomeKind::Waiting => write!(f, "waiting"),
+            StepOutcomeKind::Blocked => write!(f, "blocked"),
         }
     }
 }
@@ -149,6 +166,12 @@ pub struct StepRecord {
     /// Agent name from the runbook definition (if any)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub agent_name: Option<String>,
+    /// Child job that ran this record (parallel branches and sub-job steps)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub job_id: Option<String>,
+    /// One record per branch when this step fanned out in parallel
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub branches: Vec<StepRecord>,
 }
 
 /// Configuration for creating a new job
@@ -164,6 +187,13 @@ pub struct JobConfig {
     pub namespace: String,
     /// Name of the cron that spawned this job, if any.
     pub cron_name: Option<String>,
+    /// Jobs that must finish before this one starts
+    pub after: Vec<JobDependency>,
+    /// Admission priority (higher is admitted first)
+    pub priority: i32,
+    /// Project the job was started from, whose `.oj/config.toml` sets the
+    /// project limit on running jobs
+    pub project_root: Option<PathBuf>,
 }
 
 impl JobConfig {
@@ -183,6 +213,9 @@ impl JobConfig {
             initial_step: initial_step.into(),
             namespace: String::new(),
             cron_name: None,
+            after: Vec::new(),
+            priority: 0,
+            project_root: None,
         }
     }
 }
@@ -197,6 +230,9 @@ pub struct JobConfigBuilder {
     initial_step: String,
     namespace: String,
     cron_name: Option<String>,
+    after: Vec<JobDependency>,
+    priority: i32,
+    project_root: Option<PathBuf>,
 }
 
 impl JobConfigBuilder {
@@ -224,6 +260,18 @@ impl JobConfigBuilder {
         self.cron_name = Some(name.into());
         self
     }
+    pub fn after(mut self, after: Vec<JobDependency>) -> Self {
+        self.after = after;
+        self
+    }
+    pub fn priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+    pub fn project_root(mut self, project_root: Option<PathBuf>) -> Self {
+        self.project_root = project_root;
+        self
+    }
     pub fn build(self) -> JobConfig {
         JobConfig {
             id: self.id,
@@ -235,6 +283,9 @@ impl JobConfigBuilder {
             initial_step: self.initial_step,
             namespace: self.namespace,
             cron_name: self.cron_name,
+            after: self.after,
+            priority: self.priority,
+            project_root: self.project_root,
         }
     }
 }
@@ -244,6 +295,10 @@ impl JobConfigBuilder {
 /// (e.g., merge → resolve → push → reinit → merge looping indefinitely).
 pub const MAX_STEP_VISITS: u32 = 5;
 
+/// Failure reason recorded when a shell step outlives its timeout; the
+/// timeout follows (e.g. "shell step timed out after 30s").
+pub const SHELL_TIMEOUT_REASON: &str = "shell step timed out after";
+
 /// A job instance
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Job {
@@ -287,9 +342,29 @@ pub struct Job {
     /// Used as a circuit breaker to prevent runaway retry cycles.
     #[serde(default)]
     pub step_visits: HashMap<String, u32>,
+    /// Retries of the current step under its `retry` policy.
+    /// Reset when the job moves to another step.
+    #[serde(default)]
+    pub step_retries: u32,
     /// Name of the cron that spawned this job, if any.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub cron_name: Option<String>,
+    /// Jobs that must finish before the first step starts (`--after`, `depends_on`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub after: Vec<JobDependency>,
+    /// Admission priority: queued jobs with higher priority are admitted first
+    #[serde(default)]
+    pub priority: i32,
+    /// Project the job was started from; admission reads the project limit
+    /// on running jobs from its `.oj/config.toml`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_root: Option<PathBuf>,
+    /// Held in the admission queue until a running-job slot frees up
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub queued: bool,
+    /// The job's `budget` was crossed and `on_budget` has fired
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub budget_exceeded: bool,
     /// Session log file size when idle grace timer was set.
     /// Used to detect activity during the grace period.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -298,6 +373,13 @@ pub struct Job {
     /// Used to suppress auto-resume from our own nudge text.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub last_nudge_at: Option<u64>,
+    /// Job that spawned this one (parallel branches and sub-jobs)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_job_id: Option<String>,
+    /// True when this job runs a single branch of its parent's parallel step.
+    /// Branches finish after that step instead of following its transitions.
+    #[serde(default)]
+    pub is_branch: bool,
 }
 
 impl Job {
@@ -331,14 +413,24 @@ impl Job {
                 outcome: StepOutcome::Running,
                 agent_id: None,
                 agent_name: None,
+                job_id: None,
+                branches: Vec::new(),
             }],
             action_tracker: ActionTracker::default(),
             cancelling: false,
             total_retries: 0,
             step_visits: HashMap::new(),
+            step_retries: 0,
             cron_name: config.cron_name,
+            after: config.after,
+            priority: config.priority,
+            project_root: config.project_root,
+            queued: false,
+            budget_exceeded: false,
             idle_grace_log_size: None,
             last_nudge_at: None,
+            parent_job_id: None,
+            is_branch: false,
         }
     }
 
@@ -370,6 +462,8 @@ impl Job {
             outcome: StepOutcome::Running,
             agent_id: None,
             agent_name: None,
+            job_id: None,
+            branches: Vec::new(),
         });
     }
 
@@ -396,6 +490,20 @@ impl Job {
         self.step == "done" || self.step == "failed" || self.step == "cancelled"
     }
 
+    /// IDs of every child job this job's steps started (sub-jobs and
+    /// parallel branches), oldest first.
+    pub fn child_job_ids(&self) -> Vec<&str> {
+        self.step_history
+            .iter()
+            .flat_map(|r| {
+                r.job_id
+                    .iter()
+                    .chain(r.branches.iter().filter_map(|b| b.job_id.as_ref()))
+            })
+            .map(String::as_str)
+            .collect()
+    }
+
     /// Set the workspace ID and path
     pub fn with_workspace(mut self, id: WorkspaceId, path: PathBuf) -> Self {
         self.workspace_id = Some(id);
@@ -475,6 +583,10 @@ pub struct JobBuilder {
     cron_name: Option<String>,
     idle_grace_log_size: Option<u64>,
     last_nudge_at: Option<u64>,
+    parent_job_id: Option<String>,
+    is_branch: bool,
+    priority: i32,
+    queued: bool,
 }
 
 #[cfg(any(test, feature = "test-support"))]
@@ -502,6 +614,10 @@ impl Default for JobBuilder {
             cron_name: None,
             idle_grace_log_size: None,
             last_nudge_at: None,
+            parent_job_id: None,
+            is_branch: false,
+            priority: 0,
+            queued: false,
         }
     }
 }
@@ -576,6 +692,22 @@ impl JobBuilder {
         self.step_history = v;
         self
     }
+    pub fn parent_job_id(mut self, v: impl Into<String>) -> Self {
+        self.parent_job_id = Some(v.into());
+        self
+    }
+    pub fn is_branch(mut self, v: bool) -> Self {
+        self.is_branch = v;
+        self
+    }
+    pub fn priority(mut self, v: i32) -> Self {
+        self.priority = v;
+        self
+    }
+    pub fn queued(mut self, v: bool) -> Self {
+        self.queued = v;
+        self
+    }
     pub fn build(self) -> Job {
         Job {
             id: self.id,
@@ -598,9 +730,17 @@ impl JobBuilder {
             cancelling: self.cancelling,
             total_retries: self.total_retries,
             step_visits: self.step_visits,
+            step_retries: 0,
             cron_name: self.cron_name,
+            after: Vec::new(),
+            priority: self.priority,
+            project_root: None,
+            queued: self.queued,
+            budget_exceeded: false,
             idle_grace_log_size: self.idle_grace_log_size,
             last_nudge_at: self.last_nudge_at,
+            parent_job_id: self.parent_job_id,
+            is_branch: self.is_branch,
         }
     }
 }
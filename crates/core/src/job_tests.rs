@@ -306,11 +306,12 @@ fn job_step_visits_serde_round_trip() {
 #[test]
 fn max_step_visits_is_reasonable() {
     // Sanity check that the constant is a reasonable value
-    assert!(
-        MAX_STEP_VISITS >= 3 && MAX_STEP_VISITS <= 20,
-        "MAX_STEP_VISITS should be between 3 and 20, got {}",
-        MAX_STEP_VISITS
-    );
+    const {
+        assert!(
+            MAX_STEP_VISITS >= 3 && MAX_STEP_VISITS <= 20,
+            "MAX_STEP_VISITS should be between 3 and 20"
+        )
+    };
 }
 
 #[test]
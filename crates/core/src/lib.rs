@@ -9,20 +9,25 @@
 //! oj-core: Core library for the Odd Jobs (oj) CLI tool
 
 pub mod action_tracker;
+pub mod admission;
 pub mod agent;
 pub mod agent_record;
 pub mod agent_run;
 pub mod clock;
+pub mod cron;
 pub mod decision;
+pub mod dependency;
 pub mod effect;
 pub mod event;
 pub mod id;
 pub mod job;
 pub mod namespace;
 pub mod owner;
+pub mod rate;
 pub mod session;
 pub mod time_fmt;
 pub mod timer;
+pub mod usage;
 pub mod worker;
 pub mod workspace;
 
@@ -30,13 +35,16 @@ pub mod workspace;
 pub mod test_support;
 
 // ActionTracker and AgentSignal available via action_tracker module or job re-export
+pub use admission::{admission_order, project_max_jobs, read_max_jobs};
 pub use agent::{AgentError, AgentId, AgentState};
 pub use agent_record::{AgentRecord, AgentRecordStatus};
 #[cfg(any(test, feature = "test-support"))]
 pub use agent_run::AgentRunBuilder;
 pub use agent_run::{AgentRun, AgentRunId, AgentRunStatus};
 pub use clock::{Clock, FakeClock, SystemClock};
+pub use cron::CronSchedule;
 pub use decision::{Decision, DecisionId, DecisionOption, DecisionSource};
+pub use dependency::{DependencyCondition, DependencyState, JobDependency};
 pub use effect::Effect;
 pub use event::{AgentSignalKind, Event, PromptType, QuestionData, QuestionEntry, QuestionOption};
 pub use id::{IdGen, ShortId, UuidIdGen};
@@ -44,12 +52,14 @@ pub use id::{IdGen, ShortId, UuidIdGen};
 pub use job::JobBuilder;
 pub use job::{
     Job, JobConfig, JobConfigBuilder, JobId, StepOutcome, StepOutcomeKind, StepRecord, StepStatus,
-    StepStatusKind,
+    StepStatusKind, SHELL_TIMEOUT_REASON,
 };
 pub use namespace::{namespace_to_option, scoped_name, split_scoped_name, Namespace};
 pub use owner::OwnerId;
+pub use rate::DispatchRate;
 pub use session::SessionId;
 pub use time_fmt::{format_elapsed, format_elapsed_ms};
 pub use timer::TimerId;
+pub use usage::{BudgetScope, TokenUsage};
 // WorkerId available via worker module if needed
 pub use workspace::{WorkspaceId, WorkspaceStatus};
@@ -0,0 +1,124 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Dispatch rate limits (`rate = "10/h"`).
+//!
+//! A rate is a token bucket holding `count` tokens that refills one token
+//! every `period / count`. A full bucket allows a burst of `count`
+//! dispatches; after that, dispatches are spaced out evenly.
+//!
+//! The bucket's state is a single timestamp: the epoch ms at which it will
+//! be full again. Any time at or before now means the bucket is full. That
+//! keeps the state small enough to carry on events and persist.
+
+use std::fmt;
+
+/// A parsed `<count>/<period>` rate such as `10/h` or `3/30m`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DispatchRate {
+    count: u32,
+    period_ms: u64,
+}
+
+impl DispatchRate {
+    /// Parse `<count>/<period>`. The period is a unit (`s`, `m`, `h`, `d`)
+    /// or a duration like `30m`.
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let invalid = || format!("invalid rate '{}': expected <count>/<period>, e.g. 10/h", s);
+        let (count, period) = s.trim().split_once('/').ok_or_else(invalid)?;
+        let count: u32 = count.trim().parse().map_err(|_| invalid())?;
+        if count == 0 {
+            return Err(format!("invalid rate '{}': count must be at least 1", s));
+        }
+        let period_ms = parse_period_ms(period.trim()).ok_or_else(invalid)?;
+        if period_ms == 0 {
+            return Err(format!(
+                "invalid rate '{}': period must be greater than zero",
+                s
+            ));
+        }
+        Ok(Self { count, period_ms })
+    }
+
+    /// Bucket capacity: how many dispatches a full bucket allows at once
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Time for the bucket to gain one token
+    pub fn interval_ms(&self) -> u64 {
+        (self.period_ms / u64::from(self.count)).max(1)
+    }
+
+    /// Whole tokens in the bucket at `now_ms`.
+    pub fn available(&self, full_at_ms: u64, now_ms: u64) -> u32 {
+        let missing = full_at_ms
+            .saturating_sub(now_ms)
+            .div_ceil(self.interval_ms());
+        self.count
+            .saturating_sub(missing.min(u64::from(u32::MAX)) as u32)
+    }
+
+    /// Take a token at `now_ms`, returning the bucket's new full time.
+    pub fn take(&self, full_at_ms: u64, now_ms: u64) -> u64 {
+        full_at_ms.max(now_ms) + self.interval_ms()
+    }
+
+    /// Epoch ms at which a token is next available (`now_ms` if one is).
+    pub fn next_token_at(&self, full_at_ms: u64, now_ms: u64) -> u64 {
+        let burst_ms = u64::from(self.count - 1) * self.interval_ms();
+        full_at_ms.saturating_sub(burst_ms).max(now_ms)
+    }
+
+    /// Keep a bucket carried over from another rate within this rate's
+    /// capacity, so lowering a rate never leaves more than a full period to
+    /// wait out.
+    pub fn clamp(&self, full_at_ms: u64, now_ms: u64) -> u64 {
+        let empty_ms = u64::from(self.count) * self.interval_ms();
+        full_at_ms.min(now_ms + empty_ms)
+    }
+}
+
+impl fmt::Display for DispatchRate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (n, unit) = match self.period_ms {
+            ms if ms % 86_400_000 == 0 => (ms / 86_400_000, "d"),
+            ms if ms % 3_600_000 == 0 => (ms / 3_600_000, "h"),
+            ms if ms % 60_000 == 0 => (ms / 60_000, "m"),
+            ms if ms % 1_000 == 0 => (ms / 1_000, "s"),
+            ms => (ms, "ms"),
+        };
+        if n == 1 {
+            write!(f, "{}/{}", self.count, unit)
+        } else {
+            write!(f, "{}/{}{}", self.count, n, unit)
+        }
+    }
+}
+
+/// Parse a rate period: a bare unit (`h`) or a duration (`30m`).
+fn parse_period_ms(s: &str) -> Option<u64> {
+    let (num_str, suffix) = s
+        .char_indices()
+        .find(|(_, c)| !c.is_ascii_digit())
+        .map(|(i, _)| (&s[..i], &s[i..]))
+        .unwrap_or((s, ""));
+    let num: u64 = if num_str.is_empty() {
+        1
+    } else {
+        num_str.parse().ok()?
+    };
+    let multiplier = match suffix.trim() {
+        "ms" | "millis" | "millisecond" | "milliseconds" => 1,
+        "s" | "sec" | "secs" | "second" | "seconds" => 1_000,
+        "m" | "min" | "mins" | "minute" | "minutes" => 60_000,
+        "h" | "hr" | "hrs" | "hour" | "hours" => 3_600_000,
+        "d" | "day" | "days" => 86_400_000,
+        _ => return None,
+    };
+    num.checked_mul(multiplier)
+}
+
+#[cfg(test)]
+#[path = "rate_tests.rs"]
+mod tests;
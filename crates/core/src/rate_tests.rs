@@ -0,0 +1,88 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+const MINUTE_MS: u64 = 60_000;
+
+fn rate(s: &str) -> DispatchRate {
+    DispatchRate::parse(s).unwrap()
+}
+
+#[yare::parameterized(
+    per_hour        = { "10/h",     10, 6 * MINUTE_MS },
+    long_unit       = { "2/minute", 2,  30_000 },
+    sized_period    = { "3/30m",    3,  10 * MINUTE_MS },
+    per_day         = { "24/d",     24, 60 * MINUTE_MS },
+    spaces          = { " 1 / 5s ", 1,  5_000 },
+)]
+fn parses(s: &str, count: u32, interval_ms: u64) {
+    let r = rate(s);
+    assert_eq!(r.count(), count);
+    assert_eq!(r.interval_ms(), interval_ms);
+}
+
+#[yare::parameterized(
+    no_slash     = { "10",    "expected <count>/<period>" },
+    bad_count    = { "ten/h", "expected <count>/<period>" },
+    bad_unit     = { "10/w",  "expected <count>/<period>" },
+    zero_count   = { "0/h",   "count must be at least 1" },
+    zero_period  = { "5/0m",  "period must be greater than zero" },
+)]
+fn rejects(s: &str, fragment: &str) {
+    let err = DispatchRate::parse(s).unwrap_err();
+    assert!(err.contains(fragment), "{err}");
+}
+
+#[yare::parameterized(
+    unit         = { "10/h",      "10/h" },
+    sized        = { "3/30m",     "3/30m" },
+    normalized   = { "2/60min",   "2/h" },
+)]
+fn displays(s: &str, expected: &str) {
+    assert_eq!(rate(s).to_string(), expected);
+}
+
+#[test]
+fn full_bucket_allows_a_burst_then_spaces_dispatches() {
+    let r = rate("3/30m");
+    let now = 1_000_000;
+    // Nothing taken yet: the bucket has been full since epoch 0
+    let mut full_at = 0;
+    assert_eq!(r.available(full_at, now), 3);
+
+    for _ in 0..3 {
+        full_at = r.take(full_at, now);
+    }
+    assert_eq!(full_at, now + 30 * MINUTE_MS);
+    assert_eq!(r.available(full_at, now), 0);
+    assert_eq!(r.next_token_at(full_at, now), now + 10 * MINUTE_MS);
+
+    // One interval later a single token is back
+    let later = now + 10 * MINUTE_MS;
+    assert_eq!(r.available(full_at, later), 1);
+    assert_eq!(r.next_token_at(full_at, later), later);
+    full_at = r.take(full_at, later);
+    assert_eq!(r.available(full_at, later), 0);
+    assert_eq!(r.next_token_at(full_at, later), later + 10 * MINUTE_MS);
+}
+
+#[test]
+fn partial_refill_does_not_count_as_a_token() {
+    let r = rate("1/h");
+    let full_at = r.take(0, 0);
+    assert_eq!(r.available(full_at, 59 * MINUTE_MS), 0);
+    assert_eq!(r.available(full_at, 60 * MINUTE_MS), 1);
+}
+
+#[test]
+fn clamp_caps_the_wait_at_one_period() {
+    let slow = rate("1/d");
+    let now = 5_000;
+    let full_at = slow.take(0, now);
+
+    let fast = rate("6/h");
+    let clamped = fast.clamp(full_at, now);
+    assert_eq!(clamped, now + 60 * MINUTE_MS);
+    assert_eq!(fast.next_token_at(clamped, now), now + 10 * MINUTE_MS);
+}
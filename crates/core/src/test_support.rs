@@ -23,6 +23,9 @@ pub fn job_create_event(id: &str, kind: &str, name: &str, initial_step: &str) ->
         created_at_epoch_ms: 1_000_000,
         namespace: String::new(),
         cron_name: None,
+        after: Vec::new(),
+        priority: 0,
+        project_root: None,
     }
 }
 
@@ -73,7 +76,9 @@ pub fn worker_start_event(name: &str, ns: &str) -> Event {
         project_root: PathBuf::from("/test/project"),
         runbook_hash: "abc123".to_string(),
         queue_name: "queue".to_string(),
+        queues: Default::default(),
         concurrency: 1,
+        rate: None,
         namespace: ns.to_string(),
     }
 }
@@ -90,6 +95,11 @@ pub fn queue_pushed_event(queue_name: &str, item_id: &str) -> Event {
         .collect(),
         pushed_at_epoch_ms: 1_000_000,
         namespace: String::new(),
+        priority: 0,
+        visible_after_epoch_ms: None,
+        dedup_key: None,
+        expires_at_epoch_ms: None,
+        drop_on_expire: false,
     }
 }
 
@@ -60,6 +60,46 @@ impl TimerId {
         self.0.starts_with("queue-retry:")
     }
 
+    /// Timer ID for a delayed queue item becoming visible to workers.
+    pub fn queue_visible(queue_name: &str, item_id: &str) -> Self {
+        Self::new(format!("queue-visible:{}:{}", queue_name, item_id))
+    }
+
+    /// Returns true if this is a queue visibility timer.
+    pub fn is_queue_visible(&self) -> bool {
+        self.0.starts_with("queue-visible:")
+    }
+
+    /// Timer ID for a pending queue item's TTL running out.
+    pub fn queue_expire(queue_name: &str, item_id: &str) -> Self {
+        Self::new(format!("queue-expire:{}:{}", queue_name, item_id))
+    }
+
+    /// Returns true if this is a queue expiry timer.
+    pub fn is_queue_expire(&self) -> bool {
+        self.0.starts_with("queue-expire:")
+    }
+
+    /// Timer ID for checking an active queue item's lease.
+    pub fn queue_lease(queue_name: &str, item_id: &str) -> Self {
+        Self::new(format!("queue-lease:{}:{}", queue_name, item_id))
+    }
+
+    /// Returns true if this is a queue lease timer.
+    pub fn is_queue_lease(&self) -> bool {
+        self.0.starts_with("queue-lease:")
+    }
+
+    /// Timer ID for re-running a failed step after its retry delay.
+    pub fn step_retry(job_id: &JobId) -> Self {
+        Self::new(format!("step-retry:{}", job_id))
+    }
+
+    /// Returns true if this is a step retry timer.
+    pub fn is_step_retry(&self) -> bool {
+        self.0.starts_with("step-retry:")
+    }
+
     /// Timer ID for a cron interval tick.
     pub fn cron(cron_name: &str, namespace: &str) -> Self {
         Self::new(format!("cron:{}", scoped_name(namespace, cron_name)))
@@ -83,6 +123,19 @@ impl TimerId {
         self.0.starts_with("queue-poll:")
     }
 
+    /// Timer ID for waking a rate-limited worker when its next token is due.
+    pub fn worker_rate(worker_name: &str, namespace: &str) -> Self {
+        Self::new(format!(
+            "worker-rate:{}",
+            scoped_name(namespace, worker_name)
+        ))
+    }
+
+    /// Returns true if this is a worker rate limit timer.
+    pub fn is_worker_rate(&self) -> bool {
+        self.0.starts_with("worker-rate:")
+    }
+
     /// Timer ID for liveness monitoring of a standalone agent run.
     pub fn liveness_agent_run(agent_run_id: &AgentRunId) -> Self {
         Self::new(format!("liveness:ar:{}", agent_run_id))
@@ -188,7 +241,8 @@ impl TimerId {
 
     /// Extracts the job ID portion if this is a job-related timer.
     ///
-    /// Returns `Some(&str)` for liveness, exit-deferred, cooldown, and idle-grace timers.
+    /// Returns `Some(&str)` for liveness, exit-deferred, cooldown, idle-grace, and
+    /// step-retry timers.
     /// For cooldown timers, extracts the job_id from "cooldown:job_id:trigger:pos".
     ///
     /// NOTE: Returns `None` for agent_run timers (which have `:ar:` marker).
@@ -207,6 +261,8 @@ impl TimerId {
             rest.split(':').next()
         } else if let Some(rest) = self.0.strip_prefix("idle-grace:") {
             Some(rest)
+        } else if let Some(rest) = self.0.strip_prefix("step-retry:") {
+            Some(rest)
         } else {
             None
         }
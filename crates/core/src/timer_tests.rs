@@ -123,6 +123,15 @@ fn job_id_str_unknown_timer() {
     assert_eq!(id.job_id_str(), None);
 }
 
+#[test]
+fn step_retry_timer_id() {
+    let id = TimerId::step_retry(&JobId::new("pipe-123"));
+    assert_eq!(id.as_str(), "step-retry:pipe-123");
+    assert!(id.is_step_retry());
+    assert_eq!(id.job_id_str(), Some("pipe-123"));
+    assert!(!TimerId::queue_retry("bugs", "item-1").is_step_retry());
+}
+
 #[test]
 fn queue_retry_timer_id_format() {
     let id = TimerId::queue_retry("bugs", "item-123");
@@ -147,6 +156,31 @@ fn is_queue_retry() {
     assert!(!id.is_queue_retry());
 }
 
+#[test]
+fn queue_visible_timer_id() {
+    let id = TimerId::queue_visible("myns/bugs", "item-1");
+    assert_eq!(id.as_str(), "queue-visible:myns/bugs:item-1");
+    assert!(id.is_queue_visible());
+    assert!(!id.is_queue_retry());
+    assert!(!TimerId::queue_retry("bugs", "item-1").is_queue_visible());
+}
+
+#[test]
+fn queue_expire_timer_id() {
+    let id = TimerId::queue_expire("myns/inbox", "item-1");
+    assert_eq!(id.as_str(), "queue-expire:myns/inbox:item-1");
+    assert!(id.is_queue_expire());
+    assert!(!id.is_queue_visible());
+}
+
+#[test]
+fn queue_lease_timer_id() {
+    let id = TimerId::queue_lease("myns/inbox", "item-1");
+    assert_eq!(id.as_str(), "queue-lease:myns/inbox:item-1");
+    assert!(id.is_queue_lease());
+    assert!(!id.is_queue_expire());
+}
+
 #[test]
 fn cron_timer_id_format() {
     let id = TimerId::cron("janitor", "");
@@ -198,6 +232,14 @@ fn is_queue_poll() {
     assert!(!id.is_queue_poll());
 }
 
+#[test]
+fn worker_rate_timer_id() {
+    let id = TimerId::worker_rate("my-worker", "myproject");
+    assert_eq!(id.as_str(), "worker-rate:myproject/my-worker");
+    assert!(id.is_worker_rate());
+    assert!(!TimerId::queue_poll("my-worker", "myproject").is_worker_rate());
+}
+
 // =============================================================================
 // OwnerId-based constructor tests
 // =============================================================================
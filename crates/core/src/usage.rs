@@ -0,0 +1,141 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Agent token usage and estimated cost.
+//!
+//! Totals come from the usage metrics collector, which reads Claude's session
+//! logs. Cost is an estimate from list prices; models missing from the
+//! pricing table have no cost.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Cumulative token counts for one agent (or summed across a job's agents).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenUsage {
+    #[serde(default)]
+    pub input_tokens: u64,
+    #[serde(default)]
+    pub output_tokens: u64,
+    #[serde(default)]
+    pub cache_creation_input_tokens: u64,
+    #[serde(default)]
+    pub cache_read_input_tokens: u64,
+}
+
+/// Which budget was crossed: the agent's own, or its job's (shared by
+/// every agent the job runs).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BudgetScope {
+    Agent,
+    Job,
+}
+
+impl fmt::Display for BudgetScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BudgetScope::Agent => write!(f, "agent"),
+            BudgetScope::Job => write!(f, "job"),
+        }
+    }
+}
+
+/// List prices in USD per million tokens.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ModelPricing {
+    input: f64,
+    output: f64,
+    cache_write: f64,
+    cache_read: f64,
+}
+
+const OPUS_4_5: ModelPricing = ModelPricing {
+    input: 5.0,
+    output: 25.0,
+    cache_write: 6.25,
+    cache_read: 0.50,
+};
+const OPUS: ModelPricing = ModelPricing {
+    input: 15.0,
+    output: 75.0,
+    cache_write: 18.75,
+    cache_read: 1.50,
+};
+const SONNET: ModelPricing = ModelPricing {
+    input: 3.0,
+    output: 15.0,
+    cache_write: 3.75,
+    cache_read: 0.30,
+};
+const HAIKU_4_5: ModelPricing = ModelPricing {
+    input: 1.0,
+    output: 5.0,
+    cache_write: 1.25,
+    cache_read: 0.10,
+};
+const HAIKU_3_5: ModelPricing = ModelPricing {
+    input: 0.80,
+    output: 4.0,
+    cache_write: 1.0,
+    cache_read: 0.08,
+};
+const HAIKU_3: ModelPricing = ModelPricing {
+    input: 0.25,
+    output: 1.25,
+    cache_write: 0.30,
+    cache_read: 0.03,
+};
+
+/// Model ID fragments and their prices; the first match wins.
+const PRICING: &[(&str, ModelPricing)] = &[
+    ("opus-4-5", OPUS_4_5),
+    ("opus-4-6", OPUS_4_5),
+    ("opus", OPUS),
+    ("sonnet", SONNET),
+    ("haiku-4", HAIKU_4_5),
+    ("3-5-haiku", HAIKU_3_5),
+    ("haiku", HAIKU_3),
+];
+
+fn pricing_for(model: &str) -> Option<ModelPricing> {
+    PRICING
+        .iter()
+        .find(|(fragment, _)| model.contains(fragment))
+        .map(|(_, pricing)| *pricing)
+}
+
+impl TokenUsage {
+    /// Tokens counted against a `tokens` budget: input, output, and cache
+    /// writes. Cache reads re-read context that was already paid for, and
+    /// would otherwise dominate the count; they still show up in cost.
+    pub fn budget_tokens(&self) -> u64 {
+        self.input_tokens
+            .saturating_add(self.output_tokens)
+            .saturating_add(self.cache_creation_input_tokens)
+    }
+
+    /// Add another agent's usage to this total.
+    pub fn add(&mut self, other: &TokenUsage) {
+        self.input_tokens += other.input_tokens;
+        self.output_tokens += other.output_tokens;
+        self.cache_creation_input_tokens += other.cache_creation_input_tokens;
+        self.cache_read_input_tokens += other.cache_read_input_tokens;
+    }
+
+    /// Estimated cost in USD, or None when the model has no known price.
+    pub fn cost_usd(&self, model: &str) -> Option<f64> {
+        let price = pricing_for(model)?;
+        let per_million = |tokens: u64, rate: f64| tokens as f64 * rate / 1_000_000.0;
+        Some(
+            per_million(self.input_tokens, price.input)
+                + per_million(self.output_tokens, price.output)
+                + per_million(self.cache_creation_input_tokens, price.cache_write)
+                + per_million(self.cache_read_input_tokens, price.cache_read),
+        )
+    }
+}
+
+#[cfg(test)]
+#[path = "usage_tests.rs"]
+mod tests;
@@ -0,0 +1,51 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+fn usage(input: u64, output: u64, cache_write: u64, cache_read: u64) -> TokenUsage {
+    TokenUsage {
+        input_tokens: input,
+        output_tokens: output,
+        cache_creation_input_tokens: cache_write,
+        cache_read_input_tokens: cache_read,
+    }
+}
+
+#[test]
+fn budget_tokens_excludes_cache_reads() {
+    assert_eq!(usage(100, 50, 25, 10_000).budget_tokens(), 175);
+}
+
+#[test]
+fn add_sums_every_counter() {
+    let mut total = usage(1, 2, 3, 4);
+    total.add(&usage(10, 20, 30, 40));
+    assert_eq!(total, usage(11, 22, 33, 44));
+}
+
+#[yare::parameterized(
+    sonnet    = { "claude-sonnet-4-5-20250929", 3.0 },
+    opus_4_5  = { "claude-opus-4-5-20251101", 5.0 },
+    opus_4_1  = { "claude-opus-4-1-20250805", 15.0 },
+    haiku_4_5 = { "claude-haiku-4-5-20251001", 1.0 },
+    haiku_3_5 = { "claude-3-5-haiku-20241022", 0.8 },
+)]
+fn cost_uses_model_input_price(model: &str, per_million: f64) {
+    let cost = usage(1_000_000, 0, 0, 0).cost_usd(model).unwrap();
+    assert!((cost - per_million).abs() < 1e-9, "{model}: {cost}");
+}
+
+#[test]
+fn cost_prices_each_token_kind() {
+    // Sonnet: $3 in, $15 out, $3.75 cache write, $0.30 cache read
+    let cost = usage(1_000_000, 1_000_000, 1_000_000, 1_000_000)
+        .cost_usd("claude-sonnet-4-5-20250929")
+        .unwrap();
+    assert!((cost - 22.05).abs() < 1e-9, "{cost}");
+}
+
+#[test]
+fn cost_is_none_for_unknown_model() {
+    assert_eq!(usage(1_000, 0, 0, 0).cost_usd("<synthetic>"), None);
+}
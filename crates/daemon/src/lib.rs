@@ -16,7 +16,7 @@ pub use protocol::{
     AgentDetail, AgentEntry, AgentStatusEntry, AgentSummary, CronEntry, JobDetail, JobEntry,
     JobStatusEntry, JobSummary, MetricsHealthSummary, NamespaceStatus, OrphanAgent, OrphanSummary,
     ProjectSummary, Query, QueueItemEntry, QueueItemSummary, QueueStatus, QueueSummary, Request,
-    Response, SessionEntry, SessionSummary, StepRecordDetail, WorkerEntry, WorkerSummary,
-    WorkspaceDetail, WorkspaceEntry, WorkspaceSummary, DEFAULT_TIMEOUT, MAX_MESSAGE_SIZE,
-    PROTOCOL_VERSION,
+    Response, SessionEntry, SessionSummary, SpendDetail, StepRecordDetail, WorkerEntry,
+    WorkerSourceSummary, WorkerSummary, WorkspaceDetail, WorkspaceEntry, WorkspaceSummary,
+    DEFAULT_TIMEOUT, MAX_MESSAGE_SIZE, PROTOCOL_VERSION,
 };
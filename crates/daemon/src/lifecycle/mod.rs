@@ -6,7 +6,9 @@
 mod reconcile;
 pub(crate) use reconcile::reconcile_state;
 
+use std::collections::HashMap;
 use std::fs::File;
+use std::net::SocketAddr;
 use std::path::PathBuf;
 use std::sync::Arc;
 
@@ -25,11 +27,12 @@ use oj_engine::{
 };
 use oj_storage::{load_snapshot, Checkpointer, MaterializedState, Wal};
 use thiserror::Error;
-use tokio::net::UnixListener;
+use tokio::net::{TcpListener, UnixListener};
 use tokio::sync::mpsc;
 use tracing::{info, warn};
 
 use crate::event_bus::{EventBus, EventReader};
+use crate::listener::{load_webhook_config, WebhookConfigError, WebhookRoute};
 
 /// Daemon runtime with concrete adapter types (wrapped with tracing)
 pub type DaemonRuntime = Runtime<
@@ -60,6 +63,9 @@ pub struct Config {
     pub workspaces_path: PathBuf,
     /// Path to per-job log files
     pub logs_path: PathBuf,
+    /// Limit on running jobs across all projects (`[limits] max_jobs` in
+    /// `config.toml` under the state directory)
+    pub max_jobs: Option<u32>,
 }
 
 impl Config {
@@ -79,6 +85,7 @@ impl Config {
             snapshot_path: state_dir.join("snapshot.json"),
             workspaces_path: state_dir.join("workspaces"),
             logs_path: state_dir.join("logs"),
+            max_jobs: oj_core::read_max_jobs(&state_dir.join("config.toml")),
             state_dir,
         })
     }
@@ -113,6 +120,9 @@ pub struct StartupResult {
     pub daemon: DaemonState,
     /// The Unix socket listener to spawn as a task
     pub listener: UnixListener,
+    /// The HTTP webhook listener and its routes, when `[webhooks]` is
+    /// configured
+    pub webhooks: Option<(TcpListener, HashMap<String, WebhookRoute>)>,
     /// Event reader for the engine loop
     pub event_reader: EventReader,
     /// Context for running reconciliation as a background task
@@ -249,6 +259,12 @@ pub enum LifecycleError {
     #[error("Failed to bind socket at {0}: {1}")]
     BindFailed(PathBuf, std::io::Error),
 
+    #[error(transparent)]
+    WebhookConfig(#[from] WebhookConfigError),
+
+    #[error("Failed to bind webhook listener at {0}: {1}")]
+    WebhookBindFailed(SocketAddr, std::io::Error),
+
     #[error("WAL error: {0}")]
     Wal(#[from] oj_storage::WalError),
 
@@ -383,6 +399,22 @@ async fn startup_inner(config: &Config) -> Result<StartupResult, LifecycleError>
     let listener = UnixListener::bind(&config.socket_path)
         .map_err(|e| LifecycleError::BindFailed(config.socket_path.clone(), e))?;
 
+    // 7a. Bind the HTTP webhook listener, if `[webhooks]` is configured
+    let webhooks = match load_webhook_config(&config.state_dir.join("config.toml"))? {
+        Some(webhook_config) => {
+            let socket = TcpListener::bind(webhook_config.listen)
+                .await
+                .map_err(|e| LifecycleError::WebhookBindFailed(webhook_config.listen, e))?;
+            info!(
+                "Webhook listener on http://{} ({} routes)",
+                webhook_config.listen,
+                webhook_config.routes.len()
+            );
+            Some((socket, webhook_config.routes))
+        }
+        None => None,
+    };
+
     // 7b. Detect orphaned jobs from breadcrumb files
     let breadcrumbs = breadcrumb::scan_breadcrumbs(&config.logs_path);
     let stale_threshold = std::time::Duration::from_secs(7 * 24 * 60 * 60); // 7 days
@@ -451,6 +483,7 @@ async fn startup_inner(config: &Config) -> Result<StartupResult, LifecycleError>
         RuntimeConfig {
             state_dir: config.state_dir.clone(),
             log_dir: config.logs_path.clone(),
+            max_jobs: config.max_jobs,
         },
         internal_tx.clone(),
     ));
@@ -459,6 +492,7 @@ async fn startup_inner(config: &Config) -> Result<StartupResult, LifecycleError>
     let metrics_health = UsageMetricsCollector::spawn_collector(
         Arc::clone(&state),
         config.state_dir.join("metrics"),
+        internal_tx.clone(),
     );
 
     // 11. Prepare reconciliation context (will run as background task after READY)
@@ -505,6 +539,7 @@ async fn startup_inner(config: &Config) -> Result<StartupResult, LifecycleError>
             metrics_health,
         },
         listener,
+        webhooks,
         event_reader,
         reconcile_ctx: ReconcileCtx {
             runtime,
@@ -7,7 +7,7 @@
 //! monitoring or triggers appropriate exit handling for each entity.
 
 use oj_adapters::SessionAdapter;
-use oj_core::{AgentId, AgentRunId, AgentRunStatus, Event, JobId, OwnerId, SessionId};
+use oj_core::{AgentId, AgentRunId, AgentRunStatus, Event, JobId, OwnerId, SessionId, TimerId};
 use tracing::{info, warn};
 
 use super::ReconcileCtx;
@@ -82,6 +82,15 @@ pub(crate) async fn reconcile_state(ctx: &ReconcileCtx) {
 
     let state = &ctx.state_snapshot;
 
+    // Re-arm durable timers (step and queue retries) so a pending retry keeps
+    // its original fire time instead of being lost or reset
+    if !state.timers.is_empty() {
+        info!("Re-arming {} durable timers", state.timers.len());
+        if let Err(e) = ctx.runtime.restore_durable_timers().await {
+            warn!(error = %e, "failed to re-arm durable timers");
+        }
+    }
+
     // Resume workers that were running before the daemon restarted.
     // Re-emitting WorkerStarted recreates the in-memory WorkerState and
     // triggers an initial queue poll so the worker picks up where it left off.
@@ -108,7 +117,9 @@ pub(crate) async fn reconcile_state(ctx: &ReconcileCtx) {
                 project_root: worker.project_root.clone(),
                 runbook_hash: worker.runbook_hash.clone(),
                 queue_name: worker.queue_name.clone(),
+                queues: worker.queues.clone(),
                 concurrency: worker.concurrency,
+                rate: worker.rate.clone(),
                 namespace: worker.namespace.clone(),
             })
             .await;
@@ -138,8 +149,82 @@ pub(crate) async fn reconcile_state(ctx: &ReconcileCtx) {
                 project_root: cron.project_root.clone(),
                 runbook_hash: cron.runbook_hash.clone(),
                 interval: cron.interval.clone(),
+                schedule: cron.schedule.clone(),
+                timezone: cron.timezone.clone(),
                 run_target: cron.run_target.clone(),
                 namespace: cron.namespace.clone(),
+                started_at_ms: cron.started_at_ms,
+                resumed_from_ms: cron
+                    .last_fired_at_ms
+                    .or(Some(cron.started_at_ms))
+                    .filter(|ms| *ms > 0),
+            })
+            .await;
+    }
+
+    // Resume watches that were running before the daemon restarted.
+    // Changes made while the daemon was down are not replayed.
+    let running_watches: Vec<_> = state
+        .watches
+        .values()
+        .filter(|w| w.status == "running")
+        .collect();
+
+    if !running_watches.is_empty() {
+        info!("Resuming {} running watches", running_watches.len());
+    }
+
+    for watch in &running_watches {
+        info!(
+            watch = %watch.name,
+            namespace = %watch.namespace,
+            "resuming watch after daemon restart"
+        );
+        let _ = ctx
+            .event_tx
+            .send(Event::WatchStarted {
+                watch_name: watch.name.clone(),
+                project_root: watch.project_root.clone(),
+                runbook_hash: watch.runbook_hash.clone(),
+                paths: watch.paths.clone(),
+                debounce: watch.debounce.clone(),
+                run_target: watch.run_target.clone(),
+                namespace: watch.namespace.clone(),
+            })
+            .await;
+    }
+
+    // Resume git triggers that were running before the daemon restarted.
+    // Their recorded ref positions are kept, so refs that moved while the
+    // daemon was down run the job once on the first scan.
+    let running_git_triggers: Vec<_> = state
+        .git_triggers
+        .values()
+        .filter(|t| t.status == "running")
+        .collect();
+
+    if !running_git_triggers.is_empty() {
+        info!(
+            "Resuming {} running git triggers",
+            running_git_triggers.len()
+        );
+    }
+
+    for trigger in &running_git_triggers {
+        info!(
+            git_trigger = %trigger.name,
+            namespace = %trigger.namespace,
+            "resuming git trigger after daemon restart"
+        );
+        let _ = ctx
+            .event_tx
+            .send(Event::GitTriggerStarted {
+                trigger_name: trigger.name.clone(),
+                project_root: trigger.project_root.clone(),
+                runbook_hash: trigger.runbook_hash.clone(),
+                ref_pattern: trigger.ref_pattern.clone(),
+                run_target: trigger.run_target.clone(),
+                namespace: trigger.namespace.clone(),
             })
             .await;
     }
@@ -282,6 +367,34 @@ pub(crate) async fn reconcile_state(ctx: &ReconcileCtx) {
             continue;
         }
 
+        // Skip jobs blocked on locks or other jobs — they have no session
+        // yet and are started below once they are free to run
+        if job.step_status.is_blocked() {
+            info!(job_id = %job.id, "skipping Blocked job (waiting on locks or jobs)");
+            continue;
+        }
+
+        // Skip jobs waiting to retry a failed step — the re-armed timer
+        // starts the step again
+        if state
+            .timers
+            .contains_key(TimerId::step_retry(&JobId::new(&job.id)).as_str())
+        {
+            info!(job_id = %job.id, "skipping job waiting to retry its step");
+            continue;
+        }
+
+        // Skip jobs waiting on child jobs (parallel branches or a sub-job) —
+        // each child is reconciled on its own and advances the parent when
+        // it finishes
+        let waiting_on_children = job.step_history.last().is_some_and(|r| {
+            (r.job_id.is_some() || !r.branches.is_empty()) && r.finished_at_ms.is_none()
+        });
+        if waiting_on_children {
+            info!(job_id = %job.id, "skipping job waiting on child jobs");
+            continue;
+        }
+
         // Determine the tmux session ID
         let Some(session_id) = &job.session_id else {
             warn!(job_id = %job.id, "no session_id, marking failed");
@@ -415,4 +528,34 @@ pub(crate) async fn reconcile_state(ctx: &ReconcileCtx) {
                 .await;
         }
     }
+
+    // Start blocked steps whose locks were released while the daemon was down
+    match ctx.runtime.wake_lock_waiters().await {
+        Ok(events) => {
+            for event in events {
+                let _ = ctx.event_tx.send(event).await;
+            }
+        }
+        Err(e) => warn!(error = %e, "failed to wake lock waiters"),
+    }
+
+    // Start held jobs whose dependencies finished while the daemon was down
+    match ctx.runtime.wake_dependents(None).await {
+        Ok(events) => {
+            for event in events {
+                let _ = ctx.event_tx.send(event).await;
+            }
+        }
+        Err(e) => warn!(error = %e, "failed to wake dependent jobs"),
+    }
+
+    // Admit queued jobs that fit under the (possibly changed) limits
+    match ctx.runtime.admit_queued_jobs().await {
+        Ok(events) => {
+            for event in events {
+                let _ = ctx.event_tx.send(event).await;
+            }
+        }
+        Err(e) => warn!(error = %e, "failed to admit queued jobs"),
+    }
 }
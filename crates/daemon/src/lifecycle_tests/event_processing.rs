@@ -16,6 +16,7 @@ async fn process_event_persists_result_events_to_wal() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -124,6 +125,9 @@ async fn cancelled_job_survives_restart_as_terminal() {
         namespace: String::new(),
         created_at_epoch_ms: 1_000_000,
         cron_name: None,
+        after: vec![],
+        priority: 0,
+        project_root: None,
     });
 
     // Replay WAL events (as the daemon does on startup)
@@ -157,6 +161,7 @@ async fn process_event_materializes_state() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -186,7 +191,7 @@ async fn result_events_delivered_once_through_engine_loop() {
     // matches expectations (no duplicates from local re-processing).
     let (mut daemon, mut event_reader, _wal_path) = setup_daemon_with_job_and_reader().await;
 
-    // Process ShellExited -- produces StepCompleted + JobAdvanced result events
+    // Process ShellExited -- produces JobAdvanced + StepCompleted result events
     daemon
         .process_event(Event::ShellExited {
             job_id: JobId::new("pipe-1"),
@@ -194,6 +199,7 @@ async fn result_events_delivered_once_through_engine_loop() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -220,24 +226,20 @@ async fn result_events_delivered_once_through_engine_loop() {
 
     // Read any secondary events produced by processing result events
     let mut secondary_events = 0usize;
-    loop {
-        match tokio::time::timeout(std::time::Duration::from_millis(50), event_reader.recv()).await
-        {
-            Ok(Ok(Some(entry))) => {
-                event_reader.mark_processed(entry.seq);
-                secondary_events += 1;
-            }
-            _ => break,
-        }
+    while let Ok(Ok(Some(entry))) =
+        tokio::time::timeout(std::time::Duration::from_millis(50), event_reader.recv()).await
+    {
+        event_reader.mark_processed(entry.seq);
+        secondary_events += 1;
     }
 
     // ShellExited -> advance_job produces:
-    //   1. StepCompleted (current step done)
-    //   2. JobAdvanced("done") (from completion_effects)
-    //   3. StepCompleted (from completion_effects)
+    //   1. JobAdvanced("done") (step transition)
+    //   2. StepCompleted (from completion_effects)
+    // completion_effects doesn't advance again once the job is already done.
     assert_eq!(
-        total_wal_events, 3,
-        "ShellExited should produce exactly 3 result events in WAL"
+        total_wal_events, 2,
+        "ShellExited should produce exactly 2 result events in WAL"
     );
 
     // JobAdvanced("done") handler returns empty (no worker tracking this
@@ -269,6 +271,9 @@ fn bus_emit_maps_job_created() {
         created_at_epoch_ms: 0,
         namespace: String::new(),
         cron_name: None,
+        after: vec![],
+        priority: 0,
+        project_root: None,
     };
     let (event_type, payload) = map_event_to_bus_emit(&event).unwrap();
     assert_eq!(event_type, "OjJobCreated");
@@ -9,7 +9,7 @@ use oj_adapters::{
 };
 use oj_core::{
     AgentRun, AgentRunId, AgentRunStatus, Event, Job, JobConfig, JobId, StepOutcome, StepRecord,
-    StepStatus, SystemClock,
+    StepStatus, SystemClock, TimerId,
 };
 use oj_engine::{Runtime, RuntimeConfig, RuntimeDeps};
 use oj_runbook::{JobDef, RunDirective, Runbook, StepDef};
@@ -50,7 +50,15 @@ fn test_runbook() -> Runbook {
                 on_done: None,
                 on_fail: None,
                 on_cancel: None,
+                on_exit: HashMap::new(),
+                acquire: vec![],
+                timeout: None,
+                on_timeout: None,
+                retry: None,
             }],
+            depends_on: vec![],
+            on_dependency_fail: Default::default(),
+            budget: None,
         },
     );
     Runbook {
@@ -62,6 +70,7 @@ fn test_runbook() -> Runbook {
         queues: HashMap::new(),
         workers: HashMap::new(),
         crons: HashMap::new(),
+        ..Default::default()
     }
 }
 
@@ -132,6 +141,7 @@ async fn setup_daemon_with_job_and_reader() -> (DaemonState, EventReader, PathBu
         RuntimeConfig {
             state_dir: dir_path.clone(),
             log_dir: dir_path.join("logs"),
+            max_jobs: None,
         },
         internal_tx,
     ));
@@ -150,6 +160,7 @@ async fn setup_daemon_with_job_and_reader() -> (DaemonState, EventReader, PathBu
             snapshot_path: dir_path.join("test.snapshot"),
             workspaces_path: dir_path.join("workspaces"),
             logs_path: dir_path.join("logs"),
+            max_jobs: None,
         },
         lock_file,
         state,
@@ -175,6 +186,7 @@ fn test_config(dir: &Path) -> Config {
         snapshot_path: dir.join("test.snapshot"),
         workspaces_path: dir.join("workspaces"),
         logs_path: dir.join("logs"),
+        max_jobs: None,
     }
 }
 
@@ -196,6 +208,7 @@ fn setup_reconcile_runtime(dir_path: &Path) -> (Arc<DaemonRuntime>, TracedSessio
         RuntimeConfig {
             state_dir: dir_path.to_path_buf(),
             log_dir: dir_path.join("logs"),
+            max_jobs: None,
         },
         internal_tx,
     ));
@@ -264,6 +277,8 @@ fn make_job_with_agent(id: &str, step: &str, agent_uuid: &str, session_id: &str)
             outcome: StepOutcome::Running,
             agent_id: Some(agent_uuid.to_string()),
             agent_name: Some("test-agent".to_string()),
+            job_id: None,
+            branches: Vec::new(),
         }])
         .build()
 }
@@ -27,6 +27,7 @@ async fn reconcile_state_resumes_running_workers() {
         RuntimeConfig {
             state_dir: dir_path.clone(),
             log_dir: dir_path.join("logs"),
+            max_jobs: None,
         },
         internal_tx,
     ));
@@ -42,8 +43,12 @@ async fn reconcile_state_resumes_running_workers() {
             runbook_hash: "abc123".to_string(),
             status: "running".to_string(),
             active_job_ids: vec![],
+            active_agent_run_ids: vec![],
             queue_name: "tasks".to_string(),
+            queues: Default::default(),
             concurrency: 2,
+            rate: Some("10/h".to_string()),
+            bucket_full_at_ms: None,
         },
     );
     test_state.workers.insert(
@@ -55,8 +60,12 @@ async fn reconcile_state_resumes_running_workers() {
             runbook_hash: "def456".to_string(),
             status: "stopped".to_string(),
             active_job_ids: vec![],
+            active_agent_run_ids: vec![],
             queue_name: "other".to_string(),
+            queues: Default::default(),
             concurrency: 1,
+            rate: None,
+            bucket_full_at_ms: None,
         },
     );
 
@@ -81,14 +90,18 @@ async fn reconcile_state_resumes_running_workers() {
             project_root,
             runbook_hash,
             queue_name,
+            queues,
             concurrency,
+            rate,
             namespace,
         } => {
             assert_eq!(worker_name, "running-worker");
             assert_eq!(*project_root, dir_path);
             assert_eq!(runbook_hash, "abc123");
             assert_eq!(queue_name, "tasks");
+            assert!(queues.is_empty());
             assert_eq!(*concurrency, 2);
+            assert_eq!(rate.as_deref(), Some("10/h"));
             assert_eq!(namespace, "myns");
         }
         _ => unreachable!(),
@@ -254,6 +267,34 @@ async fn reconcile_job_no_session_id_waiting_is_skipped() {
     );
 }
 
+#[tokio::test]
+async fn reconcile_job_pending_step_retry_is_skipped() {
+    // A shell step waiting on its retry timer has no session; the re-armed
+    // timer restarts it, so reconciliation must not fail the job.
+    let dir = tempdir().unwrap();
+    let dir_path = dir.path().to_owned();
+    let (runtime, session_adapter) = setup_reconcile_runtime(&dir_path);
+
+    let job = Job::builder().id("pipe-retry").step("fetch").build();
+
+    let mut test_state = MaterializedState::default();
+    test_state.jobs.insert("pipe-retry".to_string(), job);
+    test_state.timers.insert(
+        TimerId::step_retry(&JobId::new("pipe-retry")).to_string(),
+        u64::MAX,
+    );
+
+    let events = run_reconcile(&runtime, &session_adapter, test_state).await;
+
+    assert!(
+        !events
+            .iter()
+            .any(|e| matches!(e, Event::JobAdvanced { .. })),
+        "job waiting to retry should not be failed, got: {:?}",
+        events
+    );
+}
+
 #[tokio::test]
 async fn reconcile_agent_run_dead_session_emits_gone_with_correct_id() {
     // When an agent run's tmux session is dead, reconciliation should
@@ -422,3 +463,47 @@ async fn reconcile_agent_run_no_session_id_marks_failed() {
         _ => unreachable!(),
     }
 }
+
+#[tokio::test]
+async fn reconcile_resumes_cron_from_replayed_fire_time() {
+    // Cron times come from the events themselves, so state rebuilt from the
+    // WAL resumes catch-up from the last real fire, not the replay time.
+    let dir = tempdir().unwrap();
+    let (runtime, session_adapter) = setup_reconcile_runtime(dir.path());
+
+    let mut replayed = MaterializedState::default();
+    replayed.apply_event(&Event::CronStarted {
+        cron_name: "janitor".to_string(),
+        project_root: dir.path().to_path_buf(),
+        runbook_hash: "abc123".to_string(),
+        interval: "30m".to_string(),
+        schedule: None,
+        timezone: None,
+        run_target: "job:cleanup".to_string(),
+        namespace: "myns".to_string(),
+        started_at_ms: 1_000_000,
+        resumed_from_ms: None,
+    });
+    replayed.apply_event(&Event::CronFired {
+        cron_name: "janitor".to_string(),
+        job_id: JobId::new("job-1"),
+        agent_run_id: None,
+        namespace: "myns".to_string(),
+        fired_at_ms: 2_800_000,
+    });
+
+    let events = run_reconcile(&runtime, &session_adapter, replayed).await;
+
+    let resumed: Vec<_> = events
+        .iter()
+        .filter_map(|e| match e {
+            Event::CronStarted {
+                started_at_ms,
+                resumed_from_ms,
+                ..
+            } => Some((*started_at_ms, *resumed_from_ms)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(resumed, vec![(1_000_000, Some(2_800_000))]);
+}
@@ -178,8 +178,12 @@ fn reconcile_context_counts_running_workers() {
             runbook_hash: "hash".to_string(),
             status: "running".to_string(),
             active_job_ids: vec![],
+            active_agent_run_ids: vec![],
             queue_name: "q".to_string(),
+            queues: Default::default(),
             concurrency: 1,
+            rate: None,
+            bucket_full_at_ms: None,
         },
     );
     state.workers.insert(
@@ -191,8 +195,12 @@ fn reconcile_context_counts_running_workers() {
             runbook_hash: "hash".to_string(),
             status: "stopped".to_string(),
             active_job_ids: vec![],
+            active_agent_run_ids: vec![],
             queue_name: "q".to_string(),
+            queues: Default::default(),
             concurrency: 1,
+            rate: None,
+            bucket_full_at_ms: None,
         },
     );
 
@@ -219,18 +227,17 @@ async fn shutdown_saves_final_snapshot() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
 
     // Simulate the main loop: read events from WAL and mark processed
     daemon.event_bus.flush().unwrap();
-    loop {
-        match tokio::time::timeout(std::time::Duration::from_millis(50), event_reader.recv()).await
-        {
-            Ok(Ok(Some(entry))) => event_reader.mark_processed(entry.seq),
-            _ => break,
-        }
+    while let Ok(Ok(Some(entry))) =
+        tokio::time::timeout(std::time::Duration::from_millis(50), event_reader.recv()).await
+    {
+        event_reader.mark_processed(entry.seq);
     }
 
     // No snapshot should exist yet
@@ -6,7 +6,8 @@
 use std::collections::HashMap;
 use std::path::Path;
 
-use oj_core::{IdGen, JobId, UuidIdGen};
+use oj_core::{IdGen, JobDependency, JobId, UuidIdGen};
+use oj_storage::MaterializedState;
 
 use crate::protocol::Response;
 
@@ -23,6 +24,8 @@ pub(super) struct RunCommandParams<'a> {
     pub command: &'a str,
     pub args: &'a [String],
     pub named_args: &'a HashMap<String, String>,
+    pub after: &'a [String],
+    pub priority: i32,
     pub ctx: &'a ListenCtx,
 }
 
@@ -37,6 +40,8 @@ pub(super) async fn handle_run_command(
         command,
         args,
         named_args,
+        after,
+        priority,
         ctx,
     } = params;
     // Load runbook from project (with --project fallback and suggest hints)
@@ -87,6 +92,21 @@ pub(super) async fn handle_run_command(
         });
     }
 
+    // Resolve --after dependencies to known jobs
+    let after = if after.is_empty() {
+        Vec::new()
+    } else {
+        if !matches!(&cmd_def.run, oj_runbook::RunDirective::Job { .. }) {
+            return Ok(Response::Error {
+                message: format!("--after requires a command that runs a job: {}", command),
+            });
+        }
+        match resolve_after(after, &ctx.state.lock()) {
+            Ok(deps) => deps,
+            Err(message) => return Ok(Response::Error { message }),
+        }
+    };
+
     // Detect if this is a standalone agent command
     let is_agent = matches!(&cmd_def.run, oj_runbook::RunDirective::Agent { .. });
     let agent_name_if_standalone =
@@ -114,6 +134,8 @@ pub(super) async fn handle_run_command(
         namespace: namespace.to_string(),
         command: command.to_string(),
         args: parsed_args,
+        after,
+        priority,
     };
 
     emit(&ctx.event_bus, event)?;
@@ -135,6 +157,24 @@ pub(super) async fn handle_run_command(
     }
 }
 
+/// Parse `--after` specs and resolve each job ID (or unique prefix).
+fn resolve_after(
+    specs: &[String],
+    state: &MaterializedState,
+) -> Result<Vec<JobDependency>, String> {
+    specs
+        .iter()
+        .map(|spec| {
+            let mut dep: JobDependency = spec.parse()?;
+            let job = state
+                .get_job(&dep.job_id)
+                .ok_or_else(|| format!("--after: job not found: {}", dep.job_id))?;
+            dep.job_id = job.id.clone();
+            Ok(dep)
+        })
+        .collect()
+}
+
 /// Load a runbook from a project root by scanning all .toml files.
 fn load_runbook(project_root: &Path, name: &str) -> Result<oj_runbook::Runbook, String> {
     let runbook_dir = project_root.join(".oj/runbooks");
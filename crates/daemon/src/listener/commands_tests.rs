@@ -5,9 +5,11 @@ use std::collections::HashMap;
 
 use tempfile::tempdir;
 
+use oj_core::{DependencyCondition, JobDependency};
+
 use crate::protocol::Response;
 
-use super::super::test_ctx;
+use super::super::{test_ctx, test_ctx_with_wal};
 use super::{handle_run_command, RunCommandParams};
 
 /// Helper: create a temp project with a runbook TOML and return the project root path.
@@ -38,6 +40,8 @@ run = "echo deploying"
         command: "deploy",
         args: &[],
         named_args: &HashMap::new(),
+        after: &[],
+        priority: 0,
         ctx: &ctx,
     })
     .await
@@ -77,6 +81,8 @@ run = "make"
         command: "build",
         args: &[],
         named_args: &HashMap::new(),
+        after: &[],
+        priority: 0,
         ctx: &ctx,
     })
     .await
@@ -109,6 +115,8 @@ run = "echo deploying"
         command: "deploj",
         args: &[],
         named_args: &HashMap::new(),
+        after: &[],
+        priority: 0,
         ctx: &ctx,
     })
     .await
@@ -133,6 +141,8 @@ async fn unknown_command_returns_error_without_hint_when_no_match() {
         command: "xyz",
         args: &[],
         named_args: &HashMap::new(),
+        after: &[],
+        priority: 0,
         ctx: &ctx,
     })
     .await
@@ -144,3 +154,91 @@ async fn unknown_command_returns_error_without_hint_when_no_match() {
         result
     );
 }
+
+const BUILD_RUNBOOK: &str = r#"
+[command.build]
+run = { job = "build-all" }
+
+[command.deploy]
+run = "echo deploying"
+
+[job.build-all]
+input  = []
+
+[[job.build-all.step]]
+name = "compile"
+run = "make"
+"#;
+
+async fn run_after(
+    ctx: &super::super::ListenCtx,
+    project: &std::path::Path,
+    command: &str,
+    after: &[String],
+) -> Response {
+    handle_run_command(RunCommandParams {
+        project_root: project,
+        invoke_dir: project,
+        namespace: "",
+        command,
+        args: &[],
+        named_args: &HashMap::new(),
+        after,
+        priority: 0,
+        ctx,
+    })
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn after_resolves_job_id_prefixes() {
+    let project = project_with_runbook(BUILD_RUNBOOK);
+    let wal_dir = tempdir().unwrap();
+    let (ctx, wal) = test_ctx_with_wal(wal_dir.path());
+    ctx.state
+        .lock()
+        .apply_event(&oj_core::test_support::job_create_event(
+            "0a1b2c3d-feature",
+            "build-all",
+            "feature",
+            "compile",
+        ));
+
+    let result = run_after(&ctx, project.path(), "build", &["0a1b:any".to_string()]).await;
+    assert!(
+        matches!(result, Response::CommandStarted { .. }),
+        "{result:?}"
+    );
+
+    let entry = wal.lock().next_unprocessed().unwrap().unwrap();
+    match entry.event {
+        oj_core::Event::CommandRun { after, .. } => assert_eq!(
+            after,
+            vec![JobDependency::new(
+                "0a1b2c3d-feature",
+                DependencyCondition::Any
+            )]
+        ),
+        other => panic!("expected CommandRun, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn after_rejects_unknown_jobs_and_non_job_commands() {
+    let project = project_with_runbook(BUILD_RUNBOOK);
+    let wal_dir = tempdir().unwrap();
+    let ctx = test_ctx(wal_dir.path());
+
+    let result = run_after(&ctx, project.path(), "build", &["feedface".to_string()]).await;
+    assert!(
+        matches!(result, Response::Error { ref message } if message.contains("job not found: feedface")),
+        "{result:?}"
+    );
+
+    let result = run_after(&ctx, project.path(), "deploy", &["feedface".to_string()]).await;
+    assert!(
+        matches!(result, Response::Error { ref message } if message.contains("requires a command that runs a job")),
+        "{result:?}"
+    );
+}
@@ -5,6 +5,7 @@
 
 use std::path::Path;
 use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use parking_lot::Mutex;
 
@@ -68,7 +69,7 @@ pub(super) fn handle_cron_start(
 
     // Validate run is a job or agent reference
     let run_target = match &cron_def.run {
-        oj_runbook::RunDirective::Job { job } => {
+        oj_runbook::RunDirective::Job { job, .. } => {
             if runbook.get_job(job).is_none() {
                 return Ok(Response::Error {
                     message: format!("cron '{}' references unknown job '{}'", cron_name, job),
@@ -100,8 +101,15 @@ pub(super) fn handle_cron_start(
         project_root: project_root.to_path_buf(),
         runbook_hash,
         interval: cron_def.interval.clone(),
+        schedule: cron_def.schedule.clone(),
+        timezone: cron_def.timezone.clone(),
         run_target,
         namespace: namespace.to_string(),
+        started_at_ms: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64,
+        resumed_from_ms: None,
     };
 
     emit(&ctx.event_bus, event.clone())?;
@@ -227,7 +235,7 @@ pub(super) async fn handle_cron_once(
 
     // Validate run is a job or agent reference and build event
     let (job_kind, run_target) = match &cron_def.run {
-        oj_runbook::RunDirective::Job { job } => {
+        oj_runbook::RunDirective::Job { job, .. } => {
             if runbook.get_job(job).is_none() {
                 return Ok(Response::Error {
                     message: format!("cron '{}' references unknown job '{}'", cron_name, job),
@@ -27,6 +27,8 @@ fn make_cron_record(
         runbook_hash: "fake-hash".to_string(),
         status: status.to_string(),
         interval: interval.to_string(),
+        schedule: None,
+        timezone: None,
         run_target: format!("job:{}", job_kind),
         started_at_ms: clock.epoch_ms(),
         last_fired_at_ms: None,
@@ -295,6 +297,8 @@ fn restart_stops_existing_then_starts() {
                 runbook_hash: "fake-hash".to_string(),
                 status: "running".to_string(),
                 interval: "1h".to_string(),
+                schedule: None,
+                timezone: None,
                 run_target: "job:deploy".to_string(),
                 started_at_ms: 0,
                 last_fired_at_ms: None,
@@ -332,6 +336,8 @@ fn restart_with_valid_runbook_returns_started() {
                 runbook_hash: "old-hash".to_string(),
                 status: "running".to_string(),
                 interval: "24h".to_string(),
+                schedule: None,
+                timezone: None,
                 run_target: "job:deploy".to_string(),
                 started_at_ms: 0,
                 last_fired_at_ms: None,
@@ -369,6 +375,8 @@ async fn once_with_wrong_project_root_falls_back_to_namespace() {
                 runbook_hash: "fake-hash".to_string(),
                 status: "running".to_string(),
                 interval: "24h".to_string(),
+                schedule: None,
+                timezone: None,
                 run_target: String::new(),
                 started_at_ms: 1_000,
                 last_fired_at_ms: None,
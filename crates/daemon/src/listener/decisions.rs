@@ -6,7 +6,9 @@
 use std::collections::HashMap;
 use std::time::{SystemTime, UNIX_EPOCH};
 
-use oj_core::{AgentRunId, AgentRunStatus, DecisionOption, DecisionSource, Event, JobId, OwnerId};
+use oj_core::{
+    AgentRunId, AgentRunStatus, Decision, DecisionOption, DecisionSource, Event, JobId, OwnerId,
+};
 
 use crate::protocol::Response;
 
@@ -19,6 +21,7 @@ pub(super) fn handle_decision_resolve(
     id: &str,
     chosen: Option<usize>,
     message: Option<String>,
+    values: HashMap<String, String>,
 ) -> Result<Response, ConnectionError> {
     let state_guard = ctx.state.lock();
 
@@ -54,6 +57,10 @@ pub(super) fn handle_decision_resolve(
         });
     }
 
+    if let Some(message) = validate_field_values(decision, chosen, &values) {
+        return Ok(Response::Error { message });
+    }
+
     let full_id = decision.id.as_str().to_string();
     let job_id = decision.job_id.clone();
     let decision_namespace = decision.namespace.clone();
@@ -61,6 +68,7 @@ pub(super) fn handle_decision_resolve(
     let decision_options = decision.options.clone();
     let decision_owner = decision.owner.clone();
     let decision_session_id = decision.agent_id.clone();
+    let is_approval_step = decision.step.is_some();
 
     // Get the job step for StepCompleted events (for job-owned decisions)
     let job_step = state_guard.jobs.get(&job_id).map(|p| p.step.clone());
@@ -88,9 +96,15 @@ pub(super) fn handle_decision_resolve(
         message: message.clone(),
         resolved_at_ms,
         namespace: decision_namespace,
+        values,
     };
     emit(&ctx.event_bus, event)?;
 
+    // Approval steps are routed by the engine from the resolution itself
+    if is_approval_step {
+        return Ok(Response::DecisionResolved { id: full_id });
+    }
+
     // Map chosen option to action based on owner type
     let action_events = match &decision_owner {
         OwnerId::AgentRun(ar_id) => map_decision_to_agent_run_action(
@@ -124,6 +138,45 @@ pub(super) fn handle_decision_resolve(
     Ok(Response::DecisionResolved { id: full_id })
 }
 
+/// Check field values against the fields a decision asks for.
+///
+/// Approval steps must be resolved with a choice, and every field is
+/// required unless the choice is the trailing Cancel option.
+fn validate_field_values(
+    decision: &Decision,
+    chosen: Option<usize>,
+    values: &HashMap<String, String>,
+) -> Option<String> {
+    let mut unknown: Vec<&String> = values
+        .keys()
+        .filter(|k| !decision.fields.contains(k))
+        .collect();
+    unknown.sort();
+    if let Some(field) = unknown.first() {
+        return Some(if decision.fields.is_empty() {
+            format!("decision {} has no fields", decision.id.short(8))
+        } else {
+            format!(
+                "unknown field '{}'; this decision asks for: {}",
+                field,
+                decision.fields.join(", ")
+            )
+        });
+    }
+    decision.step.as_ref()?;
+    let Some(choice) = chosen else {
+        return Some("approval steps must be resolved with a choice".to_string());
+    };
+    if choice == decision.options.len() {
+        return None;
+    }
+    decision
+        .fields
+        .iter()
+        .find(|f| !values.contains_key(*f))
+        .map(|f| format!("missing field '{}' (use --field {}=<value>)", f, f))
+}
+
 /// Intermediate representation of a resolved decision action.
 ///
 /// Captures the intent of a decision resolution independent of whether the
@@ -3,9 +3,13 @@
 
 use super::{
     build_question_resume_message, build_resume_message, map_decision_to_agent_run_action,
-    map_decision_to_job_action, resolve_decision_action, ResolvedAction,
+    map_decision_to_job_action, resolve_decision_action, validate_field_values, ResolvedAction,
 };
-use oj_core::{AgentRunId, AgentRunStatus, DecisionOption, DecisionSource, Event};
+use oj_core::{
+    AgentRunId, AgentRunStatus, Decision, DecisionId, DecisionOption, DecisionSource, Event, JobId,
+    OwnerId,
+};
+use std::collections::HashMap;
 
 #[test]
 fn idle_dismiss_returns_no_action() {
@@ -520,3 +524,79 @@ fn resolve_question_option_3_is_not_cancel_when_more_options() {
         ResolvedAction::Cancel,
     );
 }
+
+fn approval_decision(fields: &[&str]) -> Decision {
+    Decision {
+        id: DecisionId::new("dec-a1b2c3d4e5"),
+        job_id: "pipe-1".to_string(),
+        agent_id: None,
+        owner: OwnerId::Job(JobId::new("pipe-1")),
+        source: DecisionSource::Approval,
+        context: "Ship it?".to_string(),
+        options: vec![
+            DecisionOption::new("ship"),
+            DecisionOption::new("hold"),
+            DecisionOption::new("Cancel"),
+        ],
+        chosen: None,
+        message: None,
+        created_at_ms: 0,
+        resolved_at_ms: None,
+        superseded_by: None,
+        namespace: String::new(),
+        step: Some("signoff".to_string()),
+        fields: fields.iter().map(|f| f.to_string()).collect(),
+        values: HashMap::new(),
+    }
+}
+
+fn values(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[test]
+fn approval_fields_are_required_unless_cancelling() {
+    let d = approval_decision(&["reason"]);
+    assert_eq!(
+        validate_field_values(&d, Some(1), &HashMap::new()).as_deref(),
+        Some("missing field 'reason' (use --field reason=<value>)")
+    );
+    assert_eq!(
+        validate_field_values(&d, Some(1), &values(&[("reason", "ok")])),
+        None
+    );
+    assert_eq!(validate_field_values(&d, Some(3), &HashMap::new()), None);
+}
+
+#[test]
+fn approval_requires_a_choice() {
+    let d = approval_decision(&[]);
+    assert_eq!(
+        validate_field_values(&d, None, &HashMap::new()).as_deref(),
+        Some("approval steps must be resolved with a choice")
+    );
+}
+
+#[test]
+fn unknown_field_values_are_rejected() {
+    let d = approval_decision(&["reason"]);
+    assert_eq!(
+        validate_field_values(&d, Some(1), &values(&[("reason", "ok"), ("ticket", "7")]))
+            .as_deref(),
+        Some("unknown field 'ticket'; this decision asks for: reason")
+    );
+
+    let mut escalation = approval_decision(&[]);
+    escalation.step = None;
+    assert_eq!(
+        validate_field_values(&escalation, Some(1), &values(&[("reason", "ok")])).as_deref(),
+        Some("decision dec-a1b2 has no fields")
+    );
+    assert_eq!(
+        validate_field_values(&escalation, None, &HashMap::new()),
+        None
+    );
+}
@@ -0,0 +1,227 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Git ref trigger request handlers.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+
+use oj_core::Event;
+use oj_storage::MaterializedState;
+
+use crate::protocol::Response;
+
+use super::mutations::emit;
+use super::suggest;
+use super::workers::hash_and_emit_runbook;
+use super::ConnectionError;
+use super::ListenCtx;
+
+/// Handle a GitTriggerStart request.
+///
+/// Idempotent like `WatchStart`. Ref positions recorded by an earlier run
+/// are kept unless the ref pattern changed, so starting a stopped trigger
+/// runs the job for refs that moved in the meantime.
+pub(super) fn handle_git_trigger_start(
+    ctx: &ListenCtx,
+    project_root: &Path,
+    namespace: &str,
+    trigger_name: &str,
+    all: bool,
+) -> Result<Response, ConnectionError> {
+    if all {
+        return handle_git_trigger_start_all(ctx, project_root, namespace);
+    }
+
+    // Load runbook to validate the trigger exists.
+    let (runbook, effective_root) = match super::load_runbook_with_fallback(
+        project_root,
+        namespace,
+        &ctx.state,
+        |root| load_runbook_for_git_trigger(root, trigger_name),
+        || {
+            suggest_for_git_trigger(
+                Some(project_root),
+                trigger_name,
+                namespace,
+                "oj git-trigger start",
+                &ctx.state,
+            )
+        },
+    ) {
+        Ok(result) => result,
+        Err(resp) => return Ok(resp),
+    };
+    let project_root = &effective_root;
+
+    let trigger_def = match runbook.get_git_trigger(trigger_name) {
+        Some(def) => def,
+        None => {
+            return Ok(Response::Error {
+                message: format!("unknown git trigger: {}", trigger_name),
+            })
+        }
+    };
+
+    // Runbook validation guarantees a job reference; check it still resolves
+    let run_target = match trigger_def.run.job_name() {
+        Some(job) if runbook.get_job(job).is_some() => format!("job:{}", job),
+        Some(job) => {
+            return Ok(Response::Error {
+                message: format!(
+                    "git trigger '{}' references unknown job '{}'",
+                    trigger_name, job
+                ),
+            })
+        }
+        None => {
+            return Ok(Response::Error {
+                message: format!("git trigger '{}' run must reference a job", trigger_name),
+            })
+        }
+    };
+
+    // Hash runbook and emit RunbookLoaded for WAL persistence
+    let runbook_hash = hash_and_emit_runbook(&ctx.event_bus, &runbook)?;
+
+    let event = Event::GitTriggerStarted {
+        trigger_name: trigger_name.to_string(),
+        project_root: project_root.to_path_buf(),
+        runbook_hash,
+        ref_pattern: trigger_def.ref_pattern.clone(),
+        run_target,
+        namespace: namespace.to_string(),
+    };
+
+    emit(&ctx.event_bus, event.clone())?;
+
+    // Apply to materialized state before responding so queries see it
+    // immediately. apply_event is idempotent.
+    {
+        let mut state = ctx.state.lock();
+        state.apply_event(&event);
+    }
+
+    Ok(Response::GitTriggerStarted {
+        trigger_name: trigger_name.to_string(),
+    })
+}
+
+/// Handle starting all git triggers defined in runbooks.
+fn handle_git_trigger_start_all(
+    ctx: &ListenCtx,
+    project_root: &Path,
+    namespace: &str,
+) -> Result<Response, ConnectionError> {
+    let runbook_dir = project_root.join(".oj/runbooks");
+    let names = oj_runbook::collect_all_git_triggers(&runbook_dir)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(name, _)| name);
+
+    let (started, skipped) = super::collect_start_results(
+        names,
+        |name| handle_git_trigger_start(ctx, project_root, namespace, name, false),
+        |resp| match resp {
+            Response::GitTriggerStarted { trigger_name } => Some(trigger_name.clone()),
+            _ => None,
+        },
+    )?;
+
+    Ok(Response::GitTriggersStarted { started, skipped })
+}
+
+/// Handle a GitTriggerStop request.
+pub(super) fn handle_git_trigger_stop(
+    ctx: &ListenCtx,
+    trigger_name: &str,
+    namespace: &str,
+    project_root: Option<&Path>,
+) -> Result<Response, ConnectionError> {
+    if let Err(resp) = super::require_scoped_resource(
+        &ctx.state,
+        namespace,
+        trigger_name,
+        "git trigger",
+        |s, k| s.git_triggers.contains_key(k),
+        || {
+            suggest_for_git_trigger(
+                project_root,
+                trigger_name,
+                namespace,
+                "oj git-trigger stop",
+                &ctx.state,
+            )
+        },
+    ) {
+        return Ok(resp);
+    }
+
+    let event = Event::GitTriggerStopped {
+        trigger_name: trigger_name.to_string(),
+        namespace: namespace.to_string(),
+    };
+
+    emit(&ctx.event_bus, event.clone())?;
+
+    {
+        let mut state = ctx.state.lock();
+        state.apply_event(&event);
+    }
+
+    Ok(Response::Ok)
+}
+
+#[cfg(test)]
+#[path = "git_triggers_tests.rs"]
+mod tests;
+
+/// Load a runbook that contains the given git trigger name.
+fn load_runbook_for_git_trigger(
+    project_root: &Path,
+    trigger_name: &str,
+) -> Result<oj_runbook::Runbook, String> {
+    let runbook_dir = project_root.join(".oj/runbooks");
+    oj_runbook::find_runbook_by_git_trigger(&runbook_dir, trigger_name)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("no runbook found containing git trigger '{}'", trigger_name))
+}
+
+/// Generate a "did you mean" suggestion for a git trigger name.
+fn suggest_for_git_trigger(
+    project_root: Option<&Path>,
+    trigger_name: &str,
+    namespace: &str,
+    command_prefix: &str,
+    state: &Arc<Mutex<MaterializedState>>,
+) -> String {
+    let ns = namespace.to_string();
+    let root = project_root.map(|r| r.to_path_buf());
+    suggest::suggest_for_resource(
+        trigger_name,
+        namespace,
+        command_prefix,
+        state,
+        suggest::ResourceType::GitTrigger,
+        || {
+            root.map(|r| {
+                oj_runbook::collect_all_git_triggers(&r.join(".oj/runbooks"))
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|(name, _)| name)
+                    .collect()
+            })
+            .unwrap_or_default()
+        },
+        |state| {
+            state
+                .git_triggers
+                .values()
+                .filter(|t| t.namespace == ns)
+                .map(|t| t.name.clone())
+                .collect()
+        },
+    )
+}
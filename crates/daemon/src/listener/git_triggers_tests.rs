@@ -0,0 +1,124 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use tempfile::tempdir;
+
+use crate::protocol::Response;
+
+use super::{handle_git_trigger_start, handle_git_trigger_stop};
+
+/// Helper: create a temp project with a valid git trigger runbook.
+fn project_with_git_triggers() -> tempfile::TempDir {
+    let dir = tempdir().unwrap();
+    let runbook_dir = dir.path().join(".oj/runbooks");
+    std::fs::create_dir_all(&runbook_dir).unwrap();
+    std::fs::write(
+        runbook_dir.join("test.hcl"),
+        r#"
+git_trigger "main" {
+  ref = "refs/heads/main"
+  run = { job = "ci" }
+}
+
+git_trigger "features" {
+  ref = "refs/heads/feature/*"
+  run = { job = "ci" }
+}
+
+job "ci" {
+  step "check" {
+    run = "git diff ${git.old} ${git.new}"
+  }
+}
+"#,
+    )
+    .unwrap();
+    dir
+}
+
+#[test]
+fn start_applies_state_before_responding() {
+    let project = project_with_git_triggers();
+    let wal_dir = tempdir().unwrap();
+    let ctx = super::super::test_ctx(wal_dir.path());
+
+    let result = handle_git_trigger_start(&ctx, project.path(), "", "features", false).unwrap();
+
+    assert!(
+        matches!(result, Response::GitTriggerStarted { ref trigger_name } if trigger_name == "features"),
+        "expected GitTriggerStarted, got {:?}",
+        result
+    );
+    let state = ctx.state.lock();
+    let trigger = state
+        .git_triggers
+        .get("features")
+        .expect("git trigger should be in state after start");
+    assert_eq!(trigger.status, "running");
+    assert_eq!(trigger.ref_pattern, "refs/heads/feature/*");
+    assert_eq!(trigger.run_target, "job:ci");
+}
+
+#[test]
+fn start_all_starts_every_git_trigger() {
+    let project = project_with_git_triggers();
+    let wal_dir = tempdir().unwrap();
+    let ctx = super::super::test_ctx(wal_dir.path());
+
+    let result = handle_git_trigger_start(&ctx, project.path(), "app", "", true).unwrap();
+
+    let Response::GitTriggersStarted {
+        mut started,
+        skipped,
+    } = result
+    else {
+        panic!("expected GitTriggersStarted, got {:?}", result);
+    };
+    started.sort();
+    assert_eq!(started, vec!["features".to_string(), "main".to_string()]);
+    assert!(skipped.is_empty());
+    assert!(ctx.state.lock().git_triggers.contains_key("app/main"));
+}
+
+#[test]
+fn start_unknown_git_trigger_returns_error() {
+    let project = project_with_git_triggers();
+    let wal_dir = tempdir().unwrap();
+    let ctx = super::super::test_ctx(wal_dir.path());
+
+    let result = handle_git_trigger_start(&ctx, project.path(), "", "feature", false).unwrap();
+
+    assert!(
+        matches!(result, Response::Error { .. }),
+        "expected error, got {:?}",
+        result
+    );
+    assert!(ctx.state.lock().git_triggers.is_empty());
+}
+
+#[test]
+fn start_then_stop_marks_stopped() {
+    let project = project_with_git_triggers();
+    let wal_dir = tempdir().unwrap();
+    let ctx = super::super::test_ctx(wal_dir.path());
+
+    handle_git_trigger_start(&ctx, project.path(), "", "main", false).unwrap();
+    let result = handle_git_trigger_stop(&ctx, "main", "", None).unwrap();
+
+    assert_eq!(result, Response::Ok);
+    assert_eq!(ctx.state.lock().git_triggers["main"].status, "stopped");
+}
+
+#[test]
+fn stop_unknown_git_trigger_returns_error() {
+    let wal_dir = tempdir().unwrap();
+    let ctx = super::super::test_ctx(wal_dir.path());
+
+    let result = handle_git_trigger_stop(&ctx, "main", "", None).unwrap();
+
+    assert!(
+        matches!(result, Response::Error { ref message } if message.contains("main")),
+        "expected error, got {:?}",
+        result
+    );
+}
@@ -10,11 +10,14 @@
 mod commands;
 mod crons;
 mod decisions;
+mod git_triggers;
 mod mutations;
 mod query;
 mod queues;
 mod suggest;
 mod tmux;
+mod watches;
+mod webhooks;
 mod workers;
 
 use std::path::{Path, PathBuf};
@@ -36,6 +39,8 @@ use oj_engine::MetricsHealth;
 
 use crate::protocol::{self, Request, Response, DEFAULT_TIMEOUT, PROTOCOL_VERSION};
 
+pub(crate) use webhooks::{load_webhook_config, WebhookConfigError, WebhookListener, WebhookRoute};
+
 /// Shared daemon context for all request handlers.
 pub(crate) struct ListenCtx {
     pub event_bus: EventBus,
@@ -184,6 +189,8 @@ async fn handle_request(request: Request, ctx: &ListenCtx) -> Result<Response, C
             command,
             args,
             named_args,
+            after,
+            priority,
         } => {
             commands::handle_run_command(commands::RunCommandParams {
                 project_root: &project_root,
@@ -192,6 +199,8 @@ async fn handle_request(request: Request, ctx: &ListenCtx) -> Result<Response, C
                 command: &command,
                 args: &args,
                 named_args: &named_args,
+                after: &after,
+                priority,
                 ctx,
             })
             .await
@@ -311,7 +320,14 @@ async fn handle_request(request: Request, ctx: &ListenCtx) -> Result<Response, C
             worker_name,
             namespace,
             concurrency,
-        } => workers::handle_worker_resize(ctx, &worker_name, &namespace, concurrency),
+            rate,
+        } => workers::handle_worker_resize(
+            ctx,
+            &worker_name,
+            &namespace,
+            concurrency,
+            rate.as_deref(),
+        ),
 
         Request::CronStart {
             project_root,
@@ -338,12 +354,65 @@ async fn handle_request(request: Request, ctx: &ListenCtx) -> Result<Response, C
             cron_name,
         } => crons::handle_cron_once(ctx, &project_root, &namespace, &cron_name).await,
 
+        Request::WatchStart {
+            project_root,
+            namespace,
+            watch_name,
+            all,
+        } => watches::handle_watch_start(ctx, &project_root, &namespace, &watch_name, all),
+
+        Request::WatchStop {
+            watch_name,
+            namespace,
+            project_root,
+        } => watches::handle_watch_stop(ctx, &watch_name, &namespace, project_root.as_deref()),
+
+        Request::GitTriggerStart {
+            project_root,
+            namespace,
+            trigger_name,
+            all,
+        } => git_triggers::handle_git_trigger_start(
+            ctx,
+            &project_root,
+            &namespace,
+            &trigger_name,
+            all,
+        ),
+
+        Request::GitTriggerStop {
+            trigger_name,
+            namespace,
+            project_root,
+        } => git_triggers::handle_git_trigger_stop(
+            ctx,
+            &trigger_name,
+            &namespace,
+            project_root.as_deref(),
+        ),
+
         Request::QueuePush {
             project_root,
             namespace,
             queue_name,
             data,
-        } => queues::handle_queue_push(ctx, &project_root, &namespace, &queue_name, data),
+            priority,
+            visible_after_epoch_ms,
+            dedup_key,
+            ttl,
+        } => queues::handle_queue_push(
+            ctx,
+            &project_root,
+            &namespace,
+            &queue_name,
+            data,
+            queues::PushOptions {
+                priority,
+                visible_after_epoch_ms,
+                dedup_key,
+                ttl,
+            },
+        ),
 
         Request::QueueDrop {
             project_root,
@@ -396,6 +465,21 @@ async fn handle_request(request: Request, ctx: &ListenCtx) -> Result<Response, C
             queue_name,
         } => queues::handle_queue_drain(ctx, &project_root, &namespace, &queue_name),
 
+        Request::QueueEdit {
+            project_root,
+            namespace,
+            queue_name,
+            item_id,
+            priority,
+        } => queues::handle_queue_edit(
+            ctx,
+            &project_root,
+            &namespace,
+            &queue_name,
+            &item_id,
+            priority,
+        ),
+
         Request::QueueFail {
             project_root,
             namespace,
@@ -422,7 +506,8 @@ async fn handle_request(request: Request, ctx: &ListenCtx) -> Result<Response, C
             id,
             chosen,
             message,
-        } => decisions::handle_decision_resolve(ctx, &id, chosen, message),
+            values,
+        } => decisions::handle_decision_resolve(ctx, &id, chosen, message, values),
 
         Request::AgentResume {
             agent_id,
@@ -260,6 +260,9 @@ pub(super) fn handle_job_resume(
             created_at_epoch_ms: 0,
             namespace: orphan.project,
             cron_name: None,
+            after: Vec::new(),
+            priority: 0,
+            project_root: None,
         },
     )?;
 
@@ -650,6 +653,34 @@ pub(super) fn handle_job_prune(
                 step: job.step.clone(),
             });
         }
+
+        // Child jobs (sub-jobs and parallel branches) go with their parent
+        let mut i = 0;
+        while i < to_prune.len() {
+            let children: Vec<&oj_core::Job> = state_guard
+                .jobs
+                .get(&to_prune[i].id)
+                .map(|job| job.child_job_ids())
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|id| state_guard.jobs.get(id))
+                .filter(|child| child.is_terminal())
+                .collect();
+            for child in children {
+                if to_prune.iter().any(|entry| entry.id == child.id) {
+                    continue;
+                }
+                if flags.namespace.is_none_or(|ns| child.namespace == ns) {
+                    skipped = skipped.saturating_sub(1);
+                }
+                to_prune.push(JobEntry {
+                    id: child.id.clone(),
+                    name: child.name.clone(),
+                    step: child.step.clone(),
+                });
+            }
+            i += 1;
+        }
     }
 
     if !flags.dry_run {
@@ -34,6 +34,8 @@ fn make_job(id: &str, step: &str) -> Job {
             outcome: StepOutcome::Running,
             agent_id: None,
             agent_name: None,
+            job_id: None,
+            branches: Vec::new(),
         }])
         .build()
 }
@@ -258,6 +260,8 @@ fn make_job_with_agent(id: &str, step: &str, agent_id: &str) -> Job {
             outcome: StepOutcome::Completed,
             agent_id: Some(agent_id.to_string()),
             agent_name: Some("test-agent".to_string()),
+            job_id: None,
+            branches: Vec::new(),
         }])
         .build()
 }
@@ -1012,6 +1016,8 @@ fn make_job_agent_in_history(
                 outcome: StepOutcome::Completed,
                 agent_id: Some(agent_id.to_string()),
                 agent_name: Some("test-agent".to_string()),
+                job_id: None,
+                branches: Vec::new(),
             },
             StepRecord {
                 name: current_step.to_string(),
@@ -1020,6 +1026,8 @@ fn make_job_agent_in_history(
                 outcome: StepOutcome::Running,
                 agent_id: None,
                 agent_name: None,
+                job_id: None,
+                branches: Vec::new(),
             },
         ])
         .build()
@@ -1163,6 +1171,8 @@ async fn agent_send_prefers_latest_step_history_entry() {
                 outcome: StepOutcome::Completed,
                 agent_id: Some("agent-old".to_string()),
                 agent_name: Some("agent-v1".to_string()),
+                job_id: None,
+                branches: Vec::new(),
             },
             StepRecord {
                 name: "work-2".to_string(),
@@ -1171,6 +1181,8 @@ async fn agent_send_prefers_latest_step_history_entry() {
                 outcome: StepOutcome::Completed,
                 agent_id: Some("agent-new".to_string()),
                 agent_name: Some("agent-v2".to_string()),
+                job_id: None,
+                branches: Vec::new(),
             },
             StepRecord {
                 name: "done".to_string(),
@@ -1179,6 +1191,8 @@ async fn agent_send_prefers_latest_step_history_entry() {
                 outcome: StepOutcome::Running,
                 agent_id: None,
                 agent_name: None,
+                job_id: None,
+                branches: Vec::new(),
             },
         ];
         s.jobs.insert("pipe-multi".to_string(), job);
@@ -1250,6 +1264,50 @@ fn job_prune_all_without_namespace_prunes_across_all_projects() {
     }
 }
 
+#[test]
+fn job_prune_takes_child_jobs_with_their_parent() {
+    let dir = tempdir().unwrap();
+    let mut ctx = test_ctx(dir.path());
+    ctx.logs_path = dir.path().join("logs");
+    std::fs::create_dir_all(&ctx.logs_path).unwrap();
+
+    {
+        let mut s = ctx.state.lock();
+        let mut parent = make_job("pipe-parent", "failed");
+        parent.step_history[0].job_id = Some("pipe-sub".to_string());
+        let mut fan_out = parent.step_history[0].clone();
+        fan_out.job_id = None;
+        fan_out.branches = vec![fan_out.clone()];
+        fan_out.branches[0].job_id = Some("pipe-branch".to_string());
+        parent.step_history.push(fan_out);
+        s.jobs.insert(parent.id.clone(), parent);
+        // --failed alone would skip these done and cancelled children
+        s.jobs
+            .insert("pipe-sub".to_string(), make_job("pipe-sub", "done"));
+        s.jobs.insert(
+            "pipe-branch".to_string(),
+            make_job("pipe-branch", "cancelled"),
+        );
+    }
+
+    let flags = PruneFlags {
+        all: false,
+        dry_run: false,
+        namespace: None,
+    };
+    let result = handle_job_prune(&ctx, &flags, true, false);
+
+    match result {
+        Ok(Response::JobsPruned { pruned, skipped }) => {
+            let mut pruned_ids: Vec<&str> = pruned.iter().map(|e| e.id.as_str()).collect();
+            pruned_ids.sort();
+            assert_eq!(pruned_ids, ["pipe-branch", "pipe-parent", "pipe-sub"]);
+            assert_eq!(skipped, 0);
+        }
+        other => panic!("expected JobsPruned, got: {:?}", other),
+    }
+}
+
 #[test]
 fn job_prune_all_with_namespace_only_prunes_matching_project() {
     let dir = tempdir().unwrap();
@@ -20,13 +20,15 @@ mod query_status;
 
 use std::time::{SystemTime, UNIX_EPOCH};
 
-use oj_core::{namespace_to_option, scoped_name, split_scoped_name, StepStatusKind};
-use oj_storage::MaterializedState;
+use oj_core::{
+    namespace_to_option, scoped_name, split_scoped_name, DependencyState, StepStatusKind,
+};
+use oj_storage::{MaterializedState, QueueItemStatus};
 
 use crate::protocol::{
-    CronSummary, DecisionDetail, DecisionOptionDetail, DecisionSummary, JobDetail, JobSummary,
-    Query, QueueItemSummary, Response, SessionSummary, StepRecordDetail, WorkerSummary,
-    WorkspaceDetail, WorkspaceSummary,
+    CronSummary, DecisionDetail, DecisionOptionDetail, DecisionSummary, GitTriggerSummary,
+    JobDetail, JobSummary, Query, QueueItemSummary, Response, SessionSummary, SpendDetail,
+    StepRecordDetail, WatchSummary, WorkerSummary, WorkspaceDetail, WorkspaceSummary,
 };
 
 use super::ListenCtx;
@@ -66,6 +68,7 @@ pub(super) fn handle_query(ctx: &ListenCtx, query: Query) -> Response {
                         updated_at_ms,
                         namespace: p.namespace.clone(),
                         retry_count: p.total_retries,
+                        blocked_on: blocked_on(&state, p),
                     }
                 })
                 .collect();
@@ -77,8 +80,7 @@ pub(super) fn handle_query(ctx: &ListenCtx, query: Query) -> Response {
 
         Query::GetJob { id } => {
             let job = state.get_job(&id).map(|p| {
-                let steps: Vec<StepRecordDetail> =
-                    p.step_history.iter().map(StepRecordDetail::from).collect();
+                let steps = step_details(&state, p);
 
                 // Compute agent summaries from log files
                 let namespace = namespace_to_option(&p.namespace);
@@ -102,6 +104,13 @@ pub(super) fn handle_query(ctx: &ListenCtx, query: Query) -> Response {
                     steps,
                     agents,
                     namespace: p.namespace.clone(),
+                    parent_job_id: p.parent_job_id.clone(),
+                    blocked_on: blocked_on(&state, p),
+                    queue_position: queue_position(&state, p),
+                    spend: {
+                        let (usage, usd) = state.job_usage(&p.id);
+                        SpendDetail::from_usage(&usage, usd)
+                    },
                 })
             });
 
@@ -202,17 +211,32 @@ pub(super) fn handle_query(ctx: &ListenCtx, query: Query) -> Response {
         } => {
             let key = scoped_name(&namespace, &queue_name);
 
+            let now_ms = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis() as u64;
+
             match state.queue_items.get(&key) {
                 Some(queue_items) => {
                     let items = queue_items
                         .iter()
                         .map(|item| QueueItemSummary {
                             id: item.id.clone(),
-                            status: item.status.to_string(),
+                            // Delayed items are pending but not yet visible
+                            status: if item.is_scheduled(now_ms) {
+                                "scheduled".to_string()
+                            } else {
+                                item.status.to_string()
+                            },
                             data: item.data.clone(),
                             worker_name: item.worker_name.clone(),
                             pushed_at_epoch_ms: item.pushed_at_epoch_ms,
                             failure_count: item.failure_count,
+                            priority: item.priority,
+                            visible_after_epoch_ms: item.visible_after_epoch_ms,
+                            lease_expires_at_epoch_ms: item
+                                .lease_expires_at_epoch_ms
+                                .filter(|_| item.status == QueueItemStatus::Active),
                         })
                         .collect();
                     Response::QueueItems { items }
@@ -298,11 +322,16 @@ pub(super) fn handle_query(ctx: &ListenCtx, query: Query) -> Response {
         ),
 
         Query::ListWorkers => {
+            let now_ms = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis() as u64;
             let workers = state
                 .workers
                 .values()
                 .map(|w| {
-                    // Derive updated_at_ms from the most recently updated active job
+                    // Derive updated_at_ms from the most recently updated active
+                    // job or agent run
                     let updated_at_ms = w
                         .active_job_ids
                         .iter()
@@ -312,15 +341,24 @@ pub(super) fn handle_query(ctx: &ListenCtx, query: Query) -> Response {
                                 .last()
                                 .map(|r| r.finished_at_ms.unwrap_or(r.started_at_ms))
                         })
+                        .chain(
+                            w.active_agent_run_ids
+                                .iter()
+                                .filter_map(|id| state.agent_runs.get(id))
+                                .map(|run| run.updated_at_ms),
+                        )
                         .max()
                         .unwrap_or(0);
                     WorkerSummary {
                         name: w.name.clone(),
                         namespace: w.namespace.clone(),
-                        queue: w.queue_name.clone(),
+                        queue: query_queues::worker_queue_label(w),
                         status: w.status.clone(),
-                        active: w.active_job_ids.len(),
+                        active: w.active_job_ids.len() + w.active_agent_run_ids.len(),
                         concurrency: w.concurrency,
+                        rate: w.rate.clone(),
+                        next_dispatch_at_ms: w.next_dispatch_at_ms(now_ms),
+                        sources: query_queues::worker_sources(&state, w),
                         updated_at_ms,
                     }
                 })
@@ -355,7 +393,8 @@ pub(super) fn handle_query(ctx: &ListenCtx, query: Query) -> Response {
                     CronSummary {
                         name: c.name.clone(),
                         namespace: c.namespace.clone(),
-                        interval: c.interval.clone(),
+                        interval: query_crons::cron_timing_display(c),
+                        next_fire_at_ms: query_crons::next_fire_ms(c, now_ms),
                         job: c.run_target.clone(),
                         status: c.status.clone(),
                         time,
@@ -365,6 +404,60 @@ pub(super) fn handle_query(ctx: &ListenCtx, query: Query) -> Response {
             Response::Crons { crons }
         }
 
+        Query::ListWatches => {
+            let now_ms = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis() as u64;
+            let watches = state
+                .watches
+                .values()
+                .map(|w| WatchSummary {
+                    name: w.name.clone(),
+                    namespace: w.namespace.clone(),
+                    paths: w.paths.clone(),
+                    debounce: w.debounce.clone(),
+                    job: w.run_target.clone(),
+                    status: w.status.clone(),
+                    last_fired: match w.last_fired_at_ms {
+                        Some(fired_ms) if fired_ms > 0 && now_ms >= fired_ms => format!(
+                            "{} ago",
+                            query_crons::format_duration_short((now_ms - fired_ms) / 1000)
+                        ),
+                        _ => "-".to_string(),
+                    },
+                })
+                .collect();
+            Response::Watches { watches }
+        }
+
+        Query::ListGitTriggers => {
+            let now_ms = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis() as u64;
+            let git_triggers = state
+                .git_triggers
+                .values()
+                .map(|t| GitTriggerSummary {
+                    name: t.name.clone(),
+                    namespace: t.namespace.clone(),
+                    ref_pattern: t.ref_pattern.clone(),
+                    job: t.run_target.clone(),
+                    status: t.status.clone(),
+                    refs: t.refs.len(),
+                    last_fired: match t.last_fired_at_ms {
+                        Some(fired_ms) if fired_ms > 0 && now_ms >= fired_ms => format!(
+                            "{} ago",
+                            query_crons::format_duration_short((now_ms - fired_ms) / 1000)
+                        ),
+                        _ => "-".to_string(),
+                    },
+                })
+                .collect();
+            Response::GitTriggers { git_triggers }
+        }
+
         Query::StatusOverview => query_status::handle_status_overview(
             &state,
             &ctx.orphans,
@@ -405,7 +498,7 @@ pub(super) fn handle_query(ctx: &ListenCtx, query: Query) -> Response {
                     }
                 })
                 .collect();
-            decisions.sort_by(|a, b| b.created_at_ms.cmp(&a.created_at_ms));
+            decisions.sort_by_key(|b| std::cmp::Reverse(b.created_at_ms));
             Response::Decisions { decisions }
         }
 
@@ -441,6 +534,8 @@ pub(super) fn handle_query(ctx: &ListenCtx, query: Query) -> Response {
                     resolved_at_ms: d.resolved_at_ms,
                     superseded_by: d.superseded_by.as_ref().map(|id| id.to_string()),
                     namespace: d.namespace.clone(),
+                    fields: d.fields.clone(),
+                    values: d.values.clone(),
                 })
             });
             Response::Decision { decision }
@@ -484,8 +579,58 @@ const ALLOWED_VAR_PREFIXES: &[&str] = &[
     "workspace.", // Workspace context (id, root, branch, ref, nonce)
     "args.",      // Command arguments
     "item.",      // Queue item fields
+    "steps.",     // Outputs published by finished steps
 ];
 
+/// Step history for display, with each sub-job step carrying the steps of
+/// the job it ran.
+fn step_details(state: &MaterializedState, job: &oj_core::Job) -> Vec<StepRecordDetail> {
+    let job_def = state
+        .runbooks
+        .get(&job.runbook_hash)
+        .and_then(|stored| serde_json::from_value::<oj_runbook::Runbook>(stored.data.clone()).ok())
+        .and_then(|runbook| runbook.get_job(&job.kind).cloned());
+    job.step_history
+        .iter()
+        .map(|record| {
+            let mut detail = StepRecordDetail::from(record);
+            detail.timeout = job_def
+                .as_ref()
+                .and_then(|def| def.get_step(&record.name))
+                .and_then(|step| step.timeout.clone());
+            if record.branches.is_empty() {
+                if let Some(child) = record.job_id.as_deref().and_then(|id| state.get_job(id)) {
+                    detail.steps = step_details(state, child);
+                }
+            }
+            detail
+        })
+        .collect()
+}
+
+/// Dependencies a job is still held on before its first step.
+fn blocked_on(state: &MaterializedState, job: &oj_core::Job) -> Vec<String> {
+    if job.after.is_empty() || !job.step_status.is_blocked() {
+        return Vec::new();
+    }
+    job.after
+        .iter()
+        .filter(|dep| state.dependency_state(dep) == DependencyState::Pending)
+        .map(|dep| dep.job_id.clone())
+        .collect()
+}
+
+/// A queued job's place in the admission queue (1 = admitted next).
+pub(super) fn queue_position(state: &MaterializedState, job: &oj_core::Job) -> Option<usize> {
+    if !job.queued {
+        return None;
+    }
+    oj_core::admission_order(state.jobs.values())
+        .iter()
+        .position(|queued| queued.id == job.id)
+        .map(|i| i + 1)
+}
+
 /// Filter variables to only include user-facing scopes.
 /// Variables without a declared scope prefix are excluded.
 fn filter_vars_by_scope(
@@ -9,7 +9,7 @@ use std::path::Path;
 use oj_core::{namespace_to_option, OwnerId, StepOutcome, StepOutcomeKind};
 use oj_storage::MaterializedState;
 
-use crate::protocol::{AgentDetail, AgentSummary, Response, StepRecordDetail};
+use crate::protocol::{AgentDetail, AgentSummary, Response, SpendDetail, StepRecordDetail};
 
 pub(super) fn handle_get_agent(
     agent_id: String,
@@ -63,6 +63,7 @@ pub(super) fn handle_get_agent(
             started_at_ms,
             finished_at_ms,
             updated_at_ms: summary.updated_at_ms,
+            spend: agent_spend(state, &summary.agent_id),
         }))
     });
 
@@ -96,6 +97,7 @@ pub(super) fn handle_get_agent(
                 started_at_ms: ar.created_at_ms,
                 finished_at_ms: None,
                 updated_at_ms: ar.updated_at_ms,
+                spend: agent_spend(state, ar_agent_id),
             }))
         })
     });
@@ -103,6 +105,11 @@ pub(super) fn handle_get_agent(
     Response::Agent { agent }
 }
 
+fn agent_spend(state: &MaterializedState, agent_id: &str) -> Option<SpendDetail> {
+    let record = state.agents.get(agent_id)?;
+    SpendDetail::from_usage(&record.usage, record.cost_usd())
+}
+
 pub(super) fn handle_get_agent_signal(agent_id: String, state: &MaterializedState) -> Response {
     // Check standalone agent runs first
     let agent_run_match = state
@@ -311,7 +318,7 @@ pub(super) fn handle_list_agents(
     }
 
     // Sort by most recently updated first
-    agents.sort_by(|a, b| b.updated_at_ms.cmp(&a.updated_at_ms));
+    agents.sort_by_key(|b| std::cmp::Reverse(b.updated_at_ms));
 
     Response::Agents { agents }
 }
@@ -392,7 +399,7 @@ pub(super) fn compute_agent_summaries(
                     .as_ref()
                     .map(|d| format!("failed: {}", d))
                     .or(Some("failed".to_string())),
-                StepOutcomeKind::Running => None,
+                StepOutcomeKind::Running | StepOutcomeKind::Blocked => None,
             };
 
             // Check for "session gone" in log
@@ -3,25 +3,44 @@
 
 //! Cron time display helpers for query responses.
 
+use oj_core::CronSchedule;
 use oj_storage::CronRecord;
 
+/// Epoch ms of a running cron's next fire, or None if it isn't running or
+/// its timing can't be worked out.
+///
+/// Interval crons fire one interval after they last fired (or started);
+/// schedule crons fire at the next time matching their expression.
+pub(super) fn next_fire_ms(cron: &CronRecord, now_ms: u64) -> Option<u64> {
+    if cron.status != "running" {
+        return None;
+    }
+    if let Some(expr) = &cron.schedule {
+        return CronSchedule::parse(expr, cron.timezone.as_deref())
+            .ok()?
+            .next_after(now_ms);
+    }
+    let base_ms = cron.last_fired_at_ms.unwrap_or(cron.started_at_ms);
+    if base_ms == 0 {
+        return None;
+    }
+    let interval_ms = parse_interval_ms(&cron.interval).unwrap_or(0);
+    Some(base_ms.saturating_add(interval_ms))
+}
+
 /// Compute the human-readable time display for a cron.
 ///
 /// Running crons show "in Xm" countdown to next fire.
 /// Stopped crons show "Xm ago" since last fire, or "-" if never fired.
 pub(super) fn cron_time_display(cron: &CronRecord, now_ms: u64) -> String {
     if cron.status == "running" {
-        let base_ms = cron.last_fired_at_ms.unwrap_or(cron.started_at_ms);
-        if base_ms == 0 {
-            return "-".to_string();
-        }
-        let interval_ms = parse_interval_ms(&cron.interval).unwrap_or(0);
-        let next_fire_ms = base_ms.saturating_add(interval_ms);
-        if next_fire_ms <= now_ms {
-            "now".to_string()
-        } else {
-            let remaining_secs = (next_fire_ms - now_ms) / 1000;
-            format!("in {}", format_duration_short(remaining_secs))
+        match next_fire_ms(cron, now_ms) {
+            None => "-".to_string(),
+            Some(next_fire_ms) if next_fire_ms <= now_ms => "now".to_string(),
+            Some(next_fire_ms) => {
+                let remaining_secs = (next_fire_ms - now_ms) / 1000;
+                format!("in {}", format_duration_short(remaining_secs))
+            }
         }
     } else {
         // stopped
@@ -35,6 +54,16 @@ pub(super) fn cron_time_display(cron: &CronRecord, now_ms: u64) -> String {
     }
 }
 
+/// A cron's timing for display: the interval, or the schedule expression
+/// with its timezone.
+pub(super) fn cron_timing_display(cron: &CronRecord) -> String {
+    match (&cron.schedule, &cron.timezone) {
+        (Some(expr), Some(tz)) => format!("{} ({})", expr, tz),
+        (Some(expr), None) => expr.clone(),
+        (None, _) => cron.interval.clone(),
+    }
+}
+
 /// Parse an interval string like "30m", "1h", "6h" into milliseconds.
 pub(super) fn parse_interval_ms(s: &str) -> Option<u64> {
     let s = s.trim();
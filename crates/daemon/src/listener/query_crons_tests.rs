@@ -2,7 +2,7 @@
 // Copyright (c) 2026 Alfred Jean LLC
 
 use crate::listener::query::query_crons::{
-    cron_time_display, format_duration_short, parse_interval_ms,
+    cron_time_display, cron_timing_display, format_duration_short, next_fire_ms, parse_interval_ms,
 };
 use oj_storage::CronRecord;
 
@@ -19,6 +19,8 @@ fn make_cron_record(
         runbook_hash: String::new(),
         status: status.to_string(),
         interval: interval.to_string(),
+        schedule: None,
+        timezone: None,
         run_target: "job:cleanup".to_string(),
         started_at_ms,
         last_fired_at_ms,
@@ -40,6 +42,22 @@ fn time_display(status: &str, interval: &str, started: u64, fired: Option<u64>,
     assert_eq!(cron_time_display(&cron, NOW), expected);
 }
 
+#[test]
+fn schedule_counts_down_to_next_matching_time() {
+    // 2026-03-04 08:00 UTC; 09:00 in London is an hour away
+    let now = 1_772_611_200_000;
+    let mut cron = make_cron_record("running", "", now - 86_400_000, None);
+    cron.schedule = Some("0 9 * * *".to_string());
+    cron.timezone = Some("Europe/London".to_string());
+
+    assert_eq!(next_fire_ms(&cron, now), Some(now + 3_600_000));
+    assert_eq!(cron_time_display(&cron, now), "in 1h");
+    assert_eq!(cron_timing_display(&cron), "0 9 * * * (Europe/London)");
+
+    cron.status = "stopped".to_string();
+    assert_eq!(next_fire_ms(&cron, now), None);
+}
+
 #[yare::parameterized(
     seconds = { "30s", Some(30_000) },
     minutes = { "5m", Some(300_000) },
@@ -98,6 +98,7 @@ pub(super) fn append_orphan_summaries(
             updated_at_ms,
             namespace: bc.project.clone(),
             retry_count: 0,
+            blocked_on: Vec::new(),
         });
     }
 }
@@ -141,6 +142,10 @@ pub(super) fn find_orphan_detail(
                     })
                     .collect(),
                 namespace: bc.project.clone(),
+                parent_job_id: None,
+                blocked_on: Vec::new(),
+                queue_position: None,
+                spend: None,
             })
         })
 }
@@ -177,6 +182,8 @@ pub(super) fn collect_orphan_status_entries(
                 last_activity_ms: updated_at_ms,
                 waiting_reason: None,
                 escalate_source: None,
+                queue_position: None,
+                priority: 0,
             });
     }
     ns_orphaned
@@ -188,6 +195,7 @@ fn parse_step_status_kind(s: &str) -> StepStatusKind {
         "pending" => StepStatusKind::Pending,
         "running" => StepStatusKind::Running,
         "waiting" => StepStatusKind::Waiting,
+        "blocked" => StepStatusKind::Blocked,
         "completed" => StepStatusKind::Completed,
         "failed" => StepStatusKind::Failed,
         _ => StepStatusKind::Orphaned,
@@ -5,10 +5,10 @@
 
 use std::path::Path;
 
-use oj_core::split_scoped_name;
-use oj_storage::MaterializedState;
+use oj_core::{scoped_name, split_scoped_name};
+use oj_storage::{MaterializedState, QueueItemStatus, WorkerRecord};
 
-use crate::protocol::{QueueSummary, Response};
+use crate::protocol::{QueueSummary, Response, WorkerSourceSummary};
 
 /// Build a `Response::Queues` listing all known queues across all namespaces,
 /// plus any empty queues defined in the local runbook.
@@ -26,7 +26,7 @@ pub(super) fn list_queues(
             let workers: Vec<String> = state
                 .workers
                 .values()
-                .filter(|w| w.queue_name == queue_name && w.namespace == ns)
+                .filter(|w| w.queue_names().contains(&queue_name) && w.namespace == ns)
                 .map(|w| w.name.clone())
                 .collect();
 
@@ -65,3 +65,51 @@ pub(super) fn list_queues(
     queues.sort_by(|a, b| (&a.namespace, &a.name).cmp(&(&b.namespace, &b.name)));
     Response::Queues { queues }
 }
+
+/// The queue a worker consumes, as shown in listings: the queue name, or
+/// `bugs=3,chores=1` for weighted sources.
+pub(super) fn worker_queue_label(worker: &WorkerRecord) -> String {
+    if worker.queues.is_empty() {
+        return worker.queue_name.clone();
+    }
+    worker
+        .queues
+        .iter()
+        .map(|(queue, weight)| format!("{}={}", queue, weight))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Active and pending counts per source queue of a weighted worker. Empty
+/// for a worker with a single queue.
+pub(super) fn worker_sources(
+    state: &MaterializedState,
+    worker: &WorkerRecord,
+) -> Vec<WorkerSourceSummary> {
+    worker
+        .queues
+        .iter()
+        .map(|(queue, weight)| {
+            let items = state
+                .queue_items
+                .get(&scoped_name(&worker.namespace, queue))
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            WorkerSourceSummary {
+                queue: queue.clone(),
+                weight: *weight,
+                active: items
+                    .iter()
+                    .filter(|i| {
+                        i.status == QueueItemStatus::Active
+                            && i.worker_name.as_deref() == Some(worker.name.as_str())
+                    })
+                    .count(),
+                pending: items
+                    .iter()
+                    .filter(|i| i.status == QueueItemStatus::Pending)
+                    .count(),
+            }
+        })
+        .collect()
+}
@@ -3,7 +3,7 @@
 
 //! Status overview query handler.
 
-use std::collections::{BTreeMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::sync::Arc;
 use std::time::{Instant, SystemTime, UNIX_EPOCH};
 
@@ -34,8 +34,16 @@ pub(super) fn handle_status_overview(
     // Collect all namespaces seen across entities
     let mut ns_active: BTreeMap<String, Vec<JobStatusEntry>> = BTreeMap::new();
     let mut ns_escalated: BTreeMap<String, Vec<JobStatusEntry>> = BTreeMap::new();
+    let mut ns_queued: BTreeMap<String, Vec<JobStatusEntry>> = BTreeMap::new();
     let mut ns_agents: BTreeMap<String, Vec<AgentStatusEntry>> = BTreeMap::new();
 
+    // Positions in the daemon-wide admission queue
+    let queue_positions: HashMap<&str, usize> = oj_core::admission_order(state.jobs.values())
+        .iter()
+        .enumerate()
+        .map(|(i, job)| (job.id.as_str(), i + 1))
+        .collect();
+
     for p in state.jobs.values() {
         if p.is_terminal() {
             continue;
@@ -50,7 +58,9 @@ pub(super) fn handle_status_overview(
             .unwrap_or(0);
 
         let waiting_reason = match p.step_history.last().map(|r| &r.outcome) {
-            Some(StepOutcome::Waiting(reason)) => Some(reason.clone()),
+            Some(StepOutcome::Waiting(reason) | StepOutcome::Blocked(reason)) => {
+                Some(reason.clone())
+            }
             _ => None,
         };
 
@@ -72,10 +82,14 @@ pub(super) fn handle_status_overview(
             last_activity_ms,
             waiting_reason,
             escalate_source,
+            queue_position: queue_positions.get(p.id.as_str()).copied(),
+            priority: p.priority,
         };
 
         let ns = p.namespace.clone();
-        if p.step_status.is_waiting() {
+        if entry.queue_position.is_some() {
+            ns_queued.entry(ns).or_default().push(entry);
+        } else if p.step_status.is_waiting() {
             ns_escalated.entry(ns).or_default().push(entry);
         } else {
             ns_active.entry(ns).or_default().push(entry);
@@ -147,10 +161,13 @@ pub(super) fn handle_status_overview(
             .push(WorkerSummary {
                 name: w.name.clone(),
                 namespace: w.namespace.clone(),
-                queue: w.queue_name.clone(),
+                queue: super::query_queues::worker_queue_label(w),
                 status: w.status.clone(),
-                active: w.active_job_ids.len(),
+                active: w.active_job_ids.len() + w.active_agent_run_ids.len(),
                 concurrency: w.concurrency,
+                rate: w.rate.clone(),
+                next_dispatch_at_ms: w.next_dispatch_at_ms(now_ms),
+                sources: super::query_queues::worker_sources(state, w),
                 updated_at_ms: w
                     .active_job_ids
                     .iter()
@@ -160,6 +177,12 @@ pub(super) fn handle_status_overview(
                             .last()
                             .map(|r| r.finished_at_ms.unwrap_or(r.started_at_ms))
                     })
+                    .chain(
+                        w.active_agent_run_ids
+                            .iter()
+                            .filter_map(|id| state.agent_runs.get(id))
+                            .map(|run| run.updated_at_ms),
+                    )
                     .max()
                     .unwrap_or(0),
             });
@@ -179,7 +202,7 @@ pub(super) fn handle_status_overview(
                 QueueItemStatus::Active => active += 1,
                 QueueItemStatus::Dead => dead += 1,
                 QueueItemStatus::Failed => pending += 1, // failed items pending retry
-                QueueItemStatus::Completed => {}
+                QueueItemStatus::Completed | QueueItemStatus::Expired => {}
             }
         }
 
@@ -213,6 +236,9 @@ pub(super) fn handle_status_overview(
     for ns in ns_escalated.keys() {
         all_namespaces.insert(ns.clone());
     }
+    for ns in ns_queued.keys() {
+        all_namespaces.insert(ns.clone());
+    }
     for ns in ns_orphaned.keys() {
         all_namespaces.insert(ns.clone());
     }
@@ -234,6 +260,11 @@ pub(super) fn handle_status_overview(
         .map(|ns| NamespaceStatus {
             active_jobs: ns_active.remove(&ns).unwrap_or_default(),
             escalated_jobs: ns_escalated.remove(&ns).unwrap_or_default(),
+            queued_jobs: {
+                let mut queued = ns_queued.remove(&ns).unwrap_or_default();
+                queued.sort_by_key(|entry| entry.queue_position);
+                queued
+            },
             orphaned_jobs: ns_orphaned.remove(&ns).unwrap_or_default(),
             workers: ns_workers.remove(&ns).unwrap_or_default(),
             queues: ns_queues.remove(&ns).unwrap_or_default(),
@@ -12,7 +12,7 @@ use oj_core::{StepOutcome, StepStatus, StepStatusKind};
 
 use oj_engine::breadcrumb::{Breadcrumb, BreadcrumbAgent};
 
-use super::{empty_state, handle_query, make_breadcrumb, make_job, Query, Response};
+use super::{empty_orphans, empty_state, handle_query, make_breadcrumb, make_job, Query, Response};
 
 #[test]
 fn list_jobs_includes_orphans() {
@@ -258,3 +258,66 @@ fn get_job_logs_resolves_orphan_prefix() {
         other => panic!("unexpected response: {:?}", other),
     }
 }
+
+#[test]
+fn get_job_reports_step_timeouts_from_runbook() {
+    let runbook = oj_runbook::parse_runbook(
+        r#"
+[job.build]
+
+[[job.build.step]]
+name = "test"
+run = "cargo test"
+timeout = "30s"
+on_done = "package"
+
+[[job.build.step]]
+name = "package"
+run = "make dist"
+"#,
+    )
+    .unwrap();
+    let state = empty_state();
+    {
+        let mut state = state.lock();
+        state.apply_event(&oj_core::Event::RunbookLoaded {
+            hash: "rb".to_string(),
+            version: 1,
+            runbook: serde_json::to_value(&runbook).unwrap(),
+        });
+        let mut job = make_job(
+            "job-t",
+            "build",
+            "",
+            "test",
+            StepStatus::Pending,
+            StepOutcome::Failed(format!("{} 30s", oj_core::SHELL_TIMEOUT_REASON)),
+            None,
+            1000,
+        );
+        job.kind = "build".to_string();
+        job.runbook_hash = "rb".to_string();
+        let mut package = job.step_history[0].clone();
+        package.name = "package".to_string();
+        package.outcome = StepOutcome::Running;
+        job.step_history.push(package);
+        state.jobs.insert(job.id.clone(), job);
+    }
+    let temp = tempdir().unwrap();
+
+    let response = handle_query(
+        Query::GetJob {
+            id: "job-t".to_string(),
+        },
+        &state,
+        &empty_orphans(),
+        temp.path(),
+        Instant::now(),
+    );
+
+    let Response::Job { job: Some(job) } = response else {
+        panic!("unexpected response: {:?}", response);
+    };
+    assert_eq!(job.steps[0].timeout.as_deref(), Some("30s"));
+    assert_eq!(job.steps[1].timeout, None);
+}
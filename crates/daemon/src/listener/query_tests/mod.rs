@@ -56,6 +56,7 @@ fn empty_orphans() -> Arc<Mutex<Vec<Breadcrumb>>> {
     Arc::new(Mutex::new(Vec::new()))
 }
 
+#[allow(clippy::too_many_arguments)]
 fn make_job(
     id: &str,
     name: &str,
@@ -82,6 +83,8 @@ fn make_job(
             outcome,
             agent_id: agent_id.map(|s| s.to_string()),
             agent_name: None,
+            job_id: None,
+            branches: Vec::new(),
         }])
         .build()
 }
@@ -94,8 +97,12 @@ fn make_worker(name: &str, namespace: &str, queue: &str, active: usize) -> Worke
         runbook_hash: String::new(),
         status: "running".to_string(),
         active_job_ids: (0..active).map(|i| format!("p{}", i)).collect(),
+        active_agent_run_ids: vec![],
         queue_name: queue.to_string(),
+        queues: Default::default(),
         concurrency: 3,
+        rate: None,
+        bucket_full_at_ms: None,
     }
 }
 
@@ -108,6 +115,13 @@ fn make_queue_item(id: &str, status: QueueItemStatus) -> QueueItem {
         worker_name: None,
         pushed_at_epoch_ms: 0,
         failure_count: 0,
+        priority: 0,
+        visible_after_epoch_ms: None,
+        dedup_key: None,
+        completed_at_epoch_ms: None,
+        expires_at_epoch_ms: None,
+        drop_on_expire: false,
+        lease_expires_at_epoch_ms: None,
     }
 }
 
@@ -141,6 +155,8 @@ fn make_cron(name: &str, namespace: &str, project_root: &str) -> CronRecord {
         runbook_hash: String::new(),
         status: "running".to_string(),
         interval: "5m".to_string(),
+        schedule: None,
+        timezone: None,
         run_target: "job:check".to_string(),
         started_at_ms: 0,
         last_fired_at_ms: None,
@@ -162,5 +178,8 @@ fn make_decision(id: &str, job_id: &str, created_at_ms: u64) -> Decision {
         resolved_at_ms: None,
         superseded_by: None,
         namespace: "oddjobs".to_string(),
+        step: None,
+        fields: Vec::new(),
+        values: HashMap::new(),
     }
 }
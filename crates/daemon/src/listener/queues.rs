@@ -10,7 +10,7 @@ use std::time::{SystemTime, UNIX_EPOCH};
 use parking_lot::Mutex;
 
 use oj_core::{scoped_name, Event};
-use oj_runbook::QueueType;
+use oj_runbook::{DedupPolicy, ExpirePolicy, QueueType};
 use oj_storage::{MaterializedState, QueueItemStatus};
 
 use crate::protocol::{QueueItemEntry, Response};
@@ -21,6 +21,19 @@ use super::workers::hash_and_emit_runbook;
 use super::ConnectionError;
 use super::ListenCtx;
 
+/// Options from `oj queue push` flags.
+#[derive(Debug, Default)]
+pub(super) struct PushOptions {
+    /// Overrides the queue's `priority` template
+    pub priority: Option<i32>,
+    /// Hide the item from workers until this time (`--delay` / `--at`)
+    pub visible_after_epoch_ms: Option<u64>,
+    /// Overrides the queue's `dedup_key` template
+    pub dedup_key: Option<String>,
+    /// Overrides the queue's `ttl`
+    pub ttl: Option<String>,
+}
+
 /// Handle a QueuePush request.
 pub(super) fn handle_queue_push(
     ctx: &ListenCtx,
@@ -28,6 +41,7 @@ pub(super) fn handle_queue_push(
     namespace: &str,
     queue_name: &str,
     data: serde_json::Value,
+    options: PushOptions,
 ) -> Result<Response, ConnectionError> {
     // Load runbook containing the queue.
     let (runbook, effective_root) = match super::load_runbook_with_fallback(
@@ -62,6 +76,22 @@ pub(super) fn handle_queue_push(
 
     // External queues: wake workers to re-run the list command (no data needed)
     if queue_def.queue_type != QueueType::Persisted {
+        if options.visible_after_epoch_ms.is_some() {
+            return Ok(Response::Error {
+                message: format!(
+                    "queue '{}' is external; only persisted queues support delayed items",
+                    queue_name
+                ),
+            });
+        }
+        if options.ttl.is_some() {
+            return Ok(Response::Error {
+                message: format!(
+                    "queue '{}' is external; only persisted queues support item ttl",
+                    queue_name
+                ),
+            });
+        }
         wake_attached_workers(ctx, project_root, namespace, queue_name, &runbook)?;
 
         return Ok(Response::Ok);
@@ -108,38 +138,118 @@ pub(super) fn handle_queue_push(
         }
     }
 
-    // Deduplicate: if a pending or active item with the same data already exists, return it
-    {
+    let priority = match options.priority {
+        Some(priority) => priority,
+        None => match queue_def.item_priority(&final_data) {
+            Ok(priority) => priority,
+            Err(message) => return Ok(Response::Error { message }),
+        },
+    };
+
+    let dedup_key = options
+        .dedup_key
+        .filter(|key| !key.trim().is_empty())
+        .or_else(|| queue_def.item_dedup_key(&final_data));
+    let dedup_window_ms = match queue_def.dedup_window.as_deref() {
+        Some(window) => match oj_engine::parse_duration(window) {
+            Ok(window) => window.as_millis() as u64,
+            Err(e) => {
+                return Ok(Response::Error {
+                    message: format!("invalid dedup_window '{}': {}", window, e),
+                })
+            }
+        },
+        None => 0,
+    };
+    let ttl = match options.ttl.as_deref().or(queue_def.ttl.as_deref()) {
+        Some(ttl) => match oj_engine::parse_duration(ttl) {
+            Ok(ttl) => Some(ttl.as_millis() as u64),
+            Err(e) => {
+                return Ok(Response::Error {
+                    message: format!("invalid ttl '{}': {}", ttl, e),
+                })
+            }
+        },
+        None => None,
+    };
+
+    // Get current epoch ms
+    let pushed_at_epoch_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64;
+
+    // Deduplicate against a live item with the same key (or, without a key,
+    // the same data) and return it instead of pushing
+    let duplicate = {
         let st = ctx.state.lock();
         let key = scoped_name(namespace, queue_name);
-        if let Some(items) = st.queue_items.get(&key) {
-            if let Some(existing) = items.iter().find(|i| {
-                (i.status == oj_storage::QueueItemStatus::Pending
-                    || i.status == oj_storage::QueueItemStatus::Active)
-                    && i.data == final_data
-            }) {
-                let existing_id = existing.id.clone();
-                drop(st);
-
-                // Still wake workers so they can pick up pending work
-                wake_attached_workers(ctx, project_root, namespace, queue_name, &runbook)?;
-
-                return Ok(Response::QueuePushed {
-                    queue_name: queue_name.to_string(),
-                    item_id: existing_id,
-                });
+        st.queue_items.get(&key).and_then(|items| {
+            let recent_cutoff = pushed_at_epoch_ms.saturating_sub(dedup_window_ms);
+            items
+                .iter()
+                .find(|i| {
+                    let live = match i.status {
+                        QueueItemStatus::Pending | QueueItemStatus::Active => true,
+                        QueueItemStatus::Completed => {
+                            dedup_window_ms > 0
+                                && i.completed_at_epoch_ms.unwrap_or(i.pushed_at_epoch_ms)
+                                    > recent_cutoff
+                        }
+                        _ => false,
+                    };
+                    let same = match &dedup_key {
+                        Some(dedup_key) => i.dedup_key.as_ref() == Some(dedup_key),
+                        None => i.data == final_data,
+                    };
+                    live && same
+                })
+                .cloned()
+        })
+    };
+    if let Some(existing) = duplicate {
+        if queue_def.dedup_policy == DedupPolicy::Merge
+            && existing.status == QueueItemStatus::Pending
+        {
+            let mut merged = existing.data.clone();
+            merged.extend(final_data);
+            let merged_priority = existing.priority.max(priority);
+            if merged != existing.data || merged_priority != existing.priority {
+                emit(
+                    &ctx.event_bus,
+                    Event::QueueItemMerged {
+                        queue_name: queue_name.to_string(),
+                        item_id: existing.id.clone(),
+                        data: merged,
+                        priority: merged_priority,
+                        namespace: namespace.to_string(),
+                    },
+                )?;
             }
         }
+
+        // Still wake workers so they can pick up pending work
+        wake_attached_workers(ctx, project_root, namespace, queue_name, &runbook)?;
+
+        return Ok(Response::QueuePushed {
+            queue_name: queue_name.to_string(),
+            item_id: existing.id,
+            deduplicated: true,
+        });
     }
 
     // Generate item ID
     let item_id = uuid::Uuid::new_v4().to_string();
 
-    // Get current epoch ms
-    let pushed_at_epoch_ms = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_millis() as u64;
+    // The TTL clock starts once the item becomes visible to workers
+    let visible_after_epoch_ms = options
+        .visible_after_epoch_ms
+        .filter(|at| *at > pushed_at_epoch_ms);
+    let expires_at_epoch_ms = ttl.map(|ttl| {
+        visible_after_epoch_ms
+            .unwrap_or(pushed_at_epoch_ms)
+            .saturating_add(ttl)
+    });
 
     // Emit QueuePushed event
     let event = Event::QueuePushed {
@@ -148,6 +258,11 @@ pub(super) fn handle_queue_push(
         data: final_data,
         pushed_at_epoch_ms,
         namespace: namespace.to_string(),
+        priority,
+        visible_after_epoch_ms,
+        dedup_key,
+        expires_at_epoch_ms,
+        drop_on_expire: expires_at_epoch_ms.is_some() && queue_def.on_expire == ExpirePolicy::Drop,
     };
     emit(&ctx.event_bus, event)?;
 
@@ -157,6 +272,7 @@ pub(super) fn handle_queue_push(
     Ok(Response::QueuePushed {
         queue_name: queue_name.to_string(),
         item_id,
+        deduplicated: false,
     })
 }
 
@@ -177,7 +293,7 @@ fn wake_attached_workers(
     let worker_names: Vec<&str> = runbook
         .workers
         .iter()
-        .filter(|(_, w)| w.source.queue == queue_name)
+        .filter(|(_, w)| w.source.consumes(queue_name))
         .map(|(name, _)| name.as_str())
         .collect();
 
@@ -220,7 +336,9 @@ fn wake_attached_workers(
                     project_root: project_root.to_path_buf(),
                     runbook_hash,
                     queue_name: worker_def.source.queue.clone(),
+                    queues: worker_def.source.queues.clone(),
                     concurrency: worker_def.concurrency,
+                    rate: worker_def.rate.clone(),
                     namespace: namespace.to_string(),
                 },
             )?;
@@ -486,6 +604,7 @@ pub(super) fn handle_queue_retry(
             queue_name: queue_name.to_string(),
             item_id: resolved_id.clone(),
             namespace: namespace.to_string(),
+            automatic: false,
         };
         emit(&ctx.event_bus, event)?;
 
@@ -530,6 +649,7 @@ pub(super) fn handle_queue_retry(
                     queue_name: queue_name.to_string(),
                     item_id: resolved_id.clone(),
                     namespace: namespace.to_string(),
+                    automatic: false,
                 };
                 emit(&ctx.event_bus, event)?;
                 retried.push(resolved_id);
@@ -620,6 +740,9 @@ pub(super) fn handle_queue_drain(
                         worker_name: i.worker_name.clone(),
                         pushed_at_epoch_ms: i.pushed_at_epoch_ms,
                         failure_count: i.failure_count,
+                        priority: i.priority,
+                        visible_after_epoch_ms: i.visible_after_epoch_ms,
+                        lease_expires_at_epoch_ms: None,
                     })
                     .collect()
             })
@@ -642,6 +765,96 @@ pub(super) fn handle_queue_drain(
     })
 }
 
+/// Handle a QueueEdit request — change an item's dispatch priority.
+pub(super) fn handle_queue_edit(
+    ctx: &ListenCtx,
+    project_root: &Path,
+    namespace: &str,
+    queue_name: &str,
+    item_id: &str,
+    priority: i32,
+) -> Result<Response, ConnectionError> {
+    // Load runbook containing the queue.
+    let (runbook, _effective_root) = match super::load_runbook_with_fallback(
+        project_root,
+        namespace,
+        &ctx.state,
+        |root| load_runbook_for_queue(root, queue_name),
+        || {
+            suggest_for_queue(
+                project_root,
+                queue_name,
+                namespace,
+                "oj queue edit",
+                &ctx.state,
+            )
+        },
+    ) {
+        Ok(result) => result,
+        Err(resp) => return Ok(resp),
+    };
+
+    // Validate queue exists and is persisted
+    let queue_def = match runbook.get_queue(queue_name) {
+        Some(def) => def,
+        None => {
+            return Ok(Response::Error {
+                message: format!("unknown queue: {}", queue_name),
+            })
+        }
+    };
+    if queue_def.queue_type != QueueType::Persisted {
+        return Ok(Response::Error {
+            message: format!("queue '{}' is not a persisted queue", queue_name),
+        });
+    }
+
+    // Resolve item ID (exact or prefix match)
+    let resolved_id = match resolve_queue_item_id(&ctx.state, namespace, queue_name, item_id) {
+        Ok(id) => id,
+        Err(resp) => return Ok(resp),
+    };
+
+    // Only items that can still be dispatched have a priority worth changing
+    {
+        let st = ctx.state.lock();
+        let key = scoped_name(namespace, queue_name);
+        let item = st
+            .queue_items
+            .get(&key)
+            .and_then(|items| items.iter().find(|i| i.id == resolved_id));
+        if let Some(item) = item {
+            if !matches!(
+                item.status,
+                QueueItemStatus::Pending | QueueItemStatus::Failed
+            ) {
+                return Ok(Response::Error {
+                    message: format!(
+                        "item '{}' is {}, only pending or failed items can be edited",
+                        resolved_id, item.status
+                    ),
+                });
+            }
+        }
+    }
+
+    emit(
+        &ctx.event_bus,
+        Event::QueueItemPrioritized {
+            queue_name: queue_name.to_string(),
+            item_id: resolved_id.clone(),
+            priority,
+            namespace: namespace.to_string(),
+        },
+    )?;
+
+    Ok(Response::QueueEdited {
+        queue_name: queue_name.to_string(),
+        item_id: resolved_id,
+        priority,
+    })
+}
+
 /// Handle a QueueFail request — force-fail an active item.
 pub(super) fn handle_queue_fail(
     ctx: &ListenCtx,
@@ -802,6 +1015,12 @@ pub(super) fn handle_queue_done(
         queue_name: queue_name.to_string(),
         item_id: resolved_id.clone(),
         namespace: namespace.to_string(),
+        completed_at_epoch_ms: Some(
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis() as u64,
+        ),
     };
     emit(&ctx.event_bus, event)?;
 
@@ -867,7 +1086,7 @@ pub(super) fn handle_queue_prune(
         .as_millis() as u64;
     let age_threshold_ms: u64 = 12 * 60 * 60 * 1000; // 12 hours
 
-    // Collect terminal items (Completed, Dead)
+    // Collect terminal items (Completed, Dead, Expired)
     let mut to_prune = Vec::new();
     let mut skipped = 0usize;
     {
@@ -877,13 +1096,17 @@ pub(super) fn handle_queue_prune(
             for item in items {
                 let is_terminal = matches!(
                     item.status,
-                    QueueItemStatus::Completed | QueueItemStatus::Dead
+                    QueueItemStatus::Completed | QueueItemStatus::Dead | QueueItemStatus::Expired
                 );
                 if !is_terminal {
                     skipped += 1;
                     continue;
                 }
-                if !all && now_ms.saturating_sub(item.pushed_at_epoch_ms) < age_threshold_ms {
+                // Expired items have already outlived their TTL
+                if !all
+                    && item.status != QueueItemStatus::Expired
+                    && now_ms.saturating_sub(item.pushed_at_epoch_ms) < age_threshold_ms
+                {
                     skipped += 1;
                     continue;
                 }
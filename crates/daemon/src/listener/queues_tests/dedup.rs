@@ -0,0 +1,261 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use tempfile::tempdir;
+
+use oj_core::Event;
+use oj_storage::MaterializedState;
+
+use crate::protocol::Response;
+
+use super::super::{handle_queue_push, PushOptions};
+use super::{drain_events, make_ctx, test_event_bus};
+
+/// Helper: create a project dir with a persisted queue keyed by issue.
+fn project_with_dedup_queue(extra: &str) -> tempfile::TempDir {
+    let dir = tempdir().unwrap();
+    let runbook_dir = dir.path().join(".oj/runbooks");
+    std::fs::create_dir_all(&runbook_dir).unwrap();
+    std::fs::write(
+        runbook_dir.join("test.hcl"),
+        format!(
+            r#"
+queue "issues" {{
+  type      = "persisted"
+  vars      = ["issue", "title"]
+  dedup_key = "${{item.issue}}"
+  {extra}
+}}
+"#
+        ),
+    )
+    .unwrap();
+    dir
+}
+
+/// Seed state with an item for issue 42 and return it.
+fn state_with_issue(completed_at_epoch_ms: Option<u64>) -> Arc<Mutex<MaterializedState>> {
+    let mut state = MaterializedState::default();
+    state.apply_event(&Event::QueuePushed {
+        queue_name: "issues".to_string(),
+        item_id: "item-42".to_string(),
+        data: [
+            ("issue".to_string(), "42".to_string()),
+            ("title".to_string(), "crash on start".to_string()),
+        ]
+        .into_iter()
+        .collect(),
+        pushed_at_epoch_ms: 1_000,
+        namespace: String::new(),
+        priority: 1,
+        visible_after_epoch_ms: None,
+        dedup_key: Some("42".to_string()),
+        expires_at_epoch_ms: None,
+        drop_on_expire: false,
+    });
+    if let Some(at) = completed_at_epoch_ms {
+        state.apply_event(&Event::QueueCompleted {
+            queue_name: "issues".to_string(),
+            item_id: "item-42".to_string(),
+            namespace: String::new(),
+            completed_at_epoch_ms: Some(at),
+        });
+    }
+    Arc::new(Mutex::new(state))
+}
+
+fn now_ms() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64
+}
+
+fn push_issue(
+    project: &tempfile::TempDir,
+    state: Arc<Mutex<MaterializedState>>,
+    title: &str,
+    options: PushOptions,
+) -> (Response, Vec<Event>) {
+    let wal_dir = tempdir().unwrap();
+    let (event_bus, wal, _) = test_event_bus(wal_dir.path());
+    let ctx = make_ctx(event_bus, state);
+    let data = serde_json::json!({ "issue": "42", "title": title });
+    let result = handle_queue_push(&ctx, project.path(), "", "issues", data, options).unwrap();
+    (result, drain_events(&wal))
+}
+
+fn pushed_dedup_key(events: &[Event]) -> Option<Option<String>> {
+    events.iter().find_map(|e| match e {
+        Event::QueuePushed { dedup_key, .. } => Some(dedup_key.clone()),
+        _ => None,
+    })
+}
+
+#[test]
+fn push_with_same_key_is_deduplicated() {
+    let project = project_with_dedup_queue("");
+    let (result, events) = push_issue(
+        &project,
+        state_with_issue(None),
+        "crash on start (again)",
+        PushOptions::default(),
+    );
+
+    assert!(
+        matches!(
+            result,
+            Response::QueuePushed { ref item_id, deduplicated: true, .. } if item_id == "item-42"
+        ),
+        "{:?}",
+        result
+    );
+    assert_eq!(pushed_dedup_key(&events), None);
+    assert!(!events
+        .iter()
+        .any(|e| matches!(e, Event::QueueItemMerged { .. })));
+}
+
+#[test]
+fn push_records_key_from_template() {
+    let project = project_with_dedup_queue("");
+    let (result, events) = push_issue(
+        &project,
+        Arc::new(Mutex::new(MaterializedState::default())),
+        "crash on start",
+        PushOptions::default(),
+    );
+
+    assert!(
+        matches!(
+            result,
+            Response::QueuePushed {
+                deduplicated: false,
+                ..
+            }
+        ),
+        "{:?}",
+        result
+    );
+    assert_eq!(pushed_dedup_key(&events), Some(Some("42".to_string())));
+}
+
+#[test]
+fn dedup_key_option_overrides_template() {
+    let project = project_with_dedup_queue("");
+    let options = PushOptions {
+        dedup_key: Some("release-7".to_string()),
+        ..PushOptions::default()
+    };
+    let (result, events) = push_issue(&project, state_with_issue(None), "crash", options);
+
+    assert!(
+        matches!(
+            result,
+            Response::QueuePushed {
+                deduplicated: false,
+                ..
+            }
+        ),
+        "{:?}",
+        result
+    );
+    assert_eq!(
+        pushed_dedup_key(&events),
+        Some(Some("release-7".to_string()))
+    );
+}
+
+#[test]
+fn merge_policy_folds_data_into_pending_item() {
+    let project = project_with_dedup_queue(r#"dedup_policy = "merge""#);
+    let options = PushOptions {
+        priority: Some(5),
+        ..PushOptions::default()
+    };
+    let (result, events) = push_issue(&project, state_with_issue(None), "crash on boot", options);
+
+    assert!(
+        matches!(
+            result,
+            Response::QueuePushed {
+                deduplicated: true,
+                ..
+            }
+        ),
+        "{:?}",
+        result
+    );
+    let expected: HashMap<String, String> = [
+        ("issue".to_string(), "42".to_string()),
+        ("title".to_string(), "crash on boot".to_string()),
+    ]
+    .into_iter()
+    .collect();
+    assert!(
+        matches!(
+            &events[..],
+            [Event::QueueItemMerged { item_id, data, priority: 5, .. }]
+                if item_id == "item-42" && *data == expected
+        ),
+        "{:?}",
+        events
+    );
+}
+
+#[test]
+fn completed_item_blocks_only_within_window() {
+    let project = project_with_dedup_queue(r#"dedup_window = "1h""#);
+
+    let recent = state_with_issue(Some(now_ms() - 10 * 60 * 1000));
+    let (result, _) = push_issue(&project, recent, "crash", PushOptions::default());
+    assert!(
+        matches!(
+            result,
+            Response::QueuePushed {
+                deduplicated: true,
+                ..
+            }
+        ),
+        "{:?}",
+        result
+    );
+
+    let old = state_with_issue(Some(now_ms() - 2 * 60 * 60 * 1000));
+    let (result, events) = push_issue(&project, old, "crash", PushOptions::default());
+    assert!(
+        matches!(
+            result,
+            Response::QueuePushed {
+                deduplicated: false,
+                ..
+            }
+        ),
+        "{:?}",
+        result
+    );
+    assert_eq!(pushed_dedup_key(&events), Some(Some("42".to_string())));
+}
+
+#[test]
+fn completed_item_does_not_block_without_window() {
+    let project = project_with_dedup_queue("");
+    let state = state_with_issue(Some(now_ms()));
+    let (result, _) = push_issue(&project, state, "crash", PushOptions::default());
+
+    assert!(
+        matches!(
+            result,
+            Response::QueuePushed {
+                deduplicated: false,
+                ..
+            }
+        ),
+        "{:?}",
+        result
+    );
+}
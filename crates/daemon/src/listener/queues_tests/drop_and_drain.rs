@@ -32,6 +32,11 @@ fn drop_removes_item_from_queue() {
             .collect(),
         pushed_at_epoch_ms: 1_000_000,
         namespace: String::new(),
+        priority: 0,
+        visible_after_epoch_ms: None,
+        dedup_key: None,
+        expires_at_epoch_ms: None,
+        drop_on_expire: false,
     });
     let ctx = make_ctx(event_bus, Arc::new(Mutex::new(initial_state)));
 
@@ -108,6 +113,11 @@ fn drop_with_prefix_resolves_unique_match() {
             .collect(),
         pushed_at_epoch_ms: 1_000_000,
         namespace: String::new(),
+        priority: 0,
+        visible_after_epoch_ms: None,
+        dedup_key: None,
+        expires_at_epoch_ms: None,
+        drop_on_expire: false,
     });
     let ctx = make_ctx(event_bus, Arc::new(Mutex::new(initial_state)));
 
@@ -148,6 +158,11 @@ fn drop_ambiguous_prefix_returns_error() {
                 .collect(),
             pushed_at_epoch_ms: 1_000_000,
             namespace: String::new(),
+            priority: 0,
+            visible_after_epoch_ms: None,
+            dedup_key: None,
+            expires_at_epoch_ms: None,
+            drop_on_expire: false,
         });
     }
     let ctx = make_ctx(event_bus, Arc::new(Mutex::new(initial_state)));
@@ -180,6 +195,11 @@ fn drain_removes_all_pending_items() {
                 .collect(),
             pushed_at_epoch_ms: 1_000_000 + i,
             namespace: String::new(),
+            priority: 0,
+            visible_after_epoch_ms: None,
+            dedup_key: None,
+            expires_at_epoch_ms: None,
+            drop_on_expire: false,
         });
     }
     let ctx = make_ctx(event_bus, Arc::new(Mutex::new(initial_state)));
@@ -228,6 +248,11 @@ fn drain_skips_non_pending_items() {
             .collect(),
         pushed_at_epoch_ms: 1_000_000,
         namespace: String::new(),
+        priority: 0,
+        visible_after_epoch_ms: None,
+        dedup_key: None,
+        expires_at_epoch_ms: None,
+        drop_on_expire: false,
     });
     // One active item
     initial_state.apply_event(&Event::QueuePushed {
@@ -238,6 +263,11 @@ fn drain_skips_non_pending_items() {
             .collect(),
         pushed_at_epoch_ms: 2_000_000,
         namespace: String::new(),
+        priority: 0,
+        visible_after_epoch_ms: None,
+        dedup_key: None,
+        expires_at_epoch_ms: None,
+        drop_on_expire: false,
     });
     initial_state.apply_event(&Event::QueueTaken {
         queue_name: "tasks".to_string(),
@@ -254,6 +284,11 @@ fn drain_skips_non_pending_items() {
             .collect(),
         pushed_at_epoch_ms: 3_000_000,
         namespace: String::new(),
+        priority: 0,
+        visible_after_epoch_ms: None,
+        dedup_key: None,
+        expires_at_epoch_ms: None,
+        drop_on_expire: false,
     });
     initial_state.apply_event(&Event::QueueItemDead {
         queue_name: "tasks".to_string(),
@@ -343,6 +378,8 @@ fn drop_with_wrong_project_root_falls_back_to_namespace() {
             runbook_hash: "fake-hash".to_string(),
             status: "running".to_string(),
             interval: "24h".to_string(),
+            schedule: None,
+            timezone: None,
             run_target: "job:handle".to_string(),
             started_at_ms: 1_000,
             last_fired_at_ms: None,
@@ -357,6 +394,11 @@ fn drop_with_wrong_project_root_falls_back_to_namespace() {
             .collect(),
         pushed_at_epoch_ms: 1_000_000,
         namespace: "my-project".to_string(),
+        priority: 0,
+        visible_after_epoch_ms: None,
+        dedup_key: None,
+        expires_at_epoch_ms: None,
+        drop_on_expire: false,
     });
     let ctx = make_ctx(event_bus, Arc::new(Mutex::new(initial)));
 
@@ -397,6 +439,8 @@ fn drain_with_wrong_project_root_falls_back_to_namespace() {
             runbook_hash: "fake-hash".to_string(),
             status: "running".to_string(),
             interval: "24h".to_string(),
+            schedule: None,
+            timezone: None,
             run_target: "job:handle".to_string(),
             started_at_ms: 1_000,
             last_fired_at_ms: None,
@@ -411,6 +455,11 @@ fn drain_with_wrong_project_root_falls_back_to_namespace() {
             .collect(),
         pushed_at_epoch_ms: 1_000_000,
         namespace: "my-project".to_string(),
+        priority: 0,
+        visible_after_epoch_ms: None,
+        dedup_key: None,
+        expires_at_epoch_ms: None,
+        drop_on_expire: false,
     });
     let ctx = make_ctx(event_bus, Arc::new(Mutex::new(initial)));
 
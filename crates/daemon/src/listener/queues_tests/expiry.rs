@@ -0,0 +1,179 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use tempfile::tempdir;
+
+use oj_core::Event;
+use oj_storage::MaterializedState;
+
+use crate::protocol::Response;
+
+use super::super::{handle_queue_prune, handle_queue_push, PushOptions};
+use super::{drain_events, make_ctx, test_event_bus};
+
+const DAY_MS: u64 = 24 * 60 * 60 * 1000;
+
+/// Helper: create a project dir with an inbox queue using the given settings.
+fn project_with_inbox(extra: &str) -> tempfile::TempDir {
+    let dir = tempdir().unwrap();
+    let runbook_dir = dir.path().join(".oj/runbooks");
+    std::fs::create_dir_all(&runbook_dir).unwrap();
+    std::fs::write(
+        runbook_dir.join("test.hcl"),
+        format!(
+            r#"
+queue "inbox" {{
+  type = "persisted"
+  vars = ["finding"]
+  {extra}
+}}
+"#
+        ),
+    )
+    .unwrap();
+    dir
+}
+
+/// Push one item and return its (pushed_at, expires_at, drop_on_expire).
+fn push_expiry(
+    project: &tempfile::TempDir,
+    options: PushOptions,
+) -> Result<(u64, Option<u64>, bool), String> {
+    let wal_dir = tempdir().unwrap();
+    let (event_bus, wal, _) = test_event_bus(wal_dir.path());
+    let ctx = make_ctx(
+        event_bus,
+        Arc::new(Mutex::new(MaterializedState::default())),
+    );
+
+    let data = serde_json::json!({ "finding": "stale lockfile" });
+    match handle_queue_push(&ctx, project.path(), "", "inbox", data, options).unwrap() {
+        Response::QueuePushed { .. } => {}
+        Response::Error { message } => return Err(message),
+        other => panic!("unexpected response: {:?}", other),
+    }
+    drain_events(&wal)
+        .iter()
+        .find_map(|e| match e {
+            Event::QueuePushed {
+                pushed_at_epoch_ms,
+                expires_at_epoch_ms,
+                drop_on_expire,
+                ..
+            } => Some((*pushed_at_epoch_ms, *expires_at_epoch_ms, *drop_on_expire)),
+            _ => None,
+        })
+        .ok_or_else(|| "no QueuePushed event".to_string())
+}
+
+#[test]
+fn push_takes_ttl_from_queue() {
+    let project = project_with_inbox("ttl = \"7d\"");
+    let (pushed_at, expires_at, drop) = push_expiry(&project, PushOptions::default()).unwrap();
+
+    assert_eq!(expires_at, Some(pushed_at + 7 * DAY_MS));
+    assert!(!drop);
+}
+
+#[test]
+fn push_without_ttl_never_expires() {
+    let project = project_with_inbox("");
+    let (_, expires_at, _) = push_expiry(&project, PushOptions::default()).unwrap();
+
+    assert_eq!(expires_at, None);
+}
+
+#[test]
+fn push_ttl_flag_overrides_queue() {
+    let project = project_with_inbox("ttl = \"7d\"\n  on_expire = \"drop\"");
+    let options = PushOptions {
+        ttl: Some("2h".to_string()),
+        ..PushOptions::default()
+    };
+    let (pushed_at, expires_at, drop) = push_expiry(&project, options).unwrap();
+
+    assert_eq!(expires_at, Some(pushed_at + 2 * 60 * 60 * 1000));
+    assert!(drop);
+}
+
+#[test]
+fn ttl_counts_from_visibility_for_delayed_items() {
+    let project = project_with_inbox("ttl = \"1d\"");
+    let at = 4_102_444_800_000; // 2100-01-01
+    let options = PushOptions {
+        visible_after_epoch_ms: Some(at),
+        ..PushOptions::default()
+    };
+    let (_, expires_at, _) = push_expiry(&project, options).unwrap();
+
+    assert_eq!(expires_at, Some(at + DAY_MS));
+}
+
+#[test]
+fn push_rejects_invalid_ttl() {
+    let project = project_with_inbox("");
+    let options = PushOptions {
+        ttl: Some("soon".to_string()),
+        ..PushOptions::default()
+    };
+    let err = push_expiry(&project, options).unwrap_err();
+
+    assert!(err.starts_with("invalid ttl 'soon'"), "{err}");
+}
+
+#[test]
+fn prune_removes_expired_items_regardless_of_age() {
+    let project = project_with_inbox("ttl = \"1h\"");
+    let wal_dir = tempdir().unwrap();
+    let (event_bus, wal, _) = test_event_bus(wal_dir.path());
+    let state = Arc::new(Mutex::new(MaterializedState::default()));
+    let ctx = make_ctx(event_bus, Arc::clone(&state));
+
+    let now_ms = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64;
+    for id in ["expired-1", "pending-1"] {
+        state.lock().apply_event(&Event::QueuePushed {
+            queue_name: "inbox".to_string(),
+            item_id: id.to_string(),
+            data: [("finding".to_string(), id.to_string())]
+                .into_iter()
+                .collect(),
+            pushed_at_epoch_ms: now_ms - 60 * 60 * 1000,
+            namespace: String::new(),
+            priority: 0,
+            visible_after_epoch_ms: None,
+            dedup_key: None,
+            expires_at_epoch_ms: Some(now_ms),
+            drop_on_expire: false,
+        });
+    }
+    state.lock().apply_event(&Event::QueueItemExpired {
+        queue_name: "inbox".to_string(),
+        item_id: "expired-1".to_string(),
+        namespace: String::new(),
+    });
+
+    let result = handle_queue_prune(&ctx, project.path(), "", "inbox", false, false).unwrap();
+
+    match result {
+        Response::QueuesPruned {
+            ref pruned,
+            skipped,
+        } => {
+            assert_eq!(pruned.len(), 1);
+            assert_eq!(pruned[0].item_id, "expired-1");
+            assert_eq!(pruned[0].status, "expired");
+            assert_eq!(skipped, 1);
+        }
+        other => panic!("expected QueuesPruned, got {:?}", other),
+    }
+    assert!(matches!(
+        &drain_events(&wal)[..],
+        [Event::QueueDropped { item_id, .. }] if item_id == "expired-1"
+    ));
+}
@@ -1,7 +1,10 @@
 // SPDX-License-Identifier: BUSL-1.1
 // Copyright (c) 2026 Alfred Jean LLC
 
+mod dedup;
 mod drop_and_drain;
+mod expiry;
+mod priority;
 mod prune;
 mod push;
 mod retry;
@@ -121,6 +124,11 @@ fn push_and_mark_dead(
         data: data_map,
         pushed_at_epoch_ms: 1_000_000,
         namespace: namespace.to_string(),
+        priority: 0,
+        visible_after_epoch_ms: None,
+        dedup_key: None,
+        expires_at_epoch_ms: None,
+        drop_on_expire: false,
     });
     state.lock().apply_event(&Event::QueueItemDead {
         queue_name: queue_name.to_string(),
@@ -148,6 +156,11 @@ fn push_and_mark_failed(
         data: data_map,
         pushed_at_epoch_ms,
         namespace: namespace.to_string(),
+        priority: 0,
+        visible_after_epoch_ms: None,
+        dedup_key: None,
+        expires_at_epoch_ms: None,
+        drop_on_expire: false,
     });
     state.lock().apply_event(&Event::QueueFailed {
         queue_name: queue_name.to_string(),
@@ -0,0 +1,183 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use tempfile::tempdir;
+
+use oj_core::Event;
+use oj_storage::MaterializedState;
+
+use crate::protocol::Response;
+
+use super::super::{handle_queue_edit, handle_queue_push, PushOptions};
+use super::{drain_events, make_ctx, push_and_mark_dead, test_event_bus};
+
+/// Helper: create a project dir with a persisted queue prioritized by severity.
+fn project_with_prioritized_queue() -> tempfile::TempDir {
+    let dir = tempdir().unwrap();
+    let runbook_dir = dir.path().join(".oj/runbooks");
+    std::fs::create_dir_all(&runbook_dir).unwrap();
+    std::fs::write(
+        runbook_dir.join("test.hcl"),
+        r#"
+queue "bugs" {
+  type     = "persisted"
+  vars     = ["title", "severity"]
+  defaults = { severity = "0" }
+  priority = "${item.severity}"
+}
+"#,
+    )
+    .unwrap();
+    dir
+}
+
+fn pushed_priority(events: &[Event]) -> Option<i32> {
+    events.iter().find_map(|e| match e {
+        Event::QueuePushed { priority, .. } => Some(*priority),
+        _ => None,
+    })
+}
+
+// ── Push priority tests ───────────────────────────────────────────────
+
+#[test]
+fn push_takes_priority_from_queue_template() {
+    let project = project_with_prioritized_queue();
+    let wal_dir = tempdir().unwrap();
+    let (event_bus, wal, _) = test_event_bus(wal_dir.path());
+    let ctx = make_ctx(
+        event_bus,
+        Arc::new(Mutex::new(MaterializedState::default())),
+    );
+
+    let data = serde_json::json!({ "title": "crash", "severity": "3" });
+    let result = handle_queue_push(
+        &ctx,
+        project.path(),
+        "",
+        "bugs",
+        data,
+        PushOptions::default(),
+    )
+    .unwrap();
+
+    assert!(
+        matches!(result, Response::QueuePushed { .. }),
+        "{:?}",
+        result
+    );
+    assert_eq!(pushed_priority(&drain_events(&wal)), Some(3));
+}
+
+#[test]
+fn push_priority_flag_overrides_template() {
+    let project = project_with_prioritized_queue();
+    let wal_dir = tempdir().unwrap();
+    let (event_bus, wal, _) = test_event_bus(wal_dir.path());
+    let ctx = make_ctx(
+        event_bus,
+        Arc::new(Mutex::new(MaterializedState::default())),
+    );
+
+    let data = serde_json::json!({ "title": "crash", "severity": "3" });
+    let options = PushOptions {
+        priority: Some(10),
+        ..PushOptions::default()
+    };
+    handle_queue_push(&ctx, project.path(), "", "bugs", data, options).unwrap();
+
+    assert_eq!(pushed_priority(&drain_events(&wal)), Some(10));
+}
+
+#[test]
+fn push_rejects_non_integer_priority() {
+    let project = project_with_prioritized_queue();
+    let wal_dir = tempdir().unwrap();
+    let (event_bus, wal, _) = test_event_bus(wal_dir.path());
+    let ctx = make_ctx(
+        event_bus,
+        Arc::new(Mutex::new(MaterializedState::default())),
+    );
+
+    let data = serde_json::json!({ "title": "crash", "severity": "urgent" });
+    let result = handle_queue_push(
+        &ctx,
+        project.path(),
+        "",
+        "bugs",
+        data,
+        PushOptions::default(),
+    )
+    .unwrap();
+
+    assert!(
+        matches!(result, Response::Error { ref message } if message == "priority 'urgent' is not an integer"),
+        "{:?}",
+        result
+    );
+    assert!(drain_events(&wal).is_empty());
+}
+
+// ── Edit tests ────────────────────────────────────────────────────────
+
+#[test]
+fn edit_emits_item_prioritized() {
+    let project = project_with_prioritized_queue();
+    let wal_dir = tempdir().unwrap();
+    let (event_bus, wal, _) = test_event_bus(wal_dir.path());
+
+    let mut initial_state = MaterializedState::default();
+    initial_state.apply_event(&Event::QueuePushed {
+        queue_name: "bugs".to_string(),
+        item_id: "item-abc123".to_string(),
+        data: [("title".to_string(), "crash".to_string())]
+            .into_iter()
+            .collect(),
+        pushed_at_epoch_ms: 1_000_000,
+        namespace: String::new(),
+        priority: 0,
+        visible_after_epoch_ms: None,
+        dedup_key: None,
+        expires_at_epoch_ms: None,
+        drop_on_expire: false,
+    });
+    let ctx = make_ctx(event_bus, Arc::new(Mutex::new(initial_state)));
+
+    let result = handle_queue_edit(&ctx, project.path(), "", "bugs", "item-abc", 5).unwrap();
+
+    assert!(
+        matches!(
+            result,
+            Response::QueueEdited { ref item_id, priority: 5, .. } if item_id == "item-abc123"
+        ),
+        "{:?}",
+        result
+    );
+    let events = drain_events(&wal);
+    assert!(matches!(
+        &events[..],
+        [Event::QueueItemPrioritized { item_id, priority: 5, .. }] if item_id == "item-abc123"
+    ));
+}
+
+#[test]
+fn edit_rejects_dead_item() {
+    let project = project_with_prioritized_queue();
+    let wal_dir = tempdir().unwrap();
+    let (event_bus, wal, _) = test_event_bus(wal_dir.path());
+    let state = Arc::new(Mutex::new(MaterializedState::default()));
+    push_and_mark_dead(&state, "", "bugs", "item-dead", &[("title", "old")]);
+    let ctx = make_ctx(event_bus, state);
+
+    let result = handle_queue_edit(&ctx, project.path(), "", "bugs", "item-dead", 5).unwrap();
+
+    assert!(
+        matches!(result, Response::Error { ref message } if message.contains("only pending or failed items")),
+        "{:?}",
+        result
+    );
+    assert!(drain_events(&wal).is_empty());
+}
@@ -35,11 +35,17 @@ fn push_and_mark_completed(
         data: data_map,
         pushed_at_epoch_ms,
         namespace: namespace.to_string(),
+        priority: 0,
+        visible_after_epoch_ms: None,
+        dedup_key: None,
+        expires_at_epoch_ms: None,
+        drop_on_expire: false,
     });
     state.lock().apply_event(&Event::QueueCompleted {
         queue_name: queue_name.to_string(),
         item_id: item_id.to_string(),
         namespace: namespace.to_string(),
+        completed_at_epoch_ms: None,
     });
 }
 
@@ -62,6 +68,11 @@ fn push_and_mark_dead_at(
         data: data_map,
         pushed_at_epoch_ms,
         namespace: namespace.to_string(),
+        priority: 0,
+        visible_after_epoch_ms: None,
+        dedup_key: None,
+        expires_at_epoch_ms: None,
+        drop_on_expire: false,
     });
     state.lock().apply_event(&Event::QueueItemDead {
         queue_name: queue_name.to_string(),
@@ -85,7 +96,7 @@ fn recent_epoch_ms() -> u64 {
         .duration_since(std::time::UNIX_EPOCH)
         .unwrap()
         .as_millis() as u64
-        - 1 * 60 * 60 * 1000
+        - 60 * 60 * 1000
 }
 
 #[test]
@@ -254,6 +265,11 @@ fn prune_skips_active_pending_failed_items() {
             .collect(),
         pushed_at_epoch_ms: old_epoch_ms(),
         namespace: String::new(),
+        priority: 0,
+        visible_after_epoch_ms: None,
+        dedup_key: None,
+        expires_at_epoch_ms: None,
+        drop_on_expire: false,
     });
 
     // Active item
@@ -265,6 +281,11 @@ fn prune_skips_active_pending_failed_items() {
             .collect(),
         pushed_at_epoch_ms: old_epoch_ms(),
         namespace: String::new(),
+        priority: 0,
+        visible_after_epoch_ms: None,
+        dedup_key: None,
+        expires_at_epoch_ms: None,
+        drop_on_expire: false,
     });
     ctx.state.lock().apply_event(&Event::QueueTaken {
         queue_name: "tasks".to_string(),
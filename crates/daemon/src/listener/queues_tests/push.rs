@@ -11,7 +11,7 @@ use oj_storage::MaterializedState;
 
 use crate::protocol::Response;
 
-use super::super::handle_queue_push;
+use super::super::{handle_queue_push, PushOptions};
 use super::{
     drain_events, make_ctx, project_with_queue_and_worker, project_with_queue_only, test_event_bus,
 };
@@ -57,7 +57,15 @@ fn push_auto_starts_stopped_worker() {
     let ctx = make_ctx(event_bus, state);
 
     let data = serde_json::json!({ "task": "test-value" });
-    let result = handle_queue_push(&ctx, project.path(), "", "tasks", data).unwrap();
+    let result = handle_queue_push(
+        &ctx,
+        project.path(),
+        "",
+        "tasks",
+        data,
+        PushOptions::default(),
+    )
+    .unwrap();
 
     assert!(
         matches!(result, Response::QueuePushed { ref queue_name, .. } if queue_name == "tasks"),
@@ -106,15 +114,27 @@ fn push_wakes_running_worker() {
             runbook_hash: "fake-hash".to_string(),
             status: "running".to_string(),
             active_job_ids: vec![],
+            active_agent_run_ids: vec![],
             queue_name: "tasks".to_string(),
+            queues: Default::default(),
             concurrency: 1,
+            rate: None,
+            bucket_full_at_ms: None,
             namespace: String::new(),
         },
     );
     let ctx = make_ctx(event_bus, Arc::new(Mutex::new(initial_state)));
 
     let data = serde_json::json!({ "task": "test-value" });
-    let result = handle_queue_push(&ctx, project.path(), "", "tasks", data).unwrap();
+    let result = handle_queue_push(
+        &ctx,
+        project.path(),
+        "",
+        "tasks",
+        data,
+        PushOptions::default(),
+    )
+    .unwrap();
 
     assert!(matches!(result, Response::QueuePushed { .. }));
 
@@ -142,7 +162,15 @@ fn push_with_no_workers_succeeds() {
     let ctx = make_ctx(event_bus, state);
 
     let data = serde_json::json!({ "task": "test-value" });
-    let result = handle_queue_push(&ctx, project.path(), "", "tasks", data).unwrap();
+    let result = handle_queue_push(
+        &ctx,
+        project.path(),
+        "",
+        "tasks",
+        data,
+        PushOptions::default(),
+    )
+    .unwrap();
 
     assert!(matches!(result, Response::QueuePushed { .. }));
 
@@ -170,8 +198,12 @@ fn push_external_queue_wakes_workers() {
             runbook_hash: "fake-hash".to_string(),
             status: "running".to_string(),
             active_job_ids: vec![],
+            active_agent_run_ids: vec![],
             queue_name: "issues".to_string(),
+            queues: Default::default(),
             concurrency: 1,
+            rate: None,
+            bucket_full_at_ms: None,
             namespace: String::new(),
         },
     );
@@ -179,7 +211,15 @@ fn push_external_queue_wakes_workers() {
 
     // Push with empty data — should refresh, not error
     let data = serde_json::json!({});
-    let result = handle_queue_push(&ctx, project.path(), "", "issues", data).unwrap();
+    let result = handle_queue_push(
+        &ctx,
+        project.path(),
+        "",
+        "issues",
+        data,
+        PushOptions::default(),
+    )
+    .unwrap();
 
     assert!(
         matches!(result, Response::Ok),
@@ -209,7 +249,15 @@ fn push_external_queue_auto_starts_stopped_worker() {
     let ctx = make_ctx(event_bus, state);
 
     let data = serde_json::json!({});
-    let result = handle_queue_push(&ctx, project.path(), "", "issues", data).unwrap();
+    let result = handle_queue_push(
+        &ctx,
+        project.path(),
+        "",
+        "issues",
+        data,
+        PushOptions::default(),
+    )
+    .unwrap();
 
     assert!(
         matches!(result, Response::Ok),
@@ -254,18 +302,31 @@ fn push_deduplicates_pending_item_with_same_data() {
             .collect(),
         pushed_at_epoch_ms: 1_000_000,
         namespace: String::new(),
+        priority: 0,
+        visible_after_epoch_ms: None,
+        dedup_key: None,
+        expires_at_epoch_ms: None,
+        drop_on_expire: false,
     });
     let ctx = make_ctx(event_bus, Arc::new(Mutex::new(initial_state)));
 
     // Push the same data again
     let data = serde_json::json!({ "task": "build-feature-x" });
-    let result = handle_queue_push(&ctx, project.path(), "", "tasks", data).unwrap();
+    let result = handle_queue_push(
+        &ctx,
+        project.path(),
+        "",
+        "tasks",
+        data,
+        PushOptions::default(),
+    )
+    .unwrap();
 
     // Should return the existing item ID, not create a new one
     assert!(
         matches!(
             result,
-            Response::QueuePushed { ref queue_name, ref item_id }
+            Response::QueuePushed { ref queue_name, ref item_id, deduplicated: true }
             if queue_name == "tasks" && item_id == "existing-item-1"
         ),
         "expected QueuePushed with existing item ID, got {:?}",
@@ -299,6 +360,11 @@ fn push_deduplicates_active_item_with_same_data() {
             .collect(),
         pushed_at_epoch_ms: 1_000_000,
         namespace: String::new(),
+        priority: 0,
+        visible_after_epoch_ms: None,
+        dedup_key: None,
+        expires_at_epoch_ms: None,
+        drop_on_expire: false,
     });
     initial_state.apply_event(&Event::QueueTaken {
         queue_name: "tasks".to_string(),
@@ -310,13 +376,21 @@ fn push_deduplicates_active_item_with_same_data() {
 
     // Push the same data again
     let data = serde_json::json!({ "task": "build-feature-y" });
-    let result = handle_queue_push(&ctx, project.path(), "", "tasks", data).unwrap();
+    let result = handle_queue_push(
+        &ctx,
+        project.path(),
+        "",
+        "tasks",
+        data,
+        PushOptions::default(),
+    )
+    .unwrap();
 
     // Should return the existing active item ID
     assert!(
         matches!(
             result,
-            Response::QueuePushed { ref queue_name, ref item_id }
+            Response::QueuePushed { ref queue_name, ref item_id, deduplicated: true }
             if queue_name == "tasks" && item_id == "active-item-1"
         ),
         "expected QueuePushed with existing active item ID, got {:?}",
@@ -348,23 +422,38 @@ fn push_allows_duplicate_data_when_previous_is_completed() {
             .collect(),
         pushed_at_epoch_ms: 1_000_000,
         namespace: String::new(),
+        priority: 0,
+        visible_after_epoch_ms: None,
+        dedup_key: None,
+        expires_at_epoch_ms: None,
+        drop_on_expire: false,
     });
     initial_state.apply_event(&Event::QueueCompleted {
         queue_name: "tasks".to_string(),
         item_id: "completed-item-1".to_string(),
         namespace: String::new(),
+        completed_at_epoch_ms: None,
     });
     let ctx = make_ctx(event_bus, Arc::new(Mutex::new(initial_state)));
 
     // Push the same data again — should succeed since the previous item is completed
     let data = serde_json::json!({ "task": "build-feature-z" });
-    let result = handle_queue_push(&ctx, project.path(), "", "tasks", data).unwrap();
+    let result = handle_queue_push(
+        &ctx,
+        project.path(),
+        "",
+        "tasks",
+        data,
+        PushOptions::default(),
+    )
+    .unwrap();
 
     // Should create a new item (different ID from completed one)
     match result {
         Response::QueuePushed {
             ref queue_name,
             ref item_id,
+            deduplicated: false,
         } => {
             assert_eq!(queue_name, "tasks");
             assert_ne!(item_id, "completed-item-1", "should be a new item ID");
@@ -397,6 +486,11 @@ fn push_allows_duplicate_data_when_previous_is_dead() {
             .collect(),
         pushed_at_epoch_ms: 1_000_000,
         namespace: String::new(),
+        priority: 0,
+        visible_after_epoch_ms: None,
+        dedup_key: None,
+        expires_at_epoch_ms: None,
+        drop_on_expire: false,
     });
     initial_state.apply_event(&Event::QueueItemDead {
         queue_name: "tasks".to_string(),
@@ -407,12 +501,21 @@ fn push_allows_duplicate_data_when_previous_is_dead() {
 
     // Push the same data again — should succeed since the previous item is dead
     let data = serde_json::json!({ "task": "build-feature-w" });
-    let result = handle_queue_push(&ctx, project.path(), "", "tasks", data).unwrap();
+    let result = handle_queue_push(
+        &ctx,
+        project.path(),
+        "",
+        "tasks",
+        data,
+        PushOptions::default(),
+    )
+    .unwrap();
 
     match result {
         Response::QueuePushed {
             ref queue_name,
             ref item_id,
+            deduplicated: false,
         } => {
             assert_eq!(queue_name, "tasks");
             assert_ne!(item_id, "dead-item-1", "should be a new item ID");
@@ -445,17 +548,31 @@ fn push_different_data_is_not_deduplicated() {
             .collect(),
         pushed_at_epoch_ms: 1_000_000,
         namespace: String::new(),
+        priority: 0,
+        visible_after_epoch_ms: None,
+        dedup_key: None,
+        expires_at_epoch_ms: None,
+        drop_on_expire: false,
     });
     let ctx = make_ctx(event_bus, Arc::new(Mutex::new(initial_state)));
 
     // Push different data — should create a new item
     let data = serde_json::json!({ "task": "build-feature-y" });
-    let result = handle_queue_push(&ctx, project.path(), "", "tasks", data).unwrap();
+    let result = handle_queue_push(
+        &ctx,
+        project.path(),
+        "",
+        "tasks",
+        data,
+        PushOptions::default(),
+    )
+    .unwrap();
 
     match result {
         Response::QueuePushed {
             ref queue_name,
             ref item_id,
+            deduplicated: false,
         } => {
             assert_eq!(queue_name, "tasks");
             assert_ne!(item_id, "existing-item-1", "should be a new item ID");
@@ -472,6 +589,92 @@ fn push_different_data_is_not_deduplicated() {
     );
 }
 
+// ── Delayed push tests ────────────────────────────────────────────────
+
+fn pushed_visible_after(events: &[Event]) -> Option<Option<u64>> {
+    events.iter().find_map(|e| match e {
+        Event::QueuePushed {
+            visible_after_epoch_ms,
+            ..
+        } => Some(*visible_after_epoch_ms),
+        _ => None,
+    })
+}
+
+#[test]
+fn push_with_future_visibility_records_it() {
+    let project = project_with_queue_only();
+    let wal_dir = tempdir().unwrap();
+    let (event_bus, wal, _) = test_event_bus(wal_dir.path());
+    let ctx = make_ctx(
+        event_bus,
+        Arc::new(Mutex::new(MaterializedState::default())),
+    );
+
+    // Far enough out that it can't have passed by the time of the push
+    let at = 4_102_444_800_000; // 2100-01-01
+    let options = PushOptions {
+        visible_after_epoch_ms: Some(at),
+        ..PushOptions::default()
+    };
+    let data = serde_json::json!({ "task": "later" });
+    handle_queue_push(&ctx, project.path(), "", "tasks", data, options).unwrap();
+
+    assert_eq!(pushed_visible_after(&drain_events(&wal)), Some(Some(at)));
+}
+
+#[test]
+fn push_with_past_visibility_is_immediate() {
+    let project = project_with_queue_only();
+    let wal_dir = tempdir().unwrap();
+    let (event_bus, wal, _) = test_event_bus(wal_dir.path());
+    let ctx = make_ctx(
+        event_bus,
+        Arc::new(Mutex::new(MaterializedState::default())),
+    );
+
+    let options = PushOptions {
+        visible_after_epoch_ms: Some(1_000),
+        ..PushOptions::default()
+    };
+    let data = serde_json::json!({ "task": "now" });
+    handle_queue_push(&ctx, project.path(), "", "tasks", data, options).unwrap();
+
+    assert_eq!(pushed_visible_after(&drain_events(&wal)), Some(None));
+}
+
+#[test]
+fn push_delay_rejected_for_external_queue() {
+    let project = project_with_external_queue_and_worker();
+    let wal_dir = tempdir().unwrap();
+    let (event_bus, wal, _) = test_event_bus(wal_dir.path());
+    let ctx = make_ctx(
+        event_bus,
+        Arc::new(Mutex::new(MaterializedState::default())),
+    );
+
+    let options = PushOptions {
+        visible_after_epoch_ms: Some(4_102_444_800_000),
+        ..PushOptions::default()
+    };
+    let result = handle_queue_push(
+        &ctx,
+        project.path(),
+        "",
+        "issues",
+        serde_json::json!({}),
+        options,
+    )
+    .unwrap();
+
+    assert!(
+        matches!(result, Response::Error { ref message } if message.contains("only persisted queues support delayed items")),
+        "{:?}",
+        result
+    );
+    assert!(drain_events(&wal).is_empty());
+}
+
 // ── Push namespace fallback test ──────────────────────────────────────
 
 #[test]
@@ -491,8 +694,12 @@ fn push_with_wrong_project_root_falls_back_to_namespace() {
             runbook_hash: "fake-hash".to_string(),
             status: "running".to_string(),
             active_job_ids: vec![],
+            active_agent_run_ids: vec![],
             queue_name: "tasks".to_string(),
+            queues: Default::default(),
             concurrency: 1,
+            rate: None,
+            bucket_full_at_ms: None,
             namespace: "my-project".to_string(),
         },
     );
@@ -506,6 +713,7 @@ fn push_with_wrong_project_root_falls_back_to_namespace() {
         "my-project",
         "tasks",
         data,
+        PushOptions::default(),
     )
     .unwrap();
 
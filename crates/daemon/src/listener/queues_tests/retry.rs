@@ -258,6 +258,11 @@ fn retry_all_dead_items() {
             .collect(),
         pushed_at_epoch_ms: 1_000_000,
         namespace: String::new(),
+        priority: 0,
+        visible_after_epoch_ms: None,
+        dedup_key: None,
+        expires_at_epoch_ms: None,
+        drop_on_expire: false,
     });
 
     let result = handle_queue_retry(
@@ -378,6 +383,11 @@ fn retry_bulk_mixed_results() {
             .collect(),
         pushed_at_epoch_ms: 1_000_000,
         namespace: String::new(),
+        priority: 0,
+        visible_after_epoch_ms: None,
+        dedup_key: None,
+        expires_at_epoch_ms: None,
+        drop_on_expire: false,
     });
     // "nonexistent" doesn't exist
 
@@ -476,8 +486,12 @@ fn retry_with_wrong_project_root_falls_back_to_namespace() {
             runbook_hash: "fake-hash".to_string(),
             status: "stopped".to_string(),
             active_job_ids: vec![],
+            active_agent_run_ids: vec![],
             queue_name: "tasks".to_string(),
+            queues: Default::default(),
             concurrency: 1,
+            rate: None,
+            bucket_full_at_ms: None,
             namespace: "my-project".to_string(),
         },
     );
@@ -490,6 +504,11 @@ fn retry_with_wrong_project_root_falls_back_to_namespace() {
             .collect(),
         pushed_at_epoch_ms: 1_000_000,
         namespace: "my-project".to_string(),
+        priority: 0,
+        visible_after_epoch_ms: None,
+        dedup_key: None,
+        expires_at_epoch_ms: None,
+        drop_on_expire: false,
     });
     initial.apply_event(&Event::QueueItemDead {
         queue_name: "tasks".to_string(),
@@ -68,6 +68,8 @@ pub(super) enum ResourceType {
     Queue,
     Worker,
     Cron,
+    Watch,
+    GitTrigger,
     Command,
 }
 
@@ -102,6 +104,16 @@ pub(super) fn find_in_other_namespaces(
             .values()
             .find(|c| c.name == name && c.namespace != current_namespace)
             .map(|c| c.namespace.clone()),
+        ResourceType::Watch => state
+            .watches
+            .values()
+            .find(|w| w.name == name && w.namespace != current_namespace)
+            .map(|w| w.namespace.clone()),
+        ResourceType::GitTrigger => state
+            .git_triggers
+            .values()
+            .find(|t| t.name == name && t.namespace != current_namespace)
+            .map(|t| t.namespace.clone()),
         // Commands are stateless definitions; no cross-namespace tracking.
         ResourceType::Command => None,
     }
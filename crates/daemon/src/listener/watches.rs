@@ -0,0 +1,224 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! File watch request handlers.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+
+use oj_core::Event;
+use oj_storage::MaterializedState;
+
+use crate::protocol::Response;
+
+use super::mutations::emit;
+use super::suggest;
+use super::workers::hash_and_emit_runbook;
+use super::ConnectionError;
+use super::ListenCtx;
+
+/// Handle a WatchStart request.
+///
+/// Idempotent like `CronStart`: the runtime replaces any running watch of
+/// the same name, so a repeated start picks up edited paths or debounce.
+pub(super) fn handle_watch_start(
+    ctx: &ListenCtx,
+    project_root: &Path,
+    namespace: &str,
+    watch_name: &str,
+    all: bool,
+) -> Result<Response, ConnectionError> {
+    if all {
+        return handle_watch_start_all(ctx, project_root, namespace);
+    }
+
+    // Load runbook to validate watch exists.
+    let (runbook, effective_root) = match super::load_runbook_with_fallback(
+        project_root,
+        namespace,
+        &ctx.state,
+        |root| load_runbook_for_watch(root, watch_name),
+        || {
+            suggest_for_watch(
+                Some(project_root),
+                watch_name,
+                namespace,
+                "oj watch start",
+                &ctx.state,
+            )
+        },
+    ) {
+        Ok(result) => result,
+        Err(resp) => return Ok(resp),
+    };
+    let project_root = &effective_root;
+
+    let watch_def = match runbook.get_watch(watch_name) {
+        Some(def) => def,
+        None => {
+            return Ok(Response::Error {
+                message: format!("unknown watch: {}", watch_name),
+            })
+        }
+    };
+
+    // Runbook validation guarantees a job reference; check it still resolves
+    let run_target = match watch_def.run.job_name() {
+        Some(job) if runbook.get_job(job).is_some() => format!("job:{}", job),
+        Some(job) => {
+            return Ok(Response::Error {
+                message: format!("watch '{}' references unknown job '{}'", watch_name, job),
+            })
+        }
+        None => {
+            return Ok(Response::Error {
+                message: format!("watch '{}' run must reference a job", watch_name),
+            })
+        }
+    };
+
+    // Hash runbook and emit RunbookLoaded for WAL persistence
+    let runbook_hash = hash_and_emit_runbook(&ctx.event_bus, &runbook)?;
+
+    let event = Event::WatchStarted {
+        watch_name: watch_name.to_string(),
+        project_root: project_root.to_path_buf(),
+        runbook_hash,
+        paths: watch_def.paths.clone(),
+        debounce: watch_def.debounce().to_string(),
+        run_target,
+        namespace: namespace.to_string(),
+    };
+
+    emit(&ctx.event_bus, event.clone())?;
+
+    // Apply to materialized state before responding so queries see it
+    // immediately. apply_event is idempotent.
+    {
+        let mut state = ctx.state.lock();
+        state.apply_event(&event);
+    }
+
+    Ok(Response::WatchStarted {
+        watch_name: watch_name.to_string(),
+    })
+}
+
+/// Handle starting all watches defined in runbooks.
+fn handle_watch_start_all(
+    ctx: &ListenCtx,
+    project_root: &Path,
+    namespace: &str,
+) -> Result<Response, ConnectionError> {
+    let runbook_dir = project_root.join(".oj/runbooks");
+    let names = oj_runbook::collect_all_watches(&runbook_dir)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(name, _)| name);
+
+    let (started, skipped) = super::collect_start_results(
+        names,
+        |name| handle_watch_start(ctx, project_root, namespace, name, false),
+        |resp| match resp {
+            Response::WatchStarted { watch_name } => Some(watch_name.clone()),
+            _ => None,
+        },
+    )?;
+
+    Ok(Response::WatchesStarted { started, skipped })
+}
+
+/// Handle a WatchStop request.
+pub(super) fn handle_watch_stop(
+    ctx: &ListenCtx,
+    watch_name: &str,
+    namespace: &str,
+    project_root: Option<&Path>,
+) -> Result<Response, ConnectionError> {
+    if let Err(resp) = super::require_scoped_resource(
+        &ctx.state,
+        namespace,
+        watch_name,
+        "watch",
+        |s, k| s.watches.contains_key(k),
+        || {
+            suggest_for_watch(
+                project_root,
+                watch_name,
+                namespace,
+                "oj watch stop",
+                &ctx.state,
+            )
+        },
+    ) {
+        return Ok(resp);
+    }
+
+    let event = Event::WatchStopped {
+        watch_name: watch_name.to_string(),
+        namespace: namespace.to_string(),
+    };
+
+    emit(&ctx.event_bus, event.clone())?;
+
+    {
+        let mut state = ctx.state.lock();
+        state.apply_event(&event);
+    }
+
+    Ok(Response::Ok)
+}
+
+#[cfg(test)]
+#[path = "watches_tests.rs"]
+mod tests;
+
+/// Load a runbook that contains the given watch name.
+fn load_runbook_for_watch(
+    project_root: &Path,
+    watch_name: &str,
+) -> Result<oj_runbook::Runbook, String> {
+    let runbook_dir = project_root.join(".oj/runbooks");
+    oj_runbook::find_runbook_by_watch(&runbook_dir, watch_name)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("no runbook found containing watch '{}'", watch_name))
+}
+
+/// Generate a "did you mean" suggestion for a watch name.
+fn suggest_for_watch(
+    project_root: Option<&Path>,
+    watch_name: &str,
+    namespace: &str,
+    command_prefix: &str,
+    state: &Arc<Mutex<MaterializedState>>,
+) -> String {
+    let ns = namespace.to_string();
+    let root = project_root.map(|r| r.to_path_buf());
+    suggest::suggest_for_resource(
+        watch_name,
+        namespace,
+        command_prefix,
+        state,
+        suggest::ResourceType::Watch,
+        || {
+            root.map(|r| {
+                oj_runbook::collect_all_watches(&r.join(".oj/runbooks"))
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|(name, _)| name)
+                    .collect()
+            })
+            .unwrap_or_default()
+        },
+        |state| {
+            state
+                .watches
+                .values()
+                .filter(|w| w.namespace == ns)
+                .map(|w| w.name.clone())
+                .collect()
+        },
+    )
+}
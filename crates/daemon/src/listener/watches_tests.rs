@@ -0,0 +1,143 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use tempfile::tempdir;
+
+use crate::protocol::Response;
+
+use super::{handle_watch_start, handle_watch_stop};
+
+/// Helper: create a temp project with a valid watch runbook.
+fn project_with_watch() -> tempfile::TempDir {
+    let dir = tempdir().unwrap();
+    let runbook_dir = dir.path().join(".oj/runbooks");
+    std::fs::create_dir_all(&runbook_dir).unwrap();
+    std::fs::write(
+        runbook_dir.join("test.hcl"),
+        r#"
+watch "specs" {
+  paths    = ["docs/specs/**/*.md"]
+  debounce = "30s"
+  run      = { job = "spec-review" }
+}
+
+watch "readme" {
+  paths = ["README.md"]
+  run   = { job = "spec-review" }
+}
+
+job "spec-review" {
+  step "review" {
+    run = "echo ${watch.files}"
+  }
+}
+"#,
+    )
+    .unwrap();
+    dir
+}
+
+#[test]
+fn start_applies_state_before_responding() {
+    let project = project_with_watch();
+    let wal_dir = tempdir().unwrap();
+    let ctx = super::super::test_ctx(wal_dir.path());
+
+    let result = handle_watch_start(&ctx, project.path(), "", "specs", false).unwrap();
+
+    assert!(
+        matches!(result, Response::WatchStarted { ref watch_name } if watch_name == "specs"),
+        "expected WatchStarted, got {:?}",
+        result
+    );
+    let state = ctx.state.lock();
+    let watch = state
+        .watches
+        .get("specs")
+        .expect("watch should be in state after start");
+    assert_eq!(watch.status, "running");
+    assert_eq!(watch.paths, vec!["docs/specs/**/*.md".to_string()]);
+    assert_eq!(watch.debounce, "30s");
+    assert_eq!(watch.run_target, "job:spec-review");
+}
+
+#[test]
+fn start_with_namespace_uses_scoped_key() {
+    let project = project_with_watch();
+    let wal_dir = tempdir().unwrap();
+    let ctx = super::super::test_ctx(wal_dir.path());
+
+    handle_watch_start(&ctx, project.path(), "my-project", "specs", false).unwrap();
+
+    let state = ctx.state.lock();
+    assert!(!state.watches.contains_key("specs"));
+    assert_eq!(state.watches["my-project/specs"].namespace, "my-project");
+}
+
+#[test]
+fn start_all_starts_every_watch() {
+    let project = project_with_watch();
+    let wal_dir = tempdir().unwrap();
+    let ctx = super::super::test_ctx(wal_dir.path());
+
+    let result = handle_watch_start(&ctx, project.path(), "", "", true).unwrap();
+
+    let Response::WatchesStarted {
+        mut started,
+        skipped,
+    } = result
+    else {
+        panic!("expected WatchesStarted, got {:?}", result);
+    };
+    started.sort();
+    assert_eq!(started, vec!["readme".to_string(), "specs".to_string()]);
+    assert!(skipped.is_empty());
+    // Unset debounce falls back to the default
+    assert_eq!(
+        ctx.state.lock().watches["readme"].debounce,
+        oj_runbook::DEFAULT_WATCH_DEBOUNCE
+    );
+}
+
+#[test]
+fn start_unknown_watch_returns_error() {
+    let project = project_with_watch();
+    let wal_dir = tempdir().unwrap();
+    let ctx = super::super::test_ctx(wal_dir.path());
+
+    let result = handle_watch_start(&ctx, project.path(), "", "spec", false).unwrap();
+
+    assert!(
+        matches!(result, Response::Error { .. }),
+        "expected error, got {:?}",
+        result
+    );
+    assert!(ctx.state.lock().watches.is_empty());
+}
+
+#[test]
+fn start_then_stop_marks_stopped() {
+    let project = project_with_watch();
+    let wal_dir = tempdir().unwrap();
+    let ctx = super::super::test_ctx(wal_dir.path());
+
+    handle_watch_start(&ctx, project.path(), "", "specs", false).unwrap();
+    let result = handle_watch_stop(&ctx, "specs", "", None).unwrap();
+
+    assert_eq!(result, Response::Ok);
+    assert_eq!(ctx.state.lock().watches["specs"].status, "stopped");
+}
+
+#[test]
+fn stop_unknown_watch_returns_error() {
+    let wal_dir = tempdir().unwrap();
+    let ctx = super::super::test_ctx(wal_dir.path());
+
+    let result = handle_watch_stop(&ctx, "specs", "", None).unwrap();
+
+    assert!(
+        matches!(result, Response::Error { ref message } if message.contains("specs")),
+        "expected error, got {:?}",
+        result
+    );
+}
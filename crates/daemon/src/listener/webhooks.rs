@@ -0,0 +1,481 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Localhost HTTP listener for runbook webhooks.
+//!
+//! Tools that can only fire webhooks `POST` JSON to `/hooks/<name>`. Each
+//! route is declared in `config.toml` under the daemon's state directory,
+//! with the project defining the webhook and the bearer token its requests
+//! must carry:
+//!
+//! ```toml
+//! [webhooks]
+//! listen = "127.0.0.1:7780"
+//!
+//! [webhooks.routes.issues]
+//! project = "/home/me/src/app"
+//! token   = "…"
+//! ```
+//!
+//! The project's `webhook "<name>"` block decides whether a request pushes
+//! a queue item or runs a command. Without a `[webhooks]` section no port
+//! is opened.
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use serde::Deserialize;
+use thiserror::Error;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tracing::{debug, error, info, warn};
+
+use crate::protocol::{Response, DEFAULT_TIMEOUT};
+
+use super::commands::{handle_run_command, RunCommandParams};
+use super::queues::{handle_queue_push, PushOptions};
+use super::ListenCtx;
+
+/// Limit on the request line plus headers
+const MAX_HEAD_SIZE: usize = 16 * 1024;
+
+/// Limit on a request body
+const MAX_BODY_SIZE: usize = 1024 * 1024;
+
+/// The `[webhooks]` section of the daemon config
+#[derive(Debug, Clone)]
+pub(crate) struct WebhookConfig {
+    /// Loopback address the HTTP listener binds
+    pub listen: SocketAddr,
+    /// Routes by webhook name
+    pub routes: HashMap<String, WebhookRoute>,
+}
+
+/// A webhook the HTTP listener accepts
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct WebhookRoute {
+    /// Project whose runbooks define the webhook
+    pub project: PathBuf,
+    /// Bearer token requests must present
+    pub token: String,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawWebhookConfig {
+    listen: String,
+    #[serde(default)]
+    routes: HashMap<String, WebhookRoute>,
+}
+
+/// Errors from reading the `[webhooks]` config
+#[derive(Debug, Error)]
+pub(crate) enum WebhookConfigError {
+    #[error("failed to read {}: {}", .0.display(), .1)]
+    Read(PathBuf, String),
+
+    #[error("invalid [webhooks] config in {}: {}", .0.display(), .1)]
+    Invalid(PathBuf, String),
+}
+
+/// Read the `[webhooks]` section of a daemon config file.
+///
+/// Returns `None` when the file or the section is absent.
+pub(crate) fn load_webhook_config(
+    config_path: &Path,
+) -> Result<Option<WebhookConfig>, WebhookConfigError> {
+    let content = match std::fs::read_to_string(config_path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => {
+            return Err(WebhookConfigError::Read(
+                config_path.to_path_buf(),
+                e.to_string(),
+            ))
+        }
+    };
+    let invalid = |message: String| WebhookConfigError::Invalid(config_path.to_path_buf(), message);
+    let mut table: toml::Table = content
+        .parse()
+        .map_err(|e: toml::de::Error| invalid(e.message().to_string()))?;
+    let Some(section) = table.remove("webhooks") else {
+        return Ok(None);
+    };
+    let raw: RawWebhookConfig = section
+        .try_into()
+        .map_err(|e: toml::de::Error| invalid(e.message().to_string()))?;
+
+    let listen: SocketAddr = raw.listen.parse().map_err(|_| {
+        invalid(format!(
+            "listen '{}' is not an address like 127.0.0.1:7780",
+            raw.listen
+        ))
+    })?;
+    if !listen.ip().is_loopback() {
+        return Err(invalid(format!(
+            "listen '{}' must be a loopback address",
+            raw.listen
+        )));
+    }
+    let mut names: Vec<&String> = raw.routes.keys().collect();
+    names.sort();
+    for name in names {
+        let route = &raw.routes[name];
+        if route.token.trim().is_empty() {
+            return Err(invalid(format!("route '{}' has an empty token", name)));
+        }
+        if !route.project.is_absolute() {
+            return Err(invalid(format!(
+                "route '{}' project '{}' must be an absolute path",
+                name,
+                route.project.display()
+            )));
+        }
+    }
+
+    Ok(Some(WebhookConfig {
+        listen,
+        routes: raw.routes,
+    }))
+}
+
+/// Listener task for webhook requests over HTTP.
+pub(crate) struct WebhookListener {
+    socket: TcpListener,
+    routes: Arc<HashMap<String, WebhookRoute>>,
+    ctx: Arc<ListenCtx>,
+}
+
+impl WebhookListener {
+    /// Create a new webhook listener.
+    pub fn new(
+        socket: TcpListener,
+        routes: HashMap<String, WebhookRoute>,
+        ctx: Arc<ListenCtx>,
+    ) -> Self {
+        Self {
+            socket,
+            routes: Arc::new(routes),
+            ctx,
+        }
+    }
+
+    /// Run the listener loop until shutdown, spawning a task per connection.
+    pub async fn run(self) {
+        loop {
+            match self.socket.accept().await {
+                Ok((stream, _)) => {
+                    let routes = Arc::clone(&self.routes);
+                    let ctx = Arc::clone(&self.ctx);
+                    tokio::spawn(async move {
+                        if let Err(e) = handle_http_connection(stream, &routes, &ctx).await {
+                            debug!("Webhook connection error: {}", e);
+                        }
+                    });
+                }
+                Err(e) => {
+                    error!("Webhook accept error: {}", e);
+                }
+            }
+        }
+    }
+}
+
+/// A parsed HTTP request
+#[derive(Debug)]
+pub(super) struct HttpRequest {
+    pub method: String,
+    pub path: String,
+    /// Header names lowercased
+    pub headers: HashMap<String, String>,
+    pub body: Vec<u8>,
+}
+
+/// An HTTP response with a JSON body
+#[derive(Debug)]
+pub(super) struct HttpResponse {
+    pub status: u16,
+    pub body: serde_json::Value,
+}
+
+impl HttpResponse {
+    fn error(status: u16, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            body: serde_json::json!({ "error": message.into() }),
+        }
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let body = self.body.to_string();
+        format!(
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            self.status,
+            reason_phrase(self.status),
+            body.len(),
+            body
+        )
+        .into_bytes()
+    }
+}
+
+fn reason_phrase(status: u16) -> &'static str {
+    match status {
+        202 => "Accepted",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        408 => "Request Timeout",
+        413 => "Payload Too Large",
+        422 => "Unprocessable Entity",
+        _ => "Internal Server Error",
+    }
+}
+
+/// Handle one HTTP connection: a single request, then close.
+async fn handle_http_connection(
+    mut stream: TcpStream,
+    routes: &HashMap<String, WebhookRoute>,
+    ctx: &ListenCtx,
+) -> std::io::Result<()> {
+    let (reader, mut writer) = stream.split();
+    let response = match tokio::time::timeout(DEFAULT_TIMEOUT, read_http_request(reader)).await {
+        Ok(Ok(request)) => route_request(&request, routes, ctx).await,
+        Ok(Err(response)) => response,
+        Err(_) => HttpResponse::error(408, "timed out reading request"),
+    };
+    writer.write_all(&response.to_bytes()).await?;
+    writer.shutdown().await
+}
+
+/// Read a request line, headers, and a `Content-Length` body.
+///
+/// Malformed or oversized requests come back as the error response to send.
+pub(super) async fn read_http_request<R: AsyncRead + Unpin>(
+    reader: R,
+) -> Result<HttpRequest, HttpResponse> {
+    let bad_request = |message: &str| HttpResponse::error(400, message);
+    let mut reader = BufReader::new(reader);
+
+    let mut head = Vec::new();
+    let mut head_len = 0;
+    loop {
+        let mut line = String::new();
+        let limit = (MAX_HEAD_SIZE - head_len) as u64;
+        let n = (&mut reader)
+            .take(limit)
+            .read_line(&mut line)
+            .await
+            .map_err(|_| bad_request("malformed request"))?;
+        head_len += n;
+        if !line.ends_with('\n') {
+            return Err(if head_len >= MAX_HEAD_SIZE {
+                HttpResponse::error(413, "request headers too large")
+            } else {
+                bad_request("incomplete request")
+            });
+        }
+        let line = line.trim_end_matches(['\r', '\n']);
+        if line.is_empty() {
+            break;
+        }
+        head.push(line.to_string());
+    }
+
+    let mut lines = head.into_iter();
+    let request_line = lines.next().ok_or_else(|| bad_request("empty request"))?;
+    let mut parts = request_line.split(' ');
+    let (Some(method), Some(path), Some(version)) = (parts.next(), parts.next(), parts.next())
+    else {
+        return Err(bad_request("malformed request line"));
+    };
+    if !version.starts_with("HTTP/1.") {
+        return Err(bad_request("unsupported HTTP version"));
+    }
+
+    let mut headers = HashMap::new();
+    for line in lines {
+        let Some((name, value)) = line.split_once(':') else {
+            return Err(bad_request("malformed header"));
+        };
+        headers.insert(name.trim().to_ascii_lowercase(), value.trim().to_string());
+    }
+
+    let content_length = match headers.get("content-length") {
+        Some(value) => value
+            .parse::<usize>()
+            .map_err(|_| bad_request("invalid Content-Length"))?,
+        None => 0,
+    };
+    if content_length > MAX_BODY_SIZE {
+        return Err(HttpResponse::error(413, "request body too large"));
+    }
+    let mut body = vec![0; content_length];
+    reader
+        .read_exact(&mut body)
+        .await
+        .map_err(|_| bad_request("incomplete request body"))?;
+
+    Ok(HttpRequest {
+        method: method.to_string(),
+        path: path.to_string(),
+        headers,
+        body,
+    })
+}
+
+/// Check a request's route and token, then push or run what its webhook
+/// maps to.
+pub(super) async fn route_request(
+    request: &HttpRequest,
+    routes: &HashMap<String, WebhookRoute>,
+    ctx: &ListenCtx,
+) -> HttpResponse {
+    let path = request.path.split('?').next().unwrap_or_default();
+    let Some(name) = path
+        .strip_prefix("/hooks/")
+        .filter(|name| !name.is_empty() && !name.contains('/'))
+    else {
+        return HttpResponse::error(404, "not found");
+    };
+    // Authenticate before revealing whether the webhook exists or which
+    // methods it accepts: unknown names and bad tokens look the same.
+    let token = request
+        .headers
+        .get("authorization")
+        .and_then(|value| bearer_token(value));
+    let Some(route) = routes
+        .get(name)
+        .filter(|route| token.is_some_and(|token| token_matches(token, &route.token)))
+    else {
+        warn!(webhook = name, "rejected webhook request with a bad token");
+        return HttpResponse::error(401, "missing or invalid bearer token");
+    };
+    if request.method != "POST" {
+        return HttpResponse::error(405, "webhooks only accept POST");
+    }
+    let body: serde_json::Value = match serde_json::from_slice(&request.body) {
+        Ok(body @ serde_json::Value::Object(_)) => body,
+        Ok(_) => return HttpResponse::error(400, "body must be a JSON object"),
+        Err(e) => return HttpResponse::error(400, format!("invalid JSON body: {}", e)),
+    };
+
+    info!(webhook = name, "received webhook request");
+    match handle_webhook(ctx, name, &route.project, &body).await {
+        Ok(Response::Error { message }) => {
+            warn!(webhook = name, error = %message, "webhook request failed");
+            HttpResponse::error(422, message)
+        }
+        Ok(response) => HttpResponse {
+            status: 202,
+            body: serde_json::to_value(&response).unwrap_or_default(),
+        },
+        Err(e) => {
+            error!(webhook = name, error = %e, "webhook request failed");
+            HttpResponse::error(500, e.to_string())
+        }
+    }
+}
+
+/// Map a request body through the project's webhook definition and push
+/// the item or run the command.
+async fn handle_webhook(
+    ctx: &ListenCtx,
+    name: &str,
+    project_root: &Path,
+    body: &serde_json::Value,
+) -> Result<Response, super::ConnectionError> {
+    let runbook_dir = project_root.join(".oj/runbooks");
+    let runbook = match oj_runbook::find_runbook_by_webhook(&runbook_dir, name) {
+        Ok(Some(runbook)) => runbook,
+        Ok(None) => {
+            return Ok(Response::Error {
+                message: format!(
+                    "no runbook in {} defines webhook '{}'",
+                    project_root.display(),
+                    name
+                ),
+            })
+        }
+        Err(e) => {
+            return Ok(Response::Error {
+                message: e.to_string(),
+            })
+        }
+    };
+    let Some(webhook) = runbook.get_webhook(name) else {
+        return Ok(Response::Error {
+            message: format!("unknown webhook: {}", name),
+        });
+    };
+    let mut fields = match webhook.map_body(body) {
+        Ok(fields) => fields,
+        Err(message) => return Ok(Response::Error { message }),
+    };
+    let namespace = oj_core::namespace::resolve_namespace(project_root);
+
+    if let Some(queue) = &webhook.queue {
+        let data = serde_json::to_value(&fields).unwrap_or_default();
+        return handle_queue_push(
+            ctx,
+            project_root,
+            &namespace,
+            queue,
+            data,
+            PushOptions::default(),
+        );
+    }
+
+    let Some(command) = &webhook.command else {
+        return Ok(Response::Error {
+            message: format!("webhook '{}' has no queue or command", name),
+        });
+    };
+    // Unmapped bodies carry whatever fields the sender adds; only the ones
+    // named like the command's arguments are passed on
+    if webhook.map.is_empty() {
+        if let Some(cmd) = runbook.get_command(command) {
+            let args = cmd.args.names();
+            fields.retain(|key, _| args.contains(key.as_str()));
+        }
+    }
+    handle_run_command(RunCommandParams {
+        project_root,
+        invoke_dir: project_root,
+        namespace: &namespace,
+        command,
+        args: &[],
+        named_args: &fields,
+        after: &[],
+        priority: 0,
+        ctx,
+    })
+    .await
+}
+
+/// Token from an `Authorization: Bearer <token>` header. The scheme is
+/// matched case-insensitively (RFC 7235).
+fn bearer_token(value: &str) -> Option<&str> {
+    let (scheme, token) = value.trim().split_once(' ')?;
+    scheme
+        .eq_ignore_ascii_case("bearer")
+        .then(|| token.trim_start())
+}
+
+/// Compare tokens without exiting at the first differing byte.
+fn token_matches(given: &str, expected: &str) -> bool {
+    given.len() == expected.len()
+        && given
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+#[cfg(test)]
+#[path = "webhooks_tests.rs"]
+mod tests;
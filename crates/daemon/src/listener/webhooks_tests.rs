@@ -0,0 +1,367 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use oj_core::Event;
+use tempfile::tempdir;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+use super::super::{test_ctx, test_ctx_with_wal};
+use super::*;
+
+const TOKEN: &str = "s3cret";
+
+/// Helper: create a temp project with a queue webhook and a command webhook.
+fn project_with_webhooks() -> tempfile::TempDir {
+    let dir = tempdir().unwrap();
+    let runbook_dir = dir.path().join(".oj/runbooks");
+    std::fs::create_dir_all(&runbook_dir).unwrap();
+    std::fs::write(
+        runbook_dir.join("test.hcl"),
+        r#"
+queue "bugs" {
+  type = "persisted"
+  vars = ["title", "url"]
+}
+
+webhook "issues" {
+  queue = "bugs"
+  map   = { title = "${body.issue.title}", url = "${body.issue.url}" }
+}
+
+command "triage" {
+  args = "<title>"
+  run  = "echo ${args.title}"
+}
+
+webhook "chat" {
+  command = "triage"
+}
+"#,
+    )
+    .unwrap();
+    dir
+}
+
+fn routes(project: &Path) -> HashMap<String, WebhookRoute> {
+    ["issues", "chat", "missing"]
+        .into_iter()
+        .map(|name| {
+            (
+                name.to_string(),
+                WebhookRoute {
+                    project: project.to_path_buf(),
+                    token: TOKEN.to_string(),
+                },
+            )
+        })
+        .collect()
+}
+
+fn post(path: &str, token: Option<&str>, body: &str) -> HttpRequest {
+    let mut headers = HashMap::new();
+    if let Some(token) = token {
+        headers.insert("authorization".to_string(), format!("Bearer {}", token));
+    }
+    HttpRequest {
+        method: "POST".to_string(),
+        path: path.to_string(),
+        headers,
+        body: body.as_bytes().to_vec(),
+    }
+}
+
+fn write_config(dir: &Path, content: &str) -> PathBuf {
+    let path = dir.join("config.toml");
+    std::fs::write(&path, content).unwrap();
+    path
+}
+
+fn drain_events(wal: &parking_lot::Mutex<oj_storage::Wal>) -> Vec<Event> {
+    let mut events = Vec::new();
+    let mut wal = wal.lock();
+    while let Some(entry) = wal.next_unprocessed().unwrap() {
+        events.push(entry.event);
+        wal.mark_processed(entry.seq);
+    }
+    events
+}
+
+#[test]
+fn config_without_webhooks_section_is_none() {
+    let dir = tempdir().unwrap();
+    assert!(load_webhook_config(&dir.path().join("config.toml"))
+        .unwrap()
+        .is_none());
+    let path = write_config(dir.path(), "[limits]\nmax_jobs = 2\n");
+    assert!(load_webhook_config(&path).unwrap().is_none());
+}
+
+#[test]
+fn config_reads_listen_and_routes() {
+    let dir = tempdir().unwrap();
+    let path = write_config(
+        dir.path(),
+        r#"
+[webhooks]
+listen = "127.0.0.1:7780"
+
+[webhooks.routes.issues]
+project = "/src/app"
+token = "s3cret"
+"#,
+    );
+    let config = load_webhook_config(&path).unwrap().unwrap();
+    assert_eq!(config.listen, "127.0.0.1:7780".parse().unwrap());
+    let route = &config.routes["issues"];
+    assert_eq!(route.project, PathBuf::from("/src/app"));
+    assert_eq!(route.token, "s3cret");
+}
+
+#[yare::parameterized(
+    non_loopback = { "listen = \"0.0.0.0:7780\"", "loopback" },
+    bad_address = { "listen = \"localhost\"", "not an address" },
+    empty_token = {
+        "listen = \"127.0.0.1:7780\"\n[webhooks.routes.issues]\nproject = \"/src/app\"\ntoken = \"\"",
+        "empty token"
+    },
+    relative_project = {
+        "listen = \"127.0.0.1:7780\"\n[webhooks.routes.issues]\nproject = \"app\"\ntoken = \"x\"",
+        "absolute path"
+    },
+    unknown_key = {
+        "listen = \"127.0.0.1:7780\"\nport = 7780",
+        "unknown field"
+    },
+)]
+fn config_rejects_invalid_section(section: &str, fragment: &str) {
+    let dir = tempdir().unwrap();
+    let path = write_config(dir.path(), &format!("[webhooks]\n{}\n", section));
+    let err = load_webhook_config(&path).unwrap_err().to_string();
+    assert!(err.contains(fragment), "{err}");
+}
+
+#[tokio::test]
+async fn reads_headers_and_body() {
+    let raw = b"POST /hooks/issues HTTP/1.1\r\nHost: localhost\r\nAuthorization: Bearer abc\r\nContent-Length: 2\r\n\r\n{}";
+    let request = read_http_request(&raw[..]).await.unwrap();
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.path, "/hooks/issues");
+    assert_eq!(request.headers["authorization"], "Bearer abc");
+    assert_eq!(request.body, b"{}");
+}
+
+#[yare::parameterized(
+    truncated_body = { "POST /hooks/x HTTP/1.1\r\nContent-Length: 10\r\n\r\n{}", 400 },
+    no_blank_line = { "POST /hooks/x HTTP/1.1\r\nHost: x", 400 },
+    bad_length = { "POST /hooks/x HTTP/1.1\r\nContent-Length: ten\r\n\r\n", 400 },
+    huge_body = { "POST /hooks/x HTTP/1.1\r\nContent-Length: 99999999\r\n\r\n", 413 },
+)]
+fn rejects_malformed_requests(raw: &str, status: u16) {
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    let err = rt.block_on(read_http_request(raw.as_bytes())).unwrap_err();
+    assert_eq!(err.status, status);
+}
+
+#[yare::parameterized(
+    unknown_path = { "/other", Some(TOKEN), 404 },
+    unknown_route = { "/hooks/deploys", Some(TOKEN), 401 },
+    unknown_route_without_token = { "/hooks/deploys", None, 401 },
+    missing_token = { "/hooks/issues", None, 401 },
+    wrong_token = { "/hooks/issues", Some("s3creT"), 401 },
+)]
+fn rejects_unrouted_or_unauthorized(path: &str, token: Option<&str>, status: u16) {
+    let project = project_with_webhooks();
+    let wal_dir = tempdir().unwrap();
+    let ctx = test_ctx(wal_dir.path());
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+
+    let request = post(path, token, r#"{"issue": {"title": "crash", "url": "u"}}"#);
+    let response = rt.block_on(route_request(&request, &routes(project.path()), &ctx));
+
+    assert_eq!(response.status, status);
+    assert!(ctx.state.lock().queue_items.is_empty());
+}
+
+#[yare::parameterized(
+    valid_token = { Some(TOKEN), 405 },
+    wrong_token = { Some("nope"), 401 },
+    missing_token = { None, 401 },
+)]
+fn non_post_methods_are_rejected_after_auth(token: Option<&str>, status: u16) {
+    let project = project_with_webhooks();
+    let wal_dir = tempdir().unwrap();
+    let ctx = test_ctx(wal_dir.path());
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+
+    let mut request = post("/hooks/issues", token, "");
+    request.method = "GET".to_string();
+    let response = rt.block_on(route_request(&request, &routes(project.path()), &ctx));
+
+    assert_eq!(response.status, status);
+}
+
+#[yare::parameterized(
+    lowercase = { "bearer" },
+    uppercase = { "BEARER" },
+    mixed_case = { "BeArEr" },
+)]
+fn bearer_scheme_is_case_insensitive(scheme: &str) {
+    let project = project_with_webhooks();
+    let wal_dir = tempdir().unwrap();
+    let ctx = test_ctx(wal_dir.path());
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+
+    let mut request = post(
+        "/hooks/issues",
+        None,
+        r#"{"issue": {"title": "crash", "url": "u"}}"#,
+    );
+    request
+        .headers
+        .insert("authorization".to_string(), format!("{} {}", scheme, TOKEN));
+    let response = rt.block_on(route_request(&request, &routes(project.path()), &ctx));
+
+    assert_eq!(response.status, 202, "{:?}", response.body);
+}
+
+#[tokio::test]
+async fn queue_webhook_pushes_mapped_item() {
+    let project = project_with_webhooks();
+    let wal_dir = tempdir().unwrap();
+    let (ctx, wal) = test_ctx_with_wal(wal_dir.path());
+
+    let request = post(
+        "/hooks/issues",
+        Some(TOKEN),
+        r#"{"action": "opened", "issue": {"title": "crash", "url": "https://x/1"}}"#,
+    );
+    let response = route_request(&request, &routes(project.path()), &ctx).await;
+
+    assert_eq!(response.status, 202, "{:?}", response.body);
+    assert_eq!(response.body["type"], "QueuePushed");
+    let pushed = drain_events(&wal)
+        .into_iter()
+        .find_map(|event| match event {
+            Event::QueuePushed {
+                queue_name,
+                data,
+                namespace,
+                ..
+            } => Some((queue_name, data, namespace)),
+            _ => None,
+        });
+    let (queue_name, data, namespace) = pushed.expect("QueuePushed should be emitted");
+    assert_eq!(queue_name, "bugs");
+    assert_eq!(
+        namespace,
+        oj_core::namespace::resolve_namespace(project.path())
+    );
+    assert_eq!(data.len(), 2);
+    assert_eq!(data["title"], "crash");
+    assert_eq!(data["url"], "https://x/1");
+}
+
+#[yare::parameterized(
+    invalid_json = { "{", 400 },
+    not_an_object = { "[1, 2]", 400 },
+    missing_mapped_field = { r#"{"issue": {"title": "crash"}}"#, 422 },
+)]
+fn queue_webhook_rejects_bad_bodies(body: &str, status: u16) {
+    let project = project_with_webhooks();
+    let wal_dir = tempdir().unwrap();
+    let ctx = test_ctx(wal_dir.path());
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+
+    let request = post("/hooks/issues", Some(TOKEN), body);
+    let response = rt.block_on(route_request(&request, &routes(project.path()), &ctx));
+
+    assert_eq!(response.status, status, "{:?}", response.body);
+}
+
+#[tokio::test]
+async fn route_without_runbook_webhook_is_unprocessable() {
+    let project = project_with_webhooks();
+    let wal_dir = tempdir().unwrap();
+    let ctx = test_ctx(wal_dir.path());
+
+    let request = post("/hooks/missing", Some(TOKEN), "{}");
+    let response = route_request(&request, &routes(project.path()), &ctx).await;
+
+    assert_eq!(response.status, 422);
+    let error = response.body["error"].as_str().unwrap();
+    assert!(error.contains("defines webhook 'missing'"), "{error}");
+}
+
+#[tokio::test]
+async fn command_webhook_runs_command_with_matching_fields() {
+    let project = project_with_webhooks();
+    let wal_dir = tempdir().unwrap();
+    let (ctx, wal) = test_ctx_with_wal(wal_dir.path());
+
+    // Fields that aren't arguments of the command are dropped
+    let request = post(
+        "/hooks/chat",
+        Some(TOKEN),
+        r#"{"title": "flaky test", "channel": "ops"}"#,
+    );
+    let response = route_request(&request, &routes(project.path()), &ctx).await;
+
+    assert_eq!(response.status, 202, "{:?}", response.body);
+    let args = drain_events(&wal)
+        .into_iter()
+        .find_map(|event| match event {
+            Event::CommandRun { command, args, .. } => Some((command, args)),
+            _ => None,
+        });
+    let (command, args) = args.expect("CommandRun should be emitted");
+    assert_eq!(command, "triage");
+    assert_eq!(args.get("title").map(String::as_str), Some("flaky test"));
+    assert!(!args.contains_key("channel"));
+}
+
+#[tokio::test]
+async fn listener_answers_over_http() {
+    let project = project_with_webhooks();
+    let wal_dir = tempdir().unwrap();
+    let ctx = Arc::new(test_ctx(wal_dir.path()));
+    let socket = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = socket.local_addr().unwrap();
+    tokio::spawn(WebhookListener::new(socket, routes(project.path()), Arc::clone(&ctx)).run());
+
+    let body = r#"{"issue": {"title": "crash", "url": "u"}}"#;
+    let mut stream = TcpStream::connect(addr).await.unwrap();
+    stream
+        .write_all(
+            format!(
+                "POST /hooks/issues HTTP/1.1\r\nAuthorization: Bearer {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+                TOKEN,
+                body.len(),
+                body
+            )
+            .as_bytes(),
+        )
+        .await
+        .unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.unwrap();
+
+    assert!(
+        response.starts_with("HTTP/1.1 202 Accepted\r\n"),
+        "{response}"
+    );
+    assert!(response.contains("Connection: close\r\n"));
+    assert!(response.contains(r#""type":"QueuePushed""#), "{response}");
+}
@@ -68,24 +68,38 @@ pub(super) fn handle_worker_start(
         }
     };
 
-    // Validate referenced queue exists
-    if runbook.get_queue(&worker_def.source.queue).is_none() {
-        return Ok(Response::Error {
-            message: format!(
-                "worker '{}' references unknown queue '{}'",
-                worker_name, worker_def.source.queue
-            ),
-        });
+    // Validate referenced queues exist
+    for (queue, _) in worker_def.source.weighted() {
+        if runbook.get_queue(&queue).is_none() {
+            return Ok(Response::Error {
+                message: format!(
+                    "worker '{}' references unknown queue '{}'",
+                    worker_name, queue
+                ),
+            });
+        }
     }
 
-    // Validate referenced job exists
-    if runbook.get_job(&worker_def.handler.job).is_none() {
-        return Ok(Response::Error {
-            message: format!(
-                "worker '{}' references unknown job '{}'",
-                worker_name, worker_def.handler.job
-            ),
-        });
+    // Validate referenced job or agent exists
+    match &worker_def.handler.agent {
+        Some(agent) if runbook.get_agent(agent).is_none() => {
+            return Ok(Response::Error {
+                message: format!(
+                    "worker '{}' references unknown agent '{}'",
+                    worker_name, agent
+                ),
+            });
+        }
+        Some(_) => {}
+        None if runbook.get_job(&worker_def.handler.job).is_none() => {
+            return Ok(Response::Error {
+                message: format!(
+                    "worker '{}' references unknown job '{}'",
+                    worker_name, worker_def.handler.job
+                ),
+            });
+        }
+        None => {}
     }
 
     // If the worker is already running, emit WorkerWake instead of WorkerStarted
@@ -123,7 +137,9 @@ pub(super) fn handle_worker_start(
             project_root: project_root.to_path_buf(),
             runbook_hash,
             queue_name: worker_def.source.queue.clone(),
+            queues: worker_def.source.queues.clone(),
             concurrency: worker_def.concurrency,
+            rate: worker_def.rate.clone(),
             namespace: namespace.to_string(),
         },
     )?;
@@ -244,36 +260,52 @@ pub(super) fn handle_worker_restart(
 }
 
 /// Handle a WorkerResize request: update concurrency at runtime.
+/// Handle a WorkerResize request.
+///
+/// Changes the worker's concurrency, its dispatch rate, or both. A rate of
+/// "none" removes the limit.
 pub(super) fn handle_worker_resize(
     ctx: &ListenCtx,
     worker_name: &str,
     namespace: &str,
-    concurrency: u32,
+    concurrency: Option<u32>,
+    rate: Option<&str>,
 ) -> Result<Response, ConnectionError> {
     // Validate concurrency > 0
-    if concurrency == 0 {
+    if concurrency == Some(0) {
         return Ok(Response::Error {
             message: "concurrency must be at least 1".to_string(),
         });
     }
+    let new_rate = match rate {
+        Some("none") => Some(None),
+        Some(rate) => match oj_core::DispatchRate::parse(rate) {
+            Ok(_) => Some(Some(rate.to_string())),
+            Err(message) => return Ok(Response::Error { message }),
+        },
+        None => None,
+    };
 
-    // Check if worker exists and get current concurrency
+    // Check if worker exists and get current concurrency and rate
     let scoped = oj_core::scoped_name(namespace, worker_name);
-    let old_concurrency = match ctx.state.lock().workers.get(&scoped) {
-        Some(record) => record.concurrency,
+    let (old_concurrency, old_rate) = match ctx.state.lock().workers.get(&scoped) {
+        Some(record) => (record.concurrency, record.rate.clone()),
         None => {
             return Ok(Response::Error {
                 message: format!("unknown worker: {}", worker_name),
             })
         }
     };
+    let new_concurrency = concurrency.unwrap_or(old_concurrency);
+    let new_rate = new_rate.unwrap_or_else(|| old_rate.clone());
 
     // Emit event
     emit(
         &ctx.event_bus,
         Event::WorkerResized {
             worker_name: worker_name.to_string(),
-            concurrency,
+            concurrency: new_concurrency,
+            rate: new_rate.clone(),
             namespace: namespace.to_string(),
         },
     )?;
@@ -281,7 +313,9 @@ pub(super) fn handle_worker_resize(
     Ok(Response::WorkerResized {
         worker_name: worker_name.to_string(),
         old_concurrency,
-        new_concurrency: concurrency,
+        new_concurrency,
+        old_rate,
+        new_rate,
     })
 }
 
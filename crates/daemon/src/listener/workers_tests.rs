@@ -113,8 +113,12 @@ fn stop_suggests_similar_worker_from_state() {
                 runbook_hash: "fake-hash".to_string(),
                 status: "running".to_string(),
                 active_job_ids: vec![],
+                active_agent_run_ids: vec![],
                 queue_name: "tasks".to_string(),
+                queues: Default::default(),
                 concurrency: 1,
+                rate: None,
+                bucket_full_at_ms: None,
                 namespace: String::new(),
             },
         );
@@ -144,8 +148,12 @@ fn stop_suggests_cross_namespace_worker() {
                 runbook_hash: "fake-hash".to_string(),
                 status: "running".to_string(),
                 active_job_ids: vec![],
+                active_agent_run_ids: vec![],
                 queue_name: "issues".to_string(),
+                queues: Default::default(),
                 concurrency: 1,
+                rate: None,
+                bucket_full_at_ms: None,
                 namespace: "other-project".to_string(),
             },
         );
@@ -190,8 +198,12 @@ fn restart_stops_existing_then_starts() {
                 runbook_hash: "fake-hash".to_string(),
                 status: "running".to_string(),
                 active_job_ids: vec![],
+                active_agent_run_ids: vec![],
                 queue_name: "tasks".to_string(),
+                queues: Default::default(),
                 concurrency: 1,
+                rate: None,
+                bucket_full_at_ms: None,
                 namespace: String::new(),
             },
         );
@@ -251,8 +263,12 @@ job "handle" {
                 runbook_hash: "old-hash".to_string(),
                 status: "running".to_string(),
                 active_job_ids: vec![],
+                active_agent_run_ids: vec![],
                 queue_name: "tasks".to_string(),
+                queues: Default::default(),
                 concurrency: 1,
+                rate: None,
+                bucket_full_at_ms: None,
                 namespace: String::new(),
             },
         );
@@ -294,8 +310,12 @@ fn resolve_effective_project_root_uses_known_root_when_namespace_differs() {
             runbook_hash: "hash".to_string(),
             status: "running".to_string(),
             active_job_ids: vec![],
+            active_agent_run_ids: vec![],
             queue_name: "merges".to_string(),
+            queues: Default::default(),
             concurrency: 1,
+            rate: None,
+            bucket_full_at_ms: None,
             namespace: "wok".to_string(),
         },
     );
@@ -391,8 +411,12 @@ job "handle-merge" {
                 runbook_hash: "hash".to_string(),
                 status: "stopped".to_string(),
                 active_job_ids: vec![],
+                active_agent_run_ids: vec![],
                 queue_name: "other".to_string(),
+                queues: Default::default(),
                 concurrency: 1,
+                rate: None,
+                bucket_full_at_ms: None,
                 namespace: "wok".to_string(),
             },
         );
@@ -453,8 +477,12 @@ job "build" {
                 runbook_hash: "old-hash".to_string(),
                 status: "running".to_string(),
                 active_job_ids: vec![],
+                active_agent_run_ids: vec![],
                 queue_name: "bugs".to_string(),
+                queues: Default::default(),
                 concurrency: 3,
+                rate: None,
+                bucket_full_at_ms: None,
                 namespace: String::new(),
             },
         );
@@ -34,7 +34,7 @@ use tracing::{error, info, warn};
 
 use crate::event_bus::EventBus;
 use crate::lifecycle::{Config, LifecycleError, StartupResult};
-use crate::listener::Listener;
+use crate::listener::{Listener, WebhookListener};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -93,6 +93,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let StartupResult {
         mut daemon,
         listener: unix_listener,
+        webhooks,
         mut event_reader,
         reconcile_ctx,
     } = match lifecycle::startup(&config).await {
@@ -147,9 +148,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         start_time: daemon.start_time,
         shutdown: Arc::clone(&shutdown_notify),
     });
-    let listener = Listener::new(unix_listener, ctx);
+    let listener = Listener::new(unix_listener, Arc::clone(&ctx));
     tokio::spawn(listener.run());
 
+    // Spawn the webhook listener when `[webhooks]` is configured
+    if let Some((socket, routes)) = webhooks {
+        tokio::spawn(WebhookListener::new(socket, routes, ctx).run());
+    }
+
     // Spawn checkpoint task for periodic snapshots
     spawn_checkpoint(
         Arc::clone(&daemon.state),
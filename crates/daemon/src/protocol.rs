@@ -18,17 +18,17 @@ pub use query::Query;
 #[path = "protocol_status.rs"]
 mod status;
 pub use status::{
-    AgentEntry, AgentStatusEntry, CronEntry, CronSummary, JobEntry, JobStatusEntry,
-    MetricsHealthSummary, NamespaceStatus, OrphanAgent, OrphanSummary, ProjectSummary,
-    QueueItemEntry, QueueStatus, SessionEntry, WorkerEntry,
+    AgentEntry, AgentStatusEntry, CronEntry, CronSummary, GitTriggerSummary, JobEntry,
+    JobStatusEntry, MetricsHealthSummary, NamespaceStatus, OrphanAgent, OrphanSummary,
+    ProjectSummary, QueueItemEntry, QueueStatus, SessionEntry, WatchSummary, WorkerEntry,
 };
 
 #[path = "protocol_types.rs"]
 mod types;
 pub use types::{
     AgentDetail, AgentSummary, DecisionDetail, DecisionOptionDetail, DecisionSummary, JobDetail,
-    JobSummary, QueueItemSummary, QueueSummary, SessionSummary, StepRecordDetail, WorkerSummary,
-    WorkspaceDetail, WorkspaceEntry, WorkspaceSummary,
+    JobSummary, QueueItemSummary, QueueSummary, SessionSummary, SpendDetail, StepRecordDetail,
+    WorkerSourceSummary, WorkerSummary, WorkspaceDetail, WorkspaceEntry, WorkspaceSummary,
 };
 
 #[path = "protocol_wire.rs"]
@@ -105,6 +105,12 @@ pub enum Request {
         args: Vec<String>,
         /// Named arguments (key=value pairs)
         named_args: HashMap<String, String>,
+        /// Jobs that must finish before the job starts, as `<id>[:done|:any]`
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        after: Vec<String>,
+        /// Admission priority when running-job limits queue the job
+        #[serde(default)]
+        priority: i32,
     },
 
     /// Delete a specific workspace by ID
@@ -209,12 +215,18 @@ pub enum Request {
         worker_name: String,
     },
 
-    /// Resize a worker's concurrency at runtime
+    /// Resize a worker's concurrency or dispatch rate at runtime
     WorkerResize {
         worker_name: String,
         #[serde(default)]
         namespace: String,
-        concurrency: u32,
+        /// New concurrency; None leaves it unchanged
+        #[serde(default)]
+        concurrency: Option<u32>,
+        /// New rate limit (e.g. "10/h"), or "none" to remove it; None leaves
+        /// it unchanged
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        rate: Option<String>,
     },
 
     /// Start a cron timer
@@ -262,6 +274,48 @@ pub enum Request {
         cron_name: String,
     },
 
+    /// Start watching files for a runbook `watch` block
+    WatchStart {
+        project_root: PathBuf,
+        #[serde(default)]
+        namespace: String,
+        /// Watch name (empty string when `all` is true)
+        watch_name: String,
+        /// Start all watches defined in runbooks
+        #[serde(default)]
+        all: bool,
+    },
+
+    /// Stop a file watch
+    WatchStop {
+        watch_name: String,
+        #[serde(default)]
+        namespace: String,
+        #[serde(default)]
+        project_root: Option<PathBuf>,
+    },
+
+    /// Start watching refs for a runbook `git_trigger` block
+    GitTriggerStart {
+        project_root: PathBuf,
+        #[serde(default)]
+        namespace: String,
+        /// Trigger name (empty string when `all` is true)
+        trigger_name: String,
+        /// Start all git triggers defined in runbooks
+        #[serde(default)]
+        all: bool,
+    },
+
+    /// Stop a git trigger
+    GitTriggerStop {
+        trigger_name: String,
+        #[serde(default)]
+        namespace: String,
+        #[serde(default)]
+        project_root: Option<PathBuf>,
+    },
+
     /// Push an item to a queue (persisted: enqueue data; external: trigger poll)
     QueuePush {
         project_root: PathBuf,
@@ -269,6 +323,18 @@ pub enum Request {
         namespace: String,
         queue_name: String,
         data: serde_json::Value,
+        /// Overrides the queue's `priority` template
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        priority: Option<i32>,
+        /// Keep the item hidden from workers until this time
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        visible_after_epoch_ms: Option<u64>,
+        /// Overrides the queue's `dedup_key` template
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        dedup_key: Option<String>,
+        /// Overrides the queue's `ttl` (duration string, e.g. "7d")
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        ttl: Option<String>,
     },
 
     /// Drop an item from a persisted queue
@@ -322,6 +388,16 @@ pub enum Request {
         queue_name: String,
     },
 
+    /// Change a queue item's dispatch priority
+    QueueEdit {
+        project_root: PathBuf,
+        #[serde(default)]
+        namespace: String,
+        queue_name: String,
+        item_id: String,
+        priority: i32,
+    },
+
     /// Force-fail an active queue item
     QueueFail {
         project_root: PathBuf,
@@ -361,6 +437,9 @@ pub enum Request {
         /// Freeform message
         #[serde(default, skip_serializing_if = "Option::is_none")]
         message: Option<String>,
+        /// Values for the decision's fields (approval steps)
+        #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+        values: HashMap<String, String>,
     },
 
     /// Resume all resumable jobs (waiting/failed/pending)
@@ -545,11 +624,15 @@ pub enum Response {
         skipped: Vec<(String, String)>,
     },
 
-    /// Worker concurrency was updated
+    /// Worker concurrency or rate was updated
     WorkerResized {
         worker_name: String,
         old_concurrency: u32,
         new_concurrency: u32,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        old_rate: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        new_rate: Option<String>,
     },
 
     /// Cron started successfully
@@ -574,8 +657,44 @@ pub enum Response {
         content: String,
     },
 
+    /// Watch started successfully
+    WatchStarted { watch_name: String },
+
+    /// Multiple watches started (--all mode)
+    WatchesStarted {
+        /// Watches that were started
+        started: Vec<String>,
+        /// Watches that were skipped with reasons
+        skipped: Vec<(String, String)>,
+    },
+
+    /// List of watches
+    Watches { watches: Vec<WatchSummary> },
+
+    /// Git trigger started successfully
+    GitTriggerStarted { trigger_name: String },
+
+    /// Multiple git triggers started (--all mode)
+    GitTriggersStarted {
+        /// Triggers that were started
+        started: Vec<String>,
+        /// Triggers that were skipped with reasons
+        skipped: Vec<(String, String)>,
+    },
+
+    /// List of git triggers
+    GitTriggers {
+        git_triggers: Vec<GitTriggerSummary>,
+    },
+
     /// Item pushed to queue (persisted) or workers woken to re-poll (external)
-    QueuePushed { queue_name: String, item_id: String },
+    QueuePushed {
+        queue_name: String,
+        item_id: String,
+        /// The push matched an existing item, whose ID is returned instead
+        #[serde(default)]
+        deduplicated: bool,
+    },
 
     /// Item was dropped from queue
     QueueDropped { queue_name: String, item_id: String },
@@ -600,6 +719,13 @@ pub enum Response {
         items: Vec<QueueItemSummary>,
     },
 
+    /// Item's priority was changed
+    QueueEdited {
+        queue_name: String,
+        item_id: String,
+        priority: i32,
+    },
+
     /// Item was force-failed
     QueueFailed { queue_name: String, item_id: String },
 
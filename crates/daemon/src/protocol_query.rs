@@ -94,6 +94,10 @@ pub enum Query {
         #[serde(default)]
         project_root: Option<PathBuf>,
     },
+    /// List all file watches and their status
+    ListWatches,
+    /// List all git ref triggers and their status
+    ListGitTriggers,
     /// Get a cross-project status overview
     StatusOverview,
     /// List all projects with active work
@@ -16,12 +16,51 @@ pub struct CronSummary {
     pub name: String,
     #[serde(default)]
     pub namespace: String,
+    /// Interval ("30m"), or schedule expression and timezone
+    /// ("0 9 * * 1-5 (America/New_York)")
     pub interval: String,
     pub job: String,
     pub status: String,
     /// Human-readable time: "in 12m" for running, "3h ago" for stopped
     #[serde(default)]
     pub time: String,
+    /// Epoch ms of the next fire, for running crons
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_fire_at_ms: Option<u64>,
+}
+
+/// Summary of a file watch for listing
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WatchSummary {
+    pub name: String,
+    #[serde(default)]
+    pub namespace: String,
+    /// Glob patterns relative to the project root
+    pub paths: Vec<String>,
+    pub debounce: String,
+    pub job: String,
+    pub status: String,
+    /// When the watch last started a job ("3h ago"), or "-"
+    #[serde(default)]
+    pub last_fired: String,
+}
+
+/// Summary of a git ref trigger for listing
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GitTriggerSummary {
+    pub name: String,
+    #[serde(default)]
+    pub namespace: String,
+    /// Full ref name or glob
+    pub ref_pattern: String,
+    pub job: String,
+    pub status: String,
+    /// Number of matching refs currently tracked
+    #[serde(default)]
+    pub refs: usize,
+    /// When the trigger last started a job ("3h ago"), or "-"
+    #[serde(default)]
+    pub last_fired: String,
 }
 
 /// Per-namespace status summary
@@ -32,6 +71,9 @@ pub struct NamespaceStatus {
     pub active_jobs: Vec<JobStatusEntry>,
     /// Jobs in Waiting status (escalated to human)
     pub escalated_jobs: Vec<JobStatusEntry>,
+    /// Jobs waiting for a running-job slot, in admission order
+    #[serde(default)]
+    pub queued_jobs: Vec<JobStatusEntry>,
     /// Orphaned jobs detected from breadcrumb files
     pub orphaned_jobs: Vec<JobStatusEntry>,
     /// Workers and their status
@@ -57,11 +99,21 @@ pub struct JobStatusEntry {
     /// Epoch ms of the most recent step activity (start or finish)
     #[serde(default)]
     pub last_activity_ms: u64,
-    /// Reason job is waiting (from StepOutcome::Waiting)
+    /// Reason job is waiting (from StepOutcome::Waiting or Blocked)
     pub waiting_reason: Option<String>,
     /// Escalation source category (e.g., "idle", "error", "gate", "approval")
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub escalate_source: Option<String>,
+    /// Place in the daemon-wide admission queue (1 = admitted next)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub queue_position: Option<usize>,
+    /// Admission priority of a queued job
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub priority: i32,
+}
+
+fn is_zero(n: &i32) -> bool {
+    *n == 0
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
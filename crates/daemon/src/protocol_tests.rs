@@ -19,6 +19,8 @@ fn encode_decode_roundtrip_request() {
             command: "build".to_string(),
             namespace: String::new(),
             args: HashMap::from([("name".to_string(), "test".to_string())]),
+            after: vec![],
+            priority: 0,
         },
     };
 
@@ -83,6 +85,7 @@ fn job_summary_serialization() {
         updated_at_ms: 1700000001000,
         namespace: String::new(),
         retry_count: 0,
+        blocked_on: vec![],
     };
 
     let response = Response::Jobs {
@@ -177,6 +180,9 @@ fn encode_decode_workers_response() {
             status: "running".to_string(),
             active: 2,
             concurrency: 3,
+            rate: None,
+            next_dispatch_at_ms: None,
+            sources: vec![],
             namespace: String::new(),
             updated_at_ms: 0,
         }],
@@ -613,6 +619,8 @@ fn step_record_detail_from_running() {
         outcome: StepOutcome::Running,
         agent_id: Some("agent-1".to_string()),
         agent_name: Some("coder".to_string()),
+        job_id: None,
+        branches: Vec::new(),
     };
     let detail = StepRecordDetail::from(&record);
     assert_eq!(detail.name, "build");
@@ -633,6 +641,8 @@ fn step_record_detail_from_completed() {
         outcome: StepOutcome::Completed,
         agent_id: None,
         agent_name: None,
+        job_id: None,
+        branches: Vec::new(),
     };
     let detail = StepRecordDetail::from(&record);
     assert_eq!(detail.outcome, StepOutcomeKind::Completed);
@@ -649,6 +659,8 @@ fn step_record_detail_from_failed() {
         outcome: StepOutcome::Failed("compilation error".to_string()),
         agent_id: Some("agent-2".to_string()),
         agent_name: None,
+        job_id: None,
+        branches: Vec::new(),
     };
     let detail = StepRecordDetail::from(&record);
     assert_eq!(detail.outcome, StepOutcomeKind::Failed);
@@ -664,6 +676,8 @@ fn step_record_detail_from_waiting() {
         outcome: StepOutcome::Waiting("gate check failed".to_string()),
         agent_id: None,
         agent_name: None,
+        job_id: None,
+        branches: Vec::new(),
     };
     let detail = StepRecordDetail::from(&record);
     assert_eq!(detail.outcome, StepOutcomeKind::Waiting);
@@ -6,7 +6,7 @@
 use std::collections::HashMap;
 use std::path::PathBuf;
 
-use oj_core::{StepOutcome, StepOutcomeKind, StepRecord, StepStatusKind};
+use oj_core::{StepOutcome, StepOutcomeKind, StepRecord, StepStatusKind, TokenUsage};
 use serde::{Deserialize, Serialize};
 
 /// Summary of a job for listing
@@ -26,6 +26,9 @@ pub struct JobSummary {
     pub namespace: String,
     #[serde(default)]
     pub retry_count: u32,
+    /// Jobs this job is held on (`--after`, `depends_on`) that haven't finished
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub blocked_on: Vec<String>,
 }
 
 /// Detailed job information
@@ -46,6 +49,39 @@ pub struct JobDetail {
     pub agents: Vec<AgentSummary>,
     #[serde(default)]
     pub namespace: String,
+    /// Job whose step started this one (parallel branches and sub-jobs)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_job_id: Option<String>,
+    /// Jobs this job is held on (`--after`, `depends_on`) that haven't finished
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub blocked_on: Vec<String>,
+    /// Place in the admission queue (1 = next), when waiting for a running-job slot
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub queue_position: Option<usize>,
+    /// Tokens and estimated cost across the job's agents
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spend: Option<SpendDetail>,
+}
+
+/// Token spend as counted against budgets, with an estimated cost
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SpendDetail {
+    pub tokens: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usd: Option<f64>,
+}
+
+impl SpendDetail {
+    /// None until the usage collector has reported anything.
+    pub fn from_usage(usage: &TokenUsage, usd: Option<f64>) -> Option<Self> {
+        if *usage == TokenUsage::default() {
+            return None;
+        }
+        Some(SpendDetail {
+            tokens: usage.budget_tokens(),
+            usd,
+        })
+    }
 }
 
 /// Record of a step execution for display
@@ -60,6 +96,18 @@ pub struct StepRecordDetail {
     pub agent_id: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub agent_name: Option<String>,
+    /// Shell timeout the runbook sets for this step (filled in by the daemon)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<String>,
+    /// Child job that ran this record (parallel branches and sub-job steps)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub job_id: Option<String>,
+    /// Per-branch records of a parallel step
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub branches: Vec<StepRecordDetail>,
+    /// Step history of the sub-job this step ran (filled in by the daemon)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub steps: Vec<StepRecordDetail>,
 }
 
 impl From<&StepRecord> for StepRecordDetail {
@@ -71,11 +119,15 @@ impl From<&StepRecord> for StepRecordDetail {
             outcome: StepOutcomeKind::from(&r.outcome),
             detail: match &r.outcome {
                 StepOutcome::Failed(e) => Some(e.clone()),
-                StepOutcome::Waiting(r) => Some(r.clone()),
+                StepOutcome::Waiting(r) | StepOutcome::Blocked(r) => Some(r.clone()),
                 _ => None,
             },
             agent_id: r.agent_id.clone(),
             agent_name: r.agent_name.clone(),
+            timeout: None,
+            job_id: r.job_id.clone(),
+            branches: r.branches.iter().map(StepRecordDetail::from).collect(),
+            steps: Vec::new(),
         }
     }
 }
@@ -100,6 +152,8 @@ pub struct AgentDetail {
     pub started_at_ms: u64,
     pub finished_at_ms: Option<u64>,
     pub updated_at_ms: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spend: Option<SpendDetail>,
 }
 
 /// Summary of agent activity for a job step
@@ -188,6 +242,14 @@ pub struct QueueItemSummary {
     pub pushed_at_epoch_ms: u64,
     #[serde(default)]
     pub failure_count: u32,
+    #[serde(default)]
+    pub priority: i32,
+    /// When a delayed item becomes visible to workers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visible_after_epoch_ms: Option<u64>,
+    /// When an active item's lease runs out unless its handler renews it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lease_expires_at_epoch_ms: Option<u64>,
 }
 
 /// Summary of a queue for listing
@@ -232,6 +294,12 @@ pub struct DecisionDetail {
     pub superseded_by: Option<String>,
     #[serde(default)]
     pub namespace: String,
+    /// Freeform fields the resolver fills in (approval steps)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<String>,
+    /// Field values given on resolve
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub values: HashMap<String, String>,
 }
 
 /// A single decision option for display
@@ -253,7 +321,27 @@ pub struct WorkerSummary {
     pub status: String,
     pub active: usize,
     pub concurrency: u32,
+    /// Dispatch rate limit (e.g. "10/h"), if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate: Option<String>,
+    /// Epoch ms of the next dispatch, while waiting on the rate limit
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_dispatch_at_ms: Option<u64>,
+    /// Per-queue counts for a worker with weighted sources
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sources: Vec<WorkerSourceSummary>,
     /// Most recent activity timestamp (from active jobs)
     #[serde(default)]
     pub updated_at_ms: u64,
 }
+
+/// One of a weighted worker's source queues
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WorkerSourceSummary {
+    pub queue: String,
+    pub weight: u32,
+    /// Items from this queue the worker is running
+    pub active: usize,
+    /// Items waiting in this queue
+    pub pending: usize,
+}
@@ -170,6 +170,8 @@ fn write_captures_agents_from_history() {
             outcome: StepOutcome::Completed,
             agent_id: Some("p-001-build".to_string()),
             agent_name: None,
+            job_id: None,
+            branches: Vec::new(),
         },
         oj_core::StepRecord {
             name: "test".to_string(),
@@ -178,6 +180,8 @@ fn write_captures_agents_from_history() {
             outcome: StepOutcome::Running,
             agent_id: Some("p-001-test".to_string()),
             agent_name: None,
+            job_id: None,
+            branches: Vec::new(),
         },
     ];
     job.step = "test".to_string();
@@ -42,6 +42,11 @@ pub enum EscalationTrigger {
         question_data: Option<QuestionData>,
         assistant_context: Option<String>,
     },
+    /// Agent or job spend crossed its budget (on_budget)
+    Budget {
+        reason: String,
+        assistant_context: Option<String>,
+    },
 }
 
 impl EscalationTrigger {
@@ -53,6 +58,8 @@ impl EscalationTrigger {
             EscalationTrigger::GateFailed { .. } => DecisionSource::Gate,
             EscalationTrigger::Prompt { .. } => DecisionSource::Approval,
             EscalationTrigger::Question { .. } => DecisionSource::Question,
+            // Options line up with Idle's, so resolution maps the same way
+            EscalationTrigger::Budget { .. } => DecisionSource::Idle,
         }
     }
 }
@@ -140,6 +147,8 @@ impl EscalationDecisionBuilder {
             options,
             created_at_ms,
             namespace: self.namespace,
+            step: None,
+            fields: Vec::new(),
         };
 
         (decision_id, event)
@@ -241,6 +250,16 @@ impl EscalationDecisionBuilder {
                 }
                 assistant_context.as_deref()
             }
+            EscalationTrigger::Budget {
+                reason,
+                assistant_context,
+            } => {
+                parts.push(format!(
+                    "Agent in job \"{}\" went over budget: {}.",
+                    self.display_name, reason
+                ));
+                assistant_context.as_deref()
+            }
         };
 
         // Assistant context from session transcript
@@ -279,6 +298,14 @@ impl EscalationDecisionBuilder {
                 DecisionOption::new("Dismiss")
                     .description("Dismiss this notification without taking action"),
             ],
+            EscalationTrigger::Budget { .. } => vec![
+                DecisionOption::new("Continue")
+                    .description("Let the agent keep working past the budget"),
+                DecisionOption::new("Done").description("Mark as complete"),
+                DecisionOption::new("Cancel").description("Cancel and fail"),
+                DecisionOption::new("Dismiss")
+                    .description("Dismiss this notification without taking action"),
+            ],
             EscalationTrigger::Dead { .. } | EscalationTrigger::Error { .. } => vec![
                 DecisionOption::new("Retry")
                     .description("Restart the agent with --resume to continue")
@@ -38,6 +38,34 @@ fn test_idle_trigger_builds_correct_options() {
     }
 }
 
+#[test]
+fn test_budget_trigger_uses_idle_positions() {
+    let (_, event) = EscalationDecisionBuilder::for_job(
+        JobId::new("pipe-1"),
+        "test-job".to_string(),
+        EscalationTrigger::Budget {
+            reason: "job used 2100 tokens of 2000".to_string(),
+            assistant_context: None,
+        },
+    )
+    .build();
+
+    match event {
+        Event::DecisionCreated {
+            options,
+            source,
+            context,
+            ..
+        } => {
+            assert_eq!(source, DecisionSource::Idle);
+            let labels: Vec<_> = options.iter().map(|o| o.label.as_str()).collect();
+            assert_eq!(labels, ["Continue", "Done", "Cancel", "Dismiss"]);
+            assert!(context.contains("went over budget: job used 2100 tokens of 2000."));
+        }
+        _ => panic!("expected DecisionCreated"),
+    }
+}
+
 #[test]
 fn test_dead_trigger_builds_correct_options() {
     let (_, event) = EscalationDecisionBuilder::for_job(
@@ -4,12 +4,14 @@
 //! Effect executor
 
 use crate::{scheduler::Scheduler, RuntimeDeps};
-use oj_adapters::subprocess::{run_with_timeout, QUEUE_COMMAND_TIMEOUT, SHELL_COMMAND_TIMEOUT};
+use oj_adapters::subprocess::{run_in_process_group, QUEUE_COMMAND_TIMEOUT, SHELL_COMMAND_TIMEOUT};
 use oj_adapters::{
-    AgentAdapter, AgentReconnectConfig, AgentSpawnConfig, NotifyAdapter, SessionAdapter,
+    AgentAdapter, AgentReconnectConfig, AgentSpawnConfig, FileWatch, GitRefWatch, NotifyAdapter,
+    SessionAdapter,
 };
 use oj_core::{Clock, Effect, Event};
 use oj_storage::MaterializedState;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use parking_lot::Mutex;
@@ -29,6 +31,10 @@ pub enum ExecuteError {
     WorkspaceNotFound(String),
     #[error("shell execution error: {0}")]
     Shell(String),
+    #[error("watch error: {0}")]
+    Watch(#[from] oj_adapters::FileWatchError),
+    #[error("git ref watch error: {0}")]
+    GitRefWatch(#[from] oj_adapters::GitRefWatchError),
 }
 
 /// Executes effects using the configured adapters
@@ -41,6 +47,10 @@ pub struct Executor<S, A, N, C: Clock> {
     clock: C,
     /// Channel for emitting events from agent watchers
     event_tx: mpsc::Sender<Event>,
+    /// Running file watches, keyed by scoped watch name
+    file_watches: Mutex<HashMap<String, FileWatch>>,
+    /// Running git ref watches, keyed by scoped trigger name
+    git_watches: Mutex<HashMap<String, GitRefWatch>>,
 }
 
 impl<S, A, N, C> Executor<S, A, N, C>
@@ -65,6 +75,8 @@ where
             scheduler,
             clock,
             event_tx,
+            file_watches: Mutex::new(HashMap::new()),
+            git_watches: Mutex::new(HashMap::new()),
         }
     }
 
@@ -403,6 +415,92 @@ where
                 Ok(None)
             }
 
+            // === File watch effects ===
+            Effect::WatchFiles {
+                watch_name,
+                namespace,
+                root,
+                paths,
+                debounce,
+            } => {
+                let (tx, mut rx) = mpsc::channel(16);
+                let watch = oj_adapters::watch_files(&root, &paths, debounce, tx)?;
+
+                // Forward each settled burst of changes as a WatchChanged event.
+                // The task ends when the watch is dropped and closes the channel.
+                let key = oj_core::scoped_name(&namespace, &watch_name);
+                let event_tx = self.event_tx.clone();
+                tokio::spawn(async move {
+                    while let Some(files) = rx.recv().await {
+                        let event = Event::WatchChanged {
+                            watch_name: watch_name.clone(),
+                            namespace: namespace.clone(),
+                            files,
+                        };
+                        if let Err(e) = event_tx.send(event).await {
+                            tracing::error!("failed to send WatchChanged: {}", e);
+                            break;
+                        }
+                    }
+                });
+
+                // Replacing an existing watch drops, and so stops, the old one
+                self.file_watches.lock().insert(key, watch);
+                Ok(None)
+            }
+
+            Effect::UnwatchFiles {
+                watch_name,
+                namespace,
+            } => {
+                self.file_watches
+                    .lock()
+                    .remove(&oj_core::scoped_name(&namespace, &watch_name));
+                Ok(None)
+            }
+
+            // === Git trigger effects ===
+            Effect::WatchGitRefs {
+                trigger_name,
+                namespace,
+                repo,
+                ref_pattern,
+            } => {
+                let (tx, mut rx) = mpsc::channel(16);
+                let watch = oj_adapters::watch_git_refs(&repo, &ref_pattern, tx).await?;
+
+                // Forward each listing of the matching refs as a GitRefsScanned
+                // event; the task ends when the watch is dropped.
+                let key = oj_core::scoped_name(&namespace, &trigger_name);
+                let event_tx = self.event_tx.clone();
+                tokio::spawn(async move {
+                    while let Some(refs) = rx.recv().await {
+                        let event = Event::GitRefsScanned {
+                            trigger_name: trigger_name.clone(),
+                            namespace: namespace.clone(),
+                            refs,
+                        };
+                        if let Err(e) = event_tx.send(event).await {
+                            tracing::error!("failed to send GitRefsScanned: {}", e);
+                            break;
+                        }
+                    }
+                });
+
+                self.git_watches.lock().insert(key, watch);
+                Ok(None)
+            }
+
+            Effect::UnwatchGitRefs {
+                trigger_name,
+                namespace,
+            } => {
+                self.git_watches
+                    .lock()
+                    .remove(&oj_core::scoped_name(&namespace, &trigger_name));
+                Ok(None)
+            }
+
             // === Shell effects ===
             Effect::Shell {
                 owner,
@@ -410,6 +508,7 @@ where
                 command,
                 cwd,
                 env,
+                timeout,
             } => {
                 let event_tx = self.event_tx.clone();
 
@@ -436,11 +535,12 @@ where
                     let wrapped = format!("set -euo pipefail\n{command}");
                     let mut cmd = tokio::process::Command::new("bash");
                     cmd.arg("-c").arg(&wrapped).current_dir(&cwd).envs(&env);
-                    let result =
-                        run_with_timeout(cmd, SHELL_COMMAND_TIMEOUT, "shell command").await;
+                    let timeout = timeout.unwrap_or(SHELL_COMMAND_TIMEOUT);
+                    let result = run_in_process_group(cmd, timeout, "shell command").await;
 
+                    let mut timed_out = false;
                     let (exit_code, stdout, stderr) = match result {
-                        Ok(output) => {
+                        Ok(Some(output)) => {
                             let stdout_str = if output.stdout.is_empty() {
                                 None
                             } else {
@@ -469,6 +569,17 @@ where
                             };
                             (output.status.code().unwrap_or(-1), stdout_str, stderr_str)
                         }
+                        Ok(None) => {
+                            tracing::warn!(
+                                owner = %owner_str,
+                                step,
+                                cwd = %cwd.display(),
+                                timeout_secs = timeout.as_secs(),
+                                "shell command timed out; killed its process group"
+                            );
+                            timed_out = true;
+                            (-1, None, None)
+                        }
                         Err(e) => {
                             tracing::error!(
                                 owner = %owner_str,
@@ -487,6 +598,7 @@ where
                         exit_code,
                         stdout,
                         stderr,
+                        timed_out,
                     };
 
                     if let Err(e) = event_tx.send(event).await {
@@ -502,6 +614,7 @@ where
                 worker_name,
                 list_command,
                 cwd,
+                timeout,
             } => {
                 let event_tx = self.event_tx.clone();
 
@@ -516,7 +629,12 @@ where
                     let wrapped = format!("set -euo pipefail\n{list_command}");
                     let mut cmd = tokio::process::Command::new("bash");
                     cmd.arg("-c").arg(&wrapped).current_dir(&cwd);
-                    let result = run_with_timeout(cmd, QUEUE_COMMAND_TIMEOUT, "queue list").await;
+                    let result = run_queue_command(
+                        cmd,
+                        timeout.unwrap_or(QUEUE_COMMAND_TIMEOUT),
+                        "queue list",
+                    )
+                    .await;
 
                     let items = match result {
                         Ok(output) if output.status.success() => {
@@ -570,6 +688,7 @@ where
                 cwd,
                 item_id,
                 item,
+                timeout,
             } => {
                 let event_tx = self.event_tx.clone();
 
@@ -584,7 +703,12 @@ where
                     let wrapped = format!("set -euo pipefail\n{take_command}");
                     let mut cmd = tokio::process::Command::new("bash");
                     cmd.arg("-c").arg(&wrapped).current_dir(&cwd);
-                    let result = run_with_timeout(cmd, QUEUE_COMMAND_TIMEOUT, "queue take").await;
+                    let result = run_queue_command(
+                        cmd,
+                        timeout.unwrap_or(QUEUE_COMMAND_TIMEOUT),
+                        "queue take",
+                    )
+                    .await;
 
                     let (exit_code, stderr) = match result {
                         Ok(output) => {
@@ -714,6 +838,17 @@ where
     }
 }
 
+/// Run a queue `list`/`take` command, treating expiry as an error.
+async fn run_queue_command(
+    cmd: tokio::process::Command,
+    timeout: std::time::Duration,
+    description: &str,
+) -> Result<std::process::Output, String> {
+    run_in_process_group(cmd, timeout, description)
+        .await?
+        .ok_or_else(|| format!("{} timed out after {}s", description, timeout.as_secs()))
+}
+
 #[cfg(test)]
 #[path = "executor_tests/mod.rs"]
 mod tests;
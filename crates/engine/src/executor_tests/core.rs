@@ -24,6 +24,9 @@ async fn executor_emit_event_effect() {
                 created_at_epoch_ms: 1_000_000,
                 namespace: String::new(),
                 cron_name: None,
+                after: vec![],
+                priority: 0,
+                project_root: None,
             },
         })
         .await
@@ -193,6 +196,9 @@ async fn execute_all_collects_emitted_events() {
                 created_at_epoch_ms: 1_000,
                 namespace: String::new(),
                 cron_name: None,
+                after: vec![],
+                priority: 0,
+                project_root: None,
             },
         },
         Effect::Emit {
@@ -207,6 +213,9 @@ async fn execute_all_collects_emitted_events() {
                 created_at_epoch_ms: 2_000,
                 namespace: String::new(),
                 cron_name: None,
+                after: vec![],
+                priority: 0,
+                project_root: None,
             },
         },
     ];
@@ -235,6 +244,9 @@ async fn execute_all_mixed_effects() {
                 created_at_epoch_ms: 1_000,
                 namespace: String::new(),
                 cron_name: None,
+                after: vec![],
+                priority: 0,
+                project_root: None,
             },
         },
         Effect::Shell {
@@ -243,6 +255,7 @@ async fn execute_all_mixed_effects() {
             command: "echo mixed".to_string(),
             cwd: std::path::PathBuf::from("/tmp"),
             env: HashMap::new(),
+            timeout: None,
         },
         Effect::Notify {
             title: "Done".to_string(),
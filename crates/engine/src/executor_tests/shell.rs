@@ -17,6 +17,7 @@ async fn shell_effect_runs_command() {
             command: "echo hello".to_string(),
             cwd: std::path::PathBuf::from("/tmp"),
             env: HashMap::new(),
+            timeout: None,
         })
         .await
         .unwrap();
@@ -39,6 +40,7 @@ async fn shell_failure_returns_nonzero() {
             command: "exit 1".to_string(),
             cwd: std::path::PathBuf::from("/tmp"),
             env: HashMap::new(),
+            timeout: None,
         })
         .await
         .unwrap();
@@ -61,6 +63,7 @@ async fn shell_intermediate_failure_propagates() {
             command: "false\ntrue".to_string(),
             cwd: std::path::PathBuf::from("/tmp"),
             env: HashMap::new(),
+            timeout: None,
         })
         .await
         .unwrap();
@@ -88,6 +91,7 @@ async fn shell_pipefail_propagates() {
             command: "exit 1 | cat".to_string(),
             cwd: std::path::PathBuf::from("/tmp"),
             env: HashMap::new(),
+            timeout: None,
         })
         .await
         .unwrap();
@@ -115,6 +119,7 @@ async fn shell_captures_stdout_and_stderr() {
             command: "echo stdout_output && echo stderr_output >&2".to_string(),
             cwd: std::path::PathBuf::from("/tmp"),
             env: HashMap::new(),
+            timeout: None,
         })
         .await
         .unwrap();
@@ -150,6 +155,7 @@ async fn shell_with_env_vars() {
             command: "echo $MY_TEST_VAR".to_string(),
             cwd: std::path::PathBuf::from("/tmp"),
             env,
+            timeout: None,
         })
         .await
         .unwrap();
@@ -178,6 +184,7 @@ async fn shell_with_none_owner() {
             command: "echo no_owner".to_string(),
             cwd: std::path::PathBuf::from("/tmp"),
             env: HashMap::new(),
+            timeout: None,
         })
         .await
         .unwrap();
@@ -206,6 +213,7 @@ async fn shell_with_agent_run_owner() {
             command: "echo agent_run_shell".to_string(),
             cwd: std::path::PathBuf::from("/tmp"),
             env: HashMap::new(),
+            timeout: None,
         })
         .await
         .unwrap();
@@ -234,6 +242,7 @@ async fn shell_no_stdout_when_empty() {
             command: "true".to_string(),
             cwd: std::path::PathBuf::from("/tmp"),
             env: HashMap::new(),
+            timeout: None,
         })
         .await
         .unwrap();
@@ -265,6 +274,7 @@ async fn execute_all_shell_effects_are_async() {
             command: "echo first".to_string(),
             cwd: std::path::PathBuf::from("/tmp"),
             env: HashMap::new(),
+            timeout: None,
         },
         Effect::Shell {
             owner: Some(OwnerId::Job(JobId::new("pipe-1"))),
@@ -272,6 +282,7 @@ async fn execute_all_shell_effects_are_async() {
             command: "echo second".to_string(),
             cwd: std::path::PathBuf::from("/tmp"),
             env: HashMap::new(),
+            timeout: None,
         },
     ];
 
@@ -17,6 +17,7 @@ async fn poll_queue_with_valid_json() {
             worker_name: "poller".to_string(),
             list_command: r#"echo '[{"id":"1"},{"id":"2"}]'"#.to_string(),
             cwd: std::path::PathBuf::from("/tmp"),
+            timeout: None,
         })
         .await
         .unwrap();
@@ -47,6 +48,7 @@ async fn poll_queue_with_empty_output() {
             worker_name: "poller".to_string(),
             list_command: "echo '[]'".to_string(),
             cwd: std::path::PathBuf::from("/tmp"),
+            timeout: None,
         })
         .await
         .unwrap();
@@ -70,6 +72,7 @@ async fn poll_queue_with_invalid_json_returns_empty() {
             worker_name: "poller".to_string(),
             list_command: "echo 'not json'".to_string(),
             cwd: std::path::PathBuf::from("/tmp"),
+            timeout: None,
         })
         .await
         .unwrap();
@@ -96,6 +99,7 @@ async fn poll_queue_command_failure_returns_empty() {
             worker_name: "poller".to_string(),
             list_command: "exit 1".to_string(),
             cwd: std::path::PathBuf::from("/tmp"),
+            timeout: None,
         })
         .await
         .unwrap();
@@ -126,6 +130,7 @@ async fn take_queue_item_effect_runs_async() {
             cwd: std::path::PathBuf::from("/tmp"),
             item_id: "item-1".to_string(),
             item: serde_json::json!({"id": "item-1", "title": "test"}),
+            timeout: None,
         })
         .await
         .unwrap();
@@ -161,6 +166,7 @@ async fn take_queue_item_failure_returns_nonzero() {
             cwd: std::path::PathBuf::from("/tmp"),
             item_id: "item-2".to_string(),
             item: serde_json::json!({"id": "item-2"}),
+            timeout: None,
         })
         .await
         .unwrap();
@@ -191,6 +197,7 @@ async fn take_queue_item_with_stderr() {
             cwd: std::path::PathBuf::from("/tmp"),
             item_id: "item-3".to_string(),
             item: serde_json::json!({"id": "item-3"}),
+            timeout: None,
         })
         .await
         .unwrap();
@@ -226,6 +233,7 @@ async fn take_queue_item_preserves_item_data() {
             cwd: std::path::PathBuf::from("/tmp"),
             item_id: "item-4".to_string(),
             item: item_data.clone(),
+            timeout: None,
         })
         .await
         .unwrap();
@@ -17,6 +17,8 @@ mod error;
 mod executor;
 pub mod log_paths;
 mod monitor;
+mod outputs;
+mod retry;
 mod runtime;
 mod scheduler;
 mod spawn;
@@ -32,6 +34,7 @@ pub(crate) mod test_helpers;
 pub use activity_logger::{JobLogger, QueueLogger, WorkerLogger};
 pub use agent_logger::AgentLogger;
 pub use error::RuntimeError;
+pub use monitor::parse_duration;
 pub(crate) use monitor::ActionContext;
 pub use runtime::{Runtime, RuntimeConfig, RuntimeDeps};
 pub use usage_metrics::{MetricsHealth, UsageMetricsCollector};
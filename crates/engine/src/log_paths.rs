@@ -51,6 +51,22 @@ pub fn cron_log_path(logs_dir: &Path, cron_name: &str) -> PathBuf {
     logs_dir.join("cron").join(format!("{}.log", cron_name))
 }
 
+/// Build the path to a watch log file.
+///
+/// Structure: `{logs_dir}/watch/{watch_name}.log`
+pub fn watch_log_path(logs_dir: &Path, watch_name: &str) -> PathBuf {
+    logs_dir.join("watch").join(format!("{}.log", watch_name))
+}
+
+/// Build the path to a git trigger log file.
+///
+/// Structure: `{logs_dir}/git_trigger/{trigger_name}.log`
+pub fn git_trigger_log_path(logs_dir: &Path, trigger_name: &str) -> PathBuf {
+    logs_dir
+        .join("git_trigger")
+        .join(format!("{}.log", trigger_name))
+}
+
 /// Build the path to a worker log file.
 ///
 /// Structure: `{logs_dir}/worker/{worker_name}.log`
@@ -25,6 +25,10 @@ pub(crate) struct ActionContext<'a> {
     pub assistant_context: Option<&'a str>,
 }
 
+/// Prefix of the trigger passed to `on_budget` actions; the rest of the
+/// trigger describes the overrun (e.g. "job used 2100000 tokens of 2000000").
+pub(crate) const BUDGET_TRIGGER_PREFIX: &str = "budget exceeded: ";
+
 /// Parse a duration string like "30s", "5m", "1h" into a Duration
 pub fn parse_duration(s: &str) -> Result<Duration, String> {
     let s = s.trim();
@@ -239,6 +243,10 @@ pub fn build_action_effects(
                     prompt_type: "permission".to_string(),
                     assistant_context: ac,
                 },
+                t if t.starts_with(BUDGET_TRIGGER_PREFIX) => EscalationTrigger::Budget {
+                    reason: t.trim_start_matches(BUDGET_TRIGGER_PREFIX).to_string(),
+                    assistant_context: ac,
+                },
                 t if t.ends_with("_exhausted") => {
                     // Handle "idle_exhausted", "error_exhausted",
                     // "prompt:question_exhausted" etc.
@@ -403,6 +411,10 @@ pub fn build_action_effects_for_agent_run(
                     prompt_type: "permission".to_string(),
                     assistant_context: ac,
                 },
+                t if t.starts_with(BUDGET_TRIGGER_PREFIX) => EscalationTrigger::Budget {
+                    reason: t.trim_start_matches(BUDGET_TRIGGER_PREFIX).to_string(),
+                    assistant_context: ac,
+                },
                 t if t.ends_with("_exhausted") => {
                     let base = t.trim_end_matches("_exhausted");
                     match base {
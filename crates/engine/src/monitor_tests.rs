@@ -145,6 +145,8 @@ fn resume_without_message_uses_resume_session() {
         outcome: oj_core::StepOutcome::Running,
         agent_id: Some("prev-session-uuid".to_string()),
         agent_name: Some("worker".to_string()),
+        job_id: None,
+        branches: Vec::new(),
     });
     let agent = test_agent_def();
     let config = ActionConfig::simple(AgentAction::Resume);
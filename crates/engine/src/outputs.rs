@@ -0,0 +1,106 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Shell step outputs, exposed to later steps as `${steps.<name>.*}`.
+//!
+//! A shell step publishes:
+//! - `steps.<name>.output`: its trimmed stdout (minus marker lines), capped
+//!   at the last `MAX_OUTPUT_BYTES`
+//! - `steps.<name>.outputs.<key>`: keys of a JSON object, either written to
+//!   the file named by `$OJ_OUTPUTS` or printed on a `::outputs::` line
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Prefix of a stdout line carrying a JSON object of outputs.
+pub(crate) const OUTPUT_MARKER: &str = "::outputs::";
+
+/// Environment variable naming the file a shell step may write outputs to.
+pub(crate) const OUTPUTS_FILE_ENV: &str = "OJ_OUTPUTS";
+
+/// Most stdout kept in `steps.<name>.output`, which lives in the job's vars.
+/// Longer output keeps its end, where a command's result usually is.
+pub(crate) const MAX_OUTPUT_BYTES: usize = 16 * 1024;
+
+/// Path of the outputs file for a job's step.
+///
+/// Lives under the state directory so outputs survive workspace changes.
+pub(crate) fn outputs_path(state_dir: &Path, job_id: &str, step: &str) -> PathBuf {
+    state_dir
+        .join("outputs")
+        .join(job_id)
+        .join(format!("{}.json", step))
+}
+
+/// Build the `steps.<step>.*` variables from a finished shell step.
+///
+/// `file` is the content of the step's outputs file, if it wrote one. Keys
+/// from the file win over marker lines.
+pub(crate) fn capture_outputs(
+    step: &str,
+    stdout: Option<&str>,
+    file: Option<&str>,
+) -> Result<HashMap<String, String>, String> {
+    let mut output_lines = Vec::new();
+    let mut outputs = serde_json::Map::new();
+    for line in stdout.unwrap_or_default().lines() {
+        match line.trim_start().strip_prefix(OUTPUT_MARKER) {
+            Some(json) => outputs.extend(parse_object(json)?),
+            None => output_lines.push(line),
+        }
+    }
+    if let Some(json) = file.filter(|f| !f.trim().is_empty()) {
+        outputs.extend(parse_object(json)?);
+    }
+
+    let mut vars = HashMap::new();
+    let output = output_lines.join("\n");
+    vars.insert(
+        format!("steps.{}.output", step),
+        tail(output.trim(), MAX_OUTPUT_BYTES).to_string(),
+    );
+    for (key, value) in outputs {
+        let value = match value {
+            serde_json::Value::String(s) => s,
+            other => other.to_string(),
+        };
+        vars.insert(format!("steps.{}.outputs.{}", step, key), value);
+    }
+    Ok(vars)
+}
+
+/// Previously published `steps.<step>.*` keys that a new capture no longer
+/// sets, mapped to empty values so re-runs don't leave stale outputs behind.
+pub(crate) fn stale_outputs(
+    step: &str,
+    current: &HashMap<String, String>,
+    captured: &HashMap<String, String>,
+) -> HashMap<String, String> {
+    let prefix = format!("steps.{}.", step);
+    current
+        .keys()
+        .filter(|k| k.starts_with(&prefix) && !captured.contains_key(*k))
+        .map(|k| (k.clone(), String::new()))
+        .collect()
+}
+
+/// The last `max` bytes of `s`, moved forward to a character boundary.
+fn tail(s: &str, max: usize) -> &str {
+    let mut start = s.len().saturating_sub(max);
+    while !s.is_char_boundary(start) {
+        start += 1;
+    }
+    &s[start..]
+}
+
+fn parse_object(json: &str) -> Result<serde_json::Map<String, serde_json::Value>, String> {
+    match serde_json::from_str(json.trim()) {
+        Ok(serde_json::Value::Object(map)) => Ok(map),
+        Ok(_) => Err("outputs must be a JSON object".to_string()),
+        Err(e) => Err(format!("invalid outputs JSON: {}", e)),
+    }
+}
+
+#[cfg(test)]
+#[path = "outputs_tests.rs"]
+mod tests;
@@ -0,0 +1,70 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Tests for shell step output capture
+
+use super::*;
+
+#[test]
+fn output_is_trimmed_stdout() {
+    let vars = capture_outputs("build", Some("\n  abc123  \n\n"), None).unwrap();
+    assert_eq!(vars.len(), 1);
+    assert_eq!(vars["steps.build.output"], "abc123");
+}
+
+#[test]
+fn missing_stdout_gives_empty_output() {
+    let vars = capture_outputs("build", None, None).unwrap();
+    assert_eq!(vars["steps.build.output"], "");
+}
+
+#[test]
+fn marker_lines_become_outputs() {
+    let stdout = "building\n::outputs:: {\"sha\": \"abc\", \"count\": 3}\ndone\n";
+    let vars = capture_outputs("build", Some(stdout), None).unwrap();
+    assert_eq!(vars["steps.build.output"], "building\ndone");
+    assert_eq!(vars["steps.build.outputs.sha"], "abc");
+    assert_eq!(vars["steps.build.outputs.count"], "3");
+}
+
+#[test]
+fn file_outputs_override_marker_lines() {
+    let stdout = "::outputs::{\"sha\": \"old\", \"tag\": \"v1\"}";
+    let vars = capture_outputs("build", Some(stdout), Some(r#"{"sha": "new"}"#)).unwrap();
+    assert_eq!(vars["steps.build.outputs.sha"], "new");
+    assert_eq!(vars["steps.build.outputs.tag"], "v1");
+}
+
+#[test]
+fn non_object_outputs_are_rejected() {
+    let err = capture_outputs("build", None, Some("[1, 2]")).unwrap_err();
+    assert!(err.contains("JSON object"));
+
+    let err = capture_outputs("build", Some("::outputs:: {oops"), None).unwrap_err();
+    assert!(err.contains("invalid outputs JSON"));
+}
+
+#[test]
+fn stale_outputs_are_cleared() {
+    let current: HashMap<String, String> = [
+        ("steps.build.output".to_string(), "x".to_string()),
+        ("steps.build.outputs.old".to_string(), "y".to_string()),
+        ("steps.test.outputs.old".to_string(), "z".to_string()),
+    ]
+    .into_iter()
+    .collect();
+    let captured = capture_outputs("build", Some("x"), None).unwrap();
+
+    let stale = stale_outputs("build", &current, &captured);
+    assert_eq!(stale.len(), 1);
+    assert_eq!(stale["steps.build.outputs.old"], "");
+}
+
+#[test]
+fn long_output_keeps_its_end() {
+    let stdout = format!("{}\nresult: ok", "é".repeat(MAX_OUTPUT_BYTES));
+    let vars = capture_outputs("build", Some(&stdout), None).unwrap();
+    let output = &vars["steps.build.output"];
+    assert!(output.len() <= MAX_OUTPUT_BYTES);
+    assert!(output.ends_with("\nresult: ok"));
+}
@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Retry delays for step and queue `retry` policies
+
+use crate::monitor::parse_duration;
+use oj_runbook::{Backoff, RetryConfig};
+use std::time::Duration;
+
+/// Cap on the delay when a policy doesn't set `max`
+const DEFAULT_MAX_DELAY: Duration = Duration::from_secs(3600);
+
+/// Delay before retry number `attempt` (starting at 1).
+///
+/// `roll` is a random number in `[0, 1)`; with `jitter = j` it takes up to
+/// `j` of the computed delay off.
+pub(crate) fn retry_delay(retry: &RetryConfig, attempt: u32, roll: f64) -> Duration {
+    let base = parse_duration(&retry.base).unwrap_or(Duration::ZERO);
+    let max = retry
+        .max
+        .as_deref()
+        .and_then(|m| parse_duration(m).ok())
+        .unwrap_or(DEFAULT_MAX_DELAY);
+    let delay = match retry.backoff {
+        Backoff::Fixed => base,
+        Backoff::Exponential => {
+            let exponent = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
+            let secs = base.as_secs_f64() * retry.multiplier.powi(exponent);
+            Duration::try_from_secs_f64(secs).unwrap_or(Duration::MAX)
+        }
+    };
+    let delay = delay.min(max);
+    let jitter = retry.jitter.clamp(0.0, 1.0) * roll.clamp(0.0, 1.0);
+    delay.mul_f64(1.0 - jitter)
+}
+
+/// Delay before retry number `attempt`, with a fresh random jitter roll.
+pub(crate) fn next_retry_delay(retry: &RetryConfig, attempt: u32) -> Duration {
+    retry_delay(retry, attempt, rand::random::<f64>())
+}
+
+#[cfg(test)]
+#[path = "retry_tests.rs"]
+mod tests;
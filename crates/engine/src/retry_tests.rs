@@ -0,0 +1,46 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+fn policy(backoff: Backoff, base: &str, max: Option<&str>, jitter: f64) -> RetryConfig {
+    RetryConfig {
+        attempts: 5,
+        backoff,
+        base: base.to_string(),
+        multiplier: 2.0,
+        max: max.map(String::from),
+        jitter,
+    }
+}
+
+#[test]
+fn fixed_backoff_waits_base_every_time() {
+    let retry = policy(Backoff::Fixed, "30s", None, 0.0);
+    assert_eq!(retry_delay(&retry, 1, 0.5), Duration::from_secs(30));
+    assert_eq!(retry_delay(&retry, 4, 0.5), Duration::from_secs(30));
+}
+
+#[test]
+fn exponential_backoff_grows_until_max() {
+    let retry = policy(Backoff::Exponential, "10s", Some("1m"), 0.0);
+    let delays: Vec<u64> = (1..=5)
+        .map(|n| retry_delay(&retry, n, 0.0).as_secs())
+        .collect();
+    assert_eq!(delays, vec![10, 20, 40, 60, 60]);
+}
+
+#[test]
+fn exponential_backoff_defaults_to_an_hour_cap() {
+    let retry = policy(Backoff::Exponential, "1s", None, 0.0);
+    assert_eq!(retry_delay(&retry, 200, 0.0), DEFAULT_MAX_DELAY);
+}
+
+#[test]
+fn jitter_takes_a_random_share_off() {
+    let retry = policy(Backoff::Fixed, "100s", None, 0.2);
+    assert_eq!(retry_delay(&retry, 1, 0.0), Duration::from_secs(100));
+    assert_eq!(retry_delay(&retry, 1, 0.5), Duration::from_secs(90));
+    let delay = next_retry_delay(&retry, 1);
+    assert!(delay > Duration::from_secs(80) && delay <= Duration::from_secs(100));
+}
@@ -0,0 +1,161 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Admission control: caps on running jobs, daemon-wide and per project
+
+use super::dependencies::is_held;
+use super::Runtime;
+use crate::error::RuntimeError;
+use oj_adapters::{AgentAdapter, NotifyAdapter, SessionAdapter};
+use oj_core::{Clock, Effect, Event, Job, JobId};
+use oj_storage::MaterializedState;
+use std::time::SystemTime;
+
+/// A project's running-job limit, cached until its config file changes
+pub(crate) struct ProjectLimit {
+    modified: Option<SystemTime>,
+    max_jobs: Option<u32>,
+}
+
+impl<S, A, N, C> Runtime<S, A, N, C>
+where
+    S: SessionAdapter,
+    A: AgentAdapter,
+    N: NotifyAdapter,
+    C: Clock,
+{
+    /// Check whether a job may start its first step under the running-job
+    /// limits. If not, the job is put in the admission queue (`JobQueued`).
+    pub(crate) async fn check_admission(
+        &self,
+        job: &Job,
+        result_events: &mut Vec<Event>,
+    ) -> Result<bool, RuntimeError> {
+        if self.has_slot(job) {
+            return Ok(true);
+        }
+        self.logger
+            .append(&job.id, &job.step, "queued: running-job limit reached");
+        tracing::info!(job_id = %job.id, priority = job.priority, "job queued for admission");
+        let event = Event::JobQueued {
+            id: JobId::new(&job.id),
+        };
+        result_events.extend(self.executor.execute(Effect::Emit { event }).await?);
+        Ok(false)
+    }
+
+    /// Start queued jobs, in priority order, while there are free slots.
+    ///
+    /// A job held back only by its project's limit doesn't stop jobs from
+    /// other projects behind it.
+    pub async fn admit_queued_jobs(&self) -> Result<Vec<Event>, RuntimeError> {
+        let mut result_events = Vec::new();
+        loop {
+            let queued: Vec<Job> = self.lock_state(|state| {
+                oj_core::admission_order(state.jobs.values())
+                    .into_iter()
+                    .cloned()
+                    .collect()
+            });
+            let next = queued.into_iter().find(|job| self.has_slot(job));
+            let Some(job) = next else {
+                break;
+            };
+
+            self.logger.append(&job.id, &job.step, "admitted");
+            let event = Event::JobAdmitted {
+                id: JobId::new(&job.id),
+            };
+            result_events.extend(self.executor.execute(Effect::Emit { event }).await?);
+            // A job that fails to start doesn't hold back the ones behind it
+            let execution_dir = self.execution_dir(&job);
+            match self
+                .start_step(&JobId::new(&job.id), &job.step, &job.vars, &execution_dir)
+                .await
+            {
+                Ok(events) => result_events.extend(events),
+                Err(e) => {
+                    tracing::warn!(job_id = %job.id, error = %e, "admitted job failed to start");
+                    let job = self.require_job(&job.id)?;
+                    result_events.extend(self.fail_job(&job, &e.to_string()).await?);
+                }
+            }
+        }
+        Ok(result_events)
+    }
+
+    /// Whether starting `job` keeps it within the daemon-wide limit and its
+    /// project's limit.
+    fn has_slot(&self, job: &Job) -> bool {
+        let project_max_jobs = self.project_max_jobs(job);
+        self.lock_state(|state| has_slot(state, job, self.max_jobs, project_max_jobs))
+    }
+
+    /// The limit from `[limits] max_jobs` in the job's project config.
+    ///
+    /// Read at admission, not at job creation, so an edited limit applies to
+    /// jobs that are already queued.
+    fn project_max_jobs(&self, job: &Job) -> Option<u32> {
+        let project_root = match &job.project_root {
+            Some(root) => root.clone(),
+            // Jobs created before the project root was recorded
+            None => self.lock_state(|state| state.project_root_for_namespace(&job.namespace))?,
+        };
+        let modified = std::fs::metadata(project_root.join(".oj/config.toml"))
+            .and_then(|meta| meta.modified())
+            .ok();
+        let mut limits = self.project_limits.lock();
+        if let Some(limit) = limits.get(&project_root) {
+            if limit.modified == modified {
+                return limit.max_jobs;
+            }
+        }
+        let max_jobs = oj_core::project_max_jobs(&project_root);
+        limits.insert(project_root, ProjectLimit { modified, max_jobs });
+        max_jobs
+    }
+}
+
+/// Whether starting `job` keeps the running jobs within the daemon-wide
+/// limit and the job's project limit.
+///
+/// Standalone agent runs (from `run = { agent = ... }`) take a slot while
+/// they run, but are never queued themselves: they start at once, capped
+/// only by the agent's `max_concurrency`.
+fn has_slot(
+    state: &MaterializedState,
+    job: &Job,
+    max_jobs: Option<u32>,
+    project_max_jobs: Option<u32>,
+) -> bool {
+    // Sub-jobs and parallel branches run on their parent's slot
+    if job.parent_job_id.is_some() {
+        return true;
+    }
+    let running: Vec<&str> = state
+        .jobs
+        .values()
+        .filter(|other| other.id != job.id && occupies_slot(state, other))
+        .map(|other| other.namespace.as_str())
+        .chain(
+            state
+                .agent_runs
+                .values()
+                .filter(|run| !run.is_terminal())
+                .map(|run| run.namespace.as_str()),
+        )
+        .collect();
+    if max_jobs.is_some_and(|max| running.len() >= max as usize) {
+        return false;
+    }
+    let in_namespace = running
+        .iter()
+        .filter(|namespace| **namespace == job.namespace)
+        .count();
+    project_max_jobs.is_none_or(|max| in_namespace < max as usize)
+}
+
+/// Top-level jobs count against the limits from admission until they finish.
+fn occupies_slot(state: &MaterializedState, job: &Job) -> bool {
+    job.parent_job_id.is_none() && !job.is_terminal() && !job.queued && !is_held(state, job)
+}
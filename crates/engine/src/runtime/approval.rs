@@ -0,0 +1,149 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Approval steps (`run = { approval = { prompt = "...", options = [...] } }`)
+//!
+//! Starting the step creates a decision and parks the job in `Waiting`.
+//! Resolving it publishes the choice and field values as
+//! `steps.<name>.outputs.*` and completes the step, so `on_done` branches
+//! can route on the choice. The trailing Cancel option cancels the job.
+
+use super::Runtime;
+use crate::error::RuntimeError;
+use oj_adapters::{AgentAdapter, NotifyAdapter, SessionAdapter};
+use oj_core::{
+    Clock, DecisionOption, DecisionSource, Effect, Event, Job, JobId, OwnerId, StepStatus,
+};
+use oj_runbook::ApprovalDef;
+use std::collections::HashMap;
+use std::path::Path;
+
+impl<S, A, N, C> Runtime<S, A, N, C>
+where
+    S: SessionAdapter,
+    A: AgentAdapter,
+    N: NotifyAdapter,
+    C: Clock,
+{
+    /// Ask for sign-off: create the step's decision and notify.
+    pub(crate) async fn start_approval_step(
+        &self,
+        job: &Job,
+        step: &str,
+        approval: &ApprovalDef,
+        input: &HashMap<String, String>,
+        workspace_path: &Path,
+    ) -> Result<Vec<Event>, RuntimeError> {
+        let mut vars = crate::vars::namespace_vars(input);
+        vars.insert("job_id".to_string(), job.id.clone());
+        vars.insert("name".to_string(), job.name.clone());
+        vars.insert(
+            "workspace".to_string(),
+            workspace_path.display().to_string(),
+        );
+        let prompt = oj_runbook::interpolate(&approval.prompt, &vars);
+
+        let mut options: Vec<DecisionOption> = approval
+            .options
+            .iter()
+            .map(|label| DecisionOption::new(label.clone()))
+            .collect();
+        options.push(DecisionOption::new("Cancel").description("Cancel the job"));
+
+        let decision_id = uuid::Uuid::new_v4().to_string();
+        let job_id = JobId::new(&job.id);
+        let effects = vec![
+            Effect::Emit {
+                event: Event::DecisionCreated {
+                    id: decision_id.clone(),
+                    job_id: job_id.clone(),
+                    agent_id: None,
+                    owner: OwnerId::Job(job_id),
+                    source: DecisionSource::Approval,
+                    context: format!(
+                        "Job \"{}\" is waiting for approval at step \"{}\".\n\n{}",
+                        job.name, step, prompt
+                    ),
+                    options,
+                    created_at_ms: self.clock().epoch_ms(),
+                    namespace: job.namespace.clone(),
+                    step: Some(step.to_string()),
+                    fields: approval.fields.clone(),
+                },
+            },
+            Effect::Notify {
+                title: format!("Approval needed: {}", job.name),
+                message: prompt,
+            },
+        ];
+        self.logger.append(
+            &job.id,
+            step,
+            &format!("waiting for approval (decision {})", decision_id),
+        );
+        Ok(self.executor.execute_all(effects).await?)
+    }
+
+    /// Route a job whose approval step's decision was just resolved.
+    ///
+    /// Decisions that don't gate the job's current approval step (escalations,
+    /// superseded or replayed resolutions) are left to the daemon's mapping.
+    pub(crate) async fn handle_approval_resolved(
+        &self,
+        decision_id: &str,
+    ) -> Result<Vec<Event>, RuntimeError> {
+        let resolved = self.lock_state(|state| {
+            let decision = state.decisions.get(decision_id)?;
+            let step = decision.step.as_ref()?;
+            let OwnerId::Job(job_id) = &decision.owner else {
+                return None;
+            };
+            let job = state.jobs.get(job_id.as_str())?;
+            let waiting = job.step == *step
+                && job.step_status == StepStatus::Waiting(Some(decision_id.to_string()));
+            waiting.then(|| (decision.clone(), job.clone()))
+        });
+        let Some((decision, job)) = resolved else {
+            return Ok(Vec::new());
+        };
+        let Some(chosen) = decision.chosen else {
+            return Ok(Vec::new());
+        };
+        let job_id = JobId::new(&job.id);
+
+        if chosen == decision.options.len() {
+            self.logger
+                .append(&job.id, &job.step, "approval: cancelled");
+            return self.handle_job_cancel(&job_id).await;
+        }
+        let Some(choice) = decision.options.get(chosen - 1) else {
+            return Ok(Vec::new());
+        };
+
+        let mut vars = HashMap::new();
+        vars.insert(
+            format!("steps.{}.outputs.choice", job.step),
+            choice.label.clone(),
+        );
+        for field in &decision.fields {
+            let value = decision.values.get(field).cloned().unwrap_or_default();
+            vars.insert(format!("steps.{}.outputs.{}", job.step, field), value);
+        }
+        self.executor
+            .execute(Effect::Emit {
+                event: Event::JobUpdated {
+                    id: job_id.clone(),
+                    vars,
+                },
+            })
+            .await?;
+        self.logger.append(
+            &job.id,
+            &job.step,
+            &format!("approval: chose {}", choice.label),
+        );
+
+        let job = self.require_job(job_id.as_str())?;
+        self.advance_job(&job).await
+    }
+}
@@ -0,0 +1,282 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Spend limits (`budget { tokens = ..., usd = ... }`) on agents and jobs
+//!
+//! The usage metrics collector reports each agent's cumulative usage as
+//! `agent:usage`. When an agent crosses its own budget, or its job's total
+//! crosses the job's, the agent's `on_budget` fires. `agent:budget_exceeded`
+//! latches the scope so the action fires once, across daemon restarts too.
+//! A `usd` budget can't be checked for a model with no known price; that is
+//! warned about once per agent.
+
+use super::Runtime;
+use crate::error::RuntimeError;
+use crate::monitor::{self, BUDGET_TRIGGER_PREFIX};
+use crate::ActionContext;
+use oj_adapters::{AgentAdapter, NotifyAdapter, SessionAdapter};
+use oj_core::{
+    AgentId, AgentRecord, AgentRun, BudgetScope, Clock, Effect, Event, Job, OwnerId, TokenUsage,
+};
+use oj_runbook::{ActionConfig, AgentAction, AgentDef, BudgetDef};
+
+/// Nudge sent when `on_budget = "nudge"` has no message of its own.
+const WRAP_UP_MESSAGE: &str = "You have used up your budget for this task. \
+    Wrap up now: commit what you have, summarize what is left, and finish.";
+
+/// The agent and whoever owns it, as of the latest usage report.
+enum BudgetOwner {
+    Job {
+        job: Box<Job>,
+        job_usage: (TokenUsage, Option<f64>),
+    },
+    AgentRun(Box<AgentRun>),
+}
+
+/// Whose effects an agent's `on_budget` runs against.
+enum BudgetTarget<'a> {
+    Job(&'a Job),
+    AgentRun(&'a AgentRun),
+}
+
+/// A budget and the spend it covers.
+struct BudgetCheck<'a> {
+    scope: BudgetScope,
+    budget: Option<&'a BudgetDef>,
+    /// `on_budget` already fired for this scope
+    latched: bool,
+    tokens: u64,
+    usd: Option<f64>,
+}
+
+/// First budget in `checks` that the spend crosses, with a description like
+/// "job used 2100000 tokens of 2000000".
+fn first_exceeded(checks: &[BudgetCheck<'_>]) -> Option<(BudgetScope, String)> {
+    checks.iter().filter(|c| !c.latched).find_map(|c| {
+        let overrun = c.budget?.exceeded_by(c.tokens, c.usd)?;
+        Some((c.scope, format!("{} {}", c.scope, overrun)))
+    })
+}
+
+/// The agent's `on_budget`, with a wrap-up message for a bare nudge.
+fn budget_action(agent_def: &AgentDef) -> ActionConfig {
+    let config = &agent_def.on_budget;
+    if *config.action() == AgentAction::Nudge && config.message().is_none() {
+        ActionConfig::with_message(AgentAction::Nudge, WRAP_UP_MESSAGE)
+    } else {
+        config.clone()
+    }
+}
+
+impl<S, A, N, C> Runtime<S, A, N, C>
+where
+    S: SessionAdapter,
+    A: AgentAdapter,
+    N: NotifyAdapter,
+    C: Clock,
+{
+    /// Check an agent's new usage totals against its budgets.
+    pub(crate) async fn handle_agent_usage(
+        &self,
+        agent_id: &AgentId,
+    ) -> Result<Vec<Event>, RuntimeError> {
+        let found = self.lock_state(|state| {
+            let record = state.agents.get(agent_id.as_str())?.clone();
+            let owner = match &record.owner {
+                OwnerId::Job(job_id) => BudgetOwner::Job {
+                    job: Box::new(state.jobs.get(job_id.as_str())?.clone()),
+                    job_usage: state.job_usage(job_id.as_str()),
+                },
+                OwnerId::AgentRun(run_id) => {
+                    BudgetOwner::AgentRun(Box::new(state.agent_runs.get(run_id.as_str())?.clone()))
+                }
+            };
+            Some((record, owner))
+        });
+        let Some((record, owner)) = found else {
+            return Ok(Vec::new());
+        };
+
+        match owner {
+            BudgetOwner::Job { job, job_usage } => {
+                self.check_job_agent_budget(&record, &job, job_usage).await
+            }
+            BudgetOwner::AgentRun(agent_run) => {
+                self.check_agent_run_budget(&record, &agent_run).await
+            }
+        }
+    }
+
+    async fn check_job_agent_budget(
+        &self,
+        record: &AgentRecord,
+        job: &Job,
+        (job_tokens, job_usd): (TokenUsage, Option<f64>),
+    ) -> Result<Vec<Event>, RuntimeError> {
+        // Only the agent running the current step can be nudged or stopped;
+        // a job overrun reported late is picked up by the next agent
+        let current_agent = job
+            .step_history
+            .iter()
+            .rfind(|r| r.name == job.step)
+            .and_then(|r| r.agent_id.as_deref());
+        if job.is_terminal() || current_agent != Some(record.agent_id.as_str()) {
+            return Ok(Vec::new());
+        }
+
+        let runbook = self.cached_runbook(&job.runbook_hash)?;
+        let Some(agent_def) = runbook.get_agent(&record.agent_name) else {
+            return Ok(Vec::new());
+        };
+        let job_budget = runbook.get_job(&job.kind).and_then(|d| d.budget.as_ref());
+        if let Some(warning) =
+            self.warn_unpriced_usd_budget(record, &[agent_def.budget.as_ref(), job_budget])
+        {
+            self.logger.append(&job.id, &job.step, &warning);
+        }
+        let Some((scope, reason)) = first_exceeded(&[
+            BudgetCheck {
+                scope: BudgetScope::Agent,
+                budget: agent_def.budget.as_ref(),
+                latched: record.budget_exceeded,
+                tokens: record.usage.budget_tokens(),
+                usd: record.cost_usd(),
+            },
+            BudgetCheck {
+                scope: BudgetScope::Job,
+                budget: job_budget,
+                latched: job.budget_exceeded,
+                tokens: job_tokens.budget_tokens(),
+                usd: job_usd,
+            },
+        ]) else {
+            return Ok(Vec::new());
+        };
+
+        tracing::warn!(job_id = %job.id, agent_id = %record.agent_id, %reason, "budget exceeded");
+        self.logger
+            .append(&job.id, &job.step, &format!("budget exceeded: {}", reason));
+        self.fire_on_budget(record, agent_def, scope, reason, BudgetTarget::Job(job))
+            .await
+    }
+
+    async fn check_agent_run_budget(
+        &self,
+        record: &AgentRecord,
+        agent_run: &AgentRun,
+    ) -> Result<Vec<Event>, RuntimeError> {
+        if agent_run.is_terminal() || agent_run.agent_id.as_deref() != Some(&record.agent_id) {
+            return Ok(Vec::new());
+        }
+
+        let runbook = self.cached_runbook(&agent_run.runbook_hash)?;
+        let Some(agent_def) = runbook.get_agent(&record.agent_name) else {
+            return Ok(Vec::new());
+        };
+        self.warn_unpriced_usd_budget(record, &[agent_def.budget.as_ref()]);
+        let Some((scope, reason)) = first_exceeded(&[BudgetCheck {
+            scope: BudgetScope::Agent,
+            budget: agent_def.budget.as_ref(),
+            latched: record.budget_exceeded,
+            tokens: record.usage.budget_tokens(),
+            usd: record.cost_usd(),
+        }]) else {
+            return Ok(Vec::new());
+        };
+
+        tracing::warn!(
+            agent_run_id = %agent_run.id,
+            agent_id = %record.agent_id,
+            %reason,
+            "budget exceeded"
+        );
+        self.fire_on_budget(
+            record,
+            agent_def,
+            scope,
+            reason,
+            BudgetTarget::AgentRun(agent_run),
+        )
+        .await
+    }
+
+    /// Latch the crossed budget and run the agent's `on_budget` against the
+    /// job or agent run it belongs to.
+    async fn fire_on_budget(
+        &self,
+        record: &AgentRecord,
+        agent_def: &AgentDef,
+        scope: BudgetScope,
+        reason: String,
+        target: BudgetTarget<'_>,
+    ) -> Result<Vec<Event>, RuntimeError> {
+        let action_config = budget_action(agent_def);
+        let trigger = format!("{}{}", BUDGET_TRIGGER_PREFIX, reason);
+        let mut result = self
+            .executor
+            .execute_all(vec![Effect::Emit {
+                event: Event::AgentBudgetExceeded {
+                    agent_id: AgentId::new(&record.agent_id),
+                    owner: record.owner.clone(),
+                    scope,
+                    reason,
+                },
+            }])
+            .await?;
+
+        let ctx = ActionContext {
+            agent_def,
+            action_config: &action_config,
+            trigger: &trigger,
+            chain_pos: 0,
+            question_data: None,
+            assistant_context: None,
+        };
+        result.extend(match target {
+            BudgetTarget::Job(job) => {
+                self.execute_action_effects(
+                    job,
+                    agent_def,
+                    monitor::build_action_effects(&ctx, job)?,
+                )
+                .await?
+            }
+            BudgetTarget::AgentRun(agent_run) => {
+                self.execute_standalone_action_effects(
+                    agent_run,
+                    agent_def,
+                    monitor::build_action_effects_for_agent_run(&ctx, agent_run)?,
+                )
+                .await?
+            }
+        });
+        Ok(result)
+    }
+
+    /// Warn, once per agent, when a `usd` budget covers an agent whose model
+    /// has no known price: its spend can't be estimated, so the limit can't
+    /// be enforced. Returns the warning the first time.
+    fn warn_unpriced_usd_budget(
+        &self,
+        record: &AgentRecord,
+        budgets: &[Option<&BudgetDef>],
+    ) -> Option<String> {
+        let model = record.model.as_deref()?;
+        if record.cost_usd().is_some() || !budgets.iter().flatten().any(|b| b.usd.is_some()) {
+            return None;
+        }
+        if !self
+            .unpriced_budget_agents
+            .lock()
+            .insert(record.agent_id.clone())
+        {
+            return None;
+        }
+        let warning = format!(
+            "no price known for model '{}'; usd budget not enforced for agent {}",
+            model, record.agent_name
+        );
+        tracing::warn!(agent_id = %record.agent_id, %model, "usd budget not enforced: unpriced model");
+        Some(warning)
+    }
+}
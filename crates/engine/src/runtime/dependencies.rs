@@ -0,0 +1,167 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Holding jobs until the jobs they depend on (`--after`, `depends_on`) finish
+
+use super::Runtime;
+use crate::error::RuntimeError;
+use oj_adapters::{AgentAdapter, NotifyAdapter, SessionAdapter};
+use oj_core::{Clock, DependencyState, Effect, Event, Job, JobDependency, JobId};
+use oj_runbook::DependencyFailure;
+use oj_storage::MaterializedState;
+use std::collections::HashMap;
+
+impl<S, A, N, C> Runtime<S, A, N, C>
+where
+    S: SessionAdapter,
+    A: AgentAdapter,
+    N: NotifyAdapter,
+    C: Clock,
+{
+    /// Interpolate a job definition's `depends_on` entries into dependencies.
+    ///
+    /// Entries may expand to several comma-separated specs or to nothing.
+    /// Job ID prefixes are resolved to full IDs where they match a known job.
+    pub(crate) fn resolve_depends_on(
+        &self,
+        depends_on: &[String],
+        vars: &HashMap<String, String>,
+    ) -> Result<Vec<JobDependency>, String> {
+        let lookup: HashMap<String, String> = vars
+            .iter()
+            .flat_map(|(k, v)| vec![(k.clone(), v.clone()), (format!("var.{}", k), v.clone())])
+            .collect();
+        let mut deps = Vec::new();
+        for entry in depends_on {
+            let expanded = oj_runbook::interpolate(entry, &lookup);
+            for spec in expanded.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+                let mut dep: JobDependency = spec.parse()?;
+                if let Some(id) = self.lock_state(|s| s.get_job(&dep.job_id).map(|j| j.id.clone()))
+                {
+                    dep.job_id = id;
+                }
+                deps.push(dep);
+            }
+        }
+        Ok(deps)
+    }
+
+    /// Check a job's dependencies before its first step starts.
+    ///
+    /// Returns whether the step may start. Otherwise the job is either held
+    /// (`JobBlocked`) until the outstanding jobs finish, or — when a
+    /// dependency can never be met — failed or cancelled according to the
+    /// job's `on_dependency_fail` policy.
+    pub(crate) async fn check_dependencies(
+        &self,
+        job: &Job,
+        result_events: &mut Vec<Event>,
+    ) -> Result<bool, RuntimeError> {
+        match self.lock_state(|state| unmet_dependencies(state, job)) {
+            Ok(waiting_on) if waiting_on.is_empty() => Ok(true),
+            Ok(waiting_on) => {
+                self.logger.append(
+                    &job.id,
+                    &job.step,
+                    &format!("blocked after {}", waiting_on.join(", ")),
+                );
+                tracing::info!(job_id = %job.id, ?waiting_on, "job blocked on dependencies");
+                let event = Event::JobBlocked {
+                    id: JobId::new(&job.id),
+                    waiting_on,
+                };
+                result_events.extend(self.executor.execute(Effect::Emit { event }).await?);
+                Ok(false)
+            }
+            Err(reason) => {
+                result_events.extend(self.dependency_failed(job, &reason).await?);
+                Ok(false)
+            }
+        }
+    }
+
+    /// Re-check jobs held on dependencies, starting the ones that are free.
+    ///
+    /// `finished` narrows the check to dependents of a job that just reached
+    /// a terminal step; `None` re-checks every held job (daemon startup).
+    pub async fn wake_dependents(
+        &self,
+        finished: Option<&JobId>,
+    ) -> Result<Vec<Event>, RuntimeError> {
+        let mut held: Vec<Job> = self.lock_state(|state| {
+            state
+                .jobs
+                .values()
+                .filter(|job| is_held(state, job))
+                .filter(|job| {
+                    finished.is_none_or(|id| job.after.iter().any(|d| d.job_id == id.as_str()))
+                })
+                .cloned()
+                .collect()
+        });
+        held.sort_by(|a, b| (created_at_ms(a), &a.id).cmp(&(created_at_ms(b), &b.id)));
+
+        let mut result_events = Vec::new();
+        for job in held {
+            if self.check_dependencies(&job, &mut result_events).await? {
+                self.logger.append(&job.id, &job.step, "dependencies met");
+                if !self.check_admission(&job, &mut result_events).await? {
+                    continue;
+                }
+                let execution_dir = self.execution_dir(&job);
+                result_events.extend(
+                    self.start_step(&JobId::new(&job.id), &job.step, &job.vars, &execution_dir)
+                        .await?,
+                );
+            }
+        }
+        Ok(result_events)
+    }
+
+    /// Apply the job's `on_dependency_fail` policy.
+    async fn dependency_failed(&self, job: &Job, reason: &str) -> Result<Vec<Event>, RuntimeError> {
+        let policy = self
+            .cached_runbook(&job.runbook_hash)
+            .ok()
+            .and_then(|runbook| runbook.get_job(&job.kind).map(|def| def.on_dependency_fail))
+            .unwrap_or_default();
+        tracing::info!(job_id = %job.id, reason, ?policy, "job dependency can't be met");
+        match policy {
+            DependencyFailure::Fail => self.fail_job_terminal(job, reason).await,
+            DependencyFailure::Cancel => {
+                self.logger.append(&job.id, &job.step, reason);
+                self.cancel_job(job).await
+            }
+        }
+    }
+}
+
+/// IDs of the dependencies still running, or why one can never be met.
+fn unmet_dependencies(state: &MaterializedState, job: &Job) -> Result<Vec<String>, String> {
+    let mut waiting_on = Vec::new();
+    for dep in &job.after {
+        match state.dependency_state(dep) {
+            DependencyState::Met => {}
+            DependencyState::Pending => waiting_on.push(dep.job_id.clone()),
+            DependencyState::Broken(reason) => return Err(reason),
+        }
+    }
+    Ok(waiting_on)
+}
+
+/// Whether `job` is being held before its first step for its dependencies
+/// (as opposed to blocked on a step's locks or in the admission queue).
+pub(super) fn is_held(state: &MaterializedState, job: &Job) -> bool {
+    !job.after.is_empty()
+        && !job.is_terminal()
+        && !job.queued
+        && job.step_status.is_blocked()
+        && !state
+            .locks
+            .values()
+            .any(|r| r.waiters.iter().any(|c| c.job_id == job.id))
+}
+
+fn created_at_ms(job: &Job) -> u64 {
+    job.step_history.first().map_or(0, |r| r.started_at_ms)
+}
@@ -8,7 +8,7 @@ use super::CreateJobParams;
 use crate::error::RuntimeError;
 use crate::runtime::agent_run::SpawnAgentParams;
 use oj_adapters::{AgentAdapter, NotifyAdapter, SessionAdapter};
-use oj_core::{AgentRunId, Clock, Effect, Event, JobId};
+use oj_core::{AgentRunId, Clock, Effect, Event, JobDependency, JobId};
 use oj_runbook::RunDirective;
 use sha2::{Digest, Sha256};
 use std::collections::HashMap;
@@ -23,6 +23,8 @@ pub(crate) struct HandleCommandParams<'a> {
     pub namespace: &'a str,
     pub command: &'a str,
     pub args: &'a HashMap<String, String>,
+    pub after: &'a [JobDependency],
+    pub priority: i32,
 }
 
 impl<S, A, N, C> Runtime<S, A, N, C>
@@ -44,6 +46,8 @@ where
             namespace,
             command,
             args,
+            after,
+            priority,
         } = params;
 
         // Load runbook from project
@@ -100,6 +104,10 @@ where
                     runbook,
                     namespace: namespace.to_string(),
                     cron_name: None,
+                    parent: None,
+                    after: after.to_vec(),
+                    priority,
+                    project_root: Some(project_root.to_path_buf()),
                 })
                 .await
             }
@@ -147,6 +155,9 @@ where
                         created_at_epoch_ms: self.clock().epoch_ms(),
                         namespace: namespace.to_string(),
                         cron_name: None,
+                        after: Vec::new(),
+                        priority: 0,
+                        project_root: None,
                     },
                 });
 
@@ -205,6 +216,7 @@ where
                         } else {
                             HashMap::from([("OJ_NAMESPACE".to_string(), namespace.to_string())])
                         },
+                        timeout: None,
                     },
                 ];
                 result_events.extend(self.executor.execute_all(shell_effects).await?);
@@ -298,6 +310,16 @@ where
 
                 Ok(result_events)
             }
+            RunDirective::Parallel { parallel } => Err(Self::invalid_directive(
+                &format!("command {command}"),
+                "parallel",
+                &parallel.join(", "),
+            )),
+            RunDirective::Approval { approval } => Err(Self::invalid_directive(
+                &format!("command {command}"),
+                "approval",
+                &approval.prompt,
+            )),
         }
     }
 }
@@ -8,11 +8,18 @@ use super::CreateJobParams;
 use crate::error::RuntimeError;
 use crate::log_paths::cron_log_path;
 use crate::runtime::agent_run::SpawnAgentParams;
-use crate::time_fmt::format_utc_now;
+use crate::time_fmt::{format_utc, format_utc_now};
 use oj_adapters::{AgentAdapter, NotifyAdapter, SessionAdapter};
-use oj_core::{scoped_name, Clock, Effect, Event, IdGen, JobId, ShortId, TimerId, UuidIdGen};
+use oj_core::{
+    scoped_name, Clock, CronSchedule, Effect, Event, IdGen, JobId, ShortId, TimerId, UuidIdGen,
+};
+use oj_runbook::CatchUp;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Most missed runs counted, and started by `catch_up = "all"`, on resume.
+const MAX_CATCH_UP_RUNS: u64 = 100;
 
 /// What a cron targets when it fires.
 #[derive(Debug, Clone)]
@@ -43,16 +50,139 @@ impl CronRunTarget {
     }
 }
 
+/// When a cron fires.
+#[derive(Debug, Clone)]
+pub(crate) enum CronTiming {
+    /// Every `interval`, counted from when the cron started
+    Interval {
+        interval: String,
+        duration: Duration,
+    },
+    /// At each wall-clock time matching a cron expression
+    Schedule {
+        expr: String,
+        timezone: Option<String>,
+        schedule: CronSchedule,
+    },
+}
+
+impl CronTiming {
+    pub(crate) fn parse(
+        interval: &str,
+        schedule: Option<&str>,
+        timezone: Option<&str>,
+    ) -> Result<Self, RuntimeError> {
+        match schedule {
+            Some(expr) => Ok(CronTiming::Schedule {
+                expr: expr.to_string(),
+                timezone: timezone.map(str::to_string),
+                schedule: CronSchedule::parse(expr, timezone)
+                    .map_err(|e| RuntimeError::InvalidFormat(format!("cron {}", e)))?,
+            }),
+            None => Ok(CronTiming::Interval {
+                interval: interval.to_string(),
+                duration: crate::monitor::parse_duration(interval).map_err(|e| {
+                    RuntimeError::InvalidFormat(format!(
+                        "invalid cron interval '{}': {}",
+                        interval, e
+                    ))
+                })?,
+            }),
+        }
+    }
+
+    /// How long from `now_ms` until the next tick, if there is one. A
+    /// schedule's next tick is the first match after `after_ms`.
+    fn next_delay(&self, now_ms: u64, after_ms: u64) -> Option<Duration> {
+        match self {
+            CronTiming::Interval { duration, .. } => Some(*duration),
+            CronTiming::Schedule { schedule, .. } => schedule
+                .next_after(after_ms)
+                .map(|next| Duration::from_millis(next.saturating_sub(now_ms))),
+        }
+    }
+
+    /// How long from `now_ms` until the first tick of a cron resumed after
+    /// last firing (or starting) at `since_ms`. Interval crons keep to their
+    /// `since_ms + k * interval` times instead of counting from the restart.
+    fn resume_delay(&self, since_ms: u64, now_ms: u64) -> Option<Duration> {
+        match self {
+            CronTiming::Interval { duration, .. } => {
+                let interval_ms = duration.as_millis() as u64;
+                if interval_ms == 0 || since_ms > now_ms {
+                    return Some(
+                        *duration + Duration::from_millis(since_ms.saturating_sub(now_ms)),
+                    );
+                }
+                let elapsed = (now_ms - since_ms) % interval_ms;
+                Some(Duration::from_millis(interval_ms - elapsed))
+            }
+            CronTiming::Schedule { .. } => self.next_delay(now_ms, now_ms),
+        }
+    }
+
+    /// Number of ticks that fell due after `since_ms` up to `now_ms`,
+    /// capped at `MAX_CATCH_UP_RUNS`.
+    fn missed_runs(&self, since_ms: u64, now_ms: u64) -> u64 {
+        match self {
+            CronTiming::Interval { duration, .. } => {
+                let interval_ms = duration.as_millis() as u64;
+                if interval_ms == 0 {
+                    return 0;
+                }
+                (now_ms.saturating_sub(since_ms) / interval_ms).min(MAX_CATCH_UP_RUNS)
+            }
+            CronTiming::Schedule { schedule, .. } => {
+                let mut missed = 0;
+                let mut at = since_ms;
+                while missed < MAX_CATCH_UP_RUNS {
+                    match schedule.next_after(at) {
+                        Some(next) if next <= now_ms => {
+                            missed += 1;
+                            at = next;
+                        }
+                        _ => break,
+                    }
+                }
+                missed
+            }
+        }
+    }
+}
+
+/// "3 missed runs", or "100+ missed runs" when the count was capped.
+fn describe_missed(missed: u64) -> String {
+    let plus = if missed >= MAX_CATCH_UP_RUNS { "+" } else { "" };
+    let noun = if missed == 1 { "run" } else { "runs" };
+    format!("{}{} missed {}", missed, plus, noun)
+}
+
+impl std::fmt::Display for CronTiming {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CronTiming::Interval { interval, .. } => write!(f, "{}", interval),
+            CronTiming::Schedule {
+                expr,
+                timezone: Some(tz),
+                ..
+            } => write!(f, "{} ({})", expr, tz),
+            CronTiming::Schedule { expr, .. } => write!(f, "{}", expr),
+        }
+    }
+}
+
 /// In-memory state for a running cron
 pub(crate) struct CronState {
     pub project_root: PathBuf,
     pub runbook_hash: String,
-    pub interval: String,
+    pub timing: CronTiming,
     pub run_target: CronRunTarget,
     pub status: CronStatus,
     pub namespace: String,
     /// Maximum concurrent jobs this cron can have running. Default 1.
     pub concurrency: u32,
+    /// Epoch ms the armed timer is meant to fire at
+    pub next_tick_ms: Option<u64>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -88,8 +218,22 @@ pub(crate) struct CronStartedParams<'a> {
     pub project_root: &'a Path,
     pub runbook_hash: &'a str,
     pub interval: &'a str,
+    pub schedule: Option<&'a str>,
+    pub timezone: Option<&'a str>,
     pub run_target: &'a str,
     pub namespace: &'a str,
+    pub resumed_from_ms: Option<u64>,
+}
+
+/// A running cron, as needed to start one of its runs.
+#[derive(Clone, Copy)]
+struct CronRun<'a> {
+    cron_name: &'a str,
+    project_root: &'a Path,
+    runbook_hash: &'a str,
+    run_target: &'a CronRunTarget,
+    namespace: &'a str,
+    concurrency: u32,
 }
 
 /// Parameters for handling a one-shot cron execution.
@@ -122,12 +266,13 @@ where
             project_root,
             runbook_hash,
             interval,
+            schedule,
+            timezone,
             run_target: run_target_str,
             namespace,
+            resumed_from_ms,
         } = params;
-        let duration = crate::monitor::parse_duration(interval).map_err(|e| {
-            RuntimeError::InvalidFormat(format!("invalid cron interval '{}': {}", interval, e))
-        })?;
+        let timing = CronTiming::parse(interval, schedule, timezone)?;
 
         let run_target = CronRunTarget::from_run_target_str(run_target_str);
 
@@ -138,15 +283,21 @@ where
             .and_then(|rb| rb.get_cron(cron_name).map(|c| c.concurrency.unwrap_or(1)))
             .unwrap_or(1);
 
+        let started = match &timing {
+            CronTiming::Interval { .. } => format!("interval={}", timing),
+            CronTiming::Schedule { .. } => format!("schedule={}", timing),
+        };
+
         // Store cron state
         let state = CronState {
             project_root: project_root.to_path_buf(),
             runbook_hash: runbook_hash.to_string(),
-            interval: interval.to_string(),
+            timing: timing.clone(),
             run_target: run_target.clone(),
             status: CronStatus::Running,
             namespace: namespace.to_string(),
             concurrency,
+            next_tick_ms: None,
         };
 
         {
@@ -154,27 +305,152 @@ where
             crons.insert(cron_name.to_string(), state);
         }
 
-        // Set the first interval timer
-        let timer_id = TimerId::cron(cron_name, namespace);
-        self.executor
-            .execute(Effect::SetTimer {
-                id: timer_id,
-                duration,
-            })
+        // Set the first timer
+        self.schedule_cron_tick(cron_name, namespace, &timing, resumed_from_ms)
             .await?;
 
         append_cron_log(
             self.logger.log_dir(),
             cron_name,
             namespace,
-            &format!(
-                "started (interval={}, {})",
-                interval,
-                run_target.display_name()
-            ),
+            &format!("started ({}, {})", started, run_target.display_name()),
         );
 
-        Ok(vec![])
+        match resumed_from_ms {
+            Some(since_ms) => self.catch_up_cron(cron_name, since_ms).await,
+            None => Ok(vec![]),
+        }
+    }
+
+    /// Apply the cron's `catch_up` policy to ticks missed between `since_ms`
+    /// (its last fire before the daemon went down) and now.
+    async fn catch_up_cron(
+        &self,
+        cron_name: &str,
+        since_ms: u64,
+    ) -> Result<Vec<Event>, RuntimeError> {
+        // Pick up a policy edited while the daemon was down. A runbook that
+        // fails to load has already been logged; carry on with the cached one.
+        if let Ok(Some(loaded_event)) = self.refresh_cron_runbook(cron_name) {
+            self.executor
+                .execute_all(vec![Effect::Emit {
+                    event: loaded_event,
+                }])
+                .await?;
+        }
+
+        let Some((project_root, runbook_hash, run_target, timing, namespace, concurrency)) = ({
+            let crons = self.cron_states.lock();
+            crons.get(cron_name).map(|s| {
+                (
+                    s.project_root.clone(),
+                    s.runbook_hash.clone(),
+                    s.run_target.clone(),
+                    s.timing.clone(),
+                    s.namespace.clone(),
+                    s.concurrency,
+                )
+            })
+        }) else {
+            return Ok(vec![]);
+        };
+
+        let missed = timing.missed_runs(since_ms, self.clock().epoch_ms());
+        if missed == 0 {
+            return Ok(vec![]);
+        }
+        let policy = self
+            .cached_runbook(&runbook_hash)
+            .ok()
+            .and_then(|rb| rb.get_cron(cron_name).map(|c| c.catch_up))
+            .unwrap_or_default();
+        let log = |message: String| {
+            append_cron_log(self.logger.log_dir(), cron_name, &namespace, &message)
+        };
+        let missed_since = format!("{} since {}", describe_missed(missed), format_utc(since_ms));
+
+        let runs = match policy {
+            CatchUp::None => {
+                log(format!(
+                    "catch-up: skipped {} (catch_up = none)",
+                    missed_since
+                ));
+                return Ok(vec![]);
+            }
+            CatchUp::Once => {
+                log(format!("catch-up: {}, running once", missed_since));
+                1
+            }
+            CatchUp::All => {
+                log(format!("catch-up: {}, running each", missed_since));
+                missed
+            }
+        };
+
+        let run = CronRun {
+            cron_name,
+            project_root: &project_root,
+            runbook_hash: &runbook_hash,
+            run_target: &run_target,
+            namespace: &namespace,
+            concurrency,
+        };
+        let mut result_events = Vec::new();
+        for started in 0..runs {
+            match self.fire_cron_run(&run, "catch-up").await? {
+                Some(events) => result_events.extend(events),
+                None => {
+                    log(format!(
+                        "catch-up: skipped the remaining {} of {}",
+                        runs - started,
+                        describe_missed(missed)
+                    ));
+                    break;
+                }
+            }
+        }
+        Ok(result_events)
+    }
+
+    /// Arm the cron's timer for its next tick. `resumed_from_ms` is set for
+    /// a cron resumed after a restart, to keep it on its original times.
+    async fn schedule_cron_tick(
+        &self,
+        cron_name: &str,
+        namespace: &str,
+        timing: &CronTiming,
+        resumed_from_ms: Option<u64>,
+    ) -> Result<(), RuntimeError> {
+        let now_ms = self.clock().epoch_ms();
+        // A timer can fire a little before its wall-clock target; counting
+        // from the target keeps an early tick from landing on it again
+        let previous_tick_ms = {
+            let crons = self.cron_states.lock();
+            crons.get(cron_name).and_then(|s| s.next_tick_ms)
+        };
+        let delay = match resumed_from_ms {
+            Some(since_ms) => timing.resume_delay(since_ms, now_ms),
+            None => timing.next_delay(now_ms, previous_tick_ms.map_or(now_ms, |t| t.max(now_ms))),
+        };
+        let Some(duration) = delay else {
+            append_cron_log(
+                self.logger.log_dir(),
+                cron_name,
+                namespace,
+                &format!("schedule {} has no upcoming time", timing),
+            );
+            return Ok(());
+        };
+        if let Some(state) = self.cron_states.lock().get_mut(cron_name) {
+            state.next_tick_ms = Some(now_ms + duration.as_millis() as u64);
+        }
+        self.executor
+            .execute(Effect::SetTimer {
+                id: TimerId::cron(cron_name, namespace),
+                duration,
+            })
+            .await?;
+        Ok(())
     }
 
     pub(crate) async fn handle_cron_stopped(
@@ -288,6 +564,7 @@ where
                             job_id: JobId::new(""),
                             agent_run_id: Some(ar_id.as_str().to_string()),
                             namespace: namespace.to_string(),
+                            fired_at_ms: self.clock().epoch_ms(),
                         },
                     }])
                     .await?,
@@ -309,6 +586,10 @@ where
                     runbook,
                     namespace: namespace.to_string(),
                     cron_name: Some(cron_name.to_string()),
+                    parent: None,
+                    after: Vec::new(),
+                    priority: 0,
+                    project_root: Some(project_root.to_path_buf()),
                 })
                 .await?,
             );
@@ -322,6 +603,7 @@ where
                             job_id: job_id.clone(),
                             agent_run_id: None,
                             namespace: namespace.to_string(),
+                            fired_at_ms: self.clock().epoch_ms(),
                         },
                     }])
                     .await?,
@@ -341,14 +623,14 @@ where
         let cron_name = rest.rsplit('/').next().unwrap_or(rest);
         let timer_namespace = rest.strip_suffix(&format!("/{}", cron_name)).unwrap_or("");
 
-        let (project_root, runbook_hash, run_target, interval, namespace, concurrency) = {
+        let (project_root, runbook_hash, run_target, timing, namespace, concurrency) = {
             let crons = self.cron_states.lock();
             match crons.get(cron_name) {
                 Some(s) if s.status == CronStatus::Running => (
                     s.project_root.clone(),
                     s.runbook_hash.clone(),
                     s.run_target.clone(),
-                    s.interval.clone(),
+                    s.timing.clone(),
                     s.namespace.clone(),
                     s.concurrency,
                 ),
@@ -385,45 +667,69 @@ where
                 .unwrap_or((runbook_hash, concurrency))
         };
 
-        let runbook = self.cached_runbook(&runbook_hash)?;
+        let result_events = self
+            .fire_cron_run(
+                &CronRun {
+                    cron_name,
+                    project_root: &project_root,
+                    runbook_hash: &runbook_hash,
+                    run_target: &run_target,
+                    namespace: &namespace,
+                    concurrency,
+                },
+                "tick",
+            )
+            .await?
+            .unwrap_or_default();
+
+        // Reschedule timer for the next tick
+        self.schedule_cron_tick(cron_name, &namespace, &timing, None)
+            .await?;
+
+        Ok(result_events)
+    }
+
+    /// Start one run of a cron's job or agent. `label` prefixes the cron log
+    /// line ("tick", "catch-up"). Returns None, after logging, when the run
+    /// is skipped because the target is at its concurrency limit.
+    async fn fire_cron_run(
+        &self,
+        run: &CronRun<'_>,
+        label: &str,
+    ) -> Result<Option<Vec<Event>>, RuntimeError> {
+        let CronRun {
+            cron_name,
+            project_root,
+            runbook_hash,
+            run_target,
+            namespace,
+            concurrency,
+        } = *run;
+        let runbook = self.cached_runbook(runbook_hash)?;
 
         let mut result_events = Vec::new();
 
-        match &run_target {
+        match run_target {
             CronRunTarget::Job(job_name) => {
                 // Check concurrency before spawning
-                let active = self.count_active_cron_jobs(cron_name, &namespace);
+                let active = self.count_active_cron_jobs(cron_name, namespace);
                 if active >= concurrency as usize {
                     append_cron_log(
                         self.logger.log_dir(),
                         cron_name,
-                        &namespace,
+                        namespace,
                         &format!(
                             "skip: job '{}' at max concurrency ({}/{})",
                             job_name, active, concurrency
                         ),
                     );
-                    // Reschedule timer but don't spawn
-                    let duration = crate::monitor::parse_duration(&interval).map_err(|e| {
-                        RuntimeError::InvalidFormat(format!(
-                            "invalid cron interval '{}': {}",
-                            interval, e
-                        ))
-                    })?;
-                    let timer_id = TimerId::cron(cron_name, &namespace);
-                    self.executor
-                        .execute(Effect::SetTimer {
-                            id: timer_id,
-                            duration,
-                        })
-                        .await?;
-                    return Ok(result_events);
+                    return Ok(None);
                 }
 
                 // Generate job ID
                 let job_id = JobId::new(UuidIdGen.next());
                 let display_name =
-                    oj_runbook::job_display_name(job_name, job_id.short(8), &namespace);
+                    oj_runbook::job_display_name(job_name, job_id.short(8), namespace);
 
                 // Set invoke.dir to project root so runbooks can reference ${invoke.dir}
                 let mut vars = HashMap::new();
@@ -436,11 +742,15 @@ where
                         job_name: display_name,
                         job_kind: job_name.clone(),
                         vars,
-                        runbook_hash: runbook_hash.clone(),
+                        runbook_hash: runbook_hash.to_string(),
                         runbook_json: None,
                         runbook,
-                        namespace: namespace.clone(),
+                        namespace: namespace.to_string(),
                         cron_name: Some(cron_name.to_string()),
+                        parent: None,
+                        after: Vec::new(),
+                        priority: 0,
+                        project_root: Some(project_root.to_path_buf()),
                     })
                     .await?,
                 );
@@ -448,8 +758,13 @@ where
                 append_cron_log(
                     self.logger.log_dir(),
                     cron_name,
-                    &namespace,
-                    &format!("tick: triggered job {} ({})", job_name, job_id.short(8)),
+                    namespace,
+                    &format!(
+                        "{}: triggered job {} ({})",
+                        label,
+                        job_name,
+                        job_id.short(8)
+                    ),
                 );
 
                 // Emit CronFired tracking event
@@ -460,7 +775,8 @@ where
                                 cron_name: cron_name.to_string(),
                                 job_id,
                                 agent_run_id: None,
-                                namespace: namespace.clone(),
+                                namespace: namespace.to_string(),
+                                fired_at_ms: self.clock().epoch_ms(),
                             },
                         }])
                         .await?,
@@ -474,32 +790,18 @@ where
 
                 // Check max_concurrency before spawning
                 if let Some(max) = agent_def.max_concurrency {
-                    let running = self.count_running_agents(agent_name, &namespace);
+                    let running = self.count_running_agents(agent_name, namespace);
                     if running >= max as usize {
                         append_cron_log(
                             self.logger.log_dir(),
                             cron_name,
-                            &namespace,
+                            namespace,
                             &format!(
                                 "skip: agent '{}' at max concurrency ({}/{})",
                                 agent_name, running, max
                             ),
                         );
-                        // Reschedule timer but don't spawn
-                        let duration = crate::monitor::parse_duration(&interval).map_err(|e| {
-                            RuntimeError::InvalidFormat(format!(
-                                "invalid cron interval '{}': {}",
-                                interval, e
-                            ))
-                        })?;
-                        let timer_id = TimerId::cron(cron_name, &namespace);
-                        self.executor
-                            .execute(Effect::SetTimer {
-                                id: timer_id,
-                                duration,
-                            })
-                            .await?;
-                        return Ok(result_events);
+                        return Ok(None);
                     }
                 }
 
@@ -511,9 +813,9 @@ where
                         id: agent_run_id.clone(),
                         agent_name: agent_name.clone(),
                         command_name: format!("cron:{}", cron_name),
-                        namespace: namespace.clone(),
-                        cwd: project_root.clone(),
-                        runbook_hash: runbook_hash.clone(),
+                        namespace: namespace.to_string(),
+                        cwd: project_root.to_path_buf(),
+                        runbook_hash: runbook_hash.to_string(),
                         vars: HashMap::new(),
                         created_at_epoch_ms: self.clock().epoch_ms(),
                     },
@@ -527,8 +829,8 @@ where
                         agent_def: &agent_def,
                         agent_name,
                         input: &HashMap::new(),
-                        cwd: &project_root,
-                        namespace: &namespace,
+                        cwd: project_root,
+                        namespace,
                         resume_session_id: None,
                     })
                     .await?;
@@ -537,9 +839,10 @@ where
                 append_cron_log(
                     self.logger.log_dir(),
                     cron_name,
-                    &namespace,
+                    namespace,
                     &format!(
-                        "tick: triggered agent {} ({})",
+                        "{}: triggered agent {} ({})",
+                        label,
                         agent_name,
                         agent_run_id.short(8)
                     ),
@@ -553,7 +856,8 @@ where
                                 cron_name: cron_name.to_string(),
                                 job_id: JobId::new(""),
                                 agent_run_id: Some(agent_run_id.as_str().to_string()),
-                                namespace: namespace.clone(),
+                                namespace: namespace.to_string(),
+                                fired_at_ms: self.clock().epoch_ms(),
                             },
                         }])
                         .await?,
@@ -561,19 +865,7 @@ where
             }
         }
 
-        // Reschedule timer for next interval
-        let duration = crate::monitor::parse_duration(&interval).map_err(|e| {
-            RuntimeError::InvalidFormat(format!("invalid cron interval '{}': {}", interval, e))
-        })?;
-        let timer_id = TimerId::cron(cron_name, &namespace);
-        self.executor
-            .execute(Effect::SetTimer {
-                id: timer_id,
-                duration,
-            })
-            .await?;
-
-        Ok(result_events)
+        Ok(Some(result_events))
     }
 
     /// Re-read the runbook from disk for a running cron.
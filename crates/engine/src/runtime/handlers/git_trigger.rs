@@ -0,0 +1,362 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Git ref trigger event handling
+
+use super::super::Runtime;
+use super::watch::hash_runbook;
+use super::CreateJobParams;
+use crate::error::RuntimeError;
+use crate::log_paths::git_trigger_log_path;
+use crate::time_fmt::format_utc_now;
+use oj_adapters::{AgentAdapter, NotifyAdapter, SessionAdapter};
+use oj_core::{scoped_name, Clock, Effect, Event, IdGen, JobId, UuidIdGen};
+use oj_runbook::Runbook;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+/// `${git.old}` for a ref that didn't exist before, as in git's own hooks
+const ZERO_SHA: &str = "0000000000000000000000000000000000000000";
+
+/// In-memory state for a started git trigger
+pub(crate) struct GitTriggerState {
+    pub project_root: PathBuf,
+    pub runbook_hash: String,
+    /// Job the trigger runs
+    pub job: String,
+    pub status: GitTriggerStatus,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum GitTriggerStatus {
+    Running,
+    Stopped,
+}
+
+/// A ref that moved since the last recorded scan
+struct RefMove {
+    ref_name: String,
+    /// Previous commit, or [`ZERO_SHA`] for a new ref
+    old: String,
+    /// New commit, or empty for a deleted ref
+    new: String,
+}
+
+/// Append a timestamped line to the git trigger log file.
+///
+/// Creates the `{logs_dir}/git_trigger/` directory on first write.
+/// Errors are silently ignored — logging must not break the trigger.
+fn append_git_trigger_log(logs_dir: &Path, trigger_name: &str, namespace: &str, message: &str) {
+    let path = git_trigger_log_path(logs_dir, &scoped_name(namespace, trigger_name));
+    if let Some(parent) = path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    if let Ok(mut f) = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+    {
+        use std::io::Write;
+        let _ = writeln!(f, "[{}] {}", format_utc_now(), message);
+    }
+}
+
+/// Parameters for handling a git trigger started event.
+pub(crate) struct GitTriggerStartedParams<'a> {
+    pub trigger_name: &'a str,
+    pub project_root: &'a Path,
+    pub runbook_hash: &'a str,
+    pub ref_pattern: &'a str,
+    pub run_target: &'a str,
+    pub namespace: &'a str,
+}
+
+impl<S, A, N, C> Runtime<S, A, N, C>
+where
+    S: SessionAdapter,
+    A: AgentAdapter,
+    N: NotifyAdapter,
+    C: Clock,
+{
+    pub(crate) async fn handle_git_trigger_started(
+        &self,
+        params: GitTriggerStartedParams<'_>,
+    ) -> Result<Vec<Event>, RuntimeError> {
+        let GitTriggerStartedParams {
+            trigger_name,
+            project_root,
+            runbook_hash,
+            ref_pattern,
+            run_target,
+            namespace,
+        } = params;
+        let job = run_target.strip_prefix("job:").unwrap_or(run_target);
+
+        self.git_trigger_states.lock().insert(
+            scoped_name(namespace, trigger_name),
+            GitTriggerState {
+                project_root: project_root.to_path_buf(),
+                runbook_hash: runbook_hash.to_string(),
+                job: job.to_string(),
+                status: GitTriggerStatus::Running,
+            },
+        );
+
+        let log = |message: &str| {
+            append_git_trigger_log(self.logger.log_dir(), trigger_name, namespace, message)
+        };
+        // A repository that can't be watched (e.g. the project moved) is
+        // logged and the trigger left running, so `oj git-trigger start`
+        // can retry it
+        match self
+            .executor
+            .execute(Effect::WatchGitRefs {
+                trigger_name: trigger_name.to_string(),
+                namespace: namespace.to_string(),
+                repo: project_root.to_path_buf(),
+                ref_pattern: ref_pattern.to_string(),
+            })
+            .await
+        {
+            Ok(_) => log(&format!("started (ref={}, job={})", ref_pattern, job)),
+            Err(e) => log(&format!("error: {}", e)),
+        }
+        Ok(vec![])
+    }
+
+    pub(crate) async fn handle_git_trigger_stopped(
+        &self,
+        trigger_name: &str,
+        namespace: &str,
+    ) -> Result<Vec<Event>, RuntimeError> {
+        if let Some(state) = self
+            .git_trigger_states
+            .lock()
+            .get_mut(&scoped_name(namespace, trigger_name))
+        {
+            state.status = GitTriggerStatus::Stopped;
+        }
+
+        self.executor
+            .execute(Effect::UnwatchGitRefs {
+                trigger_name: trigger_name.to_string(),
+                namespace: namespace.to_string(),
+            })
+            .await?;
+
+        append_git_trigger_log(self.logger.log_dir(), trigger_name, namespace, "stopped");
+        Ok(vec![])
+    }
+
+    /// Compare a listing of the trigger's refs with the recorded positions
+    /// and start the job once for every ref that moved.
+    ///
+    /// The first listing after a trigger is created only records positions,
+    /// so existing commits don't run the job.
+    pub(crate) async fn handle_git_refs_scanned(
+        &self,
+        trigger_name: &str,
+        namespace: &str,
+        refs: &BTreeMap<String, String>,
+    ) -> Result<Vec<Event>, RuntimeError> {
+        let key = scoped_name(namespace, trigger_name);
+        let (project_root, runbook_hash, job) = {
+            let triggers = self.git_trigger_states.lock();
+            match triggers.get(&key) {
+                Some(s) if s.status == GitTriggerStatus::Running => (
+                    s.project_root.clone(),
+                    s.runbook_hash.clone(),
+                    s.job.clone(),
+                ),
+                _ => {
+                    tracing::debug!(
+                        git_trigger = trigger_name,
+                        "refs scanned but trigger not running"
+                    );
+                    return Ok(vec![]);
+                }
+            }
+        };
+        let Some((baselined, known)) = self.lock_state(|state| {
+            state
+                .git_triggers
+                .get(&key)
+                .map(|r| (r.baselined, r.refs.clone()))
+        }) else {
+            return Ok(vec![]);
+        };
+        let log = |message: &str| {
+            append_git_trigger_log(self.logger.log_dir(), trigger_name, namespace, message)
+        };
+
+        if !baselined {
+            log(&format!("baseline: {} matching refs", refs.len()));
+            return Ok(self
+                .executor
+                .execute_all(vec![Effect::Emit {
+                    event: Event::GitRefsBaselined {
+                        trigger_name: trigger_name.to_string(),
+                        namespace: namespace.to_string(),
+                        refs: refs.clone(),
+                    },
+                }])
+                .await?);
+        }
+
+        let moves = ref_moves(&known, refs);
+        if moves.is_empty() {
+            return Ok(vec![]);
+        }
+
+        // Prefer the runbook on disk so edits apply without a restart
+        let (runbook, job) = match self.load_git_trigger_runbook(&project_root, trigger_name) {
+            Ok(loaded) => loaded,
+            Err(e) => {
+                log(&format!("error: {}; using the runbook it started with", e));
+                (self.cached_runbook(&runbook_hash)?, job)
+            }
+        };
+        let (runbook_hash, runbook_json) = hash_runbook(&runbook)?;
+        if let Some(state) = self.git_trigger_states.lock().get_mut(&key) {
+            state.runbook_hash = runbook_hash.clone();
+            state.job = job.clone();
+        }
+
+        let mut result_events = Vec::new();
+        for RefMove { ref_name, old, new } in moves {
+            let job_id = if new.is_empty() {
+                log(&format!("{} deleted", ref_name));
+                None
+            } else {
+                let job_id = JobId::new(UuidIdGen.next());
+                let mut vars = HashMap::new();
+                vars.insert("git.ref".to_string(), ref_name.clone());
+                vars.insert("git.old".to_string(), old.clone());
+                vars.insert("git.new".to_string(), new.clone());
+                vars.insert("invoke.dir".to_string(), project_root.display().to_string());
+
+                let already_cached = self.runbook_cache.lock().contains_key(&runbook_hash);
+                match self
+                    .create_and_start_job(CreateJobParams {
+                        job_id: job_id.clone(),
+                        job_name: oj_runbook::job_display_name(&job, job_id.short(8), namespace),
+                        job_kind: job.clone(),
+                        vars,
+                        runbook_hash: runbook_hash.clone(),
+                        runbook_json: (!already_cached).then(|| runbook_json.clone()),
+                        runbook: runbook.clone(),
+                        namespace: namespace.to_string(),
+                        cron_name: None,
+                        parent: None,
+                        after: Vec::new(),
+                        priority: 0,
+                        project_root: Some(project_root.clone()),
+                    })
+                    .await
+                {
+                    Ok(events) => {
+                        result_events.extend(events);
+                        log(&format!(
+                            "{} moved to {}: triggered job {} ({})",
+                            ref_name,
+                            short_sha(&new),
+                            job,
+                            job_id.short(8)
+                        ));
+                        Some(job_id)
+                    }
+                    // The move is still recorded, so a broken job doesn't
+                    // fire again on every later scan
+                    Err(e) => {
+                        log(&format!(
+                            "error: {} moved to {} but job {} failed to start: {}",
+                            ref_name,
+                            short_sha(&new),
+                            job,
+                            e
+                        ));
+                        None
+                    }
+                }
+            };
+
+            result_events.extend(
+                self.executor
+                    .execute_all(vec![Effect::Emit {
+                        event: Event::GitRefMoved {
+                            trigger_name: trigger_name.to_string(),
+                            namespace: namespace.to_string(),
+                            ref_name,
+                            old,
+                            new,
+                            job_id,
+                        },
+                    }])
+                    .await?,
+            );
+        }
+        Ok(result_events)
+    }
+
+    /// Load the runbook defining `trigger_name` from the project, with the
+    /// job its trigger runs.
+    fn load_git_trigger_runbook(
+        &self,
+        project_root: &Path,
+        trigger_name: &str,
+    ) -> Result<(Runbook, String), RuntimeError> {
+        let runbook_dir = project_root.join(".oj/runbooks");
+        let runbook = oj_runbook::find_runbook_by_git_trigger(&runbook_dir, trigger_name)
+            .map_err(|e| RuntimeError::RunbookLoadError(e.to_string()))?
+            .ok_or_else(|| {
+                RuntimeError::RunbookLoadError(format!(
+                    "no runbook found containing git trigger '{}'",
+                    trigger_name
+                ))
+            })?;
+        let job = runbook
+            .get_git_trigger(trigger_name)
+            .and_then(|t| t.run.job_name())
+            .ok_or_else(|| {
+                RuntimeError::RunbookLoadError(format!(
+                    "git trigger '{}' runs no job",
+                    trigger_name
+                ))
+            })?
+            .to_string();
+        Ok((runbook, job))
+    }
+}
+
+/// Refs that were created, moved, or deleted between two listings, in ref
+/// name order.
+fn ref_moves(known: &BTreeMap<String, String>, current: &BTreeMap<String, String>) -> Vec<RefMove> {
+    let mut moves: Vec<RefMove> = current
+        .iter()
+        .filter(|(name, sha)| known.get(*name) != Some(*sha))
+        .map(|(name, sha)| RefMove {
+            ref_name: name.clone(),
+            old: known
+                .get(name)
+                .cloned()
+                .unwrap_or_else(|| ZERO_SHA.to_string()),
+            new: sha.clone(),
+        })
+        .chain(
+            known
+                .iter()
+                .filter(|(name, _)| !current.contains_key(*name))
+                .map(|(name, sha)| RefMove {
+                    ref_name: name.clone(),
+                    old: sha.clone(),
+                    new: String::new(),
+                }),
+        )
+        .collect();
+    moves.sort_by(|a, b| a.ref_name.cmp(&b.ref_name));
+    moves
+}
+
+fn short_sha(sha: &str) -> &str {
+    &sha[..sha.len().min(8)]
+}
@@ -6,7 +6,7 @@
 use super::super::Runtime;
 use crate::error::RuntimeError;
 use oj_adapters::{AgentAdapter, NotifyAdapter, SessionAdapter};
-use oj_core::{Clock, Effect, Event, JobId, OwnerId, WorkspaceId};
+use oj_core::{Clock, Effect, Event, JobDependency, JobId, OwnerId, WorkspaceId};
 use oj_runbook::{NotifyConfig, Runbook};
 use std::collections::HashMap;
 use std::path::PathBuf;
@@ -22,6 +22,15 @@ pub(crate) struct CreateJobParams {
     pub runbook: Runbook,
     pub namespace: String,
     pub cron_name: Option<String>,
+    /// Parent job and step, when started by a `run = { job }` step
+    pub parent: Option<(JobId, String)>,
+    /// Jobs that must finish first (`oj run --after`); the job definition's
+    /// `depends_on` is added to these
+    pub after: Vec<JobDependency>,
+    /// Admission priority (`oj run --priority`)
+    pub priority: i32,
+    /// Project the job was started from, for its running-job limit
+    pub project_root: Option<PathBuf>,
 }
 
 impl<S, A, N, C> Runtime<S, A, N, C>
@@ -45,6 +54,10 @@ where
             runbook,
             namespace,
             cron_name,
+            parent,
+            mut after,
+            priority,
+            project_root,
         } = params;
 
         // Idempotency guard: if job already exists (e.g., from crash recovery
@@ -79,6 +92,15 @@ where
         // Capture notify config before runbook is moved into cache
         let notify_config = job_def.notify.clone();
 
+        // Resolve templated dependencies; a bad spec fails the job once created
+        let depends_on_error = match self.resolve_depends_on(&job_def.depends_on, &vars) {
+            Ok(deps) => {
+                after.extend(deps);
+                None
+            }
+            Err(e) => Some(format!("invalid depends_on: {}", e)),
+        };
+
         // Determine execution path and workspace metadata (path, id, type)
         let is_worktree;
         let workspace_id_str;
@@ -302,8 +324,11 @@ where
                     .get("workspace.branch")
                     .cloned()
                     .unwrap_or_else(|| format!("ws-{}", job_id.short(8)));
+                // Jobs started by a git trigger check out the commit that
+                // triggered them unless the workspace names its own ref
                 let start_point = vars
                     .get("workspace.ref")
+                    .or_else(|| vars.get("git.new"))
                     .cloned()
                     .unwrap_or_else(|| "HEAD".to_string());
 
@@ -367,8 +392,20 @@ where
                 created_at_epoch_ms: self.clock().epoch_ms(),
                 namespace: namespace.clone(),
                 cron_name,
+                after: after.clone(),
+                priority,
+                project_root,
             },
         });
+        if let Some((parent_id, step)) = parent {
+            creation_effects.push(Effect::Emit {
+                event: Event::StepDelegated {
+                    job_id: parent_id,
+                    step,
+                    child_job_id: job_id.clone(),
+                },
+            });
+        }
 
         // Insert into in-process cache
         {
@@ -417,8 +454,22 @@ where
             }
         }
 
-        // Start the first step
+        if let Some(error) = depends_on_error {
+            let job = self.require_job(job_id.as_str())?;
+            result_events.extend(self.fail_job_terminal(&job, &error).await?);
+            return Ok(result_events);
+        }
+
+        // Start the first step, unless it has to wait for other jobs or for
+        // a running-job slot
         if let Some(step_name) = first_step_name {
+            let job = self.require_job(job_id.as_str())?;
+            if !after.is_empty() && !self.check_dependencies(&job, &mut result_events).await? {
+                return Ok(result_events);
+            }
+            if !self.check_admission(&job, &mut result_events).await? {
+                return Ok(result_events);
+            }
             result_events.extend(
                 self.start_step(&job_id, &step_name, &vars, &execution_path)
                     .await?,
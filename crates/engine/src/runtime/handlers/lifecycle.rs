@@ -6,7 +6,9 @@
 use super::super::Runtime;
 use crate::error::RuntimeError;
 use oj_adapters::{AgentAdapter, NotifyAdapter, SessionAdapter};
-use oj_core::{AgentId, Clock, Effect, Event, JobId, SessionId, ShortId, StepOutcome, WorkspaceId};
+use oj_core::{
+    AgentId, Clock, Effect, Event, JobId, SessionId, ShortId, StepOutcome, StepStatus, WorkspaceId,
+};
 use std::collections::HashMap;
 
 impl<S, A, N, C> Runtime<S, A, N, C>
@@ -25,6 +27,13 @@ where
     ) -> Result<Vec<Event>, RuntimeError> {
         let job = self.require_job(job_id.as_str())?;
 
+        if let StepStatus::Blocked(resources) = &job.step_status {
+            return Err(RuntimeError::InvalidRequest(format!(
+                "job is blocked waiting for {}; it will start when released",
+                resources
+            )));
+        }
+
         let is_failed = job.step == "failed";
 
         // If job is in terminal "failed" state, find the last failed step
@@ -52,6 +61,18 @@ where
             .get_step(&resume_step)
             .ok_or_else(|| RuntimeError::StepNotFound(resume_step.clone()))?;
 
+        // A running fan-out or sub-job step is driven by its child jobs
+        if step_def.run.is_parallel() && !is_failed {
+            return Err(RuntimeError::InvalidRequest(
+                "job is waiting on parallel branches; resume the branch jobs instead".into(),
+            ));
+        }
+        if step_def.run.is_job() && !is_failed {
+            return Err(RuntimeError::InvalidRequest(
+                "job is waiting on a child job; resume the child job instead".into(),
+            ));
+        }
+
         // Resolve message for agent steps BEFORE emitting any events.
         // For failed jobs, default to "Retrying" if no message provided.
         // For running jobs, require an explicit message.
@@ -140,6 +161,18 @@ where
                 .handle_shell_resume(&job, &resume_step, command)
                 .await?;
             result_events.extend(events);
+        } else if step_def.run.is_parallel() || step_def.run.is_job() {
+            // Parallel or sub-job step: start again with fresh child jobs
+            let job = self.require_job(job_id.as_str())?;
+            let events = self
+                .start_step(
+                    job_id,
+                    &resume_step,
+                    &merged_inputs,
+                    &self.execution_dir(&job),
+                )
+                .await?;
+            result_events.extend(events);
         } else {
             return Err(RuntimeError::InvalidRequest(format!(
                 "resume not supported for step type in step: {}",
@@ -210,6 +243,7 @@ where
         exit_code: i32,
         stdout: Option<&str>,
         stderr: Option<&str>,
+        timed_out: bool,
     ) -> Result<Vec<Event>, RuntimeError> {
         let job = self.require_job(job_id.as_str())?;
 
@@ -234,13 +268,55 @@ where
                 .append_fenced(job_id.as_str(), step, "stderr", err);
         }
 
-        if exit_code == 0 {
+        // Publish outputs as steps.<step>.* vars for later steps
+        let outputs_file = crate::outputs::outputs_path(&self.state_dir, job_id.as_str(), step);
+        let file = std::fs::read_to_string(&outputs_file).ok();
+        let _ = std::fs::remove_file(&outputs_file);
+        if let Some(dir) = outputs_file.parent() {
+            let _ = std::fs::remove_dir(dir);
+        }
+        let job = match crate::outputs::capture_outputs(step, stdout, file.as_deref()) {
+            Ok(mut vars) => {
+                vars.extend(crate::outputs::stale_outputs(step, &job.vars, &vars));
+                self.executor
+                    .execute(Effect::Emit {
+                        event: Event::JobUpdated {
+                            id: job_id.clone(),
+                            vars,
+                        },
+                    })
+                    .await?;
+                self.require_job(job_id.as_str())?
+            }
+            Err(e) if exit_code == 0 => {
+                self.logger.append(job_id.as_str(), step, &e);
+                return self.fail_job(&job, &format!("step outputs: {}", e)).await;
+            }
+            Err(e) => {
+                self.logger.append(job_id.as_str(), step, &e);
+                job
+            }
+        };
+
+        if timed_out {
+            let error = format!(
+                "{} {}",
+                oj_core::SHELL_TIMEOUT_REASON,
+                self.shell_timeout(&job)?
+            );
+            self.logger.append(job_id.as_str(), step, &error);
+            return self.fail_job_timed_out(&job, &error).await;
+        }
+
+        // on_exit routes an exit code to a step without failing this one
+        let route = self.exit_route(&job, exit_code)?;
+        if exit_code == 0 || route.is_some() {
             self.logger.append(
                 job_id.as_str(),
                 step,
                 &format!("shell completed (exit {})", exit_code),
             );
-            self.advance_job(&job).await
+            self.advance_job_to(&job, route.as_deref()).await
         } else {
             self.logger.append(
                 job_id.as_str(),
@@ -45,6 +45,9 @@ fn create_failed_job(ctx: &TestContext, job_id: &str, runbook_hash: &str) {
             created_at_epoch_ms: 1_000_000,
             namespace: String::new(),
             cron_name: None,
+            after: vec![],
+            priority: 0,
+            project_root: None,
         },
         // Agent started on "plan" step
         Event::StepStarted {
@@ -86,6 +89,9 @@ fn create_running_job(ctx: &TestContext, job_id: &str, runbook_hash: &str) {
             created_at_epoch_ms: 1_000_000,
             namespace: String::new(),
             cron_name: None,
+            after: vec![],
+            priority: 0,
+            project_root: None,
         },
         Event::StepStarted {
             job_id: JobId::new(job_id),
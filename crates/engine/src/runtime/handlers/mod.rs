@@ -6,19 +6,25 @@
 mod agent;
 mod command;
 pub(crate) mod cron;
+pub(crate) mod git_trigger;
 mod job_create;
 mod lifecycle;
 mod timer;
+pub(crate) mod trigger;
+pub(crate) mod watch;
 pub(crate) mod worker;
 
 pub(crate) use job_create::CreateJobParams;
 
 use self::command::HandleCommandParams;
 use self::cron::{CronOnceParams, CronStartedParams};
+use self::git_trigger::GitTriggerStartedParams;
+use self::watch::WatchStartedParams;
 use super::Runtime;
 use crate::error::RuntimeError;
 use oj_adapters::{AgentAdapter, NotifyAdapter, SessionAdapter};
-use oj_core::{scoped_name, Clock, Effect, Event};
+use oj_core::{scoped_name, Clock, Effect, Event, TimerId};
+use std::time::Duration;
 
 impl<S, A, N, C> Runtime<S, A, N, C>
 where
@@ -29,7 +35,7 @@ where
 {
     /// Handle an incoming event and return any produced events
     pub async fn handle_event(&self, event: Event) -> Result<Vec<Event>, RuntimeError> {
-        let mut result_events = Vec::new();
+        let mut result_events = self.fire_triggers(&event).await?;
 
         match &event {
             Event::CommandRun {
@@ -40,6 +46,8 @@ where
                 namespace,
                 command,
                 args,
+                after,
+                priority,
             } => {
                 result_events.extend(
                     self.handle_command(HandleCommandParams {
@@ -50,6 +58,8 @@ where
                         namespace,
                         command,
                         args,
+                        after,
+                        priority: *priority,
                     })
                     .await?,
                 );
@@ -93,6 +103,10 @@ where
                 result_events.extend(self.handle_agent_idle_hook(agent_id).await?);
             }
 
+            Event::AgentUsage { agent_id, .. } => {
+                result_events.extend(self.handle_agent_usage(agent_id).await?);
+            }
+
             Event::AgentStop { agent_id } => {
                 result_events.extend(self.handle_agent_stop_hook(agent_id).await?);
             }
@@ -120,6 +134,7 @@ where
                 exit_code,
                 stdout,
                 stderr,
+                timed_out,
             } => {
                 result_events.extend(
                     self.handle_shell_exited(
@@ -128,6 +143,7 @@ where
                         *exit_code,
                         stdout.as_deref(),
                         stderr.as_deref(),
+                        *timed_out,
                     )
                     .await?,
                 );
@@ -172,8 +188,12 @@ where
                 project_root,
                 runbook_hash,
                 interval,
+                schedule,
+                timezone,
                 run_target,
                 namespace,
+                started_at_ms: _,
+                resumed_from_ms,
             } => {
                 result_events.extend(
                     self.handle_cron_started(CronStartedParams {
@@ -181,8 +201,11 @@ where
                         project_root,
                         runbook_hash,
                         interval,
+                        schedule: schedule.as_deref(),
+                        timezone: timezone.as_deref(),
                         run_target,
                         namespace,
+                        resumed_from_ms: *resumed_from_ms,
                     })
                     .await?,
                 );
@@ -224,17 +247,109 @@ where
                 );
             }
 
+            // -- watch events --
+            Event::WatchStarted {
+                watch_name,
+                project_root,
+                runbook_hash,
+                paths,
+                debounce,
+                run_target,
+                namespace,
+            } => {
+                result_events.extend(
+                    self.handle_watch_started(WatchStartedParams {
+                        watch_name,
+                        project_root,
+                        runbook_hash,
+                        paths,
+                        debounce,
+                        run_target,
+                        namespace,
+                    })
+                    .await?,
+                );
+            }
+
+            Event::WatchStopped {
+                watch_name,
+                namespace,
+            } => {
+                result_events.extend(self.handle_watch_stopped(watch_name, namespace).await?);
+            }
+
+            Event::WatchChanged {
+                watch_name,
+                namespace,
+                files,
+            } => {
+                result_events.extend(
+                    self.handle_watch_changed(watch_name, namespace, files)
+                        .await?,
+                );
+            }
+
+            // -- git trigger events --
+            Event::GitTriggerStarted {
+                trigger_name,
+                project_root,
+                runbook_hash,
+                ref_pattern,
+                run_target,
+                namespace,
+            } => {
+                result_events.extend(
+                    self.handle_git_trigger_started(GitTriggerStartedParams {
+                        trigger_name,
+                        project_root,
+                        runbook_hash,
+                        ref_pattern,
+                        run_target,
+                        namespace,
+                    })
+                    .await?,
+                );
+            }
+
+            Event::GitTriggerStopped {
+                trigger_name,
+                namespace,
+            } => {
+                result_events.extend(
+                    self.handle_git_trigger_stopped(trigger_name, namespace)
+                        .await?,
+                );
+            }
+
+            Event::GitRefsScanned {
+                trigger_name,
+                namespace,
+                refs,
+            } => {
+                result_events.extend(
+                    self.handle_git_refs_scanned(trigger_name, namespace, refs)
+                        .await?,
+                );
+            }
+
             // -- worker events --
             Event::WorkerStarted {
                 worker_name,
                 project_root,
                 runbook_hash,
+                rate,
                 namespace,
                 ..
             } => {
                 result_events.extend(
-                    self.handle_worker_started(worker_name, project_root, runbook_hash, namespace)
-                        .await?,
+                    self.handle_worker_started(
+                        worker_name,
+                        project_root,
+                        runbook_hash,
+                        rate.as_deref(),
+                        namespace,
+                    )
+                    .await?,
                 );
             }
 
@@ -274,22 +389,46 @@ where
             Event::WorkerResized {
                 worker_name,
                 concurrency,
+                rate,
                 namespace,
             } => {
                 result_events.extend(
-                    self.handle_worker_resized(worker_name, *concurrency, namespace)
-                        .await?,
+                    self.handle_worker_resized(
+                        worker_name,
+                        *concurrency,
+                        rate.as_deref(),
+                        namespace,
+                    )
+                    .await?,
                 );
             }
 
-            // Job terminal state -> check worker re-poll
+            // Job terminal state -> check worker re-poll and parallel parents
             // NOTE: check_worker_job_complete is also called directly from
             // fail_job/cancel_job/complete_job for immediate queue
             // item updates. This handler is a no-op safety net (idempotent).
-            Event::JobAdvanced { id, step }
-                if step == "done" || step == "failed" || step == "cancelled" =>
-            {
-                result_events.extend(self.check_worker_job_complete(id, step).await?);
+            Event::JobAdvanced { id, step } => {
+                if step == "done" || step == "failed" || step == "cancelled" {
+                    result_events.extend(self.check_worker_job_complete(id, step).await?);
+                    // A finished child job may complete or fail its parent's step
+                    if let Some(job) = self
+                        .get_job(id.as_str())
+                        .filter(|j| j.parent_job_id.is_some())
+                    {
+                        if job.is_branch {
+                            result_events.extend(self.handle_branch_finished(&job).await?);
+                        } else {
+                            result_events.extend(self.handle_sub_job_finished(&job).await?);
+                        }
+                    }
+                }
+                // Leaving a step releases its locks
+                result_events.extend(self.wake_lock_waiters().await?);
+                // Queued jobs get the freed slot before newly unblocked dependents
+                if step == "done" || step == "failed" || step == "cancelled" {
+                    result_events.extend(self.admit_queued_jobs().await?);
+                    result_events.extend(self.wake_dependents(Some(id)).await?);
+                }
             }
 
             // Queue pushed -> wake workers watching this queue
@@ -298,6 +437,9 @@ where
                 namespace,
                 item_id,
                 data,
+                priority,
+                visible_after_epoch_ms,
+                expires_at_epoch_ms,
                 ..
             } => {
                 // Log queue push event
@@ -307,19 +449,49 @@ where
                     .map(|(k, v)| format!("{}={}", k, v))
                     .collect::<Vec<_>>()
                     .join(", ");
+                let priority_str = match priority {
+                    0 => String::new(),
+                    p => format!(" priority={}", p),
+                };
+                let now_ms = self.clock().epoch_ms();
+                let scheduled_at = visible_after_epoch_ms.filter(|at| *at > now_ms);
+                let visible_str = scheduled_at
+                    .map(|at| format!(" visible_after={}", crate::time_fmt::format_utc(at)))
+                    .unwrap_or_default();
+                let expires_str = expires_at_epoch_ms
+                    .map(|at| format!(" expires_at={}", crate::time_fmt::format_utc(at)))
+                    .unwrap_or_default();
                 self.queue_logger.append(
                     &scoped,
                     item_id,
-                    &format!("pushed data={{{}}}", data_str),
+                    &format!(
+                        "pushed{}{}{} data={{{}}}",
+                        priority_str, visible_str, expires_str, data_str
+                    ),
                 );
 
+                if let Some(at) = expires_at_epoch_ms {
+                    let timer_id = TimerId::queue_expire(&scoped, item_id);
+                    let ttl = Duration::from_millis(at.saturating_sub(now_ms));
+                    result_events.extend(self.set_durable_timer(timer_id, ttl).await?);
+                }
+
+                // Delayed items: wake workers when the item becomes visible
+                // instead of now
+                if let Some(at) = scheduled_at {
+                    let timer_id = TimerId::queue_visible(&scoped, item_id);
+                    let delay = Duration::from_millis(at - now_ms);
+                    result_events.extend(self.set_durable_timer(timer_id, delay).await?);
+                    return Ok(result_events);
+                }
+
                 let (worker_names, all_workers): (Vec<String>, Vec<String>) = {
                     let workers = self.worker_states.lock();
                     let all: Vec<String> = workers.keys().cloned().collect();
                     let matching: Vec<String> = workers
                         .iter()
                         .filter(|(_, state)| {
-                            state.queue_name == *queue_name
+                            state.sources.contains(queue_name)
                                 && state.status == worker::WorkerStatus::Running
                         })
                         .map(|(name, _)| name.clone())
@@ -367,6 +539,7 @@ where
                 queue_name,
                 item_id,
                 namespace,
+                ..
             } => {
                 let scoped = scoped_name(namespace, queue_name);
                 self.queue_logger.append(&scoped, item_id, "completed");
@@ -401,21 +574,36 @@ where
                 }
             }
 
+            // A step waiting out its retry delay has released its locks
+            Event::StepRetrying { .. } => {
+                result_events.extend(self.wake_lock_waiters().await?);
+            }
+
             Event::JobDeleted { id } => {
                 result_events.extend(self.handle_job_deleted(id).await?);
+                result_events.extend(self.wake_lock_waiters().await?);
+                result_events.extend(self.admit_queued_jobs().await?);
+                result_events.extend(self.wake_dependents(Some(id)).await?);
             }
 
             // No-op: signals and state mutations handled elsewhere
             Event::Shutdown
             | Event::Custom
             | Event::JobCreated { .. }
-            | Event::JobAdvanced { .. }
+            | Event::JobBlocked { .. }
+            | Event::JobQueued { .. }
+            | Event::JobAdmitted { .. }
             | Event::StepStarted { .. }
             | Event::StepWaiting { .. }
             | Event::StepCompleted { .. }
             | Event::StepFailed { .. }
+            | Event::StepBranched { .. }
+            | Event::StepDelegated { .. }
             | Event::SessionCreated { .. }
             | Event::SessionDeleted { .. }
+            | Event::TimerScheduled { .. }
+            | Event::TimerCancelled { .. }
+            | Event::QueueItemLeased { .. }
             | Event::WorkspaceCreated { .. }
             | Event::WorkspaceReady { .. }
             | Event::WorkspaceFailed { .. }
@@ -426,13 +614,31 @@ where
             | Event::WorkerItemDispatched { .. }
             | Event::CronFired { .. }
             | Event::CronDeleted { .. }
+            | Event::WatchFired { .. }
+            | Event::GitRefsBaselined { .. }
+            | Event::GitRefMoved { .. }
+            | Event::LockWaiting { .. }
+            | Event::LockAcquired { .. }
             | Event::DecisionCreated { .. }
-            | Event::DecisionResolved { .. }
+            | Event::AgentBudgetExceeded { .. }
             | Event::AgentRunCreated { .. }
             | Event::AgentRunStarted { .. }
-            | Event::AgentRunStatusChanged { .. }
             | Event::AgentRunDeleted { .. } => {}
 
+            // Agent run terminal state -> settle the queue item of an
+            // agent-backed worker (no-op for other runs) and hand its
+            // running-job slot to the admission queue
+            Event::AgentRunStatusChanged { id, status, .. } => {
+                if status.is_terminal() {
+                    result_events.extend(self.check_worker_agent_run_complete(id, status).await?);
+                    result_events.extend(Box::pin(self.admit_queued_jobs()).await?);
+                }
+            }
+
+            Event::DecisionResolved { id, .. } => {
+                result_events.extend(self.handle_approval_resolved(id).await?);
+            }
+
             Event::AgentRunResume { id, message, kill } => {
                 result_events.extend(
                     self.handle_agent_run_resume(id, message.as_deref(), *kill)
@@ -445,9 +651,11 @@ where
                 queue_name,
                 item_id,
                 namespace,
+                ..
             } => {
                 let scoped = scoped_name(namespace, queue_name);
                 self.queue_logger.append(&scoped, item_id, "retried");
+                result_events.extend(self.expire_overdue_queue_item(&scoped, item_id).await?);
             }
             Event::QueueItemDead {
                 queue_name,
@@ -457,6 +665,60 @@ where
                 let scoped = scoped_name(namespace, queue_name);
                 self.queue_logger.append(&scoped, item_id, "dead");
             }
+            Event::QueueItemPrioritized {
+                queue_name,
+                item_id,
+                priority,
+                namespace,
+            } => {
+                let scoped = scoped_name(namespace, queue_name);
+                self.queue_logger.append(
+                    &scoped,
+                    item_id,
+                    &format!("prioritized priority={}", priority),
+                );
+            }
+            Event::QueueItemExpired {
+                queue_name,
+                item_id,
+                namespace,
+            } => {
+                let scoped = scoped_name(namespace, queue_name);
+                self.queue_logger.append(&scoped, item_id, "expired");
+            }
+            Event::QueueLeaseExpired {
+                queue_name,
+                item_id,
+                namespace,
+            } => {
+                let scoped = scoped_name(namespace, queue_name);
+                self.queue_logger
+                    .append(&scoped, item_id, "lease expired, failed");
+            }
+            Event::QueueItemMerged {
+                queue_name,
+                item_id,
+                data,
+                priority,
+                namespace,
+            } => {
+                let scoped = scoped_name(namespace, queue_name);
+                let mut pairs: Vec<_> = data.iter().collect();
+                pairs.sort();
+                let data_str = pairs
+                    .iter()
+                    .map(|(k, v)| format!("{}={}", k, v))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                self.queue_logger.append(
+                    &scoped,
+                    item_id,
+                    &format!(
+                        "merged duplicate push priority={} data={{{}}}",
+                        priority, data_str
+                    ),
+                );
+            }
         }
 
         Ok(result_events)
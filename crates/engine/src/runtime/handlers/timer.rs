@@ -9,9 +9,10 @@ use crate::monitor::{self, MonitorState};
 use crate::ActionContext;
 use oj_adapters::{AgentAdapter, NotifyAdapter, SessionAdapter};
 use oj_core::{
-    split_scoped_name, AgentId, AgentRunId, AgentRunStatus, AgentState, Clock, Effect, Event,
-    JobId, TimerId,
+    split_scoped_name, AgentId, AgentRun, AgentRunId, AgentRunStatus, AgentState, Clock, Effect,
+    Event, Job, JobId, StepStatus, TimerId,
 };
+use oj_storage::QueueItemStatus;
 use std::time::Duration;
 
 impl<S, A, N, C> Runtime<S, A, N, C>
@@ -50,20 +51,110 @@ where
         if let Some(rest) = id_str.strip_prefix("cooldown:") {
             return self.handle_cooldown_timer(rest).await;
         }
+        if let Some(job_id) = id_str.strip_prefix("step-retry:") {
+            return self.handle_step_retry_timer(job_id).await;
+        }
         if let Some(rest) = id_str.strip_prefix("queue-retry:") {
             return self.handle_queue_retry_timer(rest).await;
         }
+        if let Some(rest) = id_str.strip_prefix("queue-visible:") {
+            return self.handle_queue_visible_timer(rest).await;
+        }
+        if let Some(rest) = id_str.strip_prefix("queue-expire:") {
+            return self.handle_queue_expire_timer(rest).await;
+        }
+        if let Some(rest) = id_str.strip_prefix("queue-lease:") {
+            return self.handle_queue_lease_timer(rest).await;
+        }
         if let Some(rest) = id_str.strip_prefix("cron:") {
             return self.handle_cron_timer_fired(rest).await;
         }
         if let Some(rest) = id_str.strip_prefix("queue-poll:") {
             return self.handle_queue_poll_timer(rest).await;
         }
+        if let Some(rest) = id_str.strip_prefix("worker-rate:") {
+            return self.handle_queue_poll_timer(rest).await;
+        }
         // Unknown timer — no-op
         tracing::debug!(timer_id = %id, "ignoring unknown timer");
         Ok(vec![])
     }
 
+    /// Set a timer that survives a daemon restart.
+    ///
+    /// The fire time is recorded in state so `restore_durable_timers` can
+    /// re-arm it on startup.
+    pub(crate) async fn set_durable_timer(
+        &self,
+        id: TimerId,
+        duration: Duration,
+    ) -> Result<Vec<Event>, RuntimeError> {
+        let fires_at_ms = self.clock().epoch_ms() + duration.as_millis() as u64;
+        let events = self
+            .executor
+            .execute_all(vec![
+                Effect::SetTimer {
+                    id: id.clone(),
+                    duration,
+                },
+                Effect::Emit {
+                    event: Event::TimerScheduled { id, fires_at_ms },
+                },
+            ])
+            .await?;
+        Ok(events)
+    }
+
+    /// Re-arm durable timers recorded before a daemon restart.
+    ///
+    /// Timers whose fire time passed while the daemon was down fire on the
+    /// next scheduler tick.
+    pub async fn restore_durable_timers(&self) -> Result<(), RuntimeError> {
+        let timers: Vec<(String, u64)> = self.lock_state(|state| {
+            state
+                .timers
+                .iter()
+                .map(|(id, at)| (id.clone(), *at))
+                .collect()
+        });
+        let now_ms = self.clock().epoch_ms();
+        for (id, fires_at_ms) in timers {
+            tracing::info!(timer_id = %id, fires_at_ms, "re-arming durable timer");
+            self.executor
+                .execute(Effect::SetTimer {
+                    id: TimerId::new(id),
+                    duration: Duration::from_millis(fires_at_ms.saturating_sub(now_ms)),
+                })
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Handle step retry timer expiry - run the failed step again
+    async fn handle_step_retry_timer(&self, job_id: &str) -> Result<Vec<Event>, RuntimeError> {
+        let Some(job) = self.get_active_job(job_id) else {
+            tracing::debug!(job_id, "step retry timer for missing/terminal job");
+            return Ok(vec![]);
+        };
+        // A resumed or cancelled job has already moved past the retry
+        if job.step_status != StepStatus::Pending {
+            return Ok(vec![]);
+        }
+
+        self.logger.append(
+            &job.id,
+            &job.step,
+            &format!("retrying step (attempt {})", job.step_retries),
+        );
+        self.start_step(
+            &JobId::new(&job.id),
+            &job.step,
+            &job.vars,
+            &self.execution_dir(&job),
+        )
+        .await
+    }
+
     /// Handle cooldown timer expiry - re-trigger the action
     async fn handle_cooldown_timer(&self, rest: &str) -> Result<Vec<Event>, RuntimeError> {
         // Parse timer ID: "job_id:trigger:chain_pos"
@@ -150,6 +241,19 @@ where
         let (ns, qn) = split_scoped_name(scoped_queue);
         let (namespace, queue_name) = (ns.to_string(), qn.to_string());
 
+        // The item may have been retried by hand or dropped since
+        let still_failed = self.lock_state(|state| {
+            state
+                .queue_items
+                .get(scoped_queue)
+                .and_then(|items| items.iter().find(|i| i.id == item_id))
+                .is_some_and(|i| i.status == QueueItemStatus::Failed)
+        });
+        if !still_failed {
+            tracing::debug!(queue = %queue_name, item = item_id, "queue retry timer for settled item");
+            return Ok(vec![]);
+        }
+
         tracing::info!(
             queue = %queue_name,
             item = item_id,
@@ -165,20 +269,303 @@ where
                     queue_name: queue_name.clone(),
                     item_id: item_id.to_string(),
                     namespace: namespace.clone(),
+                    automatic: true,
                 },
             }])
             .await?;
 
         // Wake workers attached to this queue
+        result_events.extend(self.wake_queue_workers(&queue_name, &namespace).await?);
+
+        Ok(result_events)
+    }
+
+    /// Handle a delayed queue item's visibility timer — wake workers so they
+    /// pick it up.
+    async fn handle_queue_visible_timer(&self, rest: &str) -> Result<Vec<Event>, RuntimeError> {
+        let (scoped_queue, item_id) = match rest.rsplit_once(':') {
+            Some(pair) => pair,
+            None => {
+                tracing::warn!(timer_rest = rest, "malformed queue-visible timer ID");
+                return Ok(vec![]);
+            }
+        };
+        let (namespace, queue_name) = split_scoped_name(scoped_queue);
+
+        // The item may have been dropped or drained while it waited
+        let still_pending = self.lock_state(|state| {
+            state
+                .queue_items
+                .get(scoped_queue)
+                .and_then(|items| items.iter().find(|i| i.id == item_id))
+                .is_some_and(|i| i.status == QueueItemStatus::Pending)
+        });
+        if !still_pending {
+            tracing::debug!(
+                queue = queue_name,
+                item = item_id,
+                "visibility timer for settled item"
+            );
+            return Ok(vec![]);
+        }
+
+        self.queue_logger.append(scoped_queue, item_id, "visible");
+        self.wake_queue_workers(queue_name, namespace).await
+    }
+
+    /// Handle a queue item's TTL running out — expire or drop it if it is
+    /// still waiting to be taken.
+    async fn handle_queue_expire_timer(&self, rest: &str) -> Result<Vec<Event>, RuntimeError> {
+        let (scoped_queue, item_id) = match rest.rsplit_once(':') {
+            Some(pair) => pair,
+            None => {
+                tracing::warn!(timer_rest = rest, "malformed queue-expire timer ID");
+                return Ok(vec![]);
+            }
+        };
+        self.expire_overdue_queue_item(scoped_queue, item_id).await
+    }
+
+    /// Expire or drop a pending item whose TTL has run out. Also called when
+    /// an item returns to pending, since its expiry timer is spent if it
+    /// fired while the item was taken.
+    pub(crate) async fn expire_overdue_queue_item(
+        &self,
+        scoped_queue: &str,
+        item_id: &str,
+    ) -> Result<Vec<Event>, RuntimeError> {
+        let (namespace, queue_name) = split_scoped_name(scoped_queue);
+
+        let now_ms = self.clock().epoch_ms();
+        let drop_on_expire = self.lock_state(|state| {
+            state
+                .queue_items
+                .get(scoped_queue)
+                .and_then(|items| items.iter().find(|i| i.id == item_id))
+                .filter(|i| i.is_overdue(now_ms))
+                .map(|i| i.drop_on_expire)
+        });
+        let Some(drop_on_expire) = drop_on_expire else {
+            tracing::debug!(
+                queue = queue_name,
+                item = item_id,
+                "queue item not pending past its TTL"
+            );
+            return Ok(vec![]);
+        };
+
+        tracing::info!(queue = queue_name, item = item_id, "queue item expired");
+        let mut effects = vec![Effect::Emit {
+            event: Event::QueueItemExpired {
+                queue_name: queue_name.to_string(),
+                item_id: item_id.to_string(),
+                namespace: namespace.to_string(),
+            },
+        }];
+        if drop_on_expire {
+            effects.push(Effect::Emit {
+                event: Event::QueueDropped {
+                    queue_name: queue_name.to_string(),
+                    item_id: item_id.to_string(),
+                    namespace: namespace.to_string(),
+                },
+            });
+        }
+        Ok(self.executor.execute_all(effects).await?)
+    }
+
+    /// Handle an active item's lease running out — renew it while a handler
+    /// job or agent run is still working the item, otherwise fail the item and
+    /// retry it or mark it dead by the queue's `retry` policy.
+    async fn handle_queue_lease_timer(&self, rest: &str) -> Result<Vec<Event>, RuntimeError> {
+        let (scoped_queue, item_id) = match rest.rsplit_once(':') {
+            Some(pair) => pair,
+            None => {
+                tracing::warn!(timer_rest = rest, "malformed queue-lease timer ID");
+                return Ok(vec![]);
+            }
+        };
+        let (namespace, queue_name) = split_scoped_name(scoped_queue);
+
+        let (active, handlers, queue_runbook) = self.lock_state(|state| {
+            let active = state
+                .queue_items
+                .get(scoped_queue)
+                .and_then(|items| items.iter().find(|i| i.id == item_id))
+                .is_some_and(|i| i.status == QueueItemStatus::Active);
+            let works_item = |ns: &str, vars: &std::collections::HashMap<String, String>| {
+                ns == namespace && vars.get("item.id").map(String::as_str) == Some(item_id)
+            };
+            let handlers: Vec<ItemHandler> = state
+                .jobs
+                .values()
+                .filter(|job| !job.is_terminal() && works_item(&job.namespace, &job.vars))
+                .map(|job| ItemHandler::Job(Box::new(job.clone())))
+                .chain(
+                    state
+                        .agent_runs
+                        .values()
+                        .filter(|run| !run.is_terminal() && works_item(&run.namespace, &run.vars))
+                        .map(|run| ItemHandler::AgentRun(Box::new(run.clone()))),
+                )
+                .collect();
+            let queue_runbook = state
+                .workers
+                .values()
+                .find(|w| w.namespace == namespace && w.queue_names().contains(&queue_name))
+                .map(|w| w.runbook_hash.clone());
+            (active, handlers, queue_runbook)
+        });
+        if !active {
+            tracing::debug!(
+                queue = queue_name,
+                item = item_id,
+                "lease timer for settled item"
+            );
+            return Ok(vec![]);
+        }
+
+        let mut handler_runbook = None;
+        for handler in &handlers {
+            if self.item_handler_alive(handler).await {
+                handler_runbook = Some(handler.runbook_hash().to_string());
+                break;
+            }
+        }
+        if let Some(runbook_hash) = handler_runbook {
+            let lease = self.cached_runbook(&runbook_hash).ok().and_then(|runbook| {
+                runbook
+                    .get_queue(queue_name)
+                    .and_then(super::worker::queue_lease)
+            });
+            return match lease {
+                Some(lease) => {
+                    self.lease_queue_item(queue_name, item_id, namespace, lease)
+                        .await
+                }
+                // The lease was removed from the runbook; leave the item to
+                // its handler
+                None => Ok(vec![]),
+            };
+        }
+
+        tracing::warn!(
+            queue = queue_name,
+            item = item_id,
+            "queue item lease expired without a live handler"
+        );
+        self.release_item_handlers(queue_name, namespace, item_id);
+        let mut result_events = self
+            .executor
+            .execute_all(vec![Effect::Emit {
+                event: Event::QueueLeaseExpired {
+                    queue_name: queue_name.to_string(),
+                    item_id: item_id.to_string(),
+                    namespace: namespace.to_string(),
+                },
+            }])
+            .await?;
+        // Same retry-or-dead decision as a failed handler
+        let worker_runbook = self
+            .worker_states
+            .lock()
+            .values()
+            .find(|w| w.namespace == namespace && w.sources.contains(queue_name))
+            .map(|w| w.runbook_hash.clone());
+        let runbook = worker_runbook
+            .or(queue_runbook)
+            .or_else(|| handlers.first().map(|h| h.runbook_hash().to_string()))
+            .and_then(|hash| self.cached_runbook(&hash).ok());
+        match runbook {
+            Some(runbook) => result_events.extend(
+                self.retry_or_dead_queue_item(&runbook, queue_name, item_id, namespace)
+                    .await?,
+            ),
+            None => tracing::warn!(
+                queue = queue_name,
+                item = item_id,
+                "no runbook for queue; leaving lease-expired item failed"
+            ),
+        }
+        result_events.extend(self.wake_queue_workers(queue_name, namespace).await?);
+        Ok(result_events)
+    }
+
+    /// Whether a job or agent run working a leased item is still alive: its
+    /// agent session and process are running, or it has no session (a shell
+    /// step, or an agent not spawned yet).
+    async fn item_handler_alive(&self, handler: &ItemHandler) -> bool {
+        let (session_id, process_name) = match handler {
+            ItemHandler::Job(job) => (
+                job.session_id.as_ref(),
+                self.cached_runbook(&job.runbook_hash).ok().and_then(|rb| {
+                    monitor::get_agent_def(&rb, job)
+                        .ok()
+                        .map(|def| oj_adapters::extract_process_name(&def.run))
+                }),
+            ),
+            ItemHandler::AgentRun(run) => (
+                run.session_id.as_ref(),
+                self.cached_runbook(&run.runbook_hash).ok().and_then(|rb| {
+                    rb.get_agent(&run.agent_name)
+                        .map(|def| oj_adapters::extract_process_name(&def.run))
+                }),
+            ),
+        };
+        let Some(session_id) = session_id else {
+            return true;
+        };
+        let process_name = process_name.unwrap_or_else(|| "claude".to_string());
+        self.executor.check_session_alive(session_id).await
+            && self
+                .executor
+                .check_process_running(session_id, &process_name)
+                .await
+    }
+
+    /// Drop a worker's in-memory tracking of the handlers dispatched for an
+    /// item, freeing their concurrency slots.
+    fn release_item_handlers(&self, queue_name: &str, namespace: &str, item_id: &str) {
+        let mut workers = self.worker_states.lock();
+        for state in workers.values_mut() {
+            if state.namespace != namespace || !state.sources.contains(queue_name) {
+                continue;
+            }
+            state.item_job_map.retain(|job_id, id| {
+                let held = id == item_id;
+                if held {
+                    state.active_jobs.remove(job_id);
+                }
+                !held
+            });
+            state.item_agent_run_map.retain(|ar_id, id| {
+                let held = id == item_id;
+                if held {
+                    state.active_agent_runs.remove(ar_id);
+                }
+                !held
+            });
+        }
+    }
+
+    /// Emit `WorkerWake` for every worker attached to a queue.
+    async fn wake_queue_workers(
+        &self,
+        queue_name: &str,
+        namespace: &str,
+    ) -> Result<Vec<Event>, RuntimeError> {
         let worker_names: Vec<String> = {
             let workers = self.worker_states.lock();
             workers
                 .iter()
-                .filter(|(_, state)| state.queue_name == queue_name && state.namespace == namespace)
+                .filter(|(_, state)| {
+                    state.sources.contains(queue_name) && state.namespace == namespace
+                })
                 .map(|(name, _)| name.clone())
                 .collect()
         };
 
+        let mut result_events = Vec::new();
         for worker_name in worker_names {
             // Strip namespace prefix from worker_name for the event
             let bare_name = if namespace.is_empty() {
@@ -194,13 +581,12 @@ where
                     .execute_all(vec![Effect::Emit {
                         event: Event::WorkerWake {
                             worker_name: bare_name,
-                            namespace: namespace.clone(),
+                            namespace: namespace.to_string(),
                         },
                     }])
                     .await?,
             );
         }
-
         Ok(result_events)
     }
 
@@ -654,3 +1040,18 @@ where
         .await
     }
 }
+
+/// A job or agent run working a leased queue item
+enum ItemHandler {
+    Job(Box<Job>),
+    AgentRun(Box<AgentRun>),
+}
+
+impl ItemHandler {
+    fn runbook_hash(&self) -> &str {
+        match self {
+            ItemHandler::Job(job) => &job.runbook_hash,
+            ItemHandler::AgentRun(run) => &run.runbook_hash,
+        }
+    }
+}
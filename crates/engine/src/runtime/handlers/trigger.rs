@@ -0,0 +1,375 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Event triggers: `on "<event>"` blocks that start jobs
+
+use super::super::Runtime;
+use super::watch::hash_runbook;
+use super::CreateJobParams;
+use crate::error::RuntimeError;
+use oj_adapters::{AgentAdapter, NotifyAdapter, SessionAdapter};
+use oj_core::{AgentSignalKind, Clock, Event, IdGen, Job, JobId, OwnerId, UuidIdGen};
+use oj_runbook::Runbook;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+/// How many triggered jobs can follow one another before triggers stop
+/// firing, so jobs that trigger each other in a cycle can't run forever
+pub(crate) const MAX_TRIGGER_DEPTH: u32 = 8;
+
+/// Runbooks with `on` blocks for one event, parsed once per change to the
+/// project's runbook files
+pub(crate) struct TriggerRunbooks {
+    /// Hash of the runbook files the runbooks were parsed from
+    sources_hash: String,
+    /// Each runbook with its content hash
+    runbooks: Arc<Vec<(String, Runbook)>>,
+}
+
+/// An event as seen by `on` blocks
+struct TriggerEvent {
+    /// Name used in `on "<name>"`
+    name: &'static str,
+    namespace: String,
+    /// Matched by `filter` and passed to the job as `event.*`
+    fields: HashMap<String, String>,
+    /// Vars of the job or queue item behind the event
+    vars: HashMap<String, String>,
+    /// Kind of the job behind the event, if any
+    source_kind: Option<String>,
+    /// Number of triggers that led to the job behind the event
+    depth: u32,
+}
+
+impl TriggerEvent {
+    fn new(name: &'static str, namespace: &str, fields: &[(&str, &str)]) -> Self {
+        Self {
+            name,
+            namespace: namespace.to_string(),
+            fields: fields
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            vars: HashMap::new(),
+            source_kind: None,
+            depth: 0,
+        }
+    }
+
+    /// Attach the job behind the event: its input vars pass through,
+    /// triggers won't start another job of its kind, and jobs they start
+    /// are one trigger deeper.
+    fn with_job(mut self, job: Option<&Job>) -> Self {
+        if let Some(job) = job {
+            self.vars = job
+                .vars
+                .iter()
+                .filter_map(|(k, v)| {
+                    let key = k.strip_prefix("var.").unwrap_or(k);
+                    (!key.contains('.')).then(|| (key.to_string(), v.clone()))
+                })
+                .collect();
+            self.source_kind = Some(job.kind.clone());
+            self.depth = job
+                .vars
+                .get("trigger.depth")
+                .and_then(|d| d.parse().ok())
+                .unwrap_or(0);
+        }
+        self
+    }
+}
+
+impl<S, A, N, C> Runtime<S, A, N, C>
+where
+    S: SessionAdapter,
+    A: AgentAdapter,
+    N: NotifyAdapter,
+    C: Clock,
+{
+    /// Start the jobs of every `on` block in the event's project that
+    /// subscribes to it and whose filter matches.
+    ///
+    /// A trigger that can't start its job is logged and skipped; it never
+    /// fails handling of the event itself.
+    pub(crate) async fn fire_triggers(&self, event: &Event) -> Result<Vec<Event>, RuntimeError> {
+        let Some(trigger_event) = self.trigger_event(event) else {
+            return Ok(vec![]);
+        };
+        let Some(project_root) =
+            self.lock_state(|state| state.project_root_for_namespace(&trigger_event.namespace))
+        else {
+            return Ok(vec![]);
+        };
+
+        let runbooks = match self.trigger_runbooks(&project_root, trigger_event.name) {
+            Ok(runbooks) => runbooks,
+            Err(e) => {
+                tracing::warn!(event = trigger_event.name, error = %e, "failed to load triggers");
+                return Ok(vec![]);
+            }
+        };
+
+        let mut result_events = Vec::new();
+        for (runbook_hash, runbook) in runbooks.iter() {
+            for trigger in runbook.triggers_for(trigger_event.name) {
+                let Some(job_kind) = trigger.run.job_name() else {
+                    continue;
+                };
+                if !trigger.matches(&trigger_event.fields) {
+                    continue;
+                }
+                // A job finishing must not start another of its own kind,
+                // or an unfiltered trigger would loop forever
+                if trigger_event.source_kind.as_deref() == Some(job_kind) {
+                    tracing::debug!(
+                        event = trigger_event.name,
+                        job = job_kind,
+                        "skipping trigger for a job of the same kind"
+                    );
+                    continue;
+                }
+                // Jobs triggering each other in a cycle stop after a while
+                if trigger_event.depth >= MAX_TRIGGER_DEPTH {
+                    tracing::warn!(
+                        event = trigger_event.name,
+                        job = job_kind,
+                        depth = trigger_event.depth,
+                        "skipping trigger: too many triggered jobs in a row"
+                    );
+                    continue;
+                }
+                match self
+                    .start_triggered_job(
+                        runbook,
+                        runbook_hash,
+                        job_kind,
+                        &trigger_event,
+                        &project_root,
+                    )
+                    .await
+                {
+                    Ok(events) => result_events.extend(events),
+                    Err(e) => tracing::warn!(
+                        event = trigger_event.name,
+                        job = job_kind,
+                        error = %e,
+                        "trigger failed to start job"
+                    ),
+                }
+            }
+        }
+        Ok(result_events)
+    }
+
+    /// Describe an event for `on` blocks, or `None` if no trigger can
+    /// subscribe to it.
+    fn trigger_event(&self, event: &Event) -> Option<TriggerEvent> {
+        match event {
+            Event::JobAdvanced { id, step } => {
+                let name = match step.as_str() {
+                    "done" => "job:completed",
+                    "failed" => "job:failed",
+                    _ => return None,
+                };
+                let job = self.get_job(id.as_str())?;
+                let error = job.error.clone().unwrap_or_default();
+                let mut fields = vec![
+                    ("id", job.id.as_str()),
+                    ("kind", job.kind.as_str()),
+                    ("name", job.name.as_str()),
+                ];
+                if name == "job:failed" {
+                    fields.push(("error", error.as_str()));
+                }
+                Some(TriggerEvent::new(name, &job.namespace, &fields).with_job(Some(&job)))
+            }
+            Event::QueueItemDead {
+                queue_name,
+                item_id,
+                namespace,
+            } => {
+                let data = self.lock_state(|state| {
+                    state
+                        .queue_items
+                        .get(&oj_core::scoped_name(namespace, queue_name))
+                        .and_then(|items| items.iter().find(|i| i.id == *item_id))
+                        .map(|i| i.data.clone())
+                        .unwrap_or_default()
+                });
+                let mut trigger_event = TriggerEvent::new(
+                    "queue:item_dead",
+                    namespace,
+                    &[("queue", queue_name), ("item", item_id)],
+                );
+                trigger_event.vars = data;
+                Some(trigger_event)
+            }
+            Event::DecisionCreated {
+                id,
+                job_id,
+                source,
+                namespace,
+                ..
+            } => {
+                let job = self.get_job(job_id.as_str());
+                let kind = job.as_ref().map(|j| j.kind.as_str()).unwrap_or_default();
+                let source = serde_json::to_value(source)
+                    .ok()
+                    .and_then(|v| v.as_str().map(str::to_string))
+                    .unwrap_or_default();
+                let fields = [
+                    ("id", id.as_str()),
+                    ("job", job_id.as_str()),
+                    ("kind", kind),
+                    ("source", source.as_str()),
+                ];
+                Some(
+                    TriggerEvent::new("decision:created", namespace, &fields)
+                        .with_job(job.as_ref()),
+                )
+            }
+            Event::AgentSignal {
+                agent_id,
+                kind: AgentSignalKind::Escalate,
+                message,
+            } => {
+                let (owner, namespace) = self.lock_state(|state| {
+                    state
+                        .agents
+                        .get(agent_id.as_str())
+                        .map(|a| (a.owner.clone(), a.namespace.clone()))
+                })?;
+                let job = match &owner {
+                    OwnerId::Job(job_id) => self.get_job(job_id.as_str()),
+                    OwnerId::AgentRun(_) => None,
+                };
+                let job_id = job.as_ref().map(|j| j.id.as_str()).unwrap_or_default();
+                let kind = job.as_ref().map(|j| j.kind.as_str()).unwrap_or_default();
+                let fields = [
+                    ("agent", agent_id.as_str()),
+                    ("job", job_id),
+                    ("kind", kind),
+                    ("message", message.as_deref().unwrap_or_default()),
+                ];
+                Some(TriggerEvent::new("agent:signal", &namespace, &fields).with_job(job.as_ref()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Runbooks in the project with `on` blocks for `event`, re-parsed only
+    /// when the project's runbook files change.
+    fn trigger_runbooks(
+        &self,
+        project_root: &Path,
+        event: &'static str,
+    ) -> Result<Arc<Vec<(String, Runbook)>>, RuntimeError> {
+        let runbook_dir = project_root.join(".oj/runbooks");
+        let sources = oj_runbook::read_runbook_sources(&runbook_dir)
+            .map_err(|e| RuntimeError::RunbookLoadError(e.to_string()))?;
+        let sources_hash = {
+            let mut hasher = Sha256::new();
+            for (path, content) in &sources {
+                hasher.update(path.to_string_lossy().as_bytes());
+                hasher.update([0]);
+                hasher.update(content.as_bytes());
+                hasher.update([0]);
+            }
+            format!("{:x}", hasher.finalize())
+        };
+
+        let key = (project_root.to_path_buf(), event);
+        if let Some(cached) = self.trigger_runbooks.lock().get(&key) {
+            if cached.sources_hash == sources_hash {
+                return Ok(Arc::clone(&cached.runbooks));
+            }
+        }
+
+        let runbooks = oj_runbook::collect_runbooks_with_trigger(&runbook_dir, event)
+            .map_err(|e| RuntimeError::RunbookLoadError(e.to_string()))?
+            .into_iter()
+            .map(|runbook| Ok((hash_runbook(&runbook)?.0, runbook)))
+            .collect::<Result<Vec<_>, RuntimeError>>()?;
+        let runbooks = Arc::new(runbooks);
+        self.trigger_runbooks.lock().insert(
+            key,
+            TriggerRunbooks {
+                sources_hash,
+                runbooks: Arc::clone(&runbooks),
+            },
+        );
+        Ok(runbooks)
+    }
+
+    async fn start_triggered_job(
+        &self,
+        runbook: &Runbook,
+        runbook_hash: &str,
+        job_kind: &str,
+        trigger_event: &TriggerEvent,
+        project_root: &Path,
+    ) -> Result<Vec<Event>, RuntimeError> {
+        let job_def = runbook
+            .get_job(job_kind)
+            .ok_or_else(|| RuntimeError::JobDefNotFound(job_kind.to_string()))?;
+
+        // Store the runbook in the WAL the first time a job uses it
+        let runbook_json = if self.runbook_cache.lock().contains_key(runbook_hash) {
+            None
+        } else {
+            Some(serde_json::to_value(runbook).map_err(|e| {
+                RuntimeError::RunbookLoadError(format!("failed to serialize runbook: {}", e))
+            })?)
+        };
+
+        // Vars the job declares pass through from the event's job or item
+        let mut vars: HashMap<String, String> = job_def
+            .vars
+            .iter()
+            .chain(job_def.defaults.keys())
+            .filter_map(|input| {
+                let value = trigger_event.vars.get(input)?;
+                Some((input.clone(), value.clone()))
+            })
+            .collect();
+        vars.extend(
+            trigger_event
+                .fields
+                .iter()
+                .map(|(k, v)| (format!("event.{}", k), v.clone())),
+        );
+        vars.insert("invoke.dir".to_string(), project_root.display().to_string());
+        vars.insert(
+            "trigger.depth".to_string(),
+            (trigger_event.depth + 1).to_string(),
+        );
+
+        let job_id = JobId::new(UuidIdGen.next());
+        let namespace = &trigger_event.namespace;
+        tracing::info!(
+            event = trigger_event.name,
+            job = job_kind,
+            job_id = %job_id,
+            "trigger starting job"
+        );
+        self.create_and_start_job(CreateJobParams {
+            job_id: job_id.clone(),
+            job_name: oj_runbook::job_display_name(job_kind, job_id.short(8), namespace),
+            job_kind: job_kind.to_string(),
+            vars,
+            runbook_hash: runbook_hash.to_string(),
+            runbook_json,
+            runbook: runbook.clone(),
+            namespace: namespace.clone(),
+            cron_name: None,
+            parent: None,
+            after: Vec::new(),
+            priority: 0,
+            project_root: Some(project_root.to_path_buf()),
+        })
+        .await
+    }
+}
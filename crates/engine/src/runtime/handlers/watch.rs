@@ -0,0 +1,297 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! File watch event handling
+
+use super::super::Runtime;
+use super::CreateJobParams;
+use crate::error::RuntimeError;
+use crate::log_paths::watch_log_path;
+use crate::time_fmt::format_utc_now;
+use oj_adapters::{AgentAdapter, NotifyAdapter, SessionAdapter};
+use oj_core::{scoped_name, Clock, Effect, Event, IdGen, JobId, UuidIdGen};
+use oj_runbook::Runbook;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// In-memory state for a started watch
+pub(crate) struct WatchState {
+    pub project_root: PathBuf,
+    pub runbook_hash: String,
+    /// Job the watch runs
+    pub job: String,
+    pub status: WatchStatus,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum WatchStatus {
+    Running,
+    Stopped,
+}
+
+/// Append a timestamped line to the watch log file.
+///
+/// Creates the `{logs_dir}/watch/` directory on first write.
+/// Errors are silently ignored — logging must not break the watch.
+fn append_watch_log(logs_dir: &Path, watch_name: &str, namespace: &str, message: &str) {
+    let path = watch_log_path(logs_dir, &scoped_name(namespace, watch_name));
+    if let Some(parent) = path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    if let Ok(mut f) = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+    {
+        use std::io::Write;
+        let _ = writeln!(f, "[{}] {}", format_utc_now(), message);
+    }
+}
+
+/// Parameters for handling a watch started event.
+pub(crate) struct WatchStartedParams<'a> {
+    pub watch_name: &'a str,
+    pub project_root: &'a Path,
+    pub runbook_hash: &'a str,
+    pub paths: &'a [String],
+    pub debounce: &'a str,
+    pub run_target: &'a str,
+    pub namespace: &'a str,
+}
+
+impl<S, A, N, C> Runtime<S, A, N, C>
+where
+    S: SessionAdapter,
+    A: AgentAdapter,
+    N: NotifyAdapter,
+    C: Clock,
+{
+    pub(crate) async fn handle_watch_started(
+        &self,
+        params: WatchStartedParams<'_>,
+    ) -> Result<Vec<Event>, RuntimeError> {
+        let WatchStartedParams {
+            watch_name,
+            project_root,
+            runbook_hash,
+            paths,
+            debounce,
+            run_target,
+            namespace,
+        } = params;
+        let debounce_duration = crate::monitor::parse_duration(debounce).map_err(|e| {
+            RuntimeError::InvalidFormat(format!("invalid watch debounce '{}': {}", debounce, e))
+        })?;
+        let job = run_target.strip_prefix("job:").unwrap_or(run_target);
+
+        self.watch_states.lock().insert(
+            scoped_name(namespace, watch_name),
+            WatchState {
+                project_root: project_root.to_path_buf(),
+                runbook_hash: runbook_hash.to_string(),
+                job: job.to_string(),
+                status: WatchStatus::Running,
+            },
+        );
+
+        let log =
+            |message: &str| append_watch_log(self.logger.log_dir(), watch_name, namespace, message);
+        // A watch that can't be set up (e.g. the project moved) is logged
+        // and left running, so `oj watch start` can retry it
+        match self
+            .executor
+            .execute(Effect::WatchFiles {
+                watch_name: watch_name.to_string(),
+                namespace: namespace.to_string(),
+                root: project_root.to_path_buf(),
+                paths: paths.to_vec(),
+                debounce: debounce_duration,
+            })
+            .await
+        {
+            Ok(_) => log(&format!(
+                "started (paths={}, debounce={}, job={})",
+                paths.join(","),
+                debounce,
+                job
+            )),
+            Err(e) => log(&format!("error: {}", e)),
+        }
+        Ok(vec![])
+    }
+
+    pub(crate) async fn handle_watch_stopped(
+        &self,
+        watch_name: &str,
+        namespace: &str,
+    ) -> Result<Vec<Event>, RuntimeError> {
+        if let Some(state) = self
+            .watch_states
+            .lock()
+            .get_mut(&scoped_name(namespace, watch_name))
+        {
+            state.status = WatchStatus::Stopped;
+        }
+
+        self.executor
+            .execute(Effect::UnwatchFiles {
+                watch_name: watch_name.to_string(),
+                namespace: namespace.to_string(),
+            })
+            .await?;
+
+        append_watch_log(self.logger.log_dir(), watch_name, namespace, "stopped");
+        Ok(vec![])
+    }
+
+    /// Start the watch's job for a settled burst of changed files.
+    pub(crate) async fn handle_watch_changed(
+        &self,
+        watch_name: &str,
+        namespace: &str,
+        files: &[String],
+    ) -> Result<Vec<Event>, RuntimeError> {
+        let key = scoped_name(namespace, watch_name);
+        let (project_root, runbook_hash, job) = {
+            let watches = self.watch_states.lock();
+            match watches.get(&key) {
+                Some(s) if s.status == WatchStatus::Running => (
+                    s.project_root.clone(),
+                    s.runbook_hash.clone(),
+                    s.job.clone(),
+                ),
+                _ => {
+                    tracing::debug!(watch = watch_name, "files changed but watch not running");
+                    return Ok(vec![]);
+                }
+            }
+        };
+        let log =
+            |message: &str| append_watch_log(self.logger.log_dir(), watch_name, namespace, message);
+
+        // Prefer the runbook on disk so edits apply without a restart
+        let (runbook, job) = match self.load_watch_runbook(&project_root, watch_name) {
+            Ok(loaded) => loaded,
+            Err(e) => {
+                log(&format!("error: {}; using the runbook it started with", e));
+                (self.cached_runbook(&runbook_hash)?, job)
+            }
+        };
+        let (runbook_hash, runbook_json) = hash_runbook(&runbook)?;
+        let already_cached = self.runbook_cache.lock().contains_key(&runbook_hash);
+        if let Some(state) = self.watch_states.lock().get_mut(&key) {
+            state.runbook_hash = runbook_hash.clone();
+            state.job = job.clone();
+        }
+
+        // Check concurrency before spawning
+        let concurrency = runbook
+            .get_watch(watch_name)
+            .and_then(|w| w.concurrency)
+            .unwrap_or(1);
+        let active = self.count_active_watch_jobs(watch_name, namespace);
+        if active >= concurrency as usize {
+            log(&format!(
+                "skip: {} changed but job '{}' at max concurrency ({}/{})",
+                describe_files(files),
+                job,
+                active,
+                concurrency
+            ));
+            return Ok(vec![]);
+        }
+
+        let mut vars = HashMap::new();
+        vars.insert("watch.name".to_string(), watch_name.to_string());
+        vars.insert("watch.files".to_string(), files.join(" "));
+        vars.insert("invoke.dir".to_string(), project_root.display().to_string());
+
+        let job_id = JobId::new(UuidIdGen.next());
+        let mut result_events = self
+            .create_and_start_job(CreateJobParams {
+                job_id: job_id.clone(),
+                job_name: oj_runbook::job_display_name(&job, job_id.short(8), namespace),
+                job_kind: job.clone(),
+                vars,
+                runbook_hash,
+                runbook_json: (!already_cached).then_some(runbook_json),
+                runbook,
+                namespace: namespace.to_string(),
+                cron_name: None,
+                parent: None,
+                after: Vec::new(),
+                priority: 0,
+                project_root: Some(project_root.clone()),
+            })
+            .await?;
+
+        log(&format!(
+            "{} changed: triggered job {} ({})",
+            describe_files(files),
+            job,
+            job_id.short(8)
+        ));
+
+        result_events.extend(
+            self.executor
+                .execute_all(vec![Effect::Emit {
+                    event: Event::WatchFired {
+                        watch_name: watch_name.to_string(),
+                        job_id,
+                        namespace: namespace.to_string(),
+                    },
+                }])
+                .await?,
+        );
+        Ok(result_events)
+    }
+
+    /// Load the runbook defining `watch_name` from the project, with the job
+    /// its watch runs.
+    fn load_watch_runbook(
+        &self,
+        project_root: &Path,
+        watch_name: &str,
+    ) -> Result<(Runbook, String), RuntimeError> {
+        let runbook_dir = project_root.join(".oj/runbooks");
+        let runbook = oj_runbook::find_runbook_by_watch(&runbook_dir, watch_name)
+            .map_err(|e| RuntimeError::RunbookLoadError(e.to_string()))?
+            .ok_or_else(|| {
+                RuntimeError::RunbookLoadError(format!(
+                    "no runbook found containing watch '{}'",
+                    watch_name
+                ))
+            })?;
+        let job = runbook
+            .get_watch(watch_name)
+            .and_then(|w| w.run.job_name())
+            .ok_or_else(|| {
+                RuntimeError::RunbookLoadError(format!("watch '{}' runs no job", watch_name))
+            })?
+            .to_string();
+        Ok((runbook, job))
+    }
+}
+
+/// Content hash and JSON form of a runbook, as stored in the WAL.
+pub(super) fn hash_runbook(runbook: &Runbook) -> Result<(String, serde_json::Value), RuntimeError> {
+    let runbook_json = serde_json::to_value(runbook).map_err(|e| {
+        RuntimeError::RunbookLoadError(format!("failed to serialize runbook: {}", e))
+    })?;
+    let canonical = serde_json::to_string(&runbook_json).map_err(|e| {
+        RuntimeError::RunbookLoadError(format!("failed to serialize runbook: {}", e))
+    })?;
+    Ok((
+        format!("{:x}", Sha256::digest(canonical.as_bytes())),
+        runbook_json,
+    ))
+}
+
+/// "docs/a.md", or "3 files" when more than one changed.
+fn describe_files(files: &[String]) -> String {
+    match files {
+        [file] => file.clone(),
+        _ => format!("{} files", files.len()),
+    }
+}
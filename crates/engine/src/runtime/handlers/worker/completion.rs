@@ -1,15 +1,44 @@
 // SPDX-License-Identifier: BUSL-1.1
 // Copyright (c) 2026 Alfred Jean LLC
 
-//! Job completion → queue item status updates
+//! Job and agent run completion → queue item status updates
 
-use super::WorkerStatus;
+use super::{WorkerState, WorkerStatus};
 use crate::error::RuntimeError;
 use crate::runtime::Runtime;
 use oj_adapters::{AgentAdapter, NotifyAdapter, SessionAdapter};
-use oj_core::{scoped_name, Clock, Effect, Event, JobId, TimerId};
-use oj_runbook::QueueType;
-use std::time::Duration;
+use oj_core::{scoped_name, AgentRunId, AgentRunStatus, Clock, Effect, Event, JobId, TimerId};
+use oj_runbook::{QueueType, Runbook};
+use std::path::PathBuf;
+
+/// A worker slot released by a finished job or agent run
+struct FinishedItem {
+    worker_name: String,
+    runbook_hash: String,
+    queue_names: Vec<String>,
+    project_root: PathBuf,
+    queue_type: QueueType,
+    item_id: Option<String>,
+    namespace: String,
+}
+
+impl FinishedItem {
+    fn new(worker_name: &str, state: &mut WorkerState, item_id: Option<String>) -> Self {
+        // Remove from inflight set so the item can be re-queued
+        if let Some(ref id) = item_id {
+            state.inflight_items.remove(id);
+        }
+        Self {
+            worker_name: worker_name.to_string(),
+            runbook_hash: state.runbook_hash.clone(),
+            queue_names: state.sources.names(),
+            project_root: state.project_root.clone(),
+            queue_type: state.queue_type,
+            item_id,
+            namespace: state.namespace.clone(),
+        }
+    }
+}
 
 impl<S, A, N, C> Runtime<S, A, N, C>
 where
@@ -26,198 +55,260 @@ where
         terminal_step: &str,
     ) -> Result<Vec<Event>, RuntimeError> {
         // Find which worker (if any) owns this job
-        let worker_info = {
+        let finished = {
             let mut workers = self.worker_states.lock();
-            let mut found = None;
-            for (name, state) in workers.iter_mut() {
-                if state.active_jobs.remove(job_id) {
-                    let item_id = state.item_job_map.remove(job_id);
-                    // Remove from inflight set so the item can be re-queued
-                    if let Some(ref id) = item_id {
-                        state.inflight_items.remove(id);
-                    }
-                    found = Some((
-                        name.clone(),
-                        state.runbook_hash.clone(),
-                        state.queue_name.clone(),
-                        state.project_root.clone(),
-                        state.queue_type,
-                        item_id,
-                        state.namespace.clone(),
-                    ));
-                    break;
+            workers.iter_mut().find_map(|(name, state)| {
+                if !state.active_jobs.remove(job_id) {
+                    return None;
                 }
-            }
-            found
+                let item_id = state.item_job_map.remove(job_id);
+                Some(FinishedItem::new(name, state, item_id))
+            })
+        };
+        let Some(finished) = finished else {
+            return Ok(vec![]);
         };
 
-        let mut result_events = Vec::new();
+        let error = (terminal_step != "done").then(|| format!("job reached '{}'", terminal_step));
+        self.finish_worker_item(
+            finished,
+            &format!("job {} completed (step={})", job_id.as_str(), terminal_step),
+            error,
+        )
+        .await
+    }
 
-        if let Some((
+    /// Agent-backed counterpart of [`Self::check_worker_job_complete`]: settle
+    /// the queue item once its agent run reaches a terminal status.
+    pub(crate) async fn check_worker_agent_run_complete(
+        &self,
+        agent_run_id: &AgentRunId,
+        status: &AgentRunStatus,
+    ) -> Result<Vec<Event>, RuntimeError> {
+        let finished = {
+            let mut workers = self.worker_states.lock();
+            workers.iter_mut().find_map(|(name, state)| {
+                if !state.active_agent_runs.remove(agent_run_id) {
+                    return None;
+                }
+                let item_id = state.item_agent_run_map.remove(agent_run_id);
+                Some(FinishedItem::new(name, state, item_id))
+            })
+        };
+        let Some(finished) = finished else {
+            return Ok(vec![]);
+        };
+
+        let error = (*status != AgentRunStatus::Completed).then(|| format!("agent run {}", status));
+        self.finish_worker_item(
+            finished,
+            &format!(
+                "agent run {} completed (status={})",
+                agent_run_id.short(8),
+                status
+            ),
+            error,
+        )
+        .await
+    }
+
+    /// Free a worker slot after its job or agent run finished: log it, emit
+    /// the queue item's completion or failure (with retry-or-dead), and
+    /// re-poll if the worker has capacity. `error` is None on success.
+    async fn finish_worker_item(
+        &self,
+        finished: FinishedItem,
+        summary: &str,
+        error: Option<String>,
+    ) -> Result<Vec<Event>, RuntimeError> {
+        let FinishedItem {
             worker_name,
-            _old_runbook_hash,
-            queue_name,
+            runbook_hash: old_runbook_hash,
+            queue_names,
             project_root,
             queue_type,
             item_id,
-            worker_namespace,
-        )) = worker_info
+            namespace: worker_namespace,
+        } = finished;
+        let queue_name =
+            self.item_source_queue(&queue_names, &worker_namespace, item_id.as_deref());
+        let mut result_events = Vec::new();
+
+        // Log completion
         {
-            // Log job completion
-            {
-                let workers = self.worker_states.lock();
-                let active = workers
-                    .get(&worker_name)
-                    .map(|s| s.active_jobs.len())
-                    .unwrap_or(0);
-                let concurrency = workers
-                    .get(&worker_name)
-                    .map(|s| s.concurrency)
-                    .unwrap_or(0);
-                let scoped = scoped_name(&worker_namespace, &worker_name);
-                self.worker_logger.append(
-                    &scoped,
-                    &format!(
-                        "job {} completed (step={}), active={}/{}",
-                        job_id.as_str(),
-                        terminal_step,
-                        active,
-                        concurrency,
-                    ),
+            let workers = self.worker_states.lock();
+            let active = workers
+                .get(&worker_name)
+                .map(|s| s.active_count())
+                .unwrap_or(0);
+            let concurrency = workers
+                .get(&worker_name)
+                .map(|s| s.concurrency)
+                .unwrap_or(0);
+            let scoped = scoped_name(&worker_namespace, &worker_name);
+            self.worker_logger.append(
+                &scoped,
+                &format!("{}, active={}/{}", summary, active, concurrency),
+            );
+        }
+
+        // Refresh runbook from disk so edits after `oj worker start` are picked up
+        if let Some(loaded_event) = self.refresh_worker_runbook(&worker_name)? {
+            result_events.push(loaded_event);
+        }
+        let runbook_hash = {
+            let workers = self.worker_states.lock();
+            workers
+                .get(&worker_name)
+                .map(|s| s.runbook_hash.clone())
+                .unwrap_or(old_runbook_hash)
+        };
+
+        // For persisted queues, emit queue completion/failure event
+        if queue_type == QueueType::Persisted {
+            if let Some(ref item_id) = item_id {
+                let queue_event = match &error {
+                    None => Event::QueueCompleted {
+                        queue_name: queue_name.clone(),
+                        item_id: item_id.clone(),
+                        namespace: worker_namespace.clone(),
+                        completed_at_epoch_ms: Some(self.clock().epoch_ms()),
+                    },
+                    Some(error) => Event::QueueFailed {
+                        queue_name: queue_name.clone(),
+                        item_id: item_id.clone(),
+                        error: error.clone(),
+                        namespace: worker_namespace.clone(),
+                    },
+                };
+                result_events.extend(
+                    self.executor
+                        .execute_all(vec![Effect::Emit { event: queue_event }])
+                        .await?,
                 );
-            }
 
-            // Refresh runbook from disk so edits after `oj worker start` are picked up
-            if let Some(loaded_event) = self.refresh_worker_runbook(&worker_name)? {
-                result_events.push(loaded_event);
-            }
-            let runbook_hash = {
-                let workers = self.worker_states.lock();
-                workers
-                    .get(&worker_name)
-                    .map(|s| s.runbook_hash.clone())
-                    .unwrap_or(_old_runbook_hash)
-            };
-
-            // For persisted queues, emit queue completion/failure event
-            if queue_type == QueueType::Persisted {
-                if let Some(ref item_id) = item_id {
-                    let queue_event = if terminal_step == "done" {
-                        Event::QueueCompleted {
-                            queue_name: queue_name.clone(),
-                            item_id: item_id.clone(),
-                            namespace: worker_namespace.clone(),
-                        }
-                    } else {
-                        Event::QueueFailed {
-                            queue_name: queue_name.clone(),
-                            item_id: item_id.clone(),
-                            error: format!("job reached '{}'", terminal_step),
-                            namespace: worker_namespace.clone(),
-                        }
-                    };
+                // Retry-or-dead logic: after QueueFailed is applied, check retry config
+                if error.is_some() {
+                    let runbook = self.cached_runbook(&runbook_hash)?;
                     result_events.extend(
-                        self.executor
-                            .execute_all(vec![Effect::Emit { event: queue_event }])
-                            .await?,
+                        self.retry_or_dead_queue_item(
+                            &runbook,
+                            &queue_name,
+                            item_id,
+                            &worker_namespace,
+                        )
+                        .await?,
                     );
-
-                    // Retry-or-dead logic: after QueueFailed is applied, check retry config
-                    if terminal_step != "done" {
-                        let scoped_queue = scoped_name(&worker_namespace, &queue_name);
-
-                        // Read failure_count from state (QueueFailed already incremented it)
-                        let failure_count = self.lock_state(|state| {
-                            state
-                                .queue_items
-                                .get(&scoped_queue)
-                                .and_then(|items| {
-                                    items
-                                        .iter()
-                                        .find(|i| i.id == *item_id)
-                                        .map(|i| i.failure_count)
-                                })
-                                .unwrap_or(0)
-                        });
-
-                        // Look up retry config from the runbook
-                        let runbook = self.cached_runbook(&runbook_hash)?;
-                        let retry_config = runbook
-                            .get_queue(&queue_name)
-                            .and_then(|q| q.retry.as_ref());
-
-                        let max_attempts = retry_config.map(|r| r.attempts).unwrap_or(0);
-
-                        if max_attempts > 0 && failure_count < max_attempts {
-                            // Schedule retry after cooldown
-                            let cooldown_str =
-                                retry_config.map(|r| r.cooldown.as_str()).unwrap_or("0s");
-                            let duration = crate::monitor::parse_duration(cooldown_str)
-                                .unwrap_or(Duration::ZERO);
-                            let timer_id = TimerId::queue_retry(&scoped_queue, item_id);
-                            self.executor
-                                .execute(Effect::SetTimer {
-                                    id: timer_id,
-                                    duration,
-                                })
-                                .await?;
-                        } else {
-                            // Mark as dead
-                            result_events.extend(
-                                self.executor
-                                    .execute_all(vec![Effect::Emit {
-                                        event: Event::QueueItemDead {
-                                            queue_name: queue_name.clone(),
-                                            item_id: item_id.clone(),
-                                            namespace: worker_namespace.clone(),
-                                        },
-                                    }])
-                                    .await?,
-                            );
-                        }
-                    }
                 }
             }
+        }
 
-            // Check if worker is still running and has capacity
-            let should_poll = {
-                let workers = self.worker_states.lock();
-                workers
-                    .get(&worker_name)
-                    .map(|s| {
-                        s.status == WorkerStatus::Running
-                            && (s.active_jobs.len() as u32) < s.concurrency
-                    })
-                    .unwrap_or(false)
-            };
-
-            if should_poll {
-                match queue_type {
-                    QueueType::External => {
-                        let runbook = self.cached_runbook(&runbook_hash)?;
-                        if let Some(queue_def) = runbook.get_queue(&queue_name) {
-                            result_events.extend(
-                                self.executor
-                                    .execute_all(vec![Effect::PollQueue {
-                                        worker_name,
-                                        list_command: queue_def.list.clone().unwrap_or_default(),
-                                        cwd: project_root,
-                                    }])
-                                    .await?,
-                            );
-                        }
-                    }
-                    QueueType::Persisted => {
-                        result_events.extend(self.poll_persisted_queue(
-                            &worker_name,
-                            &queue_name,
-                            &worker_namespace,
-                        )?);
+        // Check if worker is still running and has capacity
+        let should_poll = {
+            let workers = self.worker_states.lock();
+            workers
+                .get(&worker_name)
+                .map(|s| s.status == WorkerStatus::Running && s.active_count() < s.concurrency)
+                .unwrap_or(false)
+        };
+
+        if should_poll {
+            match queue_type {
+                QueueType::External => {
+                    let runbook = self.cached_runbook(&runbook_hash)?;
+                    if let Some(queue_def) = runbook.get_queue(&queue_name) {
+                        result_events.extend(
+                            self.executor
+                                .execute_all(vec![Effect::PollQueue {
+                                    worker_name,
+                                    list_command: queue_def.list.clone().unwrap_or_default(),
+                                    cwd: project_root,
+                                    timeout: super::queue_command_timeout(queue_def),
+                                }])
+                                .await?,
+                        );
                     }
                 }
+                QueueType::Persisted => {
+                    result_events.extend(self.poll_persisted_queue(
+                        &worker_name,
+                        &queue_names,
+                        &worker_namespace,
+                    )?);
+                }
             }
         }
 
         Ok(result_events)
     }
+
+    /// Settle a failed queue item by its queue's `retry` policy: schedule
+    /// another attempt after the backoff delay, or mark the item dead once its
+    /// attempts are used up. The failure must already be applied to state.
+    pub(crate) async fn retry_or_dead_queue_item(
+        &self,
+        runbook: &Runbook,
+        queue_name: &str,
+        item_id: &str,
+        namespace: &str,
+    ) -> Result<Vec<Event>, RuntimeError> {
+        let scoped_queue = scoped_name(namespace, queue_name);
+        let failure_count = self.lock_state(|state| {
+            state
+                .queue_items
+                .get(&scoped_queue)
+                .and_then(|items| items.iter().find(|i| i.id == item_id))
+                .map(|i| i.failure_count)
+                .unwrap_or(0)
+        });
+
+        let retry_config = runbook.get_queue(queue_name).and_then(|q| q.retry.as_ref());
+        let max_attempts = retry_config.map(|r| r.attempts).unwrap_or(0);
+
+        if let Some(retry) =
+            retry_config.filter(|_| max_attempts > 0 && failure_count < max_attempts)
+        {
+            let duration = crate::retry::next_retry_delay(retry, failure_count);
+            let timer_id = TimerId::queue_retry(&scoped_queue, item_id);
+            self.set_durable_timer(timer_id, duration).await
+        } else {
+            Ok(self
+                .executor
+                .execute_all(vec![Effect::Emit {
+                    event: Event::QueueItemDead {
+                        queue_name: queue_name.to_string(),
+                        item_id: item_id.to_string(),
+                        namespace: namespace.to_string(),
+                    },
+                }])
+                .await?)
+        }
+    }
+
+    /// Which of a worker's source queues holds `item_id`. Falls back to the
+    /// first queue, which is the only one unless the worker is weighted.
+    fn item_source_queue(
+        &self,
+        queue_names: &[String],
+        namespace: &str,
+        item_id: Option<&str>,
+    ) -> String {
+        if let (Some(item_id), [_, _, ..]) = (item_id, queue_names) {
+            let holder = self.lock_state(|state| {
+                queue_names
+                    .iter()
+                    .find(|queue_name| {
+                        state
+                            .queue_items
+                            .get(&scoped_name(namespace, queue_name))
+                            .is_some_and(|items| items.iter().any(|i| i.id == item_id))
+                    })
+                    .cloned()
+            });
+            if let Some(queue_name) = holder {
+                return queue_name;
+            }
+        }
+        queue_names.first().cloned().unwrap_or_default()
+    }
 }
@@ -1,17 +1,33 @@
 // SPDX-License-Identifier: BUSL-1.1
 // Copyright (c) 2026 Alfred Jean LLC
 
-//! Queue item dispatch: take items from queue and create jobs
+//! Queue item dispatch: take items from queue and create jobs or agent runs
 
 use super::WorkerStatus;
 use crate::error::RuntimeError;
+use crate::runtime::agent_run::SpawnAgentParams;
 use crate::runtime::handlers::CreateJobParams;
 use crate::runtime::Runtime;
 use oj_adapters::{AgentAdapter, NotifyAdapter, SessionAdapter};
-use oj_core::{scoped_name, Clock, Effect, Event, IdGen, JobId, UuidIdGen};
+use oj_core::{
+    scoped_name, AgentRunId, AgentRunStatus, Clock, Effect, Event, IdGen, JobId, TimerId, UuidIdGen,
+};
 use oj_runbook::QueueType;
 use oj_storage::QueueItemStatus;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::Path;
+use std::time::Duration;
+
+/// Parameters for dispatching a queue item to an agent-backed worker's agent.
+struct DispatchAgentParams<'a> {
+    worker_name: &'a str,
+    item_id: &'a str,
+    item: &'a serde_json::Value,
+    agent_name: &'a str,
+    runbook_hash: &'a str,
+    cwd: &'a Path,
+    namespace: &'a str,
+}
 
 impl<S, A, N, C> Runtime<S, A, N, C>
 where
@@ -32,14 +48,23 @@ where
             result_events.push(loaded_event);
         }
 
-        let (queue_type, take_template, cwd, available_slots, queue_name, worker_namespace) = {
+        let (
+            queue_type,
+            take_template,
+            take_timeout,
+            cwd,
+            available_slots,
+            rate_limited,
+            worker_namespace,
+            leases,
+        ) = {
             let mut workers = self.worker_states.lock();
             let state = match workers.get_mut(worker_name) {
                 Some(s) if s.status != WorkerStatus::Stopped => s,
                 _ => return Ok(result_events),
             };
 
-            let active = state.active_jobs.len() as u32 + state.pending_takes;
+            let active = state.active_count() + state.pending_takes;
             let available = state.concurrency.saturating_sub(active);
             if available == 0 || items.is_empty() {
                 let scoped = scoped_name(&state.namespace, worker_name);
@@ -51,39 +76,61 @@ where
                 return Ok(result_events);
             }
 
+            // A free slot still waits for a rate limit token
+            let tokens = state.rate_tokens(self.clock().epoch_ms());
+
             let queue_type = state.queue_type;
 
             let runbook = self.cached_runbook(&state.runbook_hash)?;
-            let queue_def = runbook.get_queue(&state.queue_name).ok_or_else(|| {
-                RuntimeError::WorkerNotFound(format!("queue '{}' not found", state.queue_name))
+            let queue_def = runbook.get_queue(state.sources.primary()).ok_or_else(|| {
+                RuntimeError::WorkerNotFound(format!(
+                    "queue '{}' not found",
+                    state.sources.primary()
+                ))
             })?;
+            let leases: HashMap<String, Duration> = state
+                .sources
+                .names()
+                .into_iter()
+                .filter_map(|name| {
+                    let lease = runbook.get_queue(&name).and_then(super::queue_lease)?;
+                    Some((name, lease))
+                })
+                .collect();
 
             state.status = WorkerStatus::Running;
 
             (
                 queue_type,
                 queue_def.take.clone(),
+                super::queue_command_timeout(queue_def),
                 state.project_root.clone(),
-                available as usize,
-                state.queue_name.clone(),
+                available.min(tokens) as usize,
+                tokens < available,
                 state.namespace.clone(),
+                leases,
             )
         };
 
+        if available_slots == 0 {
+            self.schedule_rate_wake(worker_name).await?;
+            return Ok(result_events);
+        }
+
         let mut dispatched_count = 0;
-        for item in items.iter() {
-            if dispatched_count >= available_slots {
-                break;
-            }
+        let items_left = match queue_type {
+            QueueType::External => {
+                for item in items.iter() {
+                    if dispatched_count >= available_slots {
+                        break;
+                    }
 
-            let item_id = item
-                .get("id")
-                .and_then(|v| v.as_str())
-                .unwrap_or("unknown")
-                .to_string();
+                    let item_id = item
+                        .get("id")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("unknown")
+                        .to_string();
 
-            match queue_type {
-                QueueType::External => {
                     // Guard against overlapping polls: skip items that already
                     // have an in-flight take command or an active job.
                     {
@@ -115,11 +162,13 @@ where
                     // Reserve concurrency slot and mark item as in-flight
                     // before firing the take command. The slot and inflight
                     // entry are released in handle_worker_take_complete.
+                    // The rate limit token is spent even if the take fails.
                     {
                         let mut workers = self.worker_states.lock();
                         if let Some(state) = workers.get_mut(worker_name) {
                             state.pending_takes += 1;
                             state.inflight_items.insert(item_id.clone());
+                            state.take_rate_token(self.clock().epoch_ms());
                         }
                     }
 
@@ -133,26 +182,43 @@ where
                             cwd: cwd.clone(),
                             item_id,
                             item: item.clone(),
+                            timeout: take_timeout,
                         })
                         .await?;
                     dispatched_count += 1;
                 }
-                QueueType::Persisted => {
-                    // Guard against stale WorkerPollComplete events: if multiple
-                    // polls run before any dispatches are processed, their payloads
-                    // overlap. Skip items that are no longer Pending to avoid
-                    // creating duplicate jobs for the same queue item.
-                    let scoped_queue = scoped_name(&worker_namespace, &queue_name);
-                    let still_pending = self.lock_state(|state| {
-                        state
-                            .queue_items
-                            .get(&scoped_queue)
-                            .and_then(|items| items.iter().find(|i| i.id == item_id))
-                            .map(|i| i.status == QueueItemStatus::Pending)
-                            .unwrap_or(false)
-                    });
-                    if !still_pending {
-                        continue;
+                items.len() > dispatched_count
+            }
+            QueueType::Persisted => {
+                let mut waiting = self.group_by_source(worker_name, &worker_namespace, items);
+                while dispatched_count < available_slots {
+                    // Take the next item from the source queue whose turn it
+                    // is, by weight
+                    let next = {
+                        let workers = self.worker_states.lock();
+                        workers
+                            .get(worker_name)
+                            .and_then(|state| state.sources.pick(|i| !waiting[i].1.is_empty()))
+                    };
+                    let Some(source) = next else {
+                        break;
+                    };
+                    let queue_name = waiting[source].0.clone();
+                    let Some(item) = waiting[source].1.pop_front() else {
+                        break;
+                    };
+                    let item_id = item
+                        .get("id")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("unknown")
+                        .to_string();
+
+                    {
+                        let mut workers = self.worker_states.lock();
+                        if let Some(state) = workers.get_mut(worker_name) {
+                            state.take_rate_token(self.clock().epoch_ms());
+                            state.sources.charge(source);
+                        }
                     }
 
                     // Emit queue:taken event via Effect::Emit
@@ -168,17 +234,143 @@ where
                             }])
                             .await?,
                     );
+                    if let Some(&lease) = leases.get(&queue_name) {
+                        result_events.extend(
+                            self.lease_queue_item(&queue_name, &item_id, &worker_namespace, lease)
+                                .await?,
+                        );
+                    }
 
                     // Dispatch job immediately for persisted queues
                     result_events.extend(self.dispatch_queue_item(worker_name, item).await?);
                     dispatched_count += 1;
                 }
+                waiting.iter().any(|(_, items)| !items.is_empty())
             }
+        };
+
+        // Items left behind for want of a token are picked up once one is back
+        if rate_limited && dispatched_count >= available_slots && items_left {
+            self.schedule_rate_wake(worker_name).await?;
         }
 
         Ok(result_events)
     }
 
+    /// Grant or renew an active item's lease, and arm the timer that checks
+    /// on its handler when the lease runs out.
+    pub(crate) async fn lease_queue_item(
+        &self,
+        queue_name: &str,
+        item_id: &str,
+        namespace: &str,
+        lease: Duration,
+    ) -> Result<Vec<Event>, RuntimeError> {
+        let expires_at_epoch_ms = self.clock().epoch_ms() + lease.as_millis() as u64;
+        let mut result_events = self
+            .executor
+            .execute_all(vec![Effect::Emit {
+                event: Event::QueueItemLeased {
+                    queue_name: queue_name.to_string(),
+                    item_id: item_id.to_string(),
+                    expires_at_epoch_ms,
+                    namespace: namespace.to_string(),
+                },
+            }])
+            .await?;
+        let scoped = scoped_name(namespace, queue_name);
+        result_events.extend(
+            self.set_durable_timer(TimerId::queue_lease(&scoped, item_id), lease)
+                .await?,
+        );
+        Ok(result_events)
+    }
+
+    /// Split polled items by the source queue holding them, keeping poll
+    /// order within each queue. Entries line up with the worker's sources.
+    ///
+    /// Items that are no longer Pending are dropped: if multiple polls run
+    /// before any dispatches are processed, their payloads overlap, and
+    /// dispatching those items again would create duplicate jobs.
+    fn group_by_source<'a>(
+        &self,
+        worker_name: &str,
+        namespace: &str,
+        items: &'a [serde_json::Value],
+    ) -> Vec<(String, VecDeque<&'a serde_json::Value>)> {
+        let queue_names = {
+            let workers = self.worker_states.lock();
+            workers
+                .get(worker_name)
+                .map(|s| s.sources.names())
+                .unwrap_or_default()
+        };
+        let pending: Vec<HashSet<String>> = self.lock_state(|state| {
+            queue_names
+                .iter()
+                .map(|queue_name| {
+                    state
+                        .queue_items
+                        .get(&scoped_name(namespace, queue_name))
+                        .map(|items| {
+                            items
+                                .iter()
+                                .filter(|i| i.status == QueueItemStatus::Pending)
+                                .map(|i| i.id.clone())
+                                .collect()
+                        })
+                        .unwrap_or_default()
+                })
+                .collect()
+        });
+
+        let mut waiting: Vec<(String, VecDeque<&serde_json::Value>)> = queue_names
+            .into_iter()
+            .map(|queue_name| (queue_name, VecDeque::new()))
+            .collect();
+        for item in items {
+            let Some(item_id) = item.get("id").and_then(|v| v.as_str()) else {
+                continue;
+            };
+            if let Some(source) = pending.iter().position(|ids| ids.contains(item_id)) {
+                waiting[source].1.push_back(item);
+            }
+        }
+        waiting
+    }
+
+    /// Wake a rate-limited worker when its next token is due.
+    async fn schedule_rate_wake(&self, worker_name: &str) -> Result<(), RuntimeError> {
+        let now_ms = self.clock().epoch_ms();
+        let (timer_id, next_ms) = {
+            let workers = self.worker_states.lock();
+            let Some(state) = workers.get(worker_name) else {
+                return Ok(());
+            };
+            let Some(rate) = state.rate else {
+                return Ok(());
+            };
+            let next_ms = rate.next_token_at(state.bucket_full_at_ms, now_ms);
+            let scoped = scoped_name(&state.namespace, worker_name);
+            self.worker_logger.append(
+                &scoped,
+                &format!(
+                    "rate limited (rate={}), next dispatch in {}",
+                    rate,
+                    oj_core::format_elapsed(next_ms.saturating_sub(now_ms).div_ceil(1000))
+                ),
+            );
+            (TimerId::worker_rate(worker_name, &state.namespace), next_ms)
+        };
+        self.executor
+            .execute(Effect::SetTimer {
+                id: timer_id,
+                duration: Duration::from_millis(next_ms.saturating_sub(now_ms).max(1)),
+            })
+            .await?;
+        Ok(())
+    }
+
     /// Handle a completed take command for an external queue item.
     ///
     /// On success (exit_code == 0), creates a job for the item.
@@ -260,7 +452,7 @@ where
             .unwrap_or("unknown")
             .to_string();
 
-        let (job_kind, runbook_hash, cwd, worker_namespace) = {
+        let (job_kind, agent_name, runbook_hash, cwd, worker_namespace) = {
             let workers = self.worker_states.lock();
             let state = match workers.get(worker_name) {
                 Some(s) if s.status != WorkerStatus::Stopped => s,
@@ -268,12 +460,27 @@ where
             };
             (
                 state.job_kind.clone(),
+                state.agent_name.clone(),
                 state.runbook_hash.clone(),
                 state.project_root.clone(),
                 state.namespace.clone(),
             )
         };
 
+        if let Some(agent_name) = agent_name {
+            return self
+                .dispatch_queue_item_to_agent(DispatchAgentParams {
+                    worker_name,
+                    item_id: &item_id,
+                    item,
+                    agent_name: &agent_name,
+                    runbook_hash: &runbook_hash,
+                    cwd: &cwd,
+                    namespace: &worker_namespace,
+                })
+                .await;
+        }
+
         // Create job for this item
         let job_id = JobId::new(UuidIdGen.next());
 
@@ -320,24 +527,31 @@ where
                 runbook,
                 namespace: worker_namespace.clone(),
                 cron_name: None,
+                parent: None,
+                after: Vec::new(),
+                priority: 0,
+                project_root: Some(cwd.clone()),
             })
             .await?,
         );
 
         // Track job in worker state and item-job mapping
-        {
+        let bucket_full_at_ms = {
             let mut workers = self.worker_states.lock();
-            if let Some(state) = workers.get_mut(worker_name) {
+            workers.get_mut(worker_name).and_then(|state| {
                 state.active_jobs.insert(job_id.clone());
                 state.item_job_map.insert(job_id.clone(), item_id.clone());
-            }
-        }
+                state.rate_bucket()
+            })
+        };
 
         // Emit WorkerItemDispatched
         let dispatch_event = Event::WorkerItemDispatched {
             worker_name: worker_name.to_string(),
             item_id: item_id.clone(),
             job_id: job_id.clone(),
+            agent_run_id: None,
+            bucket_full_at_ms,
             namespace: worker_namespace.clone(),
         };
         result_events.extend(
@@ -360,4 +574,127 @@ where
 
         Ok(result_events)
     }
+
+    /// Start a standalone agent run for a queue item, for workers with
+    /// `handler = { agent = "..." }`. The item settles when the run reaches a
+    /// terminal status (see `check_worker_agent_run_complete`).
+    async fn dispatch_queue_item_to_agent(
+        &self,
+        params: DispatchAgentParams<'_>,
+    ) -> Result<Vec<Event>, RuntimeError> {
+        let DispatchAgentParams {
+            worker_name,
+            item_id,
+            item,
+            agent_name,
+            runbook_hash,
+            cwd,
+            namespace,
+        } = params;
+        let runbook = self.cached_runbook(runbook_hash)?;
+        let agent_def = runbook
+            .get_agent(agent_name)
+            .ok_or_else(|| RuntimeError::AgentNotFound(agent_name.to_string()))?
+            .clone();
+
+        // Item fields are exposed to the prompt as ${item.*}
+        let mut input = HashMap::new();
+        input.insert("invoke.dir".to_string(), cwd.display().to_string());
+        if let Some(obj) = item.as_object() {
+            for (key, value) in obj {
+                let v = match value.as_str() {
+                    Some(s) => s.to_string(),
+                    None => value.to_string(),
+                };
+                input.insert(format!("item.{}", key), v);
+            }
+        }
+
+        let agent_run_id = AgentRunId::new(UuidIdGen.next());
+
+        // Track the run before spawning so a spawn failure settles the item
+        let bucket_full_at_ms = {
+            let mut workers = self.worker_states.lock();
+            workers.get_mut(worker_name).and_then(|state| {
+                state.active_agent_runs.insert(agent_run_id.clone());
+                state
+                    .item_agent_run_map
+                    .insert(agent_run_id.clone(), item_id.to_string());
+                state.rate_bucket()
+            })
+        };
+
+        let mut result_events = self
+            .executor
+            .execute_all(vec![
+                Effect::Emit {
+                    event: Event::AgentRunCreated {
+                        id: agent_run_id.clone(),
+                        agent_name: agent_name.to_string(),
+                        command_name: format!("worker:{}", worker_name),
+                        namespace: namespace.to_string(),
+                        cwd: cwd.to_path_buf(),
+                        runbook_hash: runbook_hash.to_string(),
+                        vars: input.clone(),
+                        created_at_epoch_ms: self.clock().epoch_ms(),
+                    },
+                },
+                Effect::Emit {
+                    event: Event::WorkerItemDispatched {
+                        worker_name: worker_name.to_string(),
+                        item_id: item_id.to_string(),
+                        job_id: JobId::new(""),
+                        agent_run_id: Some(agent_run_id.as_str().to_string()),
+                        bucket_full_at_ms,
+                        namespace: namespace.to_string(),
+                    },
+                },
+            ])
+            .await?;
+
+        let scoped = scoped_name(namespace, worker_name);
+        let spawned = self
+            .spawn_standalone_agent(SpawnAgentParams {
+                agent_run_id: &agent_run_id,
+                agent_def: &agent_def,
+                agent_name,
+                input: &input,
+                cwd,
+                namespace,
+                resume_session_id: None,
+            })
+            .await;
+        match spawned {
+            Ok(events) => {
+                result_events.extend(events);
+                self.worker_logger.append(
+                    &scoped,
+                    &format!(
+                        "dispatched item {} \u{2192} agent {} ({})",
+                        item_id,
+                        agent_name,
+                        agent_run_id.short(8)
+                    ),
+                );
+            }
+            Err(e) => {
+                // The run is already marked failed in state; record it in the
+                // WAL so the item is failed (and retried) like a failed job
+                self.worker_logger.append(
+                    &scoped,
+                    &format!(
+                        "error: agent {} failed to start for item {}: {}",
+                        agent_name, item_id, e
+                    ),
+                );
+                result_events.push(Event::AgentRunStatusChanged {
+                    id: agent_run_id,
+                    status: AgentRunStatus::Failed,
+                    reason: Some(e.to_string()),
+                });
+            }
+        }
+
+        Ok(result_events)
+    }
 }
@@ -3,16 +3,15 @@
 
 //! Worker start/stop lifecycle handling
 
-use super::{WorkerState, WorkerStatus};
+use super::{SourceQueues, WorkerState, WorkerStatus};
 use crate::error::RuntimeError;
 use crate::runtime::Runtime;
 use oj_adapters::{AgentAdapter, NotifyAdapter, SessionAdapter};
-use oj_core::{scoped_name, Clock, Effect, Event, JobId, TimerId};
+use oj_core::{scoped_name, AgentRunId, AgentRunStatus, Clock, Effect, Event, JobId, TimerId};
 use oj_runbook::QueueType;
 use oj_storage::QueueItemStatus;
 use std::collections::{HashMap, HashSet};
 use std::path::Path;
-use std::time::Duration;
 
 impl<S, A, N, C> Runtime<S, A, N, C>
 where
@@ -26,6 +25,7 @@ where
         worker_name: &str,
         project_root: &Path,
         runbook_hash: &str,
+        rate: Option<&str>,
         namespace: &str,
     ) -> Result<Vec<Event>, RuntimeError> {
         // Load runbook to get worker definition
@@ -34,23 +34,49 @@ where
             .get_worker(worker_name)
             .ok_or_else(|| RuntimeError::WorkerNotFound(worker_name.to_string()))?;
 
-        let queue_def = runbook.get_queue(&worker_def.source.queue).ok_or_else(|| {
-            RuntimeError::WorkerNotFound(format!(
-                "queue '{}' not found for worker '{}'",
-                worker_def.source.queue, worker_name
-            ))
+        let sources = SourceQueues::new(worker_def.source.weighted());
+        for queue_name in sources.names() {
+            if runbook.get_queue(&queue_name).is_none() {
+                return Err(RuntimeError::WorkerNotFound(format!(
+                    "queue '{}' not found for worker '{}'",
+                    queue_name, worker_name
+                )));
+            }
+        }
+        // Weighted sources are all persisted queues, so the first queue's
+        // type and poll settings stand for all of them
+        let queue_def = runbook.get_queue(sources.primary()).ok_or_else(|| {
+            RuntimeError::WorkerNotFound(format!("worker '{}' has no source queue", worker_name))
         })?;
 
         let queue_type = queue_def.queue_type;
 
-        // Restore active jobs from persisted state (survives daemon restart)
-        let (persisted_active, persisted_item_map, persisted_inflight) = self.lock_state(|state| {
+        // Restore active jobs and agent runs from persisted state (survives
+        // daemon restart)
+        let (
+            persisted_active,
+            persisted_item_map,
+            persisted_agent_runs,
+            persisted_agent_item_map,
+            persisted_inflight,
+            persisted_bucket,
+        ) = self.lock_state(|state| {
             let scoped = scoped_name(namespace, worker_name);
+            let bucket = state
+                .workers
+                .get(&scoped)
+                .and_then(|w| w.bucket_full_at_ms)
+                .unwrap_or(0);
             let active: HashSet<JobId> = state
                 .workers
                 .get(&scoped)
                 .map(|w| w.active_job_ids.iter().map(JobId::new).collect())
                 .unwrap_or_default();
+            let agent_runs: HashSet<AgentRunId> = state
+                .workers
+                .get(&scoped)
+                .map(|w| w.active_agent_run_ids.iter().map(AgentRunId::new).collect())
+                .unwrap_or_default();
 
             // Build job→item map from persisted job vars
             let item_map: HashMap<JobId, String> = active
@@ -63,30 +89,68 @@ where
                         .map(|item_id| (pid.clone(), item_id.clone()))
                 })
                 .collect();
+            let agent_item_map: HashMap<AgentRunId, String> = agent_runs
+                .iter()
+                .filter_map(|ar_id| {
+                    state
+                        .agent_runs
+                        .get(ar_id.as_str())
+                        .and_then(|run| run.vars.get("item.id"))
+                        .map(|item_id| (ar_id.clone(), item_id.clone()))
+                })
+                .collect();
 
             // For external queues, restore inflight item IDs so overlapping
             // polls after restart don't re-dispatch already-active items.
             let inflight: HashSet<String> = if queue_type == QueueType::External {
-                item_map.values().cloned().collect()
+                item_map
+                    .values()
+                    .chain(agent_item_map.values())
+                    .cloned()
+                    .collect()
             } else {
                 HashSet::new()
             };
 
-            (active, item_map, inflight)
+            (
+                active,
+                item_map,
+                agent_runs,
+                agent_item_map,
+                inflight,
+                bucket,
+            )
         });
 
+        // The event carries the rate rather than the runbook so a rate set
+        // with `oj worker resize --rate` survives a daemon restart
+        let rate = rate
+            .map(|r| {
+                oj_core::DispatchRate::parse(r).map_err(|e| {
+                    RuntimeError::InvalidFormat(format!("worker '{}': {}", worker_name, e))
+                })
+            })
+            .transpose()?;
+
         // Store worker state
         let poll_interval = queue_def.poll.clone();
+        let source_label = sources.to_string();
+        let queue_names = sources.names();
         let state = WorkerState {
             project_root: project_root.to_path_buf(),
             runbook_hash: runbook_hash.to_string(),
-            queue_name: worker_def.source.queue.clone(),
+            sources,
             job_kind: worker_def.handler.job.clone(),
+            agent_name: worker_def.handler.agent.clone(),
             concurrency: worker_def.concurrency,
+            rate,
+            bucket_full_at_ms: persisted_bucket,
             active_jobs: persisted_active,
             status: WorkerStatus::Running,
             queue_type,
             item_job_map: persisted_item_map,
+            active_agent_runs: persisted_agent_runs,
+            item_agent_run_map: persisted_agent_item_map,
             namespace: namespace.to_string(),
             poll_interval: poll_interval.clone(),
             pending_takes: 0,
@@ -99,11 +163,12 @@ where
         }
 
         let scoped = scoped_name(namespace, worker_name);
+        let rate_note = rate.map(|r| format!(", rate={}", r)).unwrap_or_default();
         self.worker_logger.append(
             &scoped,
             &format!(
-                "started (queue={}, concurrency={})",
-                worker_def.source.queue, worker_def.concurrency
+                "started (queue={}, concurrency={}{})",
+                source_label, worker_def.concurrency, rate_note
             ),
         );
 
@@ -128,6 +193,7 @@ where
                         worker_name: worker_name.to_string(),
                         list_command,
                         cwd: project_root.to_path_buf(),
+                        timeout: super::queue_command_timeout(queue_def),
                     }])
                     .await?;
 
@@ -150,9 +216,7 @@ where
 
                 Ok(events)
             }
-            QueueType::Persisted => {
-                self.poll_persisted_queue(worker_name, &worker_def.source.queue, namespace)
-            }
+            QueueType::Persisted => self.poll_persisted_queue(worker_name, &queue_names, namespace),
         }
     }
 
@@ -187,22 +251,33 @@ where
         &self,
         worker_name: &str,
         new_concurrency: u32,
+        new_rate: Option<&str>,
         namespace: &str,
     ) -> Result<Vec<Event>, RuntimeError> {
-        let (old_concurrency, should_poll) = {
+        // The daemon validated the rate before emitting the event
+        let new_rate = new_rate.and_then(|r| oj_core::DispatchRate::parse(r).ok());
+        let now_ms = self.clock().epoch_ms();
+        let (old_concurrency, old_rate, should_poll) = {
             let mut workers = self.worker_states.lock();
             match workers.get_mut(worker_name) {
                 Some(state) if state.status == WorkerStatus::Running => {
                     let old = state.concurrency;
+                    let old_rate = state.rate;
                     state.concurrency = new_concurrency;
+                    state.rate = new_rate;
+                    if let Some(rate) = new_rate {
+                        state.bucket_full_at_ms = rate.clamp(state.bucket_full_at_ms, now_ms);
+                    }
 
                     // Check if we now have more slots available
-                    let active = state.active_jobs.len() as u32 + state.pending_takes;
+                    let active = state.active_count() + state.pending_takes;
                     let had_capacity = old > active;
                     let has_capacity = new_concurrency > active;
-                    let should_poll = !had_capacity && has_capacity;
+                    // A changed rate may have freed tokens; a rate-limited
+                    // worker otherwise waits for its timer
+                    let should_poll = (!had_capacity || old_rate != new_rate) && has_capacity;
 
-                    (old, should_poll)
+                    (old, old_rate, should_poll)
                 }
                 _ => return Ok(vec![]),
             }
@@ -217,6 +292,16 @@ where
                 old_concurrency, new_concurrency
             ),
         );
+        if old_rate != new_rate {
+            let show = |rate: Option<oj_core::DispatchRate>| {
+                rate.map(|r| r.to_string())
+                    .unwrap_or_else(|| "none".to_string())
+            };
+            self.worker_logger.append(
+                &scoped,
+                &format!("rate {} → {}", show(old_rate), show(new_rate)),
+            );
+        }
 
         // If we went from full to having capacity, trigger re-poll
         if should_poll {
@@ -226,11 +311,12 @@ where
         Ok(vec![])
     }
 
-    /// Reconcile active jobs after daemon recovery.
+    /// Reconcile active jobs and agent runs after daemon recovery.
     ///
-    /// Checks if any jobs in the worker's active set have already reached
-    /// terminal state, and calls `check_worker_job_complete` to emit the
-    /// missing queue events and free the worker slot.
+    /// Checks if any jobs (or agent runs) in the worker's active set have
+    /// already reached terminal state, and calls `check_worker_job_complete`
+    /// (or `check_worker_agent_run_complete`) to emit the missing queue events
+    /// and free the worker slot.
     ///
     /// Runs for ALL queue types (external and persisted).
     async fn reconcile_active_jobs(&self, worker_name: &str) -> Result<(), RuntimeError> {
@@ -264,6 +350,36 @@ where
             let _ = self.check_worker_job_complete(&pid, &terminal_step).await;
         }
 
+        let active_runs: Vec<AgentRunId> = {
+            let workers = self.worker_states.lock();
+            workers
+                .get(worker_name)
+                .map(|s| s.active_agent_runs.iter().cloned().collect())
+                .unwrap_or_default()
+        };
+        let terminal_runs: Vec<(AgentRunId, AgentRunStatus)> = self.lock_state(|state| {
+            active_runs
+                .iter()
+                .filter_map(|ar_id| {
+                    state
+                        .agent_runs
+                        .get(ar_id.as_str())
+                        .filter(|run| run.is_terminal())
+                        .map(|run| (ar_id.clone(), run.status.clone()))
+                })
+                .collect()
+        });
+
+        for (ar_id, status) in terminal_runs {
+            tracing::info!(
+                worker = worker_name,
+                agent_run = ar_id.as_str(),
+                %status,
+                "reconciling terminal agent run for worker slot"
+            );
+            let _ = self.check_worker_agent_run_complete(&ar_id, &status).await;
+        }
+
         Ok(())
     }
 
@@ -280,26 +396,49 @@ where
         namespace: &str,
         runbook: &oj_runbook::Runbook,
     ) -> Result<(), RuntimeError> {
-        // 1. Find and track active queue items with running jobs not in worker's active list
-        let queue_name = {
+        let queue_names = {
             let workers = self.worker_states.lock();
             workers
                 .get(worker_name)
-                .map(|s| s.queue_name.clone())
+                .map(|s| s.sources.names())
                 .unwrap_or_default()
         };
-        let scoped_queue = scoped_name(namespace, &queue_name);
+        for queue_name in queue_names {
+            self.reconcile_source_queue(worker_name, &queue_name, namespace, runbook)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Reconcile the worker's items in one of its source queues.
+    async fn reconcile_source_queue(
+        &self,
+        worker_name: &str,
+        queue_name: &str,
+        namespace: &str,
+        runbook: &oj_runbook::Runbook,
+    ) -> Result<(), RuntimeError> {
+        // 1. Find and track active queue items with running jobs not in worker's active list
+        let scoped_queue = scoped_name(namespace, queue_name);
         let mapped_item_ids: HashSet<String> = {
             let workers = self.worker_states.lock();
             workers
                 .get(worker_name)
-                .map(|s| s.item_job_map.values().cloned().collect())
+                .map(|s| {
+                    s.item_job_map
+                        .values()
+                        .chain(s.item_agent_run_map.values())
+                        .cloned()
+                        .collect()
+                })
                 .unwrap_or_default()
         };
 
         // Find Active queue items assigned to this worker but not in item_job_map,
-        // then check if a corresponding job exists (by searching for item.id var match)
-        let untracked_items: Vec<(String, JobId)> = self.lock_state(|state| {
+        // then check if a corresponding job or agent run exists (by searching
+        // for item.id var match)
+        let dispatched_by = format!("worker:{}", worker_name);
+        let (untracked_items, untracked_runs) = self.lock_state(|state| {
             let active_items: Vec<String> = state
                 .queue_items
                 .get(&scoped_queue)
@@ -316,19 +455,24 @@ where
                 })
                 .unwrap_or_default();
 
-            // For each active item, look for a job with matching item.id
-            active_items
-                .into_iter()
-                .filter_map(|item_id| {
-                    state
-                        .jobs
-                        .iter()
-                        .find(|(_, job)| {
-                            job.vars.get("item.id") == Some(&item_id) && !job.is_terminal()
-                        })
-                        .map(|(job_id, _)| (item_id, JobId::new(job_id.clone())))
-                })
-                .collect()
+            // For each active item, look for a job (or, for agent-backed
+            // workers, an agent run) with matching item.id
+            let mut jobs: Vec<(String, JobId)> = Vec::new();
+            let mut runs: Vec<(String, AgentRunId)> = Vec::new();
+            for item_id in active_items {
+                if let Some((job_id, _)) = state.jobs.iter().find(|(_, job)| {
+                    job.vars.get("item.id") == Some(&item_id) && !job.is_terminal()
+                }) {
+                    jobs.push((item_id, JobId::new(job_id.clone())));
+                } else if let Some((ar_id, _)) = state.agent_runs.iter().find(|(_, run)| {
+                    run.command_name == dispatched_by
+                        && run.vars.get("item.id") == Some(&item_id)
+                        && !run.is_terminal()
+                }) {
+                    runs.push((item_id, AgentRunId::new(ar_id.clone())));
+                }
+            }
+            (jobs, runs)
         });
 
         // Add untracked jobs to worker's active list
@@ -355,6 +499,37 @@ where
                         worker_name: worker_name.to_string(),
                         item_id,
                         job_id,
+                        agent_run_id: None,
+                        bucket_full_at_ms: None,
+                        namespace: namespace.to_string(),
+                    },
+                }])
+                .await?;
+        }
+        for (item_id, ar_id) in untracked_runs {
+            tracing::info!(
+                worker = worker_name,
+                item_id = item_id.as_str(),
+                agent_run = ar_id.as_str(),
+                "reconciling untracked agent run for active queue item"
+            );
+            {
+                let mut workers = self.worker_states.lock();
+                if let Some(state) = workers.get_mut(worker_name) {
+                    state.active_agent_runs.insert(ar_id.clone());
+                    state
+                        .item_agent_run_map
+                        .insert(ar_id.clone(), item_id.clone());
+                }
+            }
+            self.executor
+                .execute_all(vec![Effect::Emit {
+                    event: Event::WorkerItemDispatched {
+                        worker_name: worker_name.to_string(),
+                        item_id,
+                        job_id: JobId::new(""),
+                        agent_run_id: Some(ar_id.as_str().to_string()),
+                        bucket_full_at_ms: None,
                         namespace: namespace.to_string(),
                     },
                 }])
@@ -367,7 +542,13 @@ where
             let workers = self.worker_states.lock();
             workers
                 .get(worker_name)
-                .map(|s| s.item_job_map.values().cloned().collect())
+                .map(|s| {
+                    s.item_job_map
+                        .values()
+                        .chain(s.item_agent_run_map.values())
+                        .cloned()
+                        .collect()
+                })
                 .unwrap_or_default()
         };
 
@@ -399,7 +580,7 @@ where
             self.executor
                 .execute_all(vec![Effect::Emit {
                     event: Event::QueueFailed {
-                        queue_name: queue_name.clone(),
+                        queue_name: queue_name.to_string(),
                         item_id: item_id.clone(),
                         error: "job lost during daemon recovery".to_string(),
                         namespace: namespace.to_string(),
@@ -408,42 +589,8 @@ where
                 .await?;
 
             // Apply retry-or-dead logic
-            let failure_count = self.lock_state(|state| {
-                state
-                    .queue_items
-                    .get(&scoped_queue)
-                    .and_then(|items| items.iter().find(|i| i.id == item_id))
-                    .map(|i| i.failure_count)
-                    .unwrap_or(0)
-            });
-
-            let retry_config = runbook
-                .get_queue(&queue_name)
-                .and_then(|q| q.retry.as_ref());
-            let max_attempts = retry_config.map(|r| r.attempts).unwrap_or(0);
-
-            if max_attempts > 0 && failure_count < max_attempts {
-                let cooldown_str = retry_config.map(|r| r.cooldown.as_str()).unwrap_or("0s");
-                let duration =
-                    crate::monitor::parse_duration(cooldown_str).unwrap_or(Duration::ZERO);
-                let timer_id = TimerId::queue_retry(&scoped_queue, &item_id);
-                self.executor
-                    .execute(Effect::SetTimer {
-                        id: timer_id,
-                        duration,
-                    })
-                    .await?;
-            } else {
-                self.executor
-                    .execute_all(vec![Effect::Emit {
-                        event: Event::QueueItemDead {
-                            queue_name: queue_name.clone(),
-                            item_id,
-                            namespace: namespace.to_string(),
-                        },
-                    }])
-                    .await?;
-            }
+            self.retry_or_dead_queue_item(runbook, queue_name, &item_id, namespace)
+                .await?;
         }
 
         Ok(())
@@ -134,7 +134,9 @@ async fn start_worker(ctx: &TestContext, runbook: &str, namespace: &str) {
             project_root: ctx.project_root.clone(),
             runbook_hash: hash,
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency: 2,
+            rate: None,
             namespace: namespace.to_string(),
         })
         .await
@@ -153,7 +155,7 @@ async fn started_external_queue_sets_running_state() {
     let workers = ctx.runtime.worker_states.lock();
     let state = workers.get("fixer").unwrap();
     assert_eq!(state.status, WorkerStatus::Running);
-    assert_eq!(state.queue_name, "bugs");
+    assert_eq!(state.sources.primary(), "bugs");
     assert_eq!(state.job_kind, "build");
     assert_eq!(state.concurrency, 2);
     assert!(state.active_jobs.is_empty());
@@ -176,6 +178,11 @@ async fn started_persisted_queue_polls_immediately() {
             },
             pushed_at_epoch_ms: 1000,
             namespace: String::new(),
+            priority: 0,
+            visible_after_epoch_ms: None,
+            dedup_key: None,
+            expires_at_epoch_ms: None,
+            drop_on_expire: false,
         });
     });
 
@@ -187,7 +194,9 @@ async fn started_persisted_queue_polls_immediately() {
             project_root: ctx.project_root.clone(),
             runbook_hash: hash,
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency: 2,
+            rate: None,
             namespace: String::new(),
         })
         .await
@@ -211,7 +220,9 @@ async fn started_external_queue_with_poll_sets_timer() {
             project_root: ctx.project_root.clone(),
             runbook_hash: hash,
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency: 1,
+            rate: None,
             namespace: String::new(),
         })
         .await
@@ -239,7 +250,9 @@ async fn started_error_worker_not_in_runbook() {
             project_root: ctx.project_root.clone(),
             runbook_hash: hash,
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency: 1,
+            rate: None,
             namespace: String::new(),
         })
         .await;
@@ -259,7 +272,9 @@ async fn started_restores_inflight_items_for_external_queue() {
             project_root: ctx.project_root.clone(),
             runbook_hash: hash.clone(),
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency: 2,
+            rate: None,
             namespace: String::new(),
         });
         // Simulate a dispatched job with an item.id var
@@ -267,6 +282,8 @@ async fn started_restores_inflight_items_for_external_queue() {
             worker_name: "fixer".to_string(),
             item_id: "ext-item-1".to_string(),
             job_id: JobId::new("pipe-ext"),
+            agent_run_id: None,
+            bucket_full_at_ms: None,
             namespace: String::new(),
         });
         // Also need a job record with the item.id var
@@ -285,6 +302,9 @@ async fn started_restores_inflight_items_for_external_queue() {
             created_at_epoch_ms: 1000,
             namespace: String::new(),
             cron_name: None,
+            after: vec![],
+            priority: 0,
+            project_root: None,
         });
     });
 
@@ -294,7 +314,9 @@ async fn started_restores_inflight_items_for_external_queue() {
             project_root: ctx.project_root.clone(),
             runbook_hash: hash,
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency: 2,
+            rate: None,
             namespace: String::new(),
         })
         .await
@@ -372,7 +394,9 @@ async fn stopped_cancels_poll_timer() {
             project_root: ctx.project_root.clone(),
             runbook_hash: hash,
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency: 1,
+            rate: None,
             namespace: String::new(),
         })
         .await
@@ -416,6 +440,7 @@ async fn resized_updates_concurrency() {
         .handle_event(Event::WorkerResized {
             worker_name: "fixer".to_string(),
             concurrency: 5,
+            rate: None,
             namespace: String::new(),
         })
         .await
@@ -443,6 +468,11 @@ async fn resized_from_full_to_capacity_triggers_repoll() {
             },
             pushed_at_epoch_ms: 1000,
             namespace: String::new(),
+            priority: 0,
+            visible_after_epoch_ms: None,
+            dedup_key: None,
+            expires_at_epoch_ms: None,
+            drop_on_expire: false,
         });
     });
 
@@ -453,7 +483,9 @@ async fn resized_from_full_to_capacity_triggers_repoll() {
             project_root: ctx.project_root.clone(),
             runbook_hash: hash,
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency: 1,
+            rate: None,
             namespace: String::new(),
         })
         .await
@@ -473,6 +505,7 @@ async fn resized_from_full_to_capacity_triggers_repoll() {
         .handle_event(Event::WorkerResized {
             worker_name: "fixer".to_string(),
             concurrency: 2,
+            rate: None,
             namespace: String::new(),
         })
         .await
@@ -500,6 +533,7 @@ async fn resized_already_had_capacity_no_repoll() {
         .handle_event(Event::WorkerResized {
             worker_name: "fixer".to_string(),
             concurrency: 3,
+            rate: None,
             namespace: String::new(),
         })
         .await
@@ -527,6 +561,7 @@ async fn resized_decrease_no_repoll() {
         .handle_event(Event::WorkerResized {
             worker_name: "fixer".to_string(),
             concurrency: 1,
+            rate: None,
             namespace: String::new(),
         })
         .await
@@ -553,6 +588,7 @@ async fn resized_nonexistent_worker_returns_empty() {
         .handle_event(Event::WorkerResized {
             worker_name: "ghost".to_string(),
             concurrency: 5,
+            rate: None,
             namespace: String::new(),
         })
         .await
@@ -581,6 +617,7 @@ async fn resized_stopped_worker_returns_empty() {
         .handle_event(Event::WorkerResized {
             worker_name: "fixer".to_string(),
             concurrency: 5,
+            rate: None,
             namespace: String::new(),
         })
         .await
@@ -619,6 +656,7 @@ async fn resized_with_pending_takes_counts_toward_capacity() {
         .handle_event(Event::WorkerResized {
             worker_name: "fixer".to_string(),
             concurrency: 3,
+            rate: None,
             namespace: String::new(),
         })
         .await
@@ -663,6 +701,11 @@ async fn reconcile_terminal_job_emits_queue_completion() {
             },
             pushed_at_epoch_ms: 1000,
             namespace: String::new(),
+            priority: 0,
+            visible_after_epoch_ms: None,
+            dedup_key: None,
+            expires_at_epoch_ms: None,
+            drop_on_expire: false,
         });
         state.apply_event(&Event::QueueTaken {
             queue_name: "bugs".to_string(),
@@ -687,6 +730,9 @@ async fn reconcile_terminal_job_emits_queue_completion() {
             created_at_epoch_ms: 1000,
             namespace: String::new(),
             cron_name: None,
+            after: vec![],
+            priority: 0,
+            project_root: None,
         });
         state.apply_event(&Event::JobAdvanced {
             id: JobId::new("pipe-done"),
@@ -698,13 +744,17 @@ async fn reconcile_terminal_job_emits_queue_completion() {
             project_root: ctx.project_root.clone(),
             runbook_hash: hash.clone(),
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency: 2,
+            rate: None,
             namespace: String::new(),
         });
         state.apply_event(&Event::WorkerItemDispatched {
             worker_name: "fixer".to_string(),
             item_id: "item-1".to_string(),
             job_id: JobId::new("pipe-done"),
+            agent_run_id: None,
+            bucket_full_at_ms: None,
             namespace: String::new(),
         });
     });
@@ -716,7 +766,9 @@ async fn reconcile_terminal_job_emits_queue_completion() {
             project_root: ctx.project_root.clone(),
             runbook_hash: hash,
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency: 2,
+            rate: None,
             namespace: String::new(),
         })
         .await
@@ -756,6 +808,11 @@ async fn reconcile_untracked_active_item_adds_to_worker() {
             },
             pushed_at_epoch_ms: 1000,
             namespace: String::new(),
+            priority: 0,
+            visible_after_epoch_ms: None,
+            dedup_key: None,
+            expires_at_epoch_ms: None,
+            drop_on_expire: false,
         });
         state.apply_event(&Event::QueueTaken {
             queue_name: "bugs".to_string(),
@@ -769,7 +826,9 @@ async fn reconcile_untracked_active_item_adds_to_worker() {
             project_root: ctx.project_root.clone(),
             runbook_hash: hash.clone(),
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency: 2,
+            rate: None,
             namespace: String::new(),
         });
         // Job exists with matching item.id
@@ -788,6 +847,9 @@ async fn reconcile_untracked_active_item_adds_to_worker() {
             created_at_epoch_ms: 1000,
             namespace: String::new(),
             cron_name: None,
+            after: vec![],
+            priority: 0,
+            project_root: None,
         });
     });
 
@@ -798,7 +860,9 @@ async fn reconcile_untracked_active_item_adds_to_worker() {
             project_root: ctx.project_root.clone(),
             runbook_hash: hash,
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency: 2,
+            rate: None,
             namespace: String::new(),
         })
         .await
@@ -835,6 +899,11 @@ async fn reconcile_orphaned_item_no_retry_goes_dead() {
             },
             pushed_at_epoch_ms: 1000,
             namespace: String::new(),
+            priority: 0,
+            visible_after_epoch_ms: None,
+            dedup_key: None,
+            expires_at_epoch_ms: None,
+            drop_on_expire: false,
         });
         state.apply_event(&Event::QueueTaken {
             queue_name: "bugs".to_string(),
@@ -847,7 +916,9 @@ async fn reconcile_orphaned_item_no_retry_goes_dead() {
             project_root: ctx.project_root.clone(),
             runbook_hash: hash.clone(),
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency: 2,
+            rate: None,
             namespace: String::new(),
         });
     });
@@ -859,7 +930,9 @@ async fn reconcile_orphaned_item_no_retry_goes_dead() {
             project_root: ctx.project_root.clone(),
             runbook_hash: hash,
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency: 2,
+            rate: None,
             namespace: String::new(),
         })
         .await
@@ -897,6 +970,11 @@ async fn reconcile_orphaned_item_with_retry_schedules_retry() {
             },
             pushed_at_epoch_ms: 1000,
             namespace: String::new(),
+            priority: 0,
+            visible_after_epoch_ms: None,
+            dedup_key: None,
+            expires_at_epoch_ms: None,
+            drop_on_expire: false,
         });
         state.apply_event(&Event::QueueTaken {
             queue_name: "bugs".to_string(),
@@ -909,7 +987,9 @@ async fn reconcile_orphaned_item_with_retry_schedules_retry() {
             project_root: ctx.project_root.clone(),
             runbook_hash: hash.clone(),
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency: 2,
+            rate: None,
             namespace: String::new(),
         });
     });
@@ -921,7 +1001,9 @@ async fn reconcile_orphaned_item_with_retry_schedules_retry() {
             project_root: ctx.project_root.clone(),
             runbook_hash: hash,
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency: 2,
+            rate: None,
             namespace: String::new(),
         })
         .await
@@ -949,6 +1031,10 @@ async fn reconcile_orphaned_item_with_retry_schedules_retry() {
         "retry timer should be scheduled for orphaned item, found: {:?}",
         timer_ids
     );
+    // ...and recorded so it survives a daemon restart
+    assert!(ctx
+        .runtime
+        .lock_state(|state| state.timers.contains_key(retry_timer.as_str())));
 }
 
 #[tokio::test]
@@ -973,6 +1059,11 @@ async fn reconcile_orphaned_item_exhausted_retries_goes_dead() {
             },
             pushed_at_epoch_ms: 1000,
             namespace: String::new(),
+            priority: 0,
+            visible_after_epoch_ms: None,
+            dedup_key: None,
+            expires_at_epoch_ms: None,
+            drop_on_expire: false,
         });
         // Cycle 1: fail (Pending→Failed, fc 0→1), then retake (Active, fc stays 1)
         state.apply_event(&Event::QueueFailed {
@@ -1006,7 +1097,9 @@ async fn reconcile_orphaned_item_exhausted_retries_goes_dead() {
             project_root: ctx.project_root.clone(),
             runbook_hash: hash.clone(),
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency: 2,
+            rate: None,
             namespace: String::new(),
         });
     });
@@ -1019,7 +1112,9 @@ async fn reconcile_orphaned_item_exhausted_retries_goes_dead() {
             project_root: ctx.project_root.clone(),
             runbook_hash: hash,
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency: 2,
+            rate: None,
             namespace: String::new(),
         })
         .await
@@ -1072,6 +1167,11 @@ async fn reconcile_respects_namespace_scoping() {
             },
             pushed_at_epoch_ms: 1000,
             namespace: namespace.to_string(),
+            priority: 0,
+            visible_after_epoch_ms: None,
+            dedup_key: None,
+            expires_at_epoch_ms: None,
+            drop_on_expire: false,
         });
         state.apply_event(&Event::QueueTaken {
             queue_name: "bugs".to_string(),
@@ -1084,7 +1184,9 @@ async fn reconcile_respects_namespace_scoping() {
             project_root: ctx.project_root.clone(),
             runbook_hash: hash.clone(),
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency: 2,
+            rate: None,
             namespace: namespace.to_string(),
         });
     });
@@ -1096,7 +1198,9 @@ async fn reconcile_respects_namespace_scoping() {
             project_root: ctx.project_root.clone(),
             runbook_hash: hash,
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency: 2,
+            rate: None,
             namespace: namespace.to_string(),
         })
         .await
@@ -1139,7 +1243,9 @@ async fn reconcile_external_queue_terminal_job_releases_slot() {
             project_root: ctx.project_root.clone(),
             runbook_hash: hash.clone(),
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency: 2,
+            rate: None,
             namespace: String::new(),
         });
         // Simulate a dispatched job with an item.id var
@@ -1147,6 +1253,8 @@ async fn reconcile_external_queue_terminal_job_releases_slot() {
             worker_name: "fixer".to_string(),
             item_id: "ext-item-done".to_string(),
             job_id: JobId::new("pipe-done"),
+            agent_run_id: None,
+            bucket_full_at_ms: None,
             namespace: String::new(),
         });
         // Job created and already at terminal step
@@ -1165,6 +1273,9 @@ async fn reconcile_external_queue_terminal_job_releases_slot() {
             created_at_epoch_ms: 1000,
             namespace: String::new(),
             cron_name: None,
+            after: vec![],
+            priority: 0,
+            project_root: None,
         });
         state.apply_event(&Event::JobAdvanced {
             id: JobId::new("pipe-done"),
@@ -1179,7 +1290,9 @@ async fn reconcile_external_queue_terminal_job_releases_slot() {
             project_root: ctx.project_root.clone(),
             runbook_hash: hash,
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency: 2,
+            rate: None,
             namespace: String::new(),
         })
         .await
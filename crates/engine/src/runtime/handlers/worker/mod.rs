@@ -7,24 +7,39 @@ mod completion;
 mod dispatch;
 mod lifecycle;
 mod polling;
+mod sources;
 
-use oj_core::JobId;
-use oj_runbook::QueueType;
+use oj_core::{AgentRunId, DispatchRate, JobId};
+use oj_runbook::{QueueDef, QueueType};
+use sources::SourceQueues;
 use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
+use std::time::Duration;
 
 /// In-memory state for a running worker
 pub(crate) struct WorkerState {
     pub project_root: PathBuf,
     pub runbook_hash: String,
-    pub queue_name: String,
+    /// Queues to take items from; more than one for `source.queues`
+    pub sources: SourceQueues,
     pub job_kind: String,
+    /// Agent to run per item instead of a job (`handler = { agent = ... }`)
+    pub agent_name: Option<String>,
     pub concurrency: u32,
+    /// Dispatch rate limit (`rate = "10/h"`), if any
+    pub rate: Option<DispatchRate>,
+    /// When the rate limiter's token bucket is full again (see
+    /// [`DispatchRate`]); restored from state on restart
+    pub bucket_full_at_ms: u64,
     pub active_jobs: HashSet<JobId>,
     pub status: WorkerStatus,
     pub queue_type: QueueType,
     /// Maps job_id -> item_id for queue item completion tracking
     pub item_job_map: HashMap<JobId, String>,
+    /// Agent runs dispatched by an agent-backed worker
+    pub active_agent_runs: HashSet<AgentRunId>,
+    /// Maps agent_run_id -> item_id, like `item_job_map`
+    pub item_agent_run_map: HashMap<AgentRunId, String>,
     /// Project namespace
     pub namespace: String,
     /// Poll interval for external queues (None = no periodic polling)
@@ -37,8 +52,52 @@ pub(crate) struct WorkerState {
     pub inflight_items: HashSet<String>,
 }
 
+impl WorkerState {
+    /// Jobs and agent runs currently holding a concurrency slot
+    pub fn active_count(&self) -> u32 {
+        (self.active_jobs.len() + self.active_agent_runs.len()) as u32
+    }
+
+    /// Rate limit tokens available at `now_ms`; unlimited without a rate
+    pub fn rate_tokens(&self, now_ms: u64) -> u32 {
+        match self.rate {
+            Some(rate) => rate.available(self.bucket_full_at_ms, now_ms),
+            None => u32::MAX,
+        }
+    }
+
+    /// Spend a rate limit token on a dispatch
+    pub fn take_rate_token(&mut self, now_ms: u64) {
+        if let Some(rate) = self.rate {
+            self.bucket_full_at_ms = rate.take(self.bucket_full_at_ms, now_ms);
+        }
+    }
+
+    /// Bucket state to record on a dispatch, for rate-limited workers
+    pub fn rate_bucket(&self) -> Option<u64> {
+        self.rate.map(|_| self.bucket_full_at_ms)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum WorkerStatus {
     Running,
     Stopped,
 }
+
+/// Timeout for an external queue's `list`/`take` commands, if it sets one.
+fn queue_command_timeout(queue_def: &QueueDef) -> Option<Duration> {
+    queue_def
+        .timeout
+        .as_deref()
+        .and_then(|t| crate::monitor::parse_duration(t).ok())
+}
+
+/// How long a persisted queue's item may stay active without a live
+/// handler, if the queue sets a lease.
+pub(crate) fn queue_lease(queue_def: &QueueDef) -> Option<Duration> {
+    queue_def
+        .lease
+        .as_deref()
+        .and_then(|l| crate::monitor::parse_duration(l).ok())
+}
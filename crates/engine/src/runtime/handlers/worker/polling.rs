@@ -39,7 +39,7 @@ where
             result_events.push(loaded_event);
         }
 
-        let (queue_type, queue_name, runbook_hash, project_root, worker_namespace, poll_interval) = {
+        let (queue_type, queue_names, runbook_hash, project_root, worker_namespace, poll_interval) = {
             let workers = self.worker_states.lock();
             let state = match workers.get(worker_name) {
                 Some(s) if s.status != WorkerStatus::Stopped => s,
@@ -50,7 +50,7 @@ where
             };
             (
                 state.queue_type,
-                state.queue_name.clone(),
+                state.sources.names(),
                 state.runbook_hash.clone(),
                 state.project_root.clone(),
                 state.namespace.clone(),
@@ -60,8 +60,10 @@ where
 
         match queue_type {
             QueueType::External => {
+                // External queues are never weighted: there is just the one
+                let queue_name = queue_names.first().map(String::as_str).unwrap_or_default();
                 let runbook = self.cached_runbook(&runbook_hash)?;
-                let queue_def = runbook.get_queue(&queue_name).ok_or_else(|| {
+                let queue_def = runbook.get_queue(queue_name).ok_or_else(|| {
                     RuntimeError::WorkerNotFound(format!("queue '{}' not found", queue_name))
                 })?;
 
@@ -69,6 +71,7 @@ where
                     worker_name: worker_name.to_string(),
                     list_command: queue_def.list.clone().unwrap_or_default(),
                     cwd: project_root,
+                    timeout: super::queue_command_timeout(queue_def),
                 };
                 result_events.extend(self.executor.execute_all(vec![poll_effect]).await?);
 
@@ -94,7 +97,7 @@ where
             QueueType::Persisted => {
                 result_events.extend(self.poll_persisted_queue(
                     worker_name,
-                    &queue_name,
+                    &queue_names,
                     &worker_namespace,
                 )?);
             }
@@ -103,23 +106,56 @@ where
         Ok(result_events)
     }
 
-    /// Read pending items from MaterializedState and synthesize a WorkerPollComplete event.
+    /// Read pending items of the worker's source queues from
+    /// MaterializedState and synthesize a WorkerPollComplete event. Items are
+    /// grouped by queue; dispatch decides how to interleave them.
     pub(super) fn poll_persisted_queue(
         &self,
         worker_name: &str,
-        queue_name: &str,
+        queue_names: &[String],
         namespace: &str,
     ) -> Result<Vec<Event>, RuntimeError> {
+        let mut items = Vec::new();
+        for queue_name in queue_names {
+            items.extend(self.pending_queue_items(worker_name, queue_name, namespace));
+        }
+
+        // Synthesize a WorkerPollComplete event to reuse the existing dispatch flow
+        Ok(vec![Event::WorkerPollComplete {
+            worker_name: worker_name.to_string(),
+            items,
+        }])
+    }
+
+    /// Dispatchable items of one persisted queue, in dispatch order.
+    fn pending_queue_items(
+        &self,
+        worker_name: &str,
+        queue_name: &str,
+        namespace: &str,
+    ) -> Vec<serde_json::Value> {
         let key = scoped_name(namespace, queue_name);
+        let now_ms = self.clock().epoch_ms();
         let (total, items): (usize, Vec<serde_json::Value>) = self.lock_state(|state| match state
             .queue_items
             .get(&key)
         {
             Some(queue_items) => {
                 let total = queue_items.len();
-                let pending: Vec<_> = queue_items
+                // Highest priority first; the stable sort keeps push order
+                // within a priority. Delayed items stay hidden until due, and
+                // items past their TTL are left for the expiry timer.
+                let mut pending: Vec<_> = queue_items
                     .iter()
-                    .filter(|item| item.status == QueueItemStatus::Pending)
+                    .filter(|item| {
+                        item.status == QueueItemStatus::Pending
+                            && !item.is_scheduled(now_ms)
+                            && !item.is_overdue(now_ms)
+                    })
+                    .collect();
+                pending.sort_by_key(|item| std::cmp::Reverse(item.priority));
+                let pending: Vec<_> = pending
+                    .into_iter()
                     .map(|item| {
                         let mut obj = serde_json::Map::new();
                         obj.insert("id".to_string(), serde_json::Value::String(item.id.clone()));
@@ -141,20 +177,16 @@ where
             total,
             "polled persisted queue"
         );
-
-        // Synthesize a WorkerPollComplete event to reuse the existing dispatch flow
-        Ok(vec![Event::WorkerPollComplete {
-            worker_name: worker_name.to_string(),
-            items,
-        }])
+        items
     }
 
     /// Handle a queue poll timer firing: wake the worker to re-poll and reschedule.
+    /// Also handles a rate-limited worker's token coming due.
     pub(crate) async fn handle_queue_poll_timer(
         &self,
         rest: &str,
     ) -> Result<Vec<Event>, RuntimeError> {
-        // Parse worker name from timer ID (after "queue-poll:" prefix)
+        // Parse worker name from timer ID (after "queue-poll:"/"worker-rate:" prefix)
         // Format: "worker_name" or "namespace/worker_name"
         let worker_name = rest.rsplit('/').next().unwrap_or(rest);
 
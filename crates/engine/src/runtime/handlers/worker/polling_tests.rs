@@ -56,7 +56,9 @@ async fn start_worker(ctx: &TestContext, namespace: &str) {
             project_root: ctx.project_root.clone(),
             runbook_hash: hash,
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency: 1,
+            rate: None,
             namespace: namespace.to_string(),
         })
         .await
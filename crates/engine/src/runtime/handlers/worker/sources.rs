@@ -0,0 +1,106 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Weighted selection across a worker's source queues
+//!
+//! Uses stride scheduling: each queue carries a pass that advances by
+//! `STRIDE / weight` whenever an item is dispatched from it, and the next
+//! item comes from the waiting queue with the lowest pass. Over time each
+//! queue's share of dispatches matches its share of the total weight, and a
+//! queue of weight `w` waits at most `total / w` dispatches for a turn.
+//!
+//! Passes persist across polls, so a worker that frees one slot at a time
+//! still alternates fairly. A queue that had nothing waiting rejoins at the
+//! current pass rather than its old one, so an idle queue can't bank turns
+//! and starve the others when it fills up.
+
+use std::fmt;
+
+/// Pass increment for a weight-1 queue
+const STRIDE: u64 = 1 << 20;
+
+/// A queue a worker consumes from
+struct SourceQueue {
+    name: String,
+    weight: u32,
+    pass: u64,
+}
+
+/// A worker's source queues and their scheduling state
+pub(crate) struct SourceQueues {
+    queues: Vec<SourceQueue>,
+    /// Pass of the most recent dispatch
+    current_pass: u64,
+}
+
+impl SourceQueues {
+    pub fn new(weighted: Vec<(String, u32)>) -> Self {
+        let queues = weighted
+            .into_iter()
+            .map(|(name, weight)| SourceQueue {
+                name,
+                weight: weight.max(1),
+                pass: 0,
+            })
+            .collect();
+        Self {
+            queues,
+            current_pass: 0,
+        }
+    }
+
+    /// The first (for single-queue workers, the only) source queue
+    pub fn primary(&self) -> &str {
+        self.queues.first().map(|q| q.name.as_str()).unwrap_or("")
+    }
+
+    pub fn names(&self) -> Vec<String> {
+        self.queues.iter().map(|q| q.name.clone()).collect()
+    }
+
+    pub fn contains(&self, queue_name: &str) -> bool {
+        self.queues.iter().any(|q| q.name == queue_name)
+    }
+
+    /// Index of the queue to dispatch from next, among those for which
+    /// `waiting` is true. Ties go to the heavier queue, then the first.
+    pub fn pick(&self, waiting: impl Fn(usize) -> bool) -> Option<usize> {
+        (0..self.queues.len())
+            .filter(|&i| waiting(i))
+            .min_by_key(|&i| {
+                let q = &self.queues[i];
+                (self.effective_pass(q), std::cmp::Reverse(q.weight))
+            })
+    }
+
+    /// Record a dispatch from queue `index`.
+    pub fn charge(&mut self, index: usize) {
+        let pass = self.effective_pass(&self.queues[index]);
+        let queue = &mut self.queues[index];
+        queue.pass = pass + STRIDE / u64::from(queue.weight);
+        self.current_pass = pass;
+    }
+
+    fn effective_pass(&self, queue: &SourceQueue) -> u64 {
+        queue.pass.max(self.current_pass)
+    }
+}
+
+/// `bugs` for a single queue, `bugs=3,chores=1` for weighted ones
+impl fmt::Display for SourceQueues {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let [only] = self.queues.as_slice() {
+            return write!(f, "{}", only.name);
+        }
+        let weighted: Vec<String> = self
+            .queues
+            .iter()
+            .map(|q| format!("{}={}", q.name, q.weight))
+            .collect();
+        write!(f, "{}", weighted.join(","))
+    }
+}
+
+#[cfg(test)]
+#[path = "sources_tests.rs"]
+mod tests;
@@ -0,0 +1,76 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+fn sources(weights: &[(&str, u32)]) -> SourceQueues {
+    SourceQueues::new(
+        weights
+            .iter()
+            .map(|(name, weight)| (name.to_string(), *weight))
+            .collect(),
+    )
+}
+
+/// Dispatch `n` items from the queues `waiting` selects, returning the queue
+/// names in dispatch order.
+fn dispatch(sources: &mut SourceQueues, n: usize, waiting: impl Fn(usize) -> bool) -> Vec<String> {
+    (0..n)
+        .map(|_| {
+            let i = sources.pick(&waiting).unwrap();
+            sources.charge(i);
+            sources.names().swap_remove(i)
+        })
+        .collect()
+}
+
+fn count(picks: &[String], name: &str) -> usize {
+    picks.iter().filter(|p| *p == name).count()
+}
+
+#[test]
+fn single_queue_always_picked() {
+    let mut s = sources(&[("bugs", 1)]);
+    assert_eq!(dispatch(&mut s, 3, |_| true), vec!["bugs"; 3]);
+    assert_eq!(s.primary(), "bugs");
+}
+
+#[test]
+fn shares_follow_weights() {
+    let mut s = sources(&[("bugs", 3), ("chores", 1)]);
+    let picks = dispatch(&mut s, 40, |_| true);
+    assert_eq!(count(&picks, "bugs"), 30);
+    assert_eq!(count(&picks, "chores"), 10);
+}
+
+#[test]
+fn light_queue_gets_a_turn_within_its_stride() {
+    let mut s = sources(&[("bugs", 3), ("chores", 1)]);
+    let picks = dispatch(&mut s, 40, |_| true);
+    for window in picks.windows(4) {
+        assert!(
+            window.iter().any(|p| p == "chores"),
+            "chores starved: {picks:?}"
+        );
+    }
+}
+
+#[test]
+fn only_waiting_queues_are_picked() {
+    let s = sources(&[("bugs", 3), ("chores", 1)]);
+    assert_eq!(s.pick(|i| i == 1), Some(1));
+    assert_eq!(s.pick(|_| false), None);
+}
+
+#[test]
+fn idle_queue_does_not_bank_turns() {
+    let mut s = sources(&[("bugs", 1), ("chores", 1)]);
+    // chores is empty for a while; bugs takes every slot
+    dispatch(&mut s, 20, |i| i == 0);
+
+    // Once chores fills up, the two alternate instead of chores taking
+    // the next 20 dispatches
+    let picks = dispatch(&mut s, 4, |_| true);
+    assert_eq!(count(&picks, "bugs"), 2);
+    assert_eq!(count(&picks, "chores"), 2);
+}
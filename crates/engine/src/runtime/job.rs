@@ -6,9 +6,10 @@
 use super::Runtime;
 use crate::error::RuntimeError;
 use crate::steps;
+use oj_adapters::subprocess::SHELL_COMMAND_TIMEOUT;
 use oj_adapters::{AgentAdapter, NotifyAdapter, SessionAdapter};
 use oj_core::{Clock, Effect, Event, Job, JobId, SessionId, TimerId};
-use oj_runbook::{NotifyConfig, RunDirective};
+use oj_runbook::{NotifyConfig, OnDone, RetryConfig, RunDirective};
 use std::collections::HashMap;
 use std::path::Path;
 
@@ -66,6 +67,21 @@ where
 
         let mut result_events = Vec::new();
 
+        // Claim locks/semaphores first; a blocked step starts once they free up
+        if !step_def.acquire.is_empty()
+            && !self
+                .acquire_step_locks(
+                    &job,
+                    step_name,
+                    &step_def.acquire,
+                    &runbook,
+                    &mut result_events,
+                )
+                .await?
+        {
+            return Ok(result_events);
+        }
+
         // Mark step as running
         let effects = steps::step_start_effects(job_id, step_name);
         result_events.extend(self.executor.execute_all(effects).await?);
@@ -108,12 +124,28 @@ where
                     shell_env.entry(key).or_insert(value);
                 }
 
+                // Give the step a fresh file to publish outputs to
+                let outputs_file =
+                    crate::outputs::outputs_path(&self.state_dir, job_id.as_str(), step_name);
+                if let Some(dir) = outputs_file.parent() {
+                    let _ = std::fs::create_dir_all(dir);
+                }
+                let _ = std::fs::remove_file(&outputs_file);
+                shell_env.insert(
+                    crate::outputs::OUTPUTS_FILE_ENV.to_string(),
+                    outputs_file.display().to_string(),
+                );
+
                 let effects = vec![Effect::Shell {
                     owner: Some(oj_core::OwnerId::Job(job_id.clone())),
                     step: step_name.to_string(),
                     command,
                     cwd: workspace_path.to_path_buf(),
                     env: shell_env,
+                    timeout: step_def
+                        .timeout
+                        .as_deref()
+                        .and_then(|t| crate::monitor::parse_duration(t).ok()),
                 }];
 
                 result_events.extend(self.executor.execute_all(effects).await?);
@@ -123,12 +155,28 @@ where
                 result_events.extend(self.spawn_agent(job_id, agent, input).await?);
             }
 
-            RunDirective::Job { job } => {
-                return Err(Self::invalid_directive(
-                    &format!("step {step_name}"),
-                    "nested job",
-                    job,
-                ));
+            RunDirective::Job {
+                job: child_kind,
+                vars,
+            } => {
+                result_events.extend(
+                    self.start_sub_job_step(&job, step_name, child_kind, vars, workspace_path)
+                        .await?,
+                );
+            }
+
+            RunDirective::Parallel { parallel } => {
+                result_events.extend(
+                    self.start_parallel_step(&job, step_name, parallel, workspace_path)
+                        .await?,
+                );
+            }
+
+            RunDirective::Approval { approval } => {
+                result_events.extend(
+                    self.start_approval_step(&job, step_name, approval, input, workspace_path)
+                        .await?,
+                );
             }
         }
 
@@ -136,6 +184,16 @@ where
     }
 
     pub(crate) async fn advance_job(&self, job: &Job) -> Result<Vec<Event>, RuntimeError> {
+        self.advance_job_to(job, None).await
+    }
+
+    /// Complete the current step and move on: to `route` when given (an
+    /// `on_exit` match), otherwise by the step's `on_done`.
+    pub(crate) async fn advance_job_to(
+        &self,
+        job: &Job,
+        route: Option<&str>,
+    ) -> Result<Vec<Event>, RuntimeError> {
         // If current step is terminal (done/failed), complete the job
         // This handles the case where a "done" step has a run command that just finished
         if job.is_terminal() {
@@ -203,15 +261,27 @@ where
             })
             .await?;
 
-        // Determine next step: explicit on_done > complete
-        // Steps without on_done complete the job (same as on_fail requiring explicit targets)
-        let next_transition = current_step_def.and_then(|p| p.on_done.clone());
-
         let mut result_events = Vec::new();
 
-        match next_transition {
-            Some(transition) => {
-                let next_step = transition.step_name();
+        // A parallel branch runs a single step; the parent owns the transitions
+        if job.is_branch {
+            let effects = steps::step_transition_effects(job, "done");
+            result_events.extend(self.executor.execute_all(effects).await?);
+            result_events.extend(self.complete_job(job).await?);
+            return Ok(result_events);
+        }
+
+        // Determine next step: on_exit route > first matching on_done branch > complete
+        // Steps without on_done complete the job (same as on_fail requiring explicit targets)
+        let next_step = match route {
+            Some(step) => Some(step.to_string()),
+            None => current_step_def
+                .and_then(|p| p.on_done.as_ref())
+                .and_then(|on_done| self.select_on_done(job, on_done)),
+        };
+
+        match next_step.as_deref() {
+            Some(next_step) => {
                 self.logger
                     .append(&job.id, &job.step, &format!("advancing to {}", next_step));
                 let effects = steps::step_transition_effects(job, next_step);
@@ -280,15 +350,98 @@ where
         Ok(result_events)
     }
 
+    /// Pick the target of the first `on_done` branch whose condition holds.
+    fn select_on_done(&self, job: &Job, on_done: &OnDone) -> Option<String> {
+        let mut vars = crate::vars::namespace_vars(&job.vars);
+        vars.insert("job_id".to_string(), job.id.clone());
+        vars.insert("name".to_string(), job.name.clone());
+        on_done.branches().iter().find_map(|branch| {
+            let Some(condition) = &branch.condition else {
+                return Some(branch.step_name().to_string());
+            };
+            let holds = oj_runbook::evaluate_condition(condition, &vars);
+            self.logger.append(
+                &job.id,
+                &job.step,
+                &format!("on_done: `{}` is {}", condition, holds),
+            );
+            holds.then(|| branch.step_name().to_string())
+        })
+    }
+
+    /// The `on_exit` target for the current shell step's exit code, if any.
+    ///
+    /// Parallel branches leave routing to their parent, so they never match.
+    pub(crate) fn exit_route(
+        &self,
+        job: &Job,
+        exit_code: i32,
+    ) -> Result<Option<String>, RuntimeError> {
+        if job.is_branch {
+            return Ok(None);
+        }
+        let runbook = self.cached_runbook(&job.runbook_hash)?;
+        Ok(runbook
+            .get_job(&job.kind)
+            .and_then(|def| def.get_step(&job.step))
+            .and_then(|step| step.on_exit.get(&exit_code.to_string()))
+            .map(|t| t.step_name().to_string()))
+    }
+
+    /// The current shell step's timeout as written in the runbook, or the
+    /// executor default when the step sets none.
+    pub(crate) fn shell_timeout(&self, job: &Job) -> Result<String, RuntimeError> {
+        let runbook = self.cached_runbook(&job.runbook_hash)?;
+        Ok(runbook
+            .get_job(&job.kind)
+            .and_then(|def| def.get_step(&job.step))
+            .and_then(|step| step.timeout.clone())
+            .unwrap_or_else(|| oj_core::format_elapsed(SHELL_COMMAND_TIMEOUT.as_secs())))
+    }
+
     pub(crate) async fn fail_job(
         &self,
         job: &Job,
         error: &str,
+    ) -> Result<Vec<Event>, RuntimeError> {
+        self.fail_step(job, error, false).await
+    }
+
+    /// Fail the current shell step after its command timed out. Retries come
+    /// first, as for any failure; then the step's `on_timeout` is taken ahead
+    /// of `on_fail`.
+    pub(crate) async fn fail_job_timed_out(
+        &self,
+        job: &Job,
+        error: &str,
+    ) -> Result<Vec<Event>, RuntimeError> {
+        self.fail_step(job, error, true).await
+    }
+
+    async fn fail_step(
+        &self,
+        job: &Job,
+        error: &str,
+        timed_out: bool,
     ) -> Result<Vec<Event>, RuntimeError> {
         let runbook = self.cached_runbook(&job.runbook_hash)?;
         let job_def = runbook.get_job(&job.kind);
         let current_step_def = job_def.as_ref().and_then(|p| p.get_step(&job.step));
-        let on_fail = current_step_def.and_then(|p| p.on_fail.as_ref());
+        let on_fail = current_step_def.filter(|_| !job.is_branch).and_then(|p| {
+            let on_timeout = p.on_timeout.as_ref().filter(|_| timed_out);
+            on_timeout.or(p.on_fail.as_ref())
+        });
+        let job_on_fail = job_def
+            .as_ref()
+            .filter(|_| !job.is_branch)
+            .and_then(|p| p.on_fail.clone());
+
+        // A retry policy gets its attempts before on_fail routing kicks in
+        if let Some(retry) = current_step_def.and_then(|s| s.retry.as_ref()) {
+            if job.step_retries + 1 < retry.attempts {
+                return self.schedule_step_retry(job, retry, error).await;
+            }
+        }
 
         // Cancel session monitor timers when leaving an agent step
         let current_is_agent = current_step_def
@@ -337,7 +490,8 @@ where
         self.logger
             .append(&job.id, &job.step, &format!("job failed: {}", error));
 
-        let mut result_events = Vec::new();
+        // Cancel any child jobs (parallel branches or a sub-job) still running
+        let mut result_events = self.cancel_children(job).await?;
 
         if let Some(on_fail) = on_fail {
             let on_fail_step = on_fail.step_name();
@@ -347,7 +501,7 @@ where
                 self.start_step(&job_id, on_fail_step, &job.vars, &self.execution_dir(job))
                     .await?,
             );
-        } else if let Some(ref job_on_fail) = job_def.as_ref().and_then(|p| p.on_fail.clone()) {
+        } else if let Some(ref job_on_fail) = job_on_fail {
             let on_fail_step = job_on_fail.step_name();
             if job.step != on_fail_step {
                 // Job-level on_fail: route to that step
@@ -372,22 +526,78 @@ where
             }
         } else {
             // Terminal failure — no on_fail handler
-            let effects = steps::failure_effects(job, error);
-            result_events.extend(self.executor.execute_all(effects).await?);
-            self.breadcrumb.delete(&job.id);
+            result_events.extend(self.fail_job_terminal(job, error).await?);
+        }
 
-            // Update queue item status immediately (don't rely on event loop)
-            result_events.extend(self.check_worker_job_complete(&job_id, "failed").await?);
+        Ok(result_events)
+    }
+
+    /// Move a job straight to `failed`, bypassing any `on_fail` routing.
+    pub(crate) async fn fail_job_terminal(
+        &self,
+        job: &Job,
+        error: &str,
+    ) -> Result<Vec<Event>, RuntimeError> {
+        let mut result_events = self.cancel_children(job).await?;
+        let effects = steps::failure_effects(job, error);
+        result_events.extend(self.executor.execute_all(effects).await?);
+        self.breadcrumb.delete(&job.id);
+
+        // Update queue item status immediately (don't rely on event loop)
+        let job_id = JobId::new(&job.id);
+        result_events.extend(self.check_worker_job_complete(&job_id, "failed").await?);
 
-            // Emit on_fail notification only on terminal failure (not on_fail transition)
-            if let Some(job_def) = job_def.as_ref() {
+        // Emit on_fail notification only on terminal failure (not on_fail transition)
+        if job.is_branch {
+            return Ok(result_events);
+        }
+        if let Ok(runbook) = self.cached_runbook(&job.runbook_hash) {
+            if let Some(job_def) = runbook.get_job(&job.kind) {
                 result_events.extend(
                     self.emit_notify(job, &job_def.notify, job_def.notify.on_fail.as_ref())
                         .await?,
                 );
             }
         }
+        Ok(result_events)
+    }
 
+    /// Record a failed attempt and arm the timer that re-runs the step.
+    async fn schedule_step_retry(
+        &self,
+        job: &Job,
+        retry: &RetryConfig,
+        error: &str,
+    ) -> Result<Vec<Event>, RuntimeError> {
+        let job_id = JobId::new(&job.id);
+        let attempt = job.step_retries + 1;
+        let delay = crate::retry::next_retry_delay(retry, attempt);
+        self.logger.append(
+            &job.id,
+            &job.step,
+            &format!(
+                "step failed: {}; retry {}/{} in {}",
+                error,
+                attempt,
+                retry.attempts - 1,
+                oj_core::format_elapsed(delay.as_secs()),
+            ),
+        );
+        let mut result_events = self
+            .executor
+            .execute_all(vec![Effect::Emit {
+                event: Event::StepRetrying {
+                    job_id: job_id.clone(),
+                    step: job.step.clone(),
+                    attempt,
+                    error: error.to_string(),
+                },
+            }])
+            .await?;
+        result_events.extend(
+            self.set_durable_timer(TimerId::step_retry(&job_id), delay)
+                .await?,
+        );
         Ok(result_events)
     }
 
@@ -419,6 +629,18 @@ where
         self.logger.append(&job.id, &job.step, "job completed");
         self.breadcrumb.delete(&job.id);
         let mut effects = steps::completion_effects(job);
+        // Callers usually transition to "done" before completing; don't
+        // advance again from the stale copy they pass in
+        if self.get_job(&job.id).is_some_and(|j| j.is_terminal()) {
+            effects.retain(|e| {
+                !matches!(
+                    e,
+                    Effect::Emit {
+                        event: Event::JobAdvanced { .. }
+                    }
+                )
+            });
+        }
 
         // Clean up workspaces on successful completion
         effects.extend(self.workspace_cleanup_effects(job));
@@ -429,7 +651,10 @@ where
         let job_id = JobId::new(&job.id);
         result_events.extend(self.check_worker_job_complete(&job_id, "done").await?);
 
-        // Emit on_done notification if configured
+        // Emit on_done notification if configured (branches report via their parent)
+        if job.is_branch {
+            return Ok(result_events);
+        }
         if let Ok(runbook) = self.cached_runbook(&job.runbook_hash) {
             if let Some(job_def) = runbook.get_job(&job.kind) {
                 result_events.extend(
@@ -492,10 +717,19 @@ where
         let runbook = self.cached_runbook(&job.runbook_hash)?;
         let job_def = runbook.get_job(&job.kind);
         let current_step_def = job_def.as_ref().and_then(|p| p.get_step(&job.step));
-        let on_cancel = current_step_def.and_then(|s| s.on_cancel.as_ref());
+        let on_cancel = current_step_def
+            .filter(|_| !job.is_branch)
+            .and_then(|s| s.on_cancel.as_ref());
+        let job_on_cancel = job_def
+            .as_ref()
+            .filter(|_| !job.is_branch)
+            .and_then(|p| p.on_cancel.clone());
 
         let job_id = JobId::new(&job.id);
 
+        // Cancel any child jobs (parallel branches or a sub-job) still running
+        let mut result_events = self.cancel_children(job).await?;
+
         // Cancel timers and kill session (same cleanup as fail_job for agent steps)
         let current_is_agent = current_step_def
             .map(|s| matches!(&s.run, RunDirective::Agent { .. }))
@@ -536,8 +770,6 @@ where
             }
         }
 
-        let mut result_events = Vec::new();
-
         if let Some(on_cancel) = on_cancel {
             // Step-level on_cancel: route to cleanup step
             let target = on_cancel.step_name();
@@ -554,7 +786,7 @@ where
                 self.start_step(&job_id, target, &job.vars, &self.execution_dir(job))
                     .await?,
             );
-        } else if let Some(ref job_on_cancel) = job_def.as_ref().and_then(|p| p.on_cancel.clone()) {
+        } else if let Some(ref job_on_cancel) = job_on_cancel {
             // Job-level on_cancel fallback
             let target = job_on_cancel.step_name();
             if job.step != target {
@@ -593,4 +825,37 @@ where
         tracing::info!(job_id = %job.id, "cancelled job");
         Ok(result_events)
     }
+
+    /// Cancel the still-running child jobs of a job's current step.
+    pub(crate) async fn cancel_children(&self, job: &Job) -> Result<Vec<Event>, RuntimeError> {
+        let mut result_events = Vec::new();
+        for child_id in current_child_job_ids(job) {
+            if let Some(child) = self.get_job(&child_id) {
+                if !child.is_terminal() {
+                    // Boxed: cancel_job re-enters itself for the child
+                    result_events.extend(Box::pin(self.cancel_job(&child)).await?);
+                }
+            }
+        }
+        Ok(result_events)
+    }
+}
+
+/// Job IDs of the child jobs started by the step the job is currently on:
+/// its parallel branches, or the job run by a sub-job step.
+pub(crate) fn current_child_job_ids(job: &Job) -> Vec<String> {
+    if job.is_terminal() {
+        return Vec::new();
+    }
+    job.step_history
+        .last()
+        .filter(|r| r.name == job.step && r.finished_at_ms.is_none())
+        .map(|r| {
+            r.job_id
+                .iter()
+                .chain(r.branches.iter().filter_map(|b| b.job_id.as_ref()))
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default()
 }
@@ -0,0 +1,132 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Lock and semaphore acquisition for steps with `acquire`
+
+use super::Runtime;
+use crate::error::RuntimeError;
+use oj_adapters::{AgentAdapter, NotifyAdapter, SessionAdapter};
+use oj_core::{scoped_name, Clock, Effect, Event, Job, JobId};
+use oj_runbook::Runbook;
+use oj_storage::MaterializedState;
+
+impl<S, A, N, C> Runtime<S, A, N, C>
+where
+    S: SessionAdapter,
+    A: AgentAdapter,
+    N: NotifyAdapter,
+    C: Clock,
+{
+    /// Try to take every resource a step needs before it starts.
+    ///
+    /// Acquisition is all-or-nothing: either all resources are claimed
+    /// (`LockAcquired`) or the job is queued on them (`LockWaiting`) and the
+    /// step stays blocked. Returns whether the step may start.
+    pub(crate) async fn acquire_step_locks(
+        &self,
+        job: &Job,
+        step: &str,
+        acquire: &[String],
+        runbook: &Runbook,
+        result_events: &mut Vec<Event>,
+    ) -> Result<bool, RuntimeError> {
+        let acquired = self.lock_state(|state| can_acquire(state, job, acquire, runbook));
+        let job_id = JobId::new(&job.id);
+        let resources = acquire.to_vec();
+        let event = if acquired {
+            Event::LockAcquired {
+                job_id,
+                step: step.to_string(),
+                resources,
+                namespace: job.namespace.clone(),
+            }
+        } else {
+            Event::LockWaiting {
+                job_id,
+                step: step.to_string(),
+                resources,
+                namespace: job.namespace.clone(),
+            }
+        };
+        result_events.extend(self.executor.execute(Effect::Emit { event }).await?);
+
+        let verb = if acquired { "acquired" } else { "blocked on" };
+        self.logger
+            .append(&job.id, step, &format!("{} {}", verb, acquire.join(", ")));
+        if !acquired {
+            tracing::info!(job_id = %job.id, step, resources = ?acquire, "step blocked on locks");
+        }
+        Ok(acquired)
+    }
+
+    /// Start any blocked steps whose resources have become available.
+    ///
+    /// Called after events that can release claims (step transitions,
+    /// job deletion) and on daemon startup.
+    pub async fn wake_lock_waiters(&self) -> Result<Vec<Event>, RuntimeError> {
+        let mut waiters: Vec<(u64, String)> = self.lock_state(|state| {
+            state
+                .locks
+                .values()
+                .flat_map(|r| r.waiters.iter().map(|c| (c.since_ms, c.job_id.clone())))
+                .collect()
+        });
+        waiters.sort();
+        waiters.dedup_by(|a, b| a.1 == b.1);
+
+        let mut result_events = Vec::new();
+        for (_, job_id) in waiters {
+            let Some(job) = self.get_active_job(&job_id) else {
+                continue;
+            };
+            if !job.step_status.is_blocked() {
+                continue;
+            }
+            let runbook = self.cached_runbook(&job.runbook_hash)?;
+            let Some(step_def) = runbook
+                .get_job(&job.kind)
+                .and_then(|def| def.get_step(&job.step))
+            else {
+                continue;
+            };
+            let ready =
+                self.lock_state(|state| can_acquire(state, &job, &step_def.acquire, &runbook));
+            if ready {
+                let execution_dir = self.execution_dir(&job);
+                result_events.extend(
+                    self.start_step(&JobId::new(&job.id), &job.step, &job.vars, &execution_dir)
+                        .await?,
+                );
+            }
+        }
+        Ok(result_events)
+    }
+}
+
+/// Whether `job` can claim every resource in `acquire` right now.
+///
+/// Waiters are served in arrival order: a job may only take a free permit if
+/// fewer jobs are queued ahead of it than there are free permits.
+fn can_acquire(
+    state: &MaterializedState,
+    job: &Job,
+    acquire: &[String],
+    runbook: &Runbook,
+) -> bool {
+    acquire.iter().all(|resource| {
+        let Some(record) = state.locks.get(&scoped_name(&job.namespace, resource)) else {
+            return true;
+        };
+        if record.holders.iter().any(|c| c.job_id == job.id) {
+            return true;
+        }
+        let permits = runbook.resource_permits(resource).unwrap_or(1) as usize;
+        let free = permits.saturating_sub(record.holders.len());
+        let ahead = record
+            .waiters
+            .iter()
+            .position(|c| c.job_id == job.id)
+            .unwrap_or(record.waiters.len());
+        ahead < free
+    })
+}
@@ -3,10 +3,17 @@
 
 //! Runtime for the Odd Jobs engine
 
+mod admission;
 pub(crate) mod agent_run;
+mod approval;
+mod budget;
+mod dependencies;
 mod handlers;
 mod job;
+mod locks;
 mod monitor;
+mod parallel;
+mod sub_job;
 
 use crate::{
     activity_logger::{JobLogger, QueueLogger, WorkerLogger},
@@ -15,7 +22,11 @@ use crate::{
     executor::Executor,
     scheduler::Scheduler,
 };
+use admission::ProjectLimit;
 use handlers::cron::CronState;
+use handlers::git_trigger::GitTriggerState;
+use handlers::trigger::TriggerRunbooks;
+use handlers::watch::WatchState;
 use handlers::worker::WorkerState;
 #[cfg(test)]
 use handlers::worker::WorkerStatus;
@@ -24,7 +35,7 @@ use oj_core::{AgentId, Clock, Job, OwnerId, ShortId};
 use oj_runbook::Runbook;
 
 use oj_storage::MaterializedState;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
@@ -41,6 +52,8 @@ pub struct RuntimeConfig {
     pub state_dir: PathBuf,
     /// Directory for per-job log files
     pub log_dir: PathBuf,
+    /// Daemon-wide limit on running jobs (`[limits] max_jobs`)
+    pub max_jobs: Option<u32>,
 }
 
 /// Runtime adapter dependencies
@@ -55,6 +68,7 @@ pub struct RuntimeDeps<S, A, N> {
 pub struct Runtime<S, A, N, C: Clock> {
     pub(crate) executor: Executor<S, A, N, C>,
     pub(crate) state_dir: PathBuf,
+    pub(crate) max_jobs: Option<u32>,
     pub(crate) logger: JobLogger,
     pub(crate) worker_logger: WorkerLogger,
     pub(crate) queue_logger: QueueLogger,
@@ -63,6 +77,17 @@ pub struct Runtime<S, A, N, C: Clock> {
     pub(crate) runbook_cache: Mutex<HashMap<String, Runbook>>,
     pub(crate) worker_states: Mutex<HashMap<String, WorkerState>>,
     pub(crate) cron_states: Mutex<HashMap<String, CronState>>,
+    /// Keyed by scoped watch name
+    pub(crate) watch_states: Mutex<HashMap<String, WatchState>>,
+    /// Keyed by scoped git trigger name
+    pub(crate) git_trigger_states: Mutex<HashMap<String, GitTriggerState>>,
+    /// Keyed by project root and event name
+    pub(crate) trigger_runbooks: Mutex<HashMap<(PathBuf, &'static str), TriggerRunbooks>>,
+    /// Agents already warned that a `usd` budget can't be enforced on
+    /// their model
+    pub(crate) unpriced_budget_agents: Mutex<HashSet<String>>,
+    /// Keyed by project root
+    pub(crate) project_limits: Mutex<HashMap<PathBuf, ProjectLimit>>,
 }
 
 impl<S, A, N, C> Runtime<S, A, N, C>
@@ -87,6 +112,7 @@ where
                 event_tx,
             ),
             state_dir: config.state_dir,
+            max_jobs: config.max_jobs,
             logger: JobLogger::new(config.log_dir.clone()),
             worker_logger: WorkerLogger::new(config.log_dir.clone()),
             queue_logger: QueueLogger::new(config.log_dir.clone()),
@@ -95,6 +121,11 @@ where
             runbook_cache: Mutex::new(HashMap::new()),
             worker_states: Mutex::new(HashMap::new()),
             cron_states: Mutex::new(HashMap::new()),
+            watch_states: Mutex::new(HashMap::new()),
+            git_trigger_states: Mutex::new(HashMap::new()),
+            trigger_runbooks: Mutex::new(HashMap::new()),
+            unpriced_budget_agents: Mutex::new(HashSet::new()),
+            project_limits: Mutex::new(HashMap::new()),
         }
     }
 
@@ -147,6 +178,21 @@ where
         })
     }
 
+    /// Count non-terminal jobs started by a watch.
+    pub(crate) fn count_active_watch_jobs(&self, watch_name: &str, namespace: &str) -> usize {
+        self.lock_state(|state| {
+            state
+                .jobs
+                .values()
+                .filter(|p| {
+                    p.vars.get("watch.name").map(String::as_str) == Some(watch_name)
+                        && p.namespace == namespace
+                        && !p.is_terminal()
+                })
+                .count()
+        })
+    }
+
     /// Count currently running (non-terminal) instances of an agent by name.
     pub(crate) fn count_running_agents(&self, agent_name: &str, namespace: &str) -> usize {
         self.lock_state(|state| {
@@ -0,0 +1,145 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Parallel fan-out steps (`run = { parallel = [...] }`)
+//!
+//! Each branch runs in its own child job so that shell and agent branches
+//! get their own session, timers, and step status. The parent stays on the
+//! parallel step until every branch is done, and fails through its normal
+//! `on_fail` routing as soon as any branch fails.
+
+use super::job::current_child_job_ids;
+use super::Runtime;
+use crate::error::RuntimeError;
+use oj_adapters::{AgentAdapter, NotifyAdapter, SessionAdapter};
+use oj_core::{Clock, Effect, Event, IdGen, Job, JobId, UuidIdGen};
+use std::path::Path;
+
+impl<S, A, N, C> Runtime<S, A, N, C>
+where
+    S: SessionAdapter,
+    A: AgentAdapter,
+    N: NotifyAdapter,
+    C: Clock,
+{
+    /// Spawn one branch job per sibling step and start them all.
+    pub(crate) async fn start_parallel_step(
+        &self,
+        job: &Job,
+        step: &str,
+        branches: &[String],
+        workspace_path: &Path,
+    ) -> Result<Vec<Event>, RuntimeError> {
+        let mut result_events = Vec::new();
+
+        // Record every branch before starting any, so the parent's history
+        // is complete even if one of them fails to start.
+        let mut branch_jobs = Vec::new();
+        for branch in branches {
+            let branch_job_id = JobId::new(UuidIdGen.next());
+            let effects = vec![
+                Effect::Emit {
+                    event: Event::JobCreated {
+                        id: branch_job_id.clone(),
+                        kind: job.kind.clone(),
+                        name: format!("{}/{}", job.name, branch),
+                        runbook_hash: job.runbook_hash.clone(),
+                        cwd: workspace_path.to_path_buf(),
+                        vars: job.vars.clone(),
+                        initial_step: branch.clone(),
+                        created_at_epoch_ms: self.clock().epoch_ms(),
+                        namespace: job.namespace.clone(),
+                        cron_name: None,
+                        after: Vec::new(),
+                        priority: 0,
+                        project_root: None,
+                    },
+                },
+                Effect::Emit {
+                    event: Event::StepBranched {
+                        job_id: JobId::new(&job.id),
+                        step: step.to_string(),
+                        branch: branch.clone(),
+                        branch_job_id: branch_job_id.clone(),
+                    },
+                },
+            ];
+            result_events.extend(self.executor.execute_all(effects).await?);
+            self.logger.append(
+                &job.id,
+                step,
+                &format!("branch {} started as job {}", branch, branch_job_id),
+            );
+            branch_jobs.push((branch_job_id, branch));
+        }
+
+        // Boxed: branch steps re-enter start_step
+        for (branch_job_id, branch) in branch_jobs {
+            match Box::pin(self.start_step(&branch_job_id, branch, &job.vars, workspace_path)).await
+            {
+                Ok(events) => result_events.extend(events),
+                Err(e) => {
+                    let branch_job = self.require_job(branch_job_id.as_str())?;
+                    result_events
+                        .extend(Box::pin(self.fail_job(&branch_job, &e.to_string())).await?);
+                }
+            }
+        }
+
+        Ok(result_events)
+    }
+
+    /// React to a branch job reaching a terminal step.
+    ///
+    /// Advances the parent once all branches are done; on the first failed
+    /// or cancelled branch, cancels the remaining siblings and fails the
+    /// parent's parallel step.
+    pub(crate) async fn handle_branch_finished(
+        &self,
+        branch_job: &Job,
+    ) -> Result<Vec<Event>, RuntimeError> {
+        let Some(parent_id) = branch_job.parent_job_id.as_deref() else {
+            return Ok(vec![]);
+        };
+        let Some(parent) = self.get_job(parent_id) else {
+            return Ok(vec![]);
+        };
+
+        // Ignore branches of a fan-out the parent has already left
+        let branch_job_ids = current_child_job_ids(&parent);
+        if !branch_job_ids.contains(&branch_job.id) {
+            return Ok(vec![]);
+        }
+
+        let branch = branch_job
+            .step_history
+            .first()
+            .map(|r| r.name.clone())
+            .unwrap_or_default();
+
+        if branch_job.step == "done" {
+            let all_done = branch_job_ids
+                .iter()
+                .all(|id| self.get_job(id).map(|j| j.step == "done").unwrap_or(false));
+            if !all_done {
+                self.logger
+                    .append(&parent.id, &parent.step, &format!("branch {} done", branch));
+                return Ok(vec![]);
+            }
+            self.logger
+                .append(&parent.id, &parent.step, "all branches done");
+            return self.advance_job(&parent).await;
+        }
+
+        let error = match &branch_job.error {
+            Some(reason) if !reason.is_empty() && branch_job.step != "cancelled" => {
+                format!("branch '{}' failed: {}", branch, reason)
+            }
+            _ => format!("branch '{}' {}", branch, branch_job.step),
+        };
+        let mut result_events = self.cancel_children(&parent).await?;
+        let parent = self.require_job(parent_id)?;
+        result_events.extend(self.fail_job(&parent, &error).await?);
+        Ok(result_events)
+    }
+}
@@ -0,0 +1,131 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Sub-job steps (`run = { job = "...", vars = { ... } }`)
+//!
+//! The step starts a full job of another kind, linked to the parent through
+//! `step:delegated`. The parent step completes when the child reaches `done`
+//! and fails when the child fails or is cancelled.
+
+use super::handlers::CreateJobParams;
+use super::job::current_child_job_ids;
+use super::Runtime;
+use crate::error::RuntimeError;
+use oj_adapters::{AgentAdapter, NotifyAdapter, SessionAdapter};
+use oj_core::{Clock, Event, IdGen, Job, JobId, UuidIdGen};
+use std::collections::HashMap;
+use std::path::Path;
+
+impl<S, A, N, C> Runtime<S, A, N, C>
+where
+    S: SessionAdapter,
+    A: AgentAdapter,
+    N: NotifyAdapter,
+    C: Clock,
+{
+    /// Create and start the child job for a sub-job step.
+    pub(crate) async fn start_sub_job_step(
+        &self,
+        job: &Job,
+        step: &str,
+        child_kind: &str,
+        var_templates: &HashMap<String, String>,
+        workspace_path: &Path,
+    ) -> Result<Vec<Event>, RuntimeError> {
+        let runbook = self.cached_runbook(&job.runbook_hash)?;
+        let child_def = runbook
+            .get_job(child_kind)
+            .ok_or_else(|| RuntimeError::JobDefNotFound(child_kind.to_string()))?;
+
+        // Child input is interpolated from the parent's variables, with the
+        // child's own defaults filling the gaps
+        let mut lookup = crate::vars::namespace_vars(&job.vars);
+        lookup.insert("job_id".to_string(), job.id.clone());
+        lookup.insert("name".to_string(), job.name.clone());
+        lookup.insert(
+            "workspace".to_string(),
+            workspace_path.display().to_string(),
+        );
+        let mut vars: HashMap<String, String> = var_templates
+            .iter()
+            .map(|(k, v)| (k.clone(), oj_runbook::interpolate(v, &lookup)))
+            .collect();
+        for (key, value) in &child_def.defaults {
+            vars.entry(key.clone()).or_insert_with(|| value.clone());
+        }
+        if let Some(dir) = job.vars.get("invoke.dir") {
+            vars.insert("invoke.dir".to_string(), dir.clone());
+        }
+
+        let child_id = JobId::new(UuidIdGen.next());
+        self.logger.append(
+            &job.id,
+            step,
+            &format!("starting job {} as {}", child_kind, child_id),
+        );
+
+        // Boxed: the child's first step re-enters start_step
+        let created = Box::pin(self.create_and_start_job(CreateJobParams {
+            job_id: child_id.clone(),
+            job_name: format!("{}/{}", job.name, step),
+            job_kind: child_kind.to_string(),
+            vars,
+            runbook_hash: job.runbook_hash.clone(),
+            runbook_json: None,
+            runbook,
+            namespace: job.namespace.clone(),
+            cron_name: None,
+            parent: Some((JobId::new(&job.id), step.to_string())),
+            after: Vec::new(),
+            priority: job.priority,
+            project_root: None,
+        }))
+        .await;
+
+        match created {
+            Ok(events) => Ok(events),
+            Err(e) => {
+                // A child that never got created cannot report back; fail the
+                // parent step directly
+                let job = self.require_job(&job.id)?;
+                let error = format!("job '{}' failed to start: {}", child_kind, e);
+                Box::pin(self.fail_job(&job, &error)).await
+            }
+        }
+    }
+
+    /// React to a sub-job reaching a terminal step.
+    ///
+    /// Advances the parent when the child is done and fails the parent's
+    /// step otherwise.
+    pub(crate) async fn handle_sub_job_finished(
+        &self,
+        child: &Job,
+    ) -> Result<Vec<Event>, RuntimeError> {
+        let Some(parent_id) = child.parent_job_id.as_deref() else {
+            return Ok(vec![]);
+        };
+        let Some(parent) = self.get_job(parent_id) else {
+            return Ok(vec![]);
+        };
+
+        // Ignore children of a step the parent has already left
+        if !current_child_job_ids(&parent).contains(&child.id) {
+            return Ok(vec![]);
+        }
+
+        if child.step == "done" {
+            self.logger
+                .append(&parent.id, &parent.step, &format!("job {} done", child.id));
+            return self.advance_job(&parent).await;
+        }
+
+        let error = match &child.error {
+            Some(reason) if !reason.is_empty() && child.step != "cancelled" => {
+                format!("job '{}' failed: {}", child.kind, reason)
+            }
+            _ => format!("job '{}' {}", child.kind, child.step),
+        };
+        self.fail_job(&parent, &error).await
+    }
+}
@@ -0,0 +1,233 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Running-job limit and admission queue tests
+
+use super::*;
+use oj_core::{AgentRunId, AgentRunStatus, DependencyCondition, JobDependency, JobId, StepStatus};
+
+const ADMISSION_RUNBOOK: &str = r#"
+[command.build]
+args = "<name>"
+run = { job = "build" }
+
+[job.build]
+input = ["name"]
+
+[[job.build.step]]
+name = "compile"
+run = "echo compile"
+"#;
+
+async fn start(ctx: &TestContext, job_id: &str, priority: i32, after: Vec<JobDependency>) {
+    let mut event = command_event(
+        job_id,
+        "build",
+        "build",
+        [("name".to_string(), job_id.to_string())]
+            .into_iter()
+            .collect(),
+        &ctx.project_root,
+    );
+    if let Event::CommandRun {
+        priority: p,
+        after: a,
+        ..
+    } = &mut event
+    {
+        *p = priority;
+        *a = after;
+    }
+    ctx.runtime.handle_event(event).await.unwrap();
+}
+
+/// Finish a job's only step and deliver its terminal transition.
+async fn finish(ctx: &TestContext, job_id: &str) {
+    ctx.runtime
+        .handle_event(Event::ShellExited {
+            job_id: JobId::new(job_id),
+            step: "compile".to_string(),
+            exit_code: 0,
+            stdout: None,
+            stderr: None,
+            timed_out: false,
+        })
+        .await
+        .unwrap();
+    // Result events are not re-fed in tests; deliver the transition ourselves
+    ctx.runtime
+        .handle_event(Event::JobAdvanced {
+            id: JobId::new(job_id),
+            step: "done".to_string(),
+        })
+        .await
+        .unwrap();
+}
+
+fn status(ctx: &TestContext, job_id: &str) -> StepStatus {
+    ctx.runtime.get_job(job_id).unwrap().step_status
+}
+
+fn is_queued(ctx: &TestContext, job_id: &str) -> bool {
+    ctx.runtime.get_job(job_id).unwrap().queued
+}
+
+#[tokio::test]
+async fn jobs_over_the_limit_are_queued() {
+    let mut ctx = setup_with_runbook(ADMISSION_RUNBOOK).await;
+    ctx.runtime.max_jobs = Some(1);
+    start(&ctx, "job-1", 0, Vec::new()).await;
+    start(&ctx, "job-2", 0, Vec::new()).await;
+
+    assert_eq!(status(&ctx, "job-1"), StepStatus::Running);
+    assert_eq!(
+        status(&ctx, "job-2"),
+        StepStatus::Blocked("queued".to_string())
+    );
+    assert!(is_queued(&ctx, "job-2"));
+}
+
+#[tokio::test]
+async fn freed_slot_goes_to_highest_priority_then_oldest() {
+    let mut ctx = setup_with_runbook(ADMISSION_RUNBOOK).await;
+    ctx.runtime.max_jobs = Some(1);
+    start(&ctx, "job-1", 0, Vec::new()).await;
+    start(&ctx, "job-2", 0, Vec::new()).await;
+    start(&ctx, "job-3", 5, Vec::new()).await;
+    start(&ctx, "job-4", 0, Vec::new()).await;
+
+    finish(&ctx, "job-1").await;
+    assert_eq!(status(&ctx, "job-3"), StepStatus::Running);
+    assert!(!is_queued(&ctx, "job-3"));
+    assert!(is_queued(&ctx, "job-2") && is_queued(&ctx, "job-4"));
+
+    finish(&ctx, "job-3").await;
+    assert_eq!(status(&ctx, "job-2"), StepStatus::Running);
+    assert!(is_queued(&ctx, "job-4"));
+}
+
+#[tokio::test]
+async fn project_config_limits_its_namespace() {
+    let ctx = setup_with_runbook(ADMISSION_RUNBOOK).await;
+    std::fs::write(
+        ctx.project_root.join(".oj/config.toml"),
+        "[limits]\nmax_jobs = 2\n",
+    )
+    .unwrap();
+    for id in ["job-1", "job-2", "job-3"] {
+        start(&ctx, id, 0, Vec::new()).await;
+    }
+
+    assert_eq!(
+        ctx.runtime.get_job("job-1").unwrap().project_root,
+        Some(ctx.project_root.clone())
+    );
+    assert_eq!(status(&ctx, "job-2"), StepStatus::Running);
+    assert!(is_queued(&ctx, "job-3"));
+}
+
+#[tokio::test]
+async fn raised_project_limit_applies_to_queued_jobs() {
+    let ctx = setup_with_runbook(ADMISSION_RUNBOOK).await;
+    let config = ctx.project_root.join(".oj/config.toml");
+    std::fs::write(&config, "[limits]\nmax_jobs = 1\n").unwrap();
+    start(&ctx, "job-1", 0, Vec::new()).await;
+    start(&ctx, "job-2", 0, Vec::new()).await;
+    assert!(is_queued(&ctx, "job-2"));
+
+    std::fs::write(&config, "[limits]\nmax_jobs = 2\n").unwrap();
+    ctx.runtime.admit_queued_jobs().await.unwrap();
+
+    assert_eq!(status(&ctx, "job-2"), StepStatus::Running);
+    assert!(!is_queued(&ctx, "job-2"));
+}
+
+#[tokio::test]
+async fn running_agent_runs_take_a_slot() {
+    let mut ctx = setup_with_runbook(ADMISSION_RUNBOOK).await;
+    ctx.runtime.max_jobs = Some(1);
+    ctx.runtime.lock_state_mut(|state| {
+        state.apply_event(&Event::AgentRunCreated {
+            id: AgentRunId::new("run-1"),
+            agent_name: "helper".to_string(),
+            command_name: "help".to_string(),
+            namespace: String::new(),
+            cwd: ctx.project_root.clone(),
+            runbook_hash: "hash".to_string(),
+            vars: HashMap::new(),
+            created_at_epoch_ms: 0,
+        })
+    });
+    start(&ctx, "job-1", 0, Vec::new()).await;
+    assert!(is_queued(&ctx, "job-1"));
+
+    // The finished run hands its slot to the queue
+    let finished = Event::AgentRunStatusChanged {
+        id: AgentRunId::new("run-1"),
+        status: AgentRunStatus::Completed,
+        reason: None,
+    };
+    ctx.runtime
+        .lock_state_mut(|state| state.apply_event(&finished));
+    ctx.runtime.handle_event(finished).await.unwrap();
+
+    assert_eq!(status(&ctx, "job-1"), StepStatus::Running);
+}
+
+#[tokio::test]
+async fn jobs_held_on_dependencies_do_not_take_a_slot() {
+    let mut ctx = setup_with_runbook(ADMISSION_RUNBOOK).await;
+    ctx.runtime.max_jobs = Some(1);
+    start(&ctx, "job-1", 0, Vec::new()).await;
+    let after = vec![JobDependency::new("job-1", DependencyCondition::Done)];
+    start(&ctx, "job-2", 0, after).await;
+    start(&ctx, "job-3", 0, Vec::new()).await;
+
+    assert!(!is_queued(&ctx, "job-2"));
+    assert!(is_queued(&ctx, "job-3"));
+
+    // The queued job gets the slot; the dependent queues behind it
+    finish(&ctx, "job-1").await;
+    assert_eq!(status(&ctx, "job-3"), StepStatus::Running);
+    assert!(is_queued(&ctx, "job-2"));
+}
+
+#[tokio::test]
+async fn cancelling_a_queued_job_removes_it_from_the_queue() {
+    let mut ctx = setup_with_runbook(ADMISSION_RUNBOOK).await;
+    ctx.runtime.max_jobs = Some(1);
+    start(&ctx, "job-1", 0, Vec::new()).await;
+    start(&ctx, "job-2", 0, Vec::new()).await;
+
+    ctx.runtime
+        .handle_event(Event::JobCancel {
+            id: JobId::new("job-2"),
+        })
+        .await
+        .unwrap();
+    finish(&ctx, "job-1").await;
+
+    assert_eq!(ctx.runtime.get_job("job-2").unwrap().step, "cancelled");
+    assert!(ctx
+        .runtime
+        .lock_state(|s| oj_core::admission_order(s.jobs.values()).is_empty()));
+}
+
+#[tokio::test]
+async fn job_that_fails_to_start_does_not_block_the_queue() {
+    let mut ctx = setup_with_runbook(ADMISSION_RUNBOOK).await;
+    ctx.runtime.max_jobs = Some(1);
+    start(&ctx, "job-1", 0, Vec::new()).await;
+    start(&ctx, "job-2", 5, Vec::new()).await;
+    start(&ctx, "job-3", 0, Vec::new()).await;
+    // job-2 is admitted first but its step can no longer be found
+    ctx.runtime.lock_state_mut(|s| {
+        s.jobs.get_mut("job-2").unwrap().step = "missing".to_string();
+    });
+
+    finish(&ctx, "job-1").await;
+
+    assert_eq!(ctx.runtime.get_job("job-2").unwrap().step, "failed");
+    assert_eq!(status(&ctx, "job-3"), StepStatus::Running);
+    assert!(!is_queued(&ctx, "job-3"));
+}
@@ -0,0 +1,156 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Approval step tests
+
+use super::*;
+use oj_core::{DecisionSource, StepStatus};
+
+const APPROVAL_RUNBOOK: &str = r#"
+[command.release]
+args = "<version>"
+run = { job = "release" }
+
+[job.release]
+input = ["version"]
+
+[[job.release.step]]
+name = "signoff"
+run = { approval = { prompt = "Ship ${var.version}?", options = ["ship", "hold"], fields = ["reason"] } }
+on_done = [
+  { if = "${steps.signoff.outputs.choice} == ship", step = "tag" },
+  { step = "hold" },
+]
+
+[[job.release.step]]
+name = "tag"
+run = "git tag ${var.version}"
+
+[[job.release.step]]
+name = "hold"
+run = "echo held"
+"#;
+
+async fn start_release(ctx: &TestContext) {
+    ctx.runtime
+        .handle_event(command_event(
+            "job-1",
+            "release",
+            "release",
+            [("version".to_string(), "v1.2".to_string())]
+                .into_iter()
+                .collect(),
+            &ctx.project_root,
+        ))
+        .await
+        .unwrap();
+}
+
+fn pending_decision(ctx: &TestContext) -> String {
+    match ctx.runtime.get_job("job-1").unwrap().step_status {
+        StepStatus::Waiting(Some(id)) => id,
+        other => panic!("expected job to wait on a decision, got {:?}", other),
+    }
+}
+
+/// Apply a resolution to state the way the daemon does, then deliver it.
+async fn resolve(ctx: &TestContext, chosen: usize, values: &[(&str, &str)]) {
+    let event = Event::DecisionResolved {
+        id: pending_decision(ctx),
+        chosen: Some(chosen),
+        message: None,
+        resolved_at_ms: 2_000,
+        namespace: String::new(),
+        values: values
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect(),
+    };
+    ctx.runtime
+        .lock_state_mut(|state| state.apply_event(&event));
+    ctx.runtime.handle_event(event).await.unwrap();
+}
+
+#[tokio::test]
+async fn approval_step_waits_on_a_decision() {
+    let ctx = setup_with_runbook(APPROVAL_RUNBOOK).await;
+    start_release(&ctx).await;
+
+    let id = pending_decision(&ctx);
+    let decision = ctx
+        .runtime
+        .lock_state(|s| s.decisions.get(&id).cloned())
+        .unwrap();
+    assert_eq!(decision.source, DecisionSource::Approval);
+    assert_eq!(decision.step.as_deref(), Some("signoff"));
+    assert_eq!(decision.fields, vec!["reason".to_string()]);
+    assert!(decision.context.contains("Ship v1.2?"));
+    let labels: Vec<&str> = decision.options.iter().map(|o| o.label.as_str()).collect();
+    assert_eq!(labels, vec!["ship", "hold", "Cancel"]);
+
+    let calls = ctx.notifier.calls();
+    assert_eq!(calls.len(), 1);
+    assert!(calls[0].title.starts_with("Approval needed: "));
+    assert_eq!(calls[0].message, "Ship v1.2?");
+}
+
+#[tokio::test]
+async fn chosen_option_routes_and_fields_become_vars() {
+    let ctx = setup_with_runbook(APPROVAL_RUNBOOK).await;
+    start_release(&ctx).await;
+
+    resolve(&ctx, 1, &[("reason", "staging verified")]).await;
+
+    let job = ctx.runtime.get_job("job-1").unwrap();
+    assert_eq!(job.step, "tag");
+    assert_eq!(job.vars["steps.signoff.outputs.choice"], "ship");
+    assert_eq!(job.vars["steps.signoff.outputs.reason"], "staging verified");
+}
+
+#[tokio::test]
+async fn other_option_takes_the_fallback_branch() {
+    let ctx = setup_with_runbook(APPROVAL_RUNBOOK).await;
+    start_release(&ctx).await;
+
+    resolve(&ctx, 2, &[("reason", "waiting on QA")]).await;
+
+    let job = ctx.runtime.get_job("job-1").unwrap();
+    assert_eq!(job.step, "hold");
+    assert_eq!(job.vars["steps.signoff.outputs.choice"], "hold");
+}
+
+#[tokio::test]
+async fn cancel_option_cancels_the_job() {
+    let ctx = setup_with_runbook(APPROVAL_RUNBOOK).await;
+    start_release(&ctx).await;
+
+    resolve(&ctx, 3, &[]).await;
+
+    assert_eq!(ctx.runtime.get_job("job-1").unwrap().step, "cancelled");
+}
+
+#[tokio::test]
+async fn resolving_a_stale_decision_does_nothing() {
+    let ctx = setup_with_runbook(APPROVAL_RUNBOOK).await;
+    start_release(&ctx).await;
+    resolve(&ctx, 2, &[("reason", "later")]).await;
+
+    // A late copy of the same resolution must not advance the job again
+    let id = ctx
+        .runtime
+        .lock_state(|s| s.decisions.keys().next().cloned())
+        .unwrap();
+    ctx.runtime
+        .handle_event(Event::DecisionResolved {
+            id,
+            chosen: Some(1),
+            message: None,
+            resolved_at_ms: 3_000,
+            namespace: String::new(),
+            values: HashMap::new(),
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(ctx.runtime.get_job("job-1").unwrap().step, "hold");
+}
@@ -0,0 +1,214 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Budget tests: `agent:usage` reports checked against agent and job budgets
+
+use super::*;
+use oj_adapters::SessionCall;
+use oj_core::{DecisionSource, TokenUsage};
+
+fn budget_runbook(job_extra: &str, agent_extra: &str) -> String {
+    format!(
+        r#"
+[command.build]
+args = "<name> <prompt>"
+run = {{ job = "build" }}
+
+[job.build]
+input = ["name", "prompt"]
+{job_extra}
+
+[[job.build.step]]
+name = "init"
+run = "echo init"
+on_done = "plan"
+
+[[job.build.step]]
+name = "plan"
+run = {{ agent = "planner" }}
+
+[agent.planner]
+run = "claude --print"
+{agent_extra}
+"#
+    )
+}
+
+/// Create the job and advance it to the agent step.
+async fn start_agent_step(ctx: &TestContext) -> AgentId {
+    let job_id = create_job(ctx).await;
+    ctx.runtime
+        .handle_event(Event::ShellExited {
+            job_id: JobId::new(&job_id),
+            step: "init".to_string(),
+            exit_code: 0,
+            stdout: None,
+            stderr: None,
+            timed_out: false,
+        })
+        .await
+        .unwrap();
+    get_agent_id(ctx, &job_id).unwrap()
+}
+
+/// Apply a usage report to state the way the daemon does, then deliver it.
+async fn report_usage(ctx: &TestContext, agent_id: &AgentId, input_tokens: u64) -> Vec<Event> {
+    report_model_usage(ctx, agent_id, "claude-sonnet-4-5-20250929", input_tokens).await
+}
+
+async fn report_model_usage(
+    ctx: &TestContext,
+    agent_id: &AgentId,
+    model: &str,
+    input_tokens: u64,
+) -> Vec<Event> {
+    let event = Event::AgentUsage {
+        agent_id: agent_id.clone(),
+        usage: TokenUsage {
+            input_tokens,
+            ..Default::default()
+        },
+        model: Some(model.to_string()),
+    };
+    ctx.runtime
+        .lock_state_mut(|state| state.apply_event(&event));
+    ctx.runtime.handle_event(event).await.unwrap()
+}
+
+fn budget_decisions(ctx: &TestContext) -> Vec<oj_core::Decision> {
+    ctx.runtime.lock_state(|state| {
+        state
+            .decisions
+            .values()
+            .filter(|d| d.context.contains("went over budget"))
+            .cloned()
+            .collect()
+    })
+}
+
+#[tokio::test]
+async fn usage_within_budget_does_nothing() {
+    let ctx = setup_with_runbook(&budget_runbook("", "budget = { tokens = 1000 }")).await;
+    let agent_id = start_agent_step(&ctx).await;
+
+    let events = report_usage(&ctx, &agent_id, 1000).await;
+
+    assert!(events.is_empty());
+    let job = ctx.runtime.get_job("pipe-1").unwrap();
+    assert!(!job.step_status.is_waiting());
+    assert!(budget_decisions(&ctx).is_empty());
+}
+
+#[tokio::test]
+async fn agent_budget_escalates_once_by_default() {
+    let ctx = setup_with_runbook(&budget_runbook("", "budget = { tokens = 1000 }")).await;
+    let agent_id = start_agent_step(&ctx).await;
+
+    report_usage(&ctx, &agent_id, 1200).await;
+
+    let job = ctx.runtime.get_job("pipe-1").unwrap();
+    assert!(job.step_status.is_waiting());
+    let decisions = budget_decisions(&ctx);
+    assert_eq!(decisions.len(), 1);
+    assert_eq!(decisions[0].source, DecisionSource::Idle);
+    assert!(decisions[0]
+        .context
+        .contains("agent used 1200 tokens of 1000"));
+    assert!(ctx
+        .runtime
+        .lock_state(|s| s.agents[agent_id.as_str()].budget_exceeded));
+
+    // Further spend doesn't fire again
+    report_usage(&ctx, &agent_id, 5000).await;
+    assert_eq!(budget_decisions(&ctx).len(), 1);
+}
+
+#[tokio::test]
+async fn job_budget_fires_agent_on_budget() {
+    let ctx = setup_with_runbook(&budget_runbook(
+        "budget = { tokens = 1000 }",
+        "on_budget = \"fail\"",
+    ))
+    .await;
+    let agent_id = start_agent_step(&ctx).await;
+
+    report_usage(&ctx, &agent_id, 1500).await;
+
+    let job = ctx.runtime.get_job("pipe-1").unwrap();
+    assert_eq!(job.step, "failed");
+    assert!(job.budget_exceeded);
+    assert_eq!(
+        job.error.as_deref(),
+        Some("budget exceeded: job used 1500 tokens of 1000")
+    );
+}
+
+#[tokio::test]
+async fn nudge_without_message_asks_agent_to_wrap_up() {
+    let ctx = setup_with_runbook(&budget_runbook(
+        "",
+        "budget = { tokens = 10 }\non_budget = \"nudge\"",
+    ))
+    .await;
+    let agent_id = start_agent_step(&ctx).await;
+    let session_id = ctx.runtime.get_job("pipe-1").unwrap().session_id.unwrap();
+    ctx.sessions.add_session(&session_id, true);
+
+    report_usage(&ctx, &agent_id, 11).await;
+
+    let sent = ctx.sessions.calls().into_iter().any(|call| match call {
+        SessionCall::Send { input, .. } => input.contains("Wrap up now"),
+        SessionCall::SendLiteral { text, .. } => text.contains("Wrap up now"),
+        _ => false,
+    });
+    assert!(sent, "expected a wrap-up nudge");
+    let job = ctx.runtime.get_job("pipe-1").unwrap();
+    assert!(!job.step_status.is_waiting());
+}
+
+#[tokio::test]
+async fn usd_budget_uses_model_prices() {
+    // Sonnet input is $3 per million tokens
+    let ctx = setup_with_runbook(&budget_runbook(
+        "",
+        "budget = { usd = 3 }\non_budget = \"fail\"",
+    ))
+    .await;
+    let agent_id = start_agent_step(&ctx).await;
+
+    report_usage(&ctx, &agent_id, 1_000_000).await;
+    assert_eq!(ctx.runtime.get_job("pipe-1").unwrap().step, "plan");
+
+    report_usage(&ctx, &agent_id, 1_100_000).await;
+    let job = ctx.runtime.get_job("pipe-1").unwrap();
+    assert_eq!(job.step, "failed");
+    assert_eq!(
+        job.error.as_deref(),
+        Some("budget exceeded: agent spent $3.30 of $3.00")
+    );
+}
+
+#[tokio::test]
+async fn usd_budget_on_unpriced_model_warns_once() {
+    let ctx = setup_with_runbook(&budget_runbook(
+        "",
+        "budget = { usd = 3 }\non_budget = \"fail\"",
+    ))
+    .await;
+    let agent_id = start_agent_step(&ctx).await;
+
+    report_model_usage(&ctx, &agent_id, "local-llama", 1_000_000).await;
+    report_model_usage(&ctx, &agent_id, "local-llama", 2_000_000).await;
+
+    assert_eq!(ctx.runtime.get_job("pipe-1").unwrap().step, "plan");
+    let log = std::fs::read_to_string(crate::log_paths::job_log_path(
+        ctx.runtime.logger.log_dir(),
+        "pipe-1",
+    ))
+    .unwrap();
+    assert_eq!(
+        log.matches("no price known for model 'local-llama'; usd budget not enforced")
+            .count(),
+        1
+    );
+}
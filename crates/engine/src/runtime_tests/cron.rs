@@ -104,8 +104,12 @@ async fn cron_start_sets_timer() {
             project_root: ctx.project_root.clone(),
             runbook_hash: runbook_hash.clone(),
             interval: "30m".to_string(),
+            schedule: None,
+            timezone: None,
             run_target: "job:cleanup".to_string(),
             namespace: String::new(),
+            started_at_ms: ctx.clock.epoch_ms(),
+            resumed_from_ms: None,
         })
         .await
         .unwrap();
@@ -118,7 +122,7 @@ async fn cron_start_sets_timer() {
         assert!(
             matches!(state.run_target, crate::runtime::handlers::cron::CronRunTarget::Job(ref p) if p == "cleanup")
         );
-        assert_eq!(state.interval, "30m");
+        assert_eq!(state.timing.to_string(), "30m");
     }
 
     // Timer should have been set
@@ -160,8 +164,12 @@ async fn cron_stop_cancels_timer() {
             project_root: ctx.project_root.clone(),
             runbook_hash: runbook_hash.clone(),
             interval: "30m".to_string(),
+            schedule: None,
+            timezone: None,
             run_target: "job:cleanup".to_string(),
             namespace: String::new(),
+            started_at_ms: ctx.clock.epoch_ms(),
+            resumed_from_ms: None,
         })
         .await
         .unwrap();
@@ -218,8 +226,12 @@ async fn cron_timer_fired_creates_job() {
             project_root: ctx.project_root.clone(),
             runbook_hash: runbook_hash.clone(),
             interval: "30m".to_string(),
+            schedule: None,
+            timezone: None,
             run_target: "job:cleanup".to_string(),
             namespace: String::new(),
+            started_at_ms: ctx.clock.epoch_ms(),
+            resumed_from_ms: None,
         })
         .await
         .unwrap();
@@ -264,8 +276,12 @@ async fn cron_timer_fired_reloads_runbook() {
             project_root: ctx.project_root.clone(),
             runbook_hash: runbook_hash.clone(),
             interval: "30m".to_string(),
+            schedule: None,
+            timezone: None,
             run_target: "job:cleanup".to_string(),
             namespace: String::new(),
+            started_at_ms: ctx.clock.epoch_ms(),
+            resumed_from_ms: None,
         })
         .await
         .unwrap();
@@ -348,6 +364,7 @@ async fn cron_once_job_steps_execute() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -364,6 +381,7 @@ async fn cron_once_job_steps_execute() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -392,8 +410,12 @@ async fn cron_timer_fired_reschedules_timer() {
             project_root: ctx.project_root.clone(),
             runbook_hash: runbook_hash.clone(),
             interval: "30m".to_string(),
+            schedule: None,
+            timezone: None,
             run_target: "job:cleanup".to_string(),
             namespace: String::new(),
+            started_at_ms: ctx.clock.epoch_ms(),
+            resumed_from_ms: None,
         })
         .await
         .unwrap();
@@ -432,8 +454,12 @@ async fn cron_timer_fired_when_stopped_is_noop() {
             project_root: ctx.project_root.clone(),
             runbook_hash: runbook_hash.clone(),
             interval: "30m".to_string(),
+            schedule: None,
+            timezone: None,
             run_target: "job:cleanup".to_string(),
             namespace: String::new(),
+            started_at_ms: ctx.clock.epoch_ms(),
+            resumed_from_ms: None,
         })
         .await
         .unwrap();
@@ -486,8 +512,12 @@ async fn cron_start_with_namespace() {
             project_root: ctx.project_root.clone(),
             runbook_hash: runbook_hash.clone(),
             interval: "30m".to_string(),
+            schedule: None,
+            timezone: None,
             run_target: "job:cleanup".to_string(),
             namespace: "myproject".to_string(),
+            started_at_ms: ctx.clock.epoch_ms(),
+            resumed_from_ms: None,
         })
         .await
         .unwrap();
@@ -513,8 +543,106 @@ async fn cron_start_with_namespace() {
         })
         .collect();
     assert!(
-        timer_ids.iter().any(|id| *id == "cron:myproject/janitor"),
+        timer_ids.contains(&"cron:myproject/janitor"),
         "timer ID should include namespace: {:?}",
         timer_ids
     );
 }
+
+// ---- Test: schedule crons fire at wall-clock times ----
+
+/// Advance the clock and report whether the janitor cron timer fired.
+fn advance_and_check_cron_timer(ctx: &TestContext, secs: u64) -> bool {
+    let scheduler = ctx.runtime.executor.scheduler();
+    let mut sched = scheduler.lock();
+    ctx.clock.advance(std::time::Duration::from_secs(secs));
+    sched
+        .fired_timers(ctx.clock.now())
+        .iter()
+        .any(|e| matches!(e, Event::TimerStart { id } if id.as_str() == "cron:janitor"))
+}
+
+#[tokio::test]
+async fn schedule_cron_fires_at_next_matching_time_without_drift() {
+    let ctx = setup_with_runbook(CRON_RUNBOOK).await;
+    let (runbook_json, runbook_hash) = hash_cron_runbook();
+    load_runbook(&ctx, &runbook_json, &runbook_hash).await;
+
+    // 2026-03-04 08:59:30 UTC
+    ctx.clock.set_epoch_ms(1_772_614_770_000);
+    ctx.runtime
+        .handle_event(Event::CronStarted {
+            cron_name: "janitor".to_string(),
+            project_root: ctx.project_root.clone(),
+            runbook_hash: runbook_hash.clone(),
+            interval: String::new(),
+            schedule: Some("0 9 * * *".to_string()),
+            timezone: Some("UTC".to_string()),
+            run_target: "job:cleanup".to_string(),
+            namespace: String::new(),
+            started_at_ms: ctx.clock.epoch_ms(),
+            resumed_from_ms: None,
+        })
+        .await
+        .unwrap();
+    {
+        let crons = ctx.runtime.cron_states.lock();
+        assert_eq!(crons["janitor"].timing.to_string(), "0 9 * * * (UTC)");
+    }
+
+    assert!(!advance_and_check_cron_timer(&ctx, 29));
+    assert!(advance_and_check_cron_timer(&ctx, 1));
+
+    // The tick is handled 15s late; the next one is still 09:00 tomorrow
+    ctx.clock.advance(std::time::Duration::from_secs(15));
+    ctx.runtime
+        .handle_event(Event::TimerStart {
+            id: oj_core::TimerId::cron("janitor", ""),
+        })
+        .await
+        .unwrap();
+    assert_eq!(ctx.runtime.jobs().len(), 1);
+
+    assert!(!advance_and_check_cron_timer(&ctx, 24 * 3600 - 16));
+    assert!(advance_and_check_cron_timer(&ctx, 1));
+}
+
+#[tokio::test]
+async fn schedule_cron_fired_early_does_not_fire_twice() {
+    let ctx = setup_with_runbook(CRON_RUNBOOK).await;
+    let (runbook_json, runbook_hash) = hash_cron_runbook();
+    load_runbook(&ctx, &runbook_json, &runbook_hash).await;
+
+    // 2026-03-04 08:59:30 UTC
+    ctx.clock.set_epoch_ms(1_772_614_770_000);
+    ctx.runtime
+        .handle_event(Event::CronStarted {
+            cron_name: "janitor".to_string(),
+            project_root: ctx.project_root.clone(),
+            runbook_hash,
+            interval: String::new(),
+            schedule: Some("0 9 * * *".to_string()),
+            timezone: Some("UTC".to_string()),
+            run_target: "job:cleanup".to_string(),
+            namespace: String::new(),
+            started_at_ms: ctx.clock.epoch_ms(),
+            resumed_from_ms: None,
+        })
+        .await
+        .unwrap();
+
+    // The monotonic timer fires half a second before 09:00 on the wall clock
+    ctx.clock.advance(std::time::Duration::from_millis(29_500));
+    ctx.runtime
+        .handle_event(Event::TimerStart {
+            id: oj_core::TimerId::cron("janitor", ""),
+        })
+        .await
+        .unwrap();
+    assert_eq!(ctx.runtime.jobs().len(), 1);
+
+    // The next tick is 09:00 tomorrow, not 09:00 again in half a second
+    assert!(!advance_and_check_cron_timer(&ctx, 1));
+    assert!(!advance_and_check_cron_timer(&ctx, 24 * 3600 - 1));
+    assert!(advance_and_check_cron_timer(&ctx, 1));
+}
@@ -93,8 +93,12 @@ async fn cron_start_agent_sets_timer() {
             project_root: ctx.project_root.clone(),
             runbook_hash: runbook_hash.clone(),
             interval: "30m".to_string(),
+            schedule: None,
+            timezone: None,
             run_target: "agent:doctor".to_string(),
             namespace: String::new(),
+            started_at_ms: ctx.clock.epoch_ms(),
+            resumed_from_ms: None,
         })
         .await
         .unwrap();
@@ -132,8 +136,12 @@ async fn cron_timer_fires_agent() {
             project_root: ctx.project_root.clone(),
             runbook_hash: runbook_hash.clone(),
             interval: "30m".to_string(),
+            schedule: None,
+            timezone: None,
             run_target: "agent:doctor".to_string(),
             namespace: String::new(),
+            started_at_ms: ctx.clock.epoch_ms(),
+            resumed_from_ms: None,
         })
         .await
         .unwrap();
@@ -214,8 +222,12 @@ async fn cron_agent_concurrency_skip() {
             project_root: ctx.project_root.clone(),
             runbook_hash: runbook_hash.clone(),
             interval: "30m".to_string(),
+            schedule: None,
+            timezone: None,
             run_target: "agent:doctor".to_string(),
             namespace: String::new(),
+            started_at_ms: ctx.clock.epoch_ms(),
+            resumed_from_ms: None,
         })
         .await
         .unwrap();
@@ -308,8 +320,12 @@ async fn cron_agent_concurrency_respawns_after_complete() {
             project_root: ctx.project_root.clone(),
             runbook_hash: runbook_hash.clone(),
             interval: "30m".to_string(),
+            schedule: None,
+            timezone: None,
             run_target: "agent:doctor".to_string(),
             namespace: String::new(),
+            started_at_ms: ctx.clock.epoch_ms(),
+            resumed_from_ms: None,
         })
         .await
         .unwrap();
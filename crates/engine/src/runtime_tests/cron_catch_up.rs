@@ -0,0 +1,147 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Cron catch-up tests: runs missed while the daemon was down
+
+use super::*;
+
+use super::cron::load_runbook;
+use crate::log_paths::cron_log_path;
+
+/// 2026-03-04 12:00:00 UTC
+const NOW_MS: u64 = 1_772_625_600_000;
+const MINUTE_MS: u64 = 60_000;
+
+fn catch_up_runbook(timing: &str, extra: &str) -> String {
+    format!(
+        r#"
+[cron.janitor]
+{timing}
+{extra}
+run = {{ job = "cleanup" }}
+
+[job.cleanup]
+
+[[job.cleanup.step]]
+name = "prune"
+run = "echo pruning"
+"#
+    )
+}
+
+/// Set up a cron and resume it as the daemon does after a restart.
+async fn resume_cron(
+    runbook: &str,
+    interval: &str,
+    schedule: Option<&str>,
+    since_ms: u64,
+) -> TestContext {
+    let ctx = setup_with_runbook(runbook).await;
+    let (runbook_json, runbook_hash) = hash_runbook(runbook);
+    load_runbook(&ctx, &runbook_json, &runbook_hash).await;
+
+    ctx.clock.set_epoch_ms(NOW_MS);
+    ctx.runtime
+        .handle_event(Event::CronStarted {
+            cron_name: "janitor".to_string(),
+            project_root: ctx.project_root.clone(),
+            runbook_hash,
+            interval: interval.to_string(),
+            schedule: schedule.map(str::to_string),
+            timezone: schedule.map(|_| "UTC".to_string()),
+            run_target: "job:cleanup".to_string(),
+            namespace: String::new(),
+            started_at_ms: ctx.clock.epoch_ms(),
+            resumed_from_ms: Some(since_ms),
+        })
+        .await
+        .unwrap();
+    ctx
+}
+
+fn cron_log(ctx: &TestContext) -> String {
+    let path = cron_log_path(&ctx.project_root.join("logs"), "janitor");
+    std::fs::read_to_string(path).unwrap_or_default()
+}
+
+#[tokio::test]
+async fn missed_runs_are_skipped_by_default() {
+    let runbook = catch_up_runbook("interval = \"30m\"", "");
+    let ctx = resume_cron(&runbook, "30m", None, NOW_MS - 125 * MINUTE_MS).await;
+
+    assert!(ctx.runtime.jobs().is_empty());
+    let log = cron_log(&ctx);
+    assert!(
+        log.contains(
+            "catch-up: skipped 4 missed runs since 2026-03-04T09:55:00Z (catch_up = none)"
+        ),
+        "{log}"
+    );
+}
+
+#[tokio::test]
+async fn catch_up_once_runs_a_single_job_for_missed_schedule_times() {
+    let runbook = catch_up_runbook(
+        "schedule = \"0 9 * * *\"\ntimezone = \"UTC\"",
+        "catch_up = \"once\"",
+    );
+    // Last fired three mornings ago
+    let since_ms = NOW_MS - 3 * 24 * 60 * MINUTE_MS - 3 * 60 * MINUTE_MS;
+    let ctx = resume_cron(&runbook, "", Some("0 9 * * *"), since_ms).await;
+
+    assert_eq!(ctx.runtime.jobs().len(), 1);
+    let log = cron_log(&ctx);
+    assert!(
+        log.contains("catch-up: 3 missed runs since 2026-03-01T09:00:00Z, running once"),
+        "{log}"
+    );
+    assert!(log.contains("catch-up: triggered job cleanup"), "{log}");
+}
+
+#[tokio::test]
+async fn catch_up_all_runs_missed_ticks_up_to_concurrency() {
+    let runbook = catch_up_runbook("interval = \"10m\"", "catch_up = \"all\"\nconcurrency = 2");
+    let ctx = resume_cron(&runbook, "10m", None, NOW_MS - 35 * MINUTE_MS).await;
+
+    assert_eq!(ctx.runtime.jobs().len(), 2);
+    let log = cron_log(&ctx);
+    assert!(log.contains("3 missed runs since"), "{log}");
+    assert!(
+        log.contains("skip: job 'cleanup' at max concurrency (2/2)"),
+        "{log}"
+    );
+    assert!(
+        log.contains("catch-up: skipped the remaining 1 of 3 missed runs"),
+        "{log}"
+    );
+}
+
+#[tokio::test]
+async fn resume_without_missed_runs_does_nothing() {
+    let runbook = catch_up_runbook("interval = \"30m\"", "catch_up = \"all\"\nconcurrency = 2");
+    let ctx = resume_cron(&runbook, "30m", None, NOW_MS - 20 * MINUTE_MS).await;
+
+    assert!(ctx.runtime.jobs().is_empty());
+    assert!(!cron_log(&ctx).contains("catch-up"));
+
+    // The next tick stays on the original 30m grid: 10 minutes away, not 30
+    let scheduler = ctx.runtime.executor.scheduler();
+    let mut sched = scheduler.lock();
+    ctx.clock.advance(std::time::Duration::from_secs(9 * 60));
+    assert!(cron_timers_fired(&mut sched, &ctx).is_empty());
+    ctx.clock.advance(std::time::Duration::from_secs(60));
+    assert_eq!(cron_timers_fired(&mut sched, &ctx), vec!["cron:janitor"]);
+}
+
+fn cron_timers_fired(sched: &mut crate::scheduler::Scheduler, ctx: &TestContext) -> Vec<String> {
+    sched
+        .fired_timers(ctx.clock.now())
+        .into_iter()
+        .filter_map(|e| match e {
+            Event::TimerStart { id } if id.as_str().starts_with("cron:") => {
+                Some(id.as_str().to_string())
+            }
+            _ => None,
+        })
+        .collect()
+}
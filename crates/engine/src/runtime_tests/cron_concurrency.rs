@@ -81,6 +81,9 @@ async fn cron_job_concurrency_skip() {
                 created_at_epoch_ms: 1000,
                 namespace: String::new(),
                 cron_name: Some("deployer".to_string()),
+                after: vec![],
+                priority: 0,
+                project_root: None,
             },
         })
         .await
@@ -100,8 +103,12 @@ async fn cron_job_concurrency_skip() {
             project_root: ctx.project_root.clone(),
             runbook_hash: runbook_hash.clone(),
             interval: "10m".to_string(),
+            schedule: None,
+            timezone: None,
             run_target: "job:deploy".to_string(),
             namespace: String::new(),
+            started_at_ms: ctx.clock.epoch_ms(),
+            resumed_from_ms: None,
         })
         .await
         .unwrap();
@@ -161,6 +168,9 @@ async fn cron_job_concurrency_respawns_after_complete() {
                 created_at_epoch_ms: 1000,
                 namespace: String::new(),
                 cron_name: Some("deployer".to_string()),
+                after: vec![],
+                priority: 0,
+                project_root: None,
             },
         })
         .await
@@ -192,8 +202,12 @@ async fn cron_job_concurrency_respawns_after_complete() {
             project_root: ctx.project_root.clone(),
             runbook_hash: runbook_hash.clone(),
             interval: "10m".to_string(),
+            schedule: None,
+            timezone: None,
             run_target: "job:deploy".to_string(),
             namespace: String::new(),
+            started_at_ms: ctx.clock.epoch_ms(),
+            resumed_from_ms: None,
         })
         .await
         .unwrap();
@@ -245,6 +259,9 @@ async fn cron_job_concurrency_default_singleton() {
                 created_at_epoch_ms: 1000,
                 namespace: String::new(),
                 cron_name: Some("deployer".to_string()),
+                after: vec![],
+                priority: 0,
+                project_root: None,
             },
         })
         .await
@@ -257,8 +274,12 @@ async fn cron_job_concurrency_default_singleton() {
             project_root: ctx.project_root.clone(),
             runbook_hash: runbook_hash.clone(),
             interval: "10m".to_string(),
+            schedule: None,
+            timezone: None,
             run_target: "job:deploy".to_string(),
             namespace: String::new(),
+            started_at_ms: ctx.clock.epoch_ms(),
+            resumed_from_ms: None,
         })
         .await
         .unwrap();
@@ -306,6 +327,9 @@ async fn cron_job_concurrency_allows_multiple() {
                 created_at_epoch_ms: 1000,
                 namespace: String::new(),
                 cron_name: Some("deployer".to_string()),
+                after: vec![],
+                priority: 0,
+                project_root: None,
             },
         })
         .await
@@ -318,8 +342,12 @@ async fn cron_job_concurrency_allows_multiple() {
             project_root: ctx.project_root.clone(),
             runbook_hash: runbook_hash.clone(),
             interval: "10m".to_string(),
+            schedule: None,
+            timezone: None,
             run_target: "job:deploy".to_string(),
             namespace: String::new(),
+            started_at_ms: ctx.clock.epoch_ms(),
+            resumed_from_ms: None,
         })
         .await
         .unwrap();
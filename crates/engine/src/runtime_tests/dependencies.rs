@@ -0,0 +1,270 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Job dependency (`--after`, `depends_on`) tests
+
+use super::*;
+use oj_core::{DependencyCondition, JobDependency, JobId, StepStatus};
+
+const DEPS_RUNBOOK: &str = r#"
+[command.build]
+args = "<name>"
+run = { job = "build" }
+
+[job.build]
+input = ["name"]
+
+[[job.build.step]]
+name = "compile"
+run = "echo compile"
+
+[command.deploy]
+args = "<name>"
+run = { job = "deploy" }
+
+[job.deploy]
+input = ["name"]
+
+[[job.deploy.step]]
+name = "ship"
+run = "echo ship"
+
+[command.release]
+args = "<name> <build>"
+run = { job = "release" }
+
+[job.release]
+input = ["name", "build"]
+depends_on = ["${var.build}"]
+on_dependency_fail = "cancel"
+
+[[job.release.step]]
+name = "tag"
+run = "echo tag"
+"#;
+
+fn args(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+async fn start(ctx: &TestContext, job_id: &str, command: &str, after: Vec<JobDependency>) {
+    let mut event = command_event(
+        job_id,
+        command,
+        command,
+        args(&[("name", job_id)]),
+        &ctx.project_root,
+    );
+    if let Event::CommandRun { after: deps, .. } = &mut event {
+        *deps = after;
+    }
+    ctx.runtime.handle_event(event).await.unwrap();
+}
+
+/// Finish the build job's only step and deliver its terminal transition.
+async fn finish_build(ctx: &TestContext, exit_code: i32) {
+    ctx.runtime
+        .handle_event(Event::ShellExited {
+            job_id: JobId::new("job-1"),
+            step: "compile".to_string(),
+            exit_code,
+            stdout: None,
+            stderr: None,
+            timed_out: false,
+        })
+        .await
+        .unwrap();
+    let step = ctx.runtime.get_job("job-1").unwrap().step;
+    // Result events are not re-fed in tests; deliver the transition ourselves
+    ctx.runtime
+        .handle_event(Event::JobAdvanced {
+            id: JobId::new("job-1"),
+            step,
+        })
+        .await
+        .unwrap();
+}
+
+fn after(job_id: &str, condition: DependencyCondition) -> Vec<JobDependency> {
+    vec![JobDependency::new(job_id, condition)]
+}
+
+#[tokio::test]
+async fn job_is_held_until_dependency_completes() {
+    let ctx = setup_with_runbook(DEPS_RUNBOOK).await;
+    start(&ctx, "job-1", "build", Vec::new()).await;
+    start(
+        &ctx,
+        "job-2",
+        "deploy",
+        after("job-1", DependencyCondition::Done),
+    )
+    .await;
+
+    let job = ctx.runtime.get_job("job-2").unwrap();
+    assert_eq!(job.step, "ship");
+    assert_eq!(
+        job.step_status,
+        StepStatus::Blocked("after job-1".to_string())
+    );
+
+    finish_build(&ctx, 0).await;
+
+    assert_eq!(ctx.runtime.get_job("job-1").unwrap().step, "done");
+    assert_eq!(
+        ctx.runtime.get_job("job-2").unwrap().step_status,
+        StepStatus::Running
+    );
+}
+
+#[tokio::test]
+async fn finished_dependency_does_not_hold_job() {
+    let ctx = setup_with_runbook(DEPS_RUNBOOK).await;
+    start(&ctx, "job-1", "build", Vec::new()).await;
+    finish_build(&ctx, 0).await;
+
+    start(
+        &ctx,
+        "job-2",
+        "deploy",
+        after("job-1", DependencyCondition::Done),
+    )
+    .await;
+
+    assert_eq!(
+        ctx.runtime.get_job("job-2").unwrap().step_status,
+        StepStatus::Running
+    );
+}
+
+#[tokio::test]
+async fn failed_dependency_fails_job_by_default() {
+    let ctx = setup_with_runbook(DEPS_RUNBOOK).await;
+    start(&ctx, "job-1", "build", Vec::new()).await;
+    start(
+        &ctx,
+        "job-2",
+        "deploy",
+        after("job-1", DependencyCondition::Done),
+    )
+    .await;
+
+    finish_build(&ctx, 1).await;
+
+    let job = ctx.runtime.get_job("job-2").unwrap();
+    assert_eq!(job.step, "failed");
+    assert_eq!(job.error.as_deref(), Some("dependency job job-1 failed"));
+}
+
+#[tokio::test]
+async fn any_condition_starts_after_failed_dependency() {
+    let ctx = setup_with_runbook(DEPS_RUNBOOK).await;
+    start(&ctx, "job-1", "build", Vec::new()).await;
+    start(
+        &ctx,
+        "job-2",
+        "deploy",
+        after("job-1", DependencyCondition::Any),
+    )
+    .await;
+
+    finish_build(&ctx, 1).await;
+
+    assert_eq!(ctx.runtime.get_job("job-1").unwrap().step, "failed");
+    assert_eq!(
+        ctx.runtime.get_job("job-2").unwrap().step_status,
+        StepStatus::Running
+    );
+}
+
+#[tokio::test]
+async fn depends_on_interpolates_vars_and_cancels_on_failure() {
+    let ctx = setup_with_runbook(DEPS_RUNBOOK).await;
+    start(&ctx, "job-1", "build", Vec::new()).await;
+    ctx.runtime
+        .handle_event(command_event(
+            "job-3",
+            "release",
+            "release",
+            args(&[("name", "v1"), ("build", "job-1")]),
+            &ctx.project_root,
+        ))
+        .await
+        .unwrap();
+
+    let job = ctx.runtime.get_job("job-3").unwrap();
+    assert_eq!(job.after, after("job-1", DependencyCondition::Done));
+    assert!(job.step_status.is_blocked());
+
+    finish_build(&ctx, 1).await;
+
+    assert_eq!(ctx.runtime.get_job("job-3").unwrap().step, "cancelled");
+}
+
+#[tokio::test]
+async fn unknown_dependency_fails_job() {
+    let ctx = setup_with_runbook(DEPS_RUNBOOK).await;
+    start(
+        &ctx,
+        "job-2",
+        "deploy",
+        after("nope", DependencyCondition::Any),
+    )
+    .await;
+
+    let job = ctx.runtime.get_job("job-2").unwrap();
+    assert_eq!(job.step, "failed");
+    assert_eq!(job.error.as_deref(), Some("dependency job nope not found"));
+}
+
+#[tokio::test]
+async fn pruned_done_dependency_still_counts_as_met() {
+    let ctx = setup_with_runbook(DEPS_RUNBOOK).await;
+    start(&ctx, "job-1", "build", Vec::new()).await;
+    start(&ctx, "job-3", "deploy", Vec::new()).await;
+    let mut deps = after("job-1", DependencyCondition::Done);
+    deps.push(JobDependency::new("job-3", DependencyCondition::Done));
+    start(&ctx, "job-2", "deploy", deps).await;
+
+    // job-1 finishes and is pruned while job-2 still waits on job-3
+    finish_build(&ctx, 0).await;
+    let deleted = Event::JobDeleted {
+        id: JobId::new("job-1"),
+    };
+    ctx.runtime
+        .lock_state_mut(|state| state.apply_event(&deleted));
+    ctx.runtime.handle_event(deleted).await.unwrap();
+    assert!(ctx
+        .runtime
+        .get_job("job-2")
+        .unwrap()
+        .step_status
+        .is_blocked());
+
+    ctx.runtime
+        .handle_event(Event::ShellExited {
+            job_id: JobId::new("job-3"),
+            step: "ship".to_string(),
+            exit_code: 0,
+            stdout: None,
+            stderr: None,
+            timed_out: false,
+        })
+        .await
+        .unwrap();
+    ctx.runtime
+        .handle_event(Event::JobAdvanced {
+            id: JobId::new("job-3"),
+            step: "done".to_string(),
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(
+        ctx.runtime.get_job("job-2").unwrap().step_status,
+        StepStatus::Running
+    );
+}
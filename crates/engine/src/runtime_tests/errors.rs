@@ -37,6 +37,7 @@ async fn shell_completed_for_unknown_job_errors() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await;
 
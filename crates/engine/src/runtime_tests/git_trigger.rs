@@ -0,0 +1,180 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Git ref trigger runtime tests
+
+use super::*;
+use std::collections::BTreeMap;
+
+const GIT_TRIGGER_RUNBOOK: &str = r#"
+[git_trigger.features]
+ref = "refs/heads/feature/*"
+run = { job = "ci" }
+
+[job.ci]
+
+[[job.ci.step]]
+name = "check"
+run = "git diff ${git.old} ${git.new}"
+"#;
+
+const MAIN: &str = "refs/heads/feature/main";
+const LOGIN: &str = "refs/heads/feature/login";
+
+async fn start_trigger(ctx: &TestContext) {
+    let (runbook_json, runbook_hash) = hash_runbook(GIT_TRIGGER_RUNBOOK);
+    ctx.runtime
+        .handle_event(Event::RunbookLoaded {
+            hash: runbook_hash.clone(),
+            version: 1,
+            runbook: runbook_json,
+        })
+        .await
+        .unwrap();
+    let started = Event::GitTriggerStarted {
+        trigger_name: "features".to_string(),
+        project_root: ctx.project_root.clone(),
+        runbook_hash,
+        ref_pattern: "refs/heads/feature/*".to_string(),
+        run_target: "job:ci".to_string(),
+        namespace: String::new(),
+    };
+    // The daemon records the trigger before the runtime sees the event
+    ctx.runtime
+        .lock_state_mut(|state| state.apply_event(&started));
+    ctx.runtime.handle_event(started).await.unwrap();
+}
+
+fn scanned(refs: &[(&str, &str)]) -> Event {
+    Event::GitRefsScanned {
+        trigger_name: "features".to_string(),
+        namespace: String::new(),
+        refs: refs
+            .iter()
+            .map(|(name, sha)| (name.to_string(), sha.repeat(40)))
+            .collect::<BTreeMap<_, _>>(),
+    }
+}
+
+fn recorded_refs(ctx: &TestContext) -> BTreeMap<String, String> {
+    ctx.runtime
+        .lock_state(|state| state.git_triggers["features"].refs.clone())
+}
+
+#[tokio::test]
+async fn first_scan_only_records_positions() {
+    let ctx = setup_with_runbook(GIT_TRIGGER_RUNBOOK).await;
+    start_trigger(&ctx).await;
+
+    ctx.runtime
+        .handle_event(scanned(&[(MAIN, "a")]))
+        .await
+        .unwrap();
+
+    assert!(ctx.runtime.jobs().is_empty());
+    assert_eq!(recorded_refs(&ctx)[MAIN], "a".repeat(40));
+}
+
+#[tokio::test]
+async fn moved_ref_starts_the_job() {
+    let ctx = setup_with_runbook(GIT_TRIGGER_RUNBOOK).await;
+    start_trigger(&ctx).await;
+    ctx.runtime
+        .handle_event(scanned(&[(MAIN, "a")]))
+        .await
+        .unwrap();
+
+    let events = ctx
+        .runtime
+        .handle_event(scanned(&[(MAIN, "b")]))
+        .await
+        .unwrap();
+
+    let jobs: Vec<_> = ctx.runtime.jobs().into_values().collect();
+    assert_eq!(jobs.len(), 1);
+    let job = &jobs[0];
+    assert_eq!(job.kind, "ci");
+    assert_eq!(job.vars.get("git.ref").map(String::as_str), Some(MAIN));
+    assert_eq!(job.vars.get("git.old"), Some(&"a".repeat(40)));
+    assert_eq!(job.vars.get("git.new"), Some(&"b".repeat(40)));
+    assert!(events.iter().any(|e| matches!(
+        e,
+        Event::GitRefMoved { ref_name, job_id: Some(job_id), .. }
+            if ref_name == MAIN && job_id.as_str() == job.id
+    )));
+    assert_eq!(recorded_refs(&ctx)[MAIN], "b".repeat(40));
+}
+
+#[tokio::test]
+async fn new_and_deleted_refs() {
+    let ctx = setup_with_runbook(GIT_TRIGGER_RUNBOOK).await;
+    start_trigger(&ctx).await;
+    ctx.runtime
+        .handle_event(scanned(&[(MAIN, "a")]))
+        .await
+        .unwrap();
+
+    ctx.runtime
+        .handle_event(scanned(&[(LOGIN, "c")]))
+        .await
+        .unwrap();
+
+    // Only the new ref runs the job, starting from the null commit
+    let jobs: Vec<_> = ctx.runtime.jobs().into_values().collect();
+    assert_eq!(jobs.len(), 1);
+    assert_eq!(jobs[0].vars.get("git.ref").map(String::as_str), Some(LOGIN));
+    assert_eq!(jobs[0].vars.get("git.old"), Some(&"0".repeat(40)));
+    let refs = recorded_refs(&ctx);
+    assert_eq!(refs.keys().collect::<Vec<_>>(), vec![LOGIN]);
+}
+
+#[tokio::test]
+async fn unchanged_scan_after_restart_starts_nothing() {
+    let ctx = setup_with_runbook(GIT_TRIGGER_RUNBOOK).await;
+    start_trigger(&ctx).await;
+    ctx.runtime
+        .handle_event(scanned(&[(MAIN, "a")]))
+        .await
+        .unwrap();
+    ctx.runtime
+        .handle_event(scanned(&[(MAIN, "b")]))
+        .await
+        .unwrap();
+
+    // On restart the daemon re-emits the started event and the watch scans
+    // again from scratch
+    start_trigger(&ctx).await;
+    ctx.runtime
+        .handle_event(scanned(&[(MAIN, "b")]))
+        .await
+        .unwrap();
+
+    assert_eq!(ctx.runtime.jobs().len(), 1);
+}
+
+#[tokio::test]
+async fn stopped_trigger_ignores_scans() {
+    let ctx = setup_with_runbook(GIT_TRIGGER_RUNBOOK).await;
+    start_trigger(&ctx).await;
+    ctx.runtime
+        .handle_event(scanned(&[(MAIN, "a")]))
+        .await
+        .unwrap();
+    ctx.runtime
+        .handle_event(Event::GitTriggerStopped {
+            trigger_name: "features".to_string(),
+            namespace: String::new(),
+        })
+        .await
+        .unwrap();
+
+    let events = ctx
+        .runtime
+        .handle_event(scanned(&[(MAIN, "b")]))
+        .await
+        .unwrap();
+
+    assert!(events.is_empty());
+    assert!(ctx.runtime.jobs().is_empty());
+    assert_eq!(recorded_refs(&ctx)[MAIN], "a".repeat(40));
+}
@@ -163,15 +163,15 @@ async fn job_with_folder_workspace_creates_workspace() {
 
     // workspace vars should be injected
     assert!(
-        job.vars.get("workspace.id").is_some(),
+        job.vars.contains_key("workspace.id"),
         "workspace.id var should be set"
     );
     assert!(
-        job.vars.get("workspace.root").is_some(),
+        job.vars.contains_key("workspace.root"),
         "workspace.root var should be set"
     );
     assert!(
-        job.vars.get("workspace.nonce").is_some(),
+        job.vars.contains_key("workspace.nonce"),
         "workspace.nonce var should be set"
     );
 }
@@ -303,6 +303,8 @@ async fn job_namespace_is_propagated() {
         args: [("name".to_string(), "test".to_string())]
             .into_iter()
             .collect(),
+        after: vec![],
+        priority: 0,
     };
 
     ctx.runtime.handle_event(event).await.unwrap();
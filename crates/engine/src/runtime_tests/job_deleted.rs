@@ -302,6 +302,7 @@ async fn job_deleted_handles_terminal_job() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
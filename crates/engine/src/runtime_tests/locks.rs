@@ -0,0 +1,242 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Lock and semaphore acquisition tests
+
+use super::*;
+use oj_core::{JobId, StepStatus};
+
+const LOCK_RUNBOOK: &str = r#"
+[command.merge]
+args = "<name>"
+run = { job = "merge" }
+
+[lock.main-branch]
+
+[semaphore.gpu]
+permits = 2
+
+[job.merge]
+input = ["name"]
+
+[[job.merge.step]]
+name = "rebase"
+run = "echo rebase"
+acquire = ["lock.main-branch"]
+on_done = "push"
+
+[[job.merge.step]]
+name = "push"
+run = "echo push"
+
+[command.train]
+args = "<name>"
+run = { job = "train" }
+
+[job.train]
+input = ["name"]
+
+[[job.train.step]]
+name = "fit"
+run = "echo fit"
+acquire = ["semaphore.gpu"]
+
+[command.publish]
+args = "<name>"
+run = { job = "publish" }
+
+[job.publish]
+input = ["name"]
+
+[[job.publish.step]]
+name = "upload"
+run = "exit 1"
+acquire = ["lock.main-branch"]
+retry = { attempts = 3, backoff = "exponential", base = "1m", max = "10m" }
+"#;
+
+async fn start(ctx: &TestContext, job_id: &str, command: &str) {
+    ctx.runtime
+        .handle_event(command_event(
+            job_id,
+            command,
+            command,
+            [("name".to_string(), job_id.to_string())]
+                .into_iter()
+                .collect(),
+            &ctx.project_root,
+        ))
+        .await
+        .unwrap();
+}
+
+fn status(ctx: &TestContext, job_id: &str) -> StepStatus {
+    ctx.runtime.get_job(job_id).unwrap().step_status
+}
+
+#[tokio::test]
+async fn second_job_blocks_on_held_lock() {
+    let ctx = setup_with_runbook(LOCK_RUNBOOK).await;
+    start(&ctx, "job-1", "merge").await;
+    start(&ctx, "job-2", "merge").await;
+
+    assert_eq!(status(&ctx, "job-1"), StepStatus::Running);
+    assert_eq!(
+        status(&ctx, "job-2"),
+        StepStatus::Blocked("lock.main-branch".to_string())
+    );
+}
+
+#[tokio::test]
+async fn finishing_step_wakes_next_waiter() {
+    let ctx = setup_with_runbook(LOCK_RUNBOOK).await;
+    start(&ctx, "job-1", "merge").await;
+    start(&ctx, "job-2", "merge").await;
+    start(&ctx, "job-3", "merge").await;
+
+    ctx.runtime
+        .handle_event(Event::ShellExited {
+            job_id: JobId::new("job-1"),
+            step: "rebase".to_string(),
+            exit_code: 0,
+            stdout: None,
+            stderr: None,
+            timed_out: false,
+        })
+        .await
+        .unwrap();
+    // Result events are not re-fed in tests; deliver the transition ourselves
+    ctx.runtime
+        .handle_event(Event::JobAdvanced {
+            id: JobId::new("job-1"),
+            step: "push".to_string(),
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(ctx.runtime.get_job("job-1").unwrap().step, "push");
+    assert_eq!(status(&ctx, "job-2"), StepStatus::Running);
+    assert!(status(&ctx, "job-3").is_blocked());
+}
+
+#[tokio::test]
+async fn cancelling_holder_wakes_waiter() {
+    let ctx = setup_with_runbook(LOCK_RUNBOOK).await;
+    start(&ctx, "job-1", "merge").await;
+    start(&ctx, "job-2", "merge").await;
+
+    let job = ctx.runtime.get_job("job-1").unwrap();
+    ctx.runtime.cancel_job(&job).await.unwrap();
+    ctx.runtime
+        .handle_event(Event::JobAdvanced {
+            id: JobId::new("job-1"),
+            step: "cancelled".to_string(),
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(status(&ctx, "job-2"), StepStatus::Running);
+}
+
+#[tokio::test]
+async fn deleting_holder_wakes_waiter() {
+    let ctx = setup_with_runbook(LOCK_RUNBOOK).await;
+    start(&ctx, "job-1", "merge").await;
+    start(&ctx, "job-2", "merge").await;
+
+    // The daemon applies JobDeleted to state before the runtime handles it
+    let deleted = Event::JobDeleted {
+        id: JobId::new("job-1"),
+    };
+    ctx.runtime
+        .lock_state_mut(|state| state.apply_event(&deleted));
+    ctx.runtime.handle_event(deleted).await.unwrap();
+
+    assert_eq!(status(&ctx, "job-2"), StepStatus::Running);
+}
+
+#[tokio::test]
+async fn semaphore_admits_up_to_permits() {
+    let ctx = setup_with_runbook(LOCK_RUNBOOK).await;
+    start(&ctx, "job-1", "train").await;
+    start(&ctx, "job-2", "train").await;
+    start(&ctx, "job-3", "train").await;
+
+    assert_eq!(status(&ctx, "job-1"), StepStatus::Running);
+    assert_eq!(status(&ctx, "job-2"), StepStatus::Running);
+    assert!(status(&ctx, "job-3").is_blocked());
+}
+
+#[tokio::test]
+async fn resume_rejects_blocked_job() {
+    let ctx = setup_with_runbook(LOCK_RUNBOOK).await;
+    start(&ctx, "job-1", "merge").await;
+    start(&ctx, "job-2", "merge").await;
+
+    let result = ctx
+        .runtime
+        .handle_event(Event::JobResume {
+            id: JobId::new("job-2"),
+            message: None,
+            vars: HashMap::new(),
+            kill: false,
+        })
+        .await;
+
+    assert!(result.is_err());
+    assert!(status(&ctx, "job-2").is_blocked());
+}
+
+#[tokio::test]
+async fn retry_delay_releases_locks_until_the_next_attempt() {
+    let ctx = setup_with_runbook(LOCK_RUNBOOK).await;
+    start(&ctx, "job-1", "publish").await;
+    start(&ctx, "job-2", "merge").await;
+    assert!(status(&ctx, "job-2").is_blocked());
+
+    ctx.runtime
+        .handle_event(Event::ShellExited {
+            job_id: JobId::new("job-1"),
+            step: "upload".to_string(),
+            exit_code: 1,
+            stdout: None,
+            stderr: None,
+            timed_out: false,
+        })
+        .await
+        .unwrap();
+    assert_eq!(status(&ctx, "job-1"), StepStatus::Pending);
+    // Result events are not re-fed in tests; deliver the retry ourselves
+    ctx.runtime
+        .handle_event(Event::StepRetrying {
+            job_id: JobId::new("job-1"),
+            step: "upload".to_string(),
+            attempt: 1,
+            error: "shell exit code: 1".to_string(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(status(&ctx, "job-2"), StepStatus::Running);
+
+    // The retry waits for the lock like any other claimant
+    ctx.runtime
+        .handle_event(Event::TimerStart {
+            id: oj_core::TimerId::step_retry(&JobId::new("job-1")),
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        status(&ctx, "job-1"),
+        StepStatus::Blocked("lock.main-branch".to_string())
+    );
+
+    let advanced = Event::JobAdvanced {
+        id: JobId::new("job-2"),
+        step: "push".to_string(),
+    };
+    ctx.runtime
+        .lock_state_mut(|state| state.apply_event(&advanced));
+    ctx.runtime.handle_event(advanced).await.unwrap();
+    assert_eq!(status(&ctx, "job-1"), StepStatus::Running);
+    assert_eq!(ctx.runtime.get_job("job-1").unwrap().step_retries, 1);
+}
@@ -3,29 +3,48 @@
 
 //! Runtime tests
 
+mod admission;
 mod agent_run;
+mod approval;
+mod budget;
 mod cron;
 mod cron_agent;
+mod cron_catch_up;
 mod cron_concurrency;
+mod dependencies;
 mod directives;
 mod errors;
+mod git_trigger;
 mod idempotency;
 mod job_create;
 mod job_deleted;
+mod locks;
 mod monitoring;
 mod notify;
 mod on_dead;
+mod outputs;
+mod parallel;
 mod resume;
 mod sessions;
+mod shell_timeout;
+mod step_retry;
 mod steps;
 mod steps_cycles;
 mod steps_lifecycle;
 mod steps_locals;
+mod sub_job;
 mod timer_cleanup;
+mod transitions;
+mod triggers;
+mod watch;
 mod worker;
+mod worker_agent;
 mod worker_concurrency;
 mod worker_external;
+mod worker_lease;
 mod worker_queue;
+mod worker_rate;
+mod worker_weighted;
 
 use super::*;
 use crate::test_helpers::{setup_with_runbook, TestContext};
@@ -48,6 +67,8 @@ fn command_event(
         command: command.to_string(),
         namespace: String::new(),
         args,
+        after: vec![],
+        priority: 0,
     }
 }
 
@@ -213,6 +234,7 @@ async fn shell_completion_advances_step() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -234,6 +256,7 @@ async fn agent_done_advances_step() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
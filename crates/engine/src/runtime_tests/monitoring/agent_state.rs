@@ -38,6 +38,7 @@ async fn agent_state_changed_terminal_job_is_noop() {
             exit_code: 1,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -71,6 +72,7 @@ async fn agent_state_changed_routes_through_agent_jobs() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -72,6 +72,7 @@ async fn working_noop_when_job_already_running() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
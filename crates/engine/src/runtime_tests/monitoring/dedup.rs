@@ -305,6 +305,7 @@ async fn stale_agent_event_dropped_after_job_advances() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -354,6 +355,7 @@ async fn stale_agent_signal_dropped_after_job_advances() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
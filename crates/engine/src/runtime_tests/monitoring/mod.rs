@@ -28,6 +28,7 @@ async fn setup_job_at_agent_step(ctx: &TestContext) -> (String, String, AgentId)
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
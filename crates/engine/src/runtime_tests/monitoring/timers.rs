@@ -98,6 +98,7 @@ async fn exit_deferred_timer_noop_when_job_terminal() {
             exit_code: 1,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -130,6 +131,7 @@ async fn exit_deferred_timer_on_idle_when_waiting_for_input() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -170,6 +172,7 @@ async fn exit_deferred_timer_on_error_when_agent_failed() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -212,6 +215,7 @@ async fn exit_deferred_timer_on_dead_for_exited_state() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
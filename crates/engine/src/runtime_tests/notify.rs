@@ -103,6 +103,7 @@ async fn job_on_done_emits_notification() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -143,6 +144,7 @@ async fn job_on_fail_emits_notification() {
             exit_code: 1,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
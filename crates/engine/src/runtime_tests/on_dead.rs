@@ -26,6 +26,7 @@ async fn session_death_triggers_on_dead_action() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -91,6 +92,7 @@ async fn session_death_timer_on_terminal_job_is_noop() {
             exit_code: 1,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -126,6 +128,7 @@ async fn agent_exited_on_terminal_job_is_noop() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -142,6 +145,7 @@ async fn agent_exited_on_terminal_job_is_noop() {
             exit_code: 1,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -0,0 +1,99 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Step output capture tests
+
+use super::*;
+
+const OUTPUTS_RUNBOOK: &str = r#"
+[command.release]
+args = "<name>"
+run = { job = "release" }
+
+[job.release]
+input = ["name"]
+
+[[job.release.step]]
+name = "build"
+run = '''
+echo abc123
+echo '::outputs:: {"sha": "deadbeef"}'
+printf '{"tag": "v1"}' > "$OJ_OUTPUTS"
+'''
+on_done = "verify"
+
+[[job.release.step]]
+name = "verify"
+run = "test '${steps.build.output}' = abc123 && test '${steps.build.outputs.sha}' = deadbeef && test '${steps.build.outputs.tag}' = v1"
+
+[command.broken]
+args = "<name>"
+run = { job = "broken" }
+
+[job.broken]
+input = ["name"]
+
+[[job.broken.step]]
+name = "build"
+run = "echo '::outputs:: not json'"
+on_done = "verify"
+
+[[job.broken.step]]
+name = "verify"
+run = "true"
+"#;
+
+async fn run_command(ctx: &TestContext, command: &str) {
+    ctx.runtime
+        .handle_event(command_event(
+            "job-1",
+            command,
+            command,
+            [("name".to_string(), "r".to_string())]
+                .into_iter()
+                .collect(),
+            &ctx.project_root,
+        ))
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn shell_outputs_are_interpolated_into_later_steps() {
+    let mut ctx = setup_with_runbook(OUTPUTS_RUNBOOK).await;
+    run_command(&ctx, "release").await;
+
+    let build_exited = ctx.event_rx.recv().await.unwrap();
+    ctx.runtime.handle_event(build_exited).await.unwrap();
+
+    let job = ctx.runtime.get_job("job-1").unwrap();
+    assert_eq!(job.step, "verify");
+    assert_eq!(job.vars["steps.build.output"], "abc123");
+    assert_eq!(job.vars["steps.build.outputs.sha"], "deadbeef");
+    assert_eq!(job.vars["steps.build.outputs.tag"], "v1");
+
+    // verify only exits 0 if every output was substituted
+    let verify_exited = ctx.event_rx.recv().await.unwrap();
+    assert!(
+        matches!(verify_exited, Event::ShellExited { exit_code: 0, .. }),
+        "expected outputs to be interpolated, got: {verify_exited:?}"
+    );
+}
+
+#[tokio::test]
+async fn invalid_outputs_fail_the_step() {
+    let mut ctx = setup_with_runbook(OUTPUTS_RUNBOOK).await;
+    run_command(&ctx, "broken").await;
+
+    let exited = ctx.event_rx.recv().await.unwrap();
+    assert!(matches!(exited, Event::ShellExited { exit_code: 0, .. }));
+    ctx.runtime.handle_event(exited).await.unwrap();
+
+    let job = ctx.runtime.get_job("job-1").unwrap();
+    assert_eq!(job.step, "failed");
+    assert!(job
+        .error
+        .as_deref()
+        .unwrap_or_default()
+        .starts_with("step outputs: invalid outputs JSON"));
+}
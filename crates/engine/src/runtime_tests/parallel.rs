@@ -0,0 +1,198 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Parallel fan-out step tests
+
+use super::*;
+use oj_core::{JobId, StepOutcome, StepStatus};
+
+const PARALLEL_RUNBOOK: &str = r#"
+[command.review]
+args = "<name>"
+run = { job = "review" }
+
+[job.review]
+input = ["name"]
+
+[[job.review.step]]
+name = "checks"
+run = { parallel = ["lint", "test"] }
+on_done = "merge"
+on_fail = "report"
+
+[[job.review.step]]
+name = "lint"
+run = "echo lint"
+on_done = "report"
+
+[[job.review.step]]
+name = "test"
+run = "echo test"
+
+[[job.review.step]]
+name = "merge"
+run = "echo merge"
+
+[[job.review.step]]
+name = "report"
+run = "echo report"
+"#;
+
+async fn start_review(ctx: &TestContext) {
+    ctx.runtime
+        .handle_event(command_event(
+            "job-1",
+            "review",
+            "review",
+            [("name".to_string(), "pr-1".to_string())]
+                .into_iter()
+                .collect(),
+            &ctx.project_root,
+        ))
+        .await
+        .unwrap();
+}
+
+/// Job ID of the named branch of job-1's parallel step.
+fn branch_job(ctx: &TestContext, branch: &str) -> String {
+    let job = ctx.runtime.get_job("job-1").unwrap();
+    job.step_history
+        .iter()
+        .rev()
+        .flat_map(|r| r.branches.iter())
+        .find(|b| b.name == branch)
+        .and_then(|b| b.job_id.clone())
+        .unwrap()
+}
+
+/// Finish a branch's shell step and deliver the resulting terminal
+/// transition, which the daemon would normally feed back in.
+async fn finish_branch(ctx: &TestContext, branch: &str, exit_code: i32) {
+    let job_id = branch_job(ctx, branch);
+    ctx.runtime
+        .handle_event(Event::ShellExited {
+            job_id: JobId::new(&job_id),
+            step: branch.to_string(),
+            exit_code,
+            stdout: None,
+            stderr: None,
+            timed_out: false,
+        })
+        .await
+        .unwrap();
+    let step = ctx.runtime.get_job(&job_id).unwrap().step;
+    ctx.runtime
+        .handle_event(Event::JobAdvanced {
+            id: JobId::new(&job_id),
+            step,
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn parallel_step_starts_every_branch() {
+    let ctx = setup_with_runbook(PARALLEL_RUNBOOK).await;
+    start_review(&ctx).await;
+
+    let parent = ctx.runtime.get_job("job-1").unwrap();
+    assert_eq!(parent.step, "checks");
+    assert_eq!(parent.step_status, StepStatus::Running);
+    let branches: Vec<_> = parent.step_history[0]
+        .branches
+        .iter()
+        .map(|b| b.name.as_str())
+        .collect();
+    assert_eq!(branches, vec!["lint", "test"]);
+
+    for name in ["lint", "test"] {
+        let branch = ctx.runtime.get_job(&branch_job(&ctx, name)).unwrap();
+        assert_eq!(branch.step, name);
+        assert_eq!(branch.step_status, StepStatus::Running);
+        assert_eq!(branch.parent_job_id.as_deref(), Some("job-1"));
+        assert!(branch.is_branch);
+    }
+}
+
+#[tokio::test]
+async fn parent_advances_once_all_branches_are_done() {
+    let ctx = setup_with_runbook(PARALLEL_RUNBOOK).await;
+    start_review(&ctx).await;
+
+    finish_branch(&ctx, "lint", 0).await;
+    // The branch ignores its step's own on_done and finishes the branch job
+    assert_eq!(
+        ctx.runtime.get_job(&branch_job(&ctx, "lint")).unwrap().step,
+        "done"
+    );
+    assert_eq!(ctx.runtime.get_job("job-1").unwrap().step, "checks");
+
+    finish_branch(&ctx, "test", 0).await;
+    let parent = ctx.runtime.get_job("job-1").unwrap();
+    assert_eq!(parent.step, "merge");
+    let checks = &parent.step_history[0];
+    assert_eq!(checks.outcome, StepOutcome::Completed);
+    assert!(checks
+        .branches
+        .iter()
+        .all(|b| b.outcome == StepOutcome::Completed && b.finished_at_ms.is_some()));
+}
+
+#[tokio::test]
+async fn failed_branch_cancels_siblings_and_fails_parent() {
+    let ctx = setup_with_runbook(PARALLEL_RUNBOOK).await;
+    start_review(&ctx).await;
+
+    finish_branch(&ctx, "lint", 1).await;
+
+    let parent = ctx.runtime.get_job("job-1").unwrap();
+    assert_eq!(parent.step, "report");
+    let checks = &parent.step_history[0];
+    assert_eq!(
+        checks.outcome,
+        StepOutcome::Failed("branch 'lint' failed: shell exit code: 1".to_string())
+    );
+    assert_eq!(
+        ctx.runtime.get_job(&branch_job(&ctx, "test")).unwrap().step,
+        "cancelled"
+    );
+    assert_eq!(
+        checks.branches[1].outcome,
+        StepOutcome::Failed("cancelled".to_string())
+    );
+}
+
+#[tokio::test]
+async fn cancelling_parent_cancels_branches() {
+    let ctx = setup_with_runbook(PARALLEL_RUNBOOK).await;
+    start_review(&ctx).await;
+
+    let parent = ctx.runtime.get_job("job-1").unwrap();
+    ctx.runtime.cancel_job(&parent).await.unwrap();
+
+    assert_eq!(ctx.runtime.get_job("job-1").unwrap().step, "cancelled");
+    for name in ["lint", "test"] {
+        let branch = ctx.runtime.get_job(&branch_job(&ctx, name)).unwrap();
+        assert_eq!(branch.step, "cancelled");
+    }
+}
+
+#[tokio::test]
+async fn late_branch_events_are_ignored_after_parent_moves_on() {
+    let ctx = setup_with_runbook(PARALLEL_RUNBOOK).await;
+    start_review(&ctx).await;
+
+    finish_branch(&ctx, "lint", 1).await;
+    assert_eq!(ctx.runtime.get_job("job-1").unwrap().step, "report");
+
+    // The cancelled sibling's terminal transition must not touch the parent
+    let test_job = branch_job(&ctx, "test");
+    ctx.runtime
+        .handle_event(Event::JobAdvanced {
+            id: JobId::new(&test_job),
+            step: "cancelled".to_string(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(ctx.runtime.get_job("job-1").unwrap().step, "report");
+}
@@ -68,6 +68,7 @@ async fn advance_to_agent_step(ctx: &TestContext, job_id: &str) {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -374,6 +375,7 @@ async fn resume_from_terminal_failure_shell_step() {
             exit_code: 1,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -526,6 +528,8 @@ async fn resume_collects_all_agent_ids_from_step_history() {
                 outcome: oj_core::StepOutcome::Running,
                 agent_id: Some(second_agent_id.to_string()),
                 agent_name: Some("worker".to_string()),
+                job_id: None,
+                branches: Vec::new(),
             });
         }
     });
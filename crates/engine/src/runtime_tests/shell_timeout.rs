@@ -0,0 +1,136 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Shell step timeout tests
+
+use super::*;
+use oj_core::{JobId, StepOutcome};
+
+const TIMEOUT_RUNBOOK: &str = r#"
+[command.ci]
+args = "<name>"
+run = { job = "ci" }
+
+[job.ci]
+input = ["name"]
+
+[[job.ci.step]]
+name = "test"
+run = "sleep 30"
+timeout = "1s"
+on_done = "lint"
+on_fail = "report"
+
+[[job.ci.step]]
+name = "lint"
+run = "echo lint"
+
+[[job.ci.step]]
+name = "report"
+run = "echo report"
+"#;
+
+async fn start_ci(ctx: &TestContext) {
+    ctx.runtime
+        .handle_event(command_event(
+            "job-1",
+            "ci",
+            "ci",
+            [("name".to_string(), "c".to_string())]
+                .into_iter()
+                .collect(),
+            &ctx.project_root,
+        ))
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn expired_timeout_fails_step_through_on_fail() {
+    let mut ctx = setup_with_runbook(TIMEOUT_RUNBOOK).await;
+    start_ci(&ctx).await;
+
+    let exited = ctx.event_rx.recv().await.unwrap();
+    assert!(
+        matches!(
+            exited,
+            Event::ShellExited {
+                timed_out: true,
+                ..
+            }
+        ),
+        "expected a timeout, got: {exited:?}"
+    );
+    ctx.runtime.handle_event(exited).await.unwrap();
+
+    let job = ctx.runtime.get_job("job-1").unwrap();
+    assert_eq!(job.step, "report");
+    assert_eq!(
+        job.step_history[0].outcome,
+        StepOutcome::Failed("shell step timed out after 1s".to_string())
+    );
+}
+
+#[tokio::test]
+async fn default_timeout_is_reported_for_steps_without_one() {
+    let ctx = setup_with_runbook(TIMEOUT_RUNBOOK).await;
+    start_ci(&ctx).await;
+    ctx.runtime
+        .handle_event(Event::ShellExited {
+            job_id: JobId::new("job-1"),
+            step: "test".to_string(),
+            exit_code: 0,
+            stdout: None,
+            stderr: None,
+            timed_out: false,
+        })
+        .await
+        .unwrap();
+    assert_eq!(ctx.runtime.get_job("job-1").unwrap().step, "lint");
+
+    ctx.runtime
+        .handle_event(Event::ShellExited {
+            job_id: JobId::new("job-1"),
+            step: "lint".to_string(),
+            exit_code: -1,
+            stdout: None,
+            stderr: None,
+            timed_out: true,
+        })
+        .await
+        .unwrap();
+
+    let job = ctx.runtime.get_job("job-1").unwrap();
+    assert_eq!(job.step, "failed");
+    assert_eq!(job.error.as_deref(), Some("shell step timed out after 10m"));
+}
+
+#[tokio::test]
+async fn on_timeout_routes_timeouts_apart_from_other_failures() {
+    let runbook = TIMEOUT_RUNBOOK.replace(
+        "on_fail = \"report\"",
+        "on_fail = \"report\"\non_timeout = \"lint\"",
+    );
+    for (timed_out, next) in [(true, "lint"), (false, "report")] {
+        let ctx = setup_with_runbook(&runbook).await;
+        start_ci(&ctx).await;
+        ctx.runtime
+            .handle_event(Event::ShellExited {
+                job_id: JobId::new("job-1"),
+                step: "test".to_string(),
+                exit_code: -1,
+                stdout: None,
+                stderr: None,
+                timed_out,
+            })
+            .await
+            .unwrap();
+
+        let job = ctx.runtime.get_job("job-1").unwrap();
+        assert_eq!(job.step, next, "timed_out = {timed_out}");
+        assert!(matches!(
+            job.step_history[0].outcome,
+            StepOutcome::Failed(_)
+        ));
+    }
+}
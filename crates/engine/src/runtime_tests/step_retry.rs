@@ -0,0 +1,200 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Step retry policy tests
+
+use super::*;
+use oj_core::{StepOutcome, StepStatus, TimerId};
+use std::time::Duration;
+
+const RETRY_RUNBOOK: &str = r#"
+[command.fetch]
+args = "<name>"
+run = { job = "fetch" }
+
+[job.fetch]
+input = ["name"]
+
+[[job.fetch.step]]
+name = "download"
+run = "exit 1"
+retry = { attempts = 3, backoff = "exponential", base = "10s" }
+on_fail = "report"
+
+[[job.fetch.step]]
+name = "report"
+run = "echo report"
+"#;
+
+async fn start_fetch(ctx: &TestContext) {
+    ctx.runtime
+        .handle_event(command_event(
+            "job-1",
+            "fetch",
+            "fetch",
+            [("name".to_string(), "f".to_string())]
+                .into_iter()
+                .collect(),
+            &ctx.project_root,
+        ))
+        .await
+        .unwrap();
+}
+
+async fn fail_download(ctx: &TestContext) {
+    ctx.runtime
+        .handle_event(Event::ShellExited {
+            job_id: JobId::new("job-1"),
+            step: "download".to_string(),
+            exit_code: 1,
+            stdout: None,
+            stderr: None,
+            timed_out: false,
+        })
+        .await
+        .unwrap();
+}
+
+fn retry_timer() -> TimerId {
+    TimerId::step_retry(&JobId::new("job-1"))
+}
+
+/// Fire timers due within `elapsed` and return their IDs.
+fn fire_timers(ctx: &TestContext, elapsed: Duration) -> Vec<String> {
+    ctx.clock.advance(elapsed);
+    let scheduler = ctx.runtime.executor.scheduler();
+    let fired = scheduler.lock().fired_timers(ctx.clock.now());
+    fired
+        .into_iter()
+        .filter_map(|e| match e {
+            Event::TimerStart { id } => Some(id.as_str().to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn failure_schedules_a_durable_retry() {
+    let ctx = setup_with_runbook(RETRY_RUNBOOK).await;
+    start_fetch(&ctx).await;
+    let scheduled_at = ctx.clock.epoch_ms();
+
+    fail_download(&ctx).await;
+
+    let job = ctx.runtime.get_job("job-1").unwrap();
+    assert_eq!(job.step, "download");
+    assert_eq!(job.step_status, StepStatus::Pending);
+    assert_eq!(job.step_retries, 1);
+    assert_eq!(
+        job.step_history[0].outcome,
+        StepOutcome::Failed("shell exit code: 1".to_string())
+    );
+
+    let fires_at = ctx
+        .runtime
+        .lock_state(|s| s.timers.get(retry_timer().as_str()).copied());
+    assert_eq!(fires_at, Some(scheduled_at + 10_000));
+    assert!(fire_timers(&ctx, Duration::from_secs(9)).is_empty());
+    assert_eq!(
+        fire_timers(&ctx, Duration::from_secs(1)),
+        vec![retry_timer().as_str().to_string()]
+    );
+}
+
+#[tokio::test]
+async fn retries_back_off_then_fall_through_to_on_fail() {
+    let ctx = setup_with_runbook(RETRY_RUNBOOK).await;
+    start_fetch(&ctx).await;
+
+    fail_download(&ctx).await;
+    ctx.runtime
+        .handle_event(Event::TimerStart { id: retry_timer() })
+        .await
+        .unwrap();
+    assert_eq!(
+        ctx.runtime.get_job("job-1").unwrap().step_status,
+        StepStatus::Running
+    );
+
+    // Second failure waits twice as long
+    fail_download(&ctx).await;
+    assert_eq!(ctx.runtime.get_job("job-1").unwrap().step_retries, 2);
+    assert!(fire_timers(&ctx, Duration::from_secs(19)).is_empty());
+    assert_eq!(fire_timers(&ctx, Duration::from_secs(1)).len(), 1);
+    ctx.runtime
+        .handle_event(Event::TimerStart { id: retry_timer() })
+        .await
+        .unwrap();
+
+    // Third run is the last attempt
+    fail_download(&ctx).await;
+    let job = ctx.runtime.get_job("job-1").unwrap();
+    assert_eq!(job.step, "report");
+    assert_eq!(job.step_retries, 0);
+    let attempts = job
+        .step_history
+        .iter()
+        .filter(|r| r.name == "download")
+        .count();
+    assert_eq!(attempts, 3);
+}
+
+#[tokio::test]
+async fn restart_rearms_pending_retry_with_remaining_delay() {
+    let ctx = setup_with_runbook(RETRY_RUNBOOK).await;
+    start_fetch(&ctx).await;
+    fail_download(&ctx).await;
+
+    // Simulate a restart: the in-memory timer is gone, the state entry isn't
+    ctx.runtime
+        .executor
+        .scheduler()
+        .lock()
+        .cancel_timer(retry_timer().as_str());
+    ctx.clock.advance(Duration::from_secs(4));
+    ctx.runtime.restore_durable_timers().await.unwrap();
+
+    assert!(fire_timers(&ctx, Duration::from_secs(5)).is_empty());
+    assert_eq!(fire_timers(&ctx, Duration::from_secs(1)).len(), 1);
+}
+
+#[tokio::test]
+async fn cancel_drops_pending_retry() {
+    let ctx = setup_with_runbook(RETRY_RUNBOOK).await;
+    start_fetch(&ctx).await;
+    fail_download(&ctx).await;
+
+    ctx.runtime
+        .handle_event(Event::JobCancel {
+            id: JobId::new("job-1"),
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(ctx.runtime.get_job("job-1").unwrap().step, "cancelled");
+    assert_no_timer_with_prefix(&pending_timer_ids(&ctx), "step-retry:");
+    assert!(ctx.runtime.lock_state(|s| s.timers.is_empty()));
+
+    // A restart must not re-arm the cancelled retry
+    ctx.runtime.restore_durable_timers().await.unwrap();
+    assert_no_timer_with_prefix(&pending_timer_ids(&ctx), "step-retry:");
+}
+
+#[tokio::test]
+async fn resume_supersedes_pending_retry() {
+    let ctx = setup_with_runbook(RETRY_RUNBOOK).await;
+    start_fetch(&ctx).await;
+    fail_download(&ctx).await;
+
+    ctx.runtime
+        .handle_event(Event::JobResume {
+            id: JobId::new("job-1"),
+            message: None,
+            vars: HashMap::new(),
+            kill: false,
+        })
+        .await
+        .unwrap();
+
+    assert!(ctx.runtime.lock_state(|s| s.timers.is_empty()));
+}
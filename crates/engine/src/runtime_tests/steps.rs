@@ -19,6 +19,7 @@ async fn shell_failure_fails_job() {
             exit_code: 1,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -40,6 +41,7 @@ async fn agent_error_fails_job() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -66,6 +68,7 @@ async fn on_fail_transition_executes() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -88,6 +91,7 @@ async fn on_fail_transition_executes() {
             exit_code: 1,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -115,6 +119,7 @@ async fn final_step_completes_job() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -133,6 +138,7 @@ async fn final_step_completes_job() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -154,6 +160,7 @@ async fn done_step_run_command_executes() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -172,6 +179,7 @@ async fn done_step_run_command_executes() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -190,6 +198,7 @@ async fn done_step_run_command_executes() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -213,6 +222,7 @@ async fn wrong_step_shell_completed_ignored() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -264,6 +274,7 @@ async fn step_without_on_done_completes_job() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -325,6 +336,7 @@ async fn explicit_next_step_is_followed() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -340,6 +352,7 @@ async fn explicit_next_step_is_followed() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -394,6 +407,7 @@ async fn implicit_done_step_completes_immediately() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -410,6 +424,7 @@ async fn implicit_done_step_completes_immediately() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -435,6 +450,7 @@ async fn step_runs_with_fallback_workspace_path() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -459,6 +475,7 @@ async fn advance_job_cancels_exit_deferred_timer() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -516,6 +533,7 @@ async fn fail_job_cancels_exit_deferred_timer() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -65,6 +65,7 @@ async fn on_fail_self_cycle_preserves_action_attempts() {
             exit_code: 1,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -139,6 +140,7 @@ async fn on_fail_multi_step_cycle_preserves_action_attempts() {
             exit_code: 1,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -159,6 +161,7 @@ async fn on_fail_multi_step_cycle_preserves_action_attempts() {
             exit_code: 1,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -207,6 +210,7 @@ async fn on_done_transition_resets_action_attempts() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -302,6 +306,7 @@ async fn circuit_breaker_fails_job_after_max_step_visits() {
                 exit_code: 1,
                 stdout: None,
                 stderr: None,
+                timed_out: false,
             })
             .await
             .unwrap();
@@ -341,6 +346,7 @@ async fn step_visits_tracked_across_transitions() {
             exit_code: 1,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -357,6 +363,7 @@ async fn step_visits_tracked_across_transitions() {
             exit_code: 1,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -57,6 +57,7 @@ async fn job_on_done_routes_to_teardown() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -72,6 +73,7 @@ async fn job_on_done_routes_to_teardown() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -90,6 +92,7 @@ async fn job_on_done_routes_to_teardown() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -145,6 +148,7 @@ async fn job_on_fail_routes_to_cleanup() {
             exit_code: 1,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -207,6 +211,7 @@ async fn step_level_on_done_takes_precedence() {
             exit_code: 0,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -269,6 +274,7 @@ async fn step_level_on_fail_takes_precedence() {
             exit_code: 1,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -0,0 +1,195 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Sub-job step tests
+
+use super::*;
+use oj_core::{JobId, StepOutcome, StepStatus};
+
+const SUB_JOB_RUNBOOK: &str = r#"
+[command.ship]
+args = "<name>"
+run = { job = "ship" }
+
+[job.ship]
+input = ["name"]
+
+[[job.ship.step]]
+name = "merge"
+run = { job = "merge", vars = { branch = "feature/${var.name}" } }
+on_done = "deploy"
+on_fail = "report"
+
+[[job.ship.step]]
+name = "deploy"
+run = "echo deploy"
+
+[[job.ship.step]]
+name = "report"
+run = "echo report"
+
+[job.merge]
+input = ["branch", "remote"]
+defaults = { remote = "origin" }
+
+[[job.merge.step]]
+name = "rebase"
+run = "git rebase ${var.remote}/${var.branch}"
+"#;
+
+async fn start_ship(ctx: &TestContext) {
+    ctx.runtime
+        .handle_event(command_event(
+            "job-1",
+            "ship",
+            "ship",
+            [("name".to_string(), "pr-1".to_string())]
+                .into_iter()
+                .collect(),
+            &ctx.project_root,
+        ))
+        .await
+        .unwrap();
+}
+
+/// Job ID of the child started by job-1's current step.
+fn child_job(ctx: &TestContext) -> String {
+    let job = ctx.runtime.get_job("job-1").unwrap();
+    job.step_history.last().unwrap().job_id.clone().unwrap()
+}
+
+/// Finish the child's shell step and deliver its terminal transition,
+/// which the daemon would normally feed back in.
+async fn finish_child(ctx: &TestContext, exit_code: i32) {
+    let child_id = child_job(ctx);
+    ctx.runtime
+        .handle_event(Event::ShellExited {
+            job_id: JobId::new(&child_id),
+            step: "rebase".to_string(),
+            exit_code,
+            stdout: None,
+            stderr: None,
+            timed_out: false,
+        })
+        .await
+        .unwrap();
+    let step = ctx.runtime.get_job(&child_id).unwrap().step;
+    ctx.runtime
+        .handle_event(Event::JobAdvanced {
+            id: JobId::new(&child_id),
+            step,
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn sub_job_step_starts_linked_child() {
+    let ctx = setup_with_runbook(SUB_JOB_RUNBOOK).await;
+    start_ship(&ctx).await;
+
+    let parent = ctx.runtime.get_job("job-1").unwrap();
+    assert_eq!(parent.step, "merge");
+    assert_eq!(parent.step_status, StepStatus::Running);
+
+    let child = ctx.runtime.get_job(&child_job(&ctx)).unwrap();
+    assert_eq!(child.kind, "merge");
+    assert_eq!(child.step, "rebase");
+    assert_eq!(child.parent_job_id.as_deref(), Some("job-1"));
+    assert!(!child.is_branch);
+    assert_eq!(
+        child.vars.get("var.branch").map(String::as_str),
+        Some("feature/pr-1")
+    );
+    assert_eq!(
+        child.vars.get("var.remote").map(String::as_str),
+        Some("origin")
+    );
+}
+
+#[tokio::test]
+async fn parent_step_completes_when_child_is_done() {
+    let ctx = setup_with_runbook(SUB_JOB_RUNBOOK).await;
+    start_ship(&ctx).await;
+    let child_id = child_job(&ctx);
+
+    finish_child(&ctx, 0).await;
+
+    assert_eq!(ctx.runtime.get_job(&child_id).unwrap().step, "done");
+    let parent = ctx.runtime.get_job("job-1").unwrap();
+    assert_eq!(parent.step, "deploy");
+    assert_eq!(parent.step_history[0].outcome, StepOutcome::Completed);
+}
+
+#[tokio::test]
+async fn failed_child_fails_parent_step() {
+    let ctx = setup_with_runbook(SUB_JOB_RUNBOOK).await;
+    start_ship(&ctx).await;
+
+    finish_child(&ctx, 1).await;
+
+    let parent = ctx.runtime.get_job("job-1").unwrap();
+    assert_eq!(parent.step, "report");
+    assert_eq!(
+        parent.step_history[0].outcome,
+        StepOutcome::Failed("job 'merge' failed: shell exit code: 1".to_string())
+    );
+}
+
+#[tokio::test]
+async fn cancelling_parent_cancels_child() {
+    let ctx = setup_with_runbook(SUB_JOB_RUNBOOK).await;
+    start_ship(&ctx).await;
+    let child_id = child_job(&ctx);
+
+    let parent = ctx.runtime.get_job("job-1").unwrap();
+    ctx.runtime.cancel_job(&parent).await.unwrap();
+
+    assert_eq!(ctx.runtime.get_job("job-1").unwrap().step, "cancelled");
+    assert_eq!(ctx.runtime.get_job(&child_id).unwrap().step, "cancelled");
+}
+
+#[tokio::test]
+async fn failing_parent_cancels_child() {
+    let ctx = setup_with_runbook(SUB_JOB_RUNBOOK).await;
+    start_ship(&ctx).await;
+    let child_id = child_job(&ctx);
+
+    let parent = ctx.runtime.get_job("job-1").unwrap();
+    ctx.runtime
+        .fail_job(&parent, "budget exceeded")
+        .await
+        .unwrap();
+
+    assert_eq!(ctx.runtime.get_job("job-1").unwrap().step, "report");
+    assert_eq!(ctx.runtime.get_job(&child_id).unwrap().step, "cancelled");
+
+    // The cancelled child's terminal transition no longer touches the parent
+    ctx.runtime
+        .handle_event(Event::JobAdvanced {
+            id: JobId::new(&child_id),
+            step: "cancelled".to_string(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(ctx.runtime.get_job("job-1").unwrap().step, "report");
+}
+
+#[tokio::test]
+async fn resume_is_rejected_while_child_runs() {
+    let ctx = setup_with_runbook(SUB_JOB_RUNBOOK).await;
+    start_ship(&ctx).await;
+
+    let err = ctx
+        .runtime
+        .handle_event(Event::JobResume {
+            id: JobId::new("job-1"),
+            message: None,
+            vars: HashMap::new(),
+            kill: false,
+        })
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("waiting on a child job"));
+    assert_eq!(ctx.runtime.get_job("job-1").unwrap().step, "merge");
+}
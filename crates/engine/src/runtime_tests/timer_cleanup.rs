@@ -140,6 +140,7 @@ async fn liveness_timer_cancelled_on_job_failure() {
             exit_code: 1,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -282,6 +283,7 @@ async fn exit_deferred_timer_cancelled_on_job_failure() {
             exit_code: 1,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -402,6 +404,7 @@ async fn cooldown_timer_noop_when_job_becomes_terminal() {
             exit_code: 1,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -0,0 +1,111 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Conditional transition tests (on_done branches and on_exit)
+
+use super::*;
+use oj_core::{JobId, StepOutcome};
+
+const TRANSITIONS_RUNBOOK: &str = r#"
+[command.sync]
+args = "<name>"
+run = { job = "sync" }
+
+[job.sync]
+input = ["name"]
+
+[[job.sync.step]]
+name = "check"
+run = "./check.sh"
+on_done = [
+  { if = "${steps.check.outputs.changed} == true", step = "commit" },
+  { step = "skip" },
+]
+on_exit = { 2 = "needs-review" }
+
+[[job.sync.step]]
+name = "commit"
+run = "echo commit"
+
+[[job.sync.step]]
+name = "skip"
+run = "echo skip"
+
+[[job.sync.step]]
+name = "needs-review"
+run = "echo review"
+"#;
+
+async fn start_sync(ctx: &TestContext) {
+    ctx.runtime
+        .handle_event(command_event(
+            "job-1",
+            "sync",
+            "sync",
+            [("name".to_string(), "s".to_string())]
+                .into_iter()
+                .collect(),
+            &ctx.project_root,
+        ))
+        .await
+        .unwrap();
+}
+
+async fn finish_check(ctx: &TestContext, exit_code: i32, stdout: &str) {
+    ctx.runtime
+        .handle_event(Event::ShellExited {
+            job_id: JobId::new("job-1"),
+            step: "check".to_string(),
+            exit_code,
+            stdout: Some(stdout.to_string()),
+            stderr: None,
+            timed_out: false,
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn first_matching_branch_is_taken() {
+    let ctx = setup_with_runbook(TRANSITIONS_RUNBOOK).await;
+    start_sync(&ctx).await;
+
+    finish_check(&ctx, 0, "::outputs:: {\"changed\": true}").await;
+
+    assert_eq!(ctx.runtime.get_job("job-1").unwrap().step, "commit");
+}
+
+#[tokio::test]
+async fn falls_through_to_unconditional_branch() {
+    let ctx = setup_with_runbook(TRANSITIONS_RUNBOOK).await;
+    start_sync(&ctx).await;
+
+    finish_check(&ctx, 0, "::outputs:: {\"changed\": false}").await;
+
+    assert_eq!(ctx.runtime.get_job("job-1").unwrap().step, "skip");
+}
+
+#[tokio::test]
+async fn on_exit_routes_without_failing_the_step() {
+    let ctx = setup_with_runbook(TRANSITIONS_RUNBOOK).await;
+    start_sync(&ctx).await;
+
+    finish_check(&ctx, 2, "").await;
+
+    let job = ctx.runtime.get_job("job-1").unwrap();
+    assert_eq!(job.step, "needs-review");
+    assert!(job.error.is_none());
+    assert_eq!(job.step_history[0].outcome, StepOutcome::Completed);
+}
+
+#[tokio::test]
+async fn unmatched_exit_code_still_fails() {
+    let ctx = setup_with_runbook(TRANSITIONS_RUNBOOK).await;
+    start_sync(&ctx).await;
+
+    finish_check(&ctx, 1, "").await;
+
+    let job = ctx.runtime.get_job("job-1").unwrap();
+    assert_eq!(job.step, "failed");
+    assert_eq!(job.error.as_deref(), Some("shell exit code: 1"));
+}
@@ -0,0 +1,221 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `on` event triggers that start jobs
+
+use super::*;
+use crate::runtime::handlers::trigger::MAX_TRIGGER_DEPTH;
+
+const TRIGGER_RUNBOOK: &str = r#"
+[command.build]
+args = "<name>"
+run = { job = "build" }
+
+[job.build]
+input  = ["name"]
+
+[[job.build.step]]
+name = "init"
+run = "echo build"
+
+[job.deploy]
+input  = ["name"]
+
+[[job.deploy.step]]
+name = "init"
+run = "echo deploy ${event.id}"
+
+[[on."job:completed"]]
+filter = { kind = "build" }
+run = { job = "deploy" }
+
+[[on."job:failed"]]
+run = { job = "deploy" }
+"#;
+
+/// Handle an event and everything it leads to, returning all produced events.
+async fn handle_all(ctx: &TestContext, event: Event) -> Vec<Event> {
+    let mut pending = vec![event];
+    let mut events = Vec::new();
+    while let Some(event) = pending.pop() {
+        let produced = ctx.runtime.handle_event(event).await.unwrap();
+        pending.extend(produced.iter().cloned());
+        events.extend(produced);
+    }
+    events
+}
+
+async fn setup_triggers(runbook: &str) -> TestContext {
+    let ctx = setup_with_runbook(runbook).await;
+    ctx.runtime.lock_state_mut(|state| {
+        state
+            .project_roots
+            .insert(String::new(), ctx.project_root.clone());
+    });
+    ctx
+}
+
+async fn run_build(ctx: &TestContext, exit_code: i32) {
+    run_build_as(ctx, "build-1", exit_code).await;
+}
+
+async fn run_build_as(ctx: &TestContext, job_id: &str, exit_code: i32) {
+    let args = [("name".to_string(), "feature".to_string())].into();
+    handle_all(
+        ctx,
+        command_event(job_id, "build", "build", args, &ctx.project_root),
+    )
+    .await;
+    finish_init(ctx, job_id, exit_code).await;
+}
+
+async fn finish_init(ctx: &TestContext, job_id: &str, exit_code: i32) {
+    handle_all(
+        ctx,
+        Event::ShellExited {
+            job_id: JobId::new(job_id),
+            step: "init".to_string(),
+            exit_code,
+            stdout: None,
+            stderr: None,
+            timed_out: false,
+        },
+    )
+    .await;
+}
+
+fn jobs_of_kind(ctx: &TestContext, kind: &str) -> Vec<oj_core::Job> {
+    ctx.runtime
+        .jobs()
+        .into_values()
+        .filter(|job| job.kind == kind)
+        .collect()
+}
+
+#[tokio::test]
+async fn completed_job_starts_triggered_job() {
+    let ctx = setup_triggers(TRIGGER_RUNBOOK).await;
+
+    run_build(&ctx, 0).await;
+
+    let deploys = jobs_of_kind(&ctx, "deploy");
+    assert_eq!(deploys.len(), 1);
+    let deploy = &deploys[0];
+    assert_eq!(deploy.namespace, "");
+    assert_eq!(
+        deploy.vars.get("event.id").map(String::as_str),
+        Some("build-1")
+    );
+    assert_eq!(
+        deploy.vars.get("event.kind").map(String::as_str),
+        Some("build")
+    );
+    // Inputs the target job declares pass through from the source job
+    assert_eq!(
+        deploy.vars.get("var.name").map(String::as_str),
+        Some("feature")
+    );
+}
+
+#[tokio::test]
+async fn failed_job_exposes_error_to_triggered_job() {
+    let ctx = setup_triggers(TRIGGER_RUNBOOK).await;
+
+    run_build(&ctx, 1).await;
+
+    let deploys = jobs_of_kind(&ctx, "deploy");
+    assert_eq!(deploys.len(), 1);
+    let error = deploys[0].vars.get("event.error").cloned();
+    assert!(error.is_some_and(|e| !e.is_empty()));
+}
+
+#[tokio::test]
+async fn filter_mismatch_starts_nothing() {
+    let runbook = TRIGGER_RUNBOOK.replace(r#"kind = "build""#, r#"kind = "release""#);
+    let ctx = setup_triggers(&runbook).await;
+
+    run_build(&ctx, 0).await;
+
+    assert!(jobs_of_kind(&ctx, "deploy").is_empty());
+}
+
+#[tokio::test]
+async fn job_does_not_trigger_its_own_kind() {
+    let runbook = TRIGGER_RUNBOOK.replace(r#"{ kind = "build" }"#, "{}");
+    let ctx = setup_triggers(&runbook).await;
+
+    run_build(&ctx, 0).await;
+    let deploy_id = jobs_of_kind(&ctx, "deploy")[0].id.clone();
+    finish_init(&ctx, &deploy_id, 0).await;
+
+    assert_eq!(ctx.runtime.get_job(&deploy_id).unwrap().step, "done");
+    assert_eq!(jobs_of_kind(&ctx, "deploy").len(), 1);
+}
+
+#[tokio::test]
+async fn triggers_need_a_known_project() {
+    let ctx = setup_with_runbook(TRIGGER_RUNBOOK).await;
+
+    run_build(&ctx, 0).await;
+
+    assert!(jobs_of_kind(&ctx, "deploy").is_empty());
+}
+
+#[tokio::test]
+async fn jobs_triggering_each_other_stop_at_max_depth() {
+    let runbook = format!(
+        r#"{TRIGGER_RUNBOOK}
+[[on."job:completed"]]
+filter = {{ kind = "deploy" }}
+run = {{ job = "build" }}
+"#
+    );
+    let ctx = setup_triggers(&runbook).await;
+
+    run_build(&ctx, 0).await;
+    for _ in 0..100 {
+        let running: Vec<_> = ctx
+            .runtime
+            .jobs()
+            .into_values()
+            .filter(|job| job.step == "init")
+            .collect();
+        if running.is_empty() {
+            break;
+        }
+        for job in running {
+            finish_init(&ctx, &job.id, 0).await;
+        }
+    }
+
+    let jobs = ctx.runtime.jobs();
+    assert_eq!(jobs.len() as u32, 1 + MAX_TRIGGER_DEPTH);
+    let deepest = jobs
+        .values()
+        .filter_map(|job| job.vars.get("trigger.depth")?.parse::<u32>().ok())
+        .max();
+    assert_eq!(deepest, Some(MAX_TRIGGER_DEPTH));
+}
+
+#[tokio::test]
+async fn edited_triggers_take_effect_on_the_next_event() {
+    let runbook = TRIGGER_RUNBOOK.replace(r#"kind = "build""#, r#"kind = "release""#);
+    let ctx = setup_triggers(&runbook).await;
+
+    run_build_as(&ctx, "build-1", 0).await;
+    assert!(jobs_of_kind(&ctx, "deploy").is_empty());
+
+    std::fs::write(
+        ctx.project_root.join(".oj/runbooks/test.toml"),
+        TRIGGER_RUNBOOK,
+    )
+    .unwrap();
+    run_build_as(&ctx, "build-2", 0).await;
+
+    let deploys = jobs_of_kind(&ctx, "deploy");
+    assert_eq!(deploys.len(), 1);
+    assert_eq!(
+        deploys[0].vars.get("event.id").map(String::as_str),
+        Some("build-2")
+    );
+}
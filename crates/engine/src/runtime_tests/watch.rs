@@ -0,0 +1,216 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! File watch runtime tests
+
+use super::*;
+
+const WATCH_RUNBOOK: &str = r#"
+[watch.specs]
+paths = ["docs/specs/**/*.md"]
+debounce = "30s"
+run = { job = "spec-review" }
+
+[job.spec-review]
+
+[[job.spec-review.step]]
+name = "review"
+run = "echo ${watch.files}"
+
+[job.lint]
+
+[[job.lint.step]]
+name = "lint"
+run = "echo lint"
+"#;
+
+async fn start_watch(ctx: &TestContext) {
+    let (runbook_json, runbook_hash) = hash_runbook(WATCH_RUNBOOK);
+    ctx.runtime
+        .handle_event(Event::RunbookLoaded {
+            hash: runbook_hash.clone(),
+            version: 1,
+            runbook: runbook_json,
+        })
+        .await
+        .unwrap();
+    ctx.runtime
+        .handle_event(Event::WatchStarted {
+            watch_name: "specs".to_string(),
+            project_root: ctx.project_root.clone(),
+            runbook_hash,
+            paths: vec!["docs/specs/**/*.md".to_string()],
+            debounce: "30s".to_string(),
+            run_target: "job:spec-review".to_string(),
+            namespace: String::new(),
+        })
+        .await
+        .unwrap();
+}
+
+fn files_changed(files: &[&str]) -> Event {
+    Event::WatchChanged {
+        watch_name: "specs".to_string(),
+        namespace: String::new(),
+        files: files.iter().map(|f| f.to_string()).collect(),
+    }
+}
+
+#[tokio::test]
+async fn changed_files_start_the_job() {
+    let ctx = setup_with_runbook(WATCH_RUNBOOK).await;
+    start_watch(&ctx).await;
+
+    let events = ctx
+        .runtime
+        .handle_event(files_changed(&["docs/specs/a.md", "docs/specs/b.md"]))
+        .await
+        .unwrap();
+
+    let jobs: Vec<_> = ctx.runtime.jobs().into_values().collect();
+    assert_eq!(jobs.len(), 1);
+    let job = &jobs[0];
+    assert_eq!(job.kind, "spec-review");
+    assert_eq!(
+        job.vars.get("watch.files").map(String::as_str),
+        Some("docs/specs/a.md docs/specs/b.md")
+    );
+    assert_eq!(
+        job.vars.get("watch.name").map(String::as_str),
+        Some("specs")
+    );
+    assert!(events.iter().any(|e| matches!(
+        e,
+        Event::WatchFired { watch_name, job_id, .. }
+            if watch_name == "specs" && job_id.as_str() == job.id
+    )));
+}
+
+#[tokio::test]
+async fn stopped_watch_ignores_changes() {
+    let ctx = setup_with_runbook(WATCH_RUNBOOK).await;
+    start_watch(&ctx).await;
+    ctx.runtime
+        .handle_event(Event::WatchStopped {
+            watch_name: "specs".to_string(),
+            namespace: String::new(),
+        })
+        .await
+        .unwrap();
+
+    let events = ctx
+        .runtime
+        .handle_event(files_changed(&["docs/specs/a.md"]))
+        .await
+        .unwrap();
+
+    assert!(events.is_empty());
+    assert!(ctx.runtime.jobs().is_empty());
+}
+
+#[tokio::test]
+async fn unknown_watch_ignores_changes() {
+    let ctx = setup_with_runbook(WATCH_RUNBOOK).await;
+
+    let events = ctx
+        .runtime
+        .handle_event(files_changed(&["docs/specs/a.md"]))
+        .await
+        .unwrap();
+
+    assert!(events.is_empty());
+    assert!(ctx.runtime.jobs().is_empty());
+}
+
+#[tokio::test]
+async fn runbook_edits_apply_to_the_next_change() {
+    let ctx = setup_with_runbook(WATCH_RUNBOOK).await;
+    start_watch(&ctx).await;
+    let edited = WATCH_RUNBOOK.replace(
+        r#"run = { job = "spec-review" }"#,
+        r#"run = { job = "lint" }"#,
+    );
+    std::fs::write(ctx.project_root.join(".oj/runbooks/test.toml"), edited).unwrap();
+
+    ctx.runtime
+        .handle_event(files_changed(&["docs/specs/a.md"]))
+        .await
+        .unwrap();
+
+    let kinds: Vec<_> = ctx.runtime.jobs().into_values().map(|j| j.kind).collect();
+    assert_eq!(kinds, vec!["lint".to_string()]);
+}
+
+#[tokio::test]
+async fn unreadable_runbook_falls_back_to_the_started_one() {
+    let ctx = setup_with_runbook(WATCH_RUNBOOK).await;
+    start_watch(&ctx).await;
+    std::fs::write(
+        ctx.project_root.join(".oj/runbooks/test.toml"),
+        "[watch.specs",
+    )
+    .unwrap();
+
+    ctx.runtime
+        .handle_event(files_changed(&["docs/specs/a.md"]))
+        .await
+        .unwrap();
+
+    let kinds: Vec<_> = ctx.runtime.jobs().into_values().map(|j| j.kind).collect();
+    assert_eq!(kinds, vec!["spec-review".to_string()]);
+}
+
+#[tokio::test]
+async fn changes_while_the_job_runs_are_skipped() {
+    let ctx = setup_with_runbook(WATCH_RUNBOOK).await;
+    start_watch(&ctx).await;
+    ctx.runtime
+        .handle_event(files_changed(&["docs/specs/a.md"]))
+        .await
+        .unwrap();
+    let job_id = ctx.runtime.jobs().into_keys().next().unwrap();
+
+    let events = ctx
+        .runtime
+        .handle_event(files_changed(&["docs/specs/b.md"]))
+        .await
+        .unwrap();
+    assert!(events.is_empty());
+    assert_eq!(ctx.runtime.jobs().len(), 1);
+
+    ctx.runtime
+        .handle_event(Event::ShellExited {
+            job_id: JobId::new(&job_id),
+            step: "review".to_string(),
+            exit_code: 0,
+            stdout: None,
+            stderr: None,
+            timed_out: false,
+        })
+        .await
+        .unwrap();
+    assert_eq!(ctx.runtime.get_job(&job_id).unwrap().step, "done");
+
+    ctx.runtime
+        .handle_event(files_changed(&["docs/specs/c.md"]))
+        .await
+        .unwrap();
+    assert_eq!(ctx.runtime.jobs().len(), 2);
+}
+
+#[tokio::test]
+async fn concurrency_allows_overlapping_runs() {
+    let runbook =
+        WATCH_RUNBOOK.replace(r#"debounce = "30s""#, "debounce = \"30s\"\nconcurrency = 2");
+    let ctx = setup_with_runbook(&runbook).await;
+    start_watch(&ctx).await;
+
+    for file in ["docs/specs/a.md", "docs/specs/b.md", "docs/specs/c.md"] {
+        ctx.runtime
+            .handle_event(files_changed(&[file]))
+            .await
+            .unwrap();
+    }
+
+    assert_eq!(ctx.runtime.jobs().len(), 2);
+}
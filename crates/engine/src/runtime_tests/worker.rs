@@ -72,6 +72,11 @@ pub(super) fn push_persisted_items(ctx: &TestContext, queue: &str, count: usize)
                 },
                 pushed_at_epoch_ms: 1000 + i as u64,
                 namespace: String::new(),
+                priority: 0,
+                visible_after_epoch_ms: None,
+                dedup_key: None,
+                expires_at_epoch_ms: None,
+                drop_on_expire: false,
             });
         }
     });
@@ -112,7 +117,9 @@ pub(super) async fn start_worker_and_poll(
             project_root: ctx.project_root.clone(),
             runbook_hash: hash,
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency,
+            rate: None,
             namespace: String::new(),
         })
         .await
@@ -183,7 +190,9 @@ async fn runbook_loaded_event_populates_cache_for_worker_started() {
             project_root: ctx.project_root.clone(),
             runbook_hash: runbook_hash.clone(),
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency: 1,
+            rate: None,
             namespace: String::new(),
         })
         .await;
@@ -223,13 +232,17 @@ async fn worker_restart_restores_active_jobs_from_persisted_state() {
             project_root: ctx.project_root.clone(),
             runbook_hash: runbook_hash.clone(),
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency: 1,
+            rate: None,
             namespace: String::new(),
         });
         state.apply_event(&Event::WorkerItemDispatched {
             worker_name: "fixer".to_string(),
             item_id: "item-1".to_string(),
             job_id: oj_core::JobId::new("pipe-running"),
+            agent_run_id: None,
+            bucket_full_at_ms: None,
             namespace: String::new(),
         });
     });
@@ -251,7 +264,9 @@ async fn worker_restart_restores_active_jobs_from_persisted_state() {
             project_root: ctx.project_root.clone(),
             runbook_hash: runbook_hash.clone(),
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency: 1,
+            rate: None,
             namespace: String::new(),
         })
         .await
@@ -298,13 +313,17 @@ async fn worker_restart_restores_active_jobs_with_namespace() {
             project_root: ctx.project_root.clone(),
             runbook_hash: runbook_hash.clone(),
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency: 1,
+            rate: None,
             namespace: namespace.to_string(),
         });
         state.apply_event(&Event::WorkerItemDispatched {
             worker_name: "fixer".to_string(),
             item_id: "item-1".to_string(),
             job_id: oj_core::JobId::new("pipe-running"),
+            agent_run_id: None,
+            bucket_full_at_ms: None,
             namespace: namespace.to_string(),
         });
     });
@@ -326,7 +345,9 @@ async fn worker_restart_restores_active_jobs_with_namespace() {
             project_root: ctx.project_root.clone(),
             runbook_hash: runbook_hash.clone(),
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency: 1,
+            rate: None,
             namespace: namespace.to_string(),
         })
         .await
@@ -373,7 +394,9 @@ async fn worker_picks_up_runbook_edits_on_poll() {
             project_root: ctx.project_root.clone(),
             runbook_hash: original_hash.clone(),
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency: 1,
+            rate: None,
             namespace: String::new(),
         })
         .await
@@ -448,19 +471,25 @@ async fn worker_restart_restores_multiple_active_jobs() {
             project_root: ctx.project_root.clone(),
             runbook_hash: hash.clone(),
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency: 2,
+            rate: None,
             namespace: String::new(),
         });
         state.apply_event(&Event::WorkerItemDispatched {
             worker_name: "fixer".to_string(),
             item_id: "item-1".to_string(),
             job_id: JobId::new("pipe-a"),
+            agent_run_id: None,
+            bucket_full_at_ms: None,
             namespace: String::new(),
         });
         state.apply_event(&Event::WorkerItemDispatched {
             worker_name: "fixer".to_string(),
             item_id: "item-2".to_string(),
             job_id: JobId::new("pipe-b"),
+            agent_run_id: None,
+            bucket_full_at_ms: None,
             namespace: String::new(),
         });
     });
@@ -474,7 +503,9 @@ async fn worker_restart_restores_multiple_active_jobs() {
             project_root: ctx.project_root.clone(),
             runbook_hash: hash,
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency: 2,
+            rate: None,
             namespace: String::new(),
         })
         .await
@@ -527,7 +558,9 @@ async fn worker_no_refresh_when_runbook_unchanged() {
             project_root: ctx.project_root.clone(),
             runbook_hash: hash.clone(),
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency: 1,
+            rate: None,
             namespace: String::new(),
         })
         .await
@@ -0,0 +1,149 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Workers whose handler is an agent rather than a job
+
+use super::*;
+use oj_core::{AgentRunId, AgentRunStatus};
+
+use super::worker::{
+    count_dispatched, push_persisted_items, queue_item_status, start_worker_and_poll,
+};
+
+const AGENT_WORKER_RUNBOOK: &str = r#"
+[agent.triager]
+run = "claude --print"
+prompt = "Triage ${item.title}"
+
+[queue.bugs]
+type = "persisted"
+vars = ["title"]
+
+[worker.triage]
+source = { queue = "bugs" }
+handler = { agent = "triager" }
+concurrency = 2
+"#;
+
+/// Collect agent run IDs from WorkerItemDispatched events.
+fn dispatched_agent_run_ids(events: &[Event]) -> Vec<AgentRunId> {
+    events
+        .iter()
+        .filter_map(|e| match e {
+            Event::WorkerItemDispatched {
+                agent_run_id: Some(id),
+                ..
+            } => Some(AgentRunId::new(id)),
+            _ => None,
+        })
+        .collect()
+}
+
+async fn finish_agent_run(ctx: &TestContext, id: &AgentRunId, status: AgentRunStatus) {
+    ctx.runtime
+        .handle_event(Event::AgentRunStatusChanged {
+            id: id.clone(),
+            status,
+            reason: None,
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn dispatch_creates_standalone_agent_run_with_item_vars() {
+    let ctx = setup_with_runbook(AGENT_WORKER_RUNBOOK).await;
+    push_persisted_items(&ctx, "bugs", 1);
+
+    let events = start_worker_and_poll(&ctx, AGENT_WORKER_RUNBOOK, "triage", 2).await;
+    assert_eq!(count_dispatched(&events), 1);
+    assert!(ctx.runtime.jobs().is_empty(), "no job should be created");
+
+    let run_ids = dispatched_agent_run_ids(&events);
+    assert_eq!(run_ids.len(), 1);
+
+    let run = ctx
+        .runtime
+        .lock_state(|state| state.agent_runs.get(run_ids[0].as_str()).cloned())
+        .expect("agent run should exist");
+    assert_eq!(run.agent_name, "triager");
+    assert_eq!(run.command_name, "worker:triage");
+    assert_eq!(
+        run.vars.get("item.title").map(String::as_str),
+        Some("bug 1")
+    );
+    assert_eq!(
+        queue_item_status(&ctx, "bugs", "item-1"),
+        Some(oj_storage::QueueItemStatus::Active)
+    );
+}
+
+#[tokio::test]
+async fn queue_item_completed_when_agent_run_completes() {
+    let ctx = setup_with_runbook(AGENT_WORKER_RUNBOOK).await;
+    push_persisted_items(&ctx, "bugs", 1);
+
+    let events = start_worker_and_poll(&ctx, AGENT_WORKER_RUNBOOK, "triage", 2).await;
+    let run_ids = dispatched_agent_run_ids(&events);
+
+    finish_agent_run(&ctx, &run_ids[0], AgentRunStatus::Completed).await;
+
+    assert_eq!(
+        queue_item_status(&ctx, "bugs", "item-1"),
+        Some(oj_storage::QueueItemStatus::Completed)
+    );
+    let workers = ctx.runtime.worker_states.lock();
+    assert!(workers["triage"].active_agent_runs.is_empty());
+}
+
+#[tokio::test]
+async fn queue_item_dead_when_agent_run_fails() {
+    let ctx = setup_with_runbook(AGENT_WORKER_RUNBOOK).await;
+    push_persisted_items(&ctx, "bugs", 1);
+
+    let events = start_worker_and_poll(&ctx, AGENT_WORKER_RUNBOOK, "triage", 2).await;
+    let run_ids = dispatched_agent_run_ids(&events);
+
+    finish_agent_run(&ctx, &run_ids[0], AgentRunStatus::Failed).await;
+
+    // No retry config, so the failed item goes straight to dead
+    assert_eq!(
+        queue_item_status(&ctx, "bugs", "item-1"),
+        Some(oj_storage::QueueItemStatus::Dead)
+    );
+}
+
+#[tokio::test]
+async fn concurrency_counts_agent_runs() {
+    let ctx = setup_with_runbook(AGENT_WORKER_RUNBOOK).await;
+    push_persisted_items(&ctx, "bugs", 3);
+
+    let events = start_worker_and_poll(&ctx, AGENT_WORKER_RUNBOOK, "triage", 2).await;
+    let run_ids = dispatched_agent_run_ids(&events);
+    assert_eq!(run_ids.len(), 2, "only two runs fit the concurrency limit");
+    assert_eq!(
+        queue_item_status(&ctx, "bugs", "item-3"),
+        Some(oj_storage::QueueItemStatus::Pending)
+    );
+
+    // Finishing one run frees a slot for the third item
+    let result = ctx
+        .runtime
+        .handle_event(Event::AgentRunStatusChanged {
+            id: run_ids[0].clone(),
+            status: AgentRunStatus::Completed,
+            reason: None,
+        })
+        .await
+        .unwrap();
+    let mut dispatched = 0;
+    for event in result {
+        let follow_up = ctx.runtime.handle_event(event).await.unwrap();
+        dispatched += count_dispatched(&follow_up);
+    }
+    assert_eq!(dispatched, 1);
+    assert_eq!(
+        queue_item_status(&ctx, "bugs", "item-3"),
+        Some(oj_storage::QueueItemStatus::Active)
+    );
+}
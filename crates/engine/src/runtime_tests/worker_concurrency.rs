@@ -201,6 +201,11 @@ async fn worker_at_capacity_does_not_dispatch() {
             },
             pushed_at_epoch_ms: 2000,
             namespace: String::new(),
+            priority: 0,
+            visible_after_epoch_ms: None,
+            dedup_key: None,
+            expires_at_epoch_ms: None,
+            drop_on_expire: false,
         });
     });
 
@@ -46,7 +46,9 @@ async fn external_queue_overlapping_polls_skip_inflight_items() {
             project_root: ctx.project_root.clone(),
             runbook_hash: hash,
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency: 3,
+            rate: None,
             namespace: String::new(),
         })
         .await
@@ -120,7 +122,9 @@ async fn external_queue_take_failure_clears_inflight() {
             project_root: ctx.project_root.clone(),
             runbook_hash: hash,
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency: 3,
+            rate: None,
             namespace: String::new(),
         })
         .await
@@ -182,7 +186,9 @@ async fn worker_stop_clears_inflight_items() {
             project_root: ctx.project_root.clone(),
             runbook_hash: hash,
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency: 3,
+            rate: None,
             namespace: String::new(),
         })
         .await
@@ -266,6 +272,11 @@ async fn worker_dispatch_uses_namespaced_vars_only() {
             },
             pushed_at_epoch_ms: 1000,
             namespace: String::new(),
+            priority: 0,
+            visible_after_epoch_ms: None,
+            dedup_key: None,
+            expires_at_epoch_ms: None,
+            drop_on_expire: false,
         });
     });
 
@@ -332,7 +343,9 @@ async fn pending_takes_counted_toward_concurrency() {
             project_root: ctx.project_root.clone(),
             runbook_hash: hash,
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency: 1,
+            rate: None,
             namespace: String::new(),
         })
         .await
@@ -375,7 +388,9 @@ async fn worker_stop_clears_pending_takes() {
             project_root: ctx.project_root.clone(),
             runbook_hash: hash,
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency: 1,
+            rate: None,
             namespace: String::new(),
         })
         .await
@@ -434,7 +449,9 @@ async fn worker_restart_preserves_inflight_from_pending_takes() {
             project_root: ctx.project_root.clone(),
             runbook_hash: hash.clone(),
             queue_name: "bugs".to_string(),
+            queues: Default::default(),
             concurrency: 3,
+            rate: None,
             namespace: String::new(),
         })
         .await
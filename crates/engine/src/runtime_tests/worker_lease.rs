@@ -0,0 +1,226 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Leases on active queue items
+
+use super::*;
+use oj_core::TimerId;
+use oj_storage::QueueItemStatus;
+
+use super::worker::{
+    dispatched_job_ids, push_persisted_items, queue_item_status, start_worker_and_poll,
+};
+
+const LEASE_WORKER_RUNBOOK: &str = r#"
+[job.build]
+input  = ["name"]
+
+[[job.build.step]]
+name = "init"
+run = "echo init"
+
+[queue.bugs]
+type = "persisted"
+vars = ["title"]
+lease = "2h"
+retry = { attempts = 2, base = "1m" }
+
+[worker.fixer]
+source = { queue = "bugs" }
+handler = { job = "build" }
+concurrency = 1
+"#;
+
+const TWO_HOURS: std::time::Duration = std::time::Duration::from_secs(2 * 60 * 60);
+
+/// Handle an event and everything it leads to, returning all produced events.
+async fn handle_all(ctx: &TestContext, event: Event) -> Vec<Event> {
+    let mut pending = vec![event];
+    let mut events = Vec::new();
+    while let Some(event) = pending.pop() {
+        let produced = ctx.runtime.handle_event(event).await.unwrap();
+        pending.extend(produced.iter().cloned());
+        events.extend(produced);
+    }
+    events
+}
+
+fn lease_deadline(ctx: &TestContext, item_id: &str) -> Option<u64> {
+    ctx.runtime.lock_state(|state| {
+        state.queue_items["bugs"]
+            .iter()
+            .find(|i| i.id == item_id)
+            .and_then(|i| i.lease_expires_at_epoch_ms)
+    })
+}
+
+async fn lease_runs_out(ctx: &TestContext, item_id: &str) -> Vec<Event> {
+    ctx.clock.advance(TWO_HOURS);
+    handle_all(
+        ctx,
+        Event::TimerStart {
+            id: TimerId::queue_lease("bugs", item_id),
+        },
+    )
+    .await
+}
+
+#[tokio::test]
+async fn take_grants_a_lease() {
+    let ctx = setup_with_runbook(LEASE_WORKER_RUNBOOK).await;
+    push_persisted_items(&ctx, "bugs", 1);
+    let now = ctx.clock.epoch_ms();
+
+    start_worker_and_poll(&ctx, LEASE_WORKER_RUNBOOK, "fixer", 1).await;
+
+    assert_eq!(
+        lease_deadline(&ctx, "item-1"),
+        Some(now + TWO_HOURS.as_millis() as u64)
+    );
+    let lease_timer = TimerId::queue_lease("bugs", "item-1");
+    assert!(pending_timer_ids(&ctx)
+        .iter()
+        .any(|id| id == lease_timer.as_str()));
+}
+
+#[tokio::test]
+async fn lease_renews_while_the_job_runs() {
+    let ctx = setup_with_runbook(LEASE_WORKER_RUNBOOK).await;
+    push_persisted_items(&ctx, "bugs", 1);
+    start_worker_and_poll(&ctx, LEASE_WORKER_RUNBOOK, "fixer", 1).await;
+
+    let events = lease_runs_out(&ctx, "item-1").await;
+
+    assert!(events
+        .iter()
+        .any(|e| matches!(e, Event::QueueItemLeased { .. })));
+    assert_eq!(
+        queue_item_status(&ctx, "bugs", "item-1"),
+        Some(QueueItemStatus::Active)
+    );
+    assert_eq!(
+        lease_deadline(&ctx, "item-1"),
+        Some(ctx.clock.epoch_ms() + TWO_HOURS.as_millis() as u64)
+    );
+}
+
+fn failure_count(ctx: &TestContext, item_id: &str) -> Option<u32> {
+    ctx.runtime.lock_state(|state| {
+        state.queue_items["bugs"]
+            .iter()
+            .find(|i| i.id == item_id)
+            .map(|i| i.failure_count)
+    })
+}
+
+#[tokio::test]
+async fn orphaned_item_is_retried_by_the_queue_policy() {
+    let ctx = setup_with_runbook(LEASE_WORKER_RUNBOOK).await;
+    push_persisted_items(&ctx, "bugs", 1);
+    let events = start_worker_and_poll(&ctx, LEASE_WORKER_RUNBOOK, "fixer", 1).await;
+    let job_id = dispatched_job_ids(&events).remove(0);
+
+    // The handler job disappears without settling its item
+    ctx.runtime
+        .lock_state_mut(|state| state.apply_event(&Event::JobDeleted { id: job_id.clone() }));
+
+    let events = lease_runs_out(&ctx, "item-1").await;
+
+    assert!(events
+        .iter()
+        .any(|e| matches!(e, Event::QueueLeaseExpired { .. })));
+    assert_eq!(failure_count(&ctx, "item-1"), Some(1));
+    assert_eq!(
+        queue_item_status(&ctx, "bugs", "item-1"),
+        Some(QueueItemStatus::Failed)
+    );
+    let retry_timer = TimerId::queue_retry("bugs", "item-1");
+    assert!(pending_timer_ids(&ctx)
+        .iter()
+        .any(|id| id == retry_timer.as_str()));
+
+    // The retry puts the item back in front of the worker
+    ctx.clock.advance(std::time::Duration::from_secs(60));
+    let events = handle_all(&ctx, Event::TimerStart { id: retry_timer }).await;
+    let redispatched = dispatched_job_ids(&events);
+    assert_eq!(redispatched.len(), 1);
+    assert_ne!(redispatched[0], job_id);
+    assert_eq!(
+        queue_item_status(&ctx, "bugs", "item-1"),
+        Some(QueueItemStatus::Active)
+    );
+}
+
+#[tokio::test]
+async fn orphaned_item_goes_dead_when_retries_run_out() {
+    let ctx = setup_with_runbook(LEASE_WORKER_RUNBOOK).await;
+    push_persisted_items(&ctx, "bugs", 1);
+    let events = start_worker_and_poll(&ctx, LEASE_WORKER_RUNBOOK, "fixer", 1).await;
+    let job_id = dispatched_job_ids(&events).remove(0);
+    ctx.runtime.lock_state_mut(|state| {
+        let item = state
+            .queue_items
+            .get_mut("bugs")
+            .and_then(|items| items.iter_mut().find(|i| i.id == "item-1"))
+            .unwrap();
+        item.failure_count = 1;
+        state.apply_event(&Event::JobDeleted { id: job_id });
+    });
+
+    lease_runs_out(&ctx, "item-1").await;
+
+    assert_eq!(failure_count(&ctx, "item-1"), Some(2));
+    assert_eq!(
+        queue_item_status(&ctx, "bugs", "item-1"),
+        Some(QueueItemStatus::Dead)
+    );
+}
+
+#[tokio::test]
+async fn lease_is_not_renewed_for_a_job_whose_session_is_gone() {
+    let ctx = setup_with_runbook(LEASE_WORKER_RUNBOOK).await;
+    push_persisted_items(&ctx, "bugs", 1);
+    let events = start_worker_and_poll(&ctx, LEASE_WORKER_RUNBOOK, "fixer", 1).await;
+    let job_id = dispatched_job_ids(&events).remove(0);
+
+    // The job is still open, but its agent session died with no one noticing
+    ctx.runtime.lock_state_mut(|state| {
+        state.jobs.get_mut(job_id.as_str()).unwrap().session_id = Some("oj-gone".to_string());
+    });
+
+    let events = lease_runs_out(&ctx, "item-1").await;
+
+    assert!(!events
+        .iter()
+        .any(|e| matches!(e, Event::QueueItemLeased { .. })));
+    assert!(events
+        .iter()
+        .any(|e| matches!(e, Event::QueueLeaseExpired { .. })));
+    assert_eq!(
+        queue_item_status(&ctx, "bugs", "item-1"),
+        Some(QueueItemStatus::Failed)
+    );
+}
+
+#[tokio::test]
+async fn lease_timer_ignores_settled_item() {
+    let ctx = setup_with_runbook(LEASE_WORKER_RUNBOOK).await;
+    push_persisted_items(&ctx, "bugs", 1);
+    start_worker_and_poll(&ctx, LEASE_WORKER_RUNBOOK, "fixer", 1).await;
+    ctx.runtime.lock_state_mut(|state| {
+        state.apply_event(&Event::QueueCompleted {
+            queue_name: "bugs".to_string(),
+            item_id: "item-1".to_string(),
+            namespace: String::new(),
+            completed_at_epoch_ms: None,
+        })
+    });
+
+    let events = lease_runs_out(&ctx, "item-1").await;
+
+    assert!(events.is_empty());
+    assert_eq!(
+        queue_item_status(&ctx, "bugs", "item-1"),
+        Some(QueueItemStatus::Completed)
+    );
+}
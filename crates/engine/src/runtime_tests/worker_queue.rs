@@ -4,12 +4,23 @@
 //! Worker queue item lifecycle tests (done/fail/cancel) and stale poll dedup
 
 use super::*;
+use oj_core::TimerId;
 
 use super::worker::{
     count_dispatched, dispatched_job_ids, push_persisted_items, queue_item_status,
     start_worker_and_poll, CONCURRENT_WORKER_RUNBOOK,
 };
 
+fn dispatched_item_ids(events: &[Event]) -> Vec<&str> {
+    events
+        .iter()
+        .filter_map(|e| match e {
+            Event::WorkerItemDispatched { item_id, .. } => Some(item_id.as_str()),
+            _ => None,
+        })
+        .collect()
+}
+
 /// When a worker job completes ("done"), the queue item should transition
 /// from Active to Completed.
 #[tokio::test]
@@ -73,6 +84,7 @@ async fn queue_item_failed_on_job_failure() {
             exit_code: 1,
             stdout: None,
             stderr: None,
+            timed_out: false,
         })
         .await
         .unwrap();
@@ -241,3 +253,208 @@ async fn stale_poll_skips_active_items() {
         "stale poll should not dispatch: items are Active/Completed, and slots are full"
     );
 }
+
+/// Persisted queues hand out the highest priority first, then push order.
+#[tokio::test]
+async fn persisted_queue_dispatches_highest_priority_first() {
+    let ctx = setup_with_runbook(CONCURRENT_WORKER_RUNBOOK).await;
+    push_persisted_items(&ctx, "bugs", 4);
+    ctx.runtime.lock_state_mut(|state| {
+        for (item_id, priority) in [("item-3", 5), ("item-1", -1)] {
+            state.apply_event(&Event::QueueItemPrioritized {
+                queue_name: "bugs".to_string(),
+                item_id: item_id.to_string(),
+                priority,
+                namespace: String::new(),
+            });
+        }
+    });
+
+    let events = start_worker_and_poll(&ctx, CONCURRENT_WORKER_RUNBOOK, "fixer", 2).await;
+
+    assert_eq!(dispatched_item_ids(&events), vec!["item-3", "item-2"]);
+}
+
+/// A delayed item stays hidden from workers until its visibility timer
+/// fires, which wakes the worker to take it.
+#[tokio::test]
+async fn delayed_item_is_dispatched_once_visible() {
+    let ctx = setup_with_runbook(CONCURRENT_WORKER_RUNBOOK).await;
+    push_persisted_items(&ctx, "bugs", 1);
+    let visible_at = ctx.clock.epoch_ms() + 60_000;
+    let delayed = Event::QueuePushed {
+        queue_name: "bugs".to_string(),
+        item_id: "item-later".to_string(),
+        data: [("title".to_string(), "after CI".to_string())]
+            .into_iter()
+            .collect(),
+        pushed_at_epoch_ms: ctx.clock.epoch_ms(),
+        namespace: String::new(),
+        priority: 0,
+        visible_after_epoch_ms: Some(visible_at),
+        dedup_key: None,
+        expires_at_epoch_ms: None,
+        drop_on_expire: false,
+    };
+    ctx.runtime
+        .lock_state_mut(|state| state.apply_event(&delayed));
+    ctx.runtime.handle_event(delayed).await.unwrap();
+
+    let timer_id = TimerId::queue_visible("bugs", "item-later");
+    assert_eq!(
+        ctx.runtime
+            .lock_state(|s| s.timers.get(timer_id.as_str()).copied()),
+        Some(visible_at)
+    );
+
+    let events = start_worker_and_poll(&ctx, CONCURRENT_WORKER_RUNBOOK, "fixer", 2).await;
+    assert_eq!(dispatched_item_ids(&events), vec!["item-1"]);
+
+    ctx.clock.advance(std::time::Duration::from_secs(60));
+    // Timer → worker wake → poll → dispatch
+    let mut pending = vec![Event::TimerStart { id: timer_id }];
+    let mut events = Vec::new();
+    while let Some(event) = pending.pop() {
+        let produced = ctx.runtime.handle_event(event).await.unwrap();
+        pending.extend(produced.iter().cloned());
+        events.extend(produced);
+    }
+    assert_eq!(dispatched_item_ids(&events), vec!["item-later"]);
+}
+
+/// Push an item with a TTL into state and through the runtime.
+async fn push_expiring_item(ctx: &TestContext, item_id: &str, drop_on_expire: bool) -> TimerId {
+    let pushed = Event::QueuePushed {
+        queue_name: "bugs".to_string(),
+        item_id: item_id.to_string(),
+        data: [("title".to_string(), "stale".to_string())]
+            .into_iter()
+            .collect(),
+        pushed_at_epoch_ms: ctx.clock.epoch_ms(),
+        namespace: String::new(),
+        priority: 0,
+        visible_after_epoch_ms: None,
+        dedup_key: None,
+        expires_at_epoch_ms: Some(ctx.clock.epoch_ms() + 60_000),
+        drop_on_expire,
+    };
+    ctx.runtime
+        .lock_state_mut(|state| state.apply_event(&pushed));
+    ctx.runtime.handle_event(pushed).await.unwrap();
+    TimerId::queue_expire("bugs", item_id)
+}
+
+#[tokio::test]
+async fn pending_item_expires_when_ttl_runs_out() {
+    let ctx = setup_with_runbook(CONCURRENT_WORKER_RUNBOOK).await;
+    let timer_id = push_expiring_item(&ctx, "item-stale", false).await;
+    assert!(ctx
+        .runtime
+        .lock_state(|s| s.timers.contains_key(timer_id.as_str())));
+
+    ctx.clock.advance(std::time::Duration::from_secs(60));
+    let events = ctx
+        .runtime
+        .handle_event(Event::TimerStart { id: timer_id })
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        &events[..],
+        [Event::QueueItemExpired { item_id, .. }] if item_id == "item-stale"
+    ));
+}
+
+#[tokio::test]
+async fn drop_policy_removes_expired_item() {
+    let ctx = setup_with_runbook(CONCURRENT_WORKER_RUNBOOK).await;
+    let timer_id = push_expiring_item(&ctx, "item-stale", true).await;
+
+    ctx.clock.advance(std::time::Duration::from_secs(60));
+    let events = ctx
+        .runtime
+        .handle_event(Event::TimerStart { id: timer_id })
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        &events[..],
+        [Event::QueueItemExpired { .. }, Event::QueueDropped { item_id, .. }] if item_id == "item-stale"
+    ));
+}
+
+#[tokio::test]
+async fn taken_item_does_not_expire() {
+    let ctx = setup_with_runbook(CONCURRENT_WORKER_RUNBOOK).await;
+    let timer_id = push_expiring_item(&ctx, "item-taken", false).await;
+    ctx.runtime.lock_state_mut(|state| {
+        state.apply_event(&Event::QueueTaken {
+            queue_name: "bugs".to_string(),
+            item_id: "item-taken".to_string(),
+            worker_name: "fixer".to_string(),
+            namespace: String::new(),
+        })
+    });
+
+    ctx.clock.advance(std::time::Duration::from_secs(60));
+    let events = ctx
+        .runtime
+        .handle_event(Event::TimerStart { id: timer_id })
+        .await
+        .unwrap();
+
+    assert!(events.is_empty(), "{events:?}");
+}
+
+/// An item taken when its expiry timer fires is expired once it returns to
+/// pending, rather than sitting hidden from workers with no timer left.
+#[tokio::test]
+async fn item_retried_after_ttl_expires() {
+    let ctx = setup_with_runbook(CONCURRENT_WORKER_RUNBOOK).await;
+    let timer_id = push_expiring_item(&ctx, "item-slow", false).await;
+    let queue_event = |event: Event| {
+        ctx.runtime
+            .lock_state_mut(|state| state.apply_event(&event));
+        event
+    };
+    queue_event(Event::QueueTaken {
+        queue_name: "bugs".to_string(),
+        item_id: "item-slow".to_string(),
+        worker_name: "fixer".to_string(),
+        namespace: String::new(),
+    });
+
+    ctx.clock.advance(std::time::Duration::from_secs(60));
+    let events = ctx
+        .runtime
+        .handle_event(Event::TimerStart { id: timer_id })
+        .await
+        .unwrap();
+    assert!(events.is_empty(), "{events:?}");
+
+    queue_event(Event::QueueFailed {
+        queue_name: "bugs".to_string(),
+        item_id: "item-slow".to_string(),
+        error: "handler failed".to_string(),
+        namespace: String::new(),
+    });
+    let retry = queue_event(Event::QueueItemRetry {
+        queue_name: "bugs".to_string(),
+        item_id: "item-slow".to_string(),
+        namespace: String::new(),
+        automatic: false,
+    });
+    let events = ctx.runtime.handle_event(retry).await.unwrap();
+
+    assert!(matches!(
+        &events[..],
+        [Event::QueueItemExpired { item_id, .. }] if item_id == "item-slow"
+    ));
+    let status = ctx.runtime.lock_state(|s| {
+        s.queue_items["bugs"]
+            .iter()
+            .find(|i| i.id == "item-slow")
+            .map(|i| i.status.clone())
+    });
+    assert_eq!(status, Some(oj_storage::QueueItemStatus::Expired));
+}
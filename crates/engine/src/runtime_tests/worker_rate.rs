@@ -0,0 +1,137 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Worker dispatch rate limiting
+
+use super::*;
+use oj_core::TimerId;
+
+use super::worker::{count_dispatched, load_runbook_hash, push_persisted_items};
+
+const RATE_WORKER_RUNBOOK: &str = r#"
+[job.build]
+input  = ["name"]
+
+[[job.build.step]]
+name = "init"
+run = "echo init"
+
+[queue.bugs]
+type = "persisted"
+vars = ["title"]
+
+[worker.fixer]
+source = { queue = "bugs" }
+handler = { job = "build" }
+concurrency = 5
+rate = "2/h"
+"#;
+
+/// Handle an event and everything it leads to, returning all produced events.
+async fn handle_all(ctx: &TestContext, event: Event) -> Vec<Event> {
+    let mut pending = vec![event];
+    let mut events = Vec::new();
+    while let Some(event) = pending.pop() {
+        let produced = ctx.runtime.handle_event(event).await.unwrap();
+        pending.extend(produced.iter().cloned());
+        events.extend(produced);
+    }
+    events
+}
+
+/// Start the worker with its rate and process the initial poll.
+async fn start_rate_limited_worker(ctx: &TestContext) -> Vec<Event> {
+    let hash = load_runbook_hash(ctx, RATE_WORKER_RUNBOOK);
+    let started = Event::WorkerStarted {
+        worker_name: "fixer".to_string(),
+        project_root: ctx.project_root.clone(),
+        runbook_hash: hash,
+        queue_name: "bugs".to_string(),
+        queues: Default::default(),
+        concurrency: 5,
+        rate: Some("2/h".to_string()),
+        namespace: String::new(),
+    };
+    ctx.runtime
+        .lock_state_mut(|state| state.apply_event(&started));
+    let start_events = ctx.runtime.handle_event(started).await.unwrap();
+    let mut events = Vec::new();
+    for event in start_events {
+        events.extend(ctx.runtime.handle_event(event).await.unwrap());
+    }
+    events
+}
+
+fn rate_timer_pending(ctx: &TestContext) -> bool {
+    let rate_timer = TimerId::worker_rate("fixer", "");
+    pending_timer_ids(ctx)
+        .iter()
+        .any(|id| id == rate_timer.as_str())
+}
+
+#[tokio::test]
+async fn burst_then_waits_for_a_token() {
+    let ctx = setup_with_runbook(RATE_WORKER_RUNBOOK).await;
+    push_persisted_items(&ctx, "bugs", 4);
+
+    let events = start_rate_limited_worker(&ctx).await;
+    assert_eq!(count_dispatched(&events), 2, "a full bucket allows 2");
+
+    // Half an hour later one token is back
+    ctx.clock.advance(std::time::Duration::from_secs(30 * 60));
+    let events = handle_all(
+        &ctx,
+        Event::TimerStart {
+            id: TimerId::worker_rate("fixer", ""),
+        },
+    )
+    .await;
+    assert_eq!(count_dispatched(&events), 1);
+}
+
+#[tokio::test]
+async fn dispatch_records_bucket_state() {
+    let ctx = setup_with_runbook(RATE_WORKER_RUNBOOK).await;
+    push_persisted_items(&ctx, "bugs", 1);
+    let now = ctx.clock.epoch_ms();
+
+    start_rate_limited_worker(&ctx).await;
+
+    let record = ctx
+        .runtime
+        .lock_state(|state| state.workers.get("fixer").cloned())
+        .unwrap();
+    assert_eq!(record.rate.as_deref(), Some("2/h"));
+    assert_eq!(record.bucket_full_at_ms, Some(now + 30 * 60_000));
+}
+
+#[tokio::test]
+async fn restart_keeps_an_empty_bucket() {
+    let ctx = setup_with_runbook(RATE_WORKER_RUNBOOK).await;
+    push_persisted_items(&ctx, "bugs", 3);
+    let events = start_rate_limited_worker(&ctx).await;
+    assert_eq!(count_dispatched(&events), 2);
+
+    // The daemon restarts: WorkerStarted again, with the bucket persisted
+    let events = start_rate_limited_worker(&ctx).await;
+    assert_eq!(count_dispatched(&events), 0, "no fresh burst after restart");
+    assert!(rate_timer_pending(&ctx));
+}
+
+#[tokio::test]
+async fn removing_the_rate_dispatches_waiting_items() {
+    let ctx = setup_with_runbook(RATE_WORKER_RUNBOOK).await;
+    push_persisted_items(&ctx, "bugs", 4);
+    start_rate_limited_worker(&ctx).await;
+
+    let resized = Event::WorkerResized {
+        worker_name: "fixer".to_string(),
+        concurrency: 5,
+        rate: None,
+        namespace: String::new(),
+    };
+    ctx.runtime
+        .lock_state_mut(|state| state.apply_event(&resized));
+    let events = handle_all(&ctx, resized).await;
+    assert_eq!(count_dispatched(&events), 2);
+}
@@ -0,0 +1,181 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Workers consuming several persisted queues with weights
+
+use super::*;
+
+use super::worker::{dispatched_job_ids, load_runbook_hash, queue_item_status};
+
+const WEIGHTED_WORKER_RUNBOOK: &str = r#"
+[job.build]
+input  = ["name"]
+
+[[job.build.step]]
+name = "init"
+run = "echo init"
+
+[queue.bugs]
+type = "persisted"
+vars = ["title"]
+
+[queue.chores]
+type = "persisted"
+vars = ["title"]
+
+[worker.fixer]
+source = { queues = { bugs = 3, chores = 1 } }
+handler = { job = "build" }
+concurrency = 4
+"#;
+
+/// Push `count` items named `<queue>-<n>` to a persisted queue.
+fn push_items(ctx: &TestContext, queue: &str, count: usize) {
+    ctx.runtime.lock_state_mut(|state| {
+        for i in 1..=count {
+            state.apply_event(&Event::QueuePushed {
+                queue_name: queue.to_string(),
+                item_id: format!("{}-{}", queue, i),
+                data: [("title".to_string(), format!("{} {}", queue, i))]
+                    .into_iter()
+                    .collect(),
+                pushed_at_epoch_ms: 1000 + i as u64,
+                namespace: String::new(),
+                priority: 0,
+                visible_after_epoch_ms: None,
+                dedup_key: None,
+                expires_at_epoch_ms: None,
+                drop_on_expire: false,
+            });
+        }
+    });
+}
+
+/// Queue names of the items taken, in order.
+fn taken_queues(events: &[Event]) -> Vec<&str> {
+    events
+        .iter()
+        .filter_map(|e| match e {
+            Event::QueueTaken { queue_name, .. } => Some(queue_name.as_str()),
+            _ => None,
+        })
+        .collect()
+}
+
+async fn start_weighted_worker(ctx: &TestContext) -> Vec<Event> {
+    let hash = load_runbook_hash(ctx, WEIGHTED_WORKER_RUNBOOK);
+    let start_events = ctx
+        .runtime
+        .handle_event(Event::WorkerStarted {
+            worker_name: "fixer".to_string(),
+            project_root: ctx.project_root.clone(),
+            runbook_hash: hash,
+            queue_name: String::new(),
+            queues: [("bugs".to_string(), 3), ("chores".to_string(), 1)].into(),
+            concurrency: 4,
+            rate: None,
+            namespace: String::new(),
+        })
+        .await
+        .unwrap();
+
+    let mut events = Vec::new();
+    for event in start_events {
+        events.extend(ctx.runtime.handle_event(event).await.unwrap());
+    }
+    events
+}
+
+#[tokio::test]
+async fn slots_are_shared_by_weight() {
+    let ctx = setup_with_runbook(WEIGHTED_WORKER_RUNBOOK).await;
+    push_items(&ctx, "bugs", 5);
+    push_items(&ctx, "chores", 5);
+
+    let events = start_weighted_worker(&ctx).await;
+    let mut taken = taken_queues(&events);
+    taken.sort();
+    assert_eq!(taken, vec!["bugs", "bugs", "bugs", "chores"]);
+}
+
+#[tokio::test]
+async fn light_queue_is_served_when_one_slot_frees_at_a_time() {
+    let ctx = setup_with_runbook(WEIGHTED_WORKER_RUNBOOK).await;
+    push_items(&ctx, "bugs", 10);
+    push_items(&ctx, "chores", 10);
+
+    let mut events = start_weighted_worker(&ctx).await;
+    let mut taken: Vec<String> = Vec::new();
+    for _ in 0..8 {
+        taken.extend(taken_queues(&events).into_iter().map(String::from));
+        let job_id = dispatched_job_ids(&events)[0].clone();
+        events = ctx
+            .runtime
+            .handle_event(Event::JobAdvanced {
+                id: job_id,
+                step: "done".to_string(),
+            })
+            .await
+            .unwrap();
+        let mut polled = Vec::new();
+        for event in events {
+            polled.extend(ctx.runtime.handle_event(event).await.unwrap());
+        }
+        events = polled;
+    }
+
+    let chores = taken.iter().filter(|q| *q == "chores").count();
+    assert_eq!(taken.len(), 11);
+    assert!(
+        (2..=3).contains(&chores),
+        "chores should get about a quarter of the dispatches: {taken:?}"
+    );
+}
+
+#[tokio::test]
+async fn completed_item_settles_in_its_own_queue() {
+    let ctx = setup_with_runbook(WEIGHTED_WORKER_RUNBOOK).await;
+    push_items(&ctx, "chores", 1);
+
+    let events = start_weighted_worker(&ctx).await;
+    assert_eq!(taken_queues(&events), vec!["chores"]);
+    let job_id = dispatched_job_ids(&events)[0].clone();
+
+    ctx.runtime
+        .handle_event(Event::JobAdvanced {
+            id: job_id,
+            step: "done".to_string(),
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(
+        queue_item_status(&ctx, "chores", "chores-1"),
+        Some(oj_storage::QueueItemStatus::Completed)
+    );
+}
+
+#[tokio::test]
+async fn push_to_any_source_wakes_the_worker() {
+    let ctx = setup_with_runbook(WEIGHTED_WORKER_RUNBOOK).await;
+    start_weighted_worker(&ctx).await;
+
+    let pushed = Event::QueuePushed {
+        queue_name: "chores".to_string(),
+        item_id: "chores-1".to_string(),
+        data: HashMap::new(),
+        pushed_at_epoch_ms: 2000,
+        namespace: String::new(),
+        priority: 0,
+        visible_after_epoch_ms: None,
+        dedup_key: None,
+        expires_at_epoch_ms: None,
+        drop_on_expire: false,
+    };
+    ctx.runtime
+        .lock_state_mut(|state| state.apply_event(&pushed));
+    let events = ctx.runtime.handle_event(pushed).await.unwrap();
+    assert!(events
+        .iter()
+        .any(|e| matches!(e, Event::WorkerWake { worker_name, .. } if worker_name == "fixer")));
+}
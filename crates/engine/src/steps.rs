@@ -120,15 +120,23 @@ pub fn cancellation_transition_effects(job: &Job, on_cancel_step: &str) -> Vec<E
 /// and transitions to the "cancelled" terminal state.
 pub fn cancellation_effects(job: &Job) -> Vec<Effect> {
     let job_id = JobId::new(&job.id);
-    let mut effects = vec![];
-
-    // Cancel liveness and exit-deferred timers
-    effects.push(Effect::CancelTimer {
-        id: TimerId::liveness(&job_id),
-    });
-    effects.push(Effect::CancelTimer {
-        id: TimerId::exit_deferred(&job_id),
-    });
+    // Cancel liveness, exit-deferred, and pending step-retry timers
+    let mut effects = vec![
+        Effect::CancelTimer {
+            id: TimerId::liveness(&job_id),
+        },
+        Effect::CancelTimer {
+            id: TimerId::exit_deferred(&job_id),
+        },
+        Effect::CancelTimer {
+            id: TimerId::step_retry(&job_id),
+        },
+        Effect::Emit {
+            event: Event::TimerCancelled {
+                id: TimerId::step_retry(&job_id),
+            },
+        },
+    ];
 
     // Transition to cancelled state
     if !job.is_terminal() {
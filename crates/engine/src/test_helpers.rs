@@ -57,6 +57,7 @@ pub(crate) async fn setup_with_runbook(runbook_content: &str) -> TestContext {
         RuntimeConfig {
             state_dir: dir_path.clone(),
             log_dir: dir_path.join("logs"),
+            max_jobs: None,
         },
         event_tx,
     );
@@ -10,7 +10,12 @@ pub(crate) fn format_utc_now() -> String {
     let now = SystemTime::now()
         .duration_since(SystemTime::UNIX_EPOCH)
         .unwrap_or_default();
-    let secs = now.as_secs();
+    format_utc(now.as_millis() as u64)
+}
+
+/// Format epoch milliseconds as a UTC `YYYY-MM-DDTHH:MM:SSZ` timestamp.
+pub(crate) fn format_utc(epoch_ms: u64) -> String {
+    let secs = epoch_ms / 1000;
 
     // Convert epoch seconds to date/time components
     let days = secs / 86400;
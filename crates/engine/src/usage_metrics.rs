@@ -9,6 +9,8 @@
 //!
 //! The collector runs as a background tokio task (like `spawn_checkpoint`)
 //! and writes frequently enough that cost data survives daemon crashes.
+//! Whenever an agent's totals change it also emits `agent:usage`, so spend
+//! is recorded in state and checked against runbook budgets.
 
 use std::collections::HashMap;
 use std::fs::{self, File, OpenOptions};
@@ -19,8 +21,9 @@ use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
 
-use oj_core::OwnerId;
+use oj_core::{AgentId, Event, OwnerId, TokenUsage};
 use oj_storage::MaterializedState;
 
 /// Default collection interval (30 seconds).
@@ -79,6 +82,17 @@ struct SessionParseState {
     model: Option<String>,
 }
 
+impl SessionParseState {
+    fn usage(&self) -> TokenUsage {
+        TokenUsage {
+            input_tokens: self.input_tokens,
+            output_tokens: self.output_tokens,
+            cache_creation_input_tokens: self.cache_creation_input_tokens,
+            cache_read_input_tokens: self.cache_read_input_tokens,
+        }
+    }
+}
+
 /// Deltas returned from incremental parsing of a session log.
 pub(crate) struct UsageDeltas {
     pub input_tokens: u64,
@@ -201,6 +215,8 @@ pub struct UsageMetricsCollector {
     agent_meta: HashMap<String, AgentMeta>,
     health: Arc<Mutex<MetricsHealth>>,
     cycle_count: u64,
+    /// Receives `agent:usage` events for the event bus
+    event_tx: mpsc::Sender<Event>,
 }
 
 struct AgentMeta {
@@ -219,6 +235,7 @@ impl UsageMetricsCollector {
     pub fn spawn_collector(
         state: Arc<Mutex<MaterializedState>>,
         metrics_dir: PathBuf,
+        event_tx: mpsc::Sender<Event>,
     ) -> Arc<Mutex<MetricsHealth>> {
         let health = Arc::new(Mutex::new(MetricsHealth::default()));
 
@@ -229,6 +246,7 @@ impl UsageMetricsCollector {
             agent_meta: HashMap::new(),
             health: Arc::clone(&health),
             cycle_count: 0,
+            event_tx,
         };
 
         let interval_secs = std::env::var("OJ_METRICS_INTERVAL_SECS")
@@ -313,6 +331,13 @@ impl UsageMetricsCollector {
             }
         }
 
+        for event in self.usage_events(&agents) {
+            if let Err(e) = self.event_tx.try_send(event) {
+                // Retried next cycle: state still holds the old totals
+                tracing::warn!(error = %e, "failed to emit agent usage");
+            }
+        }
+
         // Ghost detection (every N cycles)
         let ghost_sessions = if self.cycle_count.is_multiple_of(GHOST_CHECK_EVERY_N) {
             detect_ghost_sessions(&agents)
@@ -373,6 +398,27 @@ impl UsageMetricsCollector {
         }
     }
 
+    /// `agent:usage` events for agents whose totals differ from state.
+    ///
+    /// Comparing against state (rather than the previous cycle) means a
+    /// restarted daemon, which re-reads every log from the start, only
+    /// re-reports agents whose usage moved while it was down.
+    fn usage_events(&self, agents: &HashMap<String, oj_core::AgentRecord>) -> Vec<Event> {
+        agents
+            .values()
+            .filter_map(|record| {
+                let parse = self.sessions.get(&record.agent_id)?;
+                let usage = parse.usage();
+                let new_model = parse.model.is_some() && parse.model != record.model;
+                (usage != record.usage || new_model).then(|| Event::AgentUsage {
+                    agent_id: AgentId::new(&record.agent_id),
+                    usage,
+                    model: parse.model.clone(),
+                })
+            })
+            .collect()
+    }
+
     /// Append records to the JSONL file.
     fn write_records(&self, records: &[UsageRecord]) -> Result<(), std::io::Error> {
         let path = self.metrics_dir.join("usage.jsonl");
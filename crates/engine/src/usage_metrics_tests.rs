@@ -146,6 +146,7 @@ fn write_records_produces_valid_jsonl() {
         agent_meta: HashMap::new(),
         health: Arc::new(Mutex::new(MetricsHealth::default())),
         cycle_count: 0,
+        event_tx: mpsc::channel(1).0,
     };
 
     let records = vec![
@@ -246,6 +247,7 @@ fn rotation_shifts_files_and_writes_baseline() {
         agent_meta: HashMap::new(),
         health: Arc::new(Mutex::new(MetricsHealth::default())),
         cycle_count: 0,
+        event_tx: mpsc::channel(1).0,
     };
 
     collector.rotate_if_needed();
@@ -265,6 +267,73 @@ fn rotation_shifts_files_and_writes_baseline() {
     assert_eq!(record.output_tokens, 250);
 }
 
+fn agent_record(agent_id: &str, usage: oj_core::TokenUsage) -> oj_core::AgentRecord {
+    oj_core::AgentRecord {
+        agent_id: agent_id.to_string(),
+        agent_name: "worker".to_string(),
+        owner: OwnerId::Job(oj_core::JobId::new("job-1")),
+        namespace: String::new(),
+        workspace_path: PathBuf::from("/tmp/ws"),
+        session_id: None,
+        status: oj_core::AgentRecordStatus::Running,
+        created_at_ms: 0,
+        updated_at_ms: 0,
+        usage,
+        model: Some("claude-sonnet-4-5-20250929".to_string()),
+        budget_exceeded: false,
+    }
+}
+
+#[test]
+fn usage_events_only_for_agents_whose_totals_changed() {
+    let parse = |input_tokens| SessionParseState {
+        offset: 0,
+        input_tokens,
+        output_tokens: 0,
+        cache_creation_input_tokens: 0,
+        cache_read_input_tokens: 0,
+        model: Some("claude-sonnet-4-5-20250929".to_string()),
+    };
+    let mut sessions = HashMap::new();
+    sessions.insert("steady".to_string(), parse(100));
+    sessions.insert("growing".to_string(), parse(900));
+
+    let collector = UsageMetricsCollector {
+        state: Arc::new(Mutex::new(MaterializedState::default())),
+        metrics_dir: PathBuf::from("/tmp/unused"),
+        sessions,
+        agent_meta: HashMap::new(),
+        health: Arc::new(Mutex::new(MetricsHealth::default())),
+        cycle_count: 0,
+        event_tx: mpsc::channel(1).0,
+    };
+
+    let recorded = |input_tokens| TokenUsage {
+        input_tokens,
+        ..Default::default()
+    };
+    let mut agents = HashMap::new();
+    agents.insert("steady".to_string(), agent_record("steady", recorded(100)));
+    agents.insert(
+        "growing".to_string(),
+        agent_record("growing", recorded(400)),
+    );
+    // No session log found yet
+    agents.insert("silent".to_string(), agent_record("silent", recorded(0)));
+
+    let events = collector.usage_events(&agents);
+    assert_eq!(events.len(), 1);
+    match &events[0] {
+        Event::AgentUsage {
+            agent_id, usage, ..
+        } => {
+            assert_eq!(agent_id.as_str(), "growing");
+            assert_eq!(usage.input_tokens, 900);
+        }
+        other => panic!("expected AgentUsage, got {other:?}"),
+    }
+}
+
 #[test]
 fn iso_now_produces_valid_timestamp() {
     let ts = iso_now();
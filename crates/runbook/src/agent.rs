@@ -3,6 +3,7 @@
 
 //! Agent definitions
 
+use crate::BudgetDef;
 use serde::{Deserialize, Deserializer, Serialize};
 use std::collections::HashMap;
 use std::io;
@@ -246,6 +247,14 @@ pub struct AgentDef {
     #[serde(default)]
     pub on_stop: Option<StopActionConfig>,
 
+    /// Spend limit for each instance of this agent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub budget: Option<BudgetDef>,
+
+    /// What to do when this agent's budget (or its job's) is crossed
+    #[serde(default = "default_on_budget")]
+    pub on_budget: ActionConfig,
+
     /// Maximum concurrent instances of this agent. None = unlimited.
     #[serde(default)]
     pub max_concurrency: Option<u32>,
@@ -370,6 +379,7 @@ pub enum ActionTrigger {
     OnDead,   // Agent process exited
     OnError,  // API error occurred
     OnPrompt, // Agent showing a permission/approval prompt
+    OnBudget, // Agent or job spend crossed its budget (still running)
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
@@ -428,6 +438,11 @@ impl AgentAction {
                 self,
                 AgentAction::Done | AgentAction::Escalate | AgentAction::Fail | AgentAction::Gate
             ),
+            // on_budget: agent may be mid-task, so ask it to wrap up or stop it
+            ActionTrigger::OnBudget => matches!(
+                self,
+                AgentAction::Nudge | AgentAction::Escalate | AgentAction::Fail
+            ),
         }
     }
 
@@ -452,6 +467,12 @@ impl AgentAction {
             (AgentAction::Resume, ActionTrigger::OnPrompt) => {
                 "resume is for re-spawning after exit; agent is still running"
             }
+            (AgentAction::Resume, ActionTrigger::OnBudget) => {
+                "resume re-spawns the agent, which keeps spending"
+            }
+            (AgentAction::Done | AgentAction::Gate, ActionTrigger::OnBudget) => {
+                "the agent may be mid-task; nudge it to wrap up instead"
+            }
             _ => "action not allowed for this trigger",
         }
     }
@@ -531,6 +552,10 @@ fn default_on_prompt() -> ActionConfig {
     ActionConfig::Simple(AgentAction::Escalate)
 }
 
+fn default_on_budget() -> ActionConfig {
+    ActionConfig::Simple(AgentAction::Escalate)
+}
+
 fn default_on_error() -> ErrorActionConfig {
     ErrorActionConfig::default()
 }
@@ -579,6 +604,8 @@ impl Default for AgentDef {
             on_prompt: default_on_prompt(),
             on_error: default_on_error(),
             on_stop: None,
+            budget: None,
+            on_budget: default_on_budget(),
             max_concurrency: None,
             notify: Default::default(),
             session: HashMap::new(),
@@ -19,6 +19,8 @@ fn agent_build_command_with_prompt_field() {
         on_prompt: default_on_prompt(),
         on_error: default_on_error(),
         on_stop: None,
+        budget: None,
+        on_budget: default_on_budget(),
         max_concurrency: None,
         notify: Default::default(),
         session: HashMap::new(),
@@ -46,6 +48,8 @@ fn agent_build_command_with_inline_prompt() {
         on_prompt: default_on_prompt(),
         on_error: default_on_error(),
         on_stop: None,
+        budget: None,
+        on_budget: default_on_budget(),
         max_concurrency: None,
         notify: Default::default(),
         session: HashMap::new(),
@@ -73,6 +77,8 @@ fn agent_build_command_print_mode() {
         on_prompt: default_on_prompt(),
         on_error: default_on_error(),
         on_stop: None,
+        budget: None,
+        on_budget: default_on_budget(),
         max_concurrency: None,
         notify: Default::default(),
         session: HashMap::new(),
@@ -104,6 +110,8 @@ fn agent_build_env() {
         on_prompt: default_on_prompt(),
         on_error: default_on_error(),
         on_stop: None,
+        budget: None,
+        on_budget: default_on_budget(),
         max_concurrency: None,
         notify: Default::default(),
         session: HashMap::new(),
@@ -136,6 +144,8 @@ fn agent_get_prompt_from_field() {
         on_prompt: default_on_prompt(),
         on_error: default_on_error(),
         on_stop: None,
+        budget: None,
+        on_budget: default_on_budget(),
         max_concurrency: None,
         notify: Default::default(),
         session: HashMap::new(),
@@ -167,6 +177,8 @@ fn agent_get_prompt_empty_when_unset() {
         on_prompt: default_on_prompt(),
         on_error: default_on_error(),
         on_stop: None,
+        budget: None,
+        on_budget: default_on_budget(),
         max_concurrency: None,
         notify: Default::default(),
         session: HashMap::new(),
@@ -198,6 +210,8 @@ fn agent_get_prompt_from_file() {
         on_prompt: default_on_prompt(),
         on_error: default_on_error(),
         on_stop: None,
+        budget: None,
+        on_budget: default_on_budget(),
         max_concurrency: None,
         notify: Default::default(),
         session: HashMap::new(),
@@ -229,6 +243,8 @@ fn agent_get_prompt_file_not_found() {
         on_prompt: default_on_prompt(),
         on_error: default_on_error(),
         on_stop: None,
+        budget: None,
+        on_budget: default_on_budget(),
         max_concurrency: None,
         notify: Default::default(),
         session: HashMap::new(),
@@ -0,0 +1,50 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Spend limits for agents and jobs
+//!
+//! ```hcl
+//! budget {
+//!   tokens = 2000000
+//!   usd    = 25
+//! }
+//! ```
+//!
+//! An agent's budget applies to each agent it spawns; a job's budget covers
+//! every agent the job runs. Crossing either fires the agent's `on_budget`.
+
+use serde::{Deserialize, Serialize};
+
+/// Token and cost limits. At least one must be set.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BudgetDef {
+    /// Input, output, and cache-write tokens (cache reads are not counted)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tokens: Option<u64>,
+    /// Estimated cost in US dollars, from the built-in model price table
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usd: Option<f64>,
+}
+
+impl BudgetDef {
+    /// Describe how the given spend crosses this budget, or None when it is
+    /// within it. Spend with no cost estimate never crosses a `usd` limit.
+    pub fn exceeded_by(&self, tokens: u64, usd: Option<f64>) -> Option<String> {
+        if let Some(limit) = self.tokens {
+            if tokens > limit {
+                return Some(format!("used {} tokens of {}", tokens, limit));
+            }
+        }
+        match (self.usd, usd) {
+            (Some(limit), Some(spent)) if spent > limit => {
+                Some(format!("spent ${:.2} of ${:.2}", spent, limit))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "budget_tests.rs"]
+mod tests;
@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+#[test]
+fn tokens_limit_is_exceeded_only_above_the_limit() {
+    let budget = BudgetDef {
+        tokens: Some(1_000),
+        usd: None,
+    };
+    assert_eq!(budget.exceeded_by(1_000, Some(99.0)), None);
+    assert_eq!(
+        budget.exceeded_by(1_001, None).as_deref(),
+        Some("used 1001 tokens of 1000")
+    );
+}
+
+#[test]
+fn usd_limit_needs_a_cost_estimate() {
+    let budget = BudgetDef {
+        tokens: None,
+        usd: Some(5.0),
+    };
+    assert_eq!(budget.exceeded_by(u64::MAX, None), None);
+    assert_eq!(budget.exceeded_by(0, Some(5.0)), None);
+    assert_eq!(
+        budget.exceeded_by(0, Some(5.126)).as_deref(),
+        Some("spent $5.13 of $5.00")
+    );
+}
@@ -368,6 +368,17 @@ fn parse_flag_names(flag_part: &str) -> Result<(Option<char>, String), ArgSpecEr
 }
 
 impl ArgSpec {
+    /// Names of every positional, flag, option, and variadic argument
+    pub fn names(&self) -> HashSet<&str> {
+        self.positional
+            .iter()
+            .map(|a| a.name.as_str())
+            .chain(self.options.iter().map(|o| o.name.as_str()))
+            .chain(self.flags.iter().map(|f| f.name.as_str()))
+            .chain(self.variadic.iter().map(|v| v.name.as_str()))
+            .collect()
+    }
+
     /// Format as a usage string, e.g. "<name> <instructions> [--base <branch>]"
     pub fn usage_line(&self) -> String {
         let mut parts = Vec::new();
@@ -548,13 +559,42 @@ pub enum RunDirective {
     /// Shell command string: `run = "echo hello"`
     Shell(String),
     /// Job reference: `run = { job = "build" }`
-    Job { job: String },
+    ///
+    /// In job steps, `vars` supplies the child job's input:
+    /// `run = { job = "merge", vars = { branch = "${var.branch}" } }`
+    Job {
+        job: String,
+        #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+        vars: HashMap<String, String>,
+    },
     /// Agent reference: `run = { agent = "planning" }` or `run = { agent = "planning", attach = true }`
     Agent {
         agent: String,
         #[serde(default)]
         attach: Option<bool>,
     },
+    /// Parallel fan-out (job steps only): `run = { parallel = ["lint", "test"] }`
+    Parallel { parallel: Vec<String> },
+    /// Human sign-off (job steps only):
+    /// `run = { approval = { prompt = "Ship it?", options = ["ship", "hold"] } }`
+    Approval { approval: ApprovalDef },
+}
+
+/// An approval step: asks a human to pick an option and fill in fields.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ApprovalDef {
+    /// Question shown in the decision (supports template interpolation)
+    pub prompt: String,
+    /// Choices offered; the chosen label is published as `steps.<name>.outputs.choice`
+    #[serde(default = "default_approval_options")]
+    pub options: Vec<String>,
+    /// Freeform values to collect, published as `steps.<name>.outputs.<field>`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<String>,
+}
+
+fn default_approval_options() -> Vec<String> {
+    vec!["approve".to_string(), "deny".to_string()]
 }
 
 impl RunDirective {
@@ -573,6 +613,11 @@ impl RunDirective {
         matches!(self, RunDirective::Agent { .. })
     }
 
+    /// Check if this is a parallel fan-out
+    pub fn is_parallel(&self) -> bool {
+        matches!(self, RunDirective::Parallel { .. })
+    }
+
     /// Get the shell command if this is a shell directive
     pub fn shell_command(&self) -> Option<&str> {
         match self {
@@ -584,7 +629,7 @@ impl RunDirective {
     /// Get the job name if this is a job directive
     pub fn job_name(&self) -> Option<&str> {
         match self {
-            RunDirective::Job { job } => Some(job),
+            RunDirective::Job { job, .. } => Some(job),
             _ => None,
         }
     }
@@ -597,6 +642,27 @@ impl RunDirective {
         }
     }
 
+    /// Check if this is an approval step
+    pub fn is_approval(&self) -> bool {
+        matches!(self, RunDirective::Approval { .. })
+    }
+
+    /// Get the approval definition if this is an approval directive
+    pub fn approval(&self) -> Option<&ApprovalDef> {
+        match self {
+            RunDirective::Approval { approval } => Some(approval),
+            _ => None,
+        }
+    }
+
+    /// Get the sibling step names if this is a parallel directive
+    pub fn parallel_steps(&self) -> Option<&[String]> {
+        match self {
+            RunDirective::Parallel { parallel } => Some(parallel),
+            _ => None,
+        }
+    }
+
     /// Get the attach preference if this is an agent directive
     pub fn attach(&self) -> Option<bool> {
         match self {
@@ -613,16 +679,7 @@ impl CommandDef {
         positional: &[String],
         named: &HashMap<String, String>,
     ) -> Result<(), ArgValidationError> {
-        // Build set of known names (positional, options, flags, variadic)
-        let known_names: HashSet<&str> = self
-            .args
-            .positional
-            .iter()
-            .map(|a| a.name.as_str())
-            .chain(self.args.options.iter().map(|o| o.name.as_str()))
-            .chain(self.args.flags.iter().map(|f| f.name.as_str()))
-            .chain(self.args.variadic.iter().map(|v| v.name.as_str()))
-            .collect();
+        let known_names = self.args.names();
 
         // Check all named args are known (catches typos early)
         for key in named.keys() {
@@ -226,6 +226,7 @@ fn run_directive_shell() {
 fn run_directive_job() {
     let directive = RunDirective::Job {
         job: "build".to_string(),
+        vars: HashMap::new(),
     };
     assert!(directive.is_job());
     assert!(!directive.is_shell());
@@ -333,7 +334,8 @@ fn attach_accessor_returns_none_for_non_agent() {
     assert_eq!(RunDirective::Shell("echo".to_string()).attach(), None);
     assert_eq!(
         RunDirective::Job {
-            job: "build".to_string()
+            job: "build".to_string(),
+            vars: HashMap::new(),
         }
         .attach(),
         None
@@ -378,6 +380,7 @@ fn command_parse_args() {
             .collect(),
         run: RunDirective::Job {
             job: "build".to_string(),
+            vars: HashMap::new(),
         },
     };
 
@@ -414,6 +417,7 @@ fn command_named_overrides() {
             .collect(),
         run: RunDirective::Job {
             job: "build".to_string(),
+            vars: HashMap::new(),
         },
     };
 
@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Transition conditions (`if = "${steps.check.outputs.changed} == true"`)
+//!
+//! A condition is either a comparison or a single value:
+//! - `a == b` / `a != b`: string comparison of the trimmed operands; a pair
+//!   of surrounding quotes is optional (`${var.mode} == "fast"`)
+//! - `a`: true unless empty, `false`, or `0`
+//!
+//! Operands are interpolated separately, so values containing `==` can't
+//! change the shape of the expression. References to unset variables
+//! evaluate as empty.
+
+use crate::template::{interpolate, VAR_PATTERN};
+use std::collections::HashMap;
+
+enum Condition<'a> {
+    Compare {
+        lhs: &'a str,
+        eq: bool,
+        rhs: &'a str,
+    },
+    Value(&'a str),
+}
+
+fn parse(expr: &str) -> Result<Condition<'_>, String> {
+    if expr.trim().is_empty() {
+        return Err("condition is empty".to_string());
+    }
+    let ops: Vec<(usize, &str)> = expr
+        .match_indices("==")
+        .chain(expr.match_indices("!="))
+        .collect();
+    match ops.as_slice() {
+        [] if expr.contains('=') => Err(format!(
+            "invalid condition '{}': use == or != to compare values",
+            expr
+        )),
+        [] => Ok(Condition::Value(expr)),
+        [(at, op)] => {
+            let (lhs, rhs) = (&expr[..*at], &expr[at + 2..]);
+            if lhs.trim().is_empty() || rhs.trim().is_empty() {
+                return Err(format!(
+                    "invalid condition '{}': {} needs a value on both sides",
+                    expr, op
+                ));
+            }
+            Ok(Condition::Compare {
+                lhs,
+                eq: *op == "==",
+                rhs,
+            })
+        }
+        _ => Err(format!(
+            "invalid condition '{}': only one == or != comparison is allowed",
+            expr
+        )),
+    }
+}
+
+/// Check that a condition is well-formed.
+pub(crate) fn validate_condition(expr: &str) -> Result<(), String> {
+    parse(expr).map(|_| ())
+}
+
+/// Evaluate a condition against job variables.
+///
+/// Malformed conditions (rejected at parse time) evaluate as false.
+pub fn evaluate_condition(expr: &str, vars: &HashMap<String, String>) -> bool {
+    let value = |operand: &str| {
+        let resolved = interpolate(operand, vars);
+        let resolved = VAR_PATTERN.replace_all(&resolved, "");
+        unquote(resolved.trim()).to_string()
+    };
+    match parse(expr) {
+        Ok(Condition::Compare { lhs, eq, rhs }) => (value(lhs) == value(rhs)) == eq,
+        Ok(Condition::Value(v)) => {
+            let v = value(v);
+            !(v.is_empty() || v.eq_ignore_ascii_case("false") || v == "0")
+        }
+        Err(_) => false,
+    }
+}
+
+fn unquote(s: &str) -> &str {
+    for quote in ['"', '\''] {
+        if let Some(inner) = s
+            .strip_prefix(quote)
+            .and_then(|rest| rest.strip_suffix(quote))
+        {
+            return inner;
+        }
+    }
+    s
+}
+
+#[cfg(test)]
+#[path = "condition_tests.rs"]
+mod tests;
@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+fn vars(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[test]
+fn equality_compares_interpolated_operands() {
+    let vars = vars(&[("steps.check.outputs.changed", "true")]);
+    assert!(evaluate_condition(
+        "${steps.check.outputs.changed} == true",
+        &vars
+    ));
+    assert!(!evaluate_condition(
+        "${steps.check.outputs.changed} != true",
+        &vars
+    ));
+    assert!(evaluate_condition(
+        "${steps.check.outputs.changed} == \"true\"",
+        &vars
+    ));
+}
+
+#[test]
+fn bare_values_are_truthy_unless_empty_false_or_zero() {
+    let vars = vars(&[("var.yes", "1"), ("var.no", "False"), ("var.zero", "0")]);
+    assert!(evaluate_condition("${var.yes}", &vars));
+    assert!(!evaluate_condition("${var.no}", &vars));
+    assert!(!evaluate_condition("${var.zero}", &vars));
+    assert!(!evaluate_condition("${var.unset}", &vars));
+}
+
+#[test]
+fn unset_variables_evaluate_as_empty() {
+    let vars = vars(&[]);
+    assert!(evaluate_condition("${var.unset} == ''", &vars));
+    assert!(!evaluate_condition("${var.unset} == true", &vars));
+}
+
+#[test]
+fn values_containing_operators_do_not_change_the_expression() {
+    let vars = vars(&[("var.a", "x == x")]);
+    assert!(!evaluate_condition("${var.a} == y", &vars));
+    assert!(evaluate_condition("${var.a} != y", &vars));
+}
+
+#[test]
+fn malformed_conditions_are_rejected() {
+    assert!(validate_condition("").is_err());
+    assert!(validate_condition("${var.a} = b").is_err());
+    assert!(validate_condition("== b").is_err());
+    assert!(validate_condition("a == b == c").is_err());
+    assert!(validate_condition("a != b").is_ok());
+}
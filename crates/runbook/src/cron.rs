@@ -6,18 +6,44 @@
 use crate::RunDirective;
 use serde::{Deserialize, Serialize};
 
-/// A cron definition that runs a job or agent on a timer interval.
+/// A cron definition that runs a job or agent on a timer interval or a cron
+/// schedule.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CronDef {
     /// Cron name (injected from map key)
     #[serde(skip)]
     pub name: String,
-    /// Interval duration string (e.g. "30m", "6h", "24h")
+    /// Interval duration string (e.g. "30m", "6h", "24h"), counted from when
+    /// the cron starts. Empty when `schedule` is used instead.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub interval: String,
+    /// Cron expression (e.g. "0 9 * * 1-5"), fired at matching wall-clock times
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schedule: Option<String>,
+    /// IANA timezone for `schedule` (e.g. "America/New_York"); defaults to
+    /// the daemon's local time
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
     /// What to run (job reference only)
     pub run: RunDirective,
     /// Maximum number of active jobs this cron can have running
     /// simultaneously. Defaults to 1 (singleton). `None` means use default.
     #[serde(default)]
     pub concurrency: Option<u32>,
+    /// What to do about runs missed while the daemon was down
+    #[serde(default)]
+    pub catch_up: CatchUp,
+}
+
+/// How a cron handles runs that fell due while the daemon was not running.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CatchUp {
+    /// Skip missed runs and wait for the next scheduled one
+    #[default]
+    None,
+    /// Run once on startup if any runs were missed
+    Once,
+    /// Run every missed run on startup, within the cron's concurrency
+    All,
 }
@@ -220,6 +220,27 @@ pub fn find_runbook_by_cron(runbook_dir: &Path, name: &str) -> Result<Option<Run
     find_runbook(runbook_dir, name, |rb| rb.get_cron(name).is_some())
 }
 
+/// Scan `.oj/runbooks/` recursively for the file defining watch `name`.
+pub fn find_runbook_by_watch(runbook_dir: &Path, name: &str) -> Result<Option<Runbook>, FindError> {
+    find_runbook(runbook_dir, name, |rb| rb.get_watch(name).is_some())
+}
+
+/// Scan `.oj/runbooks/` recursively for the file defining git trigger `name`.
+pub fn find_runbook_by_git_trigger(
+    runbook_dir: &Path,
+    name: &str,
+) -> Result<Option<Runbook>, FindError> {
+    find_runbook(runbook_dir, name, |rb| rb.get_git_trigger(name).is_some())
+}
+
+/// Scan `.oj/runbooks/` recursively for the file defining webhook `name`.
+pub fn find_runbook_by_webhook(
+    runbook_dir: &Path,
+    name: &str,
+) -> Result<Option<Runbook>, FindError> {
+    find_runbook(runbook_dir, name, |rb| rb.get_webhook(name).is_some())
+}
+
 /// Generic helper to collect items from all runbooks in a directory.
 ///
 /// Iterates over all runbook files, parses them, and extracts items using the
@@ -330,6 +351,72 @@ pub fn collect_all_crons(runbook_dir: &Path) -> Result<Vec<(String, crate::CronD
     })
 }
 
+/// Scan `.oj/runbooks/` and collect all watch definitions.
+/// Returns a sorted vec of (watch_name, WatchDef) pairs.
+/// Skips runbooks that fail to parse (logs warnings).
+pub fn collect_all_watches(
+    runbook_dir: &Path,
+) -> Result<Vec<(String, crate::WatchDef)>, FindError> {
+    collect_all(runbook_dir, |runbook, _| {
+        runbook
+            .watches
+            .iter()
+            .map(|(name, watch)| (name.clone(), watch.clone()))
+            .collect()
+    })
+}
+
+/// Scan `.oj/runbooks/` and collect all git trigger definitions.
+/// Returns a sorted vec of (trigger_name, GitTriggerDef) pairs.
+/// Skips runbooks that fail to parse (logs warnings).
+pub fn collect_all_git_triggers(
+    runbook_dir: &Path,
+) -> Result<Vec<(String, crate::GitTriggerDef)>, FindError> {
+    collect_all(runbook_dir, |runbook, _| {
+        runbook
+            .git_triggers
+            .iter()
+            .map(|(name, trigger)| (name.clone(), trigger.clone()))
+            .collect()
+    })
+}
+
+/// Scan `.oj/runbooks/` for files with `on` blocks subscribed to `event`.
+/// Returns each matching runbook whole, so the job a trigger starts can be
+/// resolved against it. Skips runbooks that fail to parse (logs warnings).
+pub fn collect_runbooks_with_trigger(
+    runbook_dir: &Path,
+    event: &str,
+) -> Result<Vec<Runbook>, FindError> {
+    let runbooks = collect_all(runbook_dir, |runbook, _| {
+        if runbook.triggers_for(event).is_empty() {
+            Vec::new()
+        } else {
+            vec![(String::new(), runbook.clone())]
+        }
+    })?;
+    Ok(runbooks.into_iter().map(|(_, runbook)| runbook).collect())
+}
+
+/// Read every runbook file under `.oj/runbooks/`, sorted by path, without
+/// parsing. Lets callers tell whether anything changed since a previous scan.
+/// Skips unreadable files (logs warnings).
+pub fn read_runbook_sources(runbook_dir: &Path) -> Result<Vec<(PathBuf, String)>, FindError> {
+    if !runbook_dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut sources = Vec::new();
+    for (path, _) in collect_runbook_files(runbook_dir)? {
+        match std::fs::read_to_string(&path) {
+            Ok(content) => sources.push((path, content)),
+            Err(e) => {
+                tracing::warn!(path = %path.display(), error = %e, "skipping unreadable runbook");
+            }
+        }
+    }
+    Ok(sources)
+}
+
 /// Summary of a single runbook file for `oj runbook list`.
 pub struct RunbookSummary {
     /// Relative path (e.g. "merge.hcl").
@@ -452,6 +539,15 @@ pub fn validate_runbook_dir(runbook_dir: &Path) -> Result<(), Vec<FindError>> {
                 runbook.workers.keys().cloned().collect::<Vec<_>>(),
             ),
             ("cron", runbook.crons.keys().cloned().collect::<Vec<_>>()),
+            ("watch", runbook.watches.keys().cloned().collect::<Vec<_>>()),
+            (
+                "git_trigger",
+                runbook.git_triggers.keys().cloned().collect::<Vec<_>>(),
+            ),
+            (
+                "webhook",
+                runbook.webhooks.keys().cloned().collect::<Vec<_>>(),
+            ),
         ] {
             let (entity_type, names) = entity_type_names;
             for name in names {
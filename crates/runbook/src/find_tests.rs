@@ -559,6 +559,25 @@ fn collect_all_crons_skips_invalid() {
     assert_eq!(crons[0].0, "daily-backup");
 }
 
+#[test]
+fn read_runbook_sources_reads_files_in_path_order() {
+    let tmp = TempDir::new().unwrap();
+    write_hcl(tmp.path(), "b.hcl", CRON_RUNBOOK);
+    write_hcl(tmp.path(), "a.hcl", "not valid HCL {{{}}}");
+    fs::write(tmp.path().join("notes.md"), "ignored").unwrap();
+
+    let sources = read_runbook_sources(tmp.path()).unwrap();
+    let names: Vec<_> = sources
+        .iter()
+        .map(|(path, _)| path.file_name().unwrap().to_str().unwrap())
+        .collect();
+    assert_eq!(names, ["a.hcl", "b.hcl"]);
+    assert_eq!(sources[1].1, CRON_RUNBOOK);
+    assert!(read_runbook_sources(Path::new("/nonexistent"))
+        .unwrap()
+        .is_empty());
+}
+
 // ============================================================================
 // extract_block_comments tests
 // ============================================================================
@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Git ref trigger definition for runbooks
+
+use crate::RunDirective;
+use serde::{Deserialize, Serialize};
+
+/// A git trigger that runs a job whenever a matching ref in the project's
+/// repository moves.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitTriggerDef {
+    /// Trigger name (injected from map key)
+    #[serde(skip)]
+    pub name: String,
+    /// Full ref name or glob (e.g. "refs/heads/main", "refs/heads/feature/*")
+    #[serde(rename = "ref")]
+    pub ref_pattern: String,
+    /// What to run (job reference only)
+    pub run: RunDirective,
+}
@@ -22,6 +22,7 @@ fn format_help_full_args() {
         .collect(),
         run: RunDirective::Job {
             job: "build".to_string(),
+            vars: HashMap::new(),
         },
     };
 
@@ -419,6 +419,48 @@ pub fn merge_runbook(
         import_source,
         &mut warnings,
     )?;
+    merge_map(
+        &mut target.watches,
+        source.watches,
+        "watch",
+        import_source,
+        &mut warnings,
+    )?;
+    merge_map(
+        &mut target.git_triggers,
+        source.git_triggers,
+        "git_trigger",
+        import_source,
+        &mut warnings,
+    )?;
+    merge_map(
+        &mut target.webhooks,
+        source.webhooks,
+        "webhook",
+        import_source,
+        &mut warnings,
+    )?;
+    // Triggers are keyed by event, not by name: imported ones add to the
+    // local ones instead of being overridden by them
+    for (event, triggers) in source.triggers {
+        target.triggers.entry(event).or_default().extend(triggers);
+    }
+    // Locks and semaphores are shared by name across the namespace, so
+    // imported ones keep their names (no alias prefix).
+    merge_map(
+        &mut target.locks,
+        source.locks,
+        "lock",
+        import_source,
+        &mut warnings,
+    )?;
+    merge_map(
+        &mut target.semaphores,
+        source.semaphores,
+        "semaphore",
+        import_source,
+        &mut warnings,
+    )?;
 
     Ok(warnings)
 }
@@ -483,6 +525,21 @@ fn prefix_names(runbook: &mut Runbook, prefix: &str) {
         .keys()
         .map(|k| (k.clone(), format!("{}:{}", prefix, k)))
         .collect();
+    let watch_renames: HashMap<String, String> = runbook
+        .watches
+        .keys()
+        .map(|k| (k.clone(), format!("{}:{}", prefix, k)))
+        .collect();
+    let git_trigger_renames: HashMap<String, String> = runbook
+        .git_triggers
+        .keys()
+        .map(|k| (k.clone(), format!("{}:{}", prefix, k)))
+        .collect();
+    let webhook_renames: HashMap<String, String> = runbook
+        .webhooks
+        .keys()
+        .map(|k| (k.clone(), format!("{}:{}", prefix, k)))
+        .collect();
 
     // Rename entity map keys
     runbook.commands = rename_keys(std::mem::take(&mut runbook.commands), &cmd_renames);
@@ -491,6 +548,12 @@ fn prefix_names(runbook: &mut Runbook, prefix: &str) {
     runbook.queues = rename_keys(std::mem::take(&mut runbook.queues), &queue_renames);
     runbook.workers = rename_keys(std::mem::take(&mut runbook.workers), &worker_renames);
     runbook.crons = rename_keys(std::mem::take(&mut runbook.crons), &cron_renames);
+    runbook.watches = rename_keys(std::mem::take(&mut runbook.watches), &watch_renames);
+    runbook.git_triggers = rename_keys(
+        std::mem::take(&mut runbook.git_triggers),
+        &git_trigger_renames,
+    );
+    runbook.webhooks = rename_keys(std::mem::take(&mut runbook.webhooks), &webhook_renames);
 
     // Update .name fields
     for (key, cmd) in &mut runbook.commands {
@@ -511,21 +574,66 @@ fn prefix_names(runbook: &mut Runbook, prefix: &str) {
     for (key, cron) in &mut runbook.crons {
         cron.name = key.clone();
     }
+    for (key, watch) in &mut runbook.watches {
+        watch.name = key.clone();
+    }
+    for (key, trigger) in &mut runbook.git_triggers {
+        trigger.name = key.clone();
+    }
+    for (key, webhook) in &mut runbook.webhooks {
+        webhook.name = key.clone();
+    }
 
     // Update internal cross-references
     for worker in runbook.workers.values_mut() {
         if let Some(new) = queue_renames.get(&worker.source.queue) {
             worker.source.queue = new.clone();
         }
+        worker.source.queues = std::mem::take(&mut worker.source.queues)
+            .into_iter()
+            .map(|(queue, weight)| match queue_renames.get(&queue) {
+                Some(new) => (new.clone(), weight),
+                None => (queue, weight),
+            })
+            .collect();
         if let Some(new) = job_renames.get(&worker.handler.job) {
             worker.handler.job = new.clone();
         }
+        if let Some(new) = worker
+            .handler
+            .agent
+            .as_ref()
+            .and_then(|a| agent_renames.get(a))
+        {
+            worker.handler.agent = Some(new.clone());
+        }
     }
 
     for cron in runbook.crons.values_mut() {
         rename_run_directive(&mut cron.run, &job_renames, &agent_renames);
     }
 
+    for watch in runbook.watches.values_mut() {
+        rename_run_directive(&mut watch.run, &job_renames, &agent_renames);
+    }
+
+    for trigger in runbook.git_triggers.values_mut() {
+        rename_run_directive(&mut trigger.run, &job_renames, &agent_renames);
+    }
+
+    for webhook in runbook.webhooks.values_mut() {
+        if let Some(new) = webhook.queue.as_ref().and_then(|q| queue_renames.get(q)) {
+            webhook.queue = Some(new.clone());
+        }
+        if let Some(new) = webhook.command.as_ref().and_then(|c| cmd_renames.get(c)) {
+            webhook.command = Some(new.clone());
+        }
+    }
+
+    for trigger in runbook.triggers.values_mut().flatten() {
+        rename_run_directive(&mut trigger.run, &job_renames, &agent_renames);
+    }
+
     for cmd in runbook.commands.values_mut() {
         rename_run_directive(&mut cmd.run, &job_renames, &agent_renames);
     }
@@ -555,7 +663,7 @@ fn rename_run_directive(
     agent_renames: &HashMap<String, String>,
 ) {
     match directive {
-        crate::RunDirective::Job { job } => {
+        crate::RunDirective::Job { job, .. } => {
             if let Some(new) = job_renames.get(job.as_str()) {
                 *job = new.clone();
             }
@@ -565,7 +673,9 @@ fn rename_run_directive(
                 *agent = new.clone();
             }
         }
-        crate::RunDirective::Shell(_) => {}
+        crate::RunDirective::Shell(_)
+        | crate::RunDirective::Parallel { .. }
+        | crate::RunDirective::Approval { .. } => {}
     }
 }
 
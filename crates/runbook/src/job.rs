@@ -3,12 +3,13 @@
 
 //! Job definitions
 //!
-//! # Design Note: No Step Timeouts
+//! # Design Note: No Agent Step Timeouts
 //!
 //! This module deliberately does not support step-level timeouts for agent steps.
-//! This is a conscious design decision, not an oversight.
+//! This is a conscious design decision, not an oversight. Shell steps do accept
+//! `timeout` (see below).
 //!
-//! ## Why No Timeout Feature?
+//! ## Why No Timeout for Agents?
 //!
 //! **This is a dynamic, monitored system.** Agents and jobs are actively watched by
 //! both automated handlers (`on_idle`, `on_dead`, `on_error`) and human operators. When
@@ -32,11 +33,15 @@
 //! ## When Timeouts ARE Appropriate
 //!
 //! Timeouts make sense for bounded operations like shell commands with known
-//! execution bounds — not for agent steps.
+//! execution bounds — not for agent steps. A shell step's `timeout = "15m"`
+//! replaces the executor's default safety net; on expiry the command's whole
+//! process group is killed and the step fails with a "timed out" error. The
+//! step's `on_timeout` routes that failure, falling back to `on_fail`.
 //!
 //! See [`docs/01-concepts/EXECUTION.md`] for the full rationale.
 
 use crate::command::RunDirective;
+use crate::{BudgetDef, RetryConfig};
 use indexmap::IndexMap;
 use serde::de::{self, MapAccess, SeqAccess, Visitor};
 use serde::{Deserialize, Deserializer, Serialize};
@@ -48,9 +53,15 @@ use std::fmt;
 /// Accepts either:
 ///   `{ step = "name" }`  — structured form (preferred)
 ///   `"name"`             — bare string (backward compat)
+///
+/// Branches of a step's `on_done` may add a condition:
+///   `{ if = "${steps.check.outputs.changed} == true", step = "name" }`
 #[derive(Debug, Clone, Serialize)]
 pub struct StepTransition {
     pub step: String,
+    /// Condition guarding this branch (see [`crate::evaluate_condition`])
+    #[serde(rename = "if", default, skip_serializing_if = "Option::is_none")]
+    pub condition: Option<String>,
 }
 
 impl StepTransition {
@@ -62,7 +73,11 @@ impl StepTransition {
 #[derive(Deserialize)]
 #[serde(untagged)]
 enum StepTransitionRaw {
-    Structured { step: String },
+    Structured {
+        step: String,
+        #[serde(rename = "if", default)]
+        condition: Option<String>,
+    },
     Bare(String),
 }
 
@@ -70,12 +85,44 @@ impl<'de> Deserialize<'de> for StepTransition {
     fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
         let raw = StepTransitionRaw::deserialize(d)?;
         Ok(match raw {
-            StepTransitionRaw::Structured { step } => StepTransition { step },
-            StepTransitionRaw::Bare(s) => StepTransition { step: s },
+            StepTransitionRaw::Structured { step, condition } => StepTransition { step, condition },
+            StepTransitionRaw::Bare(s) => StepTransition {
+                step: s,
+                condition: None,
+            },
         })
     }
 }
 
+/// A step's `on_done`: a single target, or branches tried in order.
+///
+/// The first branch whose `if` holds (or that has no `if`) is taken. When
+/// no branch matches, the step behaves as if it had no `on_done`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum OnDone {
+    Step(StepTransition),
+    Branches(Vec<StepTransition>),
+}
+
+impl OnDone {
+    /// All branches, in evaluation order
+    pub fn branches(&self) -> &[StepTransition] {
+        match self {
+            OnDone::Step(t) => std::slice::from_ref(t),
+            OnDone::Branches(branches) => branches,
+        }
+    }
+
+    /// The target step when routing is unconditional
+    pub fn step_name(&self) -> Option<&str> {
+        match self.branches() {
+            [t] if t.condition.is_none() => Some(t.step_name()),
+            _ => None,
+        }
+    }
+}
+
 /// Notification configuration for lifecycle events
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct NotifyConfig {
@@ -132,6 +179,18 @@ pub enum GitWorkspaceMode {
     Worktree,
 }
 
+/// What happens to a job when a dependency can no longer be met
+/// (a `:done` dependency failed or was cancelled).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DependencyFailure {
+    /// Fail the job without running any step or `on_fail` handler
+    #[default]
+    Fail,
+    /// Cancel the job, running its `on_cancel` cleanup if configured
+    Cancel,
+}
+
 impl WorkspaceConfig {
     pub fn is_git_worktree(&self) -> bool {
         matches!(
@@ -152,15 +211,39 @@ pub struct StepDef {
     pub name: String,
     /// What to run: shell command or agent
     pub run: RunDirective,
-    /// Next step on success
+    /// Next step on success, optionally chosen by condition
     #[serde(default)]
-    pub on_done: Option<StepTransition>,
+    pub on_done: Option<OnDone>,
     /// Step to go to on failure
     #[serde(default)]
     pub on_fail: Option<StepTransition>,
     /// Step to route to when the job is cancelled during this step
     #[serde(default)]
     pub on_cancel: Option<StepTransition>,
+    /// Shell steps: maximum run time (e.g. "15m"); on expiry the command's
+    /// process group is killed and the step fails as timed out
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<String>,
+    /// Shell steps: step to go to when the command times out, instead of
+    /// `on_fail`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_timeout: Option<StepTransition>,
+    /// Shell steps: re-run the step after a delay when it fails, before
+    /// falling through to `on_fail`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry: Option<RetryConfig>,
+    /// Shell steps: steps to route to by exit code (e.g. `{ 2 = "needs-review" }`).
+    /// A matched exit code completes the step instead of failing it.
+    #[serde(
+        default,
+        skip_serializing_if = "HashMap::is_empty",
+        deserialize_with = "deserialize_exit_codes"
+    )]
+    pub on_exit: HashMap<String, StepTransition>,
+    /// Locks/semaphores held for the duration of the step
+    /// (e.g. `["lock.main-branch", "semaphore.gpu"]`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub acquire: Vec<String>,
 }
 
 impl StepDef {
@@ -224,11 +307,63 @@ pub struct JobDef {
     /// Notification messages for job lifecycle events
     #[serde(default)]
     pub notify: NotifyConfig,
+    /// Jobs that must finish before the first step starts, as
+    /// `"<job-id>[:done|:any]"` (supports template interpolation; an entry
+    /// may expand to several comma-separated IDs, or to nothing)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub depends_on: Vec<String>,
+    /// Whether a dependency that can't be met fails or cancels the job
+    #[serde(default)]
+    pub on_dependency_fail: DependencyFailure,
+    /// Spend limit across every agent the job runs; crossing it fires the
+    /// running agent's `on_budget`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub budget: Option<BudgetDef>,
     /// Ordered steps
     #[serde(default, alias = "step", deserialize_with = "deserialize_steps")]
     pub steps: Vec<StepDef>,
 }
 
+/// Deserialize `on_exit` keys from numbers (HCL) or strings (TOML, JSON).
+///
+/// Numeric keys are normalized to their canonical form (`"02"` and `"+2"`
+/// become `"2"`), so they match the exit code at runtime; two keys naming the
+/// same code are an error. Other keys are kept as written for the parser to
+/// reject with their location.
+fn deserialize_exit_codes<'de, D>(
+    deserializer: D,
+) -> Result<HashMap<String, StepTransition>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize, PartialEq, Eq, Hash)]
+    #[serde(untagged)]
+    enum ExitCodeKey {
+        Number(i64),
+        Text(String),
+    }
+
+    let raw = HashMap::<ExitCodeKey, StepTransition>::deserialize(deserializer)?;
+    let mut codes = HashMap::with_capacity(raw.len());
+    for (key, transition) in raw {
+        let key = match key {
+            ExitCodeKey::Number(n) => n.to_string(),
+            ExitCodeKey::Text(s) => match s.trim().parse::<i64>() {
+                Ok(n) => n.to_string(),
+                Err(_) => s,
+            },
+        };
+        if codes.contains_key(&key) {
+            return Err(de::Error::custom(format!(
+                "on_exit lists exit code {} more than once",
+                key
+            )));
+        }
+        codes.insert(key, transition);
+    }
+    Ok(codes)
+}
+
 /// Deserialize steps from either a sequence (TOML) or a map (HCL labeled blocks).
 ///
 /// - TOML `[[job.X.step]]` produces a `Vec<StepDef>`
@@ -24,6 +24,11 @@ fn sample_job() -> JobDef {
                 on_done: None,
                 on_fail: None,
                 on_cancel: None,
+                on_exit: HashMap::new(),
+                acquire: vec![],
+                timeout: None,
+                on_timeout: None,
+                retry: None,
             },
             StepDef {
                 name: "plan".to_string(),
@@ -34,6 +39,11 @@ fn sample_job() -> JobDef {
                 on_done: None,
                 on_fail: None,
                 on_cancel: None,
+                on_exit: HashMap::new(),
+                acquire: vec![],
+                timeout: None,
+                on_timeout: None,
+                retry: None,
             },
             StepDef {
                 name: "execute".to_string(),
@@ -41,13 +51,20 @@ fn sample_job() -> JobDef {
                     agent: "executor".to_string(),
                     attach: None,
                 },
-                on_done: Some(StepTransition {
+                on_done: Some(OnDone::Step(StepTransition {
                     step: "done".to_string(),
-                }),
+                    condition: None,
+                })),
                 on_fail: Some(StepTransition {
                     step: "failed".to_string(),
+                    condition: None,
                 }),
                 on_cancel: None,
+                on_exit: HashMap::new(),
+                acquire: vec![],
+                timeout: None,
+                on_timeout: None,
+                retry: None,
             },
             StepDef {
                 name: "done".to_string(),
@@ -55,6 +72,11 @@ fn sample_job() -> JobDef {
                 on_done: None,
                 on_fail: None,
                 on_cancel: None,
+                on_exit: HashMap::new(),
+                acquire: vec![],
+                timeout: None,
+                on_timeout: None,
+                retry: None,
             },
             StepDef {
                 name: "failed".to_string(),
@@ -62,8 +84,16 @@ fn sample_job() -> JobDef {
                 on_done: None,
                 on_fail: None,
                 on_cancel: None,
+                on_exit: HashMap::new(),
+                acquire: vec![],
+                timeout: None,
+                on_timeout: None,
+                retry: None,
             },
         ],
+        depends_on: vec![],
+        on_dependency_fail: Default::default(),
+        budget: None,
     }
 }
 
@@ -168,7 +198,10 @@ run = "echo next"
     let runbook = parse_runbook(toml).unwrap();
     let job = runbook.get_job("deploy").unwrap();
     let init = job.get_step("init").unwrap();
-    assert_eq!(init.on_done.as_ref().map(|t| t.step_name()), Some("next"));
+    assert_eq!(
+        init.on_done.as_ref().and_then(|t| t.step_name()),
+        Some("next")
+    );
 }
 
 #[test]
@@ -190,7 +223,10 @@ job "deploy" {
     let runbook = parse_runbook_with_format(hcl, Format::Hcl).unwrap();
     let job = runbook.get_job("deploy").unwrap();
     let init = job.get_step("init").unwrap();
-    assert_eq!(init.on_done.as_ref().map(|t| t.step_name()), Some("next"));
+    assert_eq!(
+        init.on_done.as_ref().and_then(|t| t.step_name()),
+        Some("next")
+    );
 }
 
 #[test]
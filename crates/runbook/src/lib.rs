@@ -9,17 +9,25 @@
 //! Runbook parsing and definition
 
 mod agent;
+mod budget;
 mod command;
+mod condition;
 mod cron;
 mod find;
+mod git_trigger;
 mod help;
 mod import;
 mod job;
+mod lock;
 mod parser;
 mod queue;
+mod retry;
 mod slug;
 mod template;
+mod trigger;
 mod validate;
+mod watch;
+mod webhook;
 mod worker;
 
 pub use agent::{
@@ -27,28 +35,38 @@ pub use agent::{
     ErrorType, PrimeDef, SessionStatusConfig, StopAction, StopActionConfig, TmuxSessionConfig,
     VALID_PRIME_SOURCES, VALID_SESSION_COLORS,
 };
+pub use budget::BudgetDef;
 pub use command::{
-    parse_arg_spec, ArgDef, ArgSpec, ArgSpecError, ArgValidationError, CommandDef, FlagDef,
-    OptionDef, RunDirective, VariadicDef,
+    parse_arg_spec, ApprovalDef, ArgDef, ArgSpec, ArgSpecError, ArgValidationError, CommandDef,
+    FlagDef, OptionDef, RunDirective, VariadicDef,
 };
-pub use cron::CronDef;
+pub use condition::evaluate_condition;
+pub use cron::{CatchUp, CronDef};
 pub use find::{
-    collect_all_commands, collect_all_crons, collect_all_queues, collect_all_workers,
-    collect_runbook_summaries, extract_block_comments, extract_file_comment,
+    collect_all_commands, collect_all_crons, collect_all_git_triggers, collect_all_queues,
+    collect_all_watches, collect_all_workers, collect_runbook_summaries,
+    collect_runbooks_with_trigger, extract_block_comments, extract_file_comment,
     find_command_with_comment, find_runbook_by_command, find_runbook_by_cron,
-    find_runbook_by_queue, find_runbook_by_worker, runbook_parse_warnings, validate_runbook_dir,
-    FileComment, FindError, RunbookSummary,
+    find_runbook_by_git_trigger, find_runbook_by_queue, find_runbook_by_watch,
+    find_runbook_by_webhook, find_runbook_by_worker, read_runbook_sources, runbook_parse_warnings,
+    validate_runbook_dir, FileComment, FindError, RunbookSummary,
 };
+pub use git_trigger::GitTriggerDef;
 pub use import::{
     available_libraries, parse_with_imports, resolve_library, ConstDef, ImportConst, ImportDef,
     ImportWarning, LibraryInfo,
 };
 pub use job::{
-    GitWorkspaceMode, JobDef, NotifyConfig, StepDef, StepTransition, WorkspaceBlock,
-    WorkspaceConfig, WorkspaceType,
+    DependencyFailure, GitWorkspaceMode, JobDef, NotifyConfig, OnDone, StepDef, StepTransition,
+    WorkspaceBlock, WorkspaceConfig, WorkspaceType,
 };
+pub use lock::{LockDef, ResourceRef, SemaphoreDef};
 pub use parser::{parse_runbook, parse_runbook_with_format, Format, ParseError, Runbook};
-pub use queue::{QueueDef, QueueType};
+pub use queue::{DedupPolicy, ExpirePolicy, QueueDef, QueueType};
+pub use retry::{Backoff, RetryConfig};
 pub use slug::{job_display_name, slugify};
 pub use template::{escape_for_shell, interpolate, interpolate_shell};
+pub use trigger::{trigger_event_fields, TriggerDef, TRIGGER_EVENTS};
+pub use watch::{WatchDef, DEFAULT_WATCH_DEBOUNCE};
+pub use webhook::WebhookDef;
 pub use worker::{WorkerDef, WorkerHandler, WorkerSource};
@@ -0,0 +1,50 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Lock and semaphore definitions for runbooks
+//!
+//! Locks and semaphores are named resources shared by every job in a project
+//! namespace. Steps list the resources they need in `acquire`, using the
+//! `lock.<name>` / `semaphore.<name>` reference form.
+
+use serde::{Deserialize, Serialize};
+
+/// A mutual-exclusion lock (a semaphore with a single permit).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LockDef {
+    /// Lock name (injected from map key)
+    #[serde(skip)]
+    pub name: String,
+}
+
+/// A counting semaphore that admits up to `permits` concurrent holders.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SemaphoreDef {
+    /// Semaphore name (injected from map key)
+    #[serde(skip)]
+    pub name: String,
+    /// Maximum number of steps that may hold the semaphore at once
+    pub permits: u32,
+}
+
+/// A parsed `acquire` reference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResourceRef<'a> {
+    Lock(&'a str),
+    Semaphore(&'a str),
+}
+
+impl<'a> ResourceRef<'a> {
+    /// Parse a `lock.<name>` or `semaphore.<name>` reference.
+    pub fn parse(reference: &'a str) -> Option<Self> {
+        if let Some(name) = reference.strip_prefix("lock.") {
+            (!name.is_empty()).then_some(ResourceRef::Lock(name))
+        } else if let Some(name) = reference.strip_prefix("semaphore.") {
+            (!name.is_empty()).then_some(ResourceRef::Semaphore(name))
+        } else {
+            None
+        }
+    }
+}
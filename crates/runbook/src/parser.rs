@@ -4,13 +4,18 @@
 //! Runbook parsing (TOML, HCL, and JSON)
 
 use crate::import::{ConstDef, ImportDef};
+use crate::trigger::{trigger_event_fields, TRIGGER_EVENTS};
 use crate::validate::{
-    sorted_keys, sorted_names, validate_agent_command, validate_command_template_refs,
-    validate_duration_str, validate_shell_command, validate_template_namespaces,
+    sorted_keys, sorted_names, unpublished_steps, validate_agent_command, validate_approval,
+    validate_budget, validate_command_template_refs, validate_cron_catch_up, validate_cron_timing,
+    validate_duration_str, validate_git_trigger, validate_retry, validate_shell_command,
+    validate_step_output_refs, validate_template_namespaces, validate_timeout_str,
+    validate_transition, validate_watch, validate_webhook,
 };
 use crate::{
-    ActionTrigger, AgentDef, ArgSpecError, CommandDef, CronDef, JobDef, PrimeDef, QueueDef,
-    QueueType, RunDirective, WorkerDef,
+    ActionTrigger, AgentDef, ArgSpecError, CommandDef, CronDef, DedupPolicy, ExpirePolicy,
+    GitTriggerDef, JobDef, LockDef, OnDone, PrimeDef, QueueDef, QueueType, ResourceRef,
+    RunDirective, SemaphoreDef, TriggerDef, WatchDef, WebhookDef, WorkerDef,
 };
 use oj_shell as shell;
 use serde::{Deserialize, Serialize};
@@ -84,6 +89,28 @@ pub struct Runbook {
     pub workers: HashMap<String, WorkerDef>,
     #[serde(default, alias = "cron")]
     pub crons: HashMap<String, CronDef>,
+    #[serde(default, alias = "watch", skip_serializing_if = "HashMap::is_empty")]
+    pub watches: HashMap<String, WatchDef>,
+    #[serde(
+        default,
+        alias = "git_trigger",
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    pub git_triggers: HashMap<String, GitTriggerDef>,
+    #[serde(default, alias = "webhook", skip_serializing_if = "HashMap::is_empty")]
+    pub webhooks: HashMap<String, WebhookDef>,
+    #[serde(default, alias = "lock")]
+    pub locks: HashMap<String, LockDef>,
+    #[serde(default, alias = "semaphore")]
+    pub semaphores: HashMap<String, SemaphoreDef>,
+    /// `on "<event>"` blocks, keyed by event name
+    #[serde(
+        default,
+        alias = "on",
+        skip_serializing_if = "HashMap::is_empty",
+        deserialize_with = "crate::trigger::deserialize_triggers"
+    )]
+    pub triggers: HashMap<String, Vec<TriggerDef>>,
 }
 
 impl Runbook {
@@ -116,6 +143,38 @@ impl Runbook {
     pub fn get_cron(&self, name: &str) -> Option<&CronDef> {
         self.crons.get(name)
     }
+
+    /// Get a watch definition by name
+    pub fn get_watch(&self, name: &str) -> Option<&WatchDef> {
+        self.watches.get(name)
+    }
+
+    /// Get a git trigger definition by name
+    pub fn get_git_trigger(&self, name: &str) -> Option<&GitTriggerDef> {
+        self.git_triggers.get(name)
+    }
+
+    /// Get a webhook definition by name
+    pub fn get_webhook(&self, name: &str) -> Option<&WebhookDef> {
+        self.webhooks.get(name)
+    }
+
+    /// Triggers subscribed to an event
+    pub fn triggers_for(&self, event: &str) -> &[TriggerDef] {
+        self.triggers
+            .get(event)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Number of permits for an `acquire` reference (`lock.x` → 1,
+    /// `semaphore.y` → its `permits`). Returns `None` for unknown resources.
+    pub fn resource_permits(&self, reference: &str) -> Option<u32> {
+        match ResourceRef::parse(reference)? {
+            ResourceRef::Lock(name) => self.locks.get(name).map(|_| 1),
+            ResourceRef::Semaphore(name) => self.semaphores.get(name).map(|s| s.permits),
+        }
+    }
 }
 
 /// Format a shell parse error as a diagnostic with source snippet.
@@ -181,6 +240,26 @@ fn parse_runbook_inner(
     for (name, cron) in &mut runbook.crons {
         cron.name = name.clone();
     }
+    for (name, watch) in &mut runbook.watches {
+        watch.name = name.clone();
+    }
+    for (name, trigger) in &mut runbook.git_triggers {
+        trigger.name = name.clone();
+    }
+    for (name, webhook) in &mut runbook.webhooks {
+        webhook.name = name.clone();
+    }
+    for (name, lock) in &mut runbook.locks {
+        lock.name = name.clone();
+    }
+    for (name, semaphore) in &mut runbook.semaphores {
+        semaphore.name = name.clone();
+    }
+    for (event, triggers) in &mut runbook.triggers {
+        for trigger in triggers {
+            trigger.event = event.clone();
+        }
+    }
 
     // 3. Validation — step names must not be empty
     for (job_name, job) in &runbook.jobs {
@@ -196,25 +275,110 @@ fn parse_runbook_inner(
 
     // 4. Validation — shell command syntax and agent command checks
     for (name, cmd) in &runbook.commands {
+        let location = format!("command.{}.run", name);
         if let RunDirective::Shell(ref shell_cmd) = cmd.run {
-            let location = format!("command.{}.run", name);
             validate_shell_command(shell_cmd, &location)?;
             validate_command_template_refs(shell_cmd, &location)?;
         }
+        if cmd.run.is_parallel() {
+            return Err(ParseError::InvalidFormat {
+                location,
+                message: "parallel is only valid in job steps".to_string(),
+            });
+        }
+        if cmd.run.is_approval() {
+            return Err(ParseError::InvalidFormat {
+                location,
+                message: "approval is only valid in job steps".to_string(),
+            });
+        }
+        if matches!(&cmd.run, RunDirective::Job { vars, .. } if !vars.is_empty()) {
+            return Err(ParseError::InvalidFormat {
+                location,
+                message: "job vars are only valid in job steps; use args instead".to_string(),
+            });
+        }
     }
 
     for (job_name, job) in &runbook.jobs {
+        let step_names: HashSet<&str> = job.steps.iter().map(|s| s.name.as_str()).collect();
+        let unpublished = unpublished_steps(job);
         for (i, step) in job.steps.iter().enumerate() {
             let step_location = format!("job.{}.step[{}]({}).run", job_name, i, step.name);
-            if let RunDirective::Shell(ref shell_cmd) = step.run {
-                validate_shell_command(shell_cmd, &step_location)?;
-                validate_template_namespaces(shell_cmd, &step_location)?;
+            match &step.run {
+                RunDirective::Shell(shell_cmd) => {
+                    validate_shell_command(shell_cmd, &step_location)?;
+                    validate_template_namespaces(shell_cmd, &step_location)?;
+                    validate_step_output_refs(
+                        shell_cmd,
+                        &step_names,
+                        &unpublished,
+                        &step_location,
+                    )?;
+                }
+                RunDirective::Job { vars, .. } => {
+                    for value in vars.values() {
+                        validate_step_output_refs(
+                            value,
+                            &step_names,
+                            &unpublished,
+                            &step_location,
+                        )?;
+                    }
+                }
+                RunDirective::Approval { approval } => {
+                    validate_approval(approval, &step_names, &unpublished, &step_location)?;
+                }
+                _ => {}
+            }
+            if let Some(ref timeout) = step.timeout {
+                let location = format!("job.{}.step[{}]({}).timeout", job_name, i, step.name);
+                if !step.is_shell() {
+                    // Agents are supervised by on_idle/on_dead/on_error instead
+                    return Err(ParseError::InvalidFormat {
+                        location,
+                        message: "timeout is only valid on shell steps".to_string(),
+                    });
+                }
+                if let Err(e) = validate_timeout_str(timeout) {
+                    return Err(ParseError::InvalidFormat {
+                        location,
+                        message: e,
+                    });
+                }
+            }
+            if step.on_timeout.is_some() && !step.is_shell() {
+                return Err(ParseError::InvalidFormat {
+                    location: format!("job.{}.step[{}]({}).on_timeout", job_name, i, step.name),
+                    message: "on_timeout is only valid on shell steps".to_string(),
+                });
+            }
+            if let Some(ref retry) = step.retry {
+                let location = format!("job.{}.step[{}]({}).retry", job_name, i, step.name);
+                if !step.is_shell() {
+                    return Err(ParseError::InvalidFormat {
+                        location,
+                        message: "retry is only valid on shell steps".to_string(),
+                    });
+                }
+                validate_retry(retry, &location)?;
             }
         }
+        if let Some(ref budget) = job.budget {
+            validate_budget(budget, &format!("job.{}.budget", job_name))?;
+        }
         // Validate job local variable templates
         for (local_name, local_value) in &job.locals {
             let local_location = format!("job.{}.locals.{}", job_name, local_name);
             validate_template_namespaces(local_value, &local_location)?;
+            if local_value.contains("${steps.") {
+                return Err(ParseError::InvalidFormat {
+                    location: local_location,
+                    message: "step outputs are not available in locals \
+                              (locals are evaluated when the job is created)"
+                        .to_string(),
+                });
+            }
         }
     }
 
@@ -291,6 +455,33 @@ fn parse_runbook_inner(
                         message: "external queue must not have 'retry' field".to_string(),
                     });
                 }
+                if queue.priority.is_some() {
+                    return Err(ParseError::InvalidFormat {
+                        location: format!("queue.{}", name),
+                        message: "external queue must not have 'priority' field".to_string(),
+                    });
+                }
+                if queue.dedup_key.is_some()
+                    || queue.dedup_window.is_some()
+                    || queue.dedup_policy != DedupPolicy::Reject
+                {
+                    return Err(ParseError::InvalidFormat {
+                        location: format!("queue.{}", name),
+                        message: "external queue must not have dedup fields".to_string(),
+                    });
+                }
+                if queue.ttl.is_some() || queue.on_expire != ExpirePolicy::Expire {
+                    return Err(ParseError::InvalidFormat {
+                        location: format!("queue.{}", name),
+                        message: "external queue must not have 'ttl' field".to_string(),
+                    });
+                }
+                if queue.lease.is_some() {
+                    return Err(ParseError::InvalidFormat {
+                        location: format!("queue.{}", name),
+                        message: "external queue must not have 'lease' field".to_string(),
+                    });
+                }
                 if let Some(ref poll) = queue.poll {
                     if let Err(e) = validate_duration_str(poll) {
                         return Err(ParseError::InvalidFormat {
@@ -299,6 +490,14 @@ fn parse_runbook_inner(
                         });
                     }
                 }
+                if let Some(ref timeout) = queue.timeout {
+                    if let Err(e) = validate_timeout_str(timeout) {
+                        return Err(ParseError::InvalidFormat {
+                            location: format!("queue.{}.timeout", name),
+                            message: e,
+                        });
+                    }
+                }
             }
             QueueType::Persisted => {
                 if queue.vars.is_empty() {
@@ -325,30 +524,118 @@ fn parse_runbook_inner(
                         message: "persisted queue must not have 'poll' field".to_string(),
                     });
                 }
-                if let Some(ref retry) = queue.retry {
-                    if let Err(e) = validate_duration_str(&retry.cooldown) {
+                if queue.timeout.is_some() {
+                    return Err(ParseError::InvalidFormat {
+                        location: format!("queue.{}", name),
+                        message: "persisted queue must not have 'timeout' field".to_string(),
+                    });
+                }
+                if let Some(ref priority) = queue.priority {
+                    if !priority.contains("${") && priority.trim().parse::<i32>().is_err() {
+                        return Err(ParseError::InvalidFormat {
+                            location: format!("queue.{}.priority", name),
+                            message: format!(
+                                "priority must be an integer or a template, got '{}'",
+                                priority
+                            ),
+                        });
+                    }
+                }
+                if let Some(ref ttl) = queue.ttl {
+                    if let Err(e) = validate_timeout_str(ttl) {
                         return Err(ParseError::InvalidFormat {
-                            location: format!("queue.{}.retry.cooldown", name),
+                            location: format!("queue.{}.ttl", name),
+                            message: e.replace("timeout", "ttl"),
+                        });
+                    }
+                }
+                if let Some(ref lease) = queue.lease {
+                    if let Err(e) = validate_timeout_str(lease) {
+                        return Err(ParseError::InvalidFormat {
+                            location: format!("queue.{}.lease", name),
+                            message: e.replace("timeout", "lease"),
+                        });
+                    }
+                }
+                if let Some(ref window) = queue.dedup_window {
+                    if let Err(e) = validate_duration_str(window) {
+                        return Err(ParseError::InvalidFormat {
+                            location: format!("queue.{}.dedup_window", name),
                             message: e,
                         });
                     }
                 }
+                if let Some(ref retry) = queue.retry {
+                    validate_retry(retry, &format!("queue.{}.retry", name))?;
+                }
             }
         }
     }
 
-    // 6.5. Validate cron interval syntax
-    for (name, cron) in &runbook.crons {
-        if let Err(e) = validate_duration_str(&cron.interval) {
+    // 6. Validate trigger events and filters
+    for (event, triggers) in &runbook.triggers {
+        let Some(fields) = trigger_event_fields(event) else {
             return Err(ParseError::InvalidFormat {
-                location: format!("cron.{}.interval", name),
-                message: e,
+                location: format!("on.\"{}\"", event),
+                message: format!(
+                    "unknown event '{}'; triggers can subscribe to: {}",
+                    event,
+                    TRIGGER_EVENTS
+                        .iter()
+                        .map(|(name, _)| *name)
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                ),
             });
+        };
+        for (i, trigger) in triggers.iter().enumerate() {
+            let mut keys: Vec<&String> = trigger.filter.keys().collect();
+            keys.sort();
+            if let Some(key) = keys.into_iter().find(|k| !fields.contains(&k.as_str())) {
+                return Err(ParseError::InvalidFormat {
+                    location: format!("on.\"{}\"[{}].filter.{}", event, i, key),
+                    message: format!(
+                        "'{}' events have no field '{}'; available fields: {}",
+                        event,
+                        key,
+                        fields.join(", "),
+                    ),
+                });
+            }
         }
     }
 
-    // 6.6. Validate agent max_concurrency
+    // 6.5. Validate cron interval or schedule, watch paths, git trigger refs,
+    // webhook targets, and worker dispatch rates
+    for (name, cron) in &runbook.crons {
+        validate_cron_timing(name, cron)?;
+        validate_cron_catch_up(name, cron)?;
+    }
+    for (name, watch) in &runbook.watches {
+        validate_watch(name, watch)?;
+    }
+    for (name, trigger) in &runbook.git_triggers {
+        validate_git_trigger(name, trigger)?;
+    }
+    for (name, webhook) in &runbook.webhooks {
+        validate_webhook(name, webhook)?;
+    }
+    for (name, worker) in &runbook.workers {
+        if let Some(ref rate) = worker.rate {
+            if let Err(e) = oj_core::DispatchRate::parse(rate) {
+                return Err(ParseError::InvalidFormat {
+                    location: format!("worker.{}.rate", name),
+                    message: e,
+                });
+            }
+        }
+    }
+
+    // 6.6. Validate agent max_concurrency and budget
     for (name, agent) in &runbook.agents {
+        if let Some(ref budget) = agent.budget {
+            validate_budget(budget, &format!("agent.{}.budget", name))?;
+        }
         if let Some(max) = agent.max_concurrency {
             if max == 0 {
                 return Err(ParseError::InvalidFormat {
@@ -377,6 +664,56 @@ fn parse_runbook_inner(
         }
     }
 
+    // 6.8. Validate semaphore permits and step acquire references
+    for (name, semaphore) in &runbook.semaphores {
+        if semaphore.permits == 0 {
+            return Err(ParseError::InvalidFormat {
+                location: format!("semaphore.{}.permits", name),
+                message: "permits must be >= 1".to_string(),
+            });
+        }
+    }
+    for (job_name, job) in &runbook.jobs {
+        for (i, step) in job.steps.iter().enumerate() {
+            let mut seen = HashSet::new();
+            for reference in &step.acquire {
+                let location = format!("job.{}.step[{}]({}).acquire", job_name, i, step.name);
+                if ResourceRef::parse(reference).is_none() {
+                    return Err(ParseError::InvalidFormat {
+                        location,
+                        message: format!(
+                            "invalid resource '{}'; expected 'lock.<name>' or 'semaphore.<name>'",
+                            reference
+                        ),
+                    });
+                }
+                if !seen.insert(reference.as_str()) {
+                    return Err(ParseError::InvalidFormat {
+                        location,
+                        message: format!("duplicate resource '{}'", reference),
+                    });
+                }
+            }
+        }
+    }
+
+    // 6.9. Validate job dependencies that don't depend on templates
+    for (job_name, job) in &runbook.jobs {
+        for (i, entry) in job.depends_on.iter().enumerate() {
+            if entry.contains("${") {
+                continue;
+            }
+            for spec in entry.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+                if let Err(message) = spec.parse::<oj_core::JobDependency>() {
+                    return Err(ParseError::InvalidFormat {
+                        location: format!("job.{}.depends_on[{}]", job_name, i),
+                        message,
+                    });
+                }
+            }
+        }
+    }
+
     // 7. Validate action-trigger compatibility
     for (agent_name, agent) in &runbook.agents {
         // Validate on_idle action
@@ -418,6 +755,19 @@ fn parse_runbook_inner(
             });
         }
 
+        // Validate on_budget action
+        let budget_action = agent.on_budget.action();
+        if !budget_action.is_valid_for_trigger(ActionTrigger::OnBudget) {
+            return Err(ParseError::InvalidFormat {
+                location: format!("agent.{}.on_budget", agent_name),
+                message: format!(
+                    "action '{}' is not valid for on_budget: {}",
+                    budget_action.as_str(),
+                    budget_action.invalid_reason(ActionTrigger::OnBudget)
+                ),
+            });
+        }
+
         // Validate on_error action(s)
         for error_action in agent.on_error.all_actions() {
             if !error_action.is_valid_for_trigger(ActionTrigger::OnError) {
@@ -449,6 +799,7 @@ fn parse_runbook_inner(
     // 9. Validate step transition references
     for (job_name, job) in &runbook.jobs {
         let step_names: HashSet<&str> = job.steps.iter().map(|s| s.name.as_str()).collect();
+        let unpublished = unpublished_steps(job);
 
         // Check job-level transitions
         for (field, transition) in [
@@ -457,41 +808,104 @@ fn parse_runbook_inner(
             ("on_cancel", &job.on_cancel),
         ] {
             if let Some(t) = transition {
-                if !step_names.contains(t.step_name()) {
-                    return Err(ParseError::InvalidFormat {
-                        location: format!("job.{}.{}", job_name, field),
-                        message: format!(
-                            "references unknown step '{}'; available steps: {}",
-                            t.step_name(),
-                            sorted_names(&step_names),
-                        ),
-                    });
-                }
+                let location = format!("job.{}.{}", job_name, field);
+                validate_transition(t, false, &step_names, &unpublished, &location)?;
             }
         }
 
         // Check step-level transitions
         for (i, step) in job.steps.iter().enumerate() {
+            let step_location = format!("job.{}.step[{}]({})", job_name, i, step.name);
+            if let Some(on_done) = &step.on_done {
+                let branches = on_done.branches();
+                if branches.is_empty() {
+                    return Err(ParseError::InvalidFormat {
+                        location: format!("{}.on_done", step_location),
+                        message: "on_done requires at least one branch".to_string(),
+                    });
+                }
+                for (j, t) in branches.iter().enumerate() {
+                    let location = match on_done {
+                        OnDone::Step(_) => format!("{}.on_done", step_location),
+                        OnDone::Branches(_) => format!("{}.on_done[{}]", step_location, j),
+                    };
+                    validate_transition(t, true, &step_names, &unpublished, &location)?;
+                    if t.condition.is_none() && j + 1 < branches.len() {
+                        return Err(ParseError::InvalidFormat {
+                            location,
+                            message: "branch has no `if`, so the branches after it \
+                                      are unreachable; move it last"
+                                .to_string(),
+                        });
+                    }
+                }
+            }
             for (field, transition) in [
-                ("on_done", &step.on_done),
                 ("on_fail", &step.on_fail),
+                ("on_timeout", &step.on_timeout),
                 ("on_cancel", &step.on_cancel),
             ] {
                 if let Some(t) = transition {
-                    if !step_names.contains(t.step_name()) {
-                        return Err(ParseError::InvalidFormat {
-                            location: format!(
-                                "job.{}.step[{}]({}).{}",
-                                job_name, i, step.name, field
-                            ),
-                            message: format!(
-                                "references unknown step '{}'; available steps: {}",
-                                t.step_name(),
-                                sorted_names(&step_names),
-                            ),
-                        });
-                    }
+                    let location = format!("{}.{}", step_location, field);
+                    validate_transition(t, false, &step_names, &unpublished, &location)?;
+                }
+            }
+            if !step.on_exit.is_empty() && !step.is_shell() {
+                return Err(ParseError::InvalidFormat {
+                    location: format!("{}.on_exit", step_location),
+                    message: "on_exit is only valid on shell steps".to_string(),
+                });
+            }
+            let mut codes: Vec<_> = step.on_exit.iter().collect();
+            codes.sort_by_key(|(code, _)| *code);
+            for (code, t) in codes {
+                let location = format!("{}.on_exit.{}", step_location, code);
+                if code.parse::<u8>().is_err() {
+                    return Err(ParseError::InvalidFormat {
+                        location,
+                        message: format!("invalid exit code '{}'; expected 0-255", code),
+                    });
                 }
+                validate_transition(t, false, &step_names, &unpublished, &location)?;
+            }
+        }
+    }
+
+    // 10. Validate parallel branch references
+    for (job_name, job) in &runbook.jobs {
+        let step_names: HashSet<&str> = job.steps.iter().map(|s| s.name.as_str()).collect();
+        for (i, step) in job.steps.iter().enumerate() {
+            let Some(branches) = step.run.parallel_steps() else {
+                continue;
+            };
+            let location = format!("job.{}.step[{}]({}).run.parallel", job_name, i, step.name);
+            if branches.is_empty() {
+                return Err(ParseError::InvalidFormat {
+                    location,
+                    message: "parallel requires at least one step".to_string(),
+                });
+            }
+            let mut seen = HashSet::new();
+            for branch in branches {
+                let message = if !step_names.contains(branch.as_str()) {
+                    format!(
+                        "references unknown step '{}'; available steps: {}",
+                        branch,
+                        sorted_names(&step_names),
+                    )
+                } else if *branch == step.name {
+                    format!("step '{}' cannot run itself in parallel", branch)
+                } else if job.get_step(branch).is_some_and(|s| s.run.is_parallel()) {
+                    format!("step '{}' is itself parallel; fan-outs cannot nest", branch)
+                } else if !seen.insert(branch.as_str()) {
+                    format!("duplicate step '{}'", branch)
+                } else {
+                    continue;
+                };
+                return Err(ParseError::InvalidFormat {
+                    location: location.clone(),
+                    message,
+                });
             }
         }
     }
@@ -511,12 +925,21 @@ fn parse_runbook_inner(
             referenced.insert(t.step_name());
         }
         for step in &job.steps {
-            for t in [&step.on_done, &step.on_fail, &step.on_cancel]
+            for t in step.on_done.iter().flat_map(|t| t.branches()) {
+                referenced.insert(t.step_name());
+            }
+            for t in [&step.on_fail, &step.on_timeout, &step.on_cancel]
                 .into_iter()
                 .flatten()
             {
                 referenced.insert(t.step_name());
             }
+            for t in step.on_exit.values() {
+                referenced.insert(t.step_name());
+            }
+            for branch in step.run.parallel_steps().unwrap_or_default() {
+                referenced.insert(branch.as_str());
+            }
         }
         for step in job.steps.iter().skip(1) {
             if !referenced.contains(step.name.as_str()) {
@@ -524,7 +947,7 @@ fn parse_runbook_inner(
                     location: format!("job.{}.step.{}", job_name, step.name),
                     message: format!(
                         "step '{}' is unreachable \
-                         (not referenced by any on_done/on_fail/on_cancel)",
+                         (not referenced by any on_done/on_fail/on_timeout/on_cancel/on_exit/parallel)",
                         step.name
                     ),
                 });
@@ -542,43 +965,111 @@ fn parse_runbook_inner(
 /// Validate cross-references between entities in a runbook.
 ///
 /// Checks that:
-/// - Workers reference existing queues and jobs
-/// - Crons reference existing jobs or agents
+/// - Workers reference existing queues (persisted ones, when weighted) and
+///   an existing job or agent
+/// - Crons reference existing jobs or agents, and watches, git triggers, and
+///   triggers existing jobs
+/// - Webhooks reference an existing queue, or an existing command whose
+///   arguments their `map` fills
 /// - Steps and commands reference existing agents and jobs
+/// - Sub-job steps supply their child's required vars and never start
+///   their own job again
+/// - Step `acquire` lists reference existing locks and semaphores
 pub(crate) fn validate_cross_refs(runbook: &Runbook) -> Result<(), ParseError> {
     // Worker cross-references
     for (name, worker) in &runbook.workers {
-        if !runbook.queues.contains_key(&worker.source.queue) {
-            return Err(ParseError::InvalidFormat {
-                location: format!("worker.{}.source.queue", name),
-                message: format!(
-                    "references unknown queue '{}'; available queues: {}",
-                    worker.source.queue,
-                    runbook
-                        .queues
-                        .keys()
-                        .cloned()
-                        .collect::<Vec<_>>()
-                        .join(", "),
-                ),
-            });
+        let source = &worker.source;
+        let field = match (source.queue.is_empty(), source.queues.is_empty()) {
+            (false, true) => "queue",
+            (true, false) => "queues",
+            _ => {
+                return Err(ParseError::InvalidFormat {
+                    location: format!("worker.{}.source", name),
+                    message: "source must set exactly one of queue or queues".to_string(),
+                });
+            }
+        };
+        for (queue, weight) in source.weighted() {
+            let Some(queue_def) = runbook.queues.get(&queue) else {
+                return Err(ParseError::InvalidFormat {
+                    location: format!("worker.{}.source.{}", name, field),
+                    message: format!(
+                        "references unknown queue '{}'; available queues: {}",
+                        queue,
+                        runbook
+                            .queues
+                            .keys()
+                            .cloned()
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    ),
+                });
+            };
+            if field == "queues" && weight == 0 {
+                return Err(ParseError::InvalidFormat {
+                    location: format!("worker.{}.source.queues.{}", name, queue),
+                    message: "weight must be at least 1".to_string(),
+                });
+            }
+            if field == "queues" && queue_def.queue_type != QueueType::Persisted {
+                return Err(ParseError::InvalidFormat {
+                    location: format!("worker.{}.source.queues.{}", name, queue),
+                    message: format!(
+                        "queue '{}' is external; weighted sources must be persisted queues",
+                        queue
+                    ),
+                });
+            }
         }
-        if !runbook.jobs.contains_key(&worker.handler.job) {
-            return Err(ParseError::InvalidFormat {
-                location: format!("worker.{}.handler.job", name),
-                message: format!(
-                    "references unknown job '{}'; available jobs: {}",
-                    worker.handler.job,
-                    runbook.jobs.keys().cloned().collect::<Vec<_>>().join(", "),
-                ),
-            });
+        match (worker.handler.job.is_empty(), &worker.handler.agent) {
+            (false, None) => {
+                if !runbook.jobs.contains_key(&worker.handler.job) {
+                    return Err(ParseError::InvalidFormat {
+                        location: format!("worker.{}.handler.job", name),
+                        message: format!(
+                            "references unknown job '{}'; available jobs: {}",
+                            worker.handler.job,
+                            runbook.jobs.keys().cloned().collect::<Vec<_>>().join(", "),
+                        ),
+                    });
+                }
+            }
+            (true, Some(agent)) => {
+                if !runbook.agents.contains_key(agent) {
+                    return Err(ParseError::InvalidFormat {
+                        location: format!("worker.{}.handler.agent", name),
+                        message: format!(
+                            "references unknown agent '{}'; available agents: {}",
+                            agent,
+                            runbook
+                                .agents
+                                .keys()
+                                .cloned()
+                                .collect::<Vec<_>>()
+                                .join(", "),
+                        ),
+                    });
+                }
+            }
+            _ => {
+                return Err(ParseError::InvalidFormat {
+                    location: format!("worker.{}.handler", name),
+                    message: "handler must reference exactly one of a job or an agent".to_string(),
+                });
+            }
         }
     }
 
     // Cron cross-references
     for (name, cron) in &runbook.crons {
         match &cron.run {
-            RunDirective::Job { job } => {
+            RunDirective::Job { job, vars } => {
+                if !vars.is_empty() {
+                    return Err(ParseError::InvalidFormat {
+                        location: format!("cron.{}.run", name),
+                        message: "job vars are only valid in job steps".to_string(),
+                    });
+                }
                 if !runbook.jobs.contains_key(job.as_str()) {
                     return Err(ParseError::InvalidFormat {
                         location: format!("cron.{}.run", name),
@@ -602,7 +1093,9 @@ pub(crate) fn validate_cross_refs(runbook: &Runbook) -> Result<(), ParseError> {
                     });
                 }
             }
-            RunDirective::Shell(_) => {
+            RunDirective::Shell(_)
+            | RunDirective::Parallel { .. }
+            | RunDirective::Approval { .. } => {
                 return Err(ParseError::InvalidFormat {
                     location: format!("cron.{}.run", name),
                     message: "cron run must reference a job or agent".to_string(),
@@ -611,6 +1104,63 @@ pub(crate) fn validate_cross_refs(runbook: &Runbook) -> Result<(), ParseError> {
         }
     }
 
+    // Watch, git trigger, and trigger cross-references
+    for (name, watch) in &runbook.watches {
+        validate_job_only_run(runbook, &watch.run, format!("watch.{}.run", name), "watch")?;
+    }
+    for (name, trigger) in &runbook.git_triggers {
+        validate_job_only_run(
+            runbook,
+            &trigger.run,
+            format!("git_trigger.{}.run", name),
+            "git trigger",
+        )?;
+    }
+    for (event, triggers) in &runbook.triggers {
+        for (i, trigger) in triggers.iter().enumerate() {
+            let location = format!("on.\"{}\"[{}].run", event, i);
+            validate_job_only_run(runbook, &trigger.run, location, "trigger")?;
+        }
+    }
+
+    // Webhook cross-references: the target exists, and a command's mapped
+    // fields are its arguments
+    for (name, webhook) in &runbook.webhooks {
+        if let Some(ref queue) = webhook.queue {
+            if !runbook.queues.contains_key(queue) {
+                return Err(ParseError::InvalidFormat {
+                    location: format!("webhook.{}.queue", name),
+                    message: format!(
+                        "references unknown queue '{}'; available queues: {}",
+                        queue,
+                        sorted_keys(&runbook.queues),
+                    ),
+                });
+            }
+        }
+        if let Some(ref command) = webhook.command {
+            let Some(cmd) = runbook.commands.get(command) else {
+                return Err(ParseError::InvalidFormat {
+                    location: format!("webhook.{}.command", name),
+                    message: format!(
+                        "references unknown command '{}'; available commands: {}",
+                        command,
+                        sorted_keys(&runbook.commands),
+                    ),
+                });
+            };
+            let args = cmd.args.names();
+            let mut keys: Vec<&String> = webhook.map.keys().collect();
+            keys.sort();
+            if let Some(key) = keys.into_iter().find(|k| !args.contains(&k.as_str())) {
+                return Err(ParseError::InvalidFormat {
+                    location: format!("webhook.{}.map.{}", name, key),
+                    message: format!("command '{}' has no argument '{}'", command, key),
+                });
+            }
+        }
+    }
+
     // Step and command cross-references
     for (job_name, job) in &runbook.jobs {
         for (i, step) in job.steps.iter().enumerate() {
@@ -626,16 +1176,63 @@ pub(crate) fn validate_cross_refs(runbook: &Runbook) -> Result<(), ParseError> {
                     });
                 }
             }
-            if let Some(pl_name) = step.run.job_name() {
-                if !runbook.jobs.contains_key(pl_name) {
+            if let RunDirective::Job { job: child, vars } = &step.run {
+                let location = format!("job.{}.step[{}]({}).run", job_name, i, step.name);
+                let Some(child_def) = runbook.jobs.get(child) else {
                     return Err(ParseError::InvalidFormat {
-                        location: format!("job.{}.step[{}]({}).run", job_name, i, step.name),
+                        location,
                         message: format!(
                             "references unknown job '{}'; available jobs: {}",
-                            pl_name,
+                            child,
                             sorted_keys(&runbook.jobs),
                         ),
                     });
+                };
+                if let Some(missing) = child_def
+                    .vars
+                    .iter()
+                    .find(|v| !vars.contains_key(*v) && !child_def.defaults.contains_key(*v))
+                {
+                    return Err(ParseError::InvalidFormat {
+                        location,
+                        message: format!("job '{}' requires var '{}'", child, missing),
+                    });
+                }
+                if let Some(cycle) = find_job_cycle(runbook, job_name, child) {
+                    return Err(ParseError::InvalidFormat {
+                        location,
+                        message: format!("job steps form a cycle: {}", cycle.join(" -> ")),
+                    });
+                }
+            }
+        }
+    }
+
+    // Step acquire references must name a defined lock or semaphore
+    for (job_name, job) in &runbook.jobs {
+        for (i, step) in job.steps.iter().enumerate() {
+            for reference in &step.acquire {
+                if runbook.resource_permits(reference).is_none() {
+                    let mut available: Vec<String> = runbook
+                        .locks
+                        .keys()
+                        .map(|k| format!("lock.{}", k))
+                        .chain(
+                            runbook
+                                .semaphores
+                                .keys()
+                                .map(|k| format!("semaphore.{}", k)),
+                        )
+                        .collect();
+                    available.sort();
+                    return Err(ParseError::InvalidFormat {
+                        location: format!("job.{}.step[{}]({}).acquire", job_name, i, step.name),
+                        message: format!(
+                            "references unknown resource '{}'; available: {}",
+                            reference,
+                            available.join(", "),
+                        ),
+                    });
                 }
             }
         }
@@ -670,3 +1267,63 @@ pub(crate) fn validate_cross_refs(runbook: &Runbook) -> Result<(), ParseError> {
 
     Ok(())
 }
+
+/// Check that the `run` of a watch or trigger names a job of the runbook,
+/// without vars (they're supplied by whatever starts it).
+fn validate_job_only_run(
+    runbook: &Runbook,
+    run: &RunDirective,
+    location: String,
+    entity: &str,
+) -> Result<(), ParseError> {
+    match run {
+        RunDirective::Job { job, vars } => {
+            if !vars.is_empty() {
+                return Err(ParseError::InvalidFormat {
+                    location,
+                    message: "job vars are only valid in job steps".to_string(),
+                });
+            }
+            if !runbook.jobs.contains_key(job.as_str()) {
+                return Err(ParseError::InvalidFormat {
+                    location,
+                    message: format!(
+                        "references unknown job '{}'; available jobs: {}",
+                        job,
+                        sorted_keys(&runbook.jobs),
+                    ),
+                });
+            }
+            Ok(())
+        }
+        _ => Err(ParseError::InvalidFormat {
+            location,
+            message: format!("{} run must reference a job", entity),
+        }),
+    }
+}
+
+/// Follow `run = { job }` steps from `child` looking for a path back to
+/// `root`. Returns the job chain (`root -> ... -> root`) if one exists.
+fn find_job_cycle(runbook: &Runbook, root: &str, child: &str) -> Option<Vec<String>> {
+    let mut stack = vec![vec![root.to_string(), child.to_string()]];
+    let mut visited = HashSet::new();
+    while let Some(path) = stack.pop() {
+        let current = path.last()?.clone();
+        if current == root {
+            return Some(path);
+        }
+        if !visited.insert(current.clone()) {
+            continue;
+        }
+        let Some(job) = runbook.jobs.get(&current) else {
+            continue;
+        };
+        for next in job.steps.iter().filter_map(|s| s.run.job_name()) {
+            let mut next_path = path.clone();
+            next_path.push(next.to_string());
+            stack.push(next_path);
+        }
+    }
+    None
+}
@@ -3,6 +3,7 @@
 
 //! Queue definition for runbooks
 
+use crate::RetryConfig;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -17,24 +18,6 @@ pub enum QueueType {
     Persisted,
 }
 
-/// Retry configuration for persisted queues.
-///
-/// Controls automatic retry behavior when queue items fail.
-/// When `attempts = 0` (the default), failed items go directly to `Dead`.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RetryConfig {
-    /// Number of auto-retry attempts (0 = no auto-retry, items go straight to Dead)
-    #[serde(default)]
-    pub attempts: u32,
-    /// Cooldown duration between retries (e.g. "30s", "5m"), default "0s"
-    #[serde(default = "default_cooldown")]
-    pub cooldown: String,
-}
-
-fn default_cooldown() -> String {
-    "0s".into()
-}
-
 /// A queue definition for listing and claiming work items.
 ///
 /// External queues use shell commands (`list`/`take`).
@@ -66,4 +49,87 @@ pub struct QueueDef {
     /// When set, workers periodically check the queue at this interval
     #[serde(default)]
     pub poll: Option<String>,
+    /// Timeout for the `list` and `take` commands (e.g. "2m", external queues only)
+    #[serde(default)]
+    pub timeout: Option<String>,
+    /// Dispatch priority for pushed items, an integer or a template over item
+    /// vars (e.g. "${item.severity}"); higher is taken first (persisted queues only)
+    #[serde(default)]
+    pub priority: Option<String>,
+    /// Deduplication key for pushed items, a template over item vars
+    /// (e.g. "${item.issue}"; persisted queues only)
+    #[serde(default)]
+    pub dedup_key: Option<String>,
+    /// What a push does when an item with the same key is already queued
+    #[serde(default)]
+    pub dedup_policy: DedupPolicy,
+    /// How long a completed item keeps blocking pushes with its key
+    /// (e.g. "1h"); by default only pending and active items do
+    #[serde(default)]
+    pub dedup_window: Option<String>,
+    /// How long a pending item may wait before it expires (e.g. "7d";
+    /// persisted queues only)
+    #[serde(default)]
+    pub ttl: Option<String>,
+    /// What happens to an item when its TTL runs out
+    #[serde(default)]
+    pub on_expire: ExpirePolicy,
+    /// How long a taken item stays active without a live handler (e.g.
+    /// "2h"; persisted queues only). The lease renews while the handler job
+    /// or agent run is alive; once it runs out the item goes back to pending.
+    #[serde(default)]
+    pub lease: Option<String>,
+}
+
+/// What happens to a pending item whose TTL runs out.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExpirePolicy {
+    /// Keep the item with status `expired` until it is pruned
+    #[default]
+    Expire,
+    /// Remove the item from the queue
+    Drop,
+}
+
+/// How a push handles an item whose dedup key is already queued.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DedupPolicy {
+    /// Drop the new push and keep the existing item
+    #[default]
+    Reject,
+    /// Fold the new item's data into a still-pending existing item
+    Merge,
+}
+
+impl QueueDef {
+    /// Priority for an item pushed with `data`, from the `priority` template.
+    /// Items default to 0.
+    pub fn item_priority(&self, data: &HashMap<String, String>) -> Result<i32, String> {
+        let Some(template) = &self.priority else {
+            return Ok(0);
+        };
+        let value = crate::interpolate(template, &item_vars(data));
+        value
+            .trim()
+            .parse()
+            .map_err(|_| format!("priority '{}' is not an integer", value))
+    }
+
+    /// Dedup key for an item pushed with `data`, from the `dedup_key`
+    /// template. `None` when the queue has no key or it renders empty.
+    pub fn item_dedup_key(&self, data: &HashMap<String, String>) -> Option<String> {
+        let template = self.dedup_key.as_ref()?;
+        let key = crate::interpolate(template, &item_vars(data));
+        let key = key.trim();
+        (!key.is_empty()).then(|| key.to_string())
+    }
+}
+
+/// Item data as `item.<var>` template variables.
+fn item_vars(data: &HashMap<String, String>) -> HashMap<String, String> {
+    data.iter()
+        .map(|(k, v)| (format!("item.{}", k), v.clone()))
+        .collect()
 }
@@ -0,0 +1,63 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Retry policy shared by shell steps and persisted queues
+//!
+//! ```hcl
+//! retry {
+//!   attempts = 5
+//!   backoff  = "exponential"
+//!   base     = "10s"
+//!   max      = "10m"
+//!   jitter   = 0.2
+//! }
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+/// How the delay grows between retries.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Backoff {
+    /// Every retry waits `base`
+    #[default]
+    Fixed,
+    /// Retry `n` waits `base * multiplier^(n-1)`, capped at `max`
+    Exponential,
+}
+
+/// Retry configuration for shell steps and persisted queues.
+///
+/// When `attempts = 0` (the default), nothing is retried: a failed step
+/// follows its `on_fail`, and a failed queue item goes directly to `Dead`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetryConfig {
+    /// Number of auto-retry attempts (0 = no auto-retry)
+    #[serde(default)]
+    pub attempts: u32,
+    /// "fixed" (default) or "exponential"
+    #[serde(default)]
+    pub backoff: Backoff,
+    /// Delay before the first retry (e.g. "30s", "5m"), default "0s".
+    /// `cooldown` is accepted as the older name for this field.
+    #[serde(default = "default_base", alias = "cooldown")]
+    pub base: String,
+    /// Growth factor for exponential backoff, default 2
+    #[serde(default = "default_multiplier")]
+    pub multiplier: f64,
+    /// Upper bound on the delay (e.g. "10m"), default "1h"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<String>,
+    /// Fraction of each delay (0 to 1) randomly taken off, so retries
+    /// that failed together don't all fire together
+    #[serde(default)]
+    pub jitter: f64,
+}
+
+fn default_base() -> String {
+    "0s".into()
+}
+
+fn default_multiplier() -> f64 {
+    2.0
+}
@@ -0,0 +1,103 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Event trigger definition for runbooks
+
+use crate::RunDirective;
+use serde::de::{self, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Events an `on` block can subscribe to, with the fields each one exposes
+/// to `filter` and to the started job as `${event.<field>}`.
+pub const TRIGGER_EVENTS: &[(&str, &[&str])] = &[
+    ("job:completed", &["id", "kind", "name"]),
+    ("job:failed", &["id", "kind", "name", "error"]),
+    ("queue:item_dead", &["queue", "item"]),
+    ("decision:created", &["id", "job", "kind", "source"]),
+    ("agent:signal", &["agent", "job", "kind", "message"]),
+];
+
+/// Fields exposed by a trigger event, or `None` if the event can't be
+/// subscribed to.
+pub fn trigger_event_fields(event: &str) -> Option<&'static [&'static str]> {
+    TRIGGER_EVENTS
+        .iter()
+        .find(|(name, _)| *name == event)
+        .map(|(_, fields)| *fields)
+}
+
+/// An `on "<event>"` block: starts a job whenever a matching event occurs in
+/// the project.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TriggerDef {
+    /// Event name (injected from map key)
+    #[serde(skip)]
+    pub event: String,
+    /// Event fields that must match exactly for the trigger to fire
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub filter: HashMap<String, String>,
+    /// What to run (job reference only)
+    pub run: RunDirective,
+}
+
+impl TriggerDef {
+    /// Whether an event with these fields passes the filter
+    pub fn matches(&self, fields: &HashMap<String, String>) -> bool {
+        self.filter
+            .iter()
+            .all(|(key, value)| fields.get(key) == Some(value))
+    }
+}
+
+/// Deserialize `on` blocks keyed by event name.
+///
+/// - HCL produces a single block for an event used once and a sequence when
+///   the same event label repeats
+/// - TOML `[[on."job:completed"]]` and JSON always produce sequences
+pub(crate) fn deserialize_triggers<'de, D>(
+    deserializer: D,
+) -> Result<HashMap<String, Vec<TriggerDef>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct OneOrMany(Vec<TriggerDef>);
+
+    impl<'de> Deserialize<'de> for OneOrMany {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            struct OneOrManyVisitor;
+
+            impl<'de> Visitor<'de> for OneOrManyVisitor {
+                type Value = OneOrMany;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    f.write_str("an `on` block or a sequence of them")
+                }
+
+                fn visit_seq<S>(self, seq: S) -> Result<OneOrMany, S::Error>
+                where
+                    S: SeqAccess<'de>,
+                {
+                    Vec::deserialize(de::value::SeqAccessDeserializer::new(seq)).map(OneOrMany)
+                }
+
+                fn visit_map<M>(self, map: M) -> Result<OneOrMany, M::Error>
+                where
+                    M: MapAccess<'de>,
+                {
+                    TriggerDef::deserialize(de::value::MapAccessDeserializer::new(map))
+                        .map(|trigger| OneOrMany(vec![trigger]))
+                }
+            }
+
+            deserializer.deserialize_any(OneOrManyVisitor)
+        }
+    }
+
+    let raw = HashMap::<String, OneOrMany>::deserialize(deserializer)?;
+    Ok(raw
+        .into_iter()
+        .map(|(event, triggers)| (event, triggers.0))
+        .collect())
+}
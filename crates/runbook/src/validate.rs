@@ -3,7 +3,13 @@
 
 //! Validation helpers for runbook parsing
 
+use crate::condition::validate_condition;
 use crate::parser::ParseError;
+use crate::{
+    ApprovalDef, BudgetDef, CatchUp, CronDef, GitTriggerDef, JobDef, RetryConfig, RunDirective,
+    StepTransition, WatchDef, WebhookDef,
+};
+use oj_core::CronSchedule;
 use oj_shell as shell;
 use std::collections::{HashMap, HashSet};
 
@@ -161,6 +167,266 @@ pub(crate) fn validate_duration_str(s: &str) -> Result<(), String> {
     }
 }
 
+/// Validate a timeout: a duration string that isn't zero.
+pub(crate) fn validate_timeout_str(s: &str) -> Result<(), String> {
+    validate_duration_str(s)?;
+    let significant = s.trim().trim_start_matches('0');
+    if !significant.starts_with(|c: char| c.is_ascii_digit()) {
+        return Err("timeout must be greater than zero".to_string());
+    }
+    Ok(())
+}
+
+/// Validate a `retry` block; `location` is the path of the block itself.
+pub(crate) fn validate_retry(retry: &RetryConfig, location: &str) -> Result<(), ParseError> {
+    let invalid = |field: &str, message: String| ParseError::InvalidFormat {
+        location: format!("{}.{}", location, field),
+        message,
+    };
+    validate_duration_str(&retry.base).map_err(|e| invalid("base", e))?;
+    if let Some(max) = &retry.max {
+        validate_duration_str(max).map_err(|e| invalid("max", e))?;
+    }
+    if !(retry.multiplier >= 1.0 && retry.multiplier.is_finite()) {
+        return Err(invalid(
+            "multiplier",
+            format!("multiplier must be at least 1, got {}", retry.multiplier),
+        ));
+    }
+    if !(0.0..=1.0).contains(&retry.jitter) {
+        return Err(invalid(
+            "jitter",
+            format!("jitter must be between 0 and 1, got {}", retry.jitter),
+        ));
+    }
+    Ok(())
+}
+
+/// Validate a `budget` block; `location` is the path of the block itself.
+pub(crate) fn validate_budget(budget: &BudgetDef, location: &str) -> Result<(), ParseError> {
+    let invalid = |field: &str, message: &str| ParseError::InvalidFormat {
+        location: format!("{}.{}", location, field),
+        message: message.to_string(),
+    };
+    if budget.tokens.is_none() && budget.usd.is_none() {
+        return Err(ParseError::InvalidFormat {
+            location: location.to_string(),
+            message: "budget needs a tokens or usd limit".to_string(),
+        });
+    }
+    if budget.tokens == Some(0) {
+        return Err(invalid("tokens", "tokens must be greater than zero"));
+    }
+    if let Some(usd) = budget.usd {
+        if !(usd > 0.0 && usd.is_finite()) {
+            return Err(invalid("usd", "usd must be greater than zero"));
+        }
+    }
+    Ok(())
+}
+
+/// Validate that a cron has exactly one of `interval` and `schedule`, and
+/// that whichever it has parses.
+pub(crate) fn validate_cron_timing(name: &str, cron: &CronDef) -> Result<(), ParseError> {
+    let invalid = |field: &str, message: String| ParseError::InvalidFormat {
+        location: format!("cron.{}.{}", name, field),
+        message,
+    };
+    match &cron.schedule {
+        Some(_) if !cron.interval.is_empty() => Err(ParseError::InvalidFormat {
+            location: format!("cron.{}", name),
+            message: "cron takes either interval or schedule, not both".to_string(),
+        }),
+        Some(schedule) => CronSchedule::parse(schedule, cron.timezone.as_deref())
+            .map(|_| ())
+            .map_err(|e| {
+                let field = if e.starts_with("unknown timezone") {
+                    "timezone"
+                } else {
+                    "schedule"
+                };
+                invalid(field, e)
+            }),
+        None if cron.timezone.is_some() => Err(invalid(
+            "timezone",
+            "timezone only applies to schedule".to_string(),
+        )),
+        None if cron.interval.is_empty() => Err(ParseError::InvalidFormat {
+            location: format!("cron.{}", name),
+            message: "cron needs an interval or a schedule".to_string(),
+        }),
+        None => validate_duration_str(&cron.interval).map_err(|e| invalid("interval", e)),
+    }
+}
+
+/// Validate that `catch_up = "all"` can start more than one run. A job cron
+/// holds at most `concurrency` active jobs, so with the default of 1 every
+/// missed run after the first would be dropped.
+pub(crate) fn validate_cron_catch_up(name: &str, cron: &CronDef) -> Result<(), ParseError> {
+    if cron.catch_up == CatchUp::All && cron.run.is_job() && cron.concurrency.unwrap_or(1) < 2 {
+        return Err(ParseError::InvalidFormat {
+            location: format!("cron.{}.catch_up", name),
+            message: "catch_up = \"all\" needs concurrency greater than 1; \
+                      use \"once\" for a singleton cron"
+                .to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Validate a watch's path patterns and debounce.
+///
+/// Patterns are globs relative to the project root, so they can't be absolute
+/// or climb out of it with `..`.
+pub(crate) fn validate_watch(name: &str, watch: &WatchDef) -> Result<(), ParseError> {
+    if watch.paths.is_empty() {
+        return Err(ParseError::InvalidFormat {
+            location: format!("watch.{}.paths", name),
+            message: "watch needs at least one path".to_string(),
+        });
+    }
+    for (i, pattern) in watch.paths.iter().enumerate() {
+        let invalid = |message: String| ParseError::InvalidFormat {
+            location: format!("watch.{}.paths[{}]", name, i),
+            message,
+        };
+        if pattern.trim().is_empty() {
+            return Err(invalid("path is empty".to_string()));
+        }
+        if pattern.starts_with('/') || pattern.split('/').any(|part| part == "..") {
+            return Err(invalid(format!(
+                "'{}' must be relative to the project root",
+                pattern
+            )));
+        }
+        if let Err(e) = glob::Pattern::new(pattern) {
+            return Err(invalid(format!("invalid glob '{}': {}", pattern, e.msg)));
+        }
+    }
+    if let Some(ref debounce) = watch.debounce {
+        validate_duration_str(debounce).map_err(|e| ParseError::InvalidFormat {
+            location: format!("watch.{}.debounce", name),
+            message: e,
+        })?;
+    }
+    if watch.concurrency == Some(0) {
+        return Err(ParseError::InvalidFormat {
+            location: format!("watch.{}.concurrency", name),
+            message: "concurrency must be >= 1".to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Validate a git trigger's ref: a full ref name under `refs/`, or a glob
+/// over them.
+pub(crate) fn validate_git_trigger(name: &str, trigger: &GitTriggerDef) -> Result<(), ParseError> {
+    let pattern = &trigger.ref_pattern;
+    let invalid = |message: String| ParseError::InvalidFormat {
+        location: format!("git_trigger.{}.ref", name),
+        message,
+    };
+    if !pattern.starts_with("refs/") || pattern.len() == "refs/".len() {
+        return Err(invalid(format!(
+            "'{}' must be a full ref name like 'refs/heads/main'",
+            pattern
+        )));
+    }
+    if pattern.contains(char::is_whitespace) {
+        return Err(invalid(format!("'{}' contains whitespace", pattern)));
+    }
+    if let Err(e) = glob::Pattern::new(pattern) {
+        return Err(invalid(format!("invalid glob '{}': {}", pattern, e.msg)));
+    }
+    Ok(())
+}
+
+/// Validate a webhook: it targets exactly one of a queue or a command, and
+/// its `map` templates only read the request body.
+pub(crate) fn validate_webhook(name: &str, webhook: &WebhookDef) -> Result<(), ParseError> {
+    if webhook.queue.is_some() == webhook.command.is_some() {
+        return Err(ParseError::InvalidFormat {
+            location: format!("webhook.{}", name),
+            message: "webhook must set exactly one of queue or command".to_string(),
+        });
+    }
+    for (key, template) in &webhook.map {
+        if let Some(cap) = crate::template::VAR_PATTERN
+            .captures_iter(template)
+            .find(|cap| cap[1] != *"body" && !cap[1].starts_with("body."))
+        {
+            return Err(ParseError::InvalidFormat {
+                location: format!("webhook.{}.map.{}", name, key),
+                message: format!(
+                    "${{{}}} is not a request body field; use ${{body.<field>}}",
+                    &cap[1]
+                ),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Validate an approval step; `location` is the path of its `run` directive.
+pub(crate) fn validate_approval(
+    approval: &ApprovalDef,
+    step_names: &HashSet<&str>,
+    unpublished: &HashMap<&str, &str>,
+    location: &str,
+) -> Result<(), ParseError> {
+    let invalid = |message: String| ParseError::InvalidFormat {
+        location: format!("{}.approval", location),
+        message,
+    };
+    if approval.prompt.trim().is_empty() {
+        return Err(invalid("approval prompt is empty".to_string()));
+    }
+    validate_template_namespaces(&approval.prompt, location)?;
+    validate_step_output_refs(&approval.prompt, step_names, unpublished, location)?;
+
+    if approval.options.is_empty() {
+        return Err(invalid("approval requires at least one option".to_string()));
+    }
+    let mut seen = HashSet::new();
+    for option in &approval.options {
+        if option.trim().is_empty() {
+            return Err(invalid("approval options must not be empty".to_string()));
+        }
+        // Cancel is always offered as the last option
+        if option.eq_ignore_ascii_case("cancel") {
+            return Err(invalid(
+                "'cancel' is reserved; every approval can be cancelled".to_string(),
+            ));
+        }
+        if !seen.insert(option.as_str()) {
+            return Err(invalid(format!("duplicate option '{}'", option)));
+        }
+    }
+
+    let mut seen = HashSet::new();
+    for field in &approval.fields {
+        let valid = !field.is_empty()
+            && field
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+        if !valid {
+            return Err(invalid(format!(
+                "invalid field name '{}'; use letters, digits, '_' or '-'",
+                field
+            )));
+        }
+        if field == "choice" {
+            return Err(invalid(
+                "field name 'choice' is reserved for the chosen option".to_string(),
+            ));
+        }
+        if !seen.insert(field.as_str()) {
+            return Err(invalid(format!("duplicate field '{}'", field)));
+        }
+    }
+    Ok(())
+}
+
 /// Validate that an agent's run command uses a recognized agent command.
 ///
 /// Parses the shell AST and extracts the first command name (taking basename
@@ -256,6 +522,11 @@ const VALID_TEMPLATE_NAMESPACES: &[&str] = &[
     "invoke",
     "prompt",
     "step",
+    "steps",
+    "event",
+    "watch",
+    "git",
+    "trigger",
 ];
 
 /// Validate that template references use recognized namespaces.
@@ -288,6 +559,103 @@ pub(crate) fn validate_template_namespaces(
     Ok(())
 }
 
+/// Steps of a job whose outputs never reach `${steps.<name>.*}`, with the
+/// reason. Only shell and approval steps publish outputs, and a parallel
+/// branch publishes them into its own branch job rather than the parent.
+pub(crate) fn unpublished_steps(job: &JobDef) -> HashMap<&str, &'static str> {
+    let mut unpublished = HashMap::new();
+    for step in &job.steps {
+        let reason = match &step.run {
+            RunDirective::Job { .. } => "runs a sub-job",
+            RunDirective::Agent { .. } => "runs an agent",
+            RunDirective::Parallel { .. } => "fans out in parallel",
+            _ => continue,
+        };
+        unpublished.insert(step.name.as_str(), reason);
+    }
+    for branch in job
+        .steps
+        .iter()
+        .filter_map(|s| s.run.parallel_steps())
+        .flatten()
+    {
+        unpublished.insert(branch.as_str(), "runs as a parallel branch");
+    }
+    unpublished
+}
+
+/// Validate `${steps.<name>.output}` and `${steps.<name>.outputs.<key>}`
+/// references against the steps of the enclosing job. `unpublished` comes
+/// from [`unpublished_steps`].
+pub(crate) fn validate_step_output_refs(
+    template: &str,
+    step_names: &HashSet<&str>,
+    unpublished: &HashMap<&str, &str>,
+    location: &str,
+) -> Result<(), ParseError> {
+    for cap in crate::template::VAR_PATTERN.captures_iter(template) {
+        let Some(rest) = cap[1].strip_prefix("steps.") else {
+            continue;
+        };
+        let parts: Vec<&str> = rest.split('.').collect();
+        let message = match parts.as_slice() {
+            [step, ..] if !step_names.contains(step) => format!(
+                "${{{}}} references unknown step '{}'; available steps: {}",
+                &cap[1],
+                step,
+                sorted_names(step_names),
+            ),
+            [step, ..] if unpublished.contains_key(step) => format!(
+                "${{{}}} references step '{}', which {} and publishes no outputs",
+                &cap[1], step, unpublished[step],
+            ),
+            [_, "output"] | [_, "outputs", _] => continue,
+            _ => format!(
+                "invalid step output reference ${{{}}}; expected \
+                 ${{steps.<name>.output}} or ${{steps.<name>.outputs.<key>}}",
+                &cap[1],
+            ),
+        };
+        return Err(ParseError::InvalidFormat {
+            location: location.to_string(),
+            message,
+        });
+    }
+    Ok(())
+}
+
+/// Validate a transition's target step and, where allowed, its `if` condition.
+pub(crate) fn validate_transition(
+    transition: &StepTransition,
+    allow_condition: bool,
+    step_names: &HashSet<&str>,
+    unpublished: &HashMap<&str, &str>,
+    location: &str,
+) -> Result<(), ParseError> {
+    let invalid = |message: String| ParseError::InvalidFormat {
+        location: location.to_string(),
+        message,
+    };
+    if !step_names.contains(transition.step_name()) {
+        return Err(invalid(format!(
+            "references unknown step '{}'; available steps: {}",
+            transition.step_name(),
+            sorted_names(step_names),
+        )));
+    }
+    if let Some(condition) = &transition.condition {
+        if !allow_condition {
+            return Err(invalid(
+                "`if` is only valid in a step's on_done branches".to_string(),
+            ));
+        }
+        validate_condition(condition).map_err(invalid)?;
+        validate_template_namespaces(condition, location)?;
+        validate_step_output_refs(condition, step_names, unpublished, location)?;
+    }
+    Ok(())
+}
+
 /// Sort and join names from a HashSet for deterministic error messages.
 pub(crate) fn sorted_names(names: &HashSet<&str>) -> String {
     let mut v: Vec<&str> = names.iter().copied().collect();
@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! File watch definition for runbooks
+
+use crate::RunDirective;
+use serde::{Deserialize, Serialize};
+
+/// How long a watch waits for changes to settle when `debounce` is unset.
+pub const DEFAULT_WATCH_DEBOUNCE: &str = "5s";
+
+/// A watch definition that runs a job when files under the project root
+/// change.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WatchDef {
+    /// Watch name (injected from map key)
+    #[serde(skip)]
+    pub name: String,
+    /// Glob patterns relative to the project root (e.g. "docs/specs/**/*.md")
+    pub paths: Vec<String>,
+    /// Quiet period after the last change before the job runs (e.g. "30s");
+    /// every change in a burst is passed to a single run
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub debounce: Option<String>,
+    /// Maximum number of active jobs this watch can have running
+    /// simultaneously. Defaults to 1 (singleton). `None` means use default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub concurrency: Option<u32>,
+    /// What to run (job reference only)
+    pub run: RunDirective,
+}
+
+impl WatchDef {
+    /// The configured debounce, or the default
+    pub fn debounce(&self) -> &str {
+        self.debounce.as_deref().unwrap_or(DEFAULT_WATCH_DEBOUNCE)
+    }
+}
@@ -0,0 +1,88 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Webhook definition for runbooks
+
+use crate::template::VAR_PATTERN;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A webhook that turns each `POST /hooks/<name>` request to the daemon's
+/// HTTP listener into a queue push or a command run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookDef {
+    /// Webhook name (injected from map key)
+    #[serde(skip)]
+    pub name: String,
+    /// Persisted queue that receives one item per request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub queue: Option<String>,
+    /// Command run once per request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+    /// Item fields (or command arguments) as templates over the request
+    /// body, e.g. `title = "${body.issue.title}"`. Without a map, the body's
+    /// top-level fields are passed through.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub map: HashMap<String, String>,
+}
+
+impl WebhookDef {
+    /// Map a JSON request body to item fields or command arguments.
+    ///
+    /// Fails when a `map` template references a body field the request
+    /// doesn't have.
+    pub fn map_body(&self, body: &serde_json::Value) -> Result<HashMap<String, String>, String> {
+        if self.map.is_empty() {
+            return Ok(body
+                .as_object()
+                .map(|obj| {
+                    obj.iter()
+                        .map(|(k, v)| (k.clone(), json_to_string(v)))
+                        .collect()
+                })
+                .unwrap_or_default());
+        }
+
+        let mut vars = HashMap::new();
+        flatten_body("body", body, &mut vars);
+        let mut keys: Vec<&String> = self.map.keys().collect();
+        keys.sort();
+        let mut fields = HashMap::new();
+        for key in keys {
+            let template = &self.map[key];
+            if let Some(missing) = VAR_PATTERN
+                .captures_iter(template)
+                .map(|caps| caps[1].to_string())
+                .find(|name| !vars.contains_key(name))
+            {
+                return Err(format!("request body has no field '{}'", missing));
+            }
+            fields.insert(key.clone(), crate::interpolate(template, &vars));
+        }
+        Ok(fields)
+    }
+}
+
+/// Body values as `body.<path>` template variables. Objects are also
+/// available whole, as JSON, at their own path.
+fn flatten_body(path: &str, value: &serde_json::Value, vars: &mut HashMap<String, String>) {
+    if let serde_json::Value::Object(obj) = value {
+        for (key, child) in obj {
+            flatten_body(&format!("{}.{}", path, key), child, vars);
+        }
+    }
+    vars.insert(path.to_string(), json_to_string(value));
+}
+
+/// Strings as-is; everything else as JSON.
+fn json_to_string(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+#[path = "webhook_tests.rs"]
+mod tests;
@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use serde_json::json;
+
+fn webhook(map: &[(&str, &str)]) -> WebhookDef {
+    WebhookDef {
+        name: "issues".to_string(),
+        queue: Some("bugs".to_string()),
+        command: None,
+        map: map
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect(),
+    }
+}
+
+#[test]
+fn without_map_passes_top_level_fields() {
+    let fields = webhook(&[])
+        .map_body(&json!({"title": "crash", "id": 7, "labels": ["a"]}))
+        .unwrap();
+    assert_eq!(fields["title"], "crash");
+    assert_eq!(fields["id"], "7");
+    assert_eq!(fields["labels"], r#"["a"]"#);
+}
+
+#[test]
+fn map_renders_nested_fields() {
+    let fields = webhook(&[
+        ("title", "${body.issue.title}"),
+        ("ref", "issue-${body.issue.number}"),
+        ("user", "${body.issue.user}"),
+    ])
+    .map_body(&json!({"issue": {"title": "crash", "number": 12, "user": {"login": "sam"}}}))
+    .unwrap();
+    assert_eq!(fields.len(), 3);
+    assert_eq!(fields["title"], "crash");
+    assert_eq!(fields["ref"], "issue-12");
+    assert_eq!(fields["user"], r#"{"login":"sam"}"#);
+}
+
+#[test]
+fn map_rejects_missing_body_field() {
+    let err = webhook(&[("title", "${body.issue.title}")])
+        .map_body(&json!({"issue": {}}))
+        .unwrap_err();
+    assert_eq!(err, "request body has no field 'body.issue.title'");
+}
+
+#[test]
+fn body_values_are_not_re_expanded() {
+    let fields = webhook(&[("title", "${body.title}")])
+        .map_body(&json!({"title": "${body.secret}", "secret": "x"}))
+        .unwrap();
+    assert_eq!(fields["title"], "${body.secret}");
+}
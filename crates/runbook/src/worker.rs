@@ -4,36 +4,72 @@
 //! Worker definition for runbooks
 
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 fn default_concurrency() -> u32 {
     1
 }
 
-/// A worker definition that polls a queue and dispatches items to a job.
+/// A worker definition that polls a queue and dispatches items to a job or
+/// a standalone agent.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WorkerDef {
     /// Worker name (injected from map key)
     #[serde(skip)]
     pub name: String,
-    /// Source reference: { queue = "name" }
+    /// Source reference: { queue = "name" } or { queues = { name = weight } }
     pub source: WorkerSource,
-    /// Handler reference: { job = "name" }
+    /// Handler reference: { job = "name" } or { agent = "name" }
     pub handler: WorkerHandler,
-    /// Max concurrent job instances (default 1)
+    /// Max concurrent jobs or agent runs (default 1)
     #[serde(default = "default_concurrency")]
     pub concurrency: u32,
+    /// Dispatch rate limit as `<count>/<period>` (e.g. "10/h"); items wait
+    /// for a token even when a concurrency slot is free
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate: Option<String>,
 }
 
-/// Source configuration for a worker
+/// Source configuration for a worker. Exactly one of `queue` and `queues`
+/// is set.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WorkerSource {
-    /// Name of the queue to poll
+    /// Name of the queue to poll. Empty when `queues` is used instead.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub queue: String,
+    /// Persisted queues sharing the worker's slots, with their weights.
+    /// Items are taken from each in proportion to its weight.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub queues: BTreeMap<String, u32>,
 }
 
-/// Handler configuration for a worker
+impl WorkerSource {
+    /// Source queues with their weights; a single `queue` has weight 1.
+    pub fn weighted(&self) -> Vec<(String, u32)> {
+        if self.queues.is_empty() {
+            vec![(self.queue.clone(), 1)]
+        } else {
+            self.queues.iter().map(|(q, w)| (q.clone(), *w)).collect()
+        }
+    }
+
+    /// Whether the worker consumes from `queue`.
+    pub fn consumes(&self, queue: &str) -> bool {
+        if self.queues.is_empty() {
+            self.queue == queue
+        } else {
+            self.queues.contains_key(queue)
+        }
+    }
+}
+
+/// Handler configuration for a worker. Exactly one of `job` and `agent` is set.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WorkerHandler {
-    /// Name of the job to dispatch items to
+    /// Name of the job to dispatch items to. Empty when `agent` is used instead.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub job: String,
+    /// Name of an agent to run standalone for each item
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent: Option<String>,
 }
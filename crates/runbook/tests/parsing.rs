@@ -11,22 +11,52 @@ use oj_runbook::{parse_runbook, parse_runbook_with_format, Format, ParseError, R
 mod action_trigger;
 #[path = "parsing/agents.rs"]
 mod agents;
+#[path = "parsing/approval.rs"]
+mod approval;
+#[path = "parsing/budget.rs"]
+mod budget;
 #[path = "parsing/cron.rs"]
 mod cron;
+#[path = "parsing/dependencies.rs"]
+mod dependencies;
 #[path = "parsing/epic.rs"]
 mod epic;
 #[path = "parsing/errors.rs"]
 mod errors;
 #[path = "parsing/formats.rs"]
 mod formats;
+#[path = "parsing/git_trigger.rs"]
+mod git_trigger;
+#[path = "parsing/locks.rs"]
+mod locks;
+#[path = "parsing/parallel.rs"]
+mod parallel;
 #[path = "parsing/prime.rs"]
 mod prime;
 #[path = "parsing/queues.rs"]
 mod queues;
 #[path = "parsing/references.rs"]
 mod references;
+#[path = "parsing/retry.rs"]
+mod retry;
+#[path = "parsing/step_outputs.rs"]
+mod step_outputs;
+#[path = "parsing/sub_job.rs"]
+mod sub_job;
 #[path = "parsing/template_refs.rs"]
 mod template_refs;
+#[path = "parsing/timeouts.rs"]
+mod timeouts;
+#[path = "parsing/transitions.rs"]
+mod transitions;
+#[path = "parsing/triggers.rs"]
+mod triggers;
+#[path = "parsing/watch.rs"]
+mod watch;
+#[path = "parsing/webhook.rs"]
+mod webhook;
+#[path = "parsing/workers.rs"]
+mod workers;
 
 // ---------------------------------------------------------------------------
 // Shared test helpers
@@ -0,0 +1,93 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+// ============================================================================
+// Approval Steps
+// ============================================================================
+
+use oj_runbook::ApprovalDef;
+
+#[test]
+fn approval_step_parses() {
+    let hcl = r#"
+job "release" {
+  step "signoff" {
+    run = {
+      approval = {
+        prompt  = "Staging verified for ${var.version}?"
+        options = ["ship", "hold"]
+        fields  = ["reason"]
+      }
+    }
+    on_done = [
+      { if = "${steps.signoff.outputs.choice} == ship", step = "tag" },
+    ]
+  }
+  step "tag" {
+    run = "git tag ${var.version}"
+  }
+}
+"#;
+    let runbook = super::parse_hcl(hcl);
+    let step = runbook
+        .get_job("release")
+        .unwrap()
+        .get_step("signoff")
+        .unwrap();
+    assert!(step.run.is_approval());
+    assert_eq!(
+        step.run.approval(),
+        Some(&ApprovalDef {
+            prompt: "Staging verified for ${var.version}?".to_string(),
+            options: vec!["ship".to_string(), "hold".to_string()],
+            fields: vec!["reason".to_string()],
+        })
+    );
+}
+
+#[test]
+fn approval_options_default_to_approve_and_deny() {
+    let toml = r#"
+[[job.release.step]]
+name = "signoff"
+run = { approval = { prompt = "Ship it?" } }
+"#;
+    let runbook = oj_runbook::parse_runbook(toml).unwrap();
+    let step = runbook
+        .get_job("release")
+        .unwrap()
+        .get_step("signoff")
+        .unwrap();
+    assert_eq!(
+        step.run.approval().unwrap().options,
+        vec!["approve".to_string(), "deny".to_string()]
+    );
+}
+
+#[yare::parameterized(
+    empty_prompt    = { r#"{ prompt = " " }"#, "approval prompt is empty" },
+    no_options      = { r#"{ prompt = "p", options = [] }"#, "at least one option" },
+    reserved_cancel = { r#"{ prompt = "p", options = ["ship", "Cancel"] }"#, "'cancel' is reserved" },
+    duplicate       = { r#"{ prompt = "p", options = ["ship", "ship"] }"#, "duplicate option 'ship'" },
+    bad_field       = { r#"{ prompt = "p", fields = ["why not"] }"#, "invalid field name 'why not'" },
+    choice_field    = { r#"{ prompt = "p", fields = ["choice"] }"#, "'choice' is reserved" },
+    unknown_step    = { r#"{ prompt = "${steps.nope.output}" }"#, "unknown step 'nope'" },
+)]
+fn error_invalid_approval(approval: &str, message: &str) {
+    let toml = format!(
+        "[[job.release.step]]\nname = \"signoff\"\nrun = {{ approval = {} }}\n",
+        approval
+    );
+    crate::assert_toml_err(&toml, &["job.release.step[0](signoff).run", message]);
+}
+
+#[test]
+fn error_approval_in_command() {
+    super::assert_toml_err(
+        r#"
+[command.ship]
+run = { approval = { prompt = "Ship it?" } }
+"#,
+        &["command.ship.run", "approval is only valid in job steps"],
+    );
+}
@@ -0,0 +1,113 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+// ============================================================================
+// Agent and Job Budgets
+// ============================================================================
+
+use oj_runbook::{AgentAction, BudgetDef};
+
+#[test]
+fn agent_and_job_budgets_parse() {
+    let hcl = r#"
+agent "coder" {
+  run       = "claude"
+  prompt    = "Build it"
+  on_budget = { action = "nudge", message = "Finish up." }
+
+  budget {
+    tokens = 2000000
+    usd    = 25
+  }
+}
+
+job "build" {
+  budget {
+    tokens = 5000000
+  }
+
+  step "code" {
+    run = { agent = "coder" }
+  }
+}
+"#;
+    let runbook = super::parse_hcl(hcl);
+    let agent = runbook.get_agent("coder").unwrap();
+    assert_eq!(
+        agent.budget,
+        Some(BudgetDef {
+            tokens: Some(2_000_000),
+            usd: Some(25.0),
+        })
+    );
+    assert_eq!(agent.on_budget.action(), &AgentAction::Nudge);
+    assert_eq!(agent.on_budget.message(), Some("Finish up."));
+    assert_eq!(
+        runbook.get_job("build").unwrap().budget,
+        Some(BudgetDef {
+            tokens: Some(5_000_000),
+            usd: None,
+        })
+    );
+}
+
+#[test]
+fn on_budget_defaults_to_escalate() {
+    let toml = r#"
+[agent.coder]
+run = "claude"
+budget = { tokens = 1000 }
+"#;
+    let runbook = oj_runbook::parse_runbook(toml).unwrap();
+    let agent = runbook.get_agent("coder").unwrap();
+    assert_eq!(agent.on_budget.action(), &AgentAction::Escalate);
+}
+
+#[test]
+fn error_on_budget_resume() {
+    super::assert_toml_err(
+        r#"
+[agent.coder]
+run = "claude"
+on_budget = "resume"
+"#,
+        &["not valid for on_budget", "keeps spending"],
+    );
+}
+
+#[test]
+fn error_empty_or_zero_budget() {
+    super::assert_toml_err(
+        r#"
+[agent.coder]
+run = "claude"
+budget = {}
+"#,
+        &["agent.coder.budget", "needs a tokens or usd limit"],
+    );
+    super::assert_hcl_err(
+        r#"
+job "build" {
+  budget {
+    tokens = 0
+  }
+  step "go" {
+    run = "make"
+  }
+}
+"#,
+        &["job.build.budget", "tokens must be greater than zero"],
+    );
+}
+
+#[test]
+fn error_unknown_budget_field() {
+    super::assert_toml_err(
+        r#"
+[agent.coder]
+run = "claude"
+budget = { dollars = 5 }
+"#,
+        &["unknown field `dollars`"],
+    );
+}
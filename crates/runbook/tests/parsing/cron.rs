@@ -134,3 +134,135 @@ agent "doctor" {
 "#;
     super::assert_hcl_err(hcl, &["max_concurrency must be >= 1"]);
 }
+
+#[test]
+fn hcl_cron_schedule_with_timezone() {
+    let hcl = r#"
+job "triage" {
+  step "run" { run = "echo triage" }
+}
+cron "nightly" {
+  schedule = "0 9 * * 1-5"
+  timezone = "America/New_York"
+  run      = { job = "triage" }
+}
+"#;
+    let cron = &super::parse_hcl(hcl).crons["nightly"];
+    assert_eq!(cron.schedule.as_deref(), Some("0 9 * * 1-5"));
+    assert_eq!(cron.timezone.as_deref(), Some("America/New_York"));
+    assert!(cron.interval.is_empty());
+}
+
+#[test]
+fn error_cron_invalid_schedule() {
+    super::assert_toml_err(
+        r#"
+[cron.nightly]
+schedule = "0 25 * * *"
+run = { job = "triage" }
+"#,
+        &[
+            "cron.nightly.schedule",
+            "hour value 25 is out of range 0-23",
+        ],
+    );
+}
+
+#[test]
+fn error_cron_unknown_timezone() {
+    super::assert_toml_err(
+        r#"
+[cron.nightly]
+schedule = "0 9 * * *"
+timezone = "Mars/Olympus"
+run = { job = "triage" }
+"#,
+        &["cron.nightly.timezone", "unknown timezone 'Mars/Olympus'"],
+    );
+}
+
+#[test]
+fn error_cron_interval_and_schedule() {
+    super::assert_toml_err(
+        r#"
+[cron.nightly]
+interval = "24h"
+schedule = "0 9 * * *"
+run = { job = "triage" }
+"#,
+        &["cron.nightly", "either interval or schedule, not both"],
+    );
+    super::assert_toml_err(
+        r#"
+[cron.nightly]
+run = { job = "triage" }
+"#,
+        &["cron.nightly", "needs an interval or a schedule"],
+    );
+}
+
+#[test]
+fn error_cron_timezone_without_schedule() {
+    super::assert_toml_err(
+        r#"
+[cron.nightly]
+interval = "24h"
+timezone = "UTC"
+run = { job = "triage" }
+"#,
+        &["cron.nightly.timezone", "only applies to schedule"],
+    );
+}
+
+#[test]
+fn hcl_cron_catch_up() {
+    let hcl = |extra: &str| {
+        format!(
+            r#"
+job "cleanup" {{
+  step "run" {{ run = "echo cleanup" }}
+}}
+cron "janitor" {{
+  interval = "6h"
+  {extra}
+  run      = {{ job = "cleanup" }}
+}}
+"#
+        )
+    };
+    let cron = &super::parse_hcl(&hcl("catch_up = \"once\"")).crons["janitor"];
+    assert_eq!(cron.catch_up, oj_runbook::CatchUp::Once);
+    let cron = &super::parse_hcl(&hcl("")).crons["janitor"];
+    assert_eq!(cron.catch_up, oj_runbook::CatchUp::None);
+}
+
+#[test]
+fn error_cron_unknown_catch_up() {
+    super::assert_toml_err(
+        r#"
+[cron.janitor]
+interval = "6h"
+catch_up = "latest"
+run = { job = "cleanup" }
+"#,
+        &["unknown variant `latest`", "none", "once", "all"],
+    );
+}
+
+#[test]
+fn error_cron_catch_up_all_without_concurrency() {
+    super::assert_toml_err(
+        r#"
+[job.cleanup]
+[[job.cleanup.step]]
+name = "run"
+run = "echo cleanup"
+
+[cron.janitor]
+interval = "6h"
+catch_up = "all"
+run = { job = "cleanup" }
+"#,
+        &["cron.janitor.catch_up", "needs concurrency greater than 1"],
+    );
+}
@@ -0,0 +1,68 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use oj_runbook::DependencyFailure;
+
+// ============================================================================
+// Job Dependencies
+// ============================================================================
+
+#[test]
+fn depends_on_and_failure_policy() {
+    let hcl = r#"
+job "release-notes" {
+  depends_on         = ["${var.builds}", "0a1b2c3d:any"]
+  on_dependency_fail = "cancel"
+
+  step "write" {
+    run = "make release-notes"
+  }
+}
+
+job "build" {
+  step "build" {
+    run = "make"
+  }
+}
+"#;
+    let runbook = super::parse_hcl(hcl);
+    let job = runbook.get_job("release-notes").unwrap();
+    assert_eq!(job.depends_on, vec!["${var.builds}", "0a1b2c3d:any"]);
+    assert_eq!(job.on_dependency_fail, DependencyFailure::Cancel);
+
+    let build = runbook.get_job("build").unwrap();
+    assert!(build.depends_on.is_empty());
+    assert_eq!(build.on_dependency_fail, DependencyFailure::Fail);
+}
+
+#[test]
+fn error_unknown_dependency_condition() {
+    super::assert_hcl_err(
+        r#"
+job "deploy" {
+  depends_on = ["0a1b2c3d, 4e5f6a7b:succeeded"]
+
+  step "deploy" {
+    run = "make deploy"
+  }
+}
+"#,
+        &["job.deploy.depends_on[0]", "unknown condition 'succeeded'"],
+    );
+}
+
+#[test]
+fn error_unknown_dependency_failure_policy() {
+    super::assert_hcl_err(
+        r#"
+job "deploy" {
+  on_dependency_fail = "ignore"
+
+  step "deploy" {
+    run = "make deploy"
+  }
+}
+"#,
+        &["ignore"],
+    );
+}
@@ -37,7 +37,7 @@ fn job() {
 
     assert!(job.steps[0].run.is_shell());
     assert_eq!(
-        job.steps[0].on_done.as_ref().map(|t| t.step_name()),
+        job.steps[0].on_done.as_ref().and_then(|t| t.step_name()),
         Some("decompose")
     );
     assert!(job.steps[1].run.is_agent());
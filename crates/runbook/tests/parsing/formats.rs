@@ -73,7 +73,7 @@ fn json_sample_runbook() {
 
     let job = &runbook.jobs["build"];
     assert_eq!(
-        job.steps[2].on_done.as_ref().map(|t| t.step_name()),
+        job.steps[2].on_done.as_ref().and_then(|t| t.step_name()),
         Some("done")
     );
     assert_eq!(
@@ -100,7 +100,7 @@ fn hcl_sample_runbook() {
 
     let job = &runbook.jobs["build"];
     assert_eq!(
-        job.steps[2].on_done.as_ref().map(|t| t.step_name()),
+        job.steps[2].on_done.as_ref().and_then(|t| t.step_name()),
         Some("done")
     );
     assert_eq!(
@@ -142,7 +142,7 @@ job "deploy" {
     assert_eq!(job.steps[0].name, "build");
     assert_eq!(job.steps[1].name, "test");
     assert_eq!(
-        job.steps[1].on_done.as_ref().map(|t| t.step_name()),
+        job.steps[1].on_done.as_ref().and_then(|t| t.step_name()),
         Some("deploy")
     );
     assert_eq!(job.steps[2].name, "deploy");
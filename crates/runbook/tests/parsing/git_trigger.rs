@@ -0,0 +1,75 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use oj_runbook::parse_runbook;
+
+const JOBS: &str = r#"
+job "ci" {
+  step "run" { run = "git diff ${git.old} ${git.new}" }
+}
+"#;
+
+#[test]
+fn hcl_git_trigger_valid() {
+    let hcl = format!(
+        r#"{JOBS}
+git_trigger "features" {{
+  ref = "refs/heads/feature/*"
+  run = {{ job = "ci" }}
+}}
+"#
+    );
+    let trigger = &super::parse_hcl(&hcl).git_triggers["features"];
+    assert_eq!(trigger.name, "features");
+    assert_eq!(trigger.ref_pattern, "refs/heads/feature/*");
+    assert_eq!(trigger.run.job_name(), Some("ci"));
+}
+
+#[test]
+fn toml_git_trigger_valid() {
+    let toml = r#"
+[job.ci]
+[[job.ci.step]]
+name = "run"
+run = "echo ${git.ref}"
+
+[git_trigger.main]
+ref = "refs/heads/main"
+run = { job = "ci" }
+"#;
+    let runbook = parse_runbook(toml).unwrap();
+    assert_eq!(
+        runbook.get_git_trigger("main").unwrap().ref_pattern,
+        "refs/heads/main"
+    );
+}
+
+#[yare::parameterized(
+    short_ref = {
+        "git_trigger \"main\" {\n  ref = \"main\"\n  run = { job = \"ci\" }\n}",
+        &["git_trigger.main.ref", "full ref name"]
+    },
+    bare_refs = {
+        "git_trigger \"main\" {\n  ref = \"refs/\"\n  run = { job = \"ci\" }\n}",
+        &["git_trigger.main.ref", "full ref name"]
+    },
+    whitespace = {
+        "git_trigger \"main\" {\n  ref = \"refs/heads/my branch\"\n  run = { job = \"ci\" }\n}",
+        &["git_trigger.main.ref", "whitespace"]
+    },
+    bad_glob = {
+        "git_trigger \"main\" {\n  ref = \"refs/heads/[main\"\n  run = { job = \"ci\" }\n}",
+        &["git_trigger.main.ref", "invalid glob"]
+    },
+    unknown_job = {
+        "git_trigger \"main\" {\n  ref = \"refs/heads/main\"\n  run = { job = \"ship\" }\n}",
+        &["git_trigger.main.run", "unknown job 'ship'"]
+    },
+    agent_run = {
+        "git_trigger \"main\" {\n  ref = \"refs/heads/main\"\n  run = { agent = \"reviewer\" }\n}",
+        &["git trigger run must reference a job"]
+    },
+)]
+fn error_invalid_git_trigger(block: &str, fragments: &[&str]) {
+    crate::assert_hcl_err(&format!("{JOBS}\n{block}"), fragments);
+}
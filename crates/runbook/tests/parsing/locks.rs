@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use oj_runbook::ResourceRef;
+
+// ============================================================================
+// Lock / Semaphore Blocks
+// ============================================================================
+
+#[test]
+fn lock_and_semaphore_blocks() {
+    let hcl = r#"
+lock "main-branch" {}
+
+semaphore "gpu" {
+  permits = 2
+}
+
+job "merge" {
+  step "rebase" {
+    run     = "git rebase main"
+    acquire = ["lock.main-branch", "semaphore.gpu"]
+  }
+}
+"#;
+    let runbook = super::parse_hcl(hcl);
+    assert_eq!(runbook.locks["main-branch"].name, "main-branch");
+    assert_eq!(runbook.semaphores["gpu"].permits, 2);
+    assert_eq!(
+        runbook.get_job("merge").unwrap().steps[0].acquire,
+        vec!["lock.main-branch", "semaphore.gpu"]
+    );
+    assert_eq!(runbook.resource_permits("lock.main-branch"), Some(1));
+    assert_eq!(runbook.resource_permits("semaphore.gpu"), Some(2));
+    assert_eq!(runbook.resource_permits("semaphore.cpu"), None);
+}
+
+#[test]
+fn resource_ref_parse() {
+    assert_eq!(ResourceRef::parse("lock.a"), Some(ResourceRef::Lock("a")));
+    assert_eq!(
+        ResourceRef::parse("semaphore.b"),
+        Some(ResourceRef::Semaphore("b"))
+    );
+    assert_eq!(ResourceRef::parse("lock."), None);
+    assert_eq!(ResourceRef::parse("mutex.a"), None);
+}
+
+#[test]
+fn error_unknown_resource() {
+    super::assert_hcl_err(
+        r#"
+lock "main-branch" {}
+
+job "merge" {
+  step "rebase" {
+    run     = "git rebase main"
+    acquire = ["lock.other"]
+  }
+}
+"#,
+        &[
+            "references unknown resource 'lock.other'",
+            "lock.main-branch",
+        ],
+    );
+}
+
+#[test]
+fn error_invalid_resource_reference() {
+    super::assert_hcl_err(
+        r#"
+job "merge" {
+  step "rebase" {
+    run     = "git rebase main"
+    acquire = ["main-branch"]
+  }
+}
+"#,
+        &["invalid resource 'main-branch'"],
+    );
+}
+
+#[test]
+fn error_duplicate_acquire() {
+    super::assert_hcl_err(
+        r#"
+lock "main-branch" {}
+
+job "merge" {
+  step "rebase" {
+    run     = "git rebase main"
+    acquire = ["lock.main-branch", "lock.main-branch"]
+  }
+}
+"#,
+        &["duplicate resource 'lock.main-branch'"],
+    );
+}
+
+#[test]
+fn error_semaphore_zero_permits() {
+    super::assert_hcl_err(
+        "semaphore \"gpu\" {\n  permits = 0\n}",
+        &["semaphore.gpu.permits"],
+    );
+}
@@ -0,0 +1,156 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use oj_runbook::RunDirective;
+
+// ============================================================================
+// Parallel Fan-out Steps
+// ============================================================================
+
+#[test]
+fn parallel_step_lists_branches() {
+    let hcl = r#"
+job "review" {
+  step "checks" {
+    run     = { parallel = ["lint", "test"] }
+    on_done = { step = "merge" }
+  }
+  step "lint" {
+    run = "make lint"
+  }
+  step "test" {
+    run = "make test"
+  }
+  step "merge" {
+    run = "git merge"
+  }
+}
+"#;
+    let runbook = super::parse_hcl(hcl);
+    let step = &runbook.get_job("review").unwrap().steps[0];
+    assert!(step.run.is_parallel());
+    assert_eq!(
+        step.run,
+        RunDirective::Parallel {
+            parallel: vec!["lint".to_string(), "test".to_string()]
+        }
+    );
+    assert_eq!(
+        step.run.parallel_steps(),
+        Some(&["lint".to_string(), "test".to_string()][..])
+    );
+}
+
+#[test]
+fn parallel_step_toml() {
+    let toml = r#"
+[[job.review.step]]
+name = "checks"
+run = { parallel = ["lint"] }
+
+[[job.review.step]]
+name = "lint"
+run = { agent = "linter" }
+
+[agent.linter]
+run = "claude"
+prompt = "Lint the code"
+"#;
+    let runbook = oj_runbook::parse_runbook(toml).unwrap();
+    let steps = &runbook.get_job("review").unwrap().steps;
+    assert_eq!(steps[0].run.parallel_steps().unwrap(), ["lint"]);
+}
+
+#[test]
+fn error_parallel_unknown_step() {
+    super::assert_hcl_err(
+        r#"
+job "review" {
+  step "checks" {
+    run = { parallel = ["lint", "docs"] }
+  }
+  step "lint" {
+    run = "make lint"
+  }
+}
+"#,
+        &["references unknown step 'docs'", "checks"],
+    );
+}
+
+#[test]
+fn error_parallel_duplicate_step() {
+    super::assert_hcl_err(
+        r#"
+job "review" {
+  step "checks" {
+    run = { parallel = ["lint", "lint"] }
+  }
+  step "lint" {
+    run = "make lint"
+  }
+}
+"#,
+        &["duplicate step 'lint'"],
+    );
+}
+
+#[test]
+fn error_parallel_self_reference() {
+    super::assert_hcl_err(
+        r#"
+job "review" {
+  step "checks" {
+    run = { parallel = ["checks"] }
+  }
+}
+"#,
+        &["cannot run itself in parallel"],
+    );
+}
+
+#[test]
+fn error_parallel_nested() {
+    super::assert_hcl_err(
+        r#"
+job "review" {
+  step "checks" {
+    run = { parallel = ["more"] }
+  }
+  step "more" {
+    run = { parallel = ["lint"] }
+  }
+  step "lint" {
+    run = "make lint"
+  }
+}
+"#,
+        &["fan-outs cannot nest"],
+    );
+}
+
+#[test]
+fn error_parallel_empty() {
+    super::assert_hcl_err(
+        r#"
+job "review" {
+  step "checks" {
+    run = { parallel = [] }
+  }
+}
+"#,
+        &["parallel requires at least one step"],
+    );
+}
+
+#[test]
+fn error_parallel_in_command() {
+    super::assert_hcl_err(
+        r#"
+command "review" {
+  run = { parallel = ["lint"] }
+}
+"#,
+        &["command.review.run", "only valid in job steps"],
+    );
+}
@@ -1,7 +1,8 @@
 // SPDX-License-Identifier: BUSL-1.1
 // Copyright (c) 2026 Alfred Jean LLC
 
-use oj_runbook::QueueType;
+use oj_runbook::{DedupPolicy, ExpirePolicy, QueueType};
+use std::collections::HashMap;
 
 // ============================================================================
 // Queue Type
@@ -150,3 +151,181 @@ fn error_persisted_with_poll() {
         &["persisted queue must not have 'poll' field"],
     );
 }
+
+// ============================================================================
+// Queue Priority
+// ============================================================================
+
+#[test]
+fn persisted_queue_priority_from_item_data() {
+    let hcl = r#"
+queue "bugs" {
+  type     = "persisted"
+  vars     = ["title", "severity"]
+  defaults = { severity = "0" }
+  priority = "${item.severity}"
+}
+"#;
+    let queue = &super::parse_hcl(hcl).queues["bugs"];
+    let item = |severity: &str| {
+        HashMap::from([
+            ("title".to_string(), "crash".to_string()),
+            ("severity".to_string(), severity.to_string()),
+        ])
+    };
+    assert_eq!(queue.item_priority(&item("3")), Ok(3));
+    assert_eq!(queue.item_priority(&item("-1")), Ok(-1));
+    assert_eq!(
+        queue.item_priority(&item("high")),
+        Err("priority 'high' is not an integer".to_string())
+    );
+}
+
+#[test]
+fn queue_priority_defaults_to_zero() {
+    let hcl = "queue \"items\" {\n  type = \"persisted\"\n  vars = [\"branch\"]\n}";
+    let queue = &super::parse_hcl(hcl).queues["items"];
+    assert_eq!(queue.item_priority(&HashMap::new()), Ok(0));
+}
+
+#[test]
+fn error_queue_priority_not_an_integer() {
+    super::assert_hcl_err(
+        "queue \"items\" {\n  type = \"persisted\"\n  vars = [\"branch\"]\n  priority = \"high\"\n}",
+        &["queue.items.priority", "must be an integer or a template"],
+    );
+}
+
+#[test]
+fn error_external_with_priority() {
+    super::assert_hcl_err(
+        "queue \"items\" {\n  list = \"echo '[]'\"\n  take = \"echo ok\"\n  priority = \"1\"\n}",
+        &["external queue must not have 'priority' field"],
+    );
+}
+
+#[test]
+fn persisted_queue_dedup_fields() {
+    let hcl = r#"
+queue "issues" {
+  type         = "persisted"
+  vars         = ["issue", "title"]
+  dedup_key    = "gh-${item.issue}"
+  dedup_policy = "merge"
+  dedup_window = "1h"
+}
+"#;
+    let queue = &super::parse_hcl(hcl).queues["issues"];
+    assert_eq!(queue.dedup_policy, DedupPolicy::Merge);
+    assert_eq!(queue.dedup_window.as_deref(), Some("1h"));
+    let item = HashMap::from([
+        ("issue".to_string(), "42".to_string()),
+        ("title".to_string(), "crash".to_string()),
+    ]);
+    assert_eq!(queue.item_dedup_key(&item), Some("gh-42".to_string()));
+}
+
+#[test]
+fn queue_without_dedup_key_has_no_key() {
+    let hcl = "queue \"items\" {\n  type = \"persisted\"\n  vars = [\"branch\"]\n}";
+    let queue = &super::parse_hcl(hcl).queues["items"];
+    assert_eq!(queue.dedup_policy, DedupPolicy::Reject);
+    assert_eq!(queue.item_dedup_key(&HashMap::new()), None);
+}
+
+#[test]
+fn error_queue_dedup_window_invalid() {
+    super::assert_hcl_err(
+        "queue \"items\" {\n  type = \"persisted\"\n  vars = [\"branch\"]\n  dedup_window = \"soon\"\n}",
+        &["queue.items.dedup_window"],
+    );
+}
+
+#[test]
+fn error_queue_unknown_dedup_policy() {
+    super::assert_hcl_err(
+        "queue \"items\" {\n  type = \"persisted\"\n  vars = [\"branch\"]\n  dedup_policy = \"replace\"\n}",
+        &["unknown variant `replace`"],
+    );
+}
+
+#[test]
+fn error_external_with_dedup_key() {
+    super::assert_hcl_err(
+        "queue \"items\" {\n  list = \"echo '[]'\"\n  take = \"echo ok\"\n  dedup_key = \"${item.id}\"\n}",
+        &["external queue must not have dedup fields"],
+    );
+}
+
+#[test]
+fn persisted_queue_ttl() {
+    let hcl = r#"
+queue "inbox" {
+  type      = "persisted"
+  vars      = ["finding"]
+  ttl       = "7d"
+  on_expire = "drop"
+}
+"#;
+    let queue = &super::parse_hcl(hcl).queues["inbox"];
+    assert_eq!(queue.ttl.as_deref(), Some("7d"));
+    assert_eq!(queue.on_expire, ExpirePolicy::Drop);
+}
+
+#[test]
+fn queue_on_expire_defaults_to_expire() {
+    let hcl =
+        "queue \"inbox\" {\n  type = \"persisted\"\n  vars = [\"finding\"]\n  ttl = \"12h\"\n}";
+    let queue = &super::parse_hcl(hcl).queues["inbox"];
+    assert_eq!(queue.on_expire, ExpirePolicy::Expire);
+}
+
+#[yare::parameterized(
+    bad_suffix = { "7w", "unknown duration suffix" },
+    zero       = { "0d", "ttl must be greater than zero" },
+)]
+fn error_queue_ttl_invalid(ttl: &str, fragment: &str) {
+    crate::assert_hcl_err(
+        &format!(
+            "queue \"inbox\" {{\n  type = \"persisted\"\n  vars = [\"finding\"]\n  ttl = \"{ttl}\"\n}}"
+        ),
+        &["queue.inbox.ttl", fragment],
+    );
+}
+
+#[test]
+fn error_external_with_ttl() {
+    super::assert_hcl_err(
+        "queue \"items\" {\n  list = \"echo '[]'\"\n  take = \"echo ok\"\n  ttl = \"1d\"\n}",
+        &["external queue must not have 'ttl' field"],
+    );
+}
+
+#[test]
+fn persisted_queue_lease() {
+    let hcl =
+        "queue \"inbox\" {\n  type = \"persisted\"\n  vars = [\"finding\"]\n  lease = \"2h\"\n}";
+    let queue = &super::parse_hcl(hcl).queues["inbox"];
+    assert_eq!(queue.lease.as_deref(), Some("2h"));
+}
+
+#[yare::parameterized(
+    bad_suffix = { "2w", "unknown duration suffix" },
+    zero       = { "0h", "lease must be greater than zero" },
+)]
+fn error_queue_lease_invalid(lease: &str, fragment: &str) {
+    crate::assert_hcl_err(
+        &format!(
+            "queue \"inbox\" {{\n  type = \"persisted\"\n  vars = [\"finding\"]\n  lease = \"{lease}\"\n}}"
+        ),
+        &["queue.inbox.lease", fragment],
+    );
+}
+
+#[test]
+fn error_external_with_lease() {
+    super::assert_hcl_err(
+        "queue \"items\" {\n  list = \"echo '[]'\"\n  take = \"echo ok\"\n  lease = \"2h\"\n}",
+        &["external queue must not have 'lease' field"],
+    );
+}
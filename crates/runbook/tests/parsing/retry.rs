@@ -0,0 +1,122 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use oj_runbook::Backoff;
+
+// ============================================================================
+// Step and Queue Retry Policies
+// ============================================================================
+
+#[test]
+fn step_retry_with_exponential_backoff() {
+    let hcl = r#"
+job "deps" {
+  step "fetch" {
+    run = "npm ci"
+    retry {
+      attempts = 5
+      backoff  = "exponential"
+      base     = "10s"
+      max      = "10m"
+      jitter   = 0.2
+    }
+  }
+}
+"#;
+    let runbook = super::parse_hcl(hcl);
+    let retry = runbook.get_job("deps").unwrap().steps[0]
+        .retry
+        .clone()
+        .unwrap();
+    assert_eq!(retry.attempts, 5);
+    assert_eq!(retry.backoff, Backoff::Exponential);
+    assert_eq!(retry.base, "10s");
+    assert_eq!(retry.multiplier, 2.0);
+    assert_eq!(retry.max.as_deref(), Some("10m"));
+    assert_eq!(retry.jitter, 0.2);
+}
+
+#[test]
+fn queue_retry_accepts_cooldown_as_base() {
+    let hcl = r#"
+queue "bugs" {
+  type = "persisted"
+  vars = ["title"]
+  retry {
+    attempts = 3
+    cooldown = "30s"
+  }
+}
+"#;
+    let runbook = super::parse_hcl(hcl);
+    let retry = runbook.get_queue("bugs").unwrap().retry.clone().unwrap();
+    assert_eq!(retry.base, "30s");
+    assert_eq!(retry.backoff, Backoff::Fixed);
+}
+
+#[test]
+fn error_retry_on_agent_step() {
+    super::assert_hcl_err(
+        r#"
+agent "fixer" {
+  run    = "claude"
+  prompt = "Fix it"
+}
+
+job "fix" {
+  step "fix" {
+    run = { agent = "fixer" }
+    retry {
+      attempts = 2
+    }
+  }
+}
+"#,
+        &["step[0](fix).retry", "only valid on shell steps"],
+    );
+}
+
+#[test]
+fn error_invalid_retry_fields() {
+    super::assert_hcl_err(
+        r#"
+job "deps" {
+  step "fetch" {
+    run = "npm ci"
+    retry {
+      attempts = 3
+      jitter   = 1.5
+    }
+  }
+}
+"#,
+        &["retry.jitter", "between 0 and 1"],
+    );
+    super::assert_hcl_err(
+        r#"
+job "deps" {
+  step "fetch" {
+    run = "npm ci"
+    retry {
+      attempts   = 3
+      multiplier = 0.5
+    }
+  }
+}
+"#,
+        &["retry.multiplier", "at least 1"],
+    );
+    super::assert_hcl_err(
+        r#"
+queue "bugs" {
+  type = "persisted"
+  vars = ["title"]
+  retry {
+    attempts = 3
+    max      = "soon"
+  }
+}
+"#,
+        &["queue.bugs.retry.max"],
+    );
+}
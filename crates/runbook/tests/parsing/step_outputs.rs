@@ -0,0 +1,123 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+// ============================================================================
+// Step Output References
+// ============================================================================
+
+#[test]
+fn step_output_refs_parse() {
+    let hcl = r#"
+job "release" {
+  step "build" {
+    run     = "git rev-parse HEAD"
+    on_done = { step = "tag" }
+  }
+  step "tag" {
+    run = "git tag ${steps.build.outputs.version} ${steps.build.output}"
+  }
+}
+"#;
+    let runbook = super::parse_hcl(hcl);
+    assert_eq!(runbook.get_job("release").unwrap().steps.len(), 2);
+}
+
+#[test]
+fn error_step_output_unknown_step() {
+    super::assert_hcl_err(
+        r#"
+job "release" {
+  step "tag" {
+    run = "git tag ${steps.biuld.output}"
+  }
+}
+"#,
+        &[
+            "job.release.step[0](tag).run",
+            "references unknown step 'biuld'",
+            "available steps: tag",
+        ],
+    );
+}
+
+#[test]
+fn error_step_output_malformed_ref() {
+    super::assert_hcl_err(
+        r#"
+job "release" {
+  step "build" {
+    run     = "make"
+    on_done = { step = "tag" }
+  }
+  step "tag" {
+    run = "git tag ${steps.build.stdout}"
+  }
+}
+"#,
+        &["invalid step output reference ${steps.build.stdout}"],
+    );
+}
+
+#[test]
+fn error_step_output_in_locals() {
+    super::assert_hcl_err(
+        r#"
+job "release" {
+  locals {
+    sha = "${steps.build.output}"
+  }
+  step "build" {
+    run = "make"
+  }
+}
+"#,
+        &[
+            "job.release.locals.sha",
+            "step outputs are not available in locals",
+        ],
+    );
+}
+
+#[test]
+fn error_step_output_of_parallel_branch() {
+    super::assert_hcl_err(
+        r#"
+job "release" {
+  step "checks" {
+    run     = { parallel = ["lint", "test"] }
+    on_done = { step = "tag" }
+  }
+  step "lint" { run = "make lint" }
+  step "test" { run = "make test" }
+  step "tag" {
+    run = "git tag ${steps.lint.output}"
+  }
+}
+"#,
+        &[
+            "job.release.step[3](tag).run",
+            "references step 'lint', which runs as a parallel branch and publishes no outputs",
+        ],
+    );
+}
+
+#[test]
+fn error_step_output_of_sub_job() {
+    super::assert_hcl_err(
+        r#"
+job "build" {
+  step "compile" { run = "make" }
+}
+job "release" {
+  step "build" {
+    run     = { job = "build" }
+    on_done = { step = "tag" }
+  }
+  step "tag" {
+    run = "git tag ${steps.build.outputs.version}"
+  }
+}
+"#,
+        &["references step 'build', which runs a sub-job and publishes no outputs"],
+    );
+}
@@ -0,0 +1,138 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use oj_runbook::RunDirective;
+
+// ============================================================================
+// Sub-job Steps
+// ============================================================================
+
+#[test]
+fn sub_job_step_with_vars() {
+    let hcl = r#"
+job "ship" {
+  vars = ["branch"]
+
+  step "merge" {
+    run = { job = "merge", vars = { branch = "${var.branch}" } }
+  }
+}
+
+job "merge" {
+  vars     = ["branch", "remote"]
+  defaults = { remote = "origin" }
+
+  step "rebase" {
+    run = "git rebase ${var.remote}/${var.branch}"
+  }
+}
+"#;
+    let runbook = super::parse_hcl(hcl);
+    let step = &runbook.get_job("ship").unwrap().steps[0];
+    assert_eq!(step.run.job_name(), Some("merge"));
+    let RunDirective::Job { vars, .. } = &step.run else {
+        panic!("expected job directive");
+    };
+    assert_eq!(
+        vars.get("branch").map(String::as_str),
+        Some("${var.branch}")
+    );
+}
+
+#[test]
+fn sub_job_step_without_vars_keeps_plain_job_form() {
+    let hcl = r#"
+job "ship" {
+  step "cleanup" {
+    run = { job = "cleanup" }
+  }
+}
+
+job "cleanup" {
+  step "prune" {
+    run = "git worktree prune"
+  }
+}
+"#;
+    let runbook = super::parse_hcl(hcl);
+    let step = &runbook.get_job("ship").unwrap().steps[0];
+    let json = serde_json::to_value(&step.run).unwrap();
+    assert_eq!(json, serde_json::json!({ "job": "cleanup" }));
+}
+
+#[test]
+fn error_sub_job_missing_required_var() {
+    super::assert_hcl_err(
+        r#"
+job "ship" {
+  step "merge" {
+    run = { job = "merge", vars = { remote = "upstream" } }
+  }
+}
+
+job "merge" {
+  vars = ["branch", "remote"]
+
+  step "rebase" {
+    run = "git rebase ${var.remote}/${var.branch}"
+  }
+}
+"#,
+        &[
+            "job.ship.step[0](merge).run",
+            "job 'merge' requires var 'branch'",
+        ],
+    );
+}
+
+#[test]
+fn error_sub_job_cycle() {
+    super::assert_hcl_err(
+        r#"
+job "a" {
+  step "call" {
+    run = { job = "b" }
+  }
+}
+
+job "b" {
+  step "call" {
+    run = { job = "a" }
+  }
+}
+"#,
+        &["job steps form a cycle"],
+    );
+}
+
+#[test]
+fn error_sub_job_runs_itself() {
+    super::assert_hcl_err(
+        r#"
+job "loop" {
+  step "again" {
+    run = { job = "loop" }
+  }
+}
+"#,
+        &["job.loop.step[0](again).run", "loop -> loop"],
+    );
+}
+
+#[test]
+fn error_job_vars_on_command() {
+    super::assert_hcl_err(
+        r#"
+command "ship" {
+  run = { job = "ship", vars = { branch = "main" } }
+}
+
+job "ship" {
+  step "run" {
+    run = "echo ship"
+  }
+}
+"#,
+        &["command.ship.run", "job vars are only valid in job steps"],
+    );
+}
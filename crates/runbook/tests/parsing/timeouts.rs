@@ -0,0 +1,146 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+// ============================================================================
+// Shell Step and Queue Timeouts
+// ============================================================================
+
+#[test]
+fn shell_step_and_queue_timeouts() {
+    let hcl = r#"
+queue "issues" {
+  list    = "gh issue list --json number"
+  take    = "gh issue edit ${item.number} --add-label taken"
+  timeout = "2m"
+}
+
+job "ci" {
+  step "test" {
+    run     = "make integration"
+    timeout = "45m"
+  }
+}
+"#;
+    let runbook = super::parse_hcl(hcl);
+    assert_eq!(
+        runbook.get_job("ci").unwrap().steps[0].timeout.as_deref(),
+        Some("45m")
+    );
+    assert_eq!(
+        runbook.get_queue("issues").unwrap().timeout.as_deref(),
+        Some("2m")
+    );
+}
+
+#[test]
+fn error_timeout_on_agent_step() {
+    super::assert_hcl_err(
+        r#"
+agent "fixer" {
+  run    = "claude"
+  prompt = "Fix it"
+}
+
+job "fix" {
+  step "fix" {
+    run     = { agent = "fixer" }
+    timeout = "1h"
+  }
+}
+"#,
+        &["step[0](fix).timeout", "only valid on shell steps"],
+    );
+}
+
+#[test]
+fn on_timeout_routes_a_shell_step() {
+    let hcl = r#"
+job "ci" {
+  step "test" {
+    run        = "make integration"
+    timeout    = "45m"
+    on_timeout = "collect-logs"
+  }
+  step "collect-logs" {
+    run = "tar czf logs.tgz logs"
+  }
+}
+"#;
+    let runbook = super::parse_hcl(hcl);
+    let step = &runbook.get_job("ci").unwrap().steps[0];
+    assert_eq!(
+        step.on_timeout.as_ref().map(|t| t.step_name()),
+        Some("collect-logs")
+    );
+}
+
+#[test]
+fn error_on_timeout_on_agent_step_or_unknown_step() {
+    super::assert_hcl_err(
+        r#"
+agent "fixer" {
+  run    = "claude"
+  prompt = "Fix it"
+}
+
+job "fix" {
+  step "fix" {
+    run        = { agent = "fixer" }
+    on_timeout = "fix"
+  }
+}
+"#,
+        &["step[0](fix).on_timeout", "only valid on shell steps"],
+    );
+    super::assert_hcl_err(
+        r#"
+job "ci" {
+  step "test" {
+    run        = "make integration"
+    on_timeout = "missing"
+  }
+}
+"#,
+        &["on_timeout", "unknown step 'missing'"],
+    );
+}
+
+#[test]
+fn error_invalid_or_zero_timeout() {
+    super::assert_hcl_err(
+        r#"
+job "ci" {
+  step "lint" {
+    run     = "make lint"
+    timeout = "2 fortnights"
+  }
+}
+"#,
+        &["timeout", "unknown duration suffix"],
+    );
+    super::assert_hcl_err(
+        r#"
+job "ci" {
+  step "lint" {
+    run     = "make lint"
+    timeout = "0s"
+  }
+}
+"#,
+        &["timeout must be greater than zero"],
+    );
+}
+
+#[test]
+fn error_timeout_on_persisted_queue() {
+    super::assert_hcl_err(
+        r#"
+queue "bugs" {
+  type    = "persisted"
+  vars    = ["title"]
+  timeout = "1m"
+}
+"#,
+        &["persisted queue must not have 'timeout' field"],
+    );
+}
@@ -0,0 +1,252 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use oj_runbook::OnDone;
+
+// ============================================================================
+// Conditional Transitions
+// ============================================================================
+
+#[test]
+fn on_done_branches_and_on_exit() {
+    let hcl = r#"
+job "sync" {
+  step "check" {
+    run     = "./check.sh"
+    on_done = [
+      { if = "${steps.check.outputs.changed} == true", step = "commit" },
+      { step = "skip" },
+    ]
+    on_exit = { 2 = "needs-review" }
+  }
+  step "commit" {
+    run = "git commit -am sync"
+  }
+  step "skip" {
+    run = "echo nothing to do"
+  }
+  step "needs-review" {
+    run = "echo review"
+  }
+}
+"#;
+    let runbook = super::parse_hcl(hcl);
+    let step = &runbook.get_job("sync").unwrap().steps[0];
+    let on_done = step.on_done.as_ref().unwrap();
+    assert!(matches!(on_done, OnDone::Branches(_)));
+    assert_eq!(on_done.step_name(), None);
+    let branches = on_done.branches();
+    assert_eq!(branches[0].step_name(), "commit");
+    assert_eq!(
+        branches[0].condition.as_deref(),
+        Some("${steps.check.outputs.changed} == true")
+    );
+    assert_eq!(branches[1].step_name(), "skip");
+    assert!(branches[1].condition.is_none());
+    assert_eq!(step.on_exit["2"].step_name(), "needs-review");
+}
+
+#[test]
+fn on_done_branches_toml() {
+    let toml = r#"
+[[job.sync.step]]
+name = "check"
+run = "./check.sh"
+on_done = [{ if = "${var.force}", step = "commit" }, "skip"]
+on_exit = { 3 = { step = "skip" } }
+
+[[job.sync.step]]
+name = "commit"
+run = "git commit"
+
+[[job.sync.step]]
+name = "skip"
+run = "true"
+"#;
+    let runbook = oj_runbook::parse_runbook(toml).unwrap();
+    let step = &runbook.get_job("sync").unwrap().steps[0];
+    let branches = step.on_done.as_ref().unwrap().branches();
+    assert_eq!(branches.len(), 2);
+    assert_eq!(branches[1].step_name(), "skip");
+    assert_eq!(step.on_exit["3"].step_name(), "skip");
+}
+
+#[test]
+fn error_branch_to_unknown_step() {
+    super::assert_hcl_err(
+        r#"
+job "sync" {
+  step "check" {
+    run     = "true"
+    on_done = [{ if = "${var.x}", step = "commit" }, { step = "nope" }]
+  }
+  step "commit" {
+    run = "true"
+  }
+}
+"#,
+        &["on_done[1]", "unknown step 'nope'"],
+    );
+}
+
+#[test]
+fn error_unconditional_branch_not_last() {
+    super::assert_hcl_err(
+        r#"
+job "sync" {
+  step "check" {
+    run     = "true"
+    on_done = [{ step = "skip" }, { if = "${var.x}", step = "commit" }]
+  }
+  step "commit" {
+    run = "true"
+  }
+  step "skip" {
+    run = "true"
+  }
+}
+"#,
+        &["on_done[0]", "unreachable"],
+    );
+}
+
+#[test]
+fn error_malformed_condition() {
+    super::assert_hcl_err(
+        r#"
+job "sync" {
+  step "check" {
+    run     = "true"
+    on_done = [{ if = "${var.x} = yes", step = "commit" }]
+  }
+  step "commit" {
+    run = "true"
+  }
+}
+"#,
+        &["invalid condition", "use == or !="],
+    );
+}
+
+#[test]
+fn error_condition_outside_on_done() {
+    super::assert_hcl_err(
+        r#"
+job "sync" {
+  step "check" {
+    run     = "true"
+    on_fail = { if = "${var.x}", step = "recover" }
+  }
+  step "recover" {
+    run = "true"
+  }
+}
+"#,
+        &["on_fail", "only valid in a step's on_done"],
+    );
+}
+
+#[test]
+fn error_on_exit_unknown_step_and_bad_code() {
+    super::assert_hcl_err(
+        r#"
+job "sync" {
+  step "check" {
+    run     = "true"
+    on_exit = { 2 = "missing" }
+  }
+}
+"#,
+        &["on_exit.2", "unknown step 'missing'"],
+    );
+    super::assert_hcl_err(
+        r#"
+job "sync" {
+  step "check" {
+    run     = "true"
+    on_exit = { two = "check" }
+  }
+}
+"#,
+        &["invalid exit code 'two'"],
+    );
+}
+
+#[test]
+fn on_exit_codes_are_normalized() {
+    let toml = r#"
+[[job.sync.step]]
+name = "check"
+run = "./check.sh"
+on_exit = { "02" = "skip", "+3" = "skip" }
+
+[[job.sync.step]]
+name = "skip"
+run = "true"
+"#;
+    let runbook = oj_runbook::parse_runbook(toml).unwrap();
+    let step = &runbook.get_job("sync").unwrap().steps[0];
+    let mut codes: Vec<_> = step.on_exit.keys().map(String::as_str).collect();
+    codes.sort();
+    assert_eq!(codes, ["2", "3"]);
+}
+
+#[yare::parameterized(
+    negative = { "-1", "invalid exit code '-1'; expected 0-255" },
+    too_large = { "256", "invalid exit code '256'; expected 0-255" },
+    duplicate = { "2\" = \"check\", \"02", "exit code 2 more than once" },
+)]
+fn error_on_exit_bad_code(key: &str, fragment: &str) {
+    crate::assert_toml_err(
+        &format!(
+            r#"
+[[job.sync.step]]
+name = "check"
+run = "true"
+on_exit = {{ "{key}" = "check" }}
+"#
+        ),
+        &[fragment],
+    );
+}
+
+#[test]
+fn error_on_exit_on_agent_step() {
+    super::assert_hcl_err(
+        r#"
+agent "fixer" {
+  run    = "claude"
+  prompt = "Fix it"
+}
+
+job "sync" {
+  step "fix" {
+    run     = { agent = "fixer" }
+    on_exit = { 2 = "fix" }
+  }
+}
+"#,
+        &["on_exit is only valid on shell steps"],
+    );
+}
+
+#[test]
+fn error_step_only_reachable_from_nowhere() {
+    super::assert_hcl_err(
+        r#"
+job "sync" {
+  step "check" {
+    run     = "true"
+    on_exit = { 2 = "review" }
+  }
+  step "review" {
+    run = "true"
+  }
+  step "orphan" {
+    run = "true"
+  }
+}
+"#,
+        &["step 'orphan' is unreachable"],
+    );
+}
@@ -0,0 +1,120 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use oj_runbook::parse_runbook;
+
+const JOBS: &str = r#"
+job "build" {
+  step "run" { run = "echo build" }
+}
+job "deploy-preview" {
+  step "run" { run = "echo deploy" }
+}
+"#;
+
+#[test]
+fn hcl_trigger_valid() {
+    let hcl = format!(
+        r#"{JOBS}
+on "job:completed" {{
+  filter = {{ kind = "build" }}
+  run    = {{ job = "deploy-preview" }}
+}}
+"#
+    );
+    let runbook = super::parse_hcl(&hcl);
+    let [trigger] = runbook.triggers_for("job:completed") else {
+        panic!("expected one trigger");
+    };
+    assert_eq!(trigger.event, "job:completed");
+    assert_eq!(trigger.filter["kind"], "build");
+    assert_eq!(trigger.run.job_name(), Some("deploy-preview"));
+    assert!(runbook.triggers_for("job:failed").is_empty());
+}
+
+#[test]
+fn hcl_repeated_event_keeps_every_trigger() {
+    let hcl = format!(
+        r#"{JOBS}
+on "job:completed" {{
+  filter = {{ kind = "build" }}
+  run    = {{ job = "deploy-preview" }}
+}}
+on "job:completed" {{
+  filter = {{ kind = "deploy-preview" }}
+  run    = {{ job = "build" }}
+}}
+"#
+    );
+    let runbook = super::parse_hcl(&hcl);
+    let targets: Vec<_> = runbook
+        .triggers_for("job:completed")
+        .iter()
+        .map(|t| t.run.job_name())
+        .collect();
+    assert_eq!(targets, vec![Some("deploy-preview"), Some("build")]);
+}
+
+#[test]
+fn toml_trigger_valid() {
+    let toml = r#"
+[job.build]
+[[job.build.step]]
+name = "run"
+run = "echo build"
+
+[[on."queue:item_dead"]]
+filter = { queue = "bugs" }
+run = { job = "build" }
+"#;
+    let runbook = parse_runbook(toml).unwrap();
+    let triggers = runbook.triggers_for("queue:item_dead");
+    assert_eq!(triggers.len(), 1);
+    assert_eq!(triggers[0].event, "queue:item_dead");
+    assert_eq!(triggers[0].filter["queue"], "bugs");
+}
+
+#[test]
+fn trigger_filter_matches_exact_fields() {
+    let hcl = format!(
+        r#"{JOBS}
+on "job:failed" {{
+  filter = {{ kind = "build", error = "boom" }}
+  run    = {{ job = "deploy-preview" }}
+}}
+"#
+    );
+    let runbook = super::parse_hcl(&hcl);
+    let trigger = &runbook.triggers_for("job:failed")[0];
+    let fields = |kind: &str, error: &str| {
+        [("kind", kind), ("error", error), ("id", "j1")]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    };
+    assert!(trigger.matches(&fields("build", "boom")));
+    assert!(!trigger.matches(&fields("build", "other")));
+    assert!(!trigger.matches(&fields("deploy-preview", "boom")));
+}
+
+#[yare::parameterized(
+    unknown_event = {
+        "on \"job:started\" {\n  run = { job = \"build\" }\n}",
+        &["on.\"job:started\"", "unknown event 'job:started'", "job:completed"]
+    },
+    unknown_filter_field = {
+        "on \"job:completed\" {\n  filter = { queue = \"bugs\" }\n  run = { job = \"build\" }\n}",
+        &["on.\"job:completed\"[0].filter.queue", "available fields: id, kind, name"]
+    },
+    unknown_job = {
+        "on \"job:completed\" {\n  run = { job = \"ship\" }\n}",
+        &["on.\"job:completed\"[0].run", "unknown job 'ship'"]
+    },
+    shell_run = {
+        "on \"job:completed\" {\n  run = \"echo hi\"\n}",
+        &["trigger run must reference a job"]
+    },
+)]
+fn error_invalid_trigger(block: &str, fragments: &[&str]) {
+    crate::assert_hcl_err(&format!("{JOBS}\n{block}"), fragments);
+}
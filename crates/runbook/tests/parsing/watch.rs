@@ -0,0 +1,86 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use oj_runbook::parse_runbook;
+
+const JOBS: &str = r#"
+job "spec-review" {
+  step "run" { run = "echo ${watch.files}" }
+}
+"#;
+
+#[test]
+fn hcl_watch_valid() {
+    let hcl = format!(
+        r#"{JOBS}
+watch "specs" {{
+  paths    = ["docs/specs/**/*.md", "README.md"]
+  debounce = "30s"
+  concurrency = 2
+  run      = {{ job = "spec-review" }}
+}}
+"#
+    );
+    let watch = &super::parse_hcl(&hcl).watches["specs"];
+    assert_eq!(watch.name, "specs");
+    assert_eq!(watch.paths, vec!["docs/specs/**/*.md", "README.md"]);
+    assert_eq!(watch.debounce(), "30s");
+    assert_eq!(watch.concurrency, Some(2));
+    assert_eq!(watch.run.job_name(), Some("spec-review"));
+}
+
+#[test]
+fn toml_watch_defaults_debounce() {
+    let toml = r#"
+[job.lint]
+[[job.lint.step]]
+name = "run"
+run = "echo lint"
+
+[watch.sources]
+paths = ["src/**/*.rs"]
+run = { job = "lint" }
+"#;
+    let watch = &parse_runbook(toml).unwrap().watches["sources"];
+    assert_eq!(watch.debounce, None);
+    assert_eq!(watch.debounce(), oj_runbook::DEFAULT_WATCH_DEBOUNCE);
+    assert_eq!(watch.concurrency, None);
+}
+
+#[yare::parameterized(
+    no_paths = {
+        "watch \"specs\" {\n  paths = []\n  run = { job = \"spec-review\" }\n}",
+        &["watch.specs.paths", "at least one path"]
+    },
+    absolute_path = {
+        "watch \"specs\" {\n  paths = [\"/etc/*.conf\"]\n  run = { job = \"spec-review\" }\n}",
+        &["watch.specs.paths[0]", "relative to the project root"]
+    },
+    parent_path = {
+        "watch \"specs\" {\n  paths = [\"docs/*.md\", \"../other/*.md\"]\n  run = { job = \"spec-review\" }\n}",
+        &["watch.specs.paths[1]", "relative to the project root"]
+    },
+    bad_glob = {
+        "watch \"specs\" {\n  paths = [\"docs/[*.md\"]\n  run = { job = \"spec-review\" }\n}",
+        &["watch.specs.paths[0]", "invalid glob"]
+    },
+    bad_debounce = {
+        "watch \"specs\" {\n  paths = [\"docs/*.md\"]\n  debounce = \"soon\"\n  run = { job = \"spec-review\" }\n}",
+        &["watch.specs.debounce"]
+    },
+    zero_concurrency = {
+        "watch \"specs\" {\n  paths = [\"docs/*.md\"]\n  concurrency = 0\n  run = { job = \"spec-review\" }\n}",
+        &["watch.specs.concurrency", "must be >= 1"]
+    },
+    unknown_job = {
+        "watch \"specs\" {\n  paths = [\"docs/*.md\"]\n  run = { job = \"ship\" }\n}",
+        &["watch.specs.run", "unknown job 'ship'"]
+    },
+    agent_run = {
+        "watch \"specs\" {\n  paths = [\"docs/*.md\"]\n  run = { agent = \"reviewer\" }\n}",
+        &["watch run must reference a job"]
+    },
+)]
+fn error_invalid_watch(block: &str, fragments: &[&str]) {
+    crate::assert_hcl_err(&format!("{JOBS}\n{block}"), fragments);
+}
@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use oj_runbook::parse_runbook;
+
+const TARGETS: &str = r#"
+queue "bugs" {
+  type = "persisted"
+  vars = ["title", "url"]
+}
+
+command "triage" {
+  args = "<title> [--url <url>]"
+  run  = "echo ${args.title}"
+}
+"#;
+
+#[test]
+fn hcl_webhook_queue_valid() {
+    let hcl = format!(
+        r#"{TARGETS}
+webhook "issues" {{
+  queue = "bugs"
+  map   = {{ title = "${{body.issue.title}}", url = "${{body.issue.html_url}}" }}
+}}
+"#
+    );
+    let webhook = &super::parse_hcl(&hcl).webhooks["issues"];
+    assert_eq!(webhook.name, "issues");
+    assert_eq!(webhook.queue.as_deref(), Some("bugs"));
+    assert_eq!(webhook.map["title"], "${body.issue.title}");
+}
+
+#[test]
+fn toml_webhook_command_valid() {
+    let toml = r#"
+[command.triage]
+args = "<title>"
+run = "echo ${args.title}"
+
+[webhook.chat]
+command = "triage"
+"#;
+    let runbook = parse_runbook(toml).unwrap();
+    let webhook = runbook.get_webhook("chat").unwrap();
+    assert_eq!(webhook.command.as_deref(), Some("triage"));
+    assert!(webhook.map.is_empty());
+}
+
+#[yare::parameterized(
+    no_target = {
+        "webhook \"issues\" {\n  map = { title = \"${body.title}\" }\n}",
+        &["webhook.issues", "exactly one of queue or command"]
+    },
+    both_targets = {
+        "webhook \"issues\" {\n  queue = \"bugs\"\n  command = \"triage\"\n}",
+        &["webhook.issues", "exactly one of queue or command"]
+    },
+    non_body_ref = {
+        "webhook \"issues\" {\n  queue = \"bugs\"\n  map = { title = \"${var.title}\" }\n}",
+        &["webhook.issues.map.title", "not a request body field"]
+    },
+    unknown_queue = {
+        "webhook \"issues\" {\n  queue = \"tasks\"\n}",
+        &["webhook.issues.queue", "unknown queue 'tasks'"]
+    },
+    unknown_command = {
+        "webhook \"issues\" {\n  command = \"deploy\"\n}",
+        &["webhook.issues.command", "unknown command 'deploy'"]
+    },
+    unknown_argument = {
+        "webhook \"issues\" {\n  command = \"triage\"\n  map = { name = \"${body.title}\" }\n}",
+        &["webhook.issues.map.name", "no argument 'name'"]
+    },
+)]
+fn error_invalid_webhook(block: &str, fragments: &[&str]) {
+    crate::assert_hcl_err(&format!("{TARGETS}\n{block}"), fragments);
+}
@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Worker parsing: job- and agent-backed handlers, dispatch rates.
+
+const QUEUE_AND_TARGETS: &str = r#"
+queue "findings" {
+  type = "persisted"
+  vars = ["summary"]
+}
+
+job "triage" {
+  step "run" { run = "echo ${item.summary}" }
+}
+
+agent "triager" {
+  run    = "claude"
+  prompt = "Triage: ${item.summary}"
+}
+"#;
+
+fn with_worker(handler: &str) -> String {
+    format!(
+        "{QUEUE_AND_TARGETS}\nworker \"triage\" {{\n  source  = {{ queue = \"findings\" }}\n  handler = {handler}\n}}\n"
+    )
+}
+
+#[test]
+fn job_handler() {
+    let runbook = super::parse_hcl(&with_worker(r#"{ job = "triage" }"#));
+    let handler = &runbook.workers["triage"].handler;
+    assert_eq!(handler.job, "triage");
+    assert_eq!(handler.agent, None);
+}
+
+#[test]
+fn agent_handler() {
+    let runbook = super::parse_hcl(&with_worker(r#"{ agent = "triager" }"#));
+    let handler = &runbook.workers["triage"].handler;
+    assert!(handler.job.is_empty());
+    assert_eq!(handler.agent.as_deref(), Some("triager"));
+}
+
+#[yare::parameterized(
+    unknown_agent = { r#"{ agent = "nobody" }"#, "references unknown agent 'nobody'" },
+    unknown_job   = { r#"{ job = "nothing" }"#, "references unknown job 'nothing'" },
+    both          = { r#"{ job = "triage", agent = "triager" }"#, "exactly one of a job or an agent" },
+    neither       = { "{}", "exactly one of a job or an agent" },
+)]
+fn error_invalid_handler(handler: &str, fragment: &str) {
+    crate::assert_hcl_err(&with_worker(handler), &["worker.triage.handler", fragment]);
+}
+
+#[test]
+fn rate_limit() {
+    let hcl = with_worker(r#"{ job = "triage" }"#)
+        .replace("handler =", "rate    = \"10/h\"\n  handler =");
+    let runbook = super::parse_hcl(&hcl);
+    assert_eq!(runbook.workers["triage"].rate.as_deref(), Some("10/h"));
+}
+
+#[yare::parameterized(
+    not_a_rate = { "10",   "expected <count>/<period>" },
+    zero_count = { "0/h",  "count must be at least 1" },
+)]
+fn error_invalid_rate(rate: &str, fragment: &str) {
+    let hcl = with_worker(r#"{ job = "triage" }"#)
+        .replace("handler =", &format!("rate    = \"{rate}\"\n  handler ="));
+    crate::assert_hcl_err(&hcl, &["worker.triage.rate", fragment]);
+}
+
+const WEIGHTED_QUEUES: &str = r#"
+queue "bugs" {
+  type = "persisted"
+  vars = ["summary"]
+}
+
+queue "chores" {
+  type = "persisted"
+  vars = ["summary"]
+}
+
+queue "tickets" {
+  list = "echo '[]'"
+  take = "true"
+}
+
+job "triage" {
+  step "run" { run = "echo ${item.summary}" }
+}
+"#;
+
+fn with_source(source: &str) -> String {
+    format!(
+        "{WEIGHTED_QUEUES}\nworker \"triage\" {{\n  source  = {source}\n  handler = {{ job = \"triage\" }}\n}}\n"
+    )
+}
+
+#[test]
+fn weighted_sources() {
+    let runbook = super::parse_hcl(&with_source("{ queues = { bugs = 3, chores = 1 } }"));
+    let source = &runbook.workers["triage"].source;
+    assert!(source.queue.is_empty());
+    assert_eq!(
+        source.weighted(),
+        vec![("bugs".to_string(), 3), ("chores".to_string(), 1)]
+    );
+    assert!(source.consumes("chores"));
+    assert!(!source.consumes("tickets"));
+}
+
+#[yare::parameterized(
+    unknown_queue = { "{ queues = { bugs = 1, nope = 1 } }",   "worker.triage.source.queues", "references unknown queue 'nope'" },
+    zero_weight   = { "{ queues = { bugs = 0 } }",             "worker.triage.source.queues.bugs", "weight must be at least 1" },
+    external      = { "{ queues = { bugs = 1, tickets = 1 } }", "worker.triage.source.queues.tickets", "must be persisted queues" },
+    both          = { r#"{ queue = "bugs", queues = { chores = 1 } }"#, "worker.triage.source", "exactly one of queue or queues" },
+    neither       = { "{}",                                      "worker.triage.source", "exactly one of queue or queues" },
+)]
+fn error_invalid_source(source: &str, location: &str, fragment: &str) {
+    crate::assert_hcl_err(&with_source(source), &[location, fragment]);
+}
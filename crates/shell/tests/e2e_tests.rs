@@ -6,6 +6,11 @@
 //! These tests execute real commands to validate multi-line scripts
 //! matching patterns found in production runbooks.
 
+// Allow panic/unwrap/expect in test code (matches lib.rs cfg_attr)
+#![allow(clippy::panic)]
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::expect_used)]
+
 use oj_shell::{ExecError, ShellExecutor};
 use tempfile::TempDir;
 
@@ -3,6 +3,11 @@
 
 //! Integration tests for environment variable handling.
 
+// Allow panic/unwrap/expect in test code (matches lib.rs cfg_attr)
+#![allow(clippy::panic)]
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::expect_used)]
+
 use oj_shell::ShellExecutor;
 
 // ---------------------------------------------------------------------------
@@ -9,6 +9,11 @@
 //! - Trace completeness (all executed commands recorded even on failure)
 //! - Cleanup behavior after mid-script failures
 
+// Allow panic/unwrap/expect in test code (matches lib.rs cfg_attr)
+#![allow(clippy::panic)]
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::expect_used)]
+
 use oj_shell::{diagnostic_context, ExecError, Parser, ShellExecutor};
 
 // ---------------------------------------------------------------------------
@@ -190,7 +195,7 @@ async fn redirect_failure_span() {
         ExecError::RedirectFailed { span, message, .. } => {
             // Span points to the redirection target
             // Verify the error message references the path
-            assert!(message.contains("/nonexistent_path_xyz/file.txt") || span.len() > 0);
+            assert!(message.contains("/nonexistent_path_xyz/file.txt") || !span.is_empty());
         }
         other => panic!("Expected RedirectFailed, got: {other:?}"),
     }
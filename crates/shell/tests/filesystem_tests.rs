@@ -6,6 +6,11 @@
 //! Tests verify that shell scripts correctly create, modify, and read files
 //! through redirections. All tests use tempfile for isolation.
 
+// Allow panic/unwrap/expect in test code (matches lib.rs cfg_attr)
+#![allow(clippy::panic)]
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::expect_used)]
+
 use oj_shell::{ExecError, ShellExecutor};
 use std::fs;
 use tempfile::TempDir;
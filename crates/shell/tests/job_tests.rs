@@ -8,6 +8,11 @@
 //! throughput, jobs combined with redirections, and pipefail behavior
 //! verification.
 
+// Allow panic/unwrap/expect in test code (matches lib.rs cfg_attr)
+#![allow(clippy::panic)]
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::expect_used)]
+
 use oj_shell::{ExecError, ShellExecutor};
 use std::path::PathBuf;
 
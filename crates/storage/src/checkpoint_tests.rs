@@ -88,8 +88,7 @@ impl FakeCheckpointWriter {
 impl CheckpointWriter for FakeCheckpointWriter {
     fn write_tmp(&self, path: &Path, data: &[u8]) -> Result<(), CheckpointError> {
         if self.fail_write.load(Ordering::SeqCst) {
-            return Err(CheckpointError::Io(std::io::Error::new(
-                std::io::ErrorKind::Other,
+            return Err(CheckpointError::Io(std::io::Error::other(
                 "injected write failure",
             )));
         }
@@ -104,8 +103,7 @@ impl CheckpointWriter for FakeCheckpointWriter {
 
     fn fsync_file(&self, path: &Path) -> Result<(), CheckpointError> {
         if self.fail_fsync_file.load(Ordering::SeqCst) {
-            return Err(CheckpointError::Io(std::io::Error::new(
-                std::io::ErrorKind::Other,
+            return Err(CheckpointError::Io(std::io::Error::other(
                 "injected fsync failure",
             )));
         }
@@ -117,8 +115,7 @@ impl CheckpointWriter for FakeCheckpointWriter {
 
     fn rename(&self, from: &Path, to: &Path) -> Result<(), CheckpointError> {
         if self.fail_rename.load(Ordering::SeqCst) {
-            return Err(CheckpointError::Io(std::io::Error::new(
-                std::io::ErrorKind::Other,
+            return Err(CheckpointError::Io(std::io::Error::other(
                 "injected rename failure",
             )));
         }
@@ -134,8 +131,7 @@ impl CheckpointWriter for FakeCheckpointWriter {
 
     fn fsync_dir(&self, path: &Path) -> Result<(), CheckpointError> {
         if self.fail_fsync_dir.load(Ordering::SeqCst) {
-            return Err(CheckpointError::Io(std::io::Error::new(
-                std::io::ErrorKind::Other,
+            return Err(CheckpointError::Io(std::io::Error::other(
                 "injected fsync_dir failure",
             )));
         }
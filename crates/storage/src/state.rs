@@ -5,11 +5,12 @@
 
 use oj_core::{
     job::AgentSignal, scoped_name, AgentRecord, AgentRecordStatus, AgentRun, AgentRunStatus,
-    AgentSignalKind, Decision, DecisionId, Event, Job, JobConfig, OwnerId, StepOutcome, StepStatus,
+    AgentSignalKind, BudgetScope, Decision, DecisionId, DependencyState, Event, Job, JobConfig,
+    JobDependency, OwnerId, ShortId, StepOutcome, StepRecord, StepStatus, TimerId, TokenUsage,
     WorkspaceStatus,
 };
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::PathBuf;
 use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -99,10 +100,45 @@ pub struct WorkerRecord {
     pub status: String,
     #[serde(default)]
     pub active_job_ids: Vec<String>,
+    /// Agent runs dispatched by a worker whose handler is an agent
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub active_agent_run_ids: Vec<String>,
     #[serde(default)]
     pub queue_name: String,
+    /// Weighted source queues; empty for a worker consuming only `queue_name`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub queues: BTreeMap<String, u32>,
     #[serde(default)]
     pub concurrency: u32,
+    /// Dispatch rate limit (e.g. "10/h"), if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate: Option<String>,
+    /// When the rate limiter's token bucket is full again; survives restarts
+    /// so a restarted worker doesn't get a fresh burst
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bucket_full_at_ms: Option<u64>,
+}
+
+impl WorkerRecord {
+    /// Every queue the worker consumes from
+    pub fn queue_names(&self) -> Vec<&str> {
+        if self.queues.is_empty() {
+            vec![self.queue_name.as_str()]
+        } else {
+            self.queues.keys().map(String::as_str).collect()
+        }
+    }
+
+    /// When a running, rate-limited worker can next dispatch, or None if it
+    /// isn't waiting on its rate limit.
+    pub fn next_dispatch_at_ms(&self, now_ms: u64) -> Option<u64> {
+        if self.status != "running" {
+            return None;
+        }
+        let rate = oj_core::DispatchRate::parse(self.rate.as_deref()?).ok()?;
+        let next_ms = rate.next_token_at(self.bucket_full_at_ms?, now_ms);
+        (next_ms > now_ms).then_some(next_ms)
+    }
 }
 
 /// Status of a queue item through its lifecycle
@@ -114,6 +150,8 @@ pub enum QueueItemStatus {
     Completed,
     Failed,
     Dead,
+    /// Outlived its TTL while pending (terminal)
+    Expired,
 }
 
 impl std::fmt::Display for QueueItemStatus {
@@ -124,6 +162,7 @@ impl std::fmt::Display for QueueItemStatus {
             QueueItemStatus::Completed => write!(f, "completed"),
             QueueItemStatus::Failed => write!(f, "failed"),
             QueueItemStatus::Dead => write!(f, "dead"),
+            QueueItemStatus::Expired => write!(f, "expired"),
         }
     }
 }
@@ -140,6 +179,41 @@ pub struct QueueItem {
     /// Number of times this item has failed (for retry tracking)
     #[serde(default)]
     pub failure_count: u32,
+    /// Dispatch priority: workers take higher priorities first, FIFO within one
+    #[serde(default)]
+    pub priority: i32,
+    /// Epoch ms before which workers don't see this item (delayed push)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visible_after_epoch_ms: Option<u64>,
+    /// Key that duplicate pushes are matched on
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dedup_key: Option<String>,
+    /// Epoch ms when the item completed, if it did
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_at_epoch_ms: Option<u64>,
+    /// Epoch ms when the item expires if it is still pending
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at_epoch_ms: Option<u64>,
+    /// Drop rather than mark the item when it expires
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub drop_on_expire: bool,
+    /// Epoch ms when an active item's lease runs out unless renewed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lease_expires_at_epoch_ms: Option<u64>,
+}
+
+impl QueueItem {
+    /// True for a pending item whose delay hasn't elapsed yet at `now_ms`.
+    pub fn is_scheduled(&self, now_ms: u64) -> bool {
+        self.status == QueueItemStatus::Pending
+            && self.visible_after_epoch_ms.is_some_and(|at| at > now_ms)
+    }
+
+    /// True for a pending item whose TTL ran out by `now_ms`.
+    pub fn is_overdue(&self, now_ms: u64) -> bool {
+        self.status == QueueItemStatus::Pending
+            && self.expires_at_epoch_ms.is_some_and(|at| at <= now_ms)
+    }
 }
 
 /// Record of a running cron for WAL replay / restart recovery
@@ -152,7 +226,13 @@ pub struct CronRecord {
     pub runbook_hash: String,
     /// "running" or "stopped"
     pub status: String,
+    /// Empty for schedule-based crons
+    #[serde(default)]
     pub interval: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schedule: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
     /// What this cron runs: "job:name" or "agent:name"
     pub run_target: String,
     /// Epoch ms when the cron was started (timer began)
@@ -163,6 +243,89 @@ pub struct CronRecord {
     pub last_fired_at_ms: Option<u64>,
 }
 
+/// Record of a running file watch for WAL replay / restart recovery
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WatchRecord {
+    pub name: String,
+    #[serde(default)]
+    pub namespace: String,
+    pub project_root: PathBuf,
+    pub runbook_hash: String,
+    /// "running" or "stopped"
+    pub status: String,
+    /// Glob patterns relative to the project root
+    pub paths: Vec<String>,
+    pub debounce: String,
+    /// What this watch runs: "job:name"
+    pub run_target: String,
+    /// Epoch ms when the watch was started
+    #[serde(default)]
+    pub started_at_ms: u64,
+    /// Epoch ms when the watch last fired (spawned a job)
+    #[serde(default)]
+    pub last_fired_at_ms: Option<u64>,
+}
+
+/// Record of a git ref trigger for WAL replay / restart recovery
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitTriggerRecord {
+    pub name: String,
+    #[serde(default)]
+    pub namespace: String,
+    pub project_root: PathBuf,
+    pub runbook_hash: String,
+    /// "running" or "stopped"
+    pub status: String,
+    /// Full ref name or glob (e.g. "refs/heads/feature/*")
+    pub ref_pattern: String,
+    /// What this trigger runs: "job:name"
+    pub run_target: String,
+    /// Epoch ms when the trigger was started
+    #[serde(default)]
+    pub started_at_ms: u64,
+    /// Epoch ms when the trigger last fired (spawned a job)
+    #[serde(default)]
+    pub last_fired_at_ms: Option<u64>,
+    /// Whether the first scan has been recorded; until then no move runs
+    /// the job
+    #[serde(default)]
+    pub baselined: bool,
+    /// Last seen commit of each matching ref, so restarts neither replay
+    /// nor miss moves
+    #[serde(default)]
+    pub refs: BTreeMap<String, String>,
+}
+
+/// A job's claim on a lock or semaphore (either held or waited for)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockClaim {
+    pub job_id: String,
+    pub step: String,
+    /// Epoch ms when the claim was made
+    #[serde(default)]
+    pub since_ms: u64,
+}
+
+/// Holders and waiters for a runbook lock or semaphore
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LockRecord {
+    /// Jobs currently holding a permit (at most one per job)
+    #[serde(default)]
+    pub holders: Vec<LockClaim>,
+    /// Jobs blocked on this resource, in arrival order
+    #[serde(default)]
+    pub waiters: Vec<LockClaim>,
+}
+
+/// Drop every lock/semaphore claim (held or waiting) made by a job.
+fn release_job_locks(locks: &mut HashMap<String, LockRecord>, job_id: &str) {
+    for record in locks.values_mut() {
+        record.holders.retain(|c| c.job_id != job_id);
+        record.waiters.retain(|c| c.job_id != job_id);
+    }
+    locks.retain(|_, r| !r.holders.is_empty() || !r.waiters.is_empty());
+}
+
 /// Materialized state built from WAL operations
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct MaterializedState {
@@ -178,6 +341,10 @@ pub struct MaterializedState {
     #[serde(default)]
     pub crons: HashMap<String, CronRecord>,
     #[serde(default)]
+    pub watches: HashMap<String, WatchRecord>,
+    #[serde(default)]
+    pub git_triggers: HashMap<String, GitTriggerRecord>,
+    #[serde(default)]
     pub decisions: HashMap<String, Decision>,
     #[serde(default)]
     pub agent_runs: HashMap<String, AgentRun>,
@@ -195,9 +362,34 @@ pub struct MaterializedState {
     /// Never cleared by deletion events, so the mapping survives worker/cron pruning.
     #[serde(default)]
     pub project_roots: HashMap<String, PathBuf>,
+    /// Lock/semaphore claims keyed by scoped resource ("namespace/lock.name").
+    ///
+    /// Claims are dropped when the claiming job leaves its step or is deleted.
+    #[serde(default)]
+    pub locks: HashMap<String, LockRecord>,
+    /// Durable timers (timer id → epoch ms when it fires).
+    ///
+    /// Re-armed on daemon startup; an entry is dropped once its timer fires.
+    #[serde(default)]
+    pub timers: HashMap<String, u64>,
+    /// Final step of deleted jobs that other jobs still list in `after`
+    /// (job id → "done", "failed", or "cancelled").
+    ///
+    /// Lets a pruned dependency keep counting as met or broken.
+    #[serde(default)]
+    pub finished_jobs: HashMap<String, String>,
 }
 
 impl MaterializedState {
+    /// Where a dependency stands, counting the final step of a dependency
+    /// job that has since been deleted.
+    pub fn dependency_state(&self, dep: &JobDependency) -> DependencyState {
+        match self.jobs.get(&dep.job_id) {
+            Some(job) => dep.check(Some(job)),
+            None => dep.check_step(self.finished_jobs.get(&dep.job_id).map(String::as_str)),
+        }
+    }
+
     /// Get a job by ID or unique prefix (like git commit hashes)
     pub fn get_job(&self, id: &str) -> Option<&Job> {
         // Try exact match first
@@ -244,10 +436,27 @@ impl MaterializedState {
         }
     }
 
+    /// Token usage summed across every agent a job has run, with the
+    /// estimated cost of the agents whose model has a known price.
+    pub fn job_usage(&self, job_id: &str) -> (TokenUsage, Option<f64>) {
+        let mut usage = TokenUsage::default();
+        let mut cost: Option<f64> = None;
+        for rec in self.agents.values() {
+            if !matches!(&rec.owner, OwnerId::Job(id) if id.as_str() == job_id) {
+                continue;
+            }
+            usage.add(&rec.usage);
+            if let Some(agent_cost) = rec.cost_usd() {
+                *cost.get_or_insert(0.0) += agent_cost;
+            }
+        }
+        (usage, cost)
+    }
+
     /// Look up the known project root for a namespace.
     ///
     /// Checks the durable project_roots map first (survives worker/cron pruning),
-    /// then falls back to scanning active workers and crons.
+    /// then falls back to scanning active workers, crons, and watches.
     pub fn project_root_for_namespace(&self, namespace: &str) -> Option<std::path::PathBuf> {
         if let Some(root) = self.project_roots.get(namespace) {
             return Some(root.clone());
@@ -262,6 +471,16 @@ impl MaterializedState {
                 return Some(c.project_root.clone());
             }
         }
+        for w in self.watches.values() {
+            if w.namespace == namespace {
+                return Some(w.project_root.clone());
+            }
+        }
+        for t in self.git_triggers.values() {
+            if t.namespace == namespace {
+                return Some(t.project_root.clone());
+            }
+        }
         None
     }
 
@@ -285,6 +504,48 @@ impl MaterializedState {
     /// - Guard increments with status checks (only increment on state transition)
     /// - Use `finalize_current_step` which is internally guarded by `finished_at_ms`
     pub fn apply_event(&mut self, event: &Event) {
+        self.apply_event_inner(event);
+        if let Some(job_id) = event.job_id() {
+            self.sync_branch_record(job_id.as_str());
+        }
+    }
+
+    /// Mirror a branch job's current step record into the branch entry of
+    /// its parent's parallel step, so the parent's history stays complete.
+    fn sync_branch_record(&mut self, job_id: &str) {
+        let Some(child) = self.jobs.get(job_id) else {
+            return;
+        };
+        if !child.is_branch {
+            return;
+        }
+        let (Some(parent_id), Some(mut source)) = (
+            child.parent_job_id.clone(),
+            child.step_history.last().cloned(),
+        ) else {
+            return;
+        };
+        if child.step == "cancelled" {
+            source.outcome = StepOutcome::Failed("cancelled".to_string());
+        }
+        let Some(parent) = self.jobs.get_mut(&parent_id) else {
+            return;
+        };
+        let entry = parent
+            .step_history
+            .iter_mut()
+            .rev()
+            .flat_map(|r| r.branches.iter_mut())
+            .find(|b| b.job_id.as_deref() == Some(job_id));
+        if let Some(entry) = entry {
+            entry.finished_at_ms = source.finished_at_ms;
+            entry.outcome = source.outcome;
+            entry.agent_id = source.agent_id;
+            entry.agent_name = source.agent_name;
+        }
+    }
+
+    fn apply_event_inner(&mut self, event: &Event) {
         match event {
             Event::AgentWorking {
                 agent_id, owner, ..
@@ -375,6 +636,38 @@ impl MaterializedState {
                     rec.updated_at_ms = epoch_ms_now();
                 }
             }
+            Event::AgentUsage {
+                agent_id,
+                usage,
+                model,
+            } => {
+                // Totals are cumulative, so replaying an older event is harmless
+                if let Some(rec) = self.agents.get_mut(agent_id.as_str()) {
+                    rec.usage = *usage;
+                    if model.is_some() {
+                        rec.model = model.clone();
+                    }
+                }
+            }
+            Event::AgentBudgetExceeded {
+                agent_id,
+                owner,
+                scope,
+                ..
+            } => match scope {
+                BudgetScope::Agent => {
+                    if let Some(rec) = self.agents.get_mut(agent_id.as_str()) {
+                        rec.budget_exceeded = true;
+                    }
+                }
+                BudgetScope::Job => {
+                    if let OwnerId::Job(job_id) = owner {
+                        if let Some(job) = self.jobs.get_mut(job_id.as_str()) {
+                            job.budget_exceeded = true;
+                        }
+                    }
+                }
+            },
 
             Event::ShellExited {
                 job_id, exit_code, ..
@@ -405,6 +698,9 @@ impl MaterializedState {
                 created_at_epoch_ms,
                 namespace,
                 cron_name,
+                after,
+                priority,
+                project_root,
             } => {
                 let mut builder =
                     JobConfig::builder(id.to_string(), kind.clone(), initial_step.clone())
@@ -412,7 +708,10 @@ impl MaterializedState {
                         .vars(vars.clone())
                         .runbook_hash(runbook_hash.clone())
                         .cwd(cwd.clone())
-                        .namespace(namespace.clone());
+                        .namespace(namespace.clone())
+                        .after(after.clone())
+                        .priority(*priority)
+                        .project_root(project_root.clone());
                 if let Some(cn) = cron_name {
                     builder = builder.cron_name(cn.clone());
                 }
@@ -421,6 +720,46 @@ impl MaterializedState {
                 self.jobs.insert(id.to_string(), job);
             }
 
+            Event::JobBlocked { id, waiting_on } => {
+                let Some(job) = self.jobs.get_mut(id.as_str()) else {
+                    return;
+                };
+                // Only a job that has not started its first step can be held
+                let not_started = matches!(
+                    job.step_status,
+                    StepStatus::Pending | StepStatus::Blocked(_)
+                );
+                if job.is_terminal() || !not_started {
+                    return;
+                }
+                let ids: Vec<&str> = waiting_on.iter().map(|id| id.short(8)).collect();
+                let reason = format!("after {}", ids.join(", "));
+                job.step_status = StepStatus::Blocked(reason.clone());
+                job.update_current_step_outcome(StepOutcome::Blocked(reason));
+            }
+
+            Event::JobQueued { id } => {
+                let Some(job) = self.jobs.get_mut(id.as_str()) else {
+                    return;
+                };
+                let not_started = matches!(
+                    job.step_status,
+                    StepStatus::Pending | StepStatus::Blocked(_)
+                );
+                if job.is_terminal() || !not_started {
+                    return;
+                }
+                job.queued = true;
+                job.step_status = StepStatus::Blocked("queued".to_string());
+                job.update_current_step_outcome(StepOutcome::Blocked("queued".to_string()));
+            }
+
+            Event::JobAdmitted { id } => {
+                if let Some(job) = self.jobs.get_mut(id.as_str()) {
+                    job.queued = false;
+                }
+            }
+
             Event::RunbookLoaded {
                 hash,
                 version,
@@ -455,6 +794,9 @@ impl MaterializedState {
                         job.session_id = None;
                     }
 
+                    // Locks are held per step: leaving the step releases them
+                    release_job_locks(&mut self.locks, id.as_str());
+
                     let now = epoch_ms_now();
                     // Finalize the previous step
                     let outcome = match step.as_str() {
@@ -480,6 +822,9 @@ impl MaterializedState {
                         job.reset_action_attempts();
                     }
                     job.clear_agent_signal();
+                    // A pending retry of the step being left is superseded
+                    job.step_retries = 0;
+                    self.timers.remove(TimerId::step_retry(id).as_str());
 
                     // Push new step record and track visits (unless terminal)
                     if step != "done" && step != "failed" && step != "cancelled" {
@@ -509,6 +854,8 @@ impl MaterializedState {
             } => {
                 if let Some(job) = self.jobs.get_mut(job_id.as_str()) {
                     job.step_status = StepStatus::Running;
+                    // Starting the step (e.g. on resume) supersedes a pending retry
+                    self.timers.remove(TimerId::step_retry(job_id).as_str());
                     if let Some(aid) = agent_id {
                         job.set_current_step_agent_id(aid.as_str());
 
@@ -532,6 +879,9 @@ impl MaterializedState {
                                     status: AgentRecordStatus::Starting,
                                     created_at_ms: now,
                                     updated_at_ms: now,
+                                    usage: Default::default(),
+                                    model: None,
+                                    budget_exceeded: false,
                                 }
                             });
                     }
@@ -558,6 +908,70 @@ impl MaterializedState {
                 }
             }
 
+            Event::LockWaiting {
+                job_id,
+                step,
+                resources,
+                namespace,
+            } => {
+                let Some(job) = self.jobs.get_mut(job_id.as_str()) else {
+                    return;
+                };
+                // Only a step that has not started yet can be blocked
+                let not_started = matches!(
+                    job.step_status,
+                    StepStatus::Pending | StepStatus::Blocked(_)
+                );
+                if job.step != *step || !not_started {
+                    return;
+                }
+                let reason = resources.join(", ");
+                job.step_status = StepStatus::Blocked(reason.clone());
+                job.update_current_step_outcome(StepOutcome::Blocked(reason));
+                let now = epoch_ms_now();
+                for resource in resources {
+                    let record = self
+                        .locks
+                        .entry(scoped_name(namespace, resource))
+                        .or_default();
+                    if !record.waiters.iter().any(|c| c.job_id == job_id.as_str()) {
+                        record.waiters.push(LockClaim {
+                            job_id: job_id.to_string(),
+                            step: step.clone(),
+                            since_ms: now,
+                        });
+                    }
+                }
+            }
+
+            Event::LockAcquired {
+                job_id,
+                step,
+                resources,
+                namespace,
+            } => {
+                // Guard against stale replays after the job has moved on
+                match self.jobs.get(job_id.as_str()) {
+                    Some(job) if job.step == *step && !job.is_terminal() => {}
+                    _ => return,
+                }
+                let now = epoch_ms_now();
+                for resource in resources {
+                    let record = self
+                        .locks
+                        .entry(scoped_name(namespace, resource))
+                        .or_default();
+                    record.waiters.retain(|c| c.job_id != job_id.as_str());
+                    if !record.holders.iter().any(|c| c.job_id == job_id.as_str()) {
+                        record.holders.push(LockClaim {
+                            job_id: job_id.to_string(),
+                            step: step.clone(),
+                            since_ms: now,
+                        });
+                    }
+                }
+            }
+
             Event::StepCompleted { job_id, .. } => {
                 if let Some(job) = self.jobs.get_mut(job_id.as_str()) {
                     job.step_status = StepStatus::Completed;
@@ -573,6 +987,93 @@ impl MaterializedState {
                 }
             }
 
+            Event::StepRetrying {
+                job_id,
+                step,
+                attempt,
+                error,
+            } => {
+                let Some(job) = self.jobs.get_mut(job_id.as_str()) else {
+                    return;
+                };
+                // Idempotency: each attempt is recorded once
+                if job.step != *step || job.is_terminal() || job.step_retries >= *attempt {
+                    return;
+                }
+                let now = epoch_ms_now();
+                job.step_retries = *attempt;
+                job.total_retries += 1;
+                job.step_status = StepStatus::Pending;
+                job.error = Some(error.clone());
+                job.finalize_current_step(StepOutcome::Failed(error.clone()), now);
+                job.push_step(step, now);
+                // Locks aren't held through the retry delay; the retry
+                // attempt claims them again
+                release_job_locks(&mut self.locks, job_id.as_str());
+            }
+
+            Event::StepBranched {
+                job_id,
+                step,
+                branch,
+                branch_job_id,
+            } => {
+                let Some(child) = self.jobs.get_mut(branch_job_id.as_str()) else {
+                    return;
+                };
+                child.parent_job_id = Some(job_id.to_string());
+                child.is_branch = true;
+                let started_at_ms = child
+                    .step_history
+                    .first()
+                    .map(|r| r.started_at_ms)
+                    .unwrap_or_else(epoch_ms_now);
+
+                let Some(job) = self.jobs.get_mut(job_id.as_str()) else {
+                    return;
+                };
+                if job.step != *step {
+                    return;
+                }
+                if let Some(record) = job.step_history.last_mut() {
+                    let exists = record
+                        .branches
+                        .iter()
+                        .any(|b| b.job_id.as_deref() == Some(branch_job_id.as_str()));
+                    if !exists {
+                        record.branches.push(StepRecord {
+                            name: branch.clone(),
+                            started_at_ms,
+                            finished_at_ms: None,
+                            outcome: StepOutcome::Running,
+                            agent_id: None,
+                            agent_name: None,
+                            job_id: Some(branch_job_id.to_string()),
+                            branches: Vec::new(),
+                        });
+                    }
+                }
+            }
+
+            Event::StepDelegated {
+                job_id,
+                step,
+                child_job_id,
+            } => {
+                if let Some(child) = self.jobs.get_mut(child_job_id.as_str()) {
+                    child.parent_job_id = Some(job_id.to_string());
+                }
+                let Some(job) = self.jobs.get_mut(job_id.as_str()) else {
+                    return;
+                };
+                if job.step != *step {
+                    return;
+                }
+                if let Some(record) = job.step_history.last_mut() {
+                    record.job_id = Some(child_job_id.to_string());
+                }
+            }
+
             Event::JobCancelling { id } => {
                 if let Some(job) = self.jobs.get_mut(id.as_str()) {
                     job.cancelling = true;
@@ -580,7 +1081,22 @@ impl MaterializedState {
             }
 
             Event::JobDeleted { id } => {
-                self.jobs.remove(id.as_str());
+                if let Some(job) = self.jobs.remove(id.as_str()) {
+                    let depended_on = self
+                        .jobs
+                        .values()
+                        .any(|other| other.after.iter().any(|dep| dep.job_id == job.id));
+                    if job.is_terminal() && depended_on {
+                        self.finished_jobs.insert(job.id.clone(), job.step.clone());
+                    }
+                }
+                let jobs = &self.jobs;
+                self.finished_jobs.retain(|dep_id, _| {
+                    jobs.values()
+                        .any(|job| job.after.iter().any(|dep| &dep.job_id == dep_id))
+                });
+                self.timers.remove(TimerId::step_retry(id).as_str());
+                release_job_locks(&mut self.locks, id.as_str());
                 // Clean up all decisions associated with the deleted job
                 self.decisions.retain(|_, d| d.job_id != id.as_str());
                 // Remove agents owned by this job
@@ -747,15 +1263,24 @@ impl MaterializedState {
                 project_root,
                 runbook_hash,
                 queue_name,
+                queues,
                 concurrency,
+                rate,
                 namespace,
             } => {
                 let key = scoped_name(namespace, worker_name);
-                // Preserve active_job_ids from before restart
-                let existing_job_ids = self
+                // Preserve active jobs, agent runs, and the rate limiter's
+                // bucket from before restart
+                let (existing_job_ids, existing_agent_run_ids, bucket_full_at_ms) = self
                     .workers
                     .get(&key)
-                    .map(|w| w.active_job_ids.clone())
+                    .map(|w| {
+                        (
+                            w.active_job_ids.clone(),
+                            w.active_agent_run_ids.clone(),
+                            w.bucket_full_at_ms,
+                        )
+                    })
                     .unwrap_or_default();
 
                 if !namespace.is_empty() {
@@ -771,8 +1296,12 @@ impl MaterializedState {
                         runbook_hash: runbook_hash.clone(),
                         status: "running".to_string(),
                         active_job_ids: existing_job_ids,
+                        active_agent_run_ids: existing_agent_run_ids,
                         queue_name: queue_name.clone(),
+                        queues: queues.clone(),
                         concurrency: *concurrency,
+                        rate: rate.clone(),
+                        bucket_full_at_ms,
                     },
                 );
             }
@@ -780,14 +1309,22 @@ impl MaterializedState {
             Event::WorkerItemDispatched {
                 worker_name,
                 job_id,
+                agent_run_id,
+                bucket_full_at_ms,
                 namespace,
                 ..
             } => {
                 let key = scoped_name(namespace, worker_name);
                 if let Some(record) = self.workers.get_mut(&key) {
-                    let pid = job_id.to_string();
-                    if !record.active_job_ids.contains(&pid) {
-                        record.active_job_ids.push(pid);
+                    if bucket_full_at_ms.is_some() {
+                        record.bucket_full_at_ms = *bucket_full_at_ms;
+                    }
+                    let (active, id) = match agent_run_id {
+                        Some(ar_id) => (&mut record.active_agent_run_ids, ar_id.clone()),
+                        None => (&mut record.active_job_ids, job_id.to_string()),
+                    };
+                    if !active.contains(&id) {
+                        active.push(id);
                     }
                 }
             }
@@ -805,11 +1342,13 @@ impl MaterializedState {
             Event::WorkerResized {
                 worker_name,
                 concurrency,
+                rate,
                 namespace,
             } => {
                 let key = scoped_name(namespace, worker_name);
                 if let Some(record) = self.workers.get_mut(&key) {
                     record.concurrency = *concurrency;
+                    record.rate = rate.clone();
                 }
             }
 
@@ -828,6 +1367,11 @@ impl MaterializedState {
                 data,
                 pushed_at_epoch_ms,
                 namespace,
+                priority,
+                visible_after_epoch_ms,
+                dedup_key,
+                expires_at_epoch_ms,
+                drop_on_expire,
             } => {
                 let key = scoped_name(namespace, queue_name);
                 let items = self.queue_items.entry(key).or_default();
@@ -841,6 +1385,13 @@ impl MaterializedState {
                         worker_name: None,
                         pushed_at_epoch_ms: *pushed_at_epoch_ms,
                         failure_count: 0,
+                        priority: *priority,
+                        visible_after_epoch_ms: *visible_after_epoch_ms,
+                        dedup_key: dedup_key.clone(),
+                        completed_at_epoch_ms: None,
+                        expires_at_epoch_ms: *expires_at_epoch_ms,
+                        drop_on_expire: *drop_on_expire,
+                        lease_expires_at_epoch_ms: None,
                     });
                 }
             }
@@ -864,11 +1415,14 @@ impl MaterializedState {
                 queue_name,
                 item_id,
                 namespace,
+                completed_at_epoch_ms,
             } => {
                 let key = scoped_name(namespace, queue_name);
                 if let Some(items) = self.queue_items.get_mut(&key) {
                     if let Some(item) = items.iter_mut().find(|i| i.id == *item_id) {
                         item.status = QueueItemStatus::Completed;
+                        item.completed_at_epoch_ms = *completed_at_epoch_ms;
+                        item.lease_expires_at_epoch_ms = None;
                     }
                 }
             }
@@ -888,6 +1442,7 @@ impl MaterializedState {
                             item.failure_count += 1;
                         }
                         item.status = QueueItemStatus::Failed;
+                        item.lease_expires_at_epoch_ms = None;
                     }
                 }
             }
@@ -907,12 +1462,15 @@ impl MaterializedState {
                 queue_name,
                 item_id,
                 namespace,
+                automatic,
             } => {
                 let key = scoped_name(namespace, queue_name);
                 if let Some(items) = self.queue_items.get_mut(&key) {
                     if let Some(item) = items.iter_mut().find(|i| i.id == *item_id) {
                         item.status = QueueItemStatus::Pending;
-                        item.failure_count = 0;
+                        if !automatic {
+                            item.failure_count = 0;
+                        }
                         item.worker_name = None;
                     }
                 }
@@ -931,24 +1489,120 @@ impl MaterializedState {
                 }
             }
 
+            Event::QueueItemPrioritized {
+                queue_name,
+                item_id,
+                priority,
+                namespace,
+            } => {
+                let key = scoped_name(namespace, queue_name);
+                if let Some(items) = self.queue_items.get_mut(&key) {
+                    if let Some(item) = items.iter_mut().find(|i| i.id == *item_id) {
+                        item.priority = *priority;
+                    }
+                }
+            }
+
+            Event::QueueItemExpired {
+                queue_name,
+                item_id,
+                namespace,
+            } => {
+                let key = scoped_name(namespace, queue_name);
+                if let Some(items) = self.queue_items.get_mut(&key) {
+                    if let Some(item) = items
+                        .iter_mut()
+                        .find(|i| i.id == *item_id && i.status == QueueItemStatus::Pending)
+                    {
+                        item.status = QueueItemStatus::Expired;
+                    }
+                }
+            }
+
+            Event::QueueItemLeased {
+                queue_name,
+                item_id,
+                expires_at_epoch_ms,
+                namespace,
+            } => {
+                let key = scoped_name(namespace, queue_name);
+                if let Some(items) = self.queue_items.get_mut(&key) {
+                    if let Some(item) = items
+                        .iter_mut()
+                        .find(|i| i.id == *item_id && i.status == QueueItemStatus::Active)
+                    {
+                        item.lease_expires_at_epoch_ms = Some(*expires_at_epoch_ms);
+                    }
+                }
+            }
+
+            Event::QueueLeaseExpired {
+                queue_name,
+                item_id,
+                namespace,
+            } => {
+                let key = scoped_name(namespace, queue_name);
+                if let Some(items) = self.queue_items.get_mut(&key) {
+                    // Only an active item can lose its lease, which also keeps
+                    // a replayed event from counting the failure twice
+                    if let Some(item) = items
+                        .iter_mut()
+                        .find(|i| i.id == *item_id && i.status == QueueItemStatus::Active)
+                    {
+                        item.status = QueueItemStatus::Failed;
+                        item.failure_count += 1;
+                        item.lease_expires_at_epoch_ms = None;
+                    }
+                }
+                // The handler is gone; free the worker slots it held so a
+                // restart doesn't count them as busy
+                let (jobs, agent_runs) = (&self.jobs, &self.agent_runs);
+                for worker in self.workers.values_mut() {
+                    if worker.namespace == *namespace
+                        && worker.queue_names().contains(&queue_name.as_str())
+                    {
+                        worker.active_job_ids.retain(|id| jobs.contains_key(id));
+                        worker
+                            .active_agent_run_ids
+                            .retain(|id| agent_runs.contains_key(id));
+                    }
+                }
+            }
+
+            Event::QueueItemMerged {
+                queue_name,
+                item_id,
+                data,
+                priority,
+                namespace,
+            } => {
+                let key = scoped_name(namespace, queue_name);
+                if let Some(items) = self.queue_items.get_mut(&key) {
+                    if let Some(item) = items.iter_mut().find(|i| i.id == *item_id) {
+                        item.data = data.clone();
+                        item.priority = *priority;
+                    }
+                }
+            }
+
             // -- cron events --
             Event::CronStarted {
                 cron_name,
                 project_root,
                 runbook_hash,
                 interval,
+                schedule,
+                timezone,
                 run_target,
                 namespace,
+                started_at_ms,
+                resumed_from_ms: _,
             } => {
                 if !namespace.is_empty() {
                     self.project_roots
                         .insert(namespace.clone(), project_root.clone());
                 }
                 let key = scoped_name(namespace, cron_name);
-                let now_ms = std::time::SystemTime::now()
-                    .duration_since(std::time::UNIX_EPOCH)
-                    .unwrap_or_default()
-                    .as_millis() as u64;
                 // Preserve last_fired_at_ms across restarts (re-emitted CronStarted)
                 let last_fired_at_ms = self.crons.get(&key).and_then(|r| r.last_fired_at_ms);
                 self.crons.insert(
@@ -960,8 +1614,10 @@ impl MaterializedState {
                         runbook_hash: runbook_hash.clone(),
                         status: "running".to_string(),
                         interval: interval.clone(),
+                        schedule: schedule.clone(),
+                        timezone: timezone.clone(),
                         run_target: run_target.clone(),
-                        started_at_ms: now_ms,
+                        started_at_ms: *started_at_ms,
                         last_fired_at_ms,
                     },
                 );
@@ -980,10 +1636,79 @@ impl MaterializedState {
             Event::CronFired {
                 cron_name,
                 namespace,
+                fired_at_ms,
                 ..
             } => {
                 let key = scoped_name(namespace, cron_name);
-                if let Some(record) = self.crons.get_mut(&key) {
+                // Events written before fire times were recorded carry 0
+                if let Some(record) = self.crons.get_mut(&key).filter(|_| *fired_at_ms > 0) {
+                    record.last_fired_at_ms = Some(*fired_at_ms);
+                }
+            }
+
+            Event::CronDeleted {
+                cron_name,
+                namespace,
+            } => {
+                let key = scoped_name(namespace, cron_name);
+                self.crons.remove(&key);
+            }
+
+            // -- watch events --
+            Event::WatchStarted {
+                watch_name,
+                project_root,
+                runbook_hash,
+                paths,
+                debounce,
+                run_target,
+                namespace,
+            } => {
+                if !namespace.is_empty() {
+                    self.project_roots
+                        .insert(namespace.clone(), project_root.clone());
+                }
+                let key = scoped_name(namespace, watch_name);
+                let now_ms = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_millis() as u64;
+                // Preserve last_fired_at_ms across restarts (re-emitted WatchStarted)
+                let last_fired_at_ms = self.watches.get(&key).and_then(|r| r.last_fired_at_ms);
+                self.watches.insert(
+                    key,
+                    WatchRecord {
+                        name: watch_name.clone(),
+                        namespace: namespace.clone(),
+                        project_root: project_root.clone(),
+                        runbook_hash: runbook_hash.clone(),
+                        status: "running".to_string(),
+                        paths: paths.clone(),
+                        debounce: debounce.clone(),
+                        run_target: run_target.clone(),
+                        started_at_ms: now_ms,
+                        last_fired_at_ms,
+                    },
+                );
+            }
+
+            Event::WatchStopped {
+                watch_name,
+                namespace,
+            } => {
+                let key = scoped_name(namespace, watch_name);
+                if let Some(record) = self.watches.get_mut(&key) {
+                    record.status = "stopped".to_string();
+                }
+            }
+
+            Event::WatchFired {
+                watch_name,
+                namespace,
+                ..
+            } => {
+                let key = scoped_name(namespace, watch_name);
+                if let Some(record) = self.watches.get_mut(&key) {
                     let now_ms = std::time::SystemTime::now()
                         .duration_since(std::time::UNIX_EPOCH)
                         .unwrap_or_default()
@@ -992,12 +1717,94 @@ impl MaterializedState {
                 }
             }
 
-            Event::CronDeleted {
-                cron_name,
+            // -- git trigger events --
+            Event::GitTriggerStarted {
+                trigger_name,
+                project_root,
+                runbook_hash,
+                ref_pattern,
+                run_target,
                 namespace,
             } => {
-                let key = scoped_name(namespace, cron_name);
-                self.crons.remove(&key);
+                if !namespace.is_empty() {
+                    self.project_roots
+                        .insert(namespace.clone(), project_root.clone());
+                }
+                let key = scoped_name(namespace, trigger_name);
+                let now_ms = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_millis() as u64;
+                // Ref positions carry over restarts and stop/start cycles, so
+                // moves made in between run once on the next scan. A changed
+                // pattern starts over from a fresh baseline.
+                let previous = self
+                    .git_triggers
+                    .remove(&key)
+                    .filter(|r| r.ref_pattern == *ref_pattern);
+                self.git_triggers.insert(
+                    key,
+                    GitTriggerRecord {
+                        name: trigger_name.clone(),
+                        namespace: namespace.clone(),
+                        project_root: project_root.clone(),
+                        runbook_hash: runbook_hash.clone(),
+                        status: "running".to_string(),
+                        ref_pattern: ref_pattern.clone(),
+                        run_target: run_target.clone(),
+                        started_at_ms: now_ms,
+                        last_fired_at_ms: previous.as_ref().and_then(|r| r.last_fired_at_ms),
+                        baselined: previous.as_ref().is_some_and(|r| r.baselined),
+                        refs: previous.map(|r| r.refs).unwrap_or_default(),
+                    },
+                );
+            }
+
+            Event::GitTriggerStopped {
+                trigger_name,
+                namespace,
+            } => {
+                let key = scoped_name(namespace, trigger_name);
+                if let Some(record) = self.git_triggers.get_mut(&key) {
+                    record.status = "stopped".to_string();
+                }
+            }
+
+            Event::GitRefsBaselined {
+                trigger_name,
+                namespace,
+                refs,
+            } => {
+                let key = scoped_name(namespace, trigger_name);
+                if let Some(record) = self.git_triggers.get_mut(&key) {
+                    record.refs = refs.clone();
+                    record.baselined = true;
+                }
+            }
+
+            Event::GitRefMoved {
+                trigger_name,
+                namespace,
+                ref_name,
+                new,
+                job_id,
+                ..
+            } => {
+                let key = scoped_name(namespace, trigger_name);
+                if let Some(record) = self.git_triggers.get_mut(&key) {
+                    if new.is_empty() {
+                        record.refs.remove(ref_name);
+                    } else {
+                        record.refs.insert(ref_name.clone(), new.clone());
+                    }
+                    if job_id.is_some() {
+                        let now_ms = std::time::SystemTime::now()
+                            .duration_since(std::time::UNIX_EPOCH)
+                            .unwrap_or_default()
+                            .as_millis() as u64;
+                        record.last_fired_at_ms = Some(now_ms);
+                    }
+                }
             }
 
             // -- decision events --
@@ -1011,6 +1818,8 @@ impl MaterializedState {
                 options,
                 created_at_ms,
                 namespace,
+                step,
+                fields,
             } => {
                 // Idempotency: skip if already exists
                 if !self.decisions.contains_key(id) {
@@ -1039,6 +1848,9 @@ impl MaterializedState {
                             resolved_at_ms: None,
                             superseded_by: None,
                             namespace: namespace.clone(),
+                            step: step.clone(),
+                            fields: fields.clone(),
+                            values: HashMap::new(),
                         },
                     );
                 }
@@ -1063,11 +1875,13 @@ impl MaterializedState {
                 chosen,
                 message,
                 resolved_at_ms,
+                values,
                 ..
             } => {
                 if let Some(decision) = self.decisions.get_mut(id) {
                     decision.chosen = *chosen;
                     decision.message.clone_from(message);
+                    decision.values.clone_from(values);
                     decision.resolved_at_ms = Some(*resolved_at_ms);
                 }
             }
@@ -1126,6 +1940,9 @@ impl MaterializedState {
                             status: AgentRecordStatus::Running,
                             created_at_ms: now,
                             updated_at_ms: now,
+                            usage: Default::default(),
+                            model: None,
+                            budget_exceeded: false,
                         });
                 }
             }
@@ -1139,9 +1956,13 @@ impl MaterializedState {
                     run.updated_at_ms = epoch_ms_now();
                 }
 
-                // Clean up unresolved decisions for terminal agent runs
+                // Clean up unresolved decisions for terminal agent runs and
+                // free their worker slot
                 if status.is_terminal() {
                     let ar_id = id.as_str().to_string();
+                    for record in self.workers.values_mut() {
+                        record.active_agent_run_ids.retain(|a| a != &ar_id);
+                    }
                     self.decisions.retain(|_, d| match &d.owner {
                         OwnerId::AgentRun(owner_id) if owner_id.as_str() == ar_id => {
                             d.is_resolved()
@@ -1170,10 +1991,17 @@ impl MaterializedState {
                 }
             }
 
+            Event::TimerScheduled { id, fires_at_ms } => {
+                self.timers.insert(id.to_string(), *fires_at_ms);
+            }
+
+            Event::TimerStart { id } | Event::TimerCancelled { id } => {
+                self.timers.remove(id.as_str());
+            }
+
             // Events that don't affect persisted state
             // (These are action/signal events handled by the runtime)
             Event::Custom
-            | Event::TimerStart { .. }
             | Event::SessionInput { .. }
             | Event::AgentInput { .. }
             | Event::JobResume { .. }
@@ -1187,6 +2015,8 @@ impl MaterializedState {
             | Event::AgentPrompt { .. }
             | Event::AgentStop { .. }
             | Event::CronOnce { .. }
+            | Event::WatchChanged { .. }
+            | Event::GitRefsScanned { .. }
             | Event::Shutdown => {}
         }
     }
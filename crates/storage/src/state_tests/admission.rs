@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use oj_core::StepStatus;
+
+fn queued(job_id: &str) -> Event {
+    Event::JobQueued {
+        id: JobId::new(job_id),
+    }
+}
+
+#[test]
+fn job_created_records_priority_and_project_root() {
+    let mut state = MaterializedState::default();
+    let mut event = job_create_event("job-1", "build", "b", "compile");
+    if let Event::JobCreated {
+        priority,
+        project_root,
+        ..
+    } = &mut event
+    {
+        *priority = 3;
+        *project_root = Some(PathBuf::from("/proj"));
+    }
+    state.apply_event(&event);
+
+    let job = &state.jobs["job-1"];
+    assert_eq!(job.priority, 3);
+    assert_eq!(job.project_root, Some(PathBuf::from("/proj")));
+    assert!(!job.queued);
+}
+
+#[test]
+fn job_queued_holds_first_step_until_admitted() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&job_create_event("job-1", "build", "b", "compile"));
+    state.apply_event(&queued("job-1"));
+    state.apply_event(&queued("job-1"));
+
+    let job = &state.jobs["job-1"];
+    assert!(job.queued);
+    assert_eq!(job.step_status, StepStatus::Blocked("queued".to_string()));
+    assert_eq!(job.step_history.len(), 1);
+
+    state.apply_event(&Event::JobAdmitted {
+        id: JobId::new("job-1"),
+    });
+    assert!(!state.jobs["job-1"].queued);
+}
+
+#[test]
+fn job_queued_ignores_running_jobs() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&job_create_event("job-1", "build", "b", "compile"));
+    state.apply_event(&step_started_event("job-1"));
+    state.apply_event(&queued("job-1"));
+
+    let job = &state.jobs["job-1"];
+    assert!(!job.queued);
+    assert_eq!(job.step_status, StepStatus::Running);
+}
@@ -273,3 +273,66 @@ fn idempotent_step_started() {
     assert_eq!(state.agents.len(), 1);
     assert!(state.agents.contains_key("agent-1"));
 }
+
+fn usage_event(agent_id: &str, output_tokens: u64, model: Option<&str>) -> Event {
+    Event::AgentUsage {
+        agent_id: oj_core::AgentId::new(agent_id),
+        usage: oj_core::TokenUsage {
+            output_tokens,
+            ..Default::default()
+        },
+        model: model.map(String::from),
+    }
+}
+
+#[test]
+fn usage_replaces_totals_and_keeps_last_model() {
+    let mut state = state_with_job_agent("pipe-1", "agent-1");
+    let model = "claude-sonnet-4-5-20250929";
+
+    state.apply_event(&usage_event("agent-1", 100, Some(model)));
+    state.apply_event(&usage_event("agent-1", 250, None));
+    // Replaying an event is idempotent: totals are cumulative
+    state.apply_event(&usage_event("agent-1", 250, None));
+
+    let record = &state.agents["agent-1"];
+    assert_eq!(record.usage.output_tokens, 250);
+    assert_eq!(record.model.as_deref(), Some(model));
+}
+
+#[test]
+fn job_usage_sums_agents_owned_by_the_job() {
+    let mut state = state_with_job_agent("pipe-1", "agent-1");
+    state.apply_event(&step_started_with_agent("pipe-1", "agent-2"));
+    state.apply_event(&job_create_event("pipe-2", "build", "other", "init"));
+    state.apply_event(&step_started_with_agent("pipe-2", "agent-3"));
+
+    let sonnet = Some("claude-sonnet-4-5-20250929");
+    state.apply_event(&usage_event("agent-1", 1_000_000, sonnet));
+    state.apply_event(&usage_event("agent-2", 500_000, None));
+    state.apply_event(&usage_event("agent-3", 7, sonnet));
+
+    let (usage, cost) = state.job_usage("pipe-1");
+    assert_eq!(usage.output_tokens, 1_500_000);
+    // agent-2 has no model, so only agent-1 is priced ($15/M output)
+    assert_eq!(cost, Some(15.0));
+    assert_eq!(state.job_usage("missing").1, None);
+}
+
+#[test]
+fn budget_exceeded_latches_by_scope() {
+    let mut state = state_with_job_agent("pipe-1", "agent-1");
+    let exceeded = |scope| Event::AgentBudgetExceeded {
+        agent_id: oj_core::AgentId::new("agent-1"),
+        owner: OwnerId::Job(JobId::new("pipe-1")),
+        scope,
+        reason: "over budget".to_string(),
+    };
+
+    state.apply_event(&exceeded(oj_core::BudgetScope::Agent));
+    assert!(state.agents["agent-1"].budget_exceeded);
+    assert!(!state.jobs["pipe-1"].budget_exceeded);
+
+    state.apply_event(&exceeded(oj_core::BudgetScope::Job));
+    assert!(state.jobs["pipe-1"].budget_exceeded);
+}
@@ -12,8 +12,12 @@ fn cron_started_creates_record() {
         project_root: PathBuf::from("/test/project"),
         runbook_hash: "abc123".to_string(),
         interval: "30m".to_string(),
+        schedule: None,
+        timezone: None,
         run_target: "job:cleanup".to_string(),
         namespace: "myns".to_string(),
+        started_at_ms: 1_000,
+        resumed_from_ms: None,
     });
 
     let key = "myns/janitor";
@@ -34,8 +38,12 @@ fn cron_stopped_updates_status() {
         project_root: PathBuf::from("/test/project"),
         runbook_hash: "abc123".to_string(),
         interval: "30m".to_string(),
+        schedule: None,
+        timezone: None,
         run_target: "job:cleanup".to_string(),
         namespace: String::new(),
+        started_at_ms: 1_000,
+        resumed_from_ms: None,
     });
 
     assert_eq!(state.crons["janitor"].status, "running");
@@ -58,8 +66,12 @@ fn cron_started_is_idempotent() {
         project_root: PathBuf::from("/test/project"),
         runbook_hash: "abc123".to_string(),
         interval: "30m".to_string(),
+        schedule: None,
+        timezone: None,
         run_target: "job:cleanup".to_string(),
         namespace: String::new(),
+        started_at_ms: 1_000,
+        resumed_from_ms: None,
     });
 
     // Re-start with different interval (simulates runbook update)
@@ -68,8 +80,12 @@ fn cron_started_is_idempotent() {
         project_root: PathBuf::from("/test/project"),
         runbook_hash: "def456".to_string(),
         interval: "1h".to_string(),
+        schedule: None,
+        timezone: None,
         run_target: "job:cleanup".to_string(),
         namespace: String::new(),
+        started_at_ms: 1_000,
+        resumed_from_ms: None,
     });
 
     assert_eq!(state.crons.len(), 1);
@@ -85,8 +101,12 @@ fn cron_deleted_removes_record() {
         project_root: PathBuf::from("/test/project"),
         runbook_hash: "abc123".to_string(),
         interval: "30m".to_string(),
+        schedule: None,
+        timezone: None,
         run_target: "job:cleanup".to_string(),
         namespace: "myns".to_string(),
+        started_at_ms: 1_000,
+        resumed_from_ms: None,
     });
 
     assert!(state.crons.contains_key("myns/janitor"));
@@ -112,8 +132,12 @@ fn cron_deleted_empty_namespace() {
         project_root: PathBuf::from("/test/project"),
         runbook_hash: "abc123".to_string(),
         interval: "30m".to_string(),
+        schedule: None,
+        timezone: None,
         run_target: "job:cleanup".to_string(),
         namespace: String::new(),
+        started_at_ms: 1_000,
+        resumed_from_ms: None,
     });
 
     assert!(state.crons.contains_key("janitor"));
@@ -143,6 +167,7 @@ fn cron_fired_is_noop_for_state() {
         job_id: JobId::new("pipe-123"),
         agent_run_id: None,
         namespace: String::new(),
+        fired_at_ms: 2_000,
     });
     // CronFired should not create a record if the cron doesn't exist
     assert!(state.crons.is_empty());
@@ -156,8 +181,12 @@ fn cron_fired_updates_last_fired_at() {
         project_root: PathBuf::from("/test/project"),
         runbook_hash: "abc123".to_string(),
         interval: "30m".to_string(),
+        schedule: None,
+        timezone: None,
         run_target: "job:cleanup".to_string(),
         namespace: String::new(),
+        started_at_ms: 1_000,
+        resumed_from_ms: None,
     });
 
     assert!(state.crons["janitor"].last_fired_at_ms.is_none());
@@ -167,9 +196,10 @@ fn cron_fired_updates_last_fired_at() {
         job_id: JobId::new("pipe-123"),
         agent_run_id: None,
         namespace: String::new(),
+        fired_at_ms: 2_000,
     });
 
-    assert!(state.crons["janitor"].last_fired_at_ms.is_some());
+    assert_eq!(state.crons["janitor"].last_fired_at_ms, Some(2_000));
 }
 
 #[test]
@@ -180,11 +210,15 @@ fn cron_started_sets_started_at_ms() {
         project_root: PathBuf::from("/test/project"),
         runbook_hash: "abc123".to_string(),
         interval: "30m".to_string(),
+        schedule: None,
+        timezone: None,
         run_target: "job:cleanup".to_string(),
         namespace: String::new(),
+        started_at_ms: 1_000,
+        resumed_from_ms: None,
     });
 
-    assert!(state.crons["janitor"].started_at_ms > 0);
+    assert_eq!(state.crons["janitor"].started_at_ms, 1_000);
 }
 
 #[test]
@@ -195,8 +229,12 @@ fn cron_restart_preserves_last_fired_at() {
         project_root: PathBuf::from("/test/project"),
         runbook_hash: "abc123".to_string(),
         interval: "30m".to_string(),
+        schedule: None,
+        timezone: None,
         run_target: "job:cleanup".to_string(),
         namespace: String::new(),
+        started_at_ms: 1_000,
+        resumed_from_ms: None,
     });
 
     state.apply_event(&Event::CronFired {
@@ -204,6 +242,7 @@ fn cron_restart_preserves_last_fired_at() {
         job_id: JobId::new("pipe-123"),
         agent_run_id: None,
         namespace: String::new(),
+        fired_at_ms: 2_000,
     });
 
     let fired_ms = state.crons["janitor"].last_fired_at_ms;
@@ -215,10 +254,36 @@ fn cron_restart_preserves_last_fired_at() {
         project_root: PathBuf::from("/test/project"),
         runbook_hash: "abc123".to_string(),
         interval: "30m".to_string(),
+        schedule: None,
+        timezone: None,
         run_target: "job:cleanup".to_string(),
         namespace: String::new(),
+        started_at_ms: 1_000,
+        resumed_from_ms: None,
     });
 
     // last_fired_at should be preserved
     assert_eq!(state.crons["janitor"].last_fired_at_ms, fired_ms);
 }
+
+#[test]
+fn cron_started_records_schedule_and_timezone() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&Event::CronStarted {
+        cron_name: "nightly".to_string(),
+        project_root: PathBuf::from("/test/project"),
+        runbook_hash: "abc123".to_string(),
+        interval: String::new(),
+        schedule: Some("0 9 * * 1-5".to_string()),
+        timezone: Some("America/New_York".to_string()),
+        run_target: "job:triage".to_string(),
+        namespace: String::new(),
+        started_at_ms: 1_000,
+        resumed_from_ms: None,
+    });
+
+    let record = &state.crons["nightly"];
+    assert_eq!(record.interval, "");
+    assert_eq!(record.schedule.as_deref(), Some("0 9 * * 1-5"));
+    assert_eq!(record.timezone.as_deref(), Some("America/New_York"));
+}
@@ -18,6 +18,8 @@ fn decision_created_event(id: &str, job_id: &str) -> Event {
         ],
         created_at_ms: 2_000_000,
         namespace: "testns".to_string(),
+        step: None,
+        fields: Vec::new(),
     }
 }
 
@@ -35,6 +37,8 @@ fn decision_for_agent_run(id: &str, ar_id: &str, created_at_ms: u64) -> Event {
         ],
         created_at_ms,
         namespace: "testns".to_string(),
+        step: None,
+        fields: Vec::new(),
     }
 }
 
@@ -52,6 +56,8 @@ fn decision_for_job_at(id: &str, job_id: &str, created_at_ms: u64) -> Event {
         ],
         created_at_ms,
         namespace: "testns".to_string(),
+        step: None,
+        fields: Vec::new(),
     }
 }
 
@@ -101,6 +107,7 @@ fn decision_resolved() {
         message: Some("Looks good".to_string()),
         resolved_at_ms: 3_000_000,
         namespace: "testns".to_string(),
+        values: HashMap::new(),
     });
 
     let dec = &state.decisions["dec-abc123"];
@@ -110,6 +117,33 @@ fn decision_resolved() {
     assert!(dec.is_resolved());
 }
 
+#[test]
+fn approval_decision_records_step_fields_and_values() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&job_create_event("pipe-1", "release", "r", "signoff"));
+    let mut created = decision_created_event("dec-1", "pipe-1");
+    if let Event::DecisionCreated { step, fields, .. } = &mut created {
+        *step = Some("signoff".to_string());
+        fields.push("reason".to_string());
+    }
+    state.apply_event(&created);
+    state.apply_event(&Event::DecisionResolved {
+        id: "dec-1".to_string(),
+        chosen: Some(1),
+        message: None,
+        resolved_at_ms: 3_000_000,
+        namespace: "testns".to_string(),
+        values: [("reason".to_string(), "staging verified".to_string())]
+            .into_iter()
+            .collect(),
+    });
+
+    let dec = &state.decisions["dec-1"];
+    assert_eq!(dec.step.as_deref(), Some("signoff"));
+    assert_eq!(dec.fields, vec!["reason".to_string()]);
+    assert_eq!(dec.values["reason"], "staging verified");
+}
+
 #[test]
 fn get_decision_prefix_lookup() {
     let state = state_with_job_and_decision("pipe-1", "dec-abc123");
@@ -149,6 +183,7 @@ fn job_terminal_preserves_resolved_decisions() {
         message: None,
         resolved_at_ms: 3_000_000,
         namespace: "testns".to_string(),
+        values: HashMap::new(),
     });
     assert!(state.decisions["dec-1"].is_resolved());
 
@@ -167,6 +202,7 @@ fn job_deleted_removes_all_decisions() {
         message: None,
         resolved_at_ms: 3_000_000,
         namespace: "testns".to_string(),
+        values: HashMap::new(),
     });
 
     assert_eq!(state.decisions.len(), 2);
@@ -281,6 +317,7 @@ fn new_decision_does_not_affect_already_resolved() {
         message: Some("approved".to_string()),
         resolved_at_ms: 2_500_000,
         namespace: "testns".to_string(),
+        values: HashMap::new(),
     });
     assert!(state.decisions["dec-1"].is_resolved());
 
@@ -313,6 +350,7 @@ fn superseded_decision_cannot_be_resolved() {
         message: None,
         resolved_at_ms: 4_000_000,
         namespace: "testns".to_string(),
+        values: HashMap::new(),
     });
 
     // The WAL handler always applies, but the superseded_by remains set
@@ -0,0 +1,82 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use oj_core::{DependencyCondition, DependencyState, JobDependency, StepStatus};
+
+fn blocked(job_id: &str, waiting_on: &[&str]) -> Event {
+    Event::JobBlocked {
+        id: JobId::new(job_id),
+        waiting_on: waiting_on.iter().map(|id| id.to_string()).collect(),
+    }
+}
+
+#[test]
+fn job_created_records_dependencies() {
+    let mut state = MaterializedState::default();
+    let mut event = job_create_event("job-2", "deploy", "d", "ship");
+    if let Event::JobCreated { after, .. } = &mut event {
+        after.push(JobDependency::new("job-1", DependencyCondition::Any));
+    }
+    state.apply_event(&event);
+
+    assert_eq!(
+        state.jobs["job-2"].after,
+        vec![JobDependency::new("job-1", DependencyCondition::Any)]
+    );
+}
+
+#[test]
+fn job_blocked_holds_first_step_with_short_ids() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&job_create_event("job-2", "deploy", "d", "ship"));
+    state.apply_event(&blocked("job-2", &["abcdef1234567890", "job-1"]));
+    state.apply_event(&blocked("job-2", &["abcdef1234567890", "job-1"]));
+
+    let job = &state.jobs["job-2"];
+    let reason = "after abcdef12, job-1".to_string();
+    assert_eq!(job.step_status, StepStatus::Blocked(reason.clone()));
+    assert_eq!(job.step_history.len(), 1);
+    assert_eq!(job.step_history[0].outcome, StepOutcome::Blocked(reason));
+}
+
+#[test]
+fn job_blocked_ignores_running_and_terminal_jobs() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&job_create_event("job-2", "deploy", "d", "ship"));
+    state.apply_event(&step_started_event("job-2"));
+    state.apply_event(&blocked("job-2", &["job-1"]));
+    assert_eq!(state.jobs["job-2"].step_status, StepStatus::Running);
+
+    state.apply_event(&job_transition_event("job-2", "done"));
+    state.apply_event(&blocked("job-2", &["job-1"]));
+    assert!(!state.jobs["job-2"].step_status.is_blocked());
+}
+
+#[test]
+fn deleting_a_done_dependency_keeps_a_tombstone() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&job_create_event("job-1", "build", "b", "compile"));
+    let mut event = job_create_event("job-2", "deploy", "d", "ship");
+    if let Event::JobCreated { after, .. } = &mut event {
+        after.push(JobDependency::new("job-1", DependencyCondition::Done));
+    }
+    state.apply_event(&event);
+    state.apply_event(&job_transition_event("job-1", "done"));
+
+    state.apply_event(&Event::JobDeleted {
+        id: JobId::new("job-1"),
+    });
+    let dep = JobDependency::new("job-1", DependencyCondition::Done);
+    assert!(!state.jobs.contains_key("job-1"));
+    assert_eq!(state.dependency_state(&dep), DependencyState::Met);
+
+    state.apply_event(&Event::JobDeleted {
+        id: JobId::new("job-2"),
+    });
+    assert!(state.finished_jobs.is_empty());
+    assert_eq!(
+        state.dependency_state(&dep),
+        DependencyState::Broken("dependency job job-1 not found".to_string())
+    );
+}
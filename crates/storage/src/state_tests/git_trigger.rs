@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use oj_core::{Event, JobId};
+use std::collections::BTreeMap;
+
+fn trigger_started(namespace: &str, ref_pattern: &str) -> Event {
+    Event::GitTriggerStarted {
+        trigger_name: "ci".to_string(),
+        project_root: PathBuf::from("/test/project"),
+        runbook_hash: "abc123".to_string(),
+        ref_pattern: ref_pattern.to_string(),
+        run_target: "job:ci".to_string(),
+        namespace: namespace.to_string(),
+    }
+}
+
+fn ref_moved(ref_name: &str, new: &str, job_id: Option<&str>) -> Event {
+    Event::GitRefMoved {
+        trigger_name: "ci".to_string(),
+        namespace: String::new(),
+        ref_name: ref_name.to_string(),
+        old: "a".repeat(40),
+        new: new.to_string(),
+        job_id: job_id.map(JobId::new),
+    }
+}
+
+fn baselined(refs: &[(&str, &str)]) -> Event {
+    Event::GitRefsBaselined {
+        trigger_name: "ci".to_string(),
+        namespace: String::new(),
+        refs: refs
+            .iter()
+            .map(|(r, sha)| (r.to_string(), sha.to_string()))
+            .collect::<BTreeMap<_, _>>(),
+    }
+}
+
+#[test]
+fn git_trigger_started_creates_record() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&trigger_started("app", "refs/heads/*"));
+
+    let record = &state.git_triggers["app/ci"];
+    assert_eq!(record.name, "ci");
+    assert_eq!(record.status, "running");
+    assert_eq!(record.ref_pattern, "refs/heads/*");
+    assert_eq!(record.run_target, "job:ci");
+    assert!(!record.baselined);
+    assert!(record.refs.is_empty());
+    assert_eq!(
+        state.project_root_for_namespace("app"),
+        Some(PathBuf::from("/test/project"))
+    );
+}
+
+#[test]
+fn git_trigger_stopped_updates_status() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&trigger_started("", "refs/heads/*"));
+    state.apply_event(&Event::GitTriggerStopped {
+        trigger_name: "ci".to_string(),
+        namespace: String::new(),
+    });
+
+    assert_eq!(state.git_triggers["ci"].status, "stopped");
+}
+
+#[test]
+fn ref_moves_track_positions() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&trigger_started("", "refs/heads/*"));
+    state.apply_event(&baselined(&[
+        ("refs/heads/main", "m1"),
+        ("refs/heads/old", "o1"),
+    ]));
+    assert!(state.git_triggers["ci"].baselined);
+
+    state.apply_event(&ref_moved("refs/heads/main", "m2", Some("job-1")));
+    state.apply_event(&ref_moved("refs/heads/new", "n1", None));
+    state.apply_event(&ref_moved("refs/heads/old", "", None));
+
+    let record = &state.git_triggers["ci"];
+    let refs: Vec<(&str, &str)> = record
+        .refs
+        .iter()
+        .map(|(r, sha)| (r.as_str(), sha.as_str()))
+        .collect();
+    assert_eq!(
+        refs,
+        vec![("refs/heads/main", "m2"), ("refs/heads/new", "n1")]
+    );
+    assert!(record.last_fired_at_ms.is_some());
+}
+
+#[test]
+fn positions_survive_restart() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&trigger_started("", "refs/heads/*"));
+    state.apply_event(&baselined(&[("refs/heads/main", "m1")]));
+    state.apply_event(&ref_moved("refs/heads/main", "m2", Some("job-1")));
+    let fired = state.git_triggers["ci"].last_fired_at_ms;
+
+    // The daemon re-emits GitTriggerStarted for running triggers on startup
+    state.apply_event(&trigger_started("", "refs/heads/*"));
+
+    let record = &state.git_triggers["ci"];
+    assert!(record.baselined);
+    assert_eq!(record.refs["refs/heads/main"], "m2");
+    assert_eq!(record.last_fired_at_ms, fired);
+}
+
+#[test]
+fn changed_pattern_resets_baseline() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&trigger_started("", "refs/heads/*"));
+    state.apply_event(&baselined(&[("refs/heads/main", "m1")]));
+
+    state.apply_event(&trigger_started("", "refs/heads/feature/*"));
+
+    let record = &state.git_triggers["ci"];
+    assert!(!record.baselined);
+    assert!(record.refs.is_empty());
+}
@@ -65,6 +65,8 @@ fn worker_item_dispatched_idempotent() {
         worker_name: "fixer".to_string(),
         item_id: "item-1".to_string(),
         job_id: JobId::new("pipe-1"),
+        agent_run_id: None,
+        bucket_full_at_ms: None,
         namespace: String::new(),
     });
 
@@ -75,6 +77,8 @@ fn worker_item_dispatched_idempotent() {
         worker_name: "fixer".to_string(),
         item_id: "item-1".to_string(),
         job_id: JobId::new("pipe-1"),
+        agent_run_id: None,
+        bucket_full_at_ms: None,
         namespace: String::new(),
     });
 
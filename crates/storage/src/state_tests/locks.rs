@@ -0,0 +1,142 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use oj_core::{Event, JobId, StepStatus};
+
+fn waiting(job_id: &str, step: &str, resources: &[&str]) -> Event {
+    Event::LockWaiting {
+        job_id: JobId::new(job_id),
+        step: step.to_string(),
+        resources: resources.iter().map(|r| r.to_string()).collect(),
+        namespace: "proj".to_string(),
+    }
+}
+
+fn acquired(job_id: &str, step: &str, resources: &[&str]) -> Event {
+    Event::LockAcquired {
+        job_id: JobId::new(job_id),
+        step: step.to_string(),
+        resources: resources.iter().map(|r| r.to_string()).collect(),
+        namespace: "proj".to_string(),
+    }
+}
+
+fn holders(state: &MaterializedState, key: &str) -> Vec<String> {
+    state.locks.get(key).map_or_else(Vec::new, |r| {
+        r.holders.iter().map(|c| c.job_id.clone()).collect()
+    })
+}
+
+fn waiters(state: &MaterializedState, key: &str) -> Vec<String> {
+    state.locks.get(key).map_or_else(Vec::new, |r| {
+        r.waiters.iter().map(|c| c.job_id.clone()).collect()
+    })
+}
+
+#[test]
+fn lock_acquired_records_holder() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&job_create_event("job-1", "merge", "m", "rebase"));
+    state.apply_event(&acquired("job-1", "rebase", &["lock.main"]));
+
+    assert_eq!(holders(&state, "proj/lock.main"), vec!["job-1"]);
+    assert!(waiters(&state, "proj/lock.main").is_empty());
+}
+
+#[test]
+fn lock_waiting_blocks_step_and_queues_job() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&job_create_event("job-1", "merge", "m", "rebase"));
+    state.apply_event(&job_create_event("job-2", "merge", "m", "rebase"));
+    state.apply_event(&acquired("job-1", "rebase", &["lock.main"]));
+    state.apply_event(&waiting("job-2", "rebase", &["lock.main", "semaphore.gpu"]));
+
+    let job = &state.jobs["job-2"];
+    assert_eq!(
+        job.step_status,
+        StepStatus::Blocked("lock.main, semaphore.gpu".to_string())
+    );
+    assert_eq!(
+        job.step_history.last().map(|r| &r.outcome),
+        Some(&StepOutcome::Blocked(
+            "lock.main, semaphore.gpu".to_string()
+        ))
+    );
+    assert_eq!(waiters(&state, "proj/lock.main"), vec!["job-2"]);
+    assert_eq!(waiters(&state, "proj/semaphore.gpu"), vec!["job-2"]);
+}
+
+#[test]
+fn lock_events_are_idempotent() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&job_create_event("job-1", "merge", "m", "rebase"));
+    state.apply_event(&waiting("job-1", "rebase", &["lock.main"]));
+    state.apply_event(&waiting("job-1", "rebase", &["lock.main"]));
+    assert_eq!(waiters(&state, "proj/lock.main"), vec!["job-1"]);
+
+    state.apply_event(&acquired("job-1", "rebase", &["lock.main"]));
+    state.apply_event(&acquired("job-1", "rebase", &["lock.main"]));
+    assert_eq!(holders(&state, "proj/lock.main"), vec!["job-1"]);
+    assert!(waiters(&state, "proj/lock.main").is_empty());
+}
+
+#[test]
+fn job_advanced_releases_locks() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&job_create_event("job-1", "merge", "m", "rebase"));
+    state.apply_event(&acquired("job-1", "rebase", &["lock.main"]));
+
+    state.apply_event(&job_transition_event("job-1", "push"));
+
+    assert!(state.locks.is_empty());
+}
+
+#[test]
+fn step_retrying_releases_locks() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&job_create_event("job-1", "merge", "m", "rebase"));
+    state.apply_event(&acquired("job-1", "rebase", &["lock.main"]));
+
+    state.apply_event(&Event::StepRetrying {
+        job_id: JobId::new("job-1"),
+        step: "rebase".to_string(),
+        attempt: 1,
+        error: "shell exit code: 1".to_string(),
+    });
+
+    assert!(state.locks.is_empty());
+}
+
+#[test]
+fn job_cancel_releases_waiting_claims() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&job_create_event("job-1", "merge", "m", "rebase"));
+    state.apply_event(&waiting("job-1", "rebase", &["lock.main"]));
+
+    state.apply_event(&job_transition_event("job-1", "cancelled"));
+
+    assert!(state.locks.is_empty());
+}
+
+#[test]
+fn job_deleted_releases_locks() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&job_create_event("job-1", "merge", "m", "rebase"));
+    state.apply_event(&acquired("job-1", "rebase", &["lock.main"]));
+
+    state.apply_event(&job_delete_event("job-1"));
+
+    assert!(state.locks.is_empty());
+}
+
+#[test]
+fn stale_lock_acquired_after_step_change_is_ignored() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&job_create_event("job-1", "merge", "m", "rebase"));
+    state.apply_event(&job_transition_event("job-1", "push"));
+
+    state.apply_event(&acquired("job-1", "rebase", &["lock.main"]));
+
+    assert!(state.locks.is_empty());
+}
@@ -2,12 +2,20 @@
 // Copyright (c) 2026 Alfred Jean LLC
 
 mod action_attempts;
+mod admission;
 mod agents;
 mod cron;
 mod decisions;
+mod dependencies;
+mod git_trigger;
 mod idempotency;
+mod locks;
+mod parallel;
 mod queue;
 mod step_history;
+mod sub_job;
+mod timers;
+mod watch;
 mod workers;
 
 use super::*;
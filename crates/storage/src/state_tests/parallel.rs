@@ -0,0 +1,88 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use oj_core::{Event, JobId};
+
+fn branched(parent: &str, step: &str, branch: &str, branch_job_id: &str) -> Event {
+    Event::StepBranched {
+        job_id: JobId::new(parent),
+        step: step.to_string(),
+        branch: branch.to_string(),
+        branch_job_id: JobId::new(branch_job_id),
+    }
+}
+
+/// A parent on its "checks" step with "lint" and "test" branch jobs.
+fn fanned_out() -> MaterializedState {
+    let mut state = MaterializedState::default();
+    state.apply_event(&job_create_event("job-1", "review", "r", "checks"));
+    state.apply_event(&job_create_event("job-2", "review", "r/lint", "lint"));
+    state.apply_event(&job_create_event("job-3", "review", "r/test", "test"));
+    state.apply_event(&branched("job-1", "checks", "lint", "job-2"));
+    state.apply_event(&branched("job-1", "checks", "test", "job-3"));
+    state
+}
+
+#[test]
+fn step_branched_links_branch_and_records_it_on_parent() {
+    let mut state = fanned_out();
+    // Replays must not duplicate the branch record
+    state.apply_event(&branched("job-1", "checks", "lint", "job-2"));
+
+    let branch = &state.jobs["job-2"];
+    assert_eq!(branch.parent_job_id.as_deref(), Some("job-1"));
+    assert!(branch.is_branch);
+
+    let record = &state.jobs["job-1"].step_history[0];
+    let names: Vec<_> = record.branches.iter().map(|b| b.name.as_str()).collect();
+    assert_eq!(names, vec!["lint", "test"]);
+    assert_eq!(record.branches[0].job_id.as_deref(), Some("job-2"));
+    assert_eq!(record.branches[0].outcome, StepOutcome::Running);
+}
+
+#[test]
+fn step_branched_ignored_when_parent_left_step() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&job_create_event("job-1", "review", "r", "checks"));
+    state.apply_event(&job_create_event("job-2", "review", "r/lint", "lint"));
+    state.apply_event(&job_transition_event("job-1", "merge"));
+    state.apply_event(&branched("job-1", "checks", "lint", "job-2"));
+
+    assert!(state.jobs["job-1"]
+        .step_history
+        .iter()
+        .all(|r| r.branches.is_empty()));
+}
+
+#[test]
+fn branch_outcome_is_mirrored_on_parent() {
+    let mut state = fanned_out();
+    state.apply_event(&Event::StepCompleted {
+        job_id: JobId::new("job-2"),
+        step: "lint".to_string(),
+    });
+    state.apply_event(&job_transition_event("job-2", "done"));
+    state.apply_event(&step_failed_event("job-3", "test", "exit 1"));
+    state.apply_event(&job_transition_event("job-3", "failed"));
+
+    let record = &state.jobs["job-1"].step_history[0];
+    assert_eq!(record.branches[0].outcome, StepOutcome::Completed);
+    assert!(record.branches[0].finished_at_ms.is_some());
+    assert_eq!(
+        record.branches[1].outcome,
+        StepOutcome::Failed("exit 1".to_string())
+    );
+}
+
+#[test]
+fn cancelled_branch_is_recorded_as_cancelled() {
+    let mut state = fanned_out();
+    state.apply_event(&job_transition_event("job-3", "cancelled"));
+
+    let record = &state.jobs["job-1"].step_history[0];
+    assert_eq!(
+        record.branches[1].outcome,
+        StepOutcome::Failed("cancelled".to_string())
+    );
+}
@@ -8,6 +8,7 @@ fn queue_completed_event(queue_name: &str, item_id: &str) -> Event {
         queue_name: queue_name.to_string(),
         item_id: item_id.to_string(),
         namespace: String::new(),
+        completed_at_epoch_ms: None,
     }
 }
 
@@ -153,6 +154,7 @@ fn item_retry_resets_to_pending() {
         queue_name: "bugs".to_string(),
         item_id: "item-1".to_string(),
         namespace: String::new(),
+        automatic: false,
     });
 
     assert_eq!(
@@ -163,6 +165,27 @@ fn item_retry_resets_to_pending() {
     assert!(state.queue_items["bugs"][0].worker_name.is_none());
 }
 
+#[test]
+fn automatic_retry_keeps_failure_count() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&queue_pushed_event("bugs", "item-1"));
+    state.apply_event(&queue_taken_event("bugs", "item-1", "fixer"));
+    state.apply_event(&queue_failed_event("bugs", "item-1", "job failed"));
+
+    state.apply_event(&Event::QueueItemRetry {
+        queue_name: "bugs".to_string(),
+        item_id: "item-1".to_string(),
+        namespace: String::new(),
+        automatic: true,
+    });
+
+    assert_eq!(
+        state.queue_items["bugs"][0].status,
+        QueueItemStatus::Pending
+    );
+    assert_eq!(state.queue_items["bugs"][0].failure_count, 1);
+}
+
 #[test]
 fn item_dead_sets_dead_status() {
     let mut state = MaterializedState::default();
@@ -218,6 +241,7 @@ fn item_retry_on_dead_resets_to_pending() {
         queue_name: "bugs".to_string(),
         item_id: "item-1".to_string(),
         namespace: String::new(),
+        automatic: false,
     });
 
     assert_eq!(
@@ -251,3 +275,207 @@ fn failure_count_backward_compat_defaults_to_zero() {
     let state: MaterializedState = serde_json::from_str(json).expect("deserialize");
     assert_eq!(state.queue_items["bugs"][0].failure_count, 0);
 }
+
+#[test]
+fn item_prioritized_sets_priority() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&queue_pushed_event("bugs", "item-1"));
+    assert_eq!(state.queue_items["bugs"][0].priority, 0);
+
+    let event = Event::QueueItemPrioritized {
+        queue_name: "bugs".to_string(),
+        item_id: "item-1".to_string(),
+        priority: 7,
+        namespace: String::new(),
+    };
+    state.apply_event(&event);
+    state.apply_event(&event);
+
+    assert_eq!(state.queue_items["bugs"][0].priority, 7);
+}
+
+#[test]
+fn delayed_push_is_scheduled_until_visible() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&Event::QueuePushed {
+        queue_name: "merges".to_string(),
+        item_id: "item-1".to_string(),
+        data: HashMap::new(),
+        pushed_at_epoch_ms: 1_000,
+        namespace: String::new(),
+        priority: 0,
+        visible_after_epoch_ms: Some(5_000),
+        dedup_key: None,
+        expires_at_epoch_ms: None,
+        drop_on_expire: false,
+    });
+
+    let item = &state.queue_items["merges"][0];
+    assert_eq!(item.status, QueueItemStatus::Pending);
+    assert!(item.is_scheduled(4_999));
+    assert!(!item.is_scheduled(5_000));
+}
+
+#[test]
+fn expired_item_is_overdue_then_settled() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&Event::QueuePushed {
+        queue_name: "inbox".to_string(),
+        item_id: "item-1".to_string(),
+        data: HashMap::new(),
+        pushed_at_epoch_ms: 1_000,
+        namespace: String::new(),
+        priority: 0,
+        visible_after_epoch_ms: None,
+        dedup_key: None,
+        expires_at_epoch_ms: Some(8_000),
+        drop_on_expire: false,
+    });
+    let item = &state.queue_items["inbox"][0];
+    assert!(!item.is_overdue(7_999));
+    assert!(item.is_overdue(8_000));
+
+    let expired = Event::QueueItemExpired {
+        queue_name: "inbox".to_string(),
+        item_id: "item-1".to_string(),
+        namespace: String::new(),
+    };
+    state.apply_event(&expired);
+    state.apply_event(&expired);
+
+    let item = &state.queue_items["inbox"][0];
+    assert_eq!(item.status, QueueItemStatus::Expired);
+    assert!(!item.is_overdue(9_000));
+}
+
+#[test]
+fn expire_does_not_touch_taken_item() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&queue_pushed_event("inbox", "item-1"));
+    state.apply_event(&Event::QueueTaken {
+        queue_name: "inbox".to_string(),
+        item_id: "item-1".to_string(),
+        worker_name: "w".to_string(),
+        namespace: String::new(),
+    });
+    state.apply_event(&Event::QueueItemExpired {
+        queue_name: "inbox".to_string(),
+        item_id: "item-1".to_string(),
+        namespace: String::new(),
+    });
+
+    assert_eq!(
+        state.queue_items["inbox"][0].status,
+        QueueItemStatus::Active
+    );
+}
+
+#[test]
+fn completed_records_time_and_merge_updates_data() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&queue_pushed_event("bugs", "item-1"));
+    let merged: HashMap<String, String> = [("title".to_string(), "Fix bug (dup)".to_string())]
+        .into_iter()
+        .collect();
+    state.apply_event(&Event::QueueItemMerged {
+        queue_name: "bugs".to_string(),
+        item_id: "item-1".to_string(),
+        data: merged.clone(),
+        priority: 4,
+        namespace: String::new(),
+    });
+    let item = &state.queue_items["bugs"][0];
+    assert_eq!(item.data, merged);
+    assert_eq!(item.priority, 4);
+
+    state.apply_event(&Event::QueueCompleted {
+        queue_name: "bugs".to_string(),
+        item_id: "item-1".to_string(),
+        namespace: String::new(),
+        completed_at_epoch_ms: Some(9_000),
+    });
+    assert_eq!(
+        state.queue_items["bugs"][0].completed_at_epoch_ms,
+        Some(9_000)
+    );
+}
+
+fn lease_events(item_id: &str) -> (Event, Event) {
+    let leased = Event::QueueItemLeased {
+        queue_name: "bugs".to_string(),
+        item_id: item_id.to_string(),
+        expires_at_epoch_ms: 7_200_000,
+        namespace: String::new(),
+    };
+    let expired = Event::QueueLeaseExpired {
+        queue_name: "bugs".to_string(),
+        item_id: item_id.to_string(),
+        namespace: String::new(),
+    };
+    (leased, expired)
+}
+
+#[test]
+fn lease_expiry_fails_the_item() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&queue_pushed_event("bugs", "item-1"));
+    state.apply_event(&queue_taken_event("bugs", "item-1", "fixer"));
+    let (leased, expired) = lease_events("item-1");
+    state.apply_event(&leased);
+    assert_eq!(
+        state.queue_items["bugs"][0].lease_expires_at_epoch_ms,
+        Some(7_200_000)
+    );
+
+    // Replaying the expiry must not count a second failure
+    state.apply_event(&expired);
+    state.apply_event(&expired);
+
+    let item = &state.queue_items["bugs"][0];
+    assert_eq!(item.status, QueueItemStatus::Failed);
+    assert_eq!(item.failure_count, 1);
+    assert!(item.lease_expires_at_epoch_ms.is_none());
+}
+
+#[test]
+fn lease_expiry_frees_slots_of_deleted_handlers() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&Event::WorkerStarted {
+        worker_name: "fixer".to_string(),
+        project_root: PathBuf::from("/test/project"),
+        runbook_hash: "abc123".to_string(),
+        queue_name: "bugs".to_string(),
+        queues: Default::default(),
+        concurrency: 1,
+        rate: None,
+        namespace: String::new(),
+    });
+    state
+        .workers
+        .get_mut("fixer")
+        .unwrap()
+        .active_job_ids
+        .push("deleted-job".to_string());
+    state.apply_event(&queue_pushed_event("bugs", "item-1"));
+    state.apply_event(&queue_taken_event("bugs", "item-1", "fixer"));
+    state.apply_event(&lease_events("item-1").1);
+
+    assert!(state.workers["fixer"].active_job_ids.is_empty());
+}
+
+#[test]
+fn lease_ignored_once_item_leaves_active() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&queue_pushed_event("bugs", "item-1"));
+    state.apply_event(&queue_taken_event("bugs", "item-1", "fixer"));
+    let (leased, expired) = lease_events("item-1");
+    state.apply_event(&leased);
+    state.apply_event(&queue_completed_event("bugs", "item-1"));
+    state.apply_event(&leased);
+    state.apply_event(&expired);
+
+    let item = &state.queue_items["bugs"][0];
+    assert_eq!(item.status, QueueItemStatus::Completed);
+    assert_eq!(item.failure_count, 0);
+    assert!(item.lease_expires_at_epoch_ms.is_none());
+}
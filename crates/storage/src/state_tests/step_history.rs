@@ -87,6 +87,7 @@ fn shell_completed_success() {
         exit_code: 0,
         stdout: None,
         stderr: None,
+        timed_out: false,
     });
 
     let job = &state.jobs["pipe-1"];
@@ -104,6 +105,7 @@ fn shell_completed_failure() {
         exit_code: 42,
         stdout: None,
         stderr: None,
+        timed_out: false,
     });
 
     let job = &state.jobs["pipe-1"];
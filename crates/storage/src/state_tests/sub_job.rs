@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use oj_core::{Event, JobId};
+
+fn delegated(parent: &str, step: &str, child: &str) -> Event {
+    Event::StepDelegated {
+        job_id: JobId::new(parent),
+        step: step.to_string(),
+        child_job_id: JobId::new(child),
+    }
+}
+
+#[test]
+fn step_delegated_links_child_and_records_it_on_parent() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&job_create_event("job-1", "ship", "s", "merge"));
+    state.apply_event(&job_create_event("job-2", "merge", "s/merge", "rebase"));
+    state.apply_event(&delegated("job-1", "merge", "job-2"));
+
+    let child = &state.jobs["job-2"];
+    assert_eq!(child.parent_job_id.as_deref(), Some("job-1"));
+    assert!(!child.is_branch);
+
+    let record = &state.jobs["job-1"].step_history[0];
+    assert_eq!(record.job_id.as_deref(), Some("job-2"));
+    assert!(record.branches.is_empty());
+}
+
+#[test]
+fn step_delegated_ignored_when_parent_left_step() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&job_create_event("job-1", "ship", "s", "merge"));
+    state.apply_event(&job_create_event("job-2", "merge", "s/merge", "rebase"));
+    state.apply_event(&job_transition_event("job-1", "deploy"));
+    state.apply_event(&delegated("job-1", "merge", "job-2"));
+
+    assert!(state.jobs["job-1"]
+        .step_history
+        .iter()
+        .all(|r| r.job_id.is_none()));
+}
@@ -0,0 +1,104 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use oj_core::{StepStatus, TimerId};
+
+fn step_retrying_event(job_id: &str, step: &str, attempt: u32) -> Event {
+    Event::StepRetrying {
+        job_id: JobId::new(job_id),
+        step: step.to_string(),
+        attempt,
+        error: "shell exit code: 1".to_string(),
+    }
+}
+
+#[test]
+fn durable_timer_recorded_until_it_fires() {
+    let mut state = MaterializedState::default();
+    let id = TimerId::queue_retry("bugs", "item-1");
+
+    state.apply_event(&Event::TimerScheduled {
+        id: id.clone(),
+        fires_at_ms: 5_000,
+    });
+    assert_eq!(state.timers.get(id.as_str()), Some(&5_000));
+
+    state.apply_event(&Event::TimerStart { id: id.clone() });
+    assert!(state.timers.is_empty());
+}
+
+#[test]
+fn step_retry_records_failed_attempt_once() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&job_create_event("pipe-1", "build", "test", "fetch"));
+
+    state.apply_event(&step_retrying_event("pipe-1", "fetch", 1));
+    state.apply_event(&step_retrying_event("pipe-1", "fetch", 1));
+
+    let job = &state.jobs["pipe-1"];
+    assert_eq!(job.step_retries, 1);
+    assert_eq!(job.total_retries, 1);
+    assert_eq!(job.step_status, StepStatus::Pending);
+    assert_eq!(job.step_history.len(), 2);
+    assert_eq!(
+        job.step_history[0].outcome,
+        StepOutcome::Failed("shell exit code: 1".to_string())
+    );
+    assert!(job.step_history[1].finished_at_ms.is_none());
+}
+
+#[test]
+fn leaving_step_resets_retries_and_terminal_job_drops_timer() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&job_create_event("pipe-1", "build", "test", "fetch"));
+    state.apply_event(&step_retrying_event("pipe-1", "fetch", 1));
+    let id = TimerId::step_retry(&JobId::new("pipe-1"));
+    state.apply_event(&Event::TimerScheduled {
+        id: id.clone(),
+        fires_at_ms: 5_000,
+    });
+
+    state.apply_event(&job_transition_event("pipe-1", "cancelled"));
+
+    assert_eq!(state.jobs["pipe-1"].step_retries, 0);
+    assert!(!state.timers.contains_key(id.as_str()));
+}
+
+#[test]
+fn cancelled_durable_timer_is_dropped() {
+    let mut state = MaterializedState::default();
+    let id = TimerId::step_retry(&JobId::new("pipe-1"));
+    state.apply_event(&Event::TimerScheduled {
+        id: id.clone(),
+        fires_at_ms: 5_000,
+    });
+
+    state.apply_event(&Event::TimerCancelled { id });
+
+    assert!(state.timers.is_empty());
+}
+
+#[yare::parameterized(
+    resumed = { Event::StepStarted {
+        job_id: JobId::new("pipe-1"),
+        step: "fetch".to_string(),
+        agent_id: None,
+        agent_name: None,
+    } },
+    routed_elsewhere = { job_transition_event("pipe-1", "report") },
+)]
+fn superseded_step_retry_timer_is_dropped(event: Event) {
+    let mut state = MaterializedState::default();
+    state.apply_event(&job_create_event("pipe-1", "build", "test", "fetch"));
+    state.apply_event(&step_retrying_event("pipe-1", "fetch", 1));
+    let id = TimerId::step_retry(&JobId::new("pipe-1"));
+    state.apply_event(&Event::TimerScheduled {
+        id: id.clone(),
+        fires_at_ms: 5_000,
+    });
+
+    state.apply_event(&event);
+
+    assert!(!state.timers.contains_key(id.as_str()));
+}
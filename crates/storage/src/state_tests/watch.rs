@@ -0,0 +1,65 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use oj_core::{Event, JobId};
+
+fn watch_started(namespace: &str) -> Event {
+    Event::WatchStarted {
+        watch_name: "specs".to_string(),
+        project_root: PathBuf::from("/test/project"),
+        runbook_hash: "abc123".to_string(),
+        paths: vec!["docs/specs/**/*.md".to_string()],
+        debounce: "30s".to_string(),
+        run_target: "job:spec-review".to_string(),
+        namespace: namespace.to_string(),
+    }
+}
+
+#[test]
+fn watch_started_creates_record() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&watch_started("docs"));
+
+    let record = &state.watches["docs/specs"];
+    assert_eq!(record.name, "specs");
+    assert_eq!(record.status, "running");
+    assert_eq!(record.paths, vec!["docs/specs/**/*.md"]);
+    assert_eq!(record.debounce, "30s");
+    assert_eq!(record.run_target, "job:spec-review");
+    assert_eq!(
+        state.project_root_for_namespace("docs"),
+        Some(PathBuf::from("/test/project"))
+    );
+}
+
+#[test]
+fn watch_stopped_updates_status() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&watch_started(""));
+    state.apply_event(&Event::WatchStopped {
+        watch_name: "specs".to_string(),
+        namespace: String::new(),
+    });
+
+    assert_eq!(state.watches["specs"].status, "stopped");
+}
+
+#[test]
+fn watch_fired_survives_restart() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&watch_started(""));
+    assert_eq!(state.watches["specs"].last_fired_at_ms, None);
+
+    state.apply_event(&Event::WatchFired {
+        watch_name: "specs".to_string(),
+        job_id: JobId::new("job-1"),
+        namespace: String::new(),
+    });
+    let fired = state.watches["specs"].last_fired_at_ms;
+    assert!(fired.is_some());
+
+    // The daemon re-emits WatchStarted for running watches on startup
+    state.apply_event(&watch_started(""));
+    assert_eq!(state.watches["specs"].last_fired_at_ms, fired);
+}
@@ -11,7 +11,9 @@ fn worker_started_with_queue_and_concurrency() {
         project_root: PathBuf::from("/test/project"),
         runbook_hash: "abc123".to_string(),
         queue_name: "bugs".to_string(),
+        queues: Default::default(),
         concurrency: 3,
+        rate: None,
         namespace: String::new(),
     });
     let worker = &state.workers["fixer"];
@@ -21,6 +23,24 @@ fn worker_started_with_queue_and_concurrency() {
     assert!(worker.active_job_ids.is_empty());
 }
 
+#[test]
+fn worker_started_with_weighted_queues() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&Event::WorkerStarted {
+        worker_name: "triage".to_string(),
+        project_root: PathBuf::from("/test/project"),
+        runbook_hash: "abc123".to_string(),
+        queue_name: String::new(),
+        queues: [("bugs".to_string(), 3), ("chores".to_string(), 1)].into(),
+        concurrency: 4,
+        rate: None,
+        namespace: String::new(),
+    });
+    let worker = &state.workers["triage"];
+    assert_eq!(worker.queue_names(), vec!["bugs", "chores"]);
+    assert_eq!(worker.queues["bugs"], 3);
+}
+
 #[test]
 fn worker_stopped_sets_status() {
     let mut state = MaterializedState::default();
@@ -70,12 +90,16 @@ fn worker_started_preserves_active_job_ids(ns: &str, worker_key: &str) {
         worker_name: "fixer".to_string(),
         item_id: "item-1".to_string(),
         job_id: JobId::new("pipe-1"),
+        agent_run_id: None,
+        bucket_full_at_ms: None,
         namespace: ns.to_string(),
     });
     state.apply_event(&Event::WorkerItemDispatched {
         worker_name: "fixer".to_string(),
         item_id: "item-2".to_string(),
         job_id: JobId::new("pipe-2"),
+        agent_run_id: None,
+        bucket_full_at_ms: None,
         namespace: ns.to_string(),
     });
 
@@ -90,6 +114,35 @@ fn worker_started_preserves_active_job_ids(ns: &str, worker_key: &str) {
     assert!(worker.active_job_ids.contains(&"pipe-2".to_string()));
 }
 
+#[test]
+fn agent_dispatch_tracked_until_run_finishes() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&worker_start_event("triage", ""));
+    state.apply_event(&Event::WorkerItemDispatched {
+        worker_name: "triage".to_string(),
+        item_id: "item-1".to_string(),
+        job_id: JobId::new(""),
+        agent_run_id: Some("ar-1".to_string()),
+        bucket_full_at_ms: None,
+        namespace: String::new(),
+    });
+
+    let worker = &state.workers["triage"];
+    assert!(worker.active_job_ids.is_empty());
+    assert_eq!(worker.active_agent_run_ids, vec!["ar-1".to_string()]);
+
+    // Survives a WorkerStarted replay, like active jobs
+    state.apply_event(&worker_start_event("triage", ""));
+    assert_eq!(state.workers["triage"].active_agent_run_ids.len(), 1);
+
+    state.apply_event(&Event::AgentRunStatusChanged {
+        id: AgentRunId::new("ar-1"),
+        status: oj_core::AgentRunStatus::Completed,
+        reason: None,
+    });
+    assert!(state.workers["triage"].active_agent_run_ids.is_empty());
+}
+
 #[test]
 fn worker_deleted_lifecycle_and_ghost() {
     let mut state = MaterializedState::default();
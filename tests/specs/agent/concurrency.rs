@@ -418,7 +418,7 @@ fn rapid_agent_completions_work() {
     // Create scenarios for four fast agents
     for i in 1..=4 {
         temp.file(
-            &format!(".oj/scenarios/rapid{}.toml", i),
+            format!(".oj/scenarios/rapid{}.toml", i),
             &fast_scenario(&format!("rapid{}", i)),
         );
     }